@@ -0,0 +1,76 @@
+//! Build script: embeds the git commit and GPUI version for the About window.
+
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    watch_git_head();
+    println!("cargo:rerun-if-changed=../Cargo.lock");
+
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=EXACTOBAR_COMMIT={commit}");
+
+    let gpui = std::fs::read_to_string("../Cargo.lock")
+        .ok()
+        .and_then(|lock| gpui_version(&lock))
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=EXACTOBAR_GPUI_VERSION={gpui}");
+}
+
+/// Reruns the build when the commit changes: on a checkout (HEAD), and on a
+/// commit to the current branch (its ref file, or `packed-refs` where git
+/// has packed it).
+fn watch_git_head() {
+    println!("cargo:rerun-if-changed=../.git/HEAD");
+
+    // Cargo reruns on every build for a path that doesn't exist, so only
+    // watch the files that are there. A packed branch gets its ref file back
+    // on the next commit, which the watched directory catches.
+    let head = std::fs::read_to_string("../.git/HEAD").unwrap_or_default();
+    if let Some(name) = head.strip_prefix("ref: ") {
+        let branch_ref = Path::new("../.git").join(name.trim());
+        match branch_ref.parent() {
+            Some(dir) if !branch_ref.exists() => watch(dir),
+            _ => watch(&branch_ref),
+        }
+    }
+    watch(Path::new("../.git/packed-refs"));
+}
+
+fn watch(path: &Path) {
+    if path.exists() {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+}
+
+/// Extracts "0.2.2 (c67328a)" from the `gpui` entry in Cargo.lock.
+fn gpui_version(lock: &str) -> Option<String> {
+    let entry = lock
+        .split("[[package]]")
+        .find(|entry| entry.contains("name = \"gpui\"\n"))?;
+
+    let field = |key: &str| {
+        entry
+            .lines()
+            .find_map(|line| line.strip_prefix(key))
+            .map(|value| value.trim_matches('"').to_string())
+    };
+
+    let version = field("version = ")?;
+    let rev = field("source = ").and_then(|source| {
+        source
+            .rsplit_once('#')
+            .map(|(_, rev)| rev.chars().take(7).collect::<String>())
+    });
+
+    Some(match rev {
+        Some(rev) => format!("{version} ({rev})"),
+        None => version,
+    })
+}
@@ -0,0 +1,38 @@
+//! Creates a bsdiff patch between two builds of the app for delta updates.
+//!
+//! Used by the release workflow:
+//!
+//! ```text
+//! cargo run --release -p exactobar-app --example make_delta -- OLD NEW PATCH
+//! ```
+
+use std::fs;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [old, new, patch] = args.as_slice() else {
+        eprintln!("usage: make_delta OLD NEW PATCH");
+        return ExitCode::FAILURE;
+    };
+
+    let result = (|| -> std::io::Result<usize> {
+        let old = fs::read(old)?;
+        let new = fs::read(new)?;
+        let mut out = Vec::new();
+        bsdiff::diff(&old, &new, &mut out)?;
+        fs::write(patch, &out)?;
+        Ok(out.len())
+    })();
+
+    match result {
+        Ok(size) => {
+            println!("wrote {} ({} bytes)", patch, size);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("make_delta: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
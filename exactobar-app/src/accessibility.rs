@@ -0,0 +1,304 @@
+//! Accessibility descriptions.
+//!
+//! The tray icons are images and the popup is custom-drawn, so screen readers
+//! (VoiceOver on macOS, AT-SPI on Linux) have nothing to read by default.
+//! This module describes each provider's state in words, e.g. "Claude: 62% of
+//! 5-hour limit used, resets in 2 hours", for the status items' labels and
+//! the popup's window title.
+//!
+//! It also decides whether to reduce motion, following both the setting and
+//! the OS preference (and pausing animations while saving power).
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use exactobar_core::{ProviderKind, UsageSnapshot, UsageWindow, WindowKind};
+use exactobar_providers::ProviderRegistry;
+use gpui::App;
+
+use crate::power;
+use crate::state::AppState;
+
+/// How long the OS reduce-motion preference is trusted before asking again.
+const SYSTEM_PREFERENCE_TTL: Duration = Duration::from_secs(60);
+
+/// Last OS reduce-motion preference read, and when.
+static SYSTEM_REDUCE_MOTION: Mutex<Option<(Instant, bool)>> = Mutex::new(None);
+
+/// Checks whether animations should be skipped, because of the setting, the
+/// OS preference, or power saving.
+pub fn reduce_motion(cx: &App) -> bool {
+    cx.global::<AppState>().settings.read(cx).reduce_motion()
+        || system_reduce_motion()
+        || power::saving_power(cx)
+}
+
+/// Returns the OS reduce-motion preference, read at most once per
+/// [`SYSTEM_PREFERENCE_TTL`].
+fn system_reduce_motion() -> bool {
+    let Ok(mut cached) = SYSTEM_REDUCE_MOTION.lock() else {
+        return false;
+    };
+    if let Some((read_at, reduce)) = *cached
+        && read_at.elapsed() < SYSTEM_PREFERENCE_TTL
+    {
+        return reduce;
+    }
+    let reduce = read_system_reduce_motion();
+    *cached = Some((Instant::now(), reduce));
+    reduce
+}
+
+#[cfg(target_os = "macos")]
+fn read_system_reduce_motion() -> bool {
+    use cocoa::base::{BOOL, NO, id};
+    use objc::{class, msg_send, sel, sel_impl};
+
+    unsafe {
+        let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
+        let reduce: BOOL = msg_send![workspace, accessibilityDisplayShouldReduceMotion];
+        reduce != NO
+    }
+}
+
+/// Reads GNOME's animation switch, the closest Linux has to a shared
+/// reduce-motion preference.
+#[cfg(not(target_os = "macos"))]
+fn read_system_reduce_motion() -> bool {
+    std::process::Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.interface", "enable-animations"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .is_some_and(|output| animations_disabled(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Parses the output of `gsettings get ... enable-animations`.
+#[cfg(not(target_os = "macos"))]
+fn animations_disabled(output: &str) -> bool {
+    output.trim() == "false"
+}
+
+/// What a provider is doing, as far as its description is concerned.
+enum Status<'a> {
+    Refreshing,
+    /// The last fetch failed, with a short description of why.
+    Failed(&'a str),
+    Usage(&'a UsageSnapshot),
+    NoData,
+}
+
+/// Describes a provider's current state.
+pub fn describe_provider(provider: ProviderKind, cx: &App) -> String {
+    let state = cx.global::<AppState>();
+    let descriptor = ProviderRegistry::get(provider);
+    let name = descriptor
+        .map(|d| d.display_name().to_string())
+        .unwrap_or_else(|| format!("{:?}", provider));
+    let session_label = descriptor
+        .map(|d| d.metadata.session_label.as_str())
+        .unwrap_or("Session");
+
+    let snapshot = state.get_snapshot(provider, cx);
+    let failure = state.get_failure(provider, cx);
+    let status = if state.is_provider_refreshing(provider, cx) {
+        Status::Refreshing
+    } else if state.get_error(provider, cx).is_some() {
+        Status::Failed(failure.as_ref().map_or("Error", |f| f.label()))
+    } else if let Some(snapshot) = snapshot.as_ref().filter(|s| s.has_data()) {
+        Status::Usage(snapshot)
+    } else {
+        Status::NoData
+    };
+
+    describe(&name, session_label, status, Utc::now())
+}
+
+/// Describes several providers, e.g. for the merged status item.
+pub fn describe_providers(providers: &[ProviderKind], cx: &App) -> String {
+    if providers.is_empty() {
+        return "ExactoBar: no providers enabled".to_string();
+    }
+    providers
+        .iter()
+        .map(|&provider| describe_provider(provider, cx))
+        .collect::<Vec<_>>()
+        .join(". ")
+}
+
+fn describe(name: &str, session_label: &str, status: Status<'_>, now: DateTime<Utc>) -> String {
+    match status {
+        Status::Refreshing => format!("{name}: refreshing"),
+        Status::Failed(reason) => format!("{name}: {}", reason.to_lowercase()),
+        Status::NoData => format!("{name}: no usage data yet"),
+        Status::Usage(snapshot) => {
+            // The fullest window is the one that decides when work stops
+            let Some(window) = WindowKind::all()
+                .iter()
+                .filter(|&&kind| kind != WindowKind::Search)
+                .filter_map(|&kind| snapshot.window(kind))
+                .max_by(|a, b| a.used_percent.total_cmp(&b.used_percent))
+            else {
+                return format!("{name}: no usage data yet");
+            };
+            describe_window(name, session_label, window, now)
+        }
+    }
+}
+
+fn describe_window(
+    name: &str,
+    session_label: &str,
+    window: &UsageWindow,
+    now: DateTime<Utc>,
+) -> String {
+    let limit = window
+        .window_minutes
+        .map(limit_name)
+        .unwrap_or_else(|| session_label.to_lowercase());
+    let mut description = format!(
+        "{name}: {:.0}% of {limit} limit used",
+        window.used_percent.clamp(0.0, 100.0)
+    );
+    if let Some(reset_at) = window.next_reset(now).filter(|&reset_at| reset_at > now) {
+        description.push_str(", resets in ");
+        description.push_str(&spoken_duration(reset_at - now));
+    }
+    description
+}
+
+/// Names a usage window by its length ("5-hour", "daily", "weekly").
+fn limit_name(minutes: u32) -> String {
+    match minutes {
+        1440 => "daily".to_string(),
+        10080 => "weekly".to_string(),
+        m if m % 1440 == 0 => format!("{}-day", m / 1440),
+        m if m % 60 == 0 => format!("{}-hour", m / 60),
+        m => format!("{m}-minute"),
+    }
+}
+
+/// Formats a duration for reading aloud ("2 hours 30 minutes", "3 days").
+fn spoken_duration(duration: chrono::Duration) -> String {
+    let total_minutes = duration.num_minutes();
+    if total_minutes < 1 {
+        return "less than a minute".to_string();
+    }
+
+    let days = total_minutes / (60 * 24);
+    let hours = total_minutes / 60 % 24;
+    let minutes = total_minutes % 60;
+    // Minutes stop mattering once a reset is days away
+    let parts = if days > 0 {
+        vec![(days, "day"), (hours, "hour")]
+    } else {
+        vec![(hours, "hour"), (minutes, "minute")]
+    };
+
+    parts
+        .into_iter()
+        .filter(|&(count, _)| count > 0)
+        .map(|(count, unit)| {
+            if count == 1 {
+                format!("1 {unit}")
+            } else {
+                format!("{count} {unit}s")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 3, 12, 0, 0).unwrap()
+    }
+
+    fn window(used_percent: f64, minutes: Option<u32>, resets_in: Option<Duration>) -> UsageWindow {
+        let mut window = UsageWindow::new(used_percent);
+        window.window_minutes = minutes;
+        window.resets_at = resets_in.map(|d| now() + d);
+        window
+    }
+
+    #[test]
+    fn test_describe_usage() {
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(window(62.4, Some(300), Some(Duration::hours(2))));
+        snapshot.secondary = Some(window(30.0, Some(10080), Some(Duration::days(3))));
+
+        assert_eq!(
+            describe("Claude", "Session", Status::Usage(&snapshot), now()),
+            "Claude: 62% of 5-hour limit used, resets in 2 hours"
+        );
+
+        // The fullest window is described
+        snapshot.secondary = Some(window(90.0, Some(10080), None));
+        assert_eq!(
+            describe("Claude", "Session", Status::Usage(&snapshot), now()),
+            "Claude: 90% of weekly limit used"
+        );
+    }
+
+    #[test]
+    fn test_describe_window_without_length() {
+        let window = window(5.0, None, Some(Duration::minutes(90)));
+        assert_eq!(
+            describe_window("Cursor", "Monthly", &window, now()),
+            "Cursor: 5% of monthly limit used, resets in 1 hour 30 minutes"
+        );
+    }
+
+    #[test]
+    fn test_describe_status() {
+        assert_eq!(
+            describe("Codex", "Session", Status::Refreshing, now()),
+            "Codex: refreshing"
+        );
+        assert_eq!(
+            describe("Codex", "Session", Status::Failed("Sign-in expired"), now()),
+            "Codex: sign-in expired"
+        );
+        assert_eq!(
+            describe("Codex", "Session", Status::NoData, now()),
+            "Codex: no usage data yet"
+        );
+    }
+
+    #[test]
+    fn test_limit_name() {
+        assert_eq!(limit_name(300), "5-hour");
+        assert_eq!(limit_name(1440), "daily");
+        assert_eq!(limit_name(10080), "weekly");
+        assert_eq!(limit_name(43200), "30-day");
+        assert_eq!(limit_name(90), "90-minute");
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn test_animations_disabled() {
+        assert!(animations_disabled("false\n"));
+        assert!(!animations_disabled("true\n"));
+        assert!(!animations_disabled(""));
+    }
+
+    #[test]
+    fn test_spoken_duration() {
+        assert_eq!(spoken_duration(Duration::seconds(30)), "less than a minute");
+        assert_eq!(spoken_duration(Duration::minutes(1)), "1 minute");
+        assert_eq!(
+            spoken_duration(Duration::minutes(150)),
+            "2 hours 30 minutes"
+        );
+        assert_eq!(spoken_duration(Duration::hours(49)), "2 days 1 hour");
+    }
+}
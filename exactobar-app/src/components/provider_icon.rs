@@ -3,6 +3,8 @@
 use exactobar_core::ProviderKind;
 use gpui::*;
 
+use crate::theme;
+
 /// Provider icon with brand color.
 pub struct ProviderIcon {
     provider: ProviderKind,
@@ -23,21 +25,7 @@ impl ProviderIcon {
     }
 
     fn brand_color(&self) -> Hsla {
-        match self.provider {
-            ProviderKind::Codex => hsla(160.0 / 360.0, 0.82, 0.35, 1.0),
-            ProviderKind::Claude => hsla(25.0 / 360.0, 0.55, 0.53, 1.0),
-            ProviderKind::Cursor => hsla(265.0 / 360.0, 0.70, 0.60, 1.0),
-            ProviderKind::Gemini => hsla(217.0 / 360.0, 0.91, 0.60, 1.0),
-            ProviderKind::Copilot => hsla(215.0 / 360.0, 0.14, 0.34, 1.0),
-            ProviderKind::Factory => hsla(0.0, 0.70, 0.60, 1.0),
-            ProviderKind::VertexAI => hsla(217.0 / 360.0, 0.91, 0.60, 1.0),
-            ProviderKind::Zai => hsla(0.0, 0.0, 0.40, 1.0),
-            ProviderKind::Augment => hsla(275.0 / 360.0, 1.0, 0.25, 1.0),
-            ProviderKind::Kiro => hsla(39.0 / 360.0, 1.0, 0.50, 1.0),
-            ProviderKind::MiniMax => hsla(195.0 / 360.0, 1.0, 0.50, 1.0),
-            ProviderKind::Antigravity => hsla(282.0 / 360.0, 1.0, 0.41, 1.0),
-            ProviderKind::Synthetic => hsla(168.0 / 360.0, 1.0, 0.40, 1.0), // Teal
-        }
+        theme::provider_color(self.provider)
     }
 
     fn icon_char(&self) -> &'static str {
@@ -55,6 +43,7 @@ impl ProviderIcon {
             ProviderKind::MiniMax => "M",
             ProviderKind::Antigravity => "∞",
             ProviderKind::Synthetic => "S",
+            ProviderKind::Demo => "◇",
         }
     }
 }
@@ -0,0 +1,345 @@
+//! Crash reports.
+//!
+//! A menu bar app that panics just disappears, so a panic hook writes a
+//! report (backtrace, version, enabled providers, recent log lines) to the
+//! `crashes` folder in the config directory. On the next launch the app
+//! offers to reveal the report or open a GitHub issue for it.
+
+use std::backtrace::Backtrace;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use exactobar_core::ProviderKind;
+use gpui::*;
+use tracing::{info, warn};
+
+use crate::logs;
+use crate::state::AppState;
+
+/// Log lines included in a report.
+const REPORT_LOG_LINES: usize = 50;
+
+/// Reports kept on disk; older ones are deleted when a new one is written.
+const MAX_REPORTS: usize = 10;
+
+/// Suffix of reports the user was already asked about.
+const SEEN_SUFFIX: &str = ".seen.txt";
+
+/// Where to file a crash report.
+const NEW_ISSUE_URL: &str = "https://github.com/janfeddersen/exactobar/issues/new";
+
+/// Enabled providers, kept current for the panic hook, which can't reach
+/// the app state.
+static ENABLED_PROVIDERS: Mutex<Vec<ProviderKind>> = Mutex::new(Vec::new());
+
+/// Returns the folder crash reports are written to.
+fn crash_dir() -> PathBuf {
+    exactobar_store::default_config_dir().join("crashes")
+}
+
+/// Installs the panic hook that writes crash reports.
+///
+/// Only panics on the main thread end the app; other threads (refresh
+/// workers, the local server) just unwind, so they aren't reported as
+/// crashes. The default hook still runs afterwards, so every panic is
+/// printed as usual.
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if std::thread::current().name() != Some("main") {
+            default_hook(info);
+            return;
+        }
+
+        let report = crash_report(info, &Backtrace::force_capture(), Utc::now());
+        match write_report(&crash_dir(), &report, Utc::now()) {
+            Ok(path) => eprintln!("Crash report written to {}", path.display()),
+            Err(e) => eprintln!("Failed to write crash report: {e}"),
+        }
+        default_hook(info);
+    }));
+}
+
+/// Keeps the providers listed in crash reports in sync with the settings.
+pub fn track_enabled_providers(cx: &mut App) {
+    let settings = cx.global::<AppState>().settings.clone();
+    let update = |providers: Vec<ProviderKind>| {
+        if let Ok(mut enabled) = ENABLED_PROVIDERS.lock() {
+            *enabled = providers;
+        }
+    };
+
+    update(settings.read(cx).enabled_providers());
+    cx.observe(&settings, move |settings, cx| {
+        update(settings.read(cx).enabled_providers());
+    })
+    .detach();
+}
+
+/// Builds the text of a crash report.
+fn crash_report(info: &PanicHookInfo<'_>, backtrace: &Backtrace, now: DateTime<Utc>) -> String {
+    let message = info
+        .payload()
+        .downcast_ref::<&str>()
+        .map(|s| (*s).to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string());
+    let location = info
+        .location()
+        .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+        .unwrap_or_else(|| "unknown".to_string());
+    // The hook may run while the panicking thread holds a lock, so never wait
+    let providers = ENABLED_PROVIDERS
+        .try_lock()
+        .map(|providers| format!("{:?}", *providers))
+        .unwrap_or_else(|_| "unknown".to_string());
+
+    format_report(
+        &message,
+        &location,
+        std::thread::current().name().unwrap_or("unnamed"),
+        &providers,
+        &backtrace.to_string(),
+        &logs::try_recent_lines(REPORT_LOG_LINES),
+        now,
+    )
+}
+
+fn format_report(
+    message: &str,
+    location: &str,
+    thread: &str,
+    providers: &str,
+    backtrace: &str,
+    log_lines: &[String],
+    now: DateTime<Utc>,
+) -> String {
+    let mut report = format!(
+        "ExactoBar {} ({} {}) crashed at {}\n\n\
+         Panic: {}\nLocation: {}\nThread: {}\nEnabled providers: {}\n\n\
+         Backtrace:\n{}",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        now.to_rfc3339(),
+        message,
+        location,
+        thread,
+        providers,
+        backtrace.trim_end()
+    );
+
+    if !log_lines.is_empty() {
+        report.push_str("\n\nRecent log lines:\n");
+        report.push_str(&log_lines.join("\n"));
+    }
+    report.push('\n');
+
+    report
+}
+
+/// Writes a report to `dir`, deleting the oldest ones past [`MAX_REPORTS`].
+fn write_report(dir: &Path, report: &str, now: DateTime<Utc>) -> std::io::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!("crash-{}.txt", now.format("%Y%m%d-%H%M%S")));
+    std::fs::write(&path, report)?;
+
+    let reports = list_reports(dir);
+    for old in reports
+        .iter()
+        .take(reports.len().saturating_sub(MAX_REPORTS))
+    {
+        let _ = std::fs::remove_file(old);
+    }
+
+    Ok(path)
+}
+
+/// Returns the reports in `dir`, oldest first.
+fn list_reports(dir: &Path) -> Vec<PathBuf> {
+    let mut reports: Vec<PathBuf> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("crash-") && name.ends_with(".txt"))
+        })
+        .collect();
+    // Names start with the timestamp, so they sort by age
+    reports.sort();
+    reports
+}
+
+/// Returns the reports the user hasn't been asked about yet, oldest first.
+fn unseen_reports(dir: &Path) -> Vec<PathBuf> {
+    list_reports(dir)
+        .into_iter()
+        .filter(|path| !path.to_string_lossy().ends_with(SEEN_SUFFIX))
+        .collect()
+}
+
+/// Marks a report as seen, so it isn't offered again.
+fn mark_seen(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?.strip_suffix(".txt")?;
+    let seen = path.with_file_name(format!("{name}{SEEN_SUFFIX}"));
+    std::fs::rename(path, &seen).ok()?;
+    Some(seen)
+}
+
+/// Offers to reveal or report the crash of the previous run, if there was one.
+///
+/// Only the newest report is offered; older ones are marked seen with it.
+pub fn offer_last_report() {
+    std::thread::spawn(|| {
+        let mut reports: Vec<PathBuf> = unseen_reports(&crash_dir())
+            .iter()
+            .filter_map(|path| mark_seen(path))
+            .collect();
+        let Some(report) = reports.pop() else {
+            return;
+        };
+        info!(path = %report.display(), "Found crash report from the last run");
+        ask_about_report(&report);
+    });
+}
+
+/// Asks the user what to do with a crash report.
+#[cfg(target_os = "macos")]
+fn ask_about_report(report: &Path) {
+    let script = r#"
+        set dialogResult to display dialog "ExactoBar quit unexpectedly the last time it ran. A crash report was saved; sharing it helps get the problem fixed." buttons {"Ignore", "Show Report", "Report Issue…"} default button "Report Issue…" with title "ExactoBar" with icon caution
+        return button returned of dialogResult
+        "#;
+    let Ok(output) = Command::new("osascript").args(["-e", script]).output() else {
+        return;
+    };
+
+    match String::from_utf8_lossy(&output.stdout).trim() {
+        "Show Report" => reveal(report),
+        "Report Issue…" => {
+            reveal(report);
+            crate::menu::open_url(&new_issue_url());
+        }
+        _ => {}
+    }
+}
+
+/// Asks the user what to do with a crash report.
+#[cfg(not(target_os = "macos"))]
+fn ask_about_report(report: &Path) {
+    warn!(
+        path = %report.display(),
+        "ExactoBar crashed the last time it ran; please attach this report to an issue at {}",
+        NEW_ISSUE_URL
+    );
+}
+
+/// Shows a report in the file manager.
+fn reveal(report: &Path) {
+    #[cfg(target_os = "macos")]
+    let result = Command::new("open").arg("-R").arg(report).spawn();
+
+    #[cfg(not(target_os = "macos"))]
+    let result = Command::new("xdg-open")
+        .arg(report.parent().unwrap_or(report))
+        .spawn();
+
+    if let Err(e) = result {
+        warn!(path = %report.display(), error = %e, "Failed to reveal crash report");
+    }
+}
+
+/// Returns the URL of a new GitHub issue prefilled for a crash.
+fn new_issue_url() -> String {
+    let version = env!("CARGO_PKG_VERSION");
+    let body = format!(
+        "ExactoBar {} crashed on {} {}.\n\n\
+         Please attach the crash report that was just revealed and describe what you were doing.",
+        version,
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+    );
+    reqwest::Url::parse_with_params(
+        NEW_ISSUE_URL,
+        [("title", format!("Crash in {version}")), ("body", body)],
+    )
+    .map_or_else(|_| NEW_ISSUE_URL.to_string(), String::from)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 3, 12, 0, second).unwrap()
+    }
+
+    #[test]
+    fn test_format_report() {
+        let report = format_report(
+            "index out of bounds",
+            "src/tray.rs:10:5",
+            "main",
+            "[Claude]",
+            "   0: exactobar::main\n",
+            &["INFO ExactoBar starting...".to_string()],
+            at(0),
+        );
+
+        assert!(report.starts_with(&format!("ExactoBar {}", env!("CARGO_PKG_VERSION"))));
+        assert!(report.contains("crashed at 2026-03-03T12:00:00+00:00"));
+        assert!(report.contains("Panic: index out of bounds\nLocation: src/tray.rs:10:5\n"));
+        assert!(
+            report.contains("Enabled providers: [Claude]\n\nBacktrace:\n   0: exactobar::main\n")
+        );
+        assert!(report.ends_with("Recent log lines:\nINFO ExactoBar starting...\n"));
+    }
+
+    #[test]
+    fn test_reports_are_offered_once() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(unseen_reports(dir.path()).is_empty());
+
+        let oldest = write_report(dir.path(), "first", at(0)).unwrap();
+        let newest = write_report(dir.path(), "second", at(1)).unwrap();
+        assert_eq!(
+            unseen_reports(dir.path()),
+            vec![oldest.clone(), newest.clone()]
+        );
+
+        let seen = mark_seen(&newest).unwrap();
+        assert_eq!(std::fs::read_to_string(seen).unwrap(), "second");
+        assert_eq!(unseen_reports(dir.path()), vec![oldest]);
+        assert_eq!(list_reports(dir.path()).len(), 2);
+    }
+
+    #[test]
+    fn test_new_issue_url() {
+        let url = new_issue_url();
+        assert!(url.starts_with(&format!("{NEW_ISSUE_URL}?title=Crash+in+")));
+        assert!(url.contains("&body=ExactoBar+"));
+    }
+
+    #[test]
+    fn test_old_reports_are_pruned() {
+        let dir = tempfile::tempdir().unwrap();
+        for second in 0..12 {
+            write_report(dir.path(), "report", at(second)).unwrap();
+        }
+
+        let reports = list_reports(dir.path());
+        assert_eq!(reports.len(), MAX_REPORTS);
+        assert!(reports[0].ends_with("crash-20260303-120002.txt"));
+    }
+}
@@ -0,0 +1,307 @@
+//! User theme file.
+//!
+//! A `theme.json` in the config directory overrides the menu's colors, so the
+//! popup can match the rest of the desktop. Every key is optional; colors are
+//! `#rgb`, `#rrggbb` or `#rrggbbaa` hex strings:
+//!
+//! ```json
+//! {
+//!   "surface_background": "#1e1e2ee6",
+//!   "text_primary": "#cdd6f4",
+//!   "accent": "#89b4fa",
+//!   "providers": { "copilot": "#a6adc8" },
+//!   "usage_gradient": ["#a6e3a1", "#f9e2af", "#f38ba8"]
+//! }
+//! ```
+//!
+//! The file is checked for changes every few seconds and applied live. The
+//! high-contrast theme ignores it.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime};
+
+use exactobar_core::ProviderKind;
+use gpui::*;
+use serde::Deserialize;
+use tracing::{info, warn};
+
+/// How often the theme file is checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The loaded theme file, if there is one.
+static CUSTOM_THEME: RwLock<Option<CustomTheme>> = RwLock::new(None);
+
+/// A color from the theme file.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct HexColor(pub Hsla);
+
+impl TryFrom<String> for HexColor {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        parse_hex(&value)
+            .map(HexColor)
+            .ok_or_else(|| format!("invalid color `{value}`, expected #rrggbb or #rrggbbaa"))
+    }
+}
+
+impl From<HexColor> for Hsla {
+    fn from(color: HexColor) -> Self {
+        color.0
+    }
+}
+
+/// Colors overridden by the theme file.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CustomTheme {
+    /// Menu panel and window background.
+    pub surface_background: Option<HexColor>,
+    /// Provider cards.
+    pub card_background: Option<HexColor>,
+    /// Buttons and controls.
+    pub surface: Option<HexColor>,
+    pub text_primary: Option<HexColor>,
+    pub text_secondary: Option<HexColor>,
+    pub muted: Option<HexColor>,
+    /// Borders and separators.
+    pub border: Option<HexColor>,
+    pub hover: Option<HexColor>,
+    pub active: Option<HexColor>,
+    /// Progress bar tracks.
+    pub track: Option<HexColor>,
+    pub accent: Option<HexColor>,
+    pub success: Option<HexColor>,
+    pub warning: Option<HexColor>,
+    pub error: Option<HexColor>,
+    /// Provider brand colors, keyed by provider ID ("claude", "copilot", ...).
+    pub providers: HashMap<ProviderKind, HexColor>,
+    /// Usage bar colors from 0% to 100% used, spaced evenly.
+    pub usage_gradient: Vec<HexColor>,
+}
+
+impl CustomTheme {
+    /// Parses a theme file.
+    pub fn parse(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the gradient color for a usage percentage, if the theme sets
+    /// a gradient.
+    pub fn usage_color(&self, used_percent: f32) -> Option<Hsla> {
+        let stops: Vec<Rgba> = self
+            .usage_gradient
+            .iter()
+            .map(|stop| stop.0.to_rgb())
+            .collect();
+        let (first, rest) = stops.split_first()?;
+        if rest.is_empty() {
+            return Some((*first).into());
+        }
+
+        // Position along the stops, e.g. 1.5 is halfway between the 2nd and 3rd
+        let position = (used_percent.clamp(0.0, 100.0) / 100.0) * rest.len() as f32;
+        let index = (position.floor() as usize).min(rest.len() - 1);
+        let t = position - index as f32;
+        let (from, to) = (stops[index], stops[index + 1]);
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        Some(
+            Rgba {
+                r: mix(from.r, to.r),
+                g: mix(from.g, to.g),
+                b: mix(from.b, to.b),
+                a: mix(from.a, to.a),
+            }
+            .into(),
+        )
+    }
+}
+
+/// Parses `#rgb`, `#rrggbb` or `#rrggbbaa`.
+pub fn parse_hex(value: &str) -> Option<Hsla> {
+    let hex = value.trim().strip_prefix('#')?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let packed = match hex.len() {
+        3 => {
+            // Each digit is doubled: #f80 is #ff8800
+            let expanded: String = hex.chars().flat_map(|c| [c, c]).collect();
+            (u32::from_str_radix(&expanded, 16).ok()? << 8) | 0xff
+        }
+        6 => (u32::from_str_radix(hex, 16).ok()? << 8) | 0xff,
+        8 => u32::from_str_radix(hex, 16).ok()?,
+        _ => return None,
+    };
+    Some(rgba(packed).into())
+}
+
+/// Looks up a color in the loaded theme file.
+pub fn lookup<T>(color: impl FnOnce(&CustomTheme) -> Option<T>) -> Option<T> {
+    CUSTOM_THEME.read().ok()?.as_ref().and_then(color)
+}
+
+/// Returns the theme file's path.
+pub fn theme_path() -> PathBuf {
+    exactobar_store::default_config_dir().join("theme.json")
+}
+
+/// Loads the theme file and reapplies it whenever it changes.
+pub fn watch(cx: &mut App) {
+    let path = theme_path();
+    let mut modified = modified_at(&path);
+    reload(&path);
+
+    cx.spawn(async move |cx| {
+        loop {
+            smol::Timer::after(POLL_INTERVAL).await;
+            let now = modified_at(&path);
+            if now == modified {
+                continue;
+            }
+            modified = now;
+            if reload(&path) {
+                cx.update(|cx| cx.refresh_windows());
+            }
+        }
+    })
+    .detach();
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Reads the theme file again, returning whether the colors changed.
+///
+/// A file that fails to parse leaves the current colors alone, so a
+/// half-saved edit doesn't flash the default theme.
+fn reload(path: &Path) -> bool {
+    let theme = match std::fs::read_to_string(path) {
+        Ok(json) => match CustomTheme::parse(&json) {
+            Ok(theme) => Some(theme),
+            Err(e) => {
+                warn!(path = %path.display(), error = %e, "Failed to parse theme file");
+                return false;
+            }
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => {
+            warn!(path = %path.display(), error = %e, "Failed to read theme file");
+            return false;
+        }
+    };
+
+    let Ok(mut current) = CUSTOM_THEME.write() else {
+        return false;
+    };
+    if *current == theme {
+        return false;
+    }
+    match theme {
+        Some(_) => info!(path = %path.display(), "Applied theme file"),
+        None => info!("Theme file removed, using the built-in colors"),
+    }
+    *current = theme;
+    true
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rgb(color: Hsla, expected: (f32, f32, f32, f32)) {
+        let rgb = color.to_rgb();
+        let channels = [expected.0, expected.1, expected.2, expected.3];
+        for (actual, channel) in [rgb.r, rgb.g, rgb.b, rgb.a].into_iter().zip(channels) {
+            assert!((actual - channel).abs() < 0.01, "{rgb:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_rgb(parse_hex("#ff0000").unwrap(), (1.0, 0.0, 0.0, 1.0));
+        assert_rgb(parse_hex("#f80").unwrap(), (1.0, 0.533, 0.0, 1.0));
+        assert_rgb(parse_hex(" #00ff0080 ").unwrap(), (0.0, 1.0, 0.0, 0.5));
+        assert!(parse_hex("ff0000").is_none());
+        assert!(parse_hex("#ff00").is_none());
+        assert!(parse_hex("#gg0000").is_none());
+    }
+
+    #[test]
+    fn test_parse_theme() {
+        let theme = CustomTheme::parse(
+            r##"{
+                "accent": "#0000ff",
+                "providers": { "copilot": "#ffffff", "vertexai": "#000000" },
+                "usage_gradient": ["#00ff00", "#ff0000"]
+            }"##,
+        )
+        .unwrap();
+
+        assert_rgb(theme.accent.unwrap().into(), (0.0, 0.0, 1.0, 1.0));
+        assert!(theme.text_primary.is_none());
+        assert_rgb(
+            theme.providers[&ProviderKind::Copilot].into(),
+            (1.0, 1.0, 1.0, 1.0),
+        );
+        assert!(theme.providers.contains_key(&ProviderKind::VertexAI));
+        assert_eq!(theme.usage_gradient.len(), 2);
+
+        assert_eq!(CustomTheme::parse("{}").unwrap(), CustomTheme::default());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        // Typos are reported rather than silently ignored
+        assert!(CustomTheme::parse(r##"{ "acent": "#0000ff" }"##).is_err());
+        let error = CustomTheme::parse(r#"{ "accent": "blue" }"#).unwrap_err();
+        assert!(error.to_string().contains("invalid color `blue`"));
+    }
+
+    #[test]
+    fn test_usage_color() {
+        let mut theme = CustomTheme::default();
+        assert!(theme.usage_color(50.0).is_none());
+
+        theme.usage_gradient = vec![HexColor(parse_hex("#00ff00").unwrap())];
+        assert_rgb(theme.usage_color(90.0).unwrap(), (0.0, 1.0, 0.0, 1.0));
+
+        theme.usage_gradient = ["#00ff00", "#ffff00", "#ff0000"]
+            .iter()
+            .map(|hex| HexColor(parse_hex(hex).unwrap()))
+            .collect();
+        assert_rgb(theme.usage_color(0.0).unwrap(), (0.0, 1.0, 0.0, 1.0));
+        assert_rgb(theme.usage_color(25.0).unwrap(), (0.5, 1.0, 0.0, 1.0));
+        assert_rgb(theme.usage_color(50.0).unwrap(), (1.0, 1.0, 0.0, 1.0));
+        assert_rgb(theme.usage_color(100.0).unwrap(), (1.0, 0.0, 0.0, 1.0));
+        assert_rgb(theme.usage_color(150.0).unwrap(), (1.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("theme.json");
+
+        std::fs::write(&path, r##"{ "muted": "#808080" }"##).unwrap();
+        assert!(reload(&path));
+        assert!(lookup(|theme| theme.muted).is_some());
+        assert!(!reload(&path));
+
+        // A broken edit keeps the last good colors
+        std::fs::write(&path, r##"{ "muted": "#80"##).unwrap();
+        assert!(!reload(&path));
+        assert!(lookup(|theme| theme.muted).is_some());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(reload(&path));
+        assert!(lookup(|theme| theme.muted).is_none());
+    }
+}
@@ -0,0 +1,61 @@
+//! Dock icon mode (macOS).
+//!
+//! ExactoBar normally runs as an accessory app: no Dock icon, no app menu in
+//! the menu bar, and no place in ⌘-Tab. With "Show in Dock" on it runs as a
+//! regular app instead, which suits keeping the menu pinned open as a panel.
+//! The switch happens at runtime by changing the app's activation policy.
+
+use gpui::*;
+use tracing::info;
+
+use crate::state::AppState;
+use crate::tray::SystemTray;
+
+/// Applies the Dock icon setting, and again whenever it changes.
+pub fn track(cx: &mut App) {
+    let settings = cx.global::<AppState>().settings.clone();
+    let mut current: Option<bool> = None;
+    let mut apply = move |visible: bool| {
+        if current == Some(visible) {
+            return;
+        }
+        // Only take focus when the user flips the setting, not at launch
+        let activate = current.is_some();
+        current = Some(visible);
+        set_dock_icon_visible(visible, activate);
+    };
+
+    apply(settings.read(cx).show_dock_icon());
+    cx.observe(&settings, move |settings, cx| {
+        apply(settings.read(cx).show_dock_icon());
+    })
+    .detach();
+}
+
+/// Opens the menu when the Dock icon is clicked.
+pub fn reopen(cx: &mut App) {
+    cx.update_global::<SystemTray, _>(|tray, cx| {
+        tray.show_menu(cx);
+    });
+}
+
+#[cfg(target_os = "macos")]
+fn set_dock_icon_visible(visible: bool, activate: bool) {
+    use cocoa::base::{BOOL, YES, id};
+    use objc::{class, msg_send, sel, sel_impl};
+
+    // NSApplicationActivationPolicyRegular and ...Accessory
+    let policy: i64 = if visible { 0 } else { 1 };
+    unsafe {
+        let app: id = msg_send![class!(NSApplication), sharedApplication];
+        let _: BOOL = msg_send![app, setActivationPolicy: policy];
+        if visible && activate {
+            // The app menu only appears once the app is active
+            let _: () = msg_send![app, activateIgnoringOtherApps: YES];
+        }
+    }
+    info!(visible, "Set Dock icon visibility");
+}
+
+#[cfg(not(target_os = "macos"))]
+fn set_dock_icon_visible(_visible: bool, _activate: bool) {}
@@ -0,0 +1,130 @@
+//! Usage history on disk.
+//!
+//! The history recorded at each refresh is loaded at launch and saved every
+//! minute, so the dashboard's charts survive restarts. Entries older than
+//! the retention setting are pruned at launch, once a day, and whenever the
+//! setting changes.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use exactobar_store::{HistoryFile, HistoryRetention};
+use gpui::*;
+use smol::Timer;
+use tracing::{info, warn};
+
+use crate::state::{AppState, tokio_runtime};
+
+/// How often new entries are saved.
+const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How often old entries are pruned.
+const PRUNE_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// The history file, shared so its operations run one at a time.
+static HISTORY_FILE: once_cell::sync::Lazy<HistoryFile> =
+    once_cell::sync::Lazy::new(HistoryFile::default_location);
+
+/// Loads the saved history, then keeps saving and pruning it.
+pub fn start(cx: &mut App) {
+    let state = cx.global::<AppState>();
+    let usage = state.usage.clone();
+    let settings = state.settings.clone();
+
+    let initial_retention = settings.read(cx).history_retention();
+    let mut retention = initial_retention;
+    cx.observe(&settings, move |settings, cx| {
+        let current = settings.read(cx).history_retention();
+        if current != retention {
+            retention = current;
+            prune(current, cx);
+        }
+    })
+    .detach();
+
+    cx.spawn(async move |mut cx| {
+        prune_file(initial_retention).await;
+        let loaded = on_tokio(HISTORY_FILE.load()).await;
+        let _ = cx.update_entity(&usage, |model, cx| {
+            model.merge_history(loaded);
+            cx.notify();
+        });
+        info!(path = %HISTORY_FILE.path().display(), "Loaded usage history");
+
+        let mut pruned_at = Instant::now();
+        loop {
+            Timer::after(SAVE_INTERVAL).await;
+
+            let entries = cx.update_entity(&usage, |model, _| model.take_unsaved_history());
+            if let Err(e) = on_tokio(async move { HISTORY_FILE.append(&entries).await }).await {
+                warn!(error = %e, "Failed to save usage history");
+            }
+
+            if pruned_at.elapsed() >= PRUNE_INTERVAL {
+                pruned_at = Instant::now();
+                let _ = cx.update(|cx| {
+                    let retention = cx
+                        .global::<AppState>()
+                        .settings
+                        .read(cx)
+                        .history_retention();
+                    prune(retention, cx);
+                });
+            }
+        }
+    })
+    .detach();
+}
+
+/// Deletes all recorded history, in memory and on disk.
+pub fn clear(cx: &mut App) {
+    info!("Clearing usage history");
+    let usage = cx.global::<AppState>().usage.clone();
+    usage.update(cx, |model, cx| {
+        model.clear_history();
+        cx.notify();
+    });
+    smol::spawn(on_tokio(async {
+        if let Err(e) = HISTORY_FILE.clear().await {
+            warn!(error = %e, "Failed to delete usage history");
+        }
+    }))
+    .detach();
+}
+
+/// Returns how much disk space the saved history takes, in bytes.
+pub fn disk_usage() -> u64 {
+    HISTORY_FILE.disk_usage()
+}
+
+/// Drops entries past the retention, in memory and on disk.
+fn prune(retention: HistoryRetention, cx: &mut App) {
+    let Some(cutoff) = retention.cutoff(Utc::now()) else {
+        return;
+    };
+    let usage = cx.global::<AppState>().usage.clone();
+    usage.update(cx, |model, cx| {
+        model.prune_history(cutoff);
+        cx.notify();
+    });
+    smol::spawn(prune_file(retention)).detach();
+}
+
+/// Drops entries past the retention from the history file.
+async fn prune_file(retention: HistoryRetention) {
+    let Some(cutoff) = retention.cutoff(Utc::now()) else {
+        return;
+    };
+    match on_tokio(HISTORY_FILE.prune(cutoff)).await {
+        Ok(0) => {}
+        Ok(removed) => info!(removed, "Pruned old usage history"),
+        Err(e) => warn!(error = %e, "Failed to prune usage history"),
+    }
+}
+
+/// Runs a file operation on the Tokio runtime, since the store is
+/// Tokio-based while GPUI runs on smol.
+async fn on_tokio<T: Send + 'static>(future: impl Future<Output = T> + Send + 'static) -> T {
+    smol::unblock(move || tokio_runtime().block_on(future)).await
+}
@@ -0,0 +1,611 @@
+//! Global keyboard shortcut.
+//!
+//! A system-wide shortcut (e.g. ⌥⇧E) opens and closes the menu from any app.
+//! macOS registers it with Carbon's `RegisterEventHotKey`; Linux grabs the
+//! key on the X11 root window. Wayland has no global key grab, so there the
+//! shortcut only fires while an X11 app is focused; binding the app's
+//! command to a shortcut in the desktop's keyboard settings opens the menu
+//! instead (see [`crate::instance`]).
+
+use std::fmt;
+
+use gpui::*;
+use tracing::{info, warn};
+
+use crate::state::AppState;
+use crate::tray::SystemTray;
+
+/// A key combination, parsed from strings like "alt+shift+e".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hotkey {
+    pub control: bool,
+    pub alt: bool,
+    pub shift: bool,
+    /// ⌘ on macOS, the Super (Windows) key on Linux.
+    pub command: bool,
+    /// Lowercase ASCII letter or digit, or ' ' for the space bar.
+    pub key: char,
+}
+
+impl Hotkey {
+    /// Parses a shortcut such as "alt+shift+e", "ctrl+alt+space" or "cmd+shift+1".
+    ///
+    /// At least one of Control, Alt or Command is required, so the shortcut
+    /// can't swallow ordinary typing.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut hotkey = Self {
+            control: false,
+            alt: false,
+            shift: false,
+            command: false,
+            key: '\0',
+        };
+        let parts: Vec<String> = value
+            .split('+')
+            .map(|part| part.trim().to_lowercase())
+            .collect();
+        let (key, modifiers) = parts
+            .split_last()
+            .ok_or_else(|| "empty shortcut".to_string())?;
+
+        for modifier in modifiers {
+            let flag = match modifier.as_str() {
+                "ctrl" | "control" => &mut hotkey.control,
+                "alt" | "opt" | "option" => &mut hotkey.alt,
+                "shift" => &mut hotkey.shift,
+                "cmd" | "command" | "super" | "meta" => &mut hotkey.command,
+                other => return Err(format!("unknown modifier `{other}`")),
+            };
+            *flag = true;
+        }
+
+        hotkey.key = match key.as_str() {
+            "space" => ' ',
+            key => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) if c.is_ascii_alphanumeric() => c,
+                    _ => {
+                        return Err(format!(
+                            "unsupported key `{key}`, expected a letter, digit or space"
+                        ));
+                    }
+                }
+            }
+        };
+
+        if !(hotkey.control || hotkey.alt || hotkey.command) {
+            return Err("a shortcut needs Control, Alt or Command".to_string());
+        }
+        Ok(hotkey)
+    }
+
+    fn key_name(&self) -> String {
+        if self.key == ' ' {
+            "Space".to_string()
+        } else {
+            self.key.to_ascii_uppercase().to_string()
+        }
+    }
+}
+
+/// Shows the shortcut the way the OS does ("⌥⇧E", "Ctrl+Alt+E").
+impl fmt::Display for Hotkey {
+    #[cfg(target_os = "macos")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Apple's order: ⌃⌥⇧⌘
+        for (held, symbol) in [
+            (self.control, "⌃"),
+            (self.alt, "⌥"),
+            (self.shift, "⇧"),
+            (self.command, "⌘"),
+        ] {
+            if held {
+                f.write_str(symbol)?;
+            }
+        }
+        f.write_str(&self.key_name())
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (held, name) in [
+            (self.control, "Ctrl+"),
+            (self.alt, "Alt+"),
+            (self.shift, "Shift+"),
+            (self.command, "Super+"),
+        ] {
+            if held {
+                f.write_str(name)?;
+            }
+        }
+        f.write_str(&self.key_name())
+    }
+}
+
+/// Registers the shortcut from the settings, re-registering it whenever it
+/// changes, and toggles the menu when it's pressed.
+///
+/// Call once the tray is set up.
+pub fn track(cx: &mut App) {
+    let (sender, receiver) = smol::channel::unbounded::<()>();
+    let settings = cx.global::<AppState>().settings.clone();
+    // Set up on first use, so nothing is installed without a shortcut
+    let mut registrar: Option<platform::Registrar> = None;
+    let mut current: Option<Hotkey> = None;
+    let mut apply = move |value: Option<&str>| {
+        let hotkey = value.and_then(|value| match Hotkey::parse(value) {
+            Ok(hotkey) => Some(hotkey),
+            Err(e) => {
+                warn!(shortcut = value, error = %e, "Ignoring invalid global shortcut");
+                None
+            }
+        });
+        if hotkey == current {
+            return;
+        }
+        current = hotkey;
+
+        let Some(hotkey) = hotkey else {
+            if let Some(registrar) = registrar.as_mut() {
+                registrar.unregister();
+            }
+            return;
+        };
+        if registrar.is_none() {
+            registrar = platform::Registrar::new(sender.clone());
+        }
+        let Some(registrar) = registrar.as_mut() else {
+            return;
+        };
+        match registrar.register(hotkey) {
+            Ok(()) => info!(shortcut = %hotkey, "Registered global shortcut"),
+            Err(e) => warn!(shortcut = %hotkey, error = %e, "Failed to register global shortcut"),
+        }
+    };
+
+    apply(settings.read(cx).global_hotkey());
+    cx.observe(&settings, move |settings, cx| {
+        apply(settings.read(cx).global_hotkey());
+    })
+    .detach();
+
+    cx.spawn(async move |cx| {
+        while receiver.recv().await.is_ok() {
+            let _ = cx.update_global::<SystemTray, _>(|tray, cx| {
+                tray.toggle_menu_from_shortcut(cx);
+            });
+        }
+    })
+    .detach();
+}
+
+// ============================================================================
+// macOS (Carbon)
+// ============================================================================
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::ffi::c_void;
+
+    use smol::channel::Sender;
+
+    use super::Hotkey;
+
+    type OSStatus = i32;
+    type EventTargetRef = *mut c_void;
+    type EventHandlerRef = *mut c_void;
+    type EventHandlerCallRef = *mut c_void;
+    type EventRef = *mut c_void;
+    type EventHotKeyRef = *mut c_void;
+    type EventHandlerUPP = extern "C" fn(EventHandlerCallRef, EventRef, *mut c_void) -> OSStatus;
+
+    #[repr(C)]
+    struct EventTypeSpec {
+        event_class: u32,
+        event_kind: u32,
+    }
+
+    #[repr(C)]
+    struct EventHotKeyID {
+        signature: u32,
+        id: u32,
+    }
+
+    const NO_ERR: OSStatus = 0;
+    const K_EVENT_CLASS_KEYBOARD: u32 = u32::from_be_bytes(*b"keyb");
+    const K_EVENT_HOT_KEY_PRESSED: u32 = 5;
+    /// Identifies our hot key among the app's ("exbr").
+    const HOT_KEY_SIGNATURE: u32 = u32::from_be_bytes(*b"exbr");
+
+    // Carbon modifier masks
+    const CMD_KEY: u32 = 1 << 8;
+    const SHIFT_KEY: u32 = 1 << 9;
+    const OPTION_KEY: u32 = 1 << 11;
+    const CONTROL_KEY: u32 = 1 << 12;
+
+    #[link(name = "Carbon", kind = "framework")]
+    unsafe extern "C" {
+        fn GetApplicationEventTarget() -> EventTargetRef;
+        fn InstallEventHandler(
+            target: EventTargetRef,
+            handler: EventHandlerUPP,
+            num_types: u32,
+            list: *const EventTypeSpec,
+            user_data: *mut c_void,
+            out_ref: *mut EventHandlerRef,
+        ) -> OSStatus;
+        fn RegisterEventHotKey(
+            key_code: u32,
+            modifiers: u32,
+            id: EventHotKeyID,
+            target: EventTargetRef,
+            options: u32,
+            out_ref: *mut EventHotKeyRef,
+        ) -> OSStatus;
+        fn UnregisterEventHotKey(hot_key: EventHotKeyRef) -> OSStatus;
+    }
+
+    extern "C" fn hot_key_pressed(
+        _next: EventHandlerCallRef,
+        _event: EventRef,
+        user_data: *mut c_void,
+    ) -> OSStatus {
+        // SAFETY: `user_data` is the sender leaked in `Registrar::new`
+        let sender = unsafe { &*(user_data as *const Sender<()>) };
+        let _ = sender.try_send(());
+        NO_ERR
+    }
+
+    /// Registers the shortcut with Carbon, which works without the
+    /// accessibility permission a CGEvent tap would need.
+    pub struct Registrar {
+        hot_key: Option<EventHotKeyRef>,
+    }
+
+    impl Registrar {
+        pub fn new(sender: Sender<()>) -> Option<Self> {
+            let spec = EventTypeSpec {
+                event_class: K_EVENT_CLASS_KEYBOARD,
+                event_kind: K_EVENT_HOT_KEY_PRESSED,
+            };
+            // Lives as long as the app, like the handler
+            let user_data = Box::into_raw(Box::new(sender)).cast::<c_void>();
+            let mut handler: EventHandlerRef = std::ptr::null_mut();
+            let status = unsafe {
+                InstallEventHandler(
+                    GetApplicationEventTarget(),
+                    hot_key_pressed,
+                    1,
+                    &spec,
+                    user_data,
+                    &mut handler,
+                )
+            };
+            if status != NO_ERR {
+                tracing::warn!(status, "Failed to install the global shortcut handler");
+                return None;
+            }
+            Some(Self { hot_key: None })
+        }
+
+        pub fn register(&mut self, hotkey: Hotkey) -> Result<(), String> {
+            self.unregister();
+            let key_code = virtual_key_code(hotkey.key)
+                .ok_or_else(|| format!("no key code for `{}`", hotkey.key))?;
+            let modifiers = [
+                (hotkey.control, CONTROL_KEY),
+                (hotkey.alt, OPTION_KEY),
+                (hotkey.shift, SHIFT_KEY),
+                (hotkey.command, CMD_KEY),
+            ]
+            .into_iter()
+            .filter(|&(held, _)| held)
+            .fold(0, |mask, (_, bit)| mask | bit);
+
+            let id = EventHotKeyID {
+                signature: HOT_KEY_SIGNATURE,
+                id: 1,
+            };
+            let mut hot_key: EventHotKeyRef = std::ptr::null_mut();
+            let status = unsafe {
+                RegisterEventHotKey(
+                    key_code,
+                    modifiers,
+                    id,
+                    GetApplicationEventTarget(),
+                    0,
+                    &mut hot_key,
+                )
+            };
+            if status != NO_ERR {
+                // -9878 (eventHotKeyExistsErr): another app already uses it
+                return Err(format!("RegisterEventHotKey failed ({status})"));
+            }
+            self.hot_key = Some(hot_key);
+            Ok(())
+        }
+
+        pub fn unregister(&mut self) {
+            if let Some(hot_key) = self.hot_key.take() {
+                unsafe {
+                    UnregisterEventHotKey(hot_key);
+                }
+            }
+        }
+    }
+
+    /// Returns the ANSI keyboard's virtual key code for a key (kVK_ANSI_*).
+    fn virtual_key_code(key: char) -> Option<u32> {
+        let code = match key {
+            'a' => 0x00,
+            's' => 0x01,
+            'd' => 0x02,
+            'f' => 0x03,
+            'h' => 0x04,
+            'g' => 0x05,
+            'z' => 0x06,
+            'x' => 0x07,
+            'c' => 0x08,
+            'v' => 0x09,
+            'b' => 0x0B,
+            'q' => 0x0C,
+            'w' => 0x0D,
+            'e' => 0x0E,
+            'r' => 0x0F,
+            'y' => 0x10,
+            't' => 0x11,
+            '1' => 0x12,
+            '2' => 0x13,
+            '3' => 0x14,
+            '4' => 0x15,
+            '6' => 0x16,
+            '5' => 0x17,
+            '9' => 0x19,
+            '7' => 0x1A,
+            '8' => 0x1C,
+            '0' => 0x1D,
+            'o' => 0x1F,
+            'u' => 0x20,
+            'i' => 0x22,
+            'p' => 0x23,
+            'l' => 0x25,
+            'j' => 0x26,
+            'k' => 0x28,
+            'n' => 0x2D,
+            'm' => 0x2E,
+            ' ' => 0x31,
+            _ => return None,
+        };
+        Some(code)
+    }
+}
+
+// ============================================================================
+// Linux (X11)
+// ============================================================================
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use smol::channel::Sender;
+    use tracing::{debug, info};
+    use x11rb::connection::Connection;
+    use x11rb::protocol::Event;
+    use x11rb::protocol::xproto::{ConnectionExt as _, GrabMode, Keycode, ModMask, Window};
+    use x11rb::rust_connection::RustConnection;
+
+    use super::Hotkey;
+
+    /// Held keys repeat; presses closer together than this are one press.
+    const REPEAT_INTERVAL: Duration = Duration::from_millis(300);
+
+    /// Grabs the shortcut on the X11 root window.
+    pub struct Registrar {
+        conn: Arc<RustConnection>,
+        root: Window,
+        grabbed: Option<(Keycode, ModMask)>,
+    }
+
+    impl Registrar {
+        pub fn new(sender: Sender<()>) -> Option<Self> {
+            if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+                info!(
+                    "Wayland session: the global shortcut only works while an X11 app is focused; \
+                     bind the exactobar-app command in your keyboard settings instead"
+                );
+            }
+            let (conn, screen) = match x11rb::connect(None) {
+                Ok(connected) => connected,
+                Err(e) => {
+                    info!(error = %e, "No X11 display, global shortcut unavailable");
+                    return None;
+                }
+            };
+            let conn = Arc::new(conn);
+            let root = conn.setup().roots[screen].root;
+
+            // Only our grabs deliver key presses on this connection
+            let events = Arc::clone(&conn);
+            std::thread::spawn(move || {
+                let mut last_press: Option<Instant> = None;
+                while let Ok(event) = events.wait_for_event() {
+                    if let Event::KeyPress(_) = event {
+                        if last_press.is_some_and(|at| at.elapsed() < REPEAT_INTERVAL) {
+                            continue;
+                        }
+                        last_press = Some(Instant::now());
+                        if sender.send_blocking(()).is_err() {
+                            break;
+                        }
+                    }
+                }
+                debug!("X11 shortcut listener stopped");
+            });
+
+            Some(Self {
+                conn,
+                root,
+                grabbed: None,
+            })
+        }
+
+        pub fn register(&mut self, hotkey: Hotkey) -> Result<(), String> {
+            self.unregister();
+            let keycode = self
+                .keycode(keysym(hotkey.key))
+                .ok_or_else(|| format!("no key code for `{}`", hotkey.key))?;
+            let modifiers = [
+                (hotkey.control, ModMask::CONTROL),
+                (hotkey.alt, ModMask::M1),
+                (hotkey.shift, ModMask::SHIFT),
+                (hotkey.command, ModMask::M4),
+            ]
+            .into_iter()
+            .filter(|&(held, _)| held)
+            .fold(ModMask::from(0u16), |mask, (_, bit)| mask | bit);
+
+            for variant in lock_variants(modifiers) {
+                self.conn
+                    .grab_key(
+                        false,
+                        self.root,
+                        variant,
+                        keycode,
+                        GrabMode::ASYNC,
+                        GrabMode::ASYNC,
+                    )
+                    .map_err(|e| e.to_string())?
+                    .check()
+                    // BadAccess: another client already grabbed it
+                    .map_err(|e| e.to_string())?;
+            }
+            self.grabbed = Some((keycode, modifiers));
+            Ok(())
+        }
+
+        pub fn unregister(&mut self) {
+            let Some((keycode, modifiers)) = self.grabbed.take() else {
+                return;
+            };
+            for variant in lock_variants(modifiers) {
+                let _ = self.conn.ungrab_key(keycode, self.root, variant);
+            }
+            let _ = self.conn.flush();
+        }
+
+        /// Finds the key code that types `keysym` in the current layout.
+        fn keycode(&self, keysym: u32) -> Option<Keycode> {
+            let setup = self.conn.setup();
+            let (min, max) = (setup.min_keycode, setup.max_keycode);
+            let mapping = self
+                .conn
+                .get_keyboard_mapping(min, max - min + 1)
+                .ok()?
+                .reply()
+                .ok()?;
+            let per_keycode = usize::from(mapping.keysyms_per_keycode);
+            let index = mapping
+                .keysyms
+                .chunks(per_keycode.max(1))
+                .position(|keysyms| keysyms.contains(&keysym))?;
+            Some(min + u8::try_from(index).ok()?)
+        }
+    }
+
+    /// Returns the keysym of a letter, digit or space, which match ASCII.
+    fn keysym(key: char) -> u32 {
+        u32::from(key)
+    }
+
+    /// Returns `modifiers` with every combination of Caps Lock and Num Lock,
+    /// so the shortcut works whichever is on.
+    fn lock_variants(modifiers: ModMask) -> [ModMask; 4] {
+        [
+            modifiers,
+            modifiers | ModMask::LOCK,
+            modifiers | ModMask::M2,
+            modifiers | ModMask::LOCK | ModMask::M2,
+        ]
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+mod platform {
+    use smol::channel::Sender;
+
+    use super::Hotkey;
+
+    pub struct Registrar;
+
+    impl Registrar {
+        pub fn new(_sender: Sender<()>) -> Option<Self> {
+            None
+        }
+
+        pub fn register(&mut self, _hotkey: Hotkey) -> Result<(), String> {
+            Err("global shortcuts aren't supported on this platform".to_string())
+        }
+
+        pub fn unregister(&mut self) {}
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hotkey() {
+        let hotkey = Hotkey::parse("alt+shift+e").unwrap();
+        assert!(hotkey.alt && hotkey.shift && !hotkey.control && !hotkey.command);
+        assert_eq!(hotkey.key, 'e');
+
+        let hotkey = Hotkey::parse(" Ctrl + Option + Space ").unwrap();
+        assert!(hotkey.control && hotkey.alt);
+        assert_eq!(hotkey.key, ' ');
+
+        assert_eq!(Hotkey::parse("cmd+1").unwrap().key, '1');
+        assert_eq!(
+            Hotkey::parse("super+E").unwrap(),
+            Hotkey::parse("command+e").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_hotkey_errors() {
+        assert!(Hotkey::parse("").is_err());
+        assert!(Hotkey::parse("e").is_err());
+        // Shift alone would swallow capital letters
+        assert!(Hotkey::parse("shift+e").is_err());
+        assert!(
+            Hotkey::parse("alt+f1")
+                .unwrap_err()
+                .contains("unsupported key `f1`")
+        );
+        assert!(
+            Hotkey::parse("hyper+e")
+                .unwrap_err()
+                .contains("unknown modifier `hyper`")
+        );
+    }
+
+    #[test]
+    fn test_display_hotkey() {
+        let hotkey = Hotkey::parse("shift+alt+e").unwrap();
+        let space = Hotkey::parse("ctrl+cmd+space").unwrap();
+        if cfg!(target_os = "macos") {
+            assert_eq!(hotkey.to_string(), "⌥⇧E");
+            assert_eq!(space.to_string(), "⌃⌘Space");
+        } else {
+            assert_eq!(hotkey.to_string(), "Alt+Shift+E");
+            assert_eq!(space.to_string(), "Ctrl+Super+Space");
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Cache of rendered menu bar icons.
+//!
+//! The menu bar icon is redrawn on every refresh and animation tick, but most
+//! redraws produce a frame that was already drawn. An [`IconKey`] holds
+//! everything that affects a frame, quantized to what is visible at icon
+//! size, so each distinct frame is rasterized and PNG-encoded once.
+
+use std::f64::consts::TAU;
+use std::rc::Rc;
+
+use exactobar_core::{ProviderKind, StatusIndicator, UsageSnapshot, UsageWindow};
+use once_cell::unsync::OnceCell;
+
+use super::{IconAnimationState, IconRenderer, RenderedIcon};
+
+/// Frames in one cycle of the loading animation.
+const LOADING_STEPS: f64 = 64.0;
+
+/// Steps between an open and a closed eye in the blink animation.
+const BLINK_STEPS: f32 = 16.0;
+
+/// Cached icons kept before the cache is cleared.
+const MAX_CACHED_ICONS: usize = 256;
+
+/// Identifies a menu bar icon frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IconKey {
+    pub provider: ProviderKind,
+    pub frame: IconFrame,
+}
+
+/// What an icon shows, quantized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IconFrame {
+    /// Usage meters; percentages are rounded to whole percents.
+    Usage {
+        primary: Option<u8>,
+        secondary: Option<u8>,
+        stale: bool,
+        status: StatusIndicator,
+        /// Blink step, 0 (open) to [`BLINK_STEPS`] (closed).
+        blink: u8,
+    },
+    /// A step of the loading animation.
+    Loading {
+        step: u8,
+    },
+    Error,
+    /// The provider rejected its credentials.
+    AuthRequired,
+}
+
+impl IconKey {
+    /// Key for the usage icon drawn by [`IconRenderer::render`].
+    pub fn usage(
+        provider: ProviderKind,
+        snapshot: Option<&UsageSnapshot>,
+        stale: bool,
+        status: Option<StatusIndicator>,
+        animation: Option<&IconAnimationState>,
+    ) -> Self {
+        let percent =
+            |w: Option<&UsageWindow>| w.map(|w| w.used_percent.clamp(0.0, 100.0).round() as u8);
+        // The Codex eye ignores the weekly window and is the only icon that blinks
+        let is_codex = provider == ProviderKind::Codex;
+        let secondary = if is_codex {
+            None
+        } else {
+            percent(snapshot.and_then(|s| s.secondary.as_ref()))
+        };
+        let blink = if is_codex {
+            animation.map_or(0, |a| {
+                (a.blink_phase.clamp(0.0, 1.0) * BLINK_STEPS).round() as u8
+            })
+        } else {
+            0
+        };
+        // Only incidents get a status dot
+        let status = match status {
+            Some(
+                s @ (StatusIndicator::Minor | StatusIndicator::Major | StatusIndicator::Critical),
+            ) => s,
+            _ => StatusIndicator::None,
+        };
+
+        Self {
+            provider,
+            frame: IconFrame::Usage {
+                primary: percent(snapshot.and_then(|s| s.primary.as_ref())),
+                secondary,
+                stale,
+                status,
+                blink,
+            },
+        }
+    }
+
+    /// Key for the loading animation at `phase` (radians).
+    pub fn loading(provider: ProviderKind, phase: f64) -> Self {
+        let step = (phase.rem_euclid(TAU) / TAU * LOADING_STEPS).round() % LOADING_STEPS;
+        Self {
+            provider,
+            frame: IconFrame::Loading { step: step as u8 },
+        }
+    }
+
+    /// Key for the error icon.
+    pub fn error(provider: ProviderKind) -> Self {
+        Self {
+            provider,
+            frame: IconFrame::Error,
+        }
+    }
+
+    /// Key for the icon of a provider that needs to sign in again.
+    pub fn auth_required(provider: ProviderKind) -> Self {
+        Self {
+            provider,
+            frame: IconFrame::AuthRequired,
+        }
+    }
+}
+
+/// A rendered icon, with its PNG encoding made on first use.
+pub struct CachedIcon {
+    pub rendered: RenderedIcon,
+    png: OnceCell<Vec<u8>>,
+}
+
+impl CachedIcon {
+    /// Returns the icon as PNG bytes.
+    pub fn png(&self) -> &[u8] {
+        self.png.get_or_init(|| self.rendered.to_png())
+    }
+}
+
+impl IconRenderer {
+    /// Returns the icon for `key`, rendering it only the first time.
+    pub fn render_cached(&mut self, key: IconKey) -> Rc<CachedIcon> {
+        if let Some(icon) = self.cache.get(&key) {
+            return icon.clone();
+        }
+
+        if self.cache.len() >= MAX_CACHED_ICONS {
+            self.cache.clear();
+        }
+        let icon = Rc::new(CachedIcon {
+            rendered: self.render_key(&key),
+            png: OnceCell::new(),
+        });
+        self.cache.insert(key, icon.clone());
+        icon
+    }
+
+    /// Renders a frame from its quantized key, so equal keys draw equal icons.
+    fn render_key(&self, key: &IconKey) -> RenderedIcon {
+        match key.frame {
+            IconFrame::Usage {
+                primary,
+                secondary,
+                stale,
+                status,
+                blink,
+            } => {
+                let mut snapshot = UsageSnapshot::new();
+                snapshot.primary = primary.map(|p| UsageWindow::new(f64::from(p)));
+                snapshot.secondary = secondary.map(|p| UsageWindow::new(f64::from(p)));
+                let animation = IconAnimationState::with_blink(f32::from(blink) / BLINK_STEPS);
+                self.render(
+                    key.provider,
+                    Some(&snapshot),
+                    stale,
+                    Some(status),
+                    Some(&animation),
+                )
+            }
+            IconFrame::Loading { step } => {
+                self.render_loading(key.provider, f64::from(step) / LOADING_STEPS * TAU)
+            }
+            IconFrame::Error => self.render_error(key.provider),
+            IconFrame::AuthRequired => self.render_auth_required(key.provider),
+        }
+    }
+}
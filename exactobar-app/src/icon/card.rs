@@ -0,0 +1,403 @@
+//! Shareable usage card images.
+//!
+//! Rasterizes one or more providers' usage meters into a standalone
+//! image (no window chrome) with the same bar drawing used for the
+//! menu bar icon. tiny-skia has no text support, so provider names,
+//! window labels, reset times and percentages are drawn with a small
+//! built-in bitmap font.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use exactobar_core::{ProviderKind, UsageSnapshot};
+use exactobar_providers::ProviderRegistry;
+use tiny_skia::*;
+
+use super::colors::{IconColors, create_paint};
+use super::{IconRenderer, RenderMode, RenderedIcon};
+use crate::menu::reset_phrase;
+
+/// Card layout dimensions (pixels, drawn at 2x).
+const CARD_WIDTH: u32 = 560;
+const CARD_PADDING: f32 = 32.0;
+const CARD_RADIUS: f32 = 24.0;
+const SECTION_SPACING: f32 = 28.0;
+const HEADER_HEIGHT: f32 = 24.0;
+const BRAND_DOT_RADIUS: f32 = 8.0;
+const ROW_HEIGHT: f32 = 56.0;
+const CARD_BAR_HEIGHT: f32 = 14.0;
+
+/// Bitmap glyph dimensions (5x7 cells scaled up).
+const GLYPH_COLUMNS: usize = 5;
+const GLYPH_ROWS: usize = 7;
+/// Scale of provider names and percentages.
+const GLYPH_SCALE: f32 = 3.0;
+/// Scale of the caption above each bar.
+const CAPTION_SCALE: f32 = 2.0;
+/// Width reserved for the "100%" label to the right of each bar.
+const LABEL_WIDTH: f32 = 4.0 * (GLYPH_COLUMNS as f32 + 1.0) * GLYPH_SCALE + 12.0;
+
+/// Text colors.
+const TEXT_COLOR: (u8, u8, u8) = (235, 235, 240);
+const CAPTION_COLOR: (u8, u8, u8) = (150, 150, 158);
+
+/// One usage window in a usage card.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardWindow {
+    /// Window name, e.g. "Session".
+    pub label: String,
+    pub used_percent: f64,
+    /// When the window resets, e.g. "resets in 2h 5m".
+    pub reset: Option<String>,
+}
+
+impl CardWindow {
+    /// Text drawn above the bar.
+    fn caption(&self) -> String {
+        match &self.reset {
+            Some(reset) => format!("{} · {}", self.label, reset),
+            None => self.label.clone(),
+        }
+    }
+}
+
+/// One provider's section in a usage card.
+#[derive(Debug, Clone)]
+pub struct UsageCardEntry {
+    pub provider: ProviderKind,
+    /// Provider name shown in the header.
+    pub name: String,
+    /// Each reported window, in display order.
+    pub windows: Vec<CardWindow>,
+    /// Whether the data is stale (bars are drawn faded).
+    pub stale: bool,
+}
+
+impl UsageCardEntry {
+    /// Builds an entry from a provider's latest snapshot.
+    pub fn from_snapshot(
+        provider: ProviderKind,
+        snapshot: Option<&UsageSnapshot>,
+        stale: bool,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let descriptor = ProviderRegistry::get(provider);
+        let name = descriptor
+            .map(|d| d.display_name().to_string())
+            .unwrap_or_else(|| format!("{:?}", provider));
+        let session = descriptor
+            .map(|d| d.metadata.session_label.as_str())
+            .unwrap_or("Session");
+        let weekly = descriptor
+            .map(|d| d.metadata.weekly_label.as_str())
+            .unwrap_or("Weekly");
+
+        let windows = snapshot
+            .map(|s| {
+                [
+                    (session, &s.primary),
+                    (weekly, &s.secondary),
+                    ("Premium", &s.tertiary),
+                    ("Search", &s.search),
+                ]
+                .into_iter()
+                .filter_map(|(label, window)| {
+                    window.as_ref().map(|w| CardWindow {
+                        label: label.to_string(),
+                        used_percent: w.used_percent,
+                        reset: reset_phrase(w, now),
+                    })
+                })
+                .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            provider,
+            name,
+            windows,
+            stale,
+        }
+    }
+
+    fn height(&self) -> f32 {
+        HEADER_HEIGHT + ROW_HEIGHT * self.windows.len().max(1) as f32
+    }
+}
+
+impl IconRenderer {
+    /// Renders a shareable usage card for the given providers, with any
+    /// brand colors the user picked.
+    pub fn render_usage_card(
+        entries: &[UsageCardEntry],
+        brand_colors: HashMap<ProviderKind, Color>,
+    ) -> RenderedIcon {
+        let sections: f32 = entries.iter().map(UsageCardEntry::height).sum();
+        let spacing = SECTION_SPACING * entries.len().saturating_sub(1) as f32;
+        let height = (CARD_PADDING * 2.0 + sections + spacing).ceil() as u32;
+
+        let renderer = IconRenderer::with_size(CARD_WIDTH, height)
+            .with_mode(RenderMode::Colored)
+            .with_brand_colors(brand_colors);
+        let mut pixmap = Pixmap::new(renderer.width, renderer.height).unwrap();
+        pixmap.fill(Color::TRANSPARENT);
+
+        // Card background
+        let background = renderer.rounded_rect_path(
+            0.0,
+            0.0,
+            renderer.width as f32,
+            renderer.height as f32,
+            CARD_RADIUS,
+        );
+        pixmap.fill_path(
+            &background,
+            &create_paint(Color::from_rgba8(28, 28, 30, 245)),
+            FillRule::Winding,
+            Transform::identity(),
+            None,
+        );
+
+        let mut y = CARD_PADDING;
+        for entry in entries {
+            renderer.draw_card_section(&mut pixmap, entry, y);
+            y += entry.height() + SECTION_SPACING;
+        }
+
+        RenderedIcon {
+            data: pixmap.data().to_vec(),
+            width: renderer.width,
+            height: renderer.height,
+        }
+    }
+
+    fn draw_card_section(&self, pixmap: &mut Pixmap, entry: &UsageCardEntry, top: f32) {
+        let brand = self.brand_color(entry.provider);
+        let colors = IconColors::colored(brand, entry.stale);
+        let content_width = self.width as f32 - CARD_PADDING * 2.0;
+        let right = CARD_PADDING + content_width;
+        let text_color = rgb(TEXT_COLOR);
+        let caption_color = rgb(CAPTION_COLOR);
+
+        // Header: brand dot, provider name, then a divider
+        let center_y = top + HEADER_HEIGHT / 2.0;
+        let mut pb = PathBuilder::new();
+        pb.push_circle(CARD_PADDING + BRAND_DOT_RADIUS, center_y, BRAND_DOT_RADIUS);
+        if let Some(path) = pb.finish() {
+            pixmap.fill_path(
+                &path,
+                &create_paint(brand),
+                FillRule::Winding,
+                Transform::identity(),
+                None,
+            );
+        }
+        let name_x = CARD_PADDING + BRAND_DOT_RADIUS * 2.0 + 12.0;
+        let name = fit_text(&entry.name, right - name_x, GLYPH_SCALE);
+        draw_text(
+            pixmap,
+            &name,
+            name_x,
+            center_y - text_height(GLYPH_SCALE) / 2.0,
+            GLYPH_SCALE,
+            text_color,
+        );
+        let rule_x = name_x + text_width(&name, GLYPH_SCALE) + 12.0;
+        if let Some(rect) = Rect::from_xywh(rule_x, center_y - 1.0, right - rule_x, 2.0) {
+            pixmap.fill_rect(
+                rect,
+                &create_paint(colors.track),
+                Transform::identity(),
+                None,
+            );
+        }
+
+        // One row per usage window: a caption with the window's name and
+        // reset, then the bar with its percentage on the right
+        let bar_width = content_width - LABEL_WIDTH;
+        let caption_top = |row_top: f32| row_top + 8.0;
+        let bar_top = |row_top: f32| {
+            let area = caption_top(row_top) + text_height(CAPTION_SCALE);
+            area + (row_top + ROW_HEIGHT - area - CARD_BAR_HEIGHT) / 2.0
+        };
+
+        let mut row_top = top + HEADER_HEIGHT;
+        if entry.windows.is_empty() {
+            draw_text(
+                pixmap,
+                "No data yet",
+                CARD_PADDING,
+                caption_top(row_top),
+                CAPTION_SCALE,
+                caption_color,
+            );
+            self.draw_empty_bar(
+                pixmap,
+                CARD_PADDING,
+                bar_top(row_top),
+                bar_width,
+                CARD_BAR_HEIGHT,
+                &colors,
+            );
+            return;
+        }
+
+        for window in &entry.windows {
+            let caption = fit_text(&window.caption(), content_width, CAPTION_SCALE);
+            draw_text(
+                pixmap,
+                &caption,
+                CARD_PADDING,
+                caption_top(row_top),
+                CAPTION_SCALE,
+                caption_color,
+            );
+
+            let percent = window.used_percent.clamp(0.0, 100.0) as f32;
+            let bar_y = bar_top(row_top);
+            self.draw_bar(
+                pixmap,
+                CARD_PADDING,
+                bar_y,
+                bar_width,
+                CARD_BAR_HEIGHT,
+                percent,
+                &colors,
+                entry.stale,
+            );
+
+            let label = format!("{:.0}%", percent);
+            draw_text(
+                pixmap,
+                &label,
+                right - text_width(&label, GLYPH_SCALE),
+                bar_y + (CARD_BAR_HEIGHT - text_height(GLYPH_SCALE)) / 2.0,
+                GLYPH_SCALE,
+                text_color,
+            );
+
+            row_top += ROW_HEIGHT;
+        }
+    }
+}
+
+fn rgb((r, g, b): (u8, u8, u8)) -> Color {
+    Color::from_rgba8(r, g, b, 255)
+}
+
+// ============================================================================
+// Bitmap Text
+// ============================================================================
+
+/// Returns the 5x7 bitmap for a glyph (one byte per row, low 5 bits used).
+/// Letters are uppercase only; [`draw_text`] uppercases its text.
+pub(super) fn glyph(c: char) -> Option<[u8; GLYPH_ROWS]> {
+    Some(match c {
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        'A' => [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04],
+        'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+        '%' => [0x18, 0x19, 0x02, 0x04, 0x08, 0x13, 0x03],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+        ',' => [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08],
+        ':' => [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00],
+        '·' => [0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00, 0x00],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        '+' => [0x00, 0x04, 0x04, 0x1F, 0x04, 0x04, 0x00],
+        '/' => [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00],
+        '(' => [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02],
+        ')' => [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08],
+        '~' => [0x00, 0x00, 0x08, 0x15, 0x02, 0x00, 0x00],
+        '$' => [0x04, 0x0F, 0x14, 0x0E, 0x05, 0x1E, 0x04],
+        '\'' => [0x0C, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00],
+        ' ' => [0x00; GLYPH_ROWS],
+        _ => return None,
+    })
+}
+
+/// Horizontal distance between glyphs: the glyph plus one cell of space.
+fn advance(scale: f32) -> f32 {
+    (GLYPH_COLUMNS as f32 + 1.0) * scale
+}
+
+/// Height of a line drawn with [`draw_text`].
+fn text_height(scale: f32) -> f32 {
+    GLYPH_ROWS as f32 * scale
+}
+
+/// Width of `text` when drawn with [`draw_text`].
+fn text_width(text: &str, scale: f32) -> f32 {
+    let count = text.chars().flat_map(char::to_uppercase).count() as f32;
+    if count == 0.0 {
+        return 0.0;
+    }
+    count * advance(scale) - scale
+}
+
+/// Shortens `text` with a trailing "..." so it fits in `max_width`.
+fn fit_text(text: &str, max_width: f32, scale: f32) -> String {
+    if text_width(text, scale) <= max_width {
+        return text.to_string();
+    }
+    let fits = ((max_width + scale) / advance(scale)) as usize;
+    let kept: String = text.chars().take(fits.saturating_sub(3)).collect();
+    format!("{}...", kept.trim_end())
+}
+
+/// Draws `text` with the bitmap font, uppercased; unsupported characters
+/// are left blank.
+fn draw_text(pixmap: &mut Pixmap, text: &str, x: f32, y: f32, scale: f32, color: Color) {
+    let paint = create_paint(color);
+
+    for (i, c) in text.chars().flat_map(char::to_uppercase).enumerate() {
+        let Some(rows) = glyph(c) else { continue };
+        let glyph_x = x + i as f32 * advance(scale);
+        for (row, bits) in rows.iter().enumerate() {
+            for col in 0..GLYPH_COLUMNS {
+                if bits & (1 << (GLYPH_COLUMNS - 1 - col)) == 0 {
+                    continue;
+                }
+                if let Some(rect) = Rect::from_xywh(
+                    glyph_x + col as f32 * scale,
+                    y + row as f32 * scale,
+                    scale,
+                    scale,
+                ) {
+                    pixmap.fill_rect(rect, &paint, Transform::identity(), None);
+                }
+            }
+        }
+    }
+}
@@ -59,7 +59,7 @@ impl IconRenderer {
                 &eye_path,
                 &bg_paint,
                 FillRule::Winding,
-                Transform::identity(),
+                self.transform(),
                 None,
             );
 
@@ -146,16 +146,10 @@ impl IconRenderer {
         }
 
         // Create eye mask for clipping
-        let mut mask_pixmap = Pixmap::new(self.width, self.height).unwrap();
+        let mut mask_pixmap = self.new_pixmap();
         let eye_path = self.create_eye_path(cx, cy, half_w, half_h);
         let white = create_paint(Color::WHITE);
-        mask_pixmap.fill_path(
-            &eye_path,
-            &white,
-            FillRule::Winding,
-            Transform::identity(),
-            None,
-        );
+        mask_pixmap.fill_path(&eye_path, &white, FillRule::Winding, self.transform(), None);
 
         // Determine fill color based on percentage and stale state
         let fill_color = if stale {
@@ -168,13 +162,14 @@ impl IconRenderer {
         let fill_paint = create_paint(fill_color);
 
         // Create a temporary pixmap for the fill
-        let mut fill_pixmap = Pixmap::new(self.width, self.height).unwrap();
-        fill_pixmap.fill_rect(fill_rect.unwrap(), &fill_paint, Transform::identity(), None);
+        let mut fill_pixmap = self.new_pixmap();
+        fill_pixmap.fill_rect(fill_rect.unwrap(), &fill_paint, self.transform(), None);
 
         // Apply mask: multiply fill by eye shape
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let idx = (y * self.width + x) as usize * 4;
+        let width = pixmap.width();
+        for y in 0..pixmap.height() {
+            for x in 0..width {
+                let idx = (y * width + x) as usize * 4;
                 let mask_alpha = mask_pixmap.data()[idx + 3] as f32 / 255.0;
 
                 if mask_alpha > 0.0 {
@@ -189,6 +184,7 @@ impl IconRenderer {
                         let color = Color::from_rgba8(fill_r, fill_g, fill_b, src_a);
                         let mut paint = Paint::default();
                         paint.set_color(color);
+                        // Already in output pixels
                         if let Some(rect) = Rect::from_xywh(x as f32, y as f32, 1.0, 1.0) {
                             pixmap.fill_rect(rect, &paint, Transform::identity(), None);
                         }
@@ -223,7 +219,7 @@ impl IconRenderer {
                 &iris_path,
                 &paint,
                 FillRule::Winding,
-                Transform::identity(),
+                self.transform(),
                 None,
             );
         }
@@ -238,7 +234,7 @@ impl IconRenderer {
                 &pupil_path,
                 &paint,
                 FillRule::Winding,
-                Transform::identity(),
+                self.transform(),
                 None,
             );
         }
@@ -253,7 +249,7 @@ impl IconRenderer {
                 &highlight_path,
                 &paint,
                 FillRule::Winding,
-                Transform::identity(),
+                self.transform(),
                 None,
             );
         }
@@ -282,7 +278,7 @@ impl IconRenderer {
         let outline_color = colors.good;
         let paint = create_paint(outline_color);
 
-        pixmap.stroke_path(&eye_path, &paint, &stroke, Transform::identity(), None);
+        pixmap.stroke_path(&eye_path, &paint, &stroke, self.transform(), None);
     }
 
     /// Draws a horizontal line for a fully closed eye.
@@ -306,7 +302,7 @@ impl IconRenderer {
             };
 
             let paint = create_paint(colors.good);
-            pixmap.stroke_path(&line_path, &paint, &stroke, Transform::identity(), None);
+            pixmap.stroke_path(&line_path, &paint, &stroke, self.transform(), None);
         }
     }
 }
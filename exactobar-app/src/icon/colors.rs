@@ -31,9 +31,8 @@ impl IconColors {
         }
     }
 
-    /// Colored mode with provider brand colors.
-    pub fn colored(provider: ProviderKind, stale: bool) -> Self {
-        let brand = provider_brand_color(provider);
+    /// Colored mode with a provider's brand color.
+    pub fn colored(brand: Color, stale: bool) -> Self {
         let alpha_mult = if stale { 0.7 } else { 1.0 };
 
         Self {
@@ -63,6 +62,7 @@ pub fn provider_brand_color(provider: ProviderKind) -> Color {
         ProviderKind::MiniMax => Color::from_rgba8(0, 191, 255, 255), // Deep sky blue
         ProviderKind::Antigravity => Color::from_rgba8(148, 0, 211, 255), // Violet
         ProviderKind::Synthetic => Color::from_rgba8(0, 204, 179, 255), // Teal/cyan
+        ProviderKind::Demo => Color::from_rgba8(140, 140, 153, 255), // Slate
     }
 }
 
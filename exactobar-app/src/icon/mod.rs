@@ -6,20 +6,29 @@
 //! # Module Structure
 //!
 //! - [`animation`] - Animation state for provider icons
+//! - [`cache`] - Cache of rendered icon frames
+//! - [`card`] - Shareable usage card images
 //! - [`colors`] - Color management and palettes
 //! - [`codex_eye`] - Codex-specific eye icon drawing
 //! - [`rendered`] - Rendered icon output struct
 
 mod animation;
+mod cache;
+mod card;
 mod codex_eye;
 mod colors;
 mod rendered;
 
 pub use animation::IconAnimationState;
+pub use cache::{CachedIcon, IconFrame, IconKey};
+pub use card::{CardWindow, UsageCardEntry};
 pub use rendered::RenderedIcon;
 
-use colors::{IconColors, create_paint};
+use colors::{IconColors, create_paint, provider_brand_color};
 use exactobar_core::{ProviderKind, StatusIndicator, UsageSnapshot};
+use exactobar_store::UsageGradient;
+use std::collections::HashMap;
+use std::rc::Rc;
 use tiny_skia::*;
 
 // ============================================================================
@@ -31,6 +40,10 @@ pub const ICON_SIZE: u32 = 36;
 pub const ICON_WIDTH: u32 = 36;
 pub const ICON_HEIGHT: u32 = 22;
 
+/// Backing scales the icon is rendered at for high-density displays,
+/// relative to its @2x layout: @1x, @2x and @3x.
+pub const BACKING_SCALES: [f32; 3] = [0.5, 1.0, 1.5];
+
 /// Usage bar dimensions.
 const BAR_WIDTH: f32 = 24.0;
 const BAR_HEIGHT_THICK: f32 = 6.0; // Session bar
@@ -65,9 +78,18 @@ pub enum RenderMode {
 
 /// Renders dynamic menu bar icons with usage meters.
 pub struct IconRenderer {
+    /// Layout size; every dimension is in @2x pixels.
     width: u32,
     height: u32,
+    /// Output pixels per layout pixel (see [`BACKING_SCALES`]).
+    scale: f32,
     mode: RenderMode,
+    /// Brand colors picked by the user, used instead of the built-in ones.
+    brand_colors: HashMap<ProviderKind, Color>,
+    /// Breakpoints between the good, warning and danger meter colors.
+    usage_gradient: UsageGradient,
+    /// Frames rendered by [`IconRenderer::render_cached`].
+    cache: HashMap<IconKey, Rc<CachedIcon>>,
 }
 
 impl Default for IconRenderer {
@@ -82,7 +104,11 @@ impl IconRenderer {
         Self {
             width: ICON_WIDTH,
             height: ICON_HEIGHT,
+            scale: 1.0,
             mode: RenderMode::Template,
+            brand_colors: HashMap::new(),
+            usage_gradient: UsageGradient::default(),
+            cache: HashMap::new(),
         }
     }
 
@@ -91,16 +117,61 @@ impl IconRenderer {
         Self {
             width,
             height,
+            scale: 1.0,
             mode: RenderMode::Template,
+            brand_colors: HashMap::new(),
+            usage_gradient: UsageGradient::default(),
+            cache: HashMap::new(),
         }
     }
 
+    /// Renders at `scale` output pixels per layout pixel, e.g. 1.5 for a
+    /// @3x bitmap of the @2x layout.
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self.cache.clear();
+        self
+    }
+
     /// Sets the rendering mode.
     pub fn with_mode(mut self, mode: RenderMode) -> Self {
         self.mode = mode;
+        self.cache.clear();
+        self
+    }
+
+    /// Sets the brand colors used instead of the built-in ones.
+    pub fn with_brand_colors(mut self, colors: HashMap<ProviderKind, Color>) -> Self {
+        self.set_brand_colors(colors);
         self
     }
 
+    /// Replaces the brand color overrides, dropping cached frames drawn with
+    /// the old ones.
+    pub fn set_brand_colors(&mut self, colors: HashMap<ProviderKind, Color>) {
+        if self.brand_colors != colors {
+            self.brand_colors = colors;
+            self.cache.clear();
+        }
+    }
+
+    /// Replaces the usage color breakpoints, dropping cached frames drawn
+    /// with the old ones.
+    pub fn set_usage_gradient(&mut self, gradient: UsageGradient) {
+        if self.usage_gradient != gradient {
+            self.usage_gradient = gradient;
+            self.cache.clear();
+        }
+    }
+
+    /// Returns the color a provider is drawn with in colored mode.
+    pub fn brand_color(&self, provider: ProviderKind) -> Color {
+        self.brand_colors
+            .get(&provider)
+            .copied()
+            .unwrap_or_else(|| provider_brand_color(provider))
+    }
+
     /// Renders an icon for a provider's current usage.
     ///
     /// # Arguments
@@ -117,8 +188,7 @@ impl IconRenderer {
         status: Option<StatusIndicator>,
         animation: Option<&IconAnimationState>,
     ) -> RenderedIcon {
-        let mut pixmap = Pixmap::new(self.width, self.height).unwrap();
-        pixmap.fill(Color::TRANSPARENT);
+        let mut pixmap = self.new_pixmap();
 
         let colors = self.get_colors(provider, stale);
 
@@ -154,8 +224,8 @@ impl IconRenderer {
 
         RenderedIcon {
             data: pixmap.data().to_vec(),
-            width: self.width,
-            height: self.height,
+            width: pixmap.width(),
+            height: pixmap.height(),
         }
     }
 
@@ -166,8 +236,7 @@ impl IconRenderer {
         credits_remaining_percent: Option<f64>,
         stale: bool,
     ) -> RenderedIcon {
-        let mut pixmap = Pixmap::new(self.width, self.height).unwrap();
-        pixmap.fill(Color::TRANSPARENT);
+        let mut pixmap = self.new_pixmap();
 
         let colors = self.get_colors(provider, stale);
 
@@ -179,40 +248,65 @@ impl IconRenderer {
 
         RenderedIcon {
             data: pixmap.data().to_vec(),
-            width: self.width,
-            height: self.height,
+            width: pixmap.width(),
+            height: pixmap.height(),
         }
     }
 
     /// Renders a loading animation frame.
     pub fn render_loading(&self, provider: ProviderKind, phase: f64) -> RenderedIcon {
-        let mut pixmap = Pixmap::new(self.width, self.height).unwrap();
-        pixmap.fill(Color::TRANSPARENT);
+        let mut pixmap = self.new_pixmap();
 
         let colors = self.get_colors(provider, false);
         self.draw_loading_animation(&mut pixmap, phase, &colors);
 
         RenderedIcon {
             data: pixmap.data().to_vec(),
-            width: self.width,
-            height: self.height,
+            width: pixmap.width(),
+            height: pixmap.height(),
         }
     }
 
     /// Renders an error state icon.
     pub fn render_error(&self, provider: ProviderKind) -> RenderedIcon {
-        let mut pixmap = Pixmap::new(self.width, self.height).unwrap();
-        pixmap.fill(Color::TRANSPARENT);
+        let mut pixmap = self.new_pixmap();
 
         self.draw_error_indicator(&mut pixmap, provider);
 
         RenderedIcon {
             data: pixmap.data().to_vec(),
-            width: self.width,
-            height: self.height,
+            width: pixmap.width(),
+            height: pixmap.height(),
+        }
+    }
+
+    /// Renders the icon of a provider that needs to sign in again: empty
+    /// bars with a ring badge.
+    pub fn render_auth_required(&self, provider: ProviderKind) -> RenderedIcon {
+        let mut pixmap = self.new_pixmap();
+
+        let colors = self.get_colors(provider, true);
+        self.draw_placeholder(&mut pixmap, &colors);
+        self.draw_auth_badge(&mut pixmap);
+
+        RenderedIcon {
+            data: pixmap.data().to_vec(),
+            width: pixmap.width(),
+            height: pixmap.height(),
         }
     }
 
+    /// Creates a transparent canvas at the output scale.
+    fn new_pixmap(&self) -> Pixmap {
+        let scaled = |size: u32| ((size as f32 * self.scale).round() as u32).max(1);
+        Pixmap::new(scaled(self.width), scaled(self.height)).unwrap()
+    }
+
+    /// Maps layout pixels to output pixels.
+    fn transform(&self) -> Transform {
+        Transform::from_scale(self.scale, self.scale)
+    }
+
     // ========================================================================
     // Color Management
     // ========================================================================
@@ -220,7 +314,7 @@ impl IconRenderer {
     fn get_colors(&self, provider: ProviderKind, stale: bool) -> IconColors {
         match self.mode {
             RenderMode::Template => IconColors::template(stale),
-            RenderMode::Colored => IconColors::colored(provider, stale),
+            RenderMode::Colored => IconColors::colored(self.brand_color(provider), stale),
         }
     }
 
@@ -315,7 +409,7 @@ impl IconRenderer {
             &bg_path,
             &bg_paint,
             FillRule::Winding,
-            Transform::identity(),
+            self.transform(),
             None,
         );
 
@@ -334,7 +428,7 @@ impl IconRenderer {
                 &fill_path,
                 &fill_paint,
                 FillRule::Winding,
-                Transform::identity(),
+                self.transform(),
                 None,
             );
         }
@@ -351,13 +445,7 @@ impl IconRenderer {
     ) {
         let path = self.rounded_rect_path(x, y, width, height, BAR_RADIUS.min(height / 2.0));
         let paint = create_paint(colors.track);
-        pixmap.fill_path(
-            &path,
-            &paint,
-            FillRule::Winding,
-            Transform::identity(),
-            None,
-        );
+        pixmap.fill_path(&path, &paint, FillRule::Winding, self.transform(), None);
     }
 
     fn draw_credits_bar(
@@ -430,7 +518,7 @@ impl IconRenderer {
             &bg_path,
             &bg_paint,
             FillRule::Winding,
-            Transform::identity(),
+            self.transform(),
             None,
         );
 
@@ -444,7 +532,7 @@ impl IconRenderer {
                 &fill_path,
                 &fill_paint,
                 FillRule::Winding,
-                Transform::identity(),
+                self.transform(),
                 None,
             );
         }
@@ -470,7 +558,7 @@ impl IconRenderer {
             size,
             stroke_width,
         ) {
-            pixmap.fill_rect(rect, &paint, Transform::identity(), None);
+            pixmap.fill_rect(rect, &paint, self.transform(), None);
         }
 
         // Draw vertical line
@@ -480,7 +568,24 @@ impl IconRenderer {
             stroke_width,
             size,
         ) {
-            pixmap.fill_rect(rect, &paint, Transform::identity(), None);
+            pixmap.fill_rect(rect, &paint, self.transform(), None);
+        }
+    }
+
+    /// Draws a ring in the status dot's corner, distinct from incident dots.
+    fn draw_auth_badge(&self, pixmap: &mut Pixmap) {
+        let x = self.width as f32 - STATUS_DOT_RADIUS - STATUS_DOT_MARGIN;
+        let y = self.height as f32 - STATUS_DOT_RADIUS - STATUS_DOT_MARGIN;
+
+        let mut pb = PathBuilder::new();
+        pb.push_circle(x, y, STATUS_DOT_RADIUS - 0.75);
+        if let Some(path) = pb.finish() {
+            let paint = create_paint(Color::from_rgba8(255, 149, 0, 255)); // Orange
+            let stroke = Stroke {
+                width: 1.5,
+                ..Stroke::default()
+            };
+            pixmap.stroke_path(&path, &paint, &stroke, self.transform(), None);
         }
     }
 
@@ -501,13 +606,7 @@ impl IconRenderer {
         pb.push_circle(x, y, STATUS_DOT_RADIUS);
         if let Some(path) = pb.finish() {
             let paint = create_paint(color);
-            pixmap.fill_path(
-                &path,
-                &paint,
-                FillRule::Winding,
-                Transform::identity(),
-                None,
-            );
+            pixmap.fill_path(&path, &paint, FillRule::Winding, self.transform(), None);
         }
     }
 
@@ -539,12 +638,12 @@ impl IconRenderer {
     /// Returns color based on USAGE percentage (not remaining!).
     /// Green = low usage (good), Red = high usage (warning)
     pub(crate) fn percent_to_color(&self, used_percent: f32, colors: &IconColors) -> Color {
-        if used_percent < 50.0 {
-            colors.good // Green - low usage is good!
-        } else if used_percent < 80.0 {
+        if self.usage_gradient.is_danger(used_percent) {
+            colors.danger // Red - high usage, approaching limit!
+        } else if self.usage_gradient.is_warning(used_percent) {
             colors.warning // Yellow/Orange - moderate usage
         } else {
-            colors.danger // Red - high usage, approaching limit!
+            colors.good // Green - low usage is good!
         }
     }
 }
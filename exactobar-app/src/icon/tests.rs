@@ -17,6 +17,33 @@ fn test_render_empty() {
     assert!(!icon.data.is_empty());
 }
 
+#[test]
+fn test_render_backing_scales() {
+    let mut snapshot = UsageSnapshot::new();
+    snapshot.primary = Some(UsageWindow::new(100.0));
+
+    let sizes: Vec<_> = BACKING_SCALES
+        .iter()
+        .map(|&scale| {
+            let renderer = IconRenderer::new().with_scale(scale);
+            let icon = renderer.render(ProviderKind::Claude, Some(&snapshot), false, None, None);
+            assert_eq!(icon.data.len(), (icon.width * icon.height * 4) as usize);
+            (icon.width, icon.height)
+        })
+        .collect();
+    assert_eq!(sizes, [(18, 11), (36, 22), (54, 33)]);
+
+    // The eye's fill is masked per output pixel
+    let icon = IconRenderer::new().with_scale(1.5).render(
+        ProviderKind::Codex,
+        Some(&snapshot),
+        false,
+        None,
+        None,
+    );
+    assert!(icon.data.chunks(4).any(|pixel| pixel[3] > 0));
+}
+
 #[test]
 fn test_render_with_snapshot() {
     let renderer = IconRenderer::new();
@@ -73,6 +100,14 @@ fn test_render_error() {
     assert!(!icon.data.is_empty());
 }
 
+#[test]
+fn test_render_auth_required() {
+    let renderer = IconRenderer::new();
+    let icon = renderer.render_auth_required(ProviderKind::Claude);
+    assert!(!icon.data.is_empty());
+    assert_ne!(icon.data, renderer.render_error(ProviderKind::Claude).data);
+}
+
 #[test]
 fn test_to_png() {
     let renderer = IconRenderer::new();
@@ -187,3 +222,200 @@ fn test_render_codex_eye_with_status_indicator() {
     );
     assert!(!icon.data.is_empty());
 }
+
+// ============================================================================
+// Usage Card Tests
+// ============================================================================
+
+fn card_entry(provider: ProviderKind, percents: &[f64], stale: bool) -> UsageCardEntry {
+    UsageCardEntry {
+        provider,
+        name: format!("{:?}", provider),
+        windows: percents
+            .iter()
+            .map(|&used_percent| CardWindow {
+                label: "Session".to_string(),
+                used_percent,
+                reset: Some("resets in 2h 5m".to_string()),
+            })
+            .collect(),
+        stale,
+    }
+}
+
+#[test]
+fn test_usage_card_entry_from_snapshot() {
+    let now = chrono::Utc::now();
+    let mut snapshot = UsageSnapshot::new();
+    let mut primary = UsageWindow::new(25.0);
+    primary.resets_at = Some(now + chrono::Duration::minutes(125));
+    snapshot.primary = Some(primary);
+    snapshot.tertiary = Some(UsageWindow::new(80.0));
+
+    let entry = UsageCardEntry::from_snapshot(ProviderKind::Claude, Some(&snapshot), false, now);
+    assert_eq!(entry.name, "Claude");
+    assert_eq!(
+        entry.windows,
+        vec![
+            CardWindow {
+                label: "Session".to_string(),
+                used_percent: 25.0,
+                reset: Some("resets in 2h 5m".to_string()),
+            },
+            CardWindow {
+                label: "Premium".to_string(),
+                used_percent: 80.0,
+                reset: None,
+            },
+        ]
+    );
+
+    let empty = UsageCardEntry::from_snapshot(ProviderKind::Claude, None, false, now);
+    assert!(empty.windows.is_empty());
+}
+
+#[test]
+fn test_usage_card_font_covers_names_and_labels() {
+    let now = chrono::Utc::now();
+    let mut snapshot = UsageSnapshot::new();
+    snapshot.primary = Some(UsageWindow::new(25.0));
+    snapshot.secondary = Some(UsageWindow::new(50.0));
+
+    for &provider in ProviderKind::all() {
+        let entry = UsageCardEntry::from_snapshot(provider, Some(&snapshot), false, now);
+        let texts =
+            std::iter::once(entry.name).chain(entry.windows.iter().map(|w| w.label.clone()));
+        for text in texts {
+            assert!(
+                text.chars()
+                    .flat_map(char::to_uppercase)
+                    .all(|c| card::glyph(c).is_some()),
+                "no glyph for some of {:?}",
+                text
+            );
+        }
+    }
+    assert!(
+        "resets in 2h 5m · ~45m"
+            .chars()
+            .flat_map(char::to_uppercase)
+            .all(|c| card::glyph(c).is_some())
+    );
+}
+
+#[test]
+fn test_render_usage_card_grows_with_entries() {
+    let one = IconRenderer::render_usage_card(
+        &[card_entry(ProviderKind::Claude, &[45.0], false)],
+        HashMap::new(),
+    );
+    let two = IconRenderer::render_usage_card(
+        &[
+            card_entry(ProviderKind::Claude, &[45.0, 10.0], false),
+            card_entry(ProviderKind::Codex, &[], true),
+        ],
+        HashMap::new(),
+    );
+
+    assert_eq!(one.width, two.width);
+    assert!(two.height > one.height);
+    assert_eq!(one.data.len(), (one.width * one.height * 4) as usize);
+    assert!(!two.to_png().is_empty());
+}
+
+// ============================================================================
+// Cache Tests
+// ============================================================================
+
+#[test]
+fn test_icon_key_quantizes() {
+    let mut a = UsageSnapshot::new();
+    a.primary = Some(UsageWindow::new(42.2));
+    let mut b = UsageSnapshot::new();
+    b.primary = Some(UsageWindow::new(41.8));
+
+    let claude = ProviderKind::Claude;
+    assert_eq!(
+        IconKey::usage(claude, Some(&a), false, None, None),
+        IconKey::usage(
+            claude,
+            Some(&b),
+            false,
+            Some(StatusIndicator::Unknown),
+            None
+        )
+    );
+    assert_ne!(
+        IconKey::usage(claude, Some(&a), false, None, None),
+        IconKey::usage(claude, Some(&a), true, None, None)
+    );
+
+    // Blinks only change the Codex eye
+    let blink = IconAnimationState::with_blink(0.5);
+    assert_eq!(
+        IconKey::usage(claude, None, false, None, Some(&blink)),
+        IconKey::usage(claude, None, false, None, None)
+    );
+    assert_ne!(
+        IconKey::usage(ProviderKind::Codex, None, false, None, Some(&blink)),
+        IconKey::usage(ProviderKind::Codex, None, false, None, None)
+    );
+
+    // The loading animation cycles
+    assert_eq!(
+        IconKey::loading(claude, 0.0),
+        IconKey::loading(claude, std::f64::consts::TAU)
+    );
+}
+
+#[test]
+fn test_brand_color_overrides() {
+    let white = Color::from_rgba8(255, 255, 255, 255);
+    let mut renderer = IconRenderer::new().with_mode(RenderMode::Colored);
+    let key = IconKey::usage(ProviderKind::Copilot, None, false, None, None);
+    let default = renderer.render_cached(key);
+    assert_ne!(renderer.brand_color(ProviderKind::Copilot), white);
+
+    renderer.set_brand_colors(HashMap::from([(ProviderKind::Copilot, white)]));
+    assert_eq!(renderer.brand_color(ProviderKind::Copilot), white);
+    assert_eq!(
+        renderer.brand_color(ProviderKind::Claude),
+        colors::provider_brand_color(ProviderKind::Claude)
+    );
+    // Frames drawn with the old colors aren't reused
+    assert!(!Rc::ptr_eq(&default, &renderer.render_cached(key)));
+}
+
+#[test]
+fn test_usage_gradient_breakpoints() {
+    let mut renderer = IconRenderer::new().with_mode(RenderMode::Colored);
+    let colors = IconColors::colored(Color::WHITE, false);
+    assert_eq!(renderer.percent_to_color(49.0, &colors), colors.good);
+    assert_eq!(renderer.percent_to_color(65.0, &colors), colors.warning);
+    assert_eq!(renderer.percent_to_color(80.0, &colors), colors.danger);
+
+    let key = IconKey::usage(ProviderKind::Claude, None, false, None, None);
+    let default = renderer.render_cached(key);
+    renderer.set_usage_gradient(UsageGradient::with_breakpoints(40.0, 60.0));
+    assert_eq!(renderer.percent_to_color(45.0, &colors), colors.warning);
+    assert_eq!(renderer.percent_to_color(65.0, &colors), colors.danger);
+    assert!(!Rc::ptr_eq(&default, &renderer.render_cached(key)));
+}
+
+#[test]
+fn test_render_cached_reuses_frames() {
+    let mut renderer = IconRenderer::new();
+    let key = IconKey::error(ProviderKind::Claude);
+
+    let first = renderer.render_cached(key);
+    let second = renderer.render_cached(key);
+    assert!(Rc::ptr_eq(&first, &second));
+    assert!(std::ptr::eq(first.png(), second.png()));
+    assert_eq!(
+        first.rendered.data,
+        renderer.render_error(ProviderKind::Claude).data
+    );
+
+    let other = renderer.render_cached(IconKey::loading(ProviderKind::Claude, 1.0));
+    assert!(!Rc::ptr_eq(&first, &other));
+}
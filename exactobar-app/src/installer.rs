@@ -0,0 +1,675 @@
+//! In-place update installation.
+//!
+//! Downloads a release archive, checks it against the published SHA-256
+//! checksum, and swaps the new binaries in next to the running ones. The
+//! running process keeps its old executable until it's relaunched.
+//!
+//! The `.sha256` file is published next to the archive, so it only checks
+//! that the download is intact; it doesn't prove who built the release.
+//! That rests on the HTTPS connection to GitHub. The binaries are unpacked
+//! in memory and written out from the verified bytes, never from a file
+//! that could have changed since it was checked.
+//!
+//! When the release publishes a bsdiff patch from the running version, only
+//! the patch is downloaded and applied to the current binary. Any problem
+//! with the patch falls back to the full archive.
+//!
+//! Downloads report into a shared [`DownloadProgress`], which the update
+//! dialog polls and uses to pause or cancel. Partial downloads are kept in
+//! a staging directory in the user's cache, readable only by them, so a
+//! paused or failed download resumes with an HTTP range request.
+//!
+//! On Linux the [`InstallMethod`] decides what updating means: Flatpak
+//! installs are left to the Flatpak runtime, AppImages are updated with
+//! AppImageUpdate (zsync), and deb installs point at the package repository.
+//!
+//! Like the updater, this uses `reqwest::blocking` inside `smol::unblock()`
+//! because GPUI runs on smol rather than Tokio.
+
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
+use gpui::App;
+use once_cell::sync::Lazy;
+use tracing::{info, warn};
+
+use crate::updater::{CURRENT_VERSION, DeltaAsset, UpdateAsset, platform_suffix};
+
+/// Detected once; the install method can't change while the app runs.
+static INSTALL_METHOD: Lazy<InstallMethod> = Lazy::new(InstallMethod::detect);
+
+/// Size of each read while downloading, between progress updates.
+const DOWNLOAD_CHUNK: usize = 64 * 1024;
+
+// ============================================================================
+// Download Progress
+// ============================================================================
+
+/// Progress of an update download, shared with the update dialog.
+///
+/// Pausing and cancelling both stop the download; a paused download keeps
+/// its partial file so the next attempt resumes where it stopped.
+#[derive(Debug, Default)]
+pub struct DownloadProgress {
+    downloaded: AtomicU64,
+    /// Expected size in bytes, or 0 if the server didn't say.
+    total: AtomicU64,
+    paused: AtomicBool,
+    cancelled: AtomicBool,
+}
+
+impl DownloadProgress {
+    /// Bytes downloaded so far, including any resumed part.
+    pub fn downloaded(&self) -> u64 {
+        self.downloaded.load(Ordering::Relaxed)
+    }
+
+    /// Expected size in bytes, if known.
+    pub fn total(&self) -> Option<u64> {
+        Some(self.total.load(Ordering::Relaxed)).filter(|&total| total > 0)
+    }
+
+    /// Fraction downloaded (0.0 to 1.0), if the size is known.
+    pub fn fraction(&self) -> Option<f32> {
+        self.total()
+            .map(|total| (self.downloaded() as f64 / total as f64).min(1.0) as f32)
+    }
+
+    /// Stops the download, keeping what's been downloaded for a resume.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    /// Stops the download and discards it.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    fn is_stopped(&self) -> bool {
+        self.is_paused() || self.is_cancelled()
+    }
+
+    fn start(&self, downloaded: u64, total: Option<u64>) {
+        self.downloaded.store(downloaded, Ordering::Relaxed);
+        self.total.store(total.unwrap_or(0), Ordering::Relaxed);
+    }
+}
+
+// ============================================================================
+// Install Method
+// ============================================================================
+
+/// How the app was installed, which decides how it's updated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstallMethod {
+    /// Unpacked from a release archive; updated in place.
+    Standalone,
+    /// Flatpak; the Flatpak runtime handles updates.
+    Flatpak,
+    /// AppImage at this path; updated with AppImageUpdate.
+    AppImage(PathBuf),
+    /// Debian package; updated through the package manager.
+    Deb,
+}
+
+impl InstallMethod {
+    /// Detects how the running app was installed.
+    fn detect() -> Self {
+        if !cfg!(target_os = "linux") {
+            return InstallMethod::Standalone;
+        }
+
+        let exe = std::env::current_exe().ok();
+        let method = Self::classify(
+            std::env::var_os("FLATPAK_ID").is_some() || Path::new("/.flatpak-info").exists(),
+            std::env::var_os("APPIMAGE").map(PathBuf::from),
+            exe.as_deref().is_some_and(is_dpkg_owned),
+        );
+        info!(method = ?method, "Detected install method");
+        method
+    }
+
+    fn classify(flatpak: bool, appimage: Option<PathBuf>, dpkg_owned: bool) -> Self {
+        if flatpak {
+            InstallMethod::Flatpak
+        } else if let Some(path) = appimage {
+            InstallMethod::AppImage(path)
+        } else if dpkg_owned {
+            InstallMethod::Deb
+        } else {
+            InstallMethod::Standalone
+        }
+    }
+
+    /// Returns true if the app should check GitHub for updates itself.
+    pub fn checks_for_updates(&self) -> bool {
+        *self != InstallMethod::Flatpak
+    }
+
+    /// Returns true if the update dialog can install updates, given whether
+    /// the release has a verifiable archive for this platform.
+    pub fn can_install(&self, has_asset: bool) -> bool {
+        match self {
+            InstallMethod::Standalone => has_asset,
+            InstallMethod::AppImage(_) => true,
+            InstallMethod::Flatpak | InstallMethod::Deb => false,
+        }
+    }
+
+    /// Explains how to update when the app can't do it itself.
+    pub fn update_hint(&self) -> Option<&'static str> {
+        match self {
+            InstallMethod::Deb => Some(
+                "ExactoBar was installed from a .deb package. Update it with your package manager.",
+            ),
+            InstallMethod::Flatpak => Some("Update ExactoBar with `flatpak update`."),
+            _ => None,
+        }
+    }
+}
+
+/// Returns true if `exe` belongs to an installed Debian package.
+fn is_dpkg_owned(exe: &Path) -> bool {
+    exe.starts_with("/usr")
+        && Command::new("dpkg")
+            .arg("-S")
+            .arg(exe)
+            .output()
+            .is_ok_and(|output| output.status.success())
+}
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Returns how the running app was installed.
+pub fn install_method() -> &'static InstallMethod {
+    &INSTALL_METHOD
+}
+
+/// Installs the update the way the install method requires.
+///
+/// Standalone installs download, verify, and swap in `asset`, reporting
+/// into `progress`; AppImages run AppImageUpdate. Returns the path of the
+/// installed app. Errors are user-facing messages.
+pub async fn install_update(
+    asset: Option<UpdateAsset>,
+    progress: Arc<DownloadProgress>,
+) -> Result<PathBuf, String> {
+    smol::unblock(move || match install_method() {
+        InstallMethod::Standalone => {
+            let asset = asset.ok_or("No download is available for this platform")?;
+            install_update_blocking(&asset, &progress)
+        }
+        InstallMethod::AppImage(path) => install_appimage(path),
+        InstallMethod::Flatpak | InstallMethod::Deb => {
+            Err("Update ExactoBar with your package manager".to_string())
+        }
+    })
+    .await
+}
+
+/// Deletes partially downloaded updates.
+pub fn discard_download() {
+    let _ = fs::remove_dir_all(staging_dir());
+}
+
+/// Starts the installed app again and quits this instance.
+pub fn relaunch(cx: &mut App) {
+    // An AppImage's executable lives inside its (now replaced) mount
+    let exe = match install_method() {
+        InstallMethod::AppImage(path) => Ok(path.clone()),
+        _ => std::env::current_exe(),
+    };
+
+    match exe {
+        Ok(exe) => {
+            // Otherwise the new process finds us running, signals us and exits
+            crate::instance::release(cx);
+            match Command::new(&exe).spawn() {
+                Ok(_) => {
+                    info!(path = %exe.display(), "Relaunching after update");
+                    cx.quit();
+                }
+                Err(e) => warn!(error = ?e, "Failed to relaunch"),
+            }
+        }
+        Err(e) => warn!(error = ?e, "Failed to locate current executable"),
+    }
+}
+
+// ============================================================================
+// Private Implementation
+// ============================================================================
+
+/// Directory for partial downloads, kept between attempts so downloads can
+/// resume.
+fn staging_dir() -> PathBuf {
+    exactobar_store::default_cache_dir().join("update")
+}
+
+/// Creates the staging directory, readable and writable only by the user.
+fn create_staging_dir() -> std::io::Result<PathBuf> {
+    let dir = staging_dir();
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder.create(&dir)?;
+
+    // A directory left by an earlier version keeps its old mode
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))?;
+    }
+    Ok(dir)
+}
+
+fn install_update_blocking(
+    asset: &UpdateAsset,
+    progress: &DownloadProgress,
+) -> Result<PathBuf, String> {
+    let checksum_url = asset
+        .checksum_url
+        .as_deref()
+        .ok_or("This release has no checksum, so it can't be installed automatically")?;
+    let suffix = platform_suffix().ok_or("Automatic updates aren't available on this platform")?;
+
+    let client = exactobar_fetch::network::blocking_client_builder()
+        .user_agent(format!("ExactoBar/{}", CURRENT_VERSION))
+        .connect_timeout(Duration::from_secs(10))
+        .timeout(Duration::from_secs(600))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let current = std::env::current_exe()
+        .and_then(|p| p.canonicalize())
+        .map_err(|e| format!("Failed to locate the running app: {}", e))?;
+    let install_dir = current
+        .parent()
+        .ok_or("The running app has no parent directory")?;
+
+    // The app binary is always replaced; the CLI only if it's installed alongside
+    let mut targets = vec![("exactobar-app", current.clone())];
+    let cli = install_dir.join("exactobar");
+    if cli.exists() {
+        targets.push(("exactobar", cli));
+    }
+
+    let staging = create_staging_dir()
+        .map_err(|e| format!("Failed to create {}: {}", staging_dir().display(), e))?;
+
+    // Patches only cover the app binary, so a CLI alongside needs the archive
+    if let Some(delta) = asset.delta.as_ref().filter(|_| targets.len() == 1) {
+        match install_delta(&client, delta, suffix, &current, &staging, progress) {
+            Ok(()) => {
+                let _ = fs::remove_dir_all(&staging);
+                return Ok(current);
+            }
+            // Paused or cancelled by the user, not a reason to fall back
+            Err(e) if progress.is_stopped() => return Err(e),
+            Err(e) => warn!(
+                error = e.as_str(),
+                "Delta update failed, downloading full archive"
+            ),
+        }
+    }
+
+    let checksums = String::from_utf8_lossy(&download(&client, checksum_url)?).into_owned();
+    let expected = parse_checksum(&checksums, &asset.name)
+        .ok_or("The checksum file for this release couldn't be read")?;
+
+    info!(url = asset.url.as_str(), "Downloading update");
+    let archive_path = staging.join(&asset.name);
+    let archive = download_resumable(&client, &asset.url, &archive_path, progress)?;
+    if let Err(e) = verify(&archive, &expected) {
+        // A corrupt partial file must not be resumed
+        let _ = fs::remove_file(&archive_path);
+        return Err(e);
+    }
+
+    for (binary, target) in targets {
+        let data = extract(&archive, &format!("{}-{}", binary, suffix))?;
+        replace_binary(&data, &target)?;
+        info!(path = %target.display(), "Installed update");
+    }
+
+    let _ = fs::remove_dir_all(&staging);
+    Ok(current)
+}
+
+/// Updates an AppImage in place using the zsync update information
+/// embedded in it.
+fn install_appimage(path: &Path) -> Result<PathBuf, String> {
+    info!(path = %path.display(), "Updating AppImage");
+    let status = Command::new("appimageupdatetool")
+        .arg("--overwrite")
+        .arg(path)
+        .status()
+        .map_err(|_| "Install AppImageUpdate (appimageupdatetool) to update this AppImage")?;
+
+    if status.success() {
+        Ok(path.to_path_buf())
+    } else {
+        Err("AppImageUpdate couldn't update this AppImage".to_string())
+    }
+}
+
+/// Patches the running app binary with `delta` and installs the result.
+fn install_delta(
+    client: &reqwest::blocking::Client,
+    delta: &DeltaAsset,
+    suffix: &str,
+    current: &Path,
+    staging: &Path,
+    progress: &DownloadProgress,
+) -> Result<(), String> {
+    let checksums = String::from_utf8_lossy(&download(client, &delta.checksum_url)?).into_owned();
+    let binary = format!("exactobar-app-{}", suffix);
+    let expected = parse_checksum(&checksums, &binary)
+        .ok_or("The checksum file for the patch couldn't be read")?;
+
+    info!(url = delta.url.as_str(), "Downloading update patch");
+    let patch_path = staging.join(format!("{}.bsdiff", binary));
+    let patch = download_resumable(client, &delta.url, &patch_path, progress)?;
+
+    let old = fs::read(current).map_err(|e| format!("Failed to read the running app: {}", e))?;
+    let mut new = Vec::new();
+    let applied = bsdiff::patch(&old, &mut patch.as_slice(), &mut new)
+        .map_err(|e| format!("Failed to apply the patch: {}", e))
+        .and_then(|_| verify(&new, &expected));
+    if let Err(e) = applied {
+        let _ = fs::remove_file(&patch_path);
+        return Err(e);
+    }
+
+    replace_binary(&new, current)?;
+    info!(
+        path = %current.display(),
+        patch_bytes = patch.len(),
+        "Installed update from patch"
+    );
+    Ok(())
+}
+
+/// Checks `data` against an expected SHA-256 hex digest.
+fn verify(data: &[u8], expected: &str) -> Result<(), String> {
+    let actual = sha256_hex(data);
+    if actual.eq_ignore_ascii_case(expected) {
+        Ok(())
+    } else {
+        warn!(expected, actual = %actual, "Update checksum mismatch");
+        Err("The download didn't match its checksum".to_string())
+    }
+}
+
+fn download(client: &reqwest::blocking::Client, url: &str) -> Result<Vec<u8>, String> {
+    let response = client
+        .get(url)
+        .send()
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("Download failed: {}", e))?;
+    response
+        .bytes()
+        .map(|b| b.to_vec())
+        .map_err(|e| format!("Download failed: {}", e))
+}
+
+/// Downloads `url` into `path`, resuming a partial file from an earlier
+/// attempt, and returns the complete contents.
+///
+/// Stops with an error when `progress` is paused or cancelled, leaving the
+/// partial file in place. Callers verify the contents and delete the file
+/// if they don't match, so a bad file is never resumed.
+fn download_resumable(
+    client: &reqwest::blocking::Client,
+    url: &str,
+    path: &Path,
+    progress: &DownloadProgress,
+) -> Result<Vec<u8>, String> {
+    let failed = |e: &dyn std::fmt::Display| format!("Download failed: {}", e);
+
+    let existing = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    let mut request = client.get(url);
+    if existing > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={}-", existing));
+    }
+    let response = request.send().map_err(|e| failed(&e))?;
+
+    // The range starts at the end: an earlier attempt got the whole file
+    // but stopped before verifying it
+    if existing > 0 && response.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+        info!(bytes = existing, "Download already complete");
+        progress.start(existing, Some(existing));
+        return fs::read(path).map_err(|e| failed(&e));
+    }
+    let mut response = response.error_for_status().map_err(|e| failed(&e))?;
+
+    // Servers that ignore the range send the whole file again
+    let resumed = response.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+    let start = if resumed { existing } else { 0 };
+    if resumed {
+        info!(bytes = existing, "Resuming download");
+    }
+    progress.start(start, response.content_length().map(|len| len + start));
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(resumed)
+        .truncate(!resumed)
+        .open(path)
+        .map_err(|e| failed(&e))?;
+
+    let mut buffer = vec![0; DOWNLOAD_CHUNK];
+    loop {
+        if progress.is_stopped() {
+            return Err("Download stopped".to_string());
+        }
+        let read = response.read(&mut buffer).map_err(|e| failed(&e))?;
+        if read == 0 {
+            break;
+        }
+        file.write_all(&buffer[..read]).map_err(|e| failed(&e))?;
+        progress
+            .downloaded
+            .fetch_add(read as u64, Ordering::Relaxed);
+    }
+
+    fs::read(path).map_err(|e| failed(&e))
+}
+
+/// Extracts one file from a `.tar.gz` archive in memory.
+fn extract(archive: &[u8], member: &str) -> Result<Vec<u8>, String> {
+    let mut tar = Command::new("tar")
+        .args(["-xzOf", "-", member])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to run tar: {}", e))?;
+    let mut stdin = tar.stdin.take().ok_or("Failed to run tar")?;
+
+    let output = std::thread::scope(|scope| {
+        // Fed from another thread so a full stdout pipe can't stall it
+        scope.spawn(move || {
+            let _ = stdin.write_all(archive);
+        });
+        tar.wait_with_output()
+    })
+    .map_err(|e| format!("Failed to run tar: {}", e))?;
+
+    if output.status.success() && !output.stdout.is_empty() {
+        Ok(output.stdout)
+    } else {
+        Err("Failed to unpack the update".to_string())
+    }
+}
+
+/// Writes `data` next to `target`, then renames it over `target`.
+///
+/// The rename is atomic, so a failed install never leaves a half-written
+/// binary behind.
+fn replace_binary(data: &[u8], target: &Path) -> Result<(), String> {
+    let file_name = target.file_name().ok_or("Invalid install path")?;
+    let staged = target.with_file_name(format!(".{}.new", file_name.to_string_lossy()));
+
+    let result = fs::write(&staged, data)
+        .and_then(|_| set_executable(&staged))
+        .and_then(|_| fs::rename(&staged, target));
+
+    result.map_err(|e| {
+        let _ = fs::remove_file(&staged);
+        format!("Couldn't replace {}: {}", target.display(), e)
+    })
+}
+
+#[cfg(unix)]
+fn set_executable(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// Finds the checksum for `name` in a `sha256sum`-style file.
+///
+/// Accepts `<hash>  <name>` lines (with `*` for binary mode) or a file
+/// holding just the hash.
+fn parse_checksum(text: &str, name: &str) -> Option<String> {
+    let is_hash = |s: &str| s.len() == 64 && s.chars().all(|c| c.is_ascii_hexdigit());
+
+    text.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        let hash = parts.next().filter(|h| is_hash(h))?;
+        match parts.next() {
+            Some(file) if file.trim_start_matches('*') != name => None,
+            _ => Some(hash.to_lowercase()),
+        }
+    })
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    ring::digest::digest(&ring::digest::SHA256, data)
+        .as_ref()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_install_method_classify() {
+        let appimage = PathBuf::from("/home/me/ExactoBar.AppImage");
+
+        assert_eq!(
+            InstallMethod::classify(true, Some(appimage.clone()), true),
+            InstallMethod::Flatpak
+        );
+        assert_eq!(
+            InstallMethod::classify(false, Some(appimage.clone()), false),
+            InstallMethod::AppImage(appimage)
+        );
+        assert_eq!(
+            InstallMethod::classify(false, None, true),
+            InstallMethod::Deb
+        );
+        assert_eq!(
+            InstallMethod::classify(false, None, false),
+            InstallMethod::Standalone
+        );
+        assert!(!InstallMethod::Flatpak.checks_for_updates());
+        assert!(!InstallMethod::Deb.can_install(true));
+        assert!(!InstallMethod::Standalone.can_install(false));
+    }
+
+    #[test]
+    fn test_download_progress() {
+        let progress = DownloadProgress::default();
+        assert_eq!(progress.fraction(), None);
+
+        progress.start(25, Some(100));
+        progress.downloaded.fetch_add(25, Ordering::Relaxed);
+        assert_eq!(progress.fraction(), Some(0.5));
+
+        assert!(!progress.is_stopped());
+        progress.pause();
+        assert!(progress.is_stopped() && !progress.is_cancelled());
+    }
+
+    #[test]
+    fn test_parse_checksum() {
+        let hash = "a".repeat(64);
+        let other = "b".repeat(64);
+
+        assert_eq!(parse_checksum(&hash, "any.tar.gz"), Some(hash.clone()));
+        assert_eq!(
+            parse_checksum(
+                &format!(
+                    "{}  other.tar.gz\n{} *app.tar.gz\n",
+                    other,
+                    hash.to_uppercase()
+                ),
+                "app.tar.gz"
+            ),
+            Some(hash)
+        );
+        assert_eq!(parse_checksum("not a hash  app.tar.gz", "app.tar.gz"), None);
+    }
+
+    #[test]
+    fn test_extract() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("exactobar-app-test"), b"new binary").unwrap();
+        let archive_path = dir.path().join("update.tar.gz");
+        let status = Command::new("tar")
+            .arg("-czf")
+            .arg(&archive_path)
+            .arg("-C")
+            .arg(dir.path())
+            .arg("exactobar-app-test")
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        let archive = fs::read(&archive_path).unwrap();
+        assert_eq!(
+            extract(&archive, "exactobar-app-test").unwrap(),
+            b"new binary"
+        );
+        assert!(extract(&archive, "exactobar").is_err());
+        assert!(extract(b"not an archive", "exactobar-app-test").is_err());
+    }
+
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! Single-instance guard.
+//!
+//! The first instance takes an exclusive lock on a file in the config
+//! directory and listens on a Unix socket next to it. Launching the app
+//! again finds the lock taken and connects to the socket instead of
+//! starting a second set of status items and refresh loops: the running
+//! instance opens its menu and the new process exits.
+
+#[cfg(unix)]
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::Path;
+#[cfg(unix)]
+use std::path::PathBuf;
+#[cfg(unix)]
+use std::time::Duration;
+
+use gpui::*;
+use tracing::{debug, info, warn};
+
+use crate::tray::SystemTray;
+
+/// Line a second launch sends to ask the running instance for its menu.
+const SHOW_MESSAGE: &str = "show";
+
+/// Held by the running instance for as long as it runs.
+pub struct InstanceLock {
+    /// The locked file; the lock is released when it's closed. `None` if
+    /// it couldn't be opened, in which case the app runs unguarded.
+    #[cfg(unix)]
+    lock: Option<File>,
+    /// Socket later launches connect to.
+    #[cfg(unix)]
+    socket: PathBuf,
+    /// Listener on `socket` (`None` if it couldn't be bound, or once
+    /// [`InstanceLock::listen`] has taken it).
+    #[cfg(unix)]
+    listener: Option<UnixListener>,
+}
+
+impl Global for InstanceLock {}
+
+/// Claims the single instance.
+///
+/// Returns `None` if another instance is already running, after asking it
+/// to open its menu.
+pub fn acquire() -> Option<InstanceLock> {
+    acquire_in(&exactobar_store::default_config_dir())
+}
+
+/// Gives up the single instance so a relaunched app can claim it while
+/// this one quits.
+pub fn release(cx: &mut App) {
+    if cx.has_global::<InstanceLock>() {
+        cx.global_mut::<InstanceLock>().release();
+    }
+}
+
+#[cfg(unix)]
+fn acquire_in(dir: &Path) -> Option<InstanceLock> {
+    let _ = std::fs::create_dir_all(dir);
+    let socket = dir.join("instance.sock");
+    let lock = match File::create(dir.join("instance.lock")) {
+        Ok(file) => file,
+        Err(e) => {
+            warn!(error = %e, "Failed to open single-instance lock");
+            return Some(InstanceLock {
+                lock: None,
+                socket,
+                listener: None,
+            });
+        }
+    };
+
+    if !try_lock(&lock) {
+        signal_running(&socket);
+        return None;
+    }
+
+    // Holding the lock, so any socket file is left over from a crash
+    let _ = std::fs::remove_file(&socket);
+    let listener = match UnixListener::bind(&socket) {
+        Ok(listener) => Some(listener),
+        Err(e) => {
+            warn!(path = %socket.display(), error = %e, "Failed to bind single-instance socket");
+            None
+        }
+    };
+    Some(InstanceLock {
+        lock: Some(lock),
+        socket,
+        listener,
+    })
+}
+
+#[cfg(not(unix))]
+fn acquire_in(_dir: &Path) -> Option<InstanceLock> {
+    Some(InstanceLock {})
+}
+
+/// Takes an exclusive lock on `file` without waiting.
+#[cfg(unix)]
+fn try_lock(file: &File) -> bool {
+    use std::os::fd::AsRawFd;
+
+    // SAFETY: `file` keeps the descriptor open for the duration of the call
+    unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) == 0 }
+}
+
+/// Asks the instance holding the lock to open its menu.
+///
+/// It binds its socket right after taking the lock, so if both launches
+/// started together, give it a moment.
+#[cfg(unix)]
+fn signal_running(socket: &Path) {
+    for _ in 0..10 {
+        if let Ok(mut stream) = UnixStream::connect(socket) {
+            if let Err(e) = writeln!(stream, "{SHOW_MESSAGE}") {
+                warn!(error = %e, "Failed to signal the running instance");
+            }
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    warn!("The running instance didn't answer");
+}
+
+impl InstanceLock {
+    /// Opens the menu whenever the app is launched again.
+    ///
+    /// Call once the tray is set up.
+    pub fn listen(mut self, cx: &mut App) {
+        #[cfg(unix)]
+        if let Some(listener) = self.listener.take() {
+            let (sender, receiver) = smol::channel::unbounded::<()>();
+
+            std::thread::spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    let mut line = String::new();
+                    if BufReader::new(stream).read_line(&mut line).is_ok()
+                        && line.trim() == SHOW_MESSAGE
+                        && sender.send_blocking(()).is_err()
+                    {
+                        break;
+                    }
+                }
+            });
+
+            cx.spawn(async move |cx| {
+                while receiver.recv().await.is_ok() {
+                    info!("App launched again, opening the menu");
+                    let _ = cx.update_global::<SystemTray, _>(|tray, cx| {
+                        tray.show_menu(cx);
+                    });
+                }
+            })
+            .detach();
+
+            debug!("Single-instance listener started");
+        }
+
+        cx.set_global(self);
+    }
+
+    /// Unlocks and removes the socket, so the next launch claims the
+    /// instance rather than signalling this one.
+    fn release(&mut self) {
+        #[cfg(unix)]
+        if self.lock.take().is_some() {
+            let _ = std::fs::remove_file(&self.socket);
+            debug!("Single-instance lock released");
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_second_launch_signals_first() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let first = acquire_in(dir.path()).expect("first launch runs");
+        let listener = first.listener.expect("socket bound");
+        assert!(acquire_in(dir.path()).is_none());
+
+        let (stream, _) = listener.accept().unwrap();
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line).unwrap();
+        assert_eq!(line.trim(), SHOW_MESSAGE);
+    }
+
+    #[test]
+    fn test_stale_socket_is_replaced() {
+        let dir = tempfile::tempdir().unwrap();
+
+        // A crashed instance leaves its socket file behind
+        drop(acquire_in(dir.path()));
+        assert!(dir.path().join("instance.sock").exists());
+        assert!(acquire_in(dir.path()).is_some_and(|lock| lock.listener.is_some()));
+    }
+
+    #[test]
+    fn test_release_lets_relaunch_run() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut first = acquire_in(dir.path()).expect("first launch runs");
+        first.release();
+        assert!(!dir.path().join("instance.sock").exists());
+
+        let second = acquire_in(dir.path()).expect("relaunch runs");
+        assert!(second.listener.is_some());
+    }
+}
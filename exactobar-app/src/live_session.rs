@@ -0,0 +1,89 @@
+//! Follows the Claude Code session being written to.
+//!
+//! Claude Code appends every reply to the session's JSONL log as it
+//! arrives. This polls the log's modification time and re-reads it when it
+//! changes, so the Claude card's "Current session" figures keep up with
+//! the conversation instead of waiting for the next refresh.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use exactobar_core::ProviderKind;
+use exactobar_providers::claude::{LiveSession, active_session, read_live_session};
+use gpui::{App, AsyncApp};
+
+use crate::state::AppState;
+
+/// How often the session log is checked for new lines.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How often the projects are searched for a newer session.
+const SCAN_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long a session log can go unwritten before it's no longer current.
+const IDLE_TIMEOUT: chrono::Duration = chrono::Duration::minutes(30);
+
+/// Starts following the active session while Claude is enabled.
+pub fn watch(cx: &mut App) {
+    cx.spawn(async move |cx| {
+        let mut path: Option<PathBuf> = None;
+        let mut modified: Option<SystemTime> = None;
+        let mut scanned_at: Option<Instant> = None;
+
+        loop {
+            smol::Timer::after(POLL_INTERVAL).await;
+
+            let enabled = cx.update(|cx| {
+                cx.global::<AppState>()
+                    .settings
+                    .read(cx)
+                    .is_provider_enabled(ProviderKind::Claude)
+            });
+            if !enabled {
+                if path.take().is_some() {
+                    modified = None;
+                    set_session(None, cx);
+                }
+                continue;
+            }
+
+            if scanned_at.is_none_or(|at| at.elapsed() >= SCAN_INTERVAL) {
+                scanned_at = Some(Instant::now());
+                let active = smol::unblock(|| active_session(IDLE_TIMEOUT)).await;
+                if active != path {
+                    path = active;
+                    modified = None;
+                }
+            }
+
+            let Some(current) = path.clone() else {
+                set_session(None, cx);
+                continue;
+            };
+            let now = modified_at(&current);
+            if now.is_some() && now == modified {
+                continue;
+            }
+            modified = now;
+
+            let session = smol::unblock(move || read_live_session(&current)).await;
+            set_session(session, cx);
+        }
+    })
+    .detach();
+}
+
+fn set_session(session: Option<LiveSession>, cx: &mut AsyncApp) {
+    cx.update(|cx| {
+        let usage = cx.global::<AppState>().usage.clone();
+        usage.update(cx, |model, cx| {
+            model.update_provider(ProviderKind::Claude, cx, |model| {
+                model.set_live_session(session)
+            });
+        });
+    });
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
@@ -0,0 +1,141 @@
+//! In-memory buffer of recent log lines.
+//!
+//! Log output is teed into a small ring buffer so the diagnostics copied
+//! from an error card can include the lines leading up to the failure.
+
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::sync::Mutex;
+
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Maximum number of lines kept in memory.
+const CAPACITY: usize = 500;
+
+/// Most recent log lines, oldest first.
+static RECENT_LINES: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// Returns the last `limit` log lines containing `filter`, oldest first.
+pub fn recent_lines(filter: &str, limit: usize) -> Vec<String> {
+    let lines = RECENT_LINES.lock().unwrap();
+    let mut matching: Vec<String> = lines
+        .iter()
+        .rev()
+        .filter(|line| line.contains(filter))
+        .take(limit)
+        .cloned()
+        .collect();
+    matching.reverse();
+    matching
+}
+
+/// Returns the last `limit` log lines, or none if the buffer is locked.
+///
+/// For the panic hook, which may run while the panicking thread is
+/// recording a line.
+pub fn try_recent_lines(limit: usize) -> Vec<String> {
+    let Ok(lines) = RECENT_LINES.try_lock() else {
+        return Vec::new();
+    };
+    let skip = lines.len().saturating_sub(limit);
+    lines.iter().skip(skip).cloned().collect()
+}
+
+/// Appends formatted log output to the buffer.
+fn record(text: &str) {
+    let mut lines = RECENT_LINES.lock().unwrap();
+    for line in text.lines().filter(|l| !l.trim().is_empty()) {
+        if lines.len() == CAPACITY {
+            lines.pop_front();
+        }
+        lines.push_back(strip_ansi(line));
+    }
+}
+
+/// Removes ANSI color escape sequences (e.g., `\x1b[32m`).
+fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip up to and including the final letter of the sequence
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+// ============================================================================
+// Tee Writer
+// ============================================================================
+
+/// `MakeWriter` that writes to stdout and records each event in the buffer.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TeeWriter;
+
+impl<'a> MakeWriter<'a> for TeeWriter {
+    type Writer = EventWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        EventWriter::default()
+    }
+}
+
+/// Writer for a single log event; recorded when dropped.
+#[derive(Default)]
+pub struct EventWriter {
+    buf: Vec<u8>,
+}
+
+impl Write for EventWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        io::stdout().write_all(buf)?;
+        self.buf.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stdout().flush()
+    }
+}
+
+impl Drop for EventWriter {
+    fn drop(&mut self) {
+        record(&String::from_utf8_lossy(&self.buf));
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(
+            strip_ansi("\x1b[2m2025-01-01\x1b[0m \x1b[31mERROR\x1b[0m failed"),
+            "2025-01-01 ERROR failed"
+        );
+        assert_eq!(strip_ansi("plain"), "plain");
+    }
+
+    #[test]
+    fn test_recent_lines_filters_and_limits() {
+        record("test-recent a1\nother line\ntest-recent a2\n");
+        record("test-recent a3");
+
+        assert_eq!(
+            recent_lines("test-recent", 2),
+            vec!["test-recent a2".to_string(), "test-recent a3".to_string()]
+        );
+    }
+}
@@ -61,16 +61,37 @@
 //!
 //! A macOS menu bar app for monitoring LLM provider usage.
 
+pub mod accessibility;
 pub mod actions;
 pub mod components;
+pub mod crash;
+pub mod custom_theme;
+pub mod dock;
+pub mod history;
+pub mod hotkey;
 pub mod icon;
+pub mod installer;
+pub mod instance;
+pub mod live_session;
+pub mod logs;
+pub mod manifest;
+pub mod mcp;
 pub mod menu;
+pub mod metrics;
+pub mod mqtt;
 pub mod notifications;
+pub mod power;
+pub mod query;
 pub mod refresh;
+pub mod screenshots;
+pub mod server;
 pub mod state;
+pub mod swiftbar;
 pub mod theme;
 pub mod tray;
 pub mod updater;
+pub mod webhooks;
+pub mod websocket;
 pub mod windows;
 
 use gpui::*;
@@ -82,17 +103,48 @@ use crate::tray::SystemTray;
 
 /// Application entry point.
 fn main() {
+    // Launchers read cached usage without starting the GUI
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(filter) = query::parse_args(&args) {
+        query::run(&filter);
+        return;
+    }
+
+    // Screenshots are rendered without opening any windows
+    if let Some(dir) = screenshots::parse_args(&args) {
+        screenshots::run(&dir);
+        return;
+    }
+
     // Initialize logging
     let subscriber = FmtSubscriber::builder()
         .with_max_level(Level::INFO)
         .with_target(false)
+        .with_writer(logs::TeeWriter)
         .finish();
     tracing::subscriber::set_global_default(subscriber).ok();
 
     info!("ExactoBar starting...");
 
+    // Write a crash report if the app panics
+    crash::install_panic_hook();
+
+    // A second launch opens the running instance's menu instead
+    let Some(instance) = instance::acquire() else {
+        info!("ExactoBar is already running");
+        return;
+    };
+
+    // Correct provider labels and links with the last downloaded manifest
+    exactobar_providers::manifest::load_cached();
+
     // Run the GPUI application
-    Application::new().run(|cx: &mut App| {
+    let app = Application::new();
+
+    // Clicking the Dock icon (in Dock icon mode) opens the menu
+    app.on_reopen(dock::reopen);
+
+    app.run(|cx: &mut App| {
         // IMPORTANT: Tray apps must not quit when the popup window closes!
         // On Linux, the default is to quit when last window closes.
         cx.set_quit_mode(QuitMode::Explicit);
@@ -104,6 +156,19 @@ fn main() {
         let state = AppState::init(cx);
         cx.set_global(state);
 
+        // Apply the user's theme file, and any later edits to it
+        custom_theme::watch(cx);
+
+        // Use the accent color picked in System Settings
+        theme::watch_system_accent(cx);
+
+        // List enabled providers in crash reports
+        crash::track_enabled_providers(cx);
+
+        // Send HTTP requests through the proxy, trusting the certificates,
+        // set in settings
+        refresh::track_network(cx);
+
         // Initialize system tray
         let tray = SystemTray::new(cx);
         cx.set_global(tray);
@@ -118,6 +183,24 @@ fn main() {
             tray.start_animation_timer(cx);
         });
 
+        // Draw providers and usage in the colors picked in settings
+        theme::track_provider_colors(cx);
+        theme::track_usage_gradient(cx);
+
+        // Redraw icons as refreshes change provider state
+        cx.update_global::<SystemTray, _>(|tray, cx| {
+            tray.observe_usage(cx);
+        });
+
+        // Show a Dock icon if the user asked for one
+        dock::track(cx);
+
+        // Toggle the menu with the global shortcut, if one is set
+        hotkey::track(cx);
+
+        // Open the menu when the app is launched again
+        instance.listen(cx);
+
         // Debug: write icon PNG to temp file for verification
         #[cfg(debug_assertions)]
         {
@@ -133,15 +216,42 @@ fn main() {
             }
         }
 
-        // Start background refresh task
+        // Load saved usage history, and keep saving it
+        history::start(cx);
+
+        // Start background refresh task, sending requests to the API hosts
+        // set in settings
+        refresh::track_base_urls(cx);
         refresh::spawn_refresh_task(cx);
 
+        // Follow the Claude Code session being written to
+        live_session::watch(cx);
+
+        // Serve integrations (MCP, metrics, WebSocket) on localhost, if enabled
+        server::start(cx);
+
+        // Publish usage over MQTT, if a broker is configured
+        let mqtt = cx.global::<AppState>().settings.read(cx).settings().mqtt.clone();
+        if let Some(mqtt) = mqtt {
+            mqtt::start(mqtt);
+        }
+
         // Check for updates after a short delay (don't block startup)
         spawn_update_check(cx);
 
+        // Keep provider metadata current between releases
+        manifest::start(cx);
+
+        // Offer to report a crash from the last run
+        crash::offer_last_report();
+
         // Check for onboarding - open settings if no providers
         if should_show_onboarding(cx) {
-            windows::open_settings(cx);
+            if starts_silently(cx) {
+                info!("Quiet start, not opening settings for onboarding");
+            } else {
+                windows::open_settings(cx);
+            }
         }
 
         info!("ExactoBar initialized");
@@ -154,17 +264,31 @@ fn should_show_onboarding(cx: &App) -> bool {
     state.enabled_providers(cx).is_empty()
 }
 
+/// Checks if the user asked to start without opening any windows.
+fn starts_silently(cx: &App) -> bool {
+    cx.global::<AppState>().settings.read(cx).start_silently()
+}
+
 /// Spawns a background task to check for updates after a delay.
 ///
-/// This runs 5 seconds after startup to avoid blocking the initial load.
+/// This runs 5 seconds after startup to avoid blocking the initial load. On
+/// a quiet start an available update only gets a system notification.
 fn spawn_update_check(cx: &mut App) {
     cx.spawn(async move |mut cx| {
         // Wait 5 seconds before checking for updates
         smol::Timer::after(std::time::Duration::from_secs(5)).await;
 
+        // Flatpak installs are updated by the Flatpak runtime
+        let method = crate::installer::install_method();
+        if !method.checks_for_updates() {
+            info!(method = ?method, "Skipping update check for this install method");
+            return;
+        }
+
         info!("Starting background update check...");
 
-        let result = crate::updater::check_for_updates().await;
+        let channel = cx.update(|cx| cx.global::<AppState>().settings.read(cx).update_channel());
+        let result = crate::updater::check_for_updates(channel).await;
 
         if let crate::updater::UpdateCheckResult::UpdateAvailable {
             ref current,
@@ -172,9 +296,33 @@ fn spawn_update_check(cx: &mut App) {
             ..
         } = result
         {
+            // Respect "Skip This Version" and "Remind Me Later"
+            let offer = cx.update(|cx| {
+                cx.global::<AppState>()
+                    .settings
+                    .read(cx)
+                    .settings()
+                    .should_offer_update(latest, chrono::Utc::now())
+            });
+            if !offer {
+                info!(
+                    version = latest.as_str(),
+                    "Update skipped or postponed by user"
+                );
+                return;
+            }
+
             // Show system notification about the update
             crate::updater::show_update_notification(current, latest);
 
+            if cx.update(|cx| starts_silently(cx)) {
+                info!(
+                    version = latest.as_str(),
+                    "Quiet start, not showing the update dialog"
+                );
+                return;
+            }
+
             // Show the update dialog
             let _ = cx.update(|cx| {
                 crate::windows::show_update_dialog(&result, cx);
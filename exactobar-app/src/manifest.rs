@@ -0,0 +1,80 @@
+//! Downloads of the provider metadata manifest.
+//!
+//! Fetches the signed manifest (see [`exactobar_providers::manifest`]) a
+//! little after startup and then daily, applying it when it's newer than
+//! the cached one. Failures leave the cached or compiled-in metadata in
+//! place.
+//!
+//! Note: We use `reqwest::blocking` with `smol::unblock()`, as the updater
+//! does, because GPUI uses the smol async runtime.
+
+use std::time::Duration;
+
+use exactobar_providers::manifest::{self, MANIFEST_URL};
+use gpui::App;
+use tracing::{debug, info, warn};
+
+use crate::updater::CURRENT_VERSION;
+
+/// Delay before the first download, to stay out of the way of startup.
+const FIRST_CHECK_DELAY: Duration = Duration::from_secs(30);
+
+/// Interval between downloads.
+const CHECK_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Starts downloading the manifest in the background.
+pub fn start(cx: &mut App) {
+    cx.spawn(async move |cx| {
+        smol::Timer::after(FIRST_CHECK_DELAY).await;
+        loop {
+            let applied = smol::unblock(download).await;
+            if applied {
+                // Redraw labels and links from the corrected metadata
+                let _ = cx.update(|cx| cx.refresh_windows());
+            }
+            smol::Timer::after(CHECK_INTERVAL).await;
+        }
+    })
+    .detach();
+}
+
+/// Downloads and installs the manifest, returning true if it was applied.
+fn download() -> bool {
+    let client = match exactobar_fetch::network::blocking_client_builder()
+        .user_agent(format!("ExactoBar/{}", CURRENT_VERSION))
+        .timeout(Duration::from_secs(10))
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => {
+            warn!(error = ?e, "Failed to create HTTP client for provider manifest");
+            return false;
+        }
+    };
+
+    let bytes = match client
+        .get(MANIFEST_URL)
+        .send()
+        .and_then(|r| r.error_for_status())
+        .and_then(|r| r.bytes())
+    {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            debug!(error = ?e, "Provider manifest not downloaded");
+            return false;
+        }
+    };
+
+    match manifest::install(&bytes) {
+        Ok(applied) => {
+            if applied {
+                info!(version = ?manifest::applied_version(), "Updated provider metadata");
+            }
+            applied
+        }
+        Err(e) => {
+            warn!(error = %e, "Rejected provider manifest");
+            false
+        }
+    }
+}
@@ -0,0 +1,246 @@
+//! MCP (Model Context Protocol) server.
+//!
+//! Exposes a `get_usage` tool over MCP's Streamable HTTP transport so coding
+//! agents can check how much of a quota window is left and pace themselves.
+//! Served at `/mcp` by the local server (see [`crate::server`]). Register it
+//! with, e.g.:
+//!
+//! ```text
+//! claude mcp add --transport http exactobar http://127.0.0.1:47821/mcp
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+
+use crate::server::{ProviderUsage, Response};
+
+/// Protocol revisions we speak; the first is offered when the client's isn't listed.
+const PROTOCOL_VERSIONS: [&str; 3] = ["2025-06-18", "2025-03-26", "2024-11-05"];
+
+// ============================================================================
+// HTTP
+// ============================================================================
+
+/// Handles a request to the MCP endpoint.
+pub fn handle_request(method: &str, body: &[u8], usage: &[ProviderUsage]) -> Response {
+    if method != "POST" {
+        // No server-initiated messages, so no SSE stream to open
+        return Response::empty("405 Method Not Allowed");
+    }
+
+    let reply = match serde_json::from_slice::<Value>(body) {
+        Ok(message) => handle_message(&message, usage, Utc::now()),
+        Err(e) => Some(error_response(
+            &Value::Null,
+            -32700,
+            &format!("Parse error: {}", e),
+        )),
+    };
+
+    match reply {
+        Some(reply) => Response::new("200 OK", "application/json", reply.to_string()),
+        // Notifications and responses get no reply
+        None => Response::empty("202 Accepted"),
+    }
+}
+
+// ============================================================================
+// JSON-RPC
+// ============================================================================
+
+/// Handles a JSON-RPC message, returning the response to send, if any.
+fn handle_message(message: &Value, usage: &[ProviderUsage], now: DateTime<Utc>) -> Option<Value> {
+    let method = message.get("method").and_then(Value::as_str)?;
+    // Requests carry an id; notifications don't and get no response
+    let id = message.get("id")?;
+    let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+    let result = match method {
+        "initialize" => {
+            let requested = params.get("protocolVersion").and_then(Value::as_str);
+            let version = requested
+                .filter(|v| PROTOCOL_VERSIONS.contains(v))
+                .unwrap_or(PROTOCOL_VERSIONS[0]);
+            json!({
+                "protocolVersion": version,
+                "capabilities": { "tools": {} },
+                "serverInfo": { "name": "exactobar", "version": env!("CARGO_PKG_VERSION") },
+                "instructions": "Call get_usage to see how much of each LLM provider quota window is left and when it resets.",
+            })
+        }
+        "ping" => json!({}),
+        "tools/list" => json!({ "tools": [get_usage_tool()] }),
+        "tools/call" => {
+            let name = params.get("name").and_then(Value::as_str).unwrap_or("");
+            if name != "get_usage" {
+                return Some(error_response(
+                    id,
+                    -32602,
+                    &format!("Unknown tool: {}", name),
+                ));
+            }
+            let provider = params
+                .get("arguments")
+                .and_then(|a| a.get("provider"))
+                .and_then(Value::as_str);
+            get_usage(usage, provider, now)
+        }
+        _ => {
+            return Some(error_response(
+                id,
+                -32601,
+                &format!("Method not found: {}", method),
+            ));
+        }
+    };
+
+    Some(json!({ "jsonrpc": "2.0", "id": id, "result": result }))
+}
+
+fn error_response(id: &Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+fn get_usage_tool() -> Value {
+    json!({
+        "name": "get_usage",
+        "title": "Get LLM usage",
+        "description": "Returns how much of each enabled LLM provider's usage windows \
+            (e.g. Claude's 5-hour session and weekly limits) is used and left, and when \
+            each window resets.",
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "provider": {
+                    "type": "string",
+                    "description": "Only return this provider, e.g. \"claude\" or \"codex\".",
+                },
+            },
+        },
+    })
+}
+
+/// Runs the `get_usage` tool.
+fn get_usage(usage: &[ProviderUsage], provider: Option<&str>, now: DateTime<Utc>) -> Value {
+    let selected: Vec<&ProviderUsage> = usage
+        .iter()
+        .filter(|u| provider.is_none_or(|p| u.provider.eq_ignore_ascii_case(p)))
+        .collect();
+
+    if selected.is_empty() {
+        let text = match provider {
+            Some(p) => format!("Provider \"{}\" is not enabled in ExactoBar.", p),
+            None => "No providers are enabled in ExactoBar.".to_string(),
+        };
+        return json!({ "content": [{ "type": "text", "text": text }], "isError": true });
+    }
+
+    let text = selected
+        .iter()
+        .map(|u| summary(u, now))
+        .collect::<Vec<_>>()
+        .join("\n");
+    json!({
+        "content": [{ "type": "text", "text": text }],
+        "structuredContent": { "providers": selected },
+    })
+}
+
+/// Formats a one-line summary, e.g. "Claude: Session 62% left (resets in 1h 20m)".
+fn summary(usage: &ProviderUsage, now: DateTime<Utc>) -> String {
+    if usage.windows.is_empty() {
+        let reason = usage.error.as_deref().unwrap_or("no usage data yet");
+        return format!(
+            "{}: {}",
+            usage.name,
+            reason.lines().next().unwrap_or(reason)
+        );
+    }
+
+    let windows: Vec<String> = usage
+        .windows
+        .iter()
+        .map(|w| {
+            let mut text = format!("{} {:.0}% left", w.label, w.remaining_percent);
+            if let Some(resets_at) = w.resets_at {
+                text.push_str(&format!(
+                    " (resets in {})",
+                    format_remaining(resets_at, now)
+                ));
+            }
+            text
+        })
+        .collect();
+    format!("{}: {}", usage.name, windows.join(", "))
+}
+
+/// Formats the time until `at`, e.g. "2d 3h", "1h 20m", or "5m".
+fn format_remaining(at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let minutes = (at - now).num_minutes().max(0);
+    let (days, hours, mins) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, mins)
+    } else {
+        format!("{}m", mins)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exactobar_core::{ProviderKind, UsageSnapshot, UsageWindow};
+
+    fn claude_usage(now: DateTime<Utc>) -> ProviderUsage {
+        let mut session = UsageWindow::new(38.0);
+        session.resets_at = Some(now + chrono::Duration::minutes(80));
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(session);
+        ProviderUsage::new(ProviderKind::Claude, Some(&snapshot), None)
+    }
+
+    #[test]
+    fn test_initialize_and_notifications() {
+        let now = Utc::now();
+        let init = json!({
+            "jsonrpc": "2.0", "id": 1, "method": "initialize",
+            "params": { "protocolVersion": "2025-03-26" },
+        });
+        let reply = handle_message(&init, &[], now).unwrap();
+        assert_eq!(reply["result"]["protocolVersion"], "2025-03-26");
+
+        let note = json!({ "jsonrpc": "2.0", "method": "notifications/initialized" });
+        assert!(handle_message(&note, &[], now).is_none());
+
+        let unknown = json!({ "jsonrpc": "2.0", "id": 2, "method": "resources/list" });
+        assert_eq!(
+            handle_message(&unknown, &[], now).unwrap()["error"]["code"],
+            -32601
+        );
+    }
+
+    #[test]
+    fn test_get_usage() {
+        let now = Utc::now();
+        let usage = [claude_usage(now)];
+        let call = json!({
+            "jsonrpc": "2.0", "id": 3, "method": "tools/call",
+            "params": { "name": "get_usage", "arguments": { "provider": "claude" } },
+        });
+        let result = &handle_message(&call, &usage, now).unwrap()["result"];
+        let text = result["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("62% left (resets in 1h 20m)"), "{}", text);
+        assert_eq!(
+            result["structuredContent"]["providers"][0]["provider"],
+            "claude"
+        );
+
+        let missing = get_usage(&usage, Some("codex"), now);
+        assert_eq!(missing["isError"], true);
+    }
+}
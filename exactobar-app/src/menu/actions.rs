@@ -1,13 +1,18 @@
 //! Action button components for quick provider actions.
 //!
 //! Provides Dashboard, Status, and Buy Credits buttons that open
-//! external URLs based on provider metadata.
+//! external URLs based on provider metadata, or the URLs set for them in the
+//! settings. Users can replace them per provider with their own buttons (a
+//! URL or a shell command).
 
 use exactobar_core::ProviderKind;
 use exactobar_providers::ProviderRegistry;
+use exactobar_store::{ProviderLink, QuickAction, QuickActionTarget, Settings};
 use gpui::prelude::FluentBuilder;
 use gpui::*;
+use tracing::{info, warn};
 
+use crate::state::AppState;
 use crate::theme;
 
 // ============================================================================
@@ -35,35 +40,121 @@ pub fn open_url(url: &str) {
     }
 }
 
+/// Runs a user-configured shell command in the background.
+pub fn run_command(command: &str) {
+    use std::process::Command;
+
+    info!(command = %command, "Running quick action command");
+
+    #[cfg(unix)]
+    let result = Command::new("sh").args(["-c", command]).spawn();
+
+    #[cfg(windows)]
+    let result = Command::new("cmd").args(["/C", command]).spawn();
+
+    if let Err(e) = result {
+        warn!(error = %e, command = %command, "Failed to run quick action command");
+    }
+}
+
+/// Runs a quick action's target.
+pub fn run_quick_action(target: &QuickActionTarget) {
+    match target {
+        QuickActionTarget::Url(url) => open_url(url),
+        QuickActionTarget::Command(command) => run_command(command),
+    }
+}
+
+/// Returns the built-in buttons for a provider (Dashboard, Status, Buy Credits).
+///
+/// URLs set in the settings take the place of the provider's own pages.
+pub fn default_quick_actions(provider: ProviderKind, settings: &Settings) -> Vec<QuickAction> {
+    let metadata = ProviderRegistry::get(provider).map(|d| &d.metadata);
+    let link = |link: ProviderLink, own: Option<&String>| {
+        settings
+            .link_url(provider, link)
+            .or(own.map(String::as_str))
+    };
+
+    let mut actions = Vec::new();
+
+    // Dashboard button
+    if let Some(url) = link(
+        ProviderLink::Dashboard,
+        metadata.and_then(|m| m.dashboard_url.as_ref()),
+    ) {
+        actions.push(QuickAction::url("Dashboard", url));
+    }
+
+    // Status button
+    if let Some(url) = link(
+        ProviderLink::Status,
+        metadata.and_then(|m| m.status_link_url.as_ref()),
+    ) {
+        actions.push(QuickAction::url("Status", url));
+    }
+
+    // Buy Credits button (only if provider supports credits, or a billing
+    // portal is set)
+    if let Some(url) = link(
+        ProviderLink::Billing,
+        metadata
+            .filter(|m| m.supports_credits)
+            .and_then(|m| m.subscription_dashboard_url.as_ref()),
+    ) {
+        actions.push(QuickAction::url("Buy Credits...", url));
+    }
+
+    actions
+}
+
 // ============================================================================
 // Action Buttons Section (Dashboard, Status, Buy Credits)
 // ============================================================================
 
 pub struct ActionButtonsSection {
-    dashboard_url: Option<String>,
-    status_url: Option<String>,
-    supports_credits: bool,
-    subscription_url: Option<String>,
+    actions: Vec<QuickAction>,
+    /// Whether the built-in buttons are shown (they have shortcuts).
+    defaults: bool,
+    /// Index of the keyboard-focused button, if any.
+    focused: Option<usize>,
 }
 
 impl ActionButtonsSection {
-    pub fn new(provider: ProviderKind) -> Self {
-        let descriptor = ProviderRegistry::get(provider);
-        let metadata = descriptor.map(|d| &d.metadata);
+    /// Creates the section with the user's buttons, or the provider defaults.
+    pub fn new(provider: ProviderKind, cx: &App) -> Self {
+        let settings = cx.global::<AppState>().settings.read(cx);
+        let custom = settings
+            .quick_actions(provider)
+            .map(<[QuickAction]>::to_vec);
+        Self::with_actions(custom, default_quick_actions(provider, settings.settings()))
+    }
 
+    /// Creates the section with `custom` buttons (None = `defaults`).
+    pub fn with_actions(custom: Option<Vec<QuickAction>>, defaults: Vec<QuickAction>) -> Self {
         Self {
-            dashboard_url: metadata.and_then(|m| m.dashboard_url.clone()),
-            status_url: metadata.and_then(|m| m.status_link_url.clone()),
-            supports_credits: metadata.is_some_and(|m| m.supports_credits),
-            subscription_url: metadata.and_then(|m| m.subscription_dashboard_url.clone()),
+            defaults: custom.is_none(),
+            actions: custom.unwrap_or(defaults),
+            focused: None,
         }
     }
 
-    /// Returns true if there's at least one button to show.
-    fn has_buttons(&self) -> bool {
-        self.dashboard_url.is_some()
-            || self.status_url.is_some()
-            || (self.supports_credits && self.subscription_url.is_some())
+    /// Highlights the button at `index` as keyboard-focused.
+    pub fn focused(mut self, index: Option<usize>) -> Self {
+        self.focused = index;
+        self
+    }
+
+    /// Returns the number of buttons shown for a provider.
+    pub fn button_count(provider: ProviderKind, cx: &App) -> usize {
+        Self::new(provider, cx).actions.len()
+    }
+
+    /// Activates the button at `index` for a provider (keyboard Enter).
+    pub fn activate(provider: ProviderKind, index: usize, cx: &App) {
+        if let Some(action) = Self::new(provider, cx).actions.get(index) {
+            run_quick_action(&action.target);
+        }
     }
 }
 
@@ -71,43 +162,40 @@ impl IntoElement for ActionButtonsSection {
     type Element = Div;
 
     fn into_element(self) -> Self::Element {
-        if !self.has_buttons() {
+        if self.actions.is_empty() {
             return div();
         }
 
-        let mut row = div()
+        let focused = self.focused;
+        let defaults = self.defaults;
+
+        div()
             .px(px(14.))
             .py(px(8.))
             .bg(theme::card_background())
             .border_b_1()
             .border_color(theme::glass_separator())
             .flex()
-            .gap(px(6.));
-
-        // Dashboard button
-        if let Some(url) = self.dashboard_url.clone() {
-            row = row.child(ActionButton::new("Dashboard", "⌘D", move || {
-                open_url(&url);
-            }));
-        }
-
-        // Status button
-        if let Some(url) = self.status_url.clone() {
-            row = row.child(ActionButton::new("Status", "", move || {
-                open_url(&url);
-            }));
-        }
-
-        // Buy Credits button (only if provider supports credits)
-        if self.supports_credits {
-            if let Some(url) = self.subscription_url.clone() {
-                row = row.child(ActionButton::new("Buy Credits...", "", move || {
-                    open_url(&url);
-                }));
-            }
-        }
-
-        row
+            .gap(px(6.))
+            .children(
+                self.actions
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(i, action)| {
+                        // Only the built-in Dashboard button has a shortcut
+                        let shortcut = if defaults && action.label == "Dashboard" {
+                            "⌘D"
+                        } else {
+                            ""
+                        };
+                        let target = action.target;
+                        ActionButton::new(action.label, shortcut, move || {
+                            run_quick_action(&target);
+                        })
+                        .id(i)
+                        .focused(focused == Some(i))
+                    }),
+            )
     }
 }
 
@@ -116,19 +204,38 @@ impl IntoElement for ActionButtonsSection {
 // ============================================================================
 
 struct ActionButton {
-    label: &'static str,
+    label: SharedString,
     shortcut: &'static str,
     action: Box<dyn Fn() + 'static>,
+    index: usize,
+    focused: bool,
 }
 
 impl ActionButton {
-    fn new<F: Fn() + 'static>(label: &'static str, shortcut: &'static str, action: F) -> Self {
+    fn new<F: Fn() + 'static>(
+        label: impl Into<SharedString>,
+        shortcut: &'static str,
+        action: F,
+    ) -> Self {
         Self {
-            label,
+            label: label.into(),
             shortcut,
             action: Box::new(action),
+            index: 0,
+            focused: false,
         }
     }
+
+    /// Position in the section; keeps element IDs unique when labels repeat.
+    fn id(mut self, index: usize) -> Self {
+        self.index = index;
+        self
+    }
+
+    fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
 }
 
 impl IntoElement for ActionButton {
@@ -140,7 +247,10 @@ impl IntoElement for ActionButton {
         let shortcut = self.shortcut;
 
         let mut btn = div()
-            .id(SharedString::from(format!("action-{}", label)))
+            .id(SharedString::from(format!(
+                "action-{}-{}",
+                self.index, label
+            )))
             .px(px(8.))
             .py(px(4.))
             .rounded(px(4.))
@@ -149,7 +259,11 @@ impl IntoElement for ActionButton {
             .text_color(theme::text_secondary())
             .bg(theme::surface())
             .border_1()
-            .border_color(theme::border())
+            .border_color(if self.focused {
+                theme::accent()
+            } else {
+                theme::border()
+            })
             .hover(|s| s.bg(theme::hover()))
             .active(|s| s.bg(theme::active()))
             .on_mouse_down(MouseButton::Left, move |_, _window, _cx| {
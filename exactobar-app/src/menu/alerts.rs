@@ -0,0 +1,108 @@
+//! Recent alerts section at the bottom of the "All" view.
+//!
+//! System notifications vanish after a few seconds; this lists the ones
+//! delivered since launch, newest first, until they're cleared.
+
+use chrono::Utc;
+use exactobar_store::TimeFormat;
+use gpui::*;
+
+use crate::notifications::{self, Alert};
+use crate::theme;
+
+// ============================================================================
+// Recent Alerts Section
+// ============================================================================
+
+pub struct RecentAlertsSection {
+    alerts: Vec<Alert>,
+    time_format: TimeFormat,
+}
+
+impl RecentAlertsSection {
+    /// Creates the section from the alert log, or `None` if no alerts were
+    /// delivered.
+    pub fn new(time_format: TimeFormat) -> Option<Self> {
+        let alerts = notifications::recent_alerts();
+        (!alerts.is_empty()).then_some(Self {
+            alerts,
+            time_format,
+        })
+    }
+}
+
+impl IntoElement for RecentAlertsSection {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let time_format = self.time_format;
+        let now = Utc::now();
+        let hover_bg = theme::hover();
+
+        let header = div()
+            .flex()
+            .items_center()
+            .justify_between()
+            .child(
+                div()
+                    .text_xs()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(theme::text_secondary())
+                    .child("Recent Alerts"),
+            )
+            .child(
+                div()
+                    .id("clear-alerts")
+                    .px(px(6.))
+                    .py(px(2.))
+                    .rounded(px(4.))
+                    .cursor_pointer()
+                    .text_xs()
+                    .text_color(theme::accent())
+                    .hover(move |s| s.bg(hover_bg))
+                    .on_click(|_, window, _| {
+                        notifications::clear_alerts();
+                        window.refresh();
+                    })
+                    .child("Clear All"),
+            );
+
+        div()
+            .px(px(14.))
+            .py(px(10.))
+            .border_t_1()
+            .border_color(theme::glass_separator())
+            .flex()
+            .flex_col()
+            .gap(px(6.))
+            .child(header)
+            .children(self.alerts.into_iter().map(move |alert| {
+                div()
+                    .flex()
+                    .gap(px(8.))
+                    .text_xs()
+                    .child(
+                        div()
+                            .w(px(64.))
+                            .flex_shrink_0()
+                            .text_color(theme::muted())
+                            // Names the day for alerts from before today
+                            .child(time_format.format_reset(alert.delivered_at, now)),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w(px(0.))
+                            .flex()
+                            .flex_col()
+                            .child(
+                                div()
+                                    .font_weight(FontWeight::MEDIUM)
+                                    .text_color(theme::text_primary())
+                                    .child(alert.title),
+                            )
+                            .child(div().text_color(theme::text_secondary()).child(alert.body)),
+                    )
+            }))
+    }
+}
@@ -0,0 +1,187 @@
+//! Per-model usage breakdown for the menu card.
+//!
+//! A disclosure row that expands to a compact table of requests,
+//! tokens, and cost per model. Collapsed by default.
+
+use exactobar_core::{ModelBreakdown, format_currency};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+
+use crate::theme;
+
+/// Click handler for the disclosure row.
+pub type ToggleHandler = Box<dyn Fn(&MouseDownEvent, &mut Window, &mut App) + 'static>;
+
+// ============================================================================
+// Model Breakdown Section
+// ============================================================================
+
+pub struct ModelBreakdownSection {
+    id: SharedString,
+    models: Vec<ModelBreakdown>,
+    expanded: bool,
+    on_toggle: Option<ToggleHandler>,
+}
+
+impl ModelBreakdownSection {
+    pub fn new(id: impl Into<SharedString>, models: &[ModelBreakdown], expanded: bool) -> Self {
+        Self {
+            id: id.into(),
+            models: models.to_vec(),
+            expanded,
+            on_toggle: None,
+        }
+    }
+
+    /// Sets the handler invoked when the disclosure row is clicked.
+    pub fn on_toggle(mut self, handler: Option<ToggleHandler>) -> Self {
+        self.on_toggle = handler;
+        self
+    }
+}
+
+impl IntoElement for ModelBreakdownSection {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let chevron = if self.expanded { "▾" } else { "▸" };
+        let count = self.models.len();
+        let summary = if count == 1 {
+            "1 model".to_string()
+        } else {
+            format!("{} models", count)
+        };
+
+        let mut disclosure = div()
+            .id(self.id)
+            .flex()
+            .items_center()
+            .justify_between()
+            .cursor_pointer()
+            .rounded(px(4.))
+            .hover(|s| s.bg(theme::hover()))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap(px(6.))
+                    .child(div().text_xs().text_color(theme::muted()).child(chevron))
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_weight(FontWeight::MEDIUM)
+                            .text_color(theme::text_primary())
+                            .child("Models"),
+                    ),
+            )
+            .child(div().text_xs().text_color(theme::muted()).child(summary));
+
+        if let Some(handler) = self.on_toggle {
+            disclosure = disclosure.on_mouse_down(MouseButton::Left, handler);
+        }
+
+        div()
+            .px(px(14.))
+            .py(px(8.))
+            .bg(theme::card_background())
+            .border_b_1()
+            .border_color(theme::glass_separator())
+            .flex()
+            .flex_col()
+            .gap(px(6.))
+            .child(disclosure)
+            .when(self.expanded, |el| {
+                el.child(ModelBreakdownHeader)
+                    .children(self.models.into_iter().map(ModelBreakdownRow))
+            })
+    }
+}
+
+// ============================================================================
+// Table Rows
+// ============================================================================
+
+/// Column widths shared by the header and rows.
+const REQUESTS_COLUMN_WIDTH: f32 = 56.;
+const TOKENS_COLUMN_WIDTH: f32 = 56.;
+const COST_COLUMN_WIDTH: f32 = 56.;
+
+fn table_row(name: Div, requests: Div, tokens: Div, cost: Div) -> Div {
+    div()
+        .flex()
+        .items_center()
+        .gap(px(6.))
+        .child(name.flex_1().min_w(px(0.)).overflow_hidden())
+        .child(requests.w(px(REQUESTS_COLUMN_WIDTH)).text_right())
+        .child(tokens.w(px(TOKENS_COLUMN_WIDTH)).text_right())
+        .child(cost.w(px(COST_COLUMN_WIDTH)).text_right())
+}
+
+struct ModelBreakdownHeader;
+
+impl IntoElement for ModelBreakdownHeader {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let cell = |label: &'static str| div().text_xs().text_color(theme::muted()).child(label);
+        table_row(
+            cell("Model"),
+            cell("Requests"),
+            cell("Tokens"),
+            cell("Cost"),
+        )
+    }
+}
+
+struct ModelBreakdownRow(ModelBreakdown);
+
+impl IntoElement for ModelBreakdownRow {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let model = self.0;
+        let tokens = model.total_tokens();
+
+        let requests = model
+            .requests
+            .map(|n| format_count(n as f64))
+            .unwrap_or_else(|| "—".to_string());
+        let tokens = if tokens > 0 {
+            format_count(tokens as f64)
+        } else {
+            "—".to_string()
+        };
+        let cost = model
+            .cost_usd
+            .map(|c| format_currency(c, "USD"))
+            .unwrap_or_else(|| "—".to_string());
+
+        let cell = |text: String| {
+            div()
+                .text_xs()
+                .text_color(theme::text_secondary())
+                .child(text)
+        };
+
+        table_row(
+            div()
+                .text_xs()
+                .text_color(theme::text_primary())
+                .child(model.model_name),
+            cell(requests),
+            cell(tokens),
+            cell(cost),
+        )
+    }
+}
+
+/// Formats a count compactly (e.g., "1.2K", "3.4M").
+pub(super) fn format_count(n: f64) -> String {
+    if n >= 1_000_000.0 {
+        format!("{:.1}M", n / 1_000_000.0)
+    } else if n >= 1_000.0 {
+        format!("{:.1}K", n / 1_000.0)
+    } else {
+        format!("{:.0}", n)
+    }
+}
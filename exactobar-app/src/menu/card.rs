@@ -3,8 +3,17 @@
 //! The MenuCard shows provider identity, status, usage metrics,
 //! and action buttons in a cohesive card layout.
 
-use exactobar_core::{ProviderKind, UsageSnapshot};
-use exactobar_providers::ProviderRegistry;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use exactobar_core::{
+    PricingTable, ProviderError, ProviderKind, ProviderStatus, UsageSnapshot, WindowKind,
+};
+use exactobar_providers::claude::LiveSession;
+use exactobar_providers::{ProjectUsage, ProviderRegistry};
+use exactobar_store::QuickAction;
 use gpui::prelude::FluentBuilder;
 use gpui::*;
 
@@ -12,14 +21,22 @@ use crate::components::{ProviderIcon, Spinner};
 use crate::state::AppState;
 use crate::theme;
 
-use super::actions::ActionButtonsSection;
+use super::actions::{ActionButtonsSection, default_quick_actions};
+use super::breakdown::{ModelBreakdownSection, ToggleHandler};
+use super::debug::DebugSection;
 use super::error::{EnhancedErrorSection, InstallHint, get_install_hint};
-use super::usage::UsageMetricsSection;
+use super::incident::StatusIncidentSection;
+use super::projects::{TodayRow, TopProjectsSection};
+use super::session::CurrentSessionSection;
+use super::spend::SpendRow;
+use super::summary;
+use super::usage::{ResetDisplay, UsageMetricsSection, format_elapsed};
 
 // ============================================================================
 // Menu Card Data
 // ============================================================================
 
+#[derive(Clone)]
 pub struct MenuCardData {
     pub provider: ProviderKind,
     pub provider_name: String,
@@ -28,14 +45,65 @@ pub struct MenuCardData {
     pub snapshot: Option<UsageSnapshot>,
     pub is_refreshing: bool,
     pub error: Option<String>,
+    /// What kind of failure the last fetch was, if it failed this session
+    pub failure: Option<ProviderError>,
+    /// Whether the provider rejected its credentials
+    pub needs_auth: bool,
+    /// Whether scheduled refreshes stopped after repeated errors
+    pub paused: bool,
+    /// Status page state, when it reports trouble
+    pub status: Option<ProviderStatus>,
     /// Install hint when CLI is missing
     pub install_hint: Option<InstallHint>,
     pub session_label: &'static str,
     pub weekly_label: &'static str,
     /// Whether to show "X% used" instead of "X% remaining"
     pub show_used: bool,
-    /// Whether to show "Resets at 3:00 PM" instead of "Resets in 2h 30m"
-    pub show_absolute: bool,
+    /// How to show reset times
+    pub resets: ResetDisplay,
+    /// Recorded usage per window since its last reset (for sparklines)
+    pub sparklines: HashMap<WindowKind, Vec<f64>>,
+    /// Time until each window is used up at the recent burn rate
+    pub limit_etas: HashMap<WindowKind, chrono::Duration>,
+    /// Currency code used to format spend
+    pub currency_code: String,
+    /// User-configured monthly budget (fallback when the provider reports no limit)
+    pub monthly_budget: Option<f64>,
+    /// Spend estimated from tokens per model, in USD, when the provider
+    /// reports no cost
+    pub estimated_spend: Option<f64>,
+    /// How long after a refresh the data is shown as stale
+    pub stale_after: Duration,
+    /// Whether the per-model breakdown is expanded
+    pub breakdown_expanded: bool,
+    /// Whether the full error details are expanded
+    pub error_expanded: bool,
+    /// Whether the status incident panel is expanded
+    pub incident_expanded: bool,
+    /// Index of the keyboard-focused action button, if any
+    pub focused_action: Option<usize>,
+    /// Whether the provider is pinned to the top of the menu
+    pub pinned: bool,
+    /// Whether to collapse the card to a single line
+    pub compact: bool,
+    /// Whether the card can be collapsed to its header (only in the "All" view)
+    pub collapsible: bool,
+    /// Whether the card is collapsed to its header
+    pub collapsed: bool,
+    /// Whether to show the raw JSON debug view
+    pub show_debug: bool,
+    /// Last raw provider response (for the debug view)
+    pub raw_response: Option<String>,
+    /// User-configured action buttons (None = provider defaults)
+    pub quick_actions: Option<Vec<QuickAction>>,
+    /// Built-in buttons, shown when there are no custom ones
+    pub default_actions: Vec<QuickAction>,
+    /// Tokens used per project today, most first (CLI providers only)
+    pub projects: Vec<ProjectUsage>,
+    /// The Claude Code session being written to (Claude only)
+    pub live_session: Option<LiveSession>,
+    /// Cost of the live session so far, estimated in USD
+    pub live_session_cost: Option<f64>,
 }
 
 impl MenuCardData {
@@ -44,12 +112,51 @@ impl MenuCardData {
         let snapshot = state.get_snapshot(provider, cx);
         let is_refreshing = state.is_provider_refreshing(provider, cx);
         let error = state.get_error(provider, cx);
+        let failure = state.get_failure(provider, cx);
+        let needs_auth = failure.as_ref().is_some_and(ProviderError::is_auth);
+        let paused = state.is_paused(provider, cx);
+        let status = state
+            .get_status(provider, cx)
+            .filter(ProviderStatus::has_issues);
+        let raw_response = state.get_raw_response(provider, cx);
+        let projects = state.get_projects(provider, cx);
+        let live_session = (provider == ProviderKind::Claude)
+            .then(|| state.get_live_session(cx))
+            .flatten();
         let descriptor = ProviderRegistry::get(provider);
 
         // Read display settings
         let settings = state.settings.read(cx).settings();
         let show_used = settings.usage_bars_show_used;
-        let show_absolute = settings.reset_times_show_absolute;
+        let resets = ResetDisplay {
+            absolute: settings.reset_times_show_absolute,
+            time_format: settings.time_format,
+        };
+        let pinned = settings.is_provider_pinned(provider);
+        let compact = settings.compact_menu;
+        let collapsed = settings.is_provider_collapsed(provider);
+        let show_debug = settings.debug_mode;
+        let currency_code = settings.currency_code.clone();
+        let stale_after = settings.stale_after(provider);
+        let monthly_budget = settings
+            .provider_settings
+            .get(&provider)
+            .and_then(|ps| ps.monthly_budget);
+        let quick_actions = settings
+            .provider_settings
+            .get(&provider)
+            .and_then(|ps| ps.quick_actions.clone());
+        let default_actions = default_quick_actions(provider, settings);
+        let estimated_spend = snapshot
+            .as_ref()
+            .filter(|s| s.cost.is_none() && !s.model_breakdown.is_empty())
+            .and_then(|s| {
+                PricingTable::with_overrides(&settings.model_prices).estimate(&s.model_breakdown)
+            });
+
+        let live_session_cost = live_session.as_ref().and_then(|session| {
+            PricingTable::with_overrides(&settings.model_prices).estimate(&session.models)
+        });
 
         let provider_name = descriptor
             .map(|d| d.display_name().to_string())
@@ -72,7 +179,18 @@ impl MenuCardData {
         let plan = identity.and_then(|i| i.plan_name.clone());
 
         // Detect install hints for missing CLIs
-        let install_hint = error.as_ref().and_then(|e| get_install_hint(provider, e));
+        let install_hint = error
+            .as_ref()
+            .and_then(|e| get_install_hint(provider, failure.as_ref(), e));
+
+        let sparklines = WindowKind::all()
+            .iter()
+            .map(|&kind| (kind, state.get_trajectory(provider, kind, cx)))
+            .collect();
+        let limit_etas = WindowKind::all()
+            .iter()
+            .filter_map(|&kind| Some((kind, state.get_time_until_limit(provider, kind, cx)?)))
+            .collect();
 
         Self {
             provider,
@@ -82,11 +200,36 @@ impl MenuCardData {
             snapshot,
             is_refreshing,
             error,
+            failure,
+            needs_auth,
+            paused,
+            status,
             install_hint,
             session_label,
             weekly_label,
             show_used,
-            show_absolute,
+            resets,
+            sparklines,
+            limit_etas,
+            currency_code,
+            monthly_budget,
+            estimated_spend,
+            stale_after,
+            breakdown_expanded: false,
+            error_expanded: false,
+            incident_expanded: false,
+            focused_action: None,
+            pinned,
+            compact,
+            collapsible: false,
+            collapsed,
+            show_debug,
+            raw_response,
+            quick_actions,
+            default_actions,
+            projects,
+            live_session,
+            live_session_cost,
         }
     }
 }
@@ -97,11 +240,57 @@ impl MenuCardData {
 
 pub struct MenuCard {
     data: MenuCardData,
+    on_toggle_breakdown: Option<ToggleHandler>,
+    on_toggle_debug: Option<ToggleHandler>,
+    on_toggle_error: Option<ToggleHandler>,
+    on_toggle_incident: Option<ToggleHandler>,
 }
 
 impl MenuCard {
     pub fn new(data: MenuCardData) -> Self {
-        Self { data }
+        Self {
+            data,
+            on_toggle_breakdown: None,
+            on_toggle_debug: None,
+            on_toggle_error: None,
+            on_toggle_incident: None,
+        }
+    }
+
+    /// Sets the handler for expanding/collapsing the per-model breakdown.
+    pub fn on_toggle_breakdown(
+        mut self,
+        handler: impl Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_toggle_breakdown = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets the handler for Option-clicking the header (toggles the debug view).
+    pub fn on_toggle_debug(
+        mut self,
+        handler: impl Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_toggle_debug = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets the handler for expanding/collapsing the full error details.
+    pub fn on_toggle_error(
+        mut self,
+        handler: impl Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_toggle_error = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets the handler for expanding/collapsing the status incident panel.
+    pub fn on_toggle_incident(
+        mut self,
+        handler: impl Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_toggle_incident = Some(Box::new(handler));
+        self
     }
 }
 
@@ -117,6 +306,18 @@ impl IntoElement for MenuCard {
             is_refreshing = self.data.is_refreshing,
             "MenuCard rendering"
         );
+
+        if self.data.compact {
+            return CompactCardRow::new(&self.data).into_element();
+        }
+
+        let stale = !self.data.is_refreshing
+            && self.data.snapshot.as_ref().is_some_and(|s| {
+                (Utc::now() - s.updated_at)
+                    .to_std()
+                    .is_ok_and(|age| age > self.data.stale_after)
+            });
+
         let mut card = div().flex().flex_col();
 
         // Header section
@@ -127,31 +328,116 @@ impl IntoElement for MenuCard {
             plan: self.data.plan.clone(),
             is_refreshing: self.data.is_refreshing,
             has_error: self.data.error.is_some(),
+            failure_label: self.data.failure.as_ref().map(ProviderError::label),
+            needs_auth: self.data.needs_auth,
+            updated_at: self.data.snapshot.as_ref().map(|s| s.updated_at),
+            stale,
+            pinned: self.data.pinned,
+            collapse: self.data.collapsible.then_some(self.data.collapsed),
+            on_option_click: self.on_toggle_debug,
         });
 
+        // Collapsed cards show only their header
+        if self.data.collapsible && self.data.collapsed {
+            return card;
+        }
+
+        // Status page trouble, expanding to the incidents
+        if let Some(status) = self.data.status.clone() {
+            card = card.child(
+                StatusIncidentSection::new(
+                    format!("incident-{:?}", provider),
+                    status,
+                    self.data.resets.time_format,
+                    self.data.incident_expanded,
+                )
+                .on_toggle(self.on_toggle_incident),
+            );
+        }
+
         // Error display with install hints
         if let Some(ref err) = self.data.error {
             card = card.child(EnhancedErrorSection {
+                provider,
                 summary: err.clone(),
                 details: None,
                 install_hint: self.data.install_hint.clone(),
+                needs_auth: self.data.needs_auth,
+                paused: self.data.paused,
+                expanded: self.data.error_expanded,
+                on_toggle: self.on_toggle_error,
             });
         } else if let Some(ref snap) = self.data.snapshot {
-            // Usage metrics
-            card = card.child(UsageMetricsSection::new(
-                snap,
-                self.data.session_label,
-                self.data.weekly_label,
-                Some("Search"),
-                self.data.show_used,
-                self.data.show_absolute,
+            // Usage metrics, dimmed while stale
+            card = card.child(div().when(stale, |el| el.opacity(0.5)).child(
+                UsageMetricsSection::new(
+                    provider,
+                    snap,
+                    self.data.session_label,
+                    self.data.weekly_label,
+                    Some("Search"),
+                    self.data.show_used,
+                    self.data.resets,
+                    &self.data.sparklines,
+                    &self.data.limit_etas,
+                ),
             ));
+
+            // The Claude Code session being written to
+            if let Some(session) = self.data.live_session.clone() {
+                card = card.child(CurrentSessionSection::new(
+                    session,
+                    self.data.live_session_cost,
+                ));
+            }
+
+            // Spend row (when the provider reports cost data, or tokens to
+            // estimate it from)
+            if let Some(ref cost) = snap.cost {
+                card = card.child(SpendRow::new(
+                    cost,
+                    &self.data.currency_code,
+                    self.data.monthly_budget,
+                ));
+            } else if let Some(estimate) = self.data.estimated_spend {
+                card = card.child(SpendRow::estimated(estimate, self.data.monthly_budget));
+            }
+
+            // Requests and tokens today, and the projects that used the most
+            if !self.data.projects.is_empty() {
+                card = card.child(TodayRow::new(&self.data.projects));
+                card = card.child(TopProjectsSection::new(&self.data.projects));
+            }
+
+            // Per-model breakdown (collapsed by default)
+            if !snap.model_breakdown.is_empty() {
+                card = card.child(
+                    ModelBreakdownSection::new(
+                        format!("breakdown-{:?}", provider),
+                        &snap.model_breakdown,
+                        self.data.breakdown_expanded,
+                    )
+                    .on_toggle(self.on_toggle_breakdown),
+                );
+            }
         } else if !self.data.is_refreshing {
             card = card.child(PlaceholderSection);
         }
 
+        // Raw JSON debug view (Option-click header, or debug mode)
+        if self.data.show_debug {
+            card = card.child(DebugSection::new(
+                format!("debug-copy-{:?}", provider),
+                self.data.raw_response.as_deref(),
+                self.data.snapshot.as_ref(),
+            ));
+        }
+
         // Action buttons section (Dashboard, Status, Buy Credits)
-        card = card.child(ActionButtonsSection::new(provider));
+        card = card.child(
+            ActionButtonsSection::with_actions(self.data.quick_actions, self.data.default_actions)
+                .focused(self.data.focused_action),
+        );
 
         card
     }
@@ -161,6 +447,32 @@ impl IntoElement for MenuCard {
 // Card Header
 // ============================================================================
 
+/// The provider whose header context menu is open, if any.
+static CONTEXT_MENU: Mutex<Option<ProviderKind>> = Mutex::new(None);
+
+fn context_menu_open(provider: ProviderKind) -> bool {
+    CONTEXT_MENU
+        .lock()
+        .is_ok_and(|open| *open == Some(provider))
+}
+
+/// Opens the header context menu for `provider`, or closes it if already open.
+fn toggle_context_menu(provider: ProviderKind) {
+    if let Ok(mut open) = CONTEXT_MENU.lock() {
+        *open = if *open == Some(provider) {
+            None
+        } else {
+            Some(provider)
+        };
+    }
+}
+
+fn close_context_menu() {
+    if let Ok(mut open) = CONTEXT_MENU.lock() {
+        *open = None;
+    }
+}
+
 struct CardHeader {
     provider: ProviderKind,
     provider_name: String,
@@ -168,21 +480,44 @@ struct CardHeader {
     plan: Option<String>,
     is_refreshing: bool,
     has_error: bool,
+    /// Short description of the failure ("Offline", "Rate limited", ...).
+    failure_label: Option<&'static str>,
+    /// Whether the provider rejected its credentials.
+    needs_auth: bool,
+    /// When the snapshot was fetched (for "Updated 4m ago").
+    updated_at: Option<DateTime<Utc>>,
+    /// Whether the snapshot is older than the provider's stale threshold.
+    stale: bool,
+    pinned: bool,
+    /// Collapsed state, or `None` when the card can't be collapsed.
+    collapse: Option<bool>,
+    /// Invoked on Option-click (opens the debug view).
+    on_option_click: Option<ToggleHandler>,
 }
 
 impl IntoElement for CardHeader {
     type Element = Div;
 
     fn into_element(self) -> Self::Element {
+        let now = Utc::now();
         let status_text = if self.is_refreshing {
             "Refreshing...".to_string()
         } else if self.has_error {
-            "Error".to_string()
+            self.failure_label.unwrap_or("Error").to_string()
+        } else if let Some(updated_at) = self.updated_at {
+            let elapsed = format_elapsed(updated_at, now);
+            if self.stale {
+                format!("Updated {} · Stale", elapsed)
+            } else {
+                format!("Updated {}", elapsed)
+            }
         } else {
-            "Updated just now".to_string()
+            "Not updated yet".to_string()
         };
 
-        let status_color = if self.has_error {
+        let status_color = if self.needs_auth {
+            theme::warning()
+        } else if self.has_error || self.stale {
             theme::error()
         } else {
             theme::muted()
@@ -204,9 +539,59 @@ impl IntoElement for CardHeader {
                 ),
         );
 
+        let provider = self.provider;
+        let pin_button = div()
+            .id(SharedString::from(format!("pin-{:?}", provider)))
+            .px(px(4.))
+            .rounded(px(4.))
+            .cursor_pointer()
+            .text_sm()
+            .text_color(if self.pinned {
+                theme::warning()
+            } else {
+                theme::muted()
+            })
+            .hover(|s| s.bg(theme::hover()))
+            .on_mouse_down(MouseButton::Left, move |_, _window, cx| {
+                tracing::info!(provider = ?provider, "Pin toggled");
+                cx.update_global::<AppState, _>(|state, cx| {
+                    state.settings.update(cx, |model, cx| {
+                        model.toggle_provider_pinned(provider);
+                        cx.notify();
+                    });
+                });
+            })
+            .child(if self.pinned { "★" } else { "☆" });
+
+        let mut trailing = div().flex().items_center().gap(px(6.));
         if !self.email.is_empty() {
-            top_row = top_row.child(div().text_xs().text_color(theme::muted()).child(self.email));
+            trailing = trailing.child(div().text_xs().text_color(theme::muted()).child(self.email));
         }
+        trailing = trailing.child(pin_button);
+
+        if let Some(collapsed) = self.collapse {
+            trailing = trailing.child(
+                div()
+                    .id(SharedString::from(format!("collapse-{:?}", provider)))
+                    .px(px(4.))
+                    .rounded(px(4.))
+                    .cursor_pointer()
+                    .text_xs()
+                    .text_color(theme::muted())
+                    .hover(|s| s.bg(theme::hover()))
+                    .on_mouse_down(MouseButton::Left, move |_, _window, cx| {
+                        tracing::info!(provider = ?provider, "Card collapse toggled");
+                        cx.update_global::<AppState, _>(|state, cx| {
+                            state.settings.update(cx, |model, cx| {
+                                model.toggle_provider_collapsed(provider);
+                                cx.notify();
+                            });
+                        });
+                    })
+                    .child(if collapsed { "▸" } else { "▾" }),
+            );
+        }
+        top_row = top_row.child(trailing);
 
         // Build status row with optional spinner
         let mut status_row = div()
@@ -230,7 +615,7 @@ impl IntoElement for CardHeader {
             bottom_row = bottom_row.child(div().text_xs().text_color(theme::muted()).child(plan));
         }
 
-        div()
+        let mut header = div()
             .px(px(14.))
             .py(px(10.))
             .bg(theme::card_background())
@@ -239,8 +624,142 @@ impl IntoElement for CardHeader {
             .flex()
             .flex_col()
             .gap(px(4.))
-            .child(top_row)
-            .child(bottom_row)
+            // Right-click opens a context menu with "Copy summary"
+            .on_mouse_down(MouseButton::Right, move |_, window, _cx| {
+                toggle_context_menu(provider);
+                window.refresh();
+            });
+
+        if let Some(handler) = self.on_option_click {
+            header = header.on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                if event.modifiers.alt {
+                    handler(event, window, cx);
+                }
+            });
+        }
+
+        header = header.child(top_row).child(bottom_row);
+
+        if context_menu_open(provider) {
+            header = header.child(
+                div()
+                    .mt(px(4.))
+                    .p(px(2.))
+                    .rounded(px(6.))
+                    .border_1()
+                    .border_color(theme::glass_separator())
+                    .child(
+                        div()
+                            .id(SharedString::from(format!("copy-summary-{:?}", provider)))
+                            .px(px(8.))
+                            .py(px(4.))
+                            .rounded(px(4.))
+                            .cursor_pointer()
+                            .text_sm()
+                            .text_color(theme::text_primary())
+                            .hover(|s| s.bg(theme::hover()))
+                            .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                                // Copy this provider's usage as Markdown
+                                summary::copy_summary(&[provider], cx);
+                                close_context_menu();
+                                window.refresh();
+                            })
+                            .child("Copy summary"),
+                    ),
+            );
+        }
+
+        header
+    }
+}
+
+// ============================================================================
+// Compact Card Row
+// ============================================================================
+
+/// Single-line card used in compact mode: icon, name, worst-window percent, mini bar.
+struct CompactCardRow {
+    provider: ProviderKind,
+    provider_name: String,
+    worst_percent: Option<f64>,
+    has_error: bool,
+    needs_auth: bool,
+    paused: bool,
+    is_refreshing: bool,
+}
+
+impl CompactCardRow {
+    fn new(data: &MenuCardData) -> Self {
+        Self {
+            provider: data.provider,
+            provider_name: data.provider_name.clone(),
+            worst_percent: data.snapshot.as_ref().map(|s| s.max_usage_percent()),
+            has_error: data.error.is_some(),
+            needs_auth: data.needs_auth,
+            paused: data.paused,
+            is_refreshing: data.is_refreshing,
+        }
+    }
+}
+
+impl IntoElement for CompactCardRow {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let (percent_label, color) = match (self.has_error, self.worst_percent) {
+            (true, _) if self.needs_auth => ("Sign in".to_string(), theme::warning()),
+            (true, _) if self.paused => ("Paused".to_string(), theme::muted()),
+            (true, _) => ("Error".to_string(), theme::error()),
+            (false, Some(pct)) => (format!("{:.0}%", pct), theme::color_for_usage(pct)),
+            (false, None) => ("—".to_string(), theme::muted()),
+        };
+        let fraction = self
+            .worst_percent
+            .filter(|_| !self.has_error)
+            .map(|pct| (pct.clamp(0.0, 100.0) / 100.0) as f32)
+            .unwrap_or(0.0);
+
+        div()
+            .px(px(14.))
+            .py(px(6.))
+            .bg(theme::card_background())
+            .flex()
+            .items_center()
+            .gap(px(8.))
+            .child(ProviderIcon::new(self.provider).size(px(14.)))
+            .child(
+                div()
+                    .flex_1()
+                    .min_w(px(0.))
+                    .overflow_hidden()
+                    .text_sm()
+                    .text_color(theme::text_primary())
+                    .child(self.provider_name),
+            )
+            .when(self.is_refreshing, |el| el.child(Spinner::new()))
+            // Mini bar
+            .child(
+                div()
+                    .w(px(60.))
+                    .h(px(4.))
+                    .rounded(px(2.))
+                    .bg(theme::track())
+                    .child(
+                        div()
+                            .h_full()
+                            .w(relative(fraction))
+                            .rounded(px(2.))
+                            .bg(color),
+                    ),
+            )
+            .child(
+                div()
+                    .w(px(40.))
+                    .text_right()
+                    .text_xs()
+                    .text_color(color)
+                    .child(percent_label),
+            )
     }
 }
 
@@ -0,0 +1,136 @@
+//! Provider card views.
+//!
+//! Each provider card in the panel is its own entity observing only that
+//! provider's state, so a refresh re-renders (and rebuilds the
+//! [`MenuCardData`] of) just the card whose provider changed.
+
+use exactobar_core::ProviderKind;
+use gpui::*;
+
+use crate::state::AppState;
+use crate::theme;
+
+use super::card::{MenuCard, MenuCardData};
+
+/// A provider card, with its disclosure state.
+pub struct ProviderCardView {
+    provider: ProviderKind,
+    /// Card data, rebuilt after the provider's state or the settings change.
+    data: Option<MenuCardData>,
+    /// Usage revision the data was built from.
+    revision: u64,
+    breakdown_expanded: bool,
+    error_expanded: bool,
+    incident_expanded: bool,
+    /// Whether the debug view was opened with Option-click.
+    debug_view: bool,
+    /// Index of the keyboard-focused action button, set by the panel.
+    focused_action: Option<usize>,
+    /// Whether the card is in the "All" list (collapsible, with a separator).
+    in_list: bool,
+    _subscriptions: [Subscription; 2],
+}
+
+impl ProviderCardView {
+    pub fn new(provider: ProviderKind, in_list: bool, cx: &mut Context<Self>) -> Self {
+        let state = cx.global::<AppState>();
+        let usage = state.usage.clone();
+        let settings = state.settings.clone();
+        let revision = usage.read(cx).revision(provider);
+
+        let subscriptions = [
+            cx.observe(&usage, |this, usage, cx| {
+                let revision = usage.read(cx).revision(this.provider);
+                if revision != this.revision {
+                    this.revision = revision;
+                    this.data = None;
+                    cx.notify();
+                }
+            }),
+            cx.observe(&settings, |this, _, cx| {
+                this.data = None;
+                cx.notify();
+            }),
+        ];
+
+        Self {
+            provider,
+            data: None,
+            revision,
+            breakdown_expanded: false,
+            error_expanded: false,
+            incident_expanded: false,
+            debug_view: false,
+            focused_action: None,
+            in_list,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Updates the state the panel controls, re-rendering only on change.
+    pub fn set_panel_state(
+        &mut self,
+        focused_action: Option<usize>,
+        in_list: bool,
+        cx: &mut Context<Self>,
+    ) {
+        if self.focused_action != focused_action || self.in_list != in_list {
+            self.focused_action = focused_action;
+            self.in_list = in_list;
+            cx.notify();
+        }
+    }
+}
+
+impl Render for ProviderCardView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let provider = self.provider;
+        let mut data = self
+            .data
+            .get_or_insert_with(|| MenuCardData::new(provider, cx))
+            .clone();
+        // Refreshes that found the same usage only move the timestamp
+        let state = cx.global::<AppState>();
+        if let (Some(snapshot), Some(updated_at)) = (
+            data.snapshot.as_mut(),
+            state.usage.read(cx).updated_at(provider),
+        ) {
+            snapshot.updated_at = updated_at;
+        }
+        data.breakdown_expanded = self.breakdown_expanded;
+        data.error_expanded = self.error_expanded;
+        data.incident_expanded = self.incident_expanded;
+        data.focused_action = self.focused_action;
+        data.show_debug |= self.debug_view;
+        data.collapsible = self.in_list;
+
+        let card = MenuCard::new(data)
+            .on_toggle_breakdown(cx.listener(|this, _, _window, cx| {
+                this.breakdown_expanded = !this.breakdown_expanded;
+                cx.notify();
+            }))
+            .on_toggle_debug(cx.listener(|this, _, _window, cx| {
+                this.debug_view = !this.debug_view;
+                cx.notify();
+            }))
+            .on_toggle_error(cx.listener(|this, _, _window, cx| {
+                this.error_expanded = !this.error_expanded;
+                cx.notify();
+            }))
+            .on_toggle_incident(cx.listener(|this, _, _window, cx| {
+                this.incident_expanded = !this.incident_expanded;
+                cx.notify();
+            }));
+
+        // Cards in the "All" list are divided by a subtle separator
+        let container = div();
+        if self.in_list {
+            container
+                .border_b_1()
+                .border_color(theme::border())
+                .child(card)
+        } else {
+            container.child(card)
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Provider comparison tab.
+//!
+//! Lines up the enabled providers' equivalent windows (session against
+//! session, weekly against weekly) as bars in one table, least used first,
+//! to help pick the provider for the next big task.
+
+use chrono::{DateTime, Utc};
+use exactobar_core::{ProviderKind, UsageSnapshot, UsageWindow, WindowKind};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+
+use crate::state::AppState;
+use crate::theme;
+
+use super::usage::{ProgressBar, format_countdown};
+
+/// One window kind across providers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonGroup {
+    pub window: WindowKind,
+    /// Providers reporting the window, least used first.
+    pub entries: Vec<(ProviderKind, UsageWindow)>,
+}
+
+impl ComparisonGroup {
+    /// Returns the row title shared by every provider's window of this kind.
+    pub fn title(&self) -> &'static str {
+        match self.window {
+            WindowKind::Primary => "Session",
+            WindowKind::Secondary => "Weekly",
+            WindowKind::Tertiary => "Premium",
+            WindowKind::Search => "Search",
+        }
+    }
+}
+
+/// Groups the providers' windows by kind, skipping kinds no provider reports.
+pub fn comparison_groups(snapshots: &[(ProviderKind, UsageSnapshot)]) -> Vec<ComparisonGroup> {
+    WindowKind::all()
+        .iter()
+        .filter_map(|&window| {
+            let mut entries: Vec<(ProviderKind, UsageWindow)> = snapshots
+                .iter()
+                .filter_map(|(provider, snapshot)| {
+                    snapshot.window(window).map(|w| (*provider, w.clone()))
+                })
+                .collect();
+            entries.sort_by(|(_, a), (_, b)| a.used_percent.total_cmp(&b.used_percent));
+            (!entries.is_empty()).then_some(ComparisonGroup { window, entries })
+        })
+        .collect()
+}
+
+// ============================================================================
+// Comparison View
+// ============================================================================
+
+/// The comparison table, re-rendering as usage changes.
+pub struct ComparisonView {
+    _subscriptions: [Subscription; 2],
+}
+
+impl ComparisonView {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        let state = cx.global::<AppState>();
+        let usage = state.usage.clone();
+        let settings = state.settings.clone();
+
+        Self {
+            _subscriptions: [
+                cx.observe(&usage, |_, _, cx| cx.notify()),
+                cx.observe(&settings, |_, _, cx| cx.notify()),
+            ],
+        }
+    }
+}
+
+impl Render for ComparisonView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let state = cx.global::<AppState>();
+        let snapshots: Vec<(ProviderKind, UsageSnapshot)> = state
+            .enabled_providers(cx)
+            .into_iter()
+            .filter_map(|p| state.get_snapshot(p, cx).map(|s| (p, s)))
+            .collect();
+        let groups = comparison_groups(&snapshots);
+        let now = Utc::now();
+
+        div()
+            .id("comparison-content")
+            .px(px(14.))
+            .py(px(10.))
+            .flex()
+            .flex_col()
+            .gap(px(12.))
+            .when(groups.is_empty(), |el| {
+                el.text_sm()
+                    .text_color(theme::text_secondary())
+                    .child("No usage to compare yet.")
+            })
+            .children(groups.into_iter().map(|group| {
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap(px(6.))
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_weight(FontWeight::MEDIUM)
+                            .text_color(theme::text_primary())
+                            .child(group.title()),
+                    )
+                    .children(
+                        group
+                            .entries
+                            .into_iter()
+                            .map(|(provider, window)| comparison_row(provider, &window, now)),
+                    )
+            }))
+    }
+}
+
+/// A provider's bar in a comparison group, with the columns aligned across
+/// rows.
+fn comparison_row(provider: ProviderKind, window: &UsageWindow, now: DateTime<Utc>) -> Div {
+    let used_percent = window.used_percent.clamp(0.0, 100.0);
+    let reset = window
+        .next_reset(now)
+        .and_then(|reset_at| format_countdown(reset_at, now))
+        .unwrap_or_default();
+
+    div()
+        .flex()
+        .items_center()
+        .gap(px(8.))
+        .text_xs()
+        .child(
+            div()
+                .w(px(72.))
+                .flex_shrink_0()
+                .overflow_hidden()
+                .text_color(theme::text_primary())
+                .child(provider.display_name()),
+        )
+        .child(div().flex_1().min_w(px(0.)).child(ProgressBar::new(
+            used_percent,
+            theme::color_for_usage(used_percent),
+        )))
+        .child(
+            div()
+                .w(px(36.))
+                .flex_shrink_0()
+                .text_color(theme::text_secondary())
+                .child(format!("{:.0}%", used_percent)),
+        )
+        .child(
+            div()
+                .w(px(52.))
+                .flex_shrink_0()
+                .text_color(theme::muted())
+                .child(reset),
+        )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comparison_groups() {
+        let mut claude = UsageSnapshot::new();
+        claude.primary = Some(UsageWindow::new(70.0));
+        claude.secondary = Some(UsageWindow::new(20.0));
+        let mut codex = UsageSnapshot::new();
+        codex.primary = Some(UsageWindow::new(30.0));
+        let cursor = UsageSnapshot::new();
+
+        let groups = comparison_groups(&[
+            (ProviderKind::Claude, claude),
+            (ProviderKind::Codex, codex),
+            (ProviderKind::Cursor, cursor),
+        ]);
+        assert_eq!(groups.len(), 2);
+
+        assert_eq!(groups[0].title(), "Session");
+        let session: Vec<ProviderKind> = groups[0].entries.iter().map(|(p, _)| *p).collect();
+        assert_eq!(session, vec![ProviderKind::Codex, ProviderKind::Claude]);
+
+        assert_eq!(groups[1].title(), "Weekly");
+        assert_eq!(groups[1].entries.len(), 1);
+        assert_eq!(groups[1].entries[0].0, ProviderKind::Claude);
+    }
+}
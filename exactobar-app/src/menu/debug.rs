@@ -0,0 +1,158 @@
+//! Debug view showing raw provider data inside the card.
+//!
+//! Shows the last raw provider response and the derived `UsageSnapshot`
+//! as pretty-printed JSON. Opened by Option-clicking the card header,
+//! or always shown when debug mode is enabled in settings.
+
+use exactobar_core::UsageSnapshot;
+use gpui::*;
+
+use crate::theme;
+
+use super::error::copy_to_clipboard;
+
+// ============================================================================
+// Debug Section
+// ============================================================================
+
+pub struct DebugSection {
+    id: SharedString,
+    raw_response: Option<String>,
+    snapshot_json: Option<String>,
+}
+
+impl DebugSection {
+    pub fn new(
+        id: impl Into<SharedString>,
+        raw_response: Option<&str>,
+        snapshot: Option<&UsageSnapshot>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            raw_response: raw_response.map(pretty_json),
+            snapshot_json: snapshot.and_then(|s| serde_json::to_string_pretty(s).ok()),
+        }
+    }
+
+    /// Both blocks as one text, for copying into a bug report.
+    fn report(&self) -> String {
+        format!(
+            "Raw response:\n{}\n\nSnapshot:\n{}",
+            self.raw_response.as_deref().unwrap_or("(none)"),
+            self.snapshot_json.as_deref().unwrap_or("(none)")
+        )
+    }
+}
+
+impl IntoElement for DebugSection {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let report = self.report();
+
+        let copy_button = div()
+            .id(self.id)
+            .px(px(6.))
+            .py(px(2.))
+            .rounded(px(4.))
+            .cursor_pointer()
+            .text_xs()
+            .text_color(theme::accent())
+            .hover(|s| s.bg(theme::hover()))
+            .on_mouse_down(MouseButton::Left, move |_, _window, _cx| {
+                copy_to_clipboard(&report);
+            })
+            .child("Copy");
+
+        div()
+            .px(px(14.))
+            .py(px(8.))
+            .bg(theme::card_background())
+            .border_b_1()
+            .border_color(theme::glass_separator())
+            .flex()
+            .flex_col()
+            .gap(px(6.))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_weight(FontWeight::MEDIUM)
+                            .text_color(theme::text_primary())
+                            .child("Debug"),
+                    )
+                    .child(copy_button),
+            )
+            .child(JsonBlock::new(
+                "Raw response",
+                self.raw_response
+                    .unwrap_or_else(|| "Not captured for this source".to_string()),
+            ))
+            .child(JsonBlock::new(
+                "Snapshot",
+                self.snapshot_json
+                    .unwrap_or_else(|| "No snapshot".to_string()),
+            ))
+    }
+}
+
+/// A titled block of preformatted text.
+struct JsonBlock {
+    title: &'static str,
+    text: String,
+}
+
+impl JsonBlock {
+    fn new(title: &'static str, text: String) -> Self {
+        Self { title, text }
+    }
+}
+
+impl IntoElement for JsonBlock {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(2.))
+            .child(div().text_xs().text_color(theme::muted()).child(self.title))
+            .child(
+                div()
+                    .p(px(6.))
+                    .rounded(px(4.))
+                    .bg(theme::hover())
+                    .font_family("SF Mono, Menlo, monospace")
+                    .text_xs()
+                    .text_color(theme::text_secondary())
+                    .children(self.text.lines().map(|line| line.to_string())),
+            )
+    }
+}
+
+/// Pretty-prints `raw` if it's JSON, otherwise returns it unchanged.
+fn pretty_json(raw: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(raw)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| raw.to_string())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty_json() {
+        assert_eq!(pretty_json(r#"{"a":1}"#), "{\n  \"a\": 1\n}");
+        assert_eq!(pretty_json("not json"), "not json");
+    }
+}
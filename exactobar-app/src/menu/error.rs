@@ -1,14 +1,27 @@
 //! Error display components with install hints and copy functionality.
 //!
 //! Provides enhanced error sections that show helpful install hints when
-//! CLI tools are missing, plus one-click copy for error messages.
+//! CLI tools are missing, plus one-click copy for error messages. The full
+//! error chain is collapsed behind "Show details", next to a "Copy
+//! Diagnostics" button that bundles the error with recent log lines.
+//! Providers that rejected their credentials get a "Re-authenticate" button
+//! that starts their sign-in again, and providers paused after repeated
+//! errors get a "Resume" button.
 
-use exactobar_core::ProviderKind;
+use exactobar_core::{ProviderError, ProviderKind};
 use gpui::prelude::FluentBuilder;
 use gpui::*;
 use tracing::info;
 
+use crate::logs;
+use crate::refresh::resume_provider;
 use crate::theme;
+use crate::windows::settings::reauthenticate;
+
+use super::breakdown::ToggleHandler;
+
+/// Number of recent log lines included in copied diagnostics.
+const DIAGNOSTIC_LOG_LINES: usize = 20;
 
 // ============================================================================
 // Install Hint for Missing CLIs
@@ -24,13 +37,25 @@ pub struct InstallHint {
 }
 
 /// Detects if an error indicates a missing CLI and returns install instructions.
-pub fn get_install_hint(provider: ProviderKind, error: &str) -> Option<InstallHint> {
-    let error_lower = error.to_lowercase();
-    let missing_cli = error_lower.contains("not found")
-        || error_lower.contains("command not found")
-        || error_lower.contains("no such file")
-        || error_lower.contains("cli not installed")
-        || error_lower.contains("executable not found");
+///
+/// Errors restored from the cache have no `failure`, so those fall back to
+/// matching the message.
+pub fn get_install_hint(
+    provider: ProviderKind,
+    failure: Option<&ProviderError>,
+    error: &str,
+) -> Option<InstallHint> {
+    let missing_cli = match failure {
+        Some(failure) => matches!(failure, ProviderError::NotInstalled(_)),
+        None => {
+            let error_lower = error.to_lowercase();
+            error_lower.contains("not found")
+                || error_lower.contains("command not found")
+                || error_lower.contains("no such file")
+                || error_lower.contains("cli not installed")
+                || error_lower.contains("executable not found")
+        }
+    };
 
     if !missing_cli {
         return None;
@@ -108,13 +133,41 @@ pub fn copy_to_clipboard(text: &str) {
 // ============================================================================
 
 pub struct EnhancedErrorSection {
+    /// Provider the error belongs to
+    pub provider: ProviderKind,
     /// Short error summary (always visible)
     pub summary: String,
     /// Detailed error message (shown when expanded)
-    #[allow(dead_code)]
     pub details: Option<String>,
     /// Install hint if CLI is missing
     pub install_hint: Option<InstallHint>,
+    /// Whether the provider rejected its credentials
+    pub needs_auth: bool,
+    /// Whether scheduled refreshes stopped after repeated errors
+    pub paused: bool,
+    /// Whether the full error chain is shown
+    pub expanded: bool,
+    /// Called when "Show details" / "Hide details" is clicked
+    pub on_toggle: Option<ToggleHandler>,
+}
+
+/// Builds the text copied by "Copy Diagnostics".
+fn diagnostics_report(provider: ProviderKind, error: &str, log_lines: &[String]) -> String {
+    let mut report = format!(
+        "ExactoBar {} ({} {})\nProvider: {:?}\n\n{}",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        provider,
+        error.trim_end()
+    );
+
+    if !log_lines.is_empty() {
+        report.push_str("\n\nRecent log lines:\n");
+        report.push_str(&log_lines.join("\n"));
+    }
+
+    report
 }
 
 /// Parse error message into summary (first line) and details (rest).
@@ -158,9 +211,11 @@ impl IntoElement for EnhancedErrorSection {
                 .unwrap_or_default()
         );
         let full_error_for_copy = full_error.clone();
+        let provider = self.provider;
+        let has_details = combined_details.is_some();
 
         let mut section = div()
-            .id("error-section")
+            .id(SharedString::from(format!("error-section-{:?}", provider)))
             .px(px(14.))
             .py(px(10.))
             .bg(theme::card_background())
@@ -186,15 +241,15 @@ impl IntoElement for EnhancedErrorSection {
                 ),
         );
 
-        // Show details in a scrollable box if present
-        if let Some(detail_text) = combined_details {
+        // Show details in a scrollable box when expanded
+        if let Some(detail_text) = combined_details.filter(|_| self.expanded) {
             section = section.child(
                 div()
-                    .id("error-details-scroll")
+                    .id(SharedString::from(format!("error-details-{:?}", provider)))
                     .mt(px(4.))
                     .p(px(10.))
                     .rounded(px(6.))
-                    .bg(hsla(0., 0., 0.1, 0.5))
+                    .bg(theme::code_background())
                     .max_h(px(150.))
                     .overflow_y_scroll()
                     .child(
@@ -215,30 +270,145 @@ impl IntoElement for EnhancedErrorSection {
             );
         }
 
-        // Copy error button
+        // Details toggle and copy buttons
+        let expanded = self.expanded;
+        let on_toggle = self.on_toggle;
         section = section.child(
             div()
-                .id("copy-error-btn")
-                .px(px(8.))
-                .py(px(4.))
-                .rounded(px(4.))
-                .text_xs()
-                .text_color(theme::text_secondary())
-                .bg(theme::surface())
-                .cursor_pointer()
-                .hover(|s| s.bg(theme::hover()))
-                .active(|s| s.bg(theme::active()))
-                .on_mouse_down(MouseButton::Left, move |_, _window, _cx| {
-                    copy_to_clipboard(&full_error_for_copy);
-                    info!("Error copied to clipboard");
-                })
                 .flex()
                 .items_center()
-                .gap(px(4.))
-                .child("📋")
-                .child("Copy Error"),
+                .gap(px(6.))
+                .when_some(on_toggle.filter(|_| has_details), |el, on_toggle| {
+                    el.child(
+                        error_button(
+                            format!("toggle-error-details-{:?}", provider),
+                            if expanded { "▾" } else { "▸" },
+                            if expanded {
+                                "Hide Details"
+                            } else {
+                                "Show Details"
+                            },
+                        )
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            move |event, window, cx| {
+                                on_toggle(event, window, cx);
+                            },
+                        ),
+                    )
+                })
+                .child(
+                    error_button(format!("copy-error-{:?}", provider), "📋", "Copy Error")
+                        .on_mouse_down(MouseButton::Left, move |_, _window, _cx| {
+                            copy_to_clipboard(&full_error_for_copy);
+                            info!("Error copied to clipboard");
+                        }),
+                )
+                .child(
+                    error_button(
+                        format!("copy-diagnostics-{:?}", provider),
+                        "🩺",
+                        "Copy Diagnostics",
+                    )
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        move |_, _window, _cx| {
+                            let log_lines = logs::recent_lines(
+                                &format!("{:?}", provider),
+                                DIAGNOSTIC_LOG_LINES,
+                            );
+                            copy_to_clipboard(&diagnostics_report(
+                                provider,
+                                &full_error,
+                                &log_lines,
+                            ));
+                            info!(provider = ?provider, "Diagnostics copied to clipboard");
+                        },
+                    ),
+                ),
         );
 
+        // Sign-in panel (if the credentials were rejected)
+        if self.needs_auth {
+            section = section.child(
+                div()
+                    .mt(px(4.))
+                    .p(px(10.))
+                    .rounded(px(6.))
+                    .bg(theme::hint_background())
+                    .border_1()
+                    .border_color(theme::hint_border())
+                    .flex()
+                    .flex_col()
+                    .gap(px(6.))
+                    .child(
+                        div()
+                            .text_xs()
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .text_color(theme::warning())
+                            .child("🔑 Sign-in expired"),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(theme::text_secondary())
+                            .child("Automatic refreshes are paused. Sign in again, then refresh."),
+                    )
+                    .child(
+                        div().flex().child(
+                            error_button(
+                                format!("reauthenticate-{:?}", provider),
+                                "🔑",
+                                "Re-authenticate",
+                            )
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                move |_, _window, cx| {
+                                    reauthenticate(provider, cx);
+                                },
+                            ),
+                        ),
+                    ),
+            );
+        }
+
+        // Resume panel (if refreshes stopped after repeated errors)
+        if self.paused {
+            section = section.child(
+                div()
+                    .mt(px(4.))
+                    .p(px(10.))
+                    .rounded(px(6.))
+                    .bg(theme::hint_background())
+                    .border_1()
+                    .border_color(theme::hint_border())
+                    .flex()
+                    .flex_col()
+                    .gap(px(6.))
+                    .child(
+                        div()
+                            .text_xs()
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .text_color(theme::warning())
+                            .child("⏸ Paused due to repeated errors"),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(theme::text_secondary())
+                            .child("Automatic refreshes stopped. Fix the error, then resume."),
+                    )
+                    .child(
+                        div().flex().child(
+                            error_button(format!("resume-{:?}", provider), "▶", "Resume")
+                                .on_mouse_down(MouseButton::Left, move |_, _window, cx| {
+                                    resume_provider(provider, cx);
+                                }),
+                        ),
+                    ),
+            );
+        }
+
         // Install hint panel (if CLI is missing)
         if let Some(hint) = self.install_hint {
             let cmd_for_copy = hint.command.clone();
@@ -248,9 +418,9 @@ impl IntoElement for EnhancedErrorSection {
                     .mt(px(4.))
                     .p(px(10.))
                     .rounded(px(6.))
-                    .bg(hsla(0.12, 0.8, 0.35, 0.15)) // Warm yellowish hint background
+                    .bg(theme::hint_background())
                     .border_1()
-                    .border_color(hsla(0.12, 0.6, 0.5, 0.3))
+                    .border_color(theme::hint_border())
                     .flex()
                     .flex_col()
                     .gap(px(6.))
@@ -299,3 +469,64 @@ impl IntoElement for EnhancedErrorSection {
         section
     }
 }
+
+// ============================================================================
+// Error Button
+// ============================================================================
+
+/// Small button used below the error message.
+fn error_button(id: String, icon: &'static str, label: &'static str) -> Stateful<Div> {
+    div()
+        .id(SharedString::from(id))
+        .px(px(8.))
+        .py(px(4.))
+        .rounded(px(4.))
+        .text_xs()
+        .text_color(theme::text_secondary())
+        .bg(theme::surface())
+        .cursor_pointer()
+        .hover(|s| s.bg(theme::hover()))
+        .active(|s| s.bg(theme::active()))
+        .flex()
+        .items_center()
+        .gap(px(4.))
+        .child(icon)
+        .child(label)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_message() {
+        assert_eq!(
+            parse_error_message("Error: boom\n\nStrategies tried (1):"),
+            (
+                "Error: boom".to_string(),
+                Some("Strategies tried (1):".to_string())
+            )
+        );
+        assert_eq!(
+            parse_error_message("Error: boom"),
+            ("Error: boom".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_diagnostics_report() {
+        let report = diagnostics_report(
+            ProviderKind::Claude,
+            "Error: boom\n",
+            &["WARN Strategy failed provider=Claude".to_string()],
+        );
+
+        assert!(report.starts_with(&format!("ExactoBar {}", env!("CARGO_PKG_VERSION"))));
+        assert!(report.contains("Provider: Claude\n\nError: boom\n\nRecent log lines:\n"));
+        assert!(report.ends_with("WARN Strategy failed provider=Claude"));
+    }
+}
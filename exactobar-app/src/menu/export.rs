@@ -0,0 +1,88 @@
+//! PNG export of usage cards for sharing.
+//!
+//! Renders the visible providers with [`IconRenderer::render_usage_card`],
+//! saves the image to the Downloads folder, and copies it to the clipboard.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{Local, Utc};
+use exactobar_core::ProviderKind;
+use gpui::App;
+use tracing::{error, info};
+
+use crate::icon::{IconRenderer, UsageCardEntry};
+use crate::state::AppState;
+use crate::theme;
+
+/// Renders the given providers to a PNG, saves it, and copies it to the clipboard.
+///
+/// Returns the path of the saved image.
+pub fn export_card_image(providers: &[ProviderKind], cx: &App) -> Option<PathBuf> {
+    let state = cx.global::<AppState>();
+    let settings = state.settings.read(cx).settings();
+    let now = Utc::now();
+
+    let entries: Vec<UsageCardEntry> = providers
+        .iter()
+        .map(|&provider| {
+            let snapshot = state.get_snapshot(provider, cx);
+            let stale = snapshot
+                .as_ref()
+                .is_some_and(|s| settings.is_stale(provider, s.updated_at, now));
+            UsageCardEntry::from_snapshot(provider, snapshot.as_ref(), stale, now)
+        })
+        .collect();
+
+    if entries.is_empty() {
+        return None;
+    }
+
+    let png = IconRenderer::render_usage_card(&entries, theme::icon_brand_colors()).to_png();
+
+    let dir = dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(std::env::temp_dir);
+    let path = dir.join(format!(
+        "exactobar-usage-{}.png",
+        Local::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    if let Err(e) = std::fs::write(&path, &png) {
+        error!(error = %e, path = %path.display(), "Failed to save usage card image");
+        return None;
+    }
+
+    info!(path = %path.display(), providers = providers.len(), "Exported usage card image");
+    copy_image_to_clipboard(&path);
+    Some(path)
+}
+
+/// Copies a PNG file to the system clipboard.
+fn copy_image_to_clipboard(path: &Path) {
+    #[cfg(target_os = "macos")]
+    {
+        use std::process::Command;
+
+        let script = format!(
+            "set the clipboard to (read (POSIX file \"{}\") as «class PNGf»)",
+            path.display()
+        );
+        let _ = Command::new("osascript").args(["-e", &script]).spawn();
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        use std::process::Command;
+
+        let _ = Command::new("xclip")
+            .args(["-selection", "clipboard", "-t", "image/png", "-i"])
+            .arg(path)
+            .spawn();
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        // Saving to Downloads is the fallback; no image clipboard support here
+        let _ = path;
+    }
+}
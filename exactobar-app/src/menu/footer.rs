@@ -1,8 +1,21 @@
-//! Menu footer with action buttons (Refresh, Settings, Quit).
+//! Menu footer with action buttons (Refresh, Compact, Copy, Usage, Settings, About, Close, Quit).
 //!
 //! These buttons actually work - they trigger real actions through
-//! the global AppState and window management.
+//! the global AppState and window management. Above them, quick toggles
+//! switch the most changed settings without opening the Settings window.
+//! Copy opens a small overflow to copy the summary or save an image.
+//!
+//! Close only hides the menu, monitoring goes on in the menu bar. Quit
+//! stops it until the next launch, so it can be set to need a second click
+//! or Option (Alt) held.
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
+use exactobar_core::ProviderKind;
+use exactobar_store::Settings;
+use gpui::prelude::FluentBuilder;
 use gpui::*;
 use tracing::info;
 
@@ -10,15 +23,77 @@ use crate::state::AppState;
 use crate::theme;
 use crate::windows;
 
+use super::{export, summary};
+
 // ============================================================================
 // Menu Footer
 // ============================================================================
 
-pub struct MenuFooter;
+pub struct MenuFooter {
+    /// Index of the keyboard-focused button, if any.
+    focused: Option<usize>,
+    /// Whether compact mode is on (sets the toggle's label).
+    compact: bool,
+    /// Providers currently shown (for "Copy summary" and image export).
+    providers: Vec<ProviderKind>,
+    /// Current state of the quick toggles.
+    toggles: QuickToggles,
+    /// How the Quit button is protected from misclicks.
+    quit: QuitProtection,
+}
 
 impl MenuFooter {
     pub fn new() -> Self {
-        Self
+        Self {
+            focused: None,
+            compact: false,
+            providers: Vec::new(),
+            toggles: QuickToggles::default(),
+            quit: QuitProtection::default(),
+        }
+    }
+
+    /// Sets how the Quit button is protected from misclicks.
+    pub fn quit_protection(mut self, quit: QuitProtection) -> Self {
+        self.quit = quit;
+        self
+    }
+
+    /// Sets the current state of the quick toggles.
+    pub fn toggles(mut self, toggles: QuickToggles) -> Self {
+        self.toggles = toggles;
+        self
+    }
+
+    /// Sets the providers currently shown, used by "Copy summary" and image export.
+    pub fn providers(mut self, providers: Vec<ProviderKind>) -> Self {
+        self.providers = providers;
+        self
+    }
+
+    /// Sets whether compact mode is currently on.
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
+
+    /// Highlights the button at `index` as keyboard-focused.
+    pub fn focused(mut self, index: Option<usize>) -> Self {
+        self.focused = index;
+        self
+    }
+
+    /// Number of footer buttons.
+    pub const BUTTON_COUNT: usize = FooterAction::ALL.len();
+
+    /// Activates the footer button at `index` (keyboard Enter).
+    ///
+    /// Reaching a button with the keyboard is deliberate, so Quit doesn't
+    /// need the modifier.
+    pub fn activate(index: usize, providers: &[ProviderKind], window: &mut Window, cx: &mut App) {
+        if let Some(action) = FooterAction::ALL.get(index) {
+            action.perform(providers, true, window, cx);
+        }
     }
 }
 
@@ -27,21 +102,67 @@ impl IntoElement for MenuFooter {
 
     fn into_element(self) -> Self::Element {
         tracing::trace!("MenuFooter rendering footer buttons");
-        div()
+        let toggles = self.toggles;
+        let toggle_row = div()
+            .px(px(10.))
+            .pt(px(8.))
+            .flex()
+            .flex_wrap()
+            .gap(px(4.))
+            .children(
+                QuickToggle::ALL
+                    .into_iter()
+                    .map(|toggle| QuickToggleChip::new(toggle, toggles.is_on(toggle))),
+            );
+
+        let button_row = div()
             .px(px(10.))
             .py(px(8.))
+            .flex()
+            .flex_wrap()
+            .items_center()
+            .justify_between()
+            // Refresh, Compact, Copy, Usage, Settings, About, Close, Quit - in FooterAction::ALL order
+            .children(FooterAction::ALL.iter().enumerate().map(|(i, &action)| {
+                let button = match action {
+                    FooterAction::Refresh => FooterActionButton::refresh(),
+                    FooterAction::Compact => FooterActionButton::compact(self.compact),
+                    FooterAction::Copy => FooterActionButton::copy(),
+                    FooterAction::CopySummary => FooterActionButton::copy_summary(),
+                    FooterAction::CopyImage => FooterActionButton::copy_image(),
+                    FooterAction::Dashboard => FooterActionButton::dashboard(),
+                    FooterAction::Settings => FooterActionButton::settings(),
+                    FooterAction::About => FooterActionButton::about(),
+                    FooterAction::Close => FooterActionButton::close(),
+                    FooterAction::Quit => FooterActionButton::quit(self.quit),
+                };
+                button
+                    .focused(self.focused == Some(i))
+                    .providers(self.providers.clone())
+            }));
+
+        // The Copy overflow, opened from the Copy button
+        let copy_menu = div()
+            .mx(px(10.))
+            .mt(px(8.))
+            .p(px(2.))
+            .rounded(px(6.))
+            .border_1()
+            .border_color(theme::glass_separator())
+            .flex()
+            .gap(px(2.))
+            .child(FooterActionButton::copy_summary().providers(self.providers.clone()))
+            .child(FooterActionButton::copy_image().providers(self.providers.clone()));
+
+        div()
             .bg(theme::card_background())
             .border_t_1()
             .border_color(theme::glass_separator())
             .flex()
-            .items_center()
-            .justify_between()
-            // Refresh button - ACTUALLY REFRESHES
-            .child(FooterActionButton::refresh())
-            // Settings button - OPENS SETTINGS
-            .child(FooterActionButton::settings())
-            // Quit button - ACTUALLY QUITS
-            .child(FooterActionButton::quit())
+            .flex_col()
+            .child(toggle_row)
+            .when(copy_menu_open(), |el| el.child(copy_menu))
+            .child(button_row)
     }
 }
 
@@ -51,6 +172,209 @@ impl Default for MenuFooter {
     }
 }
 
+// ============================================================================
+// Quick Toggles
+// ============================================================================
+
+/// Settings switched from the footer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum QuickToggle {
+    MergeIcons,
+    ShowUsed,
+    AbsoluteResets,
+    PauseRefresh,
+}
+
+impl QuickToggle {
+    /// Quick toggles in display order.
+    const ALL: [QuickToggle; 4] = [
+        QuickToggle::MergeIcons,
+        QuickToggle::ShowUsed,
+        QuickToggle::AbsoluteResets,
+        QuickToggle::PauseRefresh,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            QuickToggle::MergeIcons => "Merge icons",
+            QuickToggle::ShowUsed => "Show used",
+            QuickToggle::AbsoluteResets => "Absolute times",
+            QuickToggle::PauseRefresh => "Pause refresh",
+        }
+    }
+
+    fn is_on(self, settings: &Settings) -> bool {
+        match self {
+            QuickToggle::MergeIcons => settings.merge_icons,
+            QuickToggle::ShowUsed => settings.usage_bars_show_used,
+            QuickToggle::AbsoluteResets => settings.reset_times_show_absolute,
+            QuickToggle::PauseRefresh => settings.refresh_paused,
+        }
+    }
+
+    /// Flips the setting (persisted).
+    fn toggle(self, cx: &mut App) {
+        info!(toggle = ?self, "Footer quick toggle switched");
+        cx.update_global::<AppState, _>(|state, cx| {
+            state.settings.update(cx, |model, cx| {
+                let on = !self.is_on(model.settings());
+                match self {
+                    QuickToggle::MergeIcons => model.set_merge_icons(on),
+                    QuickToggle::ShowUsed => model.set_usage_bars_show_used(on),
+                    QuickToggle::AbsoluteResets => model.set_reset_times_show_absolute(on),
+                    QuickToggle::PauseRefresh => model.set_refresh_paused(on),
+                }
+                cx.notify();
+            });
+        });
+    }
+}
+
+/// Which quick toggles are on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QuickToggles {
+    on: [bool; QuickToggle::ALL.len()],
+}
+
+impl QuickToggles {
+    /// Reads the toggles' state from the settings.
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            on: QuickToggle::ALL.map(|toggle| toggle.is_on(settings)),
+        }
+    }
+
+    fn is_on(&self, toggle: QuickToggle) -> bool {
+        QuickToggle::ALL
+            .iter()
+            .position(|&t| t == toggle)
+            .is_some_and(|i| self.on[i])
+    }
+}
+
+/// A small on/off chip for a quick toggle.
+struct QuickToggleChip {
+    toggle: QuickToggle,
+    on: bool,
+}
+
+impl QuickToggleChip {
+    fn new(toggle: QuickToggle, on: bool) -> Self {
+        Self { toggle, on }
+    }
+}
+
+impl IntoElement for QuickToggleChip {
+    type Element = Stateful<Div>;
+
+    fn into_element(self) -> Self::Element {
+        let toggle = self.toggle;
+
+        div()
+            .id(SharedString::from(format!("quick-toggle-{:?}", toggle)))
+            .px(px(8.))
+            .py(px(3.))
+            .rounded(px(10.))
+            .border_1()
+            .cursor_pointer()
+            .text_xs()
+            .when(self.on, |el| {
+                el.bg(theme::accent())
+                    .border_color(theme::accent())
+                    .text_color(gpui::white())
+            })
+            .when(!self.on, |el| {
+                el.border_color(theme::glass_separator())
+                    .text_color(theme::text_secondary())
+                    .hover(|s| s.bg(theme::hover()))
+            })
+            .on_mouse_down(MouseButton::Left, move |_, _window, cx| {
+                toggle.toggle(cx);
+            })
+            .child(toggle.label())
+    }
+}
+
+// ============================================================================
+// Quit Protection
+// ============================================================================
+
+/// How long a first click on Quit waits for the confirming one.
+const QUIT_CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// When Quit was first clicked, while it waits for a confirming click.
+static QUIT_ARMED_AT: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// How the Quit button is protected from misclicks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QuitProtection {
+    /// Quit needs a second click.
+    confirm: bool,
+    /// Quit needs Option (Alt) held.
+    requires_modifier: bool,
+}
+
+impl QuitProtection {
+    /// Reads the protection from the settings.
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            confirm: settings.confirm_quit,
+            requires_modifier: settings.quit_requires_modifier,
+        }
+    }
+}
+
+fn arm_quit_confirmation() {
+    if let Ok(mut armed) = QUIT_ARMED_AT.lock() {
+        *armed = Some(Instant::now());
+    }
+}
+
+fn quit_confirmation_pending() -> bool {
+    QUIT_ARMED_AT
+        .lock()
+        .ok()
+        .and_then(|armed| *armed)
+        .is_some_and(|at| at.elapsed() < QUIT_CONFIRMATION_TIMEOUT)
+}
+
+/// Quits the app, unless the Quit protection in the settings holds it back.
+/// `modifier_held` is whether Option (Alt) was held. Returns true if a
+/// confirming second Quit is now awaited.
+pub fn request_quit(modifier_held: bool, cx: &mut App) -> bool {
+    let protection = QuitProtection::new(cx.global::<AppState>().settings.read(cx).settings());
+    if protection.requires_modifier && !modifier_held {
+        info!("Quit without the modifier held, ignoring");
+        return false;
+    }
+    if protection.confirm && !take_quit_confirmation() {
+        arm_quit_confirmation();
+        return true;
+    }
+    cx.quit();
+    false
+}
+
+/// Returns true if Quit was clicked a moment ago, using up that click.
+fn take_quit_confirmation() -> bool {
+    let pending = quit_confirmation_pending();
+    if let Ok(mut armed) = QUIT_ARMED_AT.lock() {
+        *armed = None;
+    }
+    pending
+}
+
+// ============================================================================
+// Copy Menu
+// ============================================================================
+
+/// Whether the Copy overflow is open.
+static COPY_MENU_OPEN: AtomicBool = AtomicBool::new(false);
+
+fn copy_menu_open() -> bool {
+    COPY_MENU_OPEN.load(Ordering::Relaxed)
+}
+
 // ============================================================================
 // Footer Action Buttons (Interactive!)
 // ============================================================================
@@ -59,15 +383,129 @@ impl Default for MenuFooter {
 #[derive(Clone, Copy, Debug)]
 enum FooterAction {
     Refresh,
+    Compact,
+    Copy,
+    CopySummary,
+    CopyImage,
+    Dashboard,
     Settings,
+    About,
+    Close,
     Quit,
 }
 
+impl FooterAction {
+    /// Footer actions in display order. CopySummary and CopyImage are
+    /// in the Copy overflow instead.
+    const ALL: [FooterAction; 8] = [
+        FooterAction::Refresh,
+        FooterAction::Compact,
+        FooterAction::Copy,
+        FooterAction::Dashboard,
+        FooterAction::Settings,
+        FooterAction::About,
+        FooterAction::Close,
+        FooterAction::Quit,
+    ];
+
+    /// Performs the action. `modifier_held` is whether Option (Alt) was
+    /// held, for Quit.
+    fn perform(
+        self,
+        providers: &[ProviderKind],
+        modifier_held: bool,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        info!(action = ?self, "Footer action triggered");
+        if !matches!(self, FooterAction::Copy) {
+            COPY_MENU_OPEN.store(false, Ordering::Relaxed);
+        }
+        match self {
+            FooterAction::Refresh => {
+                // Trigger refresh of all providers
+                crate::refresh::trigger_refresh(cx);
+            }
+            FooterAction::Compact => {
+                // Toggle single-line cards (persisted)
+                cx.update_global::<AppState, _>(|state, cx| {
+                    state.settings.update(cx, |model, cx| {
+                        let compact = model.compact_menu();
+                        model.set_compact_menu(!compact);
+                        cx.notify();
+                    });
+                });
+            }
+            FooterAction::Copy => {
+                // Open or close the overflow with the copy formats
+                COPY_MENU_OPEN.fetch_xor(true, Ordering::Relaxed);
+                window.refresh();
+            }
+            FooterAction::CopySummary => {
+                // Copy the visible providers as Markdown
+                summary::copy_summary(providers, cx);
+                window.refresh();
+            }
+            FooterAction::CopyImage => {
+                // Save the visible cards as a PNG and copy it
+                export::export_card_image(providers, cx);
+                window.refresh();
+            }
+            FooterAction::Dashboard => {
+                // Deferred like Settings so the popup can close first
+                cx.spawn(async move |cx| {
+                    cx.update(|cx| {
+                        windows::open_dashboard_window(cx);
+                    });
+                })
+                .detach();
+            }
+            FooterAction::Settings => {
+                tracing::trace!("Settings button clicked, opening settings window");
+                let task = cx.spawn(async move |mut cx| {
+                    cx.update(|cx| {
+                        windows::open_settings(cx);
+                    });
+                });
+                task.detach();
+            }
+            FooterAction::About => {
+                // Deferred like Settings so the popup can close first
+                cx.spawn(async move |cx| {
+                    cx.update(|cx| {
+                        windows::open_about_window(cx);
+                    });
+                })
+                .detach();
+            }
+            FooterAction::Close => {
+                // Hide the menu, the app keeps monitoring in the menu bar
+                window.remove_window();
+            }
+            FooterAction::Quit => {
+                if request_quit(modifier_held, cx) {
+                    // Ask for a second click, shown on the button until it
+                    // times out
+                    window.refresh();
+                    let handle = window.window_handle();
+                    cx.spawn(async move |cx| {
+                        smol::Timer::after(QUIT_CONFIRMATION_TIMEOUT).await;
+                        let _ = cx.update_window(handle, |_, window, _| window.refresh());
+                    })
+                    .detach();
+                }
+            }
+        }
+    }
+}
+
 /// A footer button that actually does something!
 struct FooterActionButton {
     action: FooterAction,
     label: &'static str,
     shortcut: &'static str,
+    focused: bool,
+    providers: Vec<ProviderKind>,
 }
 
 impl FooterActionButton {
@@ -76,6 +514,58 @@ impl FooterActionButton {
             action: FooterAction::Refresh,
             label: "Refresh",
             shortcut: "⌘R",
+            focused: false,
+            providers: Vec::new(),
+        }
+    }
+
+    fn compact(is_compact: bool) -> Self {
+        Self {
+            action: FooterAction::Compact,
+            label: if is_compact { "Detailed" } else { "Compact" },
+            shortcut: "",
+            focused: false,
+            providers: Vec::new(),
+        }
+    }
+
+    fn copy() -> Self {
+        Self {
+            action: FooterAction::Copy,
+            label: "Copy",
+            shortcut: if copy_menu_open() { "▴" } else { "▾" },
+            focused: false,
+            providers: Vec::new(),
+        }
+    }
+
+    fn copy_summary() -> Self {
+        Self {
+            action: FooterAction::CopySummary,
+            label: "Summary",
+            shortcut: "Markdown",
+            focused: false,
+            providers: Vec::new(),
+        }
+    }
+
+    fn copy_image() -> Self {
+        Self {
+            action: FooterAction::CopyImage,
+            label: "Image",
+            shortcut: "PNG",
+            focused: false,
+            providers: Vec::new(),
+        }
+    }
+
+    fn dashboard() -> Self {
+        Self {
+            action: FooterAction::Dashboard,
+            label: "Usage",
+            shortcut: "",
+            focused: false,
+            providers: Vec::new(),
         }
     }
 
@@ -84,16 +574,62 @@ impl FooterActionButton {
             action: FooterAction::Settings,
             label: "Settings...",
             shortcut: "⌘,",
+            focused: false,
+            providers: Vec::new(),
+        }
+    }
+
+    fn about() -> Self {
+        Self {
+            action: FooterAction::About,
+            label: "About",
+            shortcut: "",
+            focused: false,
+            providers: Vec::new(),
+        }
+    }
+
+    fn close() -> Self {
+        Self {
+            action: FooterAction::Close,
+            label: "Close",
+            shortcut: "",
+            focused: false,
+            providers: Vec::new(),
         }
     }
 
-    fn quit() -> Self {
+    fn quit(protection: QuitProtection) -> Self {
+        let label = if protection.confirm && quit_confirmation_pending() {
+            "Click again to quit"
+        } else {
+            "Quit"
+        };
+        let shortcut = if !protection.requires_modifier {
+            "⌘Q"
+        } else if cfg!(target_os = "macos") {
+            "⌥-click"
+        } else {
+            "Alt-click"
+        };
         Self {
             action: FooterAction::Quit,
-            label: "Quit",
-            shortcut: "⌘Q",
+            label,
+            shortcut,
+            focused: false,
+            providers: Vec::new(),
         }
     }
+
+    fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    fn providers(mut self, providers: Vec<ProviderKind>) -> Self {
+        self.providers = providers;
+        self
+    }
 }
 
 impl IntoElement for FooterActionButton {
@@ -103,6 +639,7 @@ impl IntoElement for FooterActionButton {
         let action = self.action;
         let label = self.label;
         let shortcut = self.shortcut;
+        let providers = self.providers;
 
         tracing::trace!(button = label, "FooterActionButton rendering");
 
@@ -112,34 +649,15 @@ impl IntoElement for FooterActionButton {
             .py(px(6.))
             .rounded(px(6.))
             .cursor_pointer()
+            .when(self.focused, |el| el.bg(theme::hover()))
             // Hover state - subtle highlight like native macOS
             .hover(|s| s.bg(theme::hover()))
             // Active/pressed state - stronger highlight
             .active(|s| s.bg(theme::active()))
             // Click handler - THE MAGIC HAPPENS HERE!
-            .on_mouse_down(MouseButton::Left, move |_, _window, cx| {
+            .on_mouse_down(MouseButton::Left, move |event, window, cx| {
                 info!(action = ?action, "Footer button clicked!");
-                match action {
-                    FooterAction::Refresh => {
-                        // Trigger refresh of all providers
-                        cx.update_global::<AppState, _>(|state, cx| {
-                            state.refresh_all(cx);
-                        });
-                    }
-                    FooterAction::Settings => {
-                        tracing::trace!("Settings button clicked, opening settings window");
-                        let task = cx.spawn(async move |mut cx| {
-                            cx.update(|cx| {
-                                windows::open_settings(cx);
-                            });
-                        });
-                        task.detach();
-                    }
-                    FooterAction::Quit => {
-                        // Quit the application
-                        cx.quit();
-                    }
-                }
+                action.perform(&providers, event.modifiers.alt, window, cx);
             })
             .flex()
             .items_center()
@@ -150,6 +668,8 @@ impl IntoElement for FooterActionButton {
                     .text_color(theme::text_primary())
                     .child(label),
             )
-            .child(div().text_xs().text_color(theme::muted()).child(shortcut))
+            .when(!shortcut.is_empty(), |el| {
+                el.child(div().text_xs().text_color(theme::muted()).child(shortcut))
+            })
     }
 }
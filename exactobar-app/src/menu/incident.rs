@@ -0,0 +1,173 @@
+//! Status incident panel for the menu card.
+//!
+//! When the provider's status page reports trouble, a disclosure row shows
+//! the status; clicking it expands the unresolved incidents with their
+//! affected components, start time and latest update.
+
+use chrono::Utc;
+use exactobar_core::{ProviderStatus, StatusIncident, StatusIndicator};
+use exactobar_store::TimeFormat;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+
+use crate::theme;
+
+use super::breakdown::ToggleHandler;
+
+// ============================================================================
+// Status Incident Section
+// ============================================================================
+
+pub struct StatusIncidentSection {
+    id: SharedString,
+    status: ProviderStatus,
+    time_format: TimeFormat,
+    expanded: bool,
+    on_toggle: Option<ToggleHandler>,
+}
+
+impl StatusIncidentSection {
+    pub fn new(
+        id: impl Into<SharedString>,
+        status: ProviderStatus,
+        time_format: TimeFormat,
+        expanded: bool,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            status,
+            time_format,
+            expanded,
+            on_toggle: None,
+        }
+    }
+
+    /// Sets the handler invoked when the disclosure row is clicked.
+    pub fn on_toggle(mut self, handler: Option<ToggleHandler>) -> Self {
+        self.on_toggle = handler;
+        self
+    }
+}
+
+impl IntoElement for StatusIncidentSection {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let chevron = if self.expanded { "▾" } else { "▸" };
+        let color = match self.status.indicator {
+            StatusIndicator::Major | StatusIndicator::Critical => theme::error(),
+            _ => theme::warning(),
+        };
+        let time_format = self.time_format;
+
+        let mut disclosure = div()
+            .id(self.id)
+            .flex()
+            .items_center()
+            .gap(px(6.))
+            .cursor_pointer()
+            .rounded(px(4.))
+            .hover(|s| s.bg(theme::hover()))
+            .child(div().text_xs().text_color(theme::muted()).child(chevron))
+            .child(
+                div()
+                    .text_sm()
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(color)
+                    .child(self.status.indicator.label()),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w(px(0.))
+                    .overflow_hidden()
+                    .text_xs()
+                    .text_color(theme::text_secondary())
+                    .child(self.status.description.clone()),
+            );
+
+        if let Some(handler) = self.on_toggle {
+            disclosure = disclosure.on_mouse_down(MouseButton::Left, handler);
+        }
+
+        let incidents = self.status.incidents;
+        div()
+            .px(px(14.))
+            .py(px(8.))
+            .bg(theme::card_background())
+            .border_b_1()
+            .border_color(theme::glass_separator())
+            .flex()
+            .flex_col()
+            .gap(px(6.))
+            .child(disclosure)
+            .when(self.expanded && incidents.is_empty(), |el| {
+                el.child(
+                    div()
+                        .text_xs()
+                        .text_color(theme::muted())
+                        .child("No incident details on the status page."),
+                )
+            })
+            .when(self.expanded, |el| {
+                el.children(
+                    incidents
+                        .into_iter()
+                        .map(move |incident| incident_details(incident, time_format)),
+                )
+            })
+    }
+}
+
+/// One incident's title, components, start time and latest update.
+fn incident_details(incident: StatusIncident, time_format: TimeFormat) -> Div {
+    let now = Utc::now();
+    let mut meta = vec![capitalize(&incident.status)];
+    if let Some(started_at) = incident.started_at {
+        meta.push(format!(
+            "since {}",
+            time_format.format_reset(started_at, now)
+        ));
+    }
+
+    div()
+        .pl(px(14.))
+        .flex()
+        .flex_col()
+        .gap(px(2.))
+        .text_xs()
+        .child(
+            div()
+                .font_weight(FontWeight::MEDIUM)
+                .text_color(theme::text_primary())
+                .child(incident.name),
+        )
+        .child(div().text_color(theme::muted()).child(meta.join(" · ")))
+        .when(!incident.components.is_empty(), |el| {
+            el.child(
+                div()
+                    .text_color(theme::text_secondary())
+                    .child(format!("Affects {}", incident.components.join(", "))),
+            )
+        })
+        .when_some(incident.latest_update, |el, update| {
+            let posted = incident
+                .latest_update_at
+                .map(|at| format!("{}: ", time_format.format_reset(at, now)))
+                .unwrap_or_default();
+            el.child(
+                div()
+                    .text_color(theme::text_secondary())
+                    .child(format!("{posted}{update}")),
+            )
+        })
+}
+
+/// Upper-cases the first letter, e.g. "investigating" to "Investigating".
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
@@ -0,0 +1,159 @@
+//! Keyboard navigation for the popup menu.
+//!
+//! Tracks which control has keyboard focus and maps key presses to
+//! navigation commands:
+//! - Left/Right move between provider tabs
+//! - Up/Down move between the action buttons of the visible cards
+//! - Tab/Shift-Tab cycle the footer buttons
+//! - Enter activates the focused button
+//! - 1–9 jump straight to a provider tab
+
+use exactobar_core::ProviderKind;
+use gpui::Keystroke;
+
+// ============================================================================
+// Focus State
+// ============================================================================
+
+/// The control that currently has keyboard focus.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum MenuFocus {
+    /// Nothing focused (mouse-driven use).
+    #[default]
+    None,
+    /// An action button within a provider's card.
+    Action {
+        provider: ProviderKind,
+        index: usize,
+    },
+    /// A footer button.
+    Footer(usize),
+}
+
+impl MenuFocus {
+    /// Returns the focused action button index for a provider's card, if any.
+    pub fn action_for(&self, provider: ProviderKind) -> Option<usize> {
+        match *self {
+            MenuFocus::Action { provider: p, index } if p == provider => Some(index),
+            _ => None,
+        }
+    }
+
+    /// Returns the focused footer button index, if any.
+    pub fn footer(&self) -> Option<usize> {
+        match *self {
+            MenuFocus::Footer(index) => Some(index),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Key Commands
+// ============================================================================
+
+/// A navigation command derived from a key press.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MenuKey {
+    /// Move to the previous tab.
+    PreviousTab,
+    /// Move to the next tab.
+    NextTab,
+    /// Move to the previous action button.
+    PreviousAction,
+    /// Move to the next action button.
+    NextAction,
+    /// Cycle footer buttons forward.
+    NextFooter,
+    /// Cycle footer buttons backward.
+    PreviousFooter,
+    /// Activate the focused button.
+    Activate,
+    /// Jump to the provider tab at this zero-based index.
+    JumpToProvider(usize),
+}
+
+impl MenuKey {
+    /// Maps a keystroke to a navigation command.
+    pub fn from_keystroke(keystroke: &Keystroke) -> Option<Self> {
+        let modifiers = &keystroke.modifiers;
+        if modifiers.platform || modifiers.control || modifiers.alt {
+            return None;
+        }
+
+        match keystroke.key.as_str() {
+            "left" => Some(MenuKey::PreviousTab),
+            "right" => Some(MenuKey::NextTab),
+            "up" => Some(MenuKey::PreviousAction),
+            "down" => Some(MenuKey::NextAction),
+            "tab" if modifiers.shift => Some(MenuKey::PreviousFooter),
+            "tab" => Some(MenuKey::NextFooter),
+            "enter" | "space" => Some(MenuKey::Activate),
+            key => match key.parse::<usize>() {
+                Ok(digit @ 1..=9) => Some(MenuKey::JumpToProvider(digit - 1)),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Steps through `len` items, wrapping at both ends.
+///
+/// Starts at the first (or last, when moving backward) item if nothing
+/// is selected yet.
+pub fn step(current: Option<usize>, len: usize, forward: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    Some(match current {
+        None if forward => 0,
+        None => len - 1,
+        Some(i) if forward => (i + 1) % len,
+        Some(i) => (i + len - 1) % len,
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_wraps() {
+        assert_eq!(step(None, 3, true), Some(0));
+        assert_eq!(step(None, 3, false), Some(2));
+        assert_eq!(step(Some(2), 3, true), Some(0));
+        assert_eq!(step(Some(0), 3, false), Some(2));
+        assert_eq!(step(Some(0), 0, true), None);
+    }
+
+    #[test]
+    fn test_keystroke_mapping() {
+        let key = |s: &str| MenuKey::from_keystroke(&Keystroke::parse(s).unwrap());
+
+        assert_eq!(key("left"), Some(MenuKey::PreviousTab));
+        assert_eq!(key("down"), Some(MenuKey::NextAction));
+        assert_eq!(key("tab"), Some(MenuKey::NextFooter));
+        assert_eq!(key("shift-tab"), Some(MenuKey::PreviousFooter));
+        assert_eq!(key("enter"), Some(MenuKey::Activate));
+        assert_eq!(key("3"), Some(MenuKey::JumpToProvider(2)));
+        assert_eq!(key("0"), None);
+        assert_eq!(key("cmd-1"), None);
+    }
+
+    #[test]
+    fn test_focus_accessors() {
+        let focus = MenuFocus::Action {
+            provider: ProviderKind::Claude,
+            index: 1,
+        };
+        assert_eq!(focus.action_for(ProviderKind::Claude), Some(1));
+        assert_eq!(focus.action_for(ProviderKind::Codex), None);
+        assert_eq!(focus.footer(), None);
+        assert_eq!(MenuFocus::Footer(2).footer(), Some(2));
+    }
+}
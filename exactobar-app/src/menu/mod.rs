@@ -9,35 +9,181 @@
 //!
 //! - `mod.rs` - MenuPanel, MenuHeader, TrayMenu alias
 //! - `card.rs` - MenuCard, MenuCardData, CardHeader
+//! - `card_view.rs` - ProviderCardView (per-provider card entity)
 //! - `error.rs` - EnhancedErrorSection, InstallHint, clipboard helpers
 //! - `usage.rs` - UsageMetricsSection, ProgressBar
-//! - `actions.rs` - ActionButtonsSection, ActionButton, URL opening
-//! - `footer.rs` - MenuFooter, FooterActionButton
+//! - `spend.rs` - SpendRow for provider-reported cost
+//! - `session.rs` - CurrentSessionSection (live Claude Code session)
+//! - `totals.rs` - TotalsView (totals card atop the "All" view)
+//! - `alerts.rs` - RecentAlertsSection (delivered alerts below the "All" view)
+//! - `compare.rs` - ComparisonView (windows side by side across providers)
+//! - `breakdown.rs` - ModelBreakdownSection (per-model disclosure table)
+//! - `incident.rs` - StatusIncidentSection (status page incidents)
+//! - `debug.rs` - DebugSection (raw response / snapshot JSON)
+//! - `keyboard.rs` - MenuFocus, MenuKey (keyboard navigation)
+//! - `summary.rs` - Markdown usage summaries ("Copy summary")
+//! - `export.rs` - PNG export of usage cards
+//! - `actions.rs` - ActionButtonsSection, ActionButton, URL/command quick actions
+//! - `footer.rs` - MenuFooter, FooterActionButton, QuickToggles, QuitProtection
+//! - `tour.rs` - TourOverlay (first-run coach marks)
 
 #![allow(dead_code)]
 
 mod actions;
+mod alerts;
+mod breakdown;
 mod card;
+mod card_view;
+mod compare;
+mod debug;
 mod error;
+mod export;
 mod footer;
+mod incident;
+mod keyboard;
+mod projects;
+mod session;
+mod spend;
+mod summary;
 mod tabs;
+mod totals;
+mod tour;
 mod usage;
 
 pub use tabs::SelectedTab;
 
 // Re-exports for public API
+pub use actions::{default_quick_actions, open_url};
 pub use card::{MenuCard, MenuCardData};
+pub use card_view::ProviderCardView;
 pub use error::{EnhancedErrorSection, InstallHint, copy_to_clipboard, get_install_hint};
-pub use footer::MenuFooter;
+pub use footer::{MenuFooter, QuickToggles, QuitProtection, request_quit};
+pub use keyboard::{MenuFocus, MenuKey};
+pub(crate) use summary::reset_phrase;
+pub(crate) use usage::{format_countdown, format_elapsed};
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use exactobar_core::ProviderKind;
-use exactobar_store::ThemeMode;
+use exactobar_store::{ThemeMode, WindowSize};
 use gpui::prelude::FluentBuilder;
 use gpui::*;
 use tracing::{debug, info};
 
+use crate::accessibility;
 use crate::state::AppState;
 use crate::theme;
+use crate::windows;
+
+use actions::ActionButtonsSection;
+use alerts::RecentAlertsSection;
+use compare::ComparisonView;
+use totals::TotalsView;
+use tour::{TourOverlay, TourStep};
+
+// ============================================================================
+// Panel Size
+// ============================================================================
+
+/// Default menu panel size (like Notification Center).
+pub const DEFAULT_PANEL_SIZE: Size<Pixels> = Size {
+    width: px(340.),
+    height: px(600.),
+};
+
+/// Smallest size the menu panel can be resized to.
+pub const MIN_PANEL_SIZE: Size<Pixels> = Size {
+    width: px(300.),
+    height: px(320.),
+};
+
+/// Largest size the menu panel can be resized to.
+pub const MAX_PANEL_SIZE: Size<Pixels> = Size {
+    width: px(640.),
+    height: px(1000.),
+};
+
+/// Clamps a size to the allowed panel range.
+fn clamp_panel_size(size: Size<Pixels>) -> Size<Pixels> {
+    let clamp = |value: Pixels, min: Pixels, max: Pixels| {
+        px(f32::from(value).clamp(f32::from(min), f32::from(max)))
+    };
+    Size {
+        width: clamp(size.width, MIN_PANEL_SIZE.width, MAX_PANEL_SIZE.width),
+        height: clamp(size.height, MIN_PANEL_SIZE.height, MAX_PANEL_SIZE.height),
+    }
+}
+
+/// Returns the persisted menu panel size, or the default.
+pub fn panel_size(cx: &App) -> Size<Pixels> {
+    cx.global::<AppState>()
+        .settings
+        .read(cx)
+        .menu_panel_size()
+        .map(|s| clamp_panel_size(size(px(s.width), px(s.height))))
+        .unwrap_or(DEFAULT_PANEL_SIZE)
+}
+
+/// Remembers the panel size as the user resizes its window, keeping it within
+/// bounds. It is saved once the resize ends.
+pub fn remember_panel_size(window: &mut Window, cx: &mut Context<MenuPanel>) {
+    cx.observe_window_bounds(window, |_this, window, cx| {
+        let current = window.bounds().size;
+        let clamped = clamp_panel_size(current);
+        if clamped != current {
+            window.resize(clamped);
+        }
+
+        let size = WindowSize {
+            width: clamped.width.into(),
+            height: clamped.height.into(),
+        };
+        cx.update_global::<AppState, _>(|state, cx| {
+            state.settings.update(cx, |model, _| {
+                model.set_menu_panel_size(size);
+            });
+        });
+    })
+    .detach();
+}
+
+// ============================================================================
+// Dismissal
+// ============================================================================
+
+/// How long after the popup closes itself a tray click is taken as part of
+/// the same gesture. Clicking the tray icon to close the popup first takes
+/// focus from it, and the click itself shouldn't open it again.
+const DISMISS_GRACE: Duration = Duration::from_millis(400);
+
+/// When the popup last closed itself.
+static LAST_DISMISSED: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Closes the popup once it loses focus, like a native transient panel.
+pub fn close_on_focus_loss(window: &mut Window, cx: &mut Context<MenuPanel>) {
+    cx.observe_window_activation(window, |_this, window, _cx| {
+        if !window.is_window_active() {
+            debug!("Menu lost focus, closing");
+            if let Ok(mut last) = LAST_DISMISSED.lock() {
+                *last = Some(Instant::now());
+            }
+            window.remove_window();
+        }
+    })
+    .detach();
+}
+
+/// Returns true if the popup closed itself a moment ago, in which case a
+/// tray click should leave it closed.
+pub fn dismissed_recently() -> bool {
+    LAST_DISMISSED
+        .lock()
+        .ok()
+        .and_then(|last| *last)
+        .is_some_and(|at| at.elapsed() < DISMISS_GRACE)
+}
 
 // ============================================================================
 // Menu Panel
@@ -47,8 +193,29 @@ use crate::theme;
 pub struct MenuPanel {
     /// Currently selected tab (All or a specific provider).
     selected_tab: SelectedTab,
+    /// Provider cards, each re-rendering on its own.
+    cards: HashMap<ProviderKind, Entity<ProviderCardView>>,
+    /// Totals card atop the "All" view, while it's turned on.
+    totals: Option<Entity<TotalsView>>,
+    /// Comparison table, while its tab is selected.
+    comparison: Option<Entity<ComparisonView>>,
+    /// Focus handle for receiving key events (created on first render).
+    focus_handle: Option<FocusHandle>,
+    /// Control that currently has keyboard focus.
+    keyboard_focus: MenuFocus,
     /// Theme mode subscription - forces re-render when theme changes.
     subscription: Option<gpui::Subscription>,
+    /// Whether this panel lives in the detached (pinned) window.
+    detached: bool,
+    /// Re-renders the cards so countdowns and timestamps tick while it's open.
+    clock_task: Option<Task<()>>,
+    /// Index of the current first-run tour step.
+    tour_step: usize,
+    /// Provider whose refresh was started by opening its tab, cancelled
+    /// when switching away before it finishes.
+    tab_refresh: Option<ProviderKind>,
+    /// Window title last set, which screen readers announce for the popup.
+    window_title: String,
 }
 
 impl MenuPanel {
@@ -59,20 +226,300 @@ impl MenuPanel {
             selected_tab: initial_provider
                 .map(SelectedTab::Provider)
                 .unwrap_or(SelectedTab::All),
+            cards: HashMap::new(),
+            totals: None,
+            comparison: None,
+            focus_handle: None,
+            keyboard_focus: MenuFocus::None,
             subscription: None,
+            detached: false,
+            clock_task: None,
+            tour_step: 0,
+            tab_refresh: None,
+            window_title: String::new(),
+        }
+    }
+
+    /// Creates a menu panel, falling back to the last selected tab when no
+    /// specific provider was requested.
+    pub fn restored(initial_provider: Option<ProviderKind>, cx: &App) -> Self {
+        let state = cx.global::<AppState>();
+        let remembered = state
+            .settings
+            .read(cx)
+            .selected_provider()
+            .filter(|&p| state.enabled_providers(cx).contains(&p));
+
+        Self::new(initial_provider.or(remembered))
+    }
+
+    /// Creates a panel for the detached window, showing `tab`.
+    pub fn detached(tab: SelectedTab) -> Self {
+        Self {
+            selected_tab: tab,
+            detached: true,
+            ..Self::new(None)
+        }
+    }
+
+    /// Returns the card for a provider, creating it on first use.
+    fn card(&mut self, provider: ProviderKind, cx: &mut Context<Self>) -> Entity<ProviderCardView> {
+        let in_list = self.selected_tab.is_list();
+        self.cards
+            .entry(provider)
+            .or_insert_with(|| cx.new(|cx| ProviderCardView::new(provider, in_list, cx)))
+            .clone()
+    }
+
+    /// Passes the keyboard focus and selected tab on to the cards.
+    fn sync_cards(&self, cx: &mut Context<Self>) {
+        let in_list = self.selected_tab.is_list();
+        for (&provider, card) in &self.cards {
+            let focused_action = self.keyboard_focus.action_for(provider);
+            card.update(cx, |card, cx| {
+                card.set_panel_state(focused_action, in_list, cx)
+            });
+        }
+    }
+
+    /// Switches tabs (remembering the choice), refreshing the provider if it
+    /// has no data yet.
+    fn select_tab(&mut self, tab: SelectedTab, cx: &mut Context<Self>) {
+        self.selected_tab = tab;
+        self.keyboard_focus = MenuFocus::None;
+
+        // Remember the tab for the next time the menu opens
+        let selected = tab.provider();
+        cx.update_global::<AppState, _>(|state, cx| {
+            state.settings.update(cx, |model, _| {
+                model.set_selected_provider(selected);
+            });
+        });
+
+        // Abort the refresh the previous tab started, it's no longer shown
+        if let Some(p) = self
+            .tab_refresh
+            .take()
+            .filter(|&p| tab != SelectedTab::Provider(p))
+        {
+            crate::refresh::cancel_fetch(p);
+        }
+
+        // Check if this provider has data, if not trigger refresh
+        if let SelectedTab::Provider(p) = tab {
+            let state = cx.global::<AppState>();
+            let has_snapshot = state.get_snapshot(p, cx).is_some();
+            if !has_snapshot {
+                info!(provider = ?p, "No snapshot, triggering refresh");
+                crate::refresh::trigger_provider_refresh(p, cx);
+                self.tab_refresh = Some(p);
+            }
+        }
+
+        self.sync_cards(cx);
+        cx.notify(); // Re-render with new selection!
+    }
+
+    /// Moves the first-run tour on, finishing it after the last step.
+    fn advance_tour(&mut self, total: usize, cx: &mut Context<Self>) {
+        if self.tour_step + 1 >= total {
+            self.finish_tour(cx);
+        } else {
+            self.tour_step += 1;
+            cx.notify();
+        }
+    }
+
+    /// Ends the first-run tour so it isn't shown again.
+    fn finish_tour(&mut self, cx: &mut Context<Self>) {
+        self.tour_step = 0;
+        cx.update_global::<AppState, _>(|state, cx| {
+            state.settings.update(cx, |model, _| {
+                model.set_tour_completed(true);
+            });
+        });
+        cx.notify();
+    }
+
+    /// Returns the providers shown in the current tab.
+    fn visible_providers(&self, enabled: &[ProviderKind], cx: &App) -> Vec<ProviderKind> {
+        match self.selected_tab {
+            SelectedTab::All | SelectedTab::Compare => enabled.to_vec(),
+            // A group that no longer exists shows everything
+            SelectedTab::Group(index) => provider_groups(cx)
+                .into_iter()
+                .nth(index)
+                .map_or_else(|| enabled.to_vec(), |(_, providers)| providers),
+            SelectedTab::Provider(p) => vec![p],
         }
     }
 
+    /// Returns how long until a visible countdown or "updated" label next changes.
+    fn clock_interval(&self, cx: &App) -> std::time::Duration {
+        let state = cx.global::<AppState>();
+        let enabled = state.enabled_providers(cx);
+        let snapshots: Vec<_> = self
+            .visible_providers(&enabled, cx)
+            .into_iter()
+            .filter_map(|p| state.get_snapshot(p, cx))
+            .collect();
+        let updated: Vec<_> = snapshots.iter().map(|s| s.updated_at).collect();
+        let now = chrono::Utc::now();
+        let resets: Vec<_> = snapshots
+            .into_iter()
+            .flat_map(|s| {
+                [s.primary, s.secondary, s.tertiary, s.search]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|w| w.next_reset(now))
+            })
+            .collect();
+
+        usage::next_tick(&resets, &updated, now)
+    }
+
+    /// Starts the timer that keeps countdowns live while the panel is open.
+    ///
+    /// The task is dropped (and stops) along with the panel.
+    fn start_clock(&mut self, cx: &mut Context<Self>) {
+        self.clock_task = Some(cx.spawn(async move |this, cx| {
+            loop {
+                let Ok(interval) = this.update(cx, |this, cx| this.clock_interval(cx)) else {
+                    break;
+                };
+                smol::Timer::after(interval).await;
+                // Only the cards show countdowns and timestamps
+                let ticked = this.update(cx, |this, cx| {
+                    for card in this.cards.values() {
+                        card.update(cx, |_, cx| cx.notify());
+                    }
+                    if let Some(totals) = &this.totals {
+                        totals.update(cx, |_, cx| cx.notify());
+                    }
+                    if let Some(comparison) = &this.comparison {
+                        comparison.update(cx, |_, cx| cx.notify());
+                    }
+                });
+                if ticked.is_err() {
+                    break;
+                }
+            }
+        }));
+    }
+
+    /// Handles keyboard navigation.
+    fn handle_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(key) = MenuKey::from_keystroke(&event.keystroke) else {
+            return;
+        };
+        debug!(key = ?key, "Menu key pressed");
+
+        let enabled = cx.global::<AppState>().enabled_providers(cx);
+
+        match key {
+            MenuKey::PreviousTab | MenuKey::NextTab => {
+                // Tab order matches the switcher: "All" and "Compare" first,
+                // then groups, then providers
+                let group_count = if cx.global::<AppState>().settings.read(cx).group_tabs() {
+                    provider_groups(cx).len()
+                } else {
+                    0
+                };
+                let tabs: Vec<SelectedTab> = [SelectedTab::All, SelectedTab::Compare]
+                    .into_iter()
+                    .chain((0..group_count).map(SelectedTab::Group))
+                    .chain(enabled.iter().map(|&p| SelectedTab::Provider(p)))
+                    .collect();
+                let current = tabs.iter().position(|t| *t == self.selected_tab);
+                if let Some(next) = keyboard::step(current, tabs.len(), key == MenuKey::NextTab) {
+                    self.select_tab(tabs[next], cx);
+                }
+            }
+            MenuKey::JumpToProvider(index) => {
+                if let Some(&p) = enabled.get(index) {
+                    self.select_tab(SelectedTab::Provider(p), cx);
+                }
+            }
+            MenuKey::PreviousAction | MenuKey::NextAction => {
+                // Flatten the action buttons of every visible card, top to bottom
+                // Compact and collapsed cards don't show action buttons
+                let settings = cx.global::<AppState>().settings.read(cx);
+                let visible: Vec<ProviderKind> = if settings.compact_menu() {
+                    Vec::new()
+                } else {
+                    self.visible_providers(&enabled, cx)
+                        .into_iter()
+                        .filter(|&p| {
+                            !self.selected_tab.is_list() || !settings.is_provider_collapsed(p)
+                        })
+                        .collect()
+                };
+                let targets: Vec<MenuFocus> = visible
+                    .iter()
+                    .flat_map(|&provider| {
+                        (0..ActionButtonsSection::button_count(provider, cx))
+                            .map(move |index| MenuFocus::Action { provider, index })
+                    })
+                    .collect();
+                let current = targets.iter().position(|t| *t == self.keyboard_focus);
+                if let Some(next) =
+                    keyboard::step(current, targets.len(), key == MenuKey::NextAction)
+                {
+                    self.keyboard_focus = targets[next];
+                }
+            }
+            MenuKey::PreviousFooter | MenuKey::NextFooter => {
+                let next = keyboard::step(
+                    self.keyboard_focus.footer(),
+                    MenuFooter::BUTTON_COUNT,
+                    key == MenuKey::NextFooter,
+                );
+                if let Some(index) = next {
+                    self.keyboard_focus = MenuFocus::Footer(index);
+                }
+            }
+            MenuKey::Activate => match self.keyboard_focus {
+                MenuFocus::Action { provider, index } => {
+                    ActionButtonsSection::activate(provider, index, cx);
+                }
+                MenuFocus::Footer(index) => {
+                    let visible = self.visible_providers(&enabled, cx);
+                    MenuFooter::activate(index, &visible, window, cx);
+                }
+                MenuFocus::None => {}
+            },
+        }
+
+        self.sync_cards(cx);
+        cx.stop_propagation();
+        cx.notify();
+    }
+
     /// Renders the provider switcher with WORKING click handlers.
     /// This must be called from render() where we have access to cx.listener().
     fn render_provider_switcher(
         &self,
         providers: &[ProviderKind],
+        groups: &[String],
         text_primary: Hsla,
         hover_bg: Hsla,
         active_bg: Hsla,
         cx: &mut Context<Self>,
     ) -> impl IntoElement {
+        let pinned: Vec<ProviderKind> = {
+            let settings = cx.global::<AppState>().settings.read(cx);
+            providers
+                .iter()
+                .copied()
+                .filter(|&p| settings.is_provider_pinned(p))
+                .collect()
+        };
+
         // Build the "All" tab button first
         let is_all_selected = self.selected_tab.is_all();
         let all_btn = div()
@@ -95,12 +542,37 @@ impl MenuPanel {
                 MouseButton::Left,
                 cx.listener(move |this, _, _window, cx| {
                     info!("All tab clicked!");
-                    this.selected_tab = SelectedTab::All;
-                    cx.notify();
+                    this.select_tab(SelectedTab::All, cx);
                 }),
             )
             .child(div().text_sm().child("All"));
 
+        let is_compare_selected = self.selected_tab == SelectedTab::Compare;
+        let compare_btn = div()
+            .id("switch-compare")
+            .px(px(10.))
+            .py(px(5.))
+            .rounded(px(6.))
+            .cursor_pointer()
+            .text_color(if is_compare_selected {
+                gpui::white()
+            } else {
+                text_primary
+            })
+            .when(is_compare_selected, |el| el.bg(theme::accent()))
+            .when(!is_compare_selected, |el| {
+                el.hover(move |s| s.bg(hover_bg))
+                    .active(move |s| s.bg(active_bg))
+            })
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |this, _, _window, cx| {
+                    info!("Compare tab clicked");
+                    this.select_tab(SelectedTab::Compare, cx);
+                }),
+            )
+            .child(div().text_sm().child("Compare"));
+
         div()
             .px(px(10.))
             .py(px(8.))
@@ -110,10 +582,43 @@ impl MenuPanel {
             .gap(px(4.))
             // "All" tab first
             .child(all_btn)
+            .child(compare_btn)
+            // Then group tabs, if turned on
+            .children(groups.iter().enumerate().map(|(index, name)| {
+                let is_selected = self.selected_tab == SelectedTab::Group(index);
+                div()
+                    .id(SharedString::from(format!("switch-group-{index}")))
+                    .px(px(10.))
+                    .py(px(5.))
+                    .rounded(px(6.))
+                    .cursor_pointer()
+                    .text_color(if is_selected {
+                        gpui::white()
+                    } else {
+                        text_primary
+                    })
+                    .when(is_selected, |el| el.bg(theme::accent()))
+                    .when(!is_selected, |el| {
+                        el.hover(move |s| s.bg(hover_bg))
+                            .active(move |s| s.bg(active_bg))
+                    })
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |this, _, _window, cx| {
+                            info!(group = index, "Group tab clicked");
+                            this.select_tab(SelectedTab::Group(index), cx);
+                        }),
+                    )
+                    .child(div().text_sm().child(name.clone()))
+            }))
             // Then individual provider tabs
             .children(providers.iter().map(|&provider| {
                 let is_selected = self.selected_tab == SelectedTab::Provider(provider);
-                let name = provider.display_name();
+                let name = if pinned.contains(&provider) {
+                    format!("★ {}", provider.display_name())
+                } else {
+                    provider.display_name().to_string()
+                };
 
                 let mut btn = div()
                     .id(SharedString::from(format!("switch-{:?}", provider)))
@@ -126,21 +631,21 @@ impl MenuPanel {
                     .on_mouse_down(
                         MouseButton::Left,
                         cx.listener(move |this, _, _window, cx| {
-                            let p = provider; // Copy provider into closure (it's Copy)
-                            info!(provider = ?p, "Provider switch button clicked!");
-                            this.selected_tab = SelectedTab::Provider(p);
-
-                            // Check if this provider has data, if not trigger refresh
-                            let state = cx.global::<AppState>();
-                            let has_snapshot = state.get_snapshot(p, cx).is_some();
-                            if !has_snapshot {
-                                info!(provider = ?p, "No snapshot, triggering refresh");
-                                cx.update_global::<AppState, _>(|state, cx| {
-                                    state.refresh_provider(p, cx);
+                            info!(provider = ?provider, "Provider switch button clicked!");
+                            this.select_tab(SelectedTab::Provider(provider), cx);
+                        }),
+                    )
+                    // Right-click pins/unpins the provider
+                    .on_mouse_down(
+                        MouseButton::Right,
+                        cx.listener(move |_this, _, _window, cx| {
+                            info!(provider = ?provider, "Provider pin toggled");
+                            cx.update_global::<AppState, _>(|state, cx| {
+                                state.settings.update(cx, |model, cx| {
+                                    model.toggle_provider_pinned(provider);
+                                    cx.notify();
                                 });
-                            }
-
-                            cx.notify(); // Re-render with new selection!
+                            });
                         }),
                     );
 
@@ -170,9 +675,29 @@ impl Render for MenuPanel {
         let enabled = state.enabled_providers(cx);
 
         // Read settings and get theme mode
-        let theme_mode = {
+        let (
+            theme_mode,
+            compact,
+            tour_completed,
+            sections,
+            group_tabs,
+            show_totals,
+            toggles,
+            quit,
+            time_format,
+        ) = {
             let settings = settings_entity.read(cx);
-            settings.theme_mode()
+            (
+                settings.theme_mode(),
+                settings.compact_menu(),
+                settings.tour_completed(),
+                settings.provider_sections(),
+                settings.group_tabs(),
+                settings.show_totals_card(),
+                QuickToggles::new(settings.settings()),
+                QuitProtection::new(settings.settings()),
+                settings.settings().time_format,
+            )
         };
 
         if self.subscription.is_none() {
@@ -180,6 +705,9 @@ impl Render for MenuPanel {
                 cx.notify(); // Re-render when settings change
             }));
         }
+        if self.clock_task.is_none() {
+            self.start_clock(cx);
+        }
         info!(
             enabled_count = enabled.len(),
             providers = ?enabled,
@@ -190,56 +718,147 @@ impl Render for MenuPanel {
 
         theme::set_current_theme_mode(theme_mode, window.appearance());
 
+        // The cards are drawn, not native controls, so screen readers get
+        // a summary of the visible providers through the window title
+        let title = accessibility::describe_providers(&self.visible_providers(&enabled, cx), cx);
+        if title != self.window_title {
+            window.set_window_title(&title);
+            self.window_title = title;
+        }
+
+        // Take keyboard focus on first render so arrow keys work immediately
+        let focus_handle = match &self.focus_handle {
+            Some(handle) => handle.clone(),
+            None => {
+                let handle = cx.focus_handle();
+                window.focus(&handle);
+                self.focus_handle = Some(handle.clone());
+                handle
+            }
+        };
+
         let text_primary = theme::text_primary();
-        let border_color = theme::border();
         let menu_bg = theme::surface_background();
         let hover_bg = theme::hover();
         let active_bg = theme::active();
 
+        // Drop the cards of providers that were disabled
+        let selected_tab = self.selected_tab;
+        self.cards
+            .retain(|&p, _| enabled.contains(&p) || selected_tab == SelectedTab::Provider(p));
+
+        if !show_totals {
+            self.totals = None;
+        }
+        if self.selected_tab != SelectedTab::Compare {
+            self.comparison = None;
+        }
+
         // Build the content based on selected tab
         let content = match self.selected_tab {
             SelectedTab::All => {
-                // Render all provider cards in a vertical stack (scrolling handled by wrapper)
-                let cards: Vec<_> = enabled
-                    .iter()
-                    .map(|&p| MenuCard::new(MenuCardData::new(p, cx)))
-                    .collect();
+                // Render all provider cards in a vertical stack (scrolling handled by wrapper),
+                // under a header per provider group
+                let mut children = Vec::new();
+                if show_totals && !enabled.is_empty() {
+                    let totals = self
+                        .totals
+                        .get_or_insert_with(|| cx.new(TotalsView::new))
+                        .clone();
+                    children.push(AnyView::from(totals).into_any_element());
+                }
+                for (name, providers) in &sections {
+                    if let Some(name) = name {
+                        children.push(section_header(name.clone()).into_any_element());
+                    } else if sections.len() > 1 {
+                        children.push(section_header("Other".to_string()).into_any_element());
+                    }
+                    children.extend(
+                        providers
+                            .iter()
+                            .map(|&p| card_view(self.card(p, cx)).into_any_element()),
+                    );
+                }
+                if let Some(alerts) = RecentAlertsSection::new(time_format) {
+                    children.push(alerts.into_any_element());
+                }
 
                 div()
                     .id("all-providers-content")
                     .flex()
                     .flex_col()
-                    .children(cards.into_iter().map(|card| {
-                        // Wrap each card with a subtle separator
-                        div().border_b_1().border_color(border_color).child(card)
-                    }))
+                    .children(children)
+                    .into_any_element()
+            }
+            SelectedTab::Compare => {
+                let comparison = self
+                    .comparison
+                    .get_or_insert_with(|| cx.new(ComparisonView::new))
+                    .clone();
+                AnyView::from(comparison).into_any_element()
+            }
+            SelectedTab::Group(_) => {
+                let cards: Vec<_> = self
+                    .visible_providers(&enabled, cx)
+                    .into_iter()
+                    .map(|p| self.card(p, cx))
+                    .collect();
+
+                div()
+                    .id("group-providers-content")
+                    .flex()
+                    .flex_col()
+                    .children(cards.into_iter().map(card_view))
                     .into_any_element()
             }
             SelectedTab::Provider(provider) => {
                 // Single provider view (existing behavior)
-                let card_data = MenuCardData::new(provider, cx);
-                div().child(MenuCard::new(card_data)).into_any_element()
+                div()
+                    .child(card_view(self.card(provider, cx)))
+                    .into_any_element()
             }
         };
 
+        // First-run tour, shown over the popup until finished or skipped
+        let tour = (!tour_completed && !self.detached && !enabled.is_empty()).then(|| {
+            let steps = TourStep::steps(enabled.len() > 1);
+            let total = steps.len();
+            let index = self.tour_step.min(total - 1);
+            TourOverlay::new(steps[index], index, total)
+                .on_next(cx.listener(move |this, _, _, cx| this.advance_tour(total, cx)))
+                .on_skip(cx.listener(|this, _, _, cx| this.finish_tour(cx)))
+        });
+
         let root = div()
             .id("menu-panel")
-            .w(px(340.)) // Slightly wider like Notification Center
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key_down))
+            // Fill the (user-resizable) window
+            .size_full()
             .bg(menu_bg) // Theme-aware background
-            .h_full()
             .overflow_hidden()
             // Deep shadow for floating glass effect
             .shadow_lg()
+            // Positioning context for the tour overlay
+            .relative()
             // Flex layout for proper space distribution
             .flex()
             .flex_col()
-            .max_h(px(600.)) // Max height for entire menu
             // Header (fixed height)
-            .child(MenuHeader::new())
+            .child(MenuHeader::new(self.selected_tab, self.detached))
             // Provider switcher if multiple providers enabled - rendered here for cx.listener() access!
             .when(enabled.len() > 1, |el| {
+                let groups: Vec<String> = if group_tabs {
+                    sections
+                        .iter()
+                        .filter_map(|(name, _)| name.clone())
+                        .collect()
+                } else {
+                    Vec::new()
+                };
                 el.child(self.render_provider_switcher(
                     &enabled,
+                    &groups,
                     text_primary,
                     hover_bg,
                     active_bg,
@@ -256,7 +875,15 @@ impl Render for MenuPanel {
                     .child(content),
             )
             // Action footer with WORKING buttons (fixed height)
-            .child(MenuFooter::new());
+            .child(
+                MenuFooter::new()
+                    .compact(compact)
+                    .toggles(toggles)
+                    .quit_protection(quit)
+                    .providers(self.visible_providers(&enabled, cx))
+                    .focused(self.keyboard_focus.footer()),
+            )
+            .when_some(tour, |el, tour| el.child(tour));
 
         // Apply opaque background on Linux (no blur support)
         #[cfg(target_os = "linux")]
@@ -266,15 +893,50 @@ impl Render for MenuPanel {
     }
 }
 
+/// Returns the named provider groups with their enabled providers, in the
+/// order of their tabs.
+fn provider_groups(cx: &App) -> Vec<(String, Vec<ProviderKind>)> {
+    cx.global::<AppState>()
+        .settings
+        .read(cx)
+        .provider_sections()
+        .into_iter()
+        .filter_map(|(name, providers)| Some((name?, providers)))
+        .collect()
+}
+
+/// Title above a provider group's cards in the "All" view.
+fn section_header(name: String) -> Div {
+    div()
+        .px(px(14.))
+        .pt(px(10.))
+        .pb(px(4.))
+        .text_xs()
+        .font_weight(FontWeight::SEMIBOLD)
+        .text_color(theme::text_secondary())
+        .child(name)
+}
+
+/// Embeds a card, re-rendering it only when the card itself is notified.
+fn card_view(card: Entity<ProviderCardView>) -> AnyView {
+    AnyView::from(card).cached(StyleRefinement::default().w_full())
+}
+
 // ============================================================================
 // Menu Header
 // ============================================================================
 
-struct MenuHeader;
+struct MenuHeader {
+    selected_tab: SelectedTab,
+    detached: bool,
+}
 
 impl MenuHeader {
-    fn new() -> Self {
-        Self
+    fn new(selected_tab: SelectedTab, detached: bool) -> Self {
+        Self {
+            selected_tab,
+            detached,
+        }
     }
 }
 
@@ -282,6 +944,8 @@ impl IntoElement for MenuHeader {
     type Element = Div;
 
     fn into_element(self) -> Self::Element {
+        let selected_tab = self.selected_tab;
+
         div()
             .px(px(14.))
             .py(px(10.))
@@ -310,6 +974,26 @@ impl IntoElement for MenuHeader {
                             .child(env!("CARGO_PKG_VERSION")),
                     ),
             )
+            // Pin button - detaches the popup into a floating window
+            .when(!self.detached, |el| {
+                el.child(
+                    div()
+                        .id("pin-panel")
+                        .px(px(6.))
+                        .py(px(2.))
+                        .rounded(px(4.))
+                        .cursor_pointer()
+                        .text_sm()
+                        .text_color(theme::muted())
+                        .hover(|s| s.bg(theme::hover()))
+                        .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                            info!("Detaching menu into pinned panel");
+                            windows::open_detached_panel(selected_tab, cx);
+                            window.remove_window();
+                        })
+                        .child("📌"),
+                )
+            })
     }
 }
 
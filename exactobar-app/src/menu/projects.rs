@@ -0,0 +1,133 @@
+//! Today's activity and top projects for CLI providers.
+//!
+//! From the provider's local session logs: a row with the requests and
+//! tokens used today across all projects, and the project directories that
+//! used the most tokens, with each one's share of the total.
+
+use exactobar_providers::ProjectUsage;
+use gpui::*;
+
+use super::breakdown::format_count;
+use crate::theme;
+
+/// How many projects to list.
+const TOP_PROJECTS: usize = 3;
+
+// ============================================================================
+// Today Row
+// ============================================================================
+
+pub struct TodayRow {
+    requests: u64,
+    tokens: u64,
+    projects: usize,
+}
+
+impl TodayRow {
+    pub fn new(projects: &[ProjectUsage]) -> Self {
+        Self {
+            requests: projects.iter().map(|p| p.requests).sum(),
+            tokens: projects.iter().map(|p| p.tokens).sum(),
+            projects: projects.len(),
+        }
+    }
+}
+
+impl IntoElement for TodayRow {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let requests = match self.requests {
+            1 => "1 request".to_string(),
+            n => format!("{} requests", format_count(n as f64)),
+        };
+        let projects = match self.projects {
+            1 => "1 project".to_string(),
+            n => format!("{n} projects"),
+        };
+
+        div()
+            .px(px(14.))
+            .py(px(6.))
+            .bg(theme::card_background())
+            .border_b_1()
+            .border_color(theme::glass_separator())
+            .flex()
+            .items_center()
+            .justify_between()
+            .gap(px(8.))
+            .text_xs()
+            .child(div().text_color(theme::text_secondary()).child("Today"))
+            .child(div().text_color(theme::text_primary()).child(format!(
+                "{} · {} tokens · {}",
+                requests,
+                format_count(self.tokens as f64),
+                projects
+            )))
+    }
+}
+
+// ============================================================================
+// Top Projects Section
+// ============================================================================
+
+pub struct TopProjectsSection {
+    projects: Vec<ProjectUsage>,
+    total: u64,
+}
+
+impl TopProjectsSection {
+    pub fn new(projects: &[ProjectUsage]) -> Self {
+        Self {
+            total: projects.iter().map(|p| p.tokens).sum(),
+            projects: projects.iter().take(TOP_PROJECTS).cloned().collect(),
+        }
+    }
+}
+
+impl IntoElement for TopProjectsSection {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let total = self.total.max(1) as f64;
+
+        div()
+            .px(px(14.))
+            .py(px(8.))
+            .bg(theme::card_background())
+            .border_b_1()
+            .border_color(theme::glass_separator())
+            .flex()
+            .flex_col()
+            .gap(px(4.))
+            .child(
+                div()
+                    .text_sm()
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(theme::text_primary())
+                    .child("Top projects today"),
+            )
+            .children(self.projects.into_iter().map(|project| {
+                let share = project.tokens as f64 / total * 100.0;
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .gap(px(8.))
+                    .text_xs()
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w(px(0.))
+                            .overflow_hidden()
+                            .text_color(theme::text_primary())
+                            .child(project.name()),
+                    )
+                    .child(div().text_color(theme::text_secondary()).child(format!(
+                        "{} tokens · {:.0}%",
+                        format_count(project.tokens as f64),
+                        share
+                    )))
+            }))
+    }
+}
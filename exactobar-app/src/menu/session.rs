@@ -0,0 +1,99 @@
+//! Current session section for Claude.
+//!
+//! Beneath the rolling 5-hour and weekly windows, shows the Claude Code
+//! session being written to: tokens used so far, how full its context
+//! window is, and what it has cost at list prices.
+
+use exactobar_core::format_currency;
+use exactobar_providers::claude::{CONTEXT_WINDOW_TOKENS, LiveSession};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+
+use super::breakdown::format_count;
+use super::usage::ProgressBar;
+use crate::theme;
+
+// ============================================================================
+// Current Session Section
+// ============================================================================
+
+pub struct CurrentSessionSection {
+    session: LiveSession,
+    /// Cost so far estimated from token prices, in USD.
+    cost_usd: Option<f64>,
+}
+
+impl CurrentSessionSection {
+    pub fn new(session: LiveSession, cost_usd: Option<f64>) -> Self {
+        Self { session, cost_usd }
+    }
+}
+
+impl IntoElement for CurrentSessionSection {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let context_percent = self.session.context_percent();
+        let context = format!(
+            "{:.0}% ({} of {})",
+            context_percent,
+            format_count(self.session.context_tokens as f64),
+            format_count(CONTEXT_WINDOW_TOKENS as f64)
+        );
+
+        div()
+            .px(px(14.))
+            .py(px(8.))
+            .bg(theme::card_background())
+            .border_b_1()
+            .border_color(theme::glass_separator())
+            .flex()
+            .flex_col()
+            .gap(px(4.))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_weight(FontWeight::MEDIUM)
+                            .text_color(theme::text_primary())
+                            .child("Current session"),
+                    )
+                    .when_some(self.cost_usd, |el, cost| {
+                        el.child(
+                            div()
+                                .text_xs()
+                                .text_color(theme::text_secondary())
+                                .child(format!("≈{}", format_currency(cost, "USD"))),
+                        )
+                    }),
+            )
+            .child(
+                div()
+                    .flex()
+                    .justify_between()
+                    .text_xs()
+                    .child(div().text_color(theme::text_secondary()).child("Tokens"))
+                    .child(
+                        div()
+                            .text_color(theme::text_primary())
+                            .child(format_count(self.session.tokens as f64)),
+                    ),
+            )
+            .child(
+                div()
+                    .flex()
+                    .justify_between()
+                    .text_xs()
+                    .child(div().text_color(theme::text_secondary()).child("Context"))
+                    .child(div().text_color(theme::text_primary()).child(context)),
+            )
+            .child(ProgressBar::new(
+                context_percent,
+                theme::color_for_usage(context_percent),
+            ))
+    }
+}
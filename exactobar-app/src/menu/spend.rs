@@ -0,0 +1,101 @@
+//! Spend row for providers that report cost data.
+//!
+//! Shows "$14.20 of $50 monthly budget" beneath the usage metrics,
+//! colored by how much of the budget has been consumed. Providers that only
+//! report tokens per model get an estimate from the pricing table instead,
+//! marked with "≈".
+
+use exactobar_core::{ProviderCost, format_currency};
+use gpui::*;
+
+use crate::theme;
+
+// ============================================================================
+// Spend Row
+// ============================================================================
+
+pub struct SpendRow {
+    cost: ProviderCost,
+    currency_code: String,
+    /// Whether the amount was estimated from token counts.
+    estimated: bool,
+}
+
+impl SpendRow {
+    /// Creates a spend row, falling back to `budget` when the provider
+    /// doesn't report its own limit.
+    pub fn new(cost: &ProviderCost, currency_code: &str, budget: Option<f64>) -> Self {
+        let mut cost = cost.clone();
+        if cost.limit.is_none() {
+            cost.limit = budget;
+        }
+        Self {
+            cost,
+            currency_code: currency_code.to_string(),
+            estimated: false,
+        }
+    }
+
+    /// Creates a spend row for an amount estimated from token prices, which
+    /// are in US dollars.
+    pub fn estimated(used_usd: f64, budget: Option<f64>) -> Self {
+        let mut cost = ProviderCost::new(used_usd);
+        cost.limit = budget;
+        Self {
+            cost,
+            currency_code: "USD".to_string(),
+            estimated: true,
+        }
+    }
+
+    fn label(&self) -> String {
+        let mut used = format_currency(self.cost.used, &self.currency_code);
+        if self.estimated {
+            used.insert(0, '≈');
+        }
+        let period = self.cost.period.as_deref().unwrap_or("monthly");
+
+        match self.cost.limit {
+            Some(limit) => format!(
+                "{} of {} {} budget",
+                used,
+                format_currency(limit, &self.currency_code),
+                period
+            ),
+            None => format!("{} spent", used),
+        }
+    }
+}
+
+impl IntoElement for SpendRow {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let label = self.label();
+
+        // Color by budget consumption; muted when there's no budget to compare against
+        let color = self
+            .cost
+            .used_percent()
+            .map(theme::color_for_usage)
+            .unwrap_or_else(theme::text_secondary);
+
+        div()
+            .px(px(14.))
+            .py(px(8.))
+            .bg(theme::card_background())
+            .border_b_1()
+            .border_color(theme::glass_separator())
+            .flex()
+            .items_center()
+            .justify_between()
+            .child(
+                div()
+                    .text_sm()
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(theme::text_primary())
+                    .child("Spend"),
+            )
+            .child(div().text_xs().text_color(color).child(label))
+    }
+}
@@ -0,0 +1,199 @@
+//! Markdown usage summaries for pasting into standups and issue reports.
+//!
+//! Formats each provider's usage windows, reset times, spend, and errors
+//! as a Markdown snippet and copies it with [`copy_to_clipboard`].
+
+use chrono::{DateTime, Local, Utc};
+use exactobar_core::{ProviderKind, UsageSnapshot, UsageWindow, format_currency};
+use exactobar_providers::ProviderRegistry;
+use gpui::App;
+use tracing::info;
+
+use crate::state::AppState;
+
+use super::error::copy_to_clipboard;
+use super::usage::format_countdown;
+
+/// Copies a Markdown summary of the given providers to the clipboard.
+pub fn copy_summary(providers: &[ProviderKind], cx: &App) {
+    let summary = markdown_summary(providers, cx);
+    info!(providers = providers.len(), "Copying usage summary");
+    copy_to_clipboard(&summary);
+}
+
+/// Builds a Markdown summary of the given providers' current usage.
+pub fn markdown_summary(providers: &[ProviderKind], cx: &App) -> String {
+    let state = cx.global::<AppState>();
+    let currency_code = state.settings.read(cx).currency_code().to_string();
+    let now = Utc::now();
+
+    let sections: Vec<String> = providers
+        .iter()
+        .map(|&provider| {
+            let descriptor = ProviderRegistry::get(provider);
+            let labels = WindowLabels {
+                session: descriptor
+                    .map(|d| d.metadata.session_label.as_str())
+                    .unwrap_or("Session"),
+                weekly: descriptor
+                    .map(|d| d.metadata.weekly_label.as_str())
+                    .unwrap_or("Weekly"),
+            };
+            let name = descriptor
+                .map(|d| d.display_name().to_string())
+                .unwrap_or_else(|| format!("{:?}", provider));
+
+            format_provider(
+                &name,
+                &labels,
+                state.get_snapshot(provider, cx).as_ref(),
+                state.get_error(provider, cx).as_deref(),
+                &currency_code,
+                now,
+            )
+        })
+        .collect();
+
+    let local_now: DateTime<Local> = now.into();
+    format!(
+        "**Usage summary** ({})\n\n{}",
+        local_now.format("%Y-%m-%d %H:%M"),
+        sections.join("\n\n")
+    )
+}
+
+/// Labels for the provider-specific usage windows.
+struct WindowLabels<'a> {
+    session: &'a str,
+    weekly: &'a str,
+}
+
+/// Formats one provider as a Markdown heading followed by a bullet list.
+fn format_provider(
+    name: &str,
+    labels: &WindowLabels<'_>,
+    snapshot: Option<&UsageSnapshot>,
+    error: Option<&str>,
+    currency_code: &str,
+    now: DateTime<Utc>,
+) -> String {
+    let mut lines = vec![format!("### {}", name)];
+
+    if let Some(error) = error {
+        lines.push(format!(
+            "- Error: {}",
+            error.lines().next().unwrap_or(error)
+        ));
+    }
+
+    match snapshot {
+        Some(snapshot) => {
+            let windows = [
+                (labels.session, &snapshot.primary),
+                (labels.weekly, &snapshot.secondary),
+                ("Premium", &snapshot.tertiary),
+                ("Search", &snapshot.search),
+            ];
+            for (label, window) in windows
+                .into_iter()
+                .filter_map(|(label, window)| window.as_ref().map(|w| (label, w)))
+            {
+                lines.push(format_window(label, window, now));
+            }
+
+            if let Some(cost) = &snapshot.cost {
+                let used = format_currency(cost.used, currency_code);
+                lines.push(match cost.limit {
+                    Some(limit) => format!(
+                        "- Spend: {} of {}",
+                        used,
+                        format_currency(limit, currency_code)
+                    ),
+                    None => format!("- Spend: {}", used),
+                });
+            }
+        }
+        None if error.is_none() => lines.push("- No data yet".to_string()),
+        None => {}
+    }
+
+    lines.join("\n")
+}
+
+/// Formats a single usage window bullet (e.g., "- Session: 45% used, resets in 2h 5m").
+fn format_window(label: &str, window: &UsageWindow, now: DateTime<Utc>) -> String {
+    let mut line = format!("- {}: {:.0}% used", label, window.used_percent);
+    if let Some(reset) = reset_phrase(window, now) {
+        line.push_str(", ");
+        line.push_str(&reset);
+    }
+    line
+}
+
+/// Describes when a window resets (e.g., "resets in 2h 5m"), if known.
+pub(crate) fn reset_phrase(window: &UsageWindow, now: DateTime<Utc>) -> Option<String> {
+    match (window.resets_at, window.next_reset(now)) {
+        (_, Some(reset_at)) => Some(match format_countdown(reset_at, now) {
+            Some(countdown) => format!("resets in {}", countdown),
+            None => "resets soon".to_string(),
+        }),
+        // Passed, with no window length to roll it forward by
+        (Some(_), None) => None,
+        (None, None) => window
+            .reset_description
+            .as_ref()
+            .map(|d| format!("resets {}", d)),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    const LABELS: WindowLabels<'static> = WindowLabels {
+        session: "Session",
+        weekly: "Weekly",
+    };
+
+    #[test]
+    fn test_format_provider_with_windows() {
+        let now = Utc::now();
+        let mut snapshot = UsageSnapshot::new();
+        let mut primary = UsageWindow::new(45.0);
+        primary.resets_at = Some(now + Duration::minutes(125));
+        snapshot.primary = Some(primary);
+        snapshot.secondary = Some(UsageWindow::new(20.0));
+
+        let text = format_provider("Claude", &LABELS, Some(&snapshot), None, "USD", now);
+
+        assert_eq!(
+            text,
+            "### Claude\n- Session: 45% used, resets in 2h 5m\n- Weekly: 20% used"
+        );
+    }
+
+    #[test]
+    fn test_format_provider_with_error() {
+        let text = format_provider(
+            "Codex",
+            &LABELS,
+            None,
+            Some("CLI not found\nmore details"),
+            "USD",
+            Utc::now(),
+        );
+
+        assert_eq!(text, "### Codex\n- Error: CLI not found");
+    }
+
+    #[test]
+    fn test_format_provider_without_data() {
+        let text = format_provider("Cursor", &LABELS, None, None, "USD", Utc::now());
+        assert_eq!(text, "### Cursor\n- No data yet");
+    }
+}
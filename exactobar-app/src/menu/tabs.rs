@@ -1,7 +1,7 @@
 //! Tab selection types for the menu panel.
 //!
-//! Provides the `SelectedTab` enum for switching between "All" providers view
-//! and individual provider views.
+//! Provides the `SelectedTab` enum for switching between "All" providers view,
+//! the comparison view, provider group views and individual provider views.
 
 use exactobar_core::ProviderKind;
 
@@ -10,6 +10,11 @@ use exactobar_core::ProviderKind;
 pub enum SelectedTab {
     /// Show all enabled providers stacked vertically.
     All,
+    /// Compare the enabled providers' equivalent windows side by side.
+    Compare,
+    /// Show the enabled providers of a group, by index among the non-empty
+    /// groups.
+    Group(usize),
     /// Show a single provider's details.
     Provider(ProviderKind),
 }
@@ -19,6 +24,9 @@ impl SelectedTab {
     pub fn display_name(&self) -> &'static str {
         match self {
             SelectedTab::All => "All",
+            SelectedTab::Compare => "Compare",
+            // Groups are named in the settings
+            SelectedTab::Group(_) => "Group",
             SelectedTab::Provider(p) => p.display_name(),
         }
     }
@@ -28,10 +36,15 @@ impl SelectedTab {
         matches!(self, SelectedTab::All)
     }
 
+    /// Returns true if this tab stacks several provider cards.
+    pub fn is_list(&self) -> bool {
+        matches!(self, SelectedTab::All | SelectedTab::Group(_))
+    }
+
     /// Returns the provider if this is a single-provider tab.
     pub fn provider(&self) -> Option<ProviderKind> {
         match self {
-            SelectedTab::All => None,
+            SelectedTab::All | SelectedTab::Compare | SelectedTab::Group(_) => None,
             SelectedTab::Provider(p) => Some(*p),
         }
     }
@@ -0,0 +1,215 @@
+//! Totals card at the top of the "All" view.
+//!
+//! Sums up the enabled providers at a glance: the spend recorded today, how
+//! many providers are above 80% of a window, and which window resets next.
+
+use chrono::{DateTime, Local, Utc};
+use exactobar_core::{ProviderKind, UsageSnapshot, format_currency};
+use gpui::*;
+
+use crate::state::AppState;
+use crate::theme;
+
+use super::usage::format_countdown;
+
+/// Figures shown on the totals card.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Totals {
+    /// Spend recorded today across providers, if any provider reports cost.
+    pub spent_today: Option<f64>,
+    /// Providers with a window above 80% used.
+    pub near_limit: usize,
+    /// Providers with usage data.
+    pub reporting: usize,
+    /// The soonest upcoming reset, and whose it is.
+    pub next_reset: Option<(ProviderKind, DateTime<Utc>)>,
+}
+
+impl Totals {
+    /// Adds up the providers' latest snapshots and today's spend per
+    /// provider.
+    pub fn new(
+        snapshots: &[(ProviderKind, UsageSnapshot)],
+        spend_today: &[f64],
+        now: DateTime<Utc>,
+    ) -> Self {
+        let next_reset = snapshots
+            .iter()
+            .flat_map(|(provider, snapshot)| {
+                [
+                    &snapshot.primary,
+                    &snapshot.secondary,
+                    &snapshot.tertiary,
+                    &snapshot.search,
+                ]
+                .into_iter()
+                .flatten()
+                .filter_map(move |w| w.next_reset(now).map(|reset| (*provider, reset)))
+            })
+            .filter(|&(_, reset)| reset > now)
+            .min_by_key(|&(_, reset)| reset);
+
+        Self {
+            spent_today: (!spend_today.is_empty()).then(|| spend_today.iter().sum()),
+            near_limit: snapshots
+                .iter()
+                .filter(|(_, s)| s.is_approaching_limit())
+                .count(),
+            reporting: snapshots.len(),
+            next_reset,
+        }
+    }
+}
+
+// ============================================================================
+// Totals View
+// ============================================================================
+
+/// The totals card, re-rendering as usage changes.
+pub struct TotalsView {
+    _subscriptions: [Subscription; 2],
+}
+
+impl TotalsView {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        let state = cx.global::<AppState>();
+        let usage = state.usage.clone();
+        let settings = state.settings.clone();
+
+        Self {
+            _subscriptions: [
+                cx.observe(&usage, |_, _, cx| cx.notify()),
+                cx.observe(&settings, |_, _, cx| cx.notify()),
+            ],
+        }
+    }
+}
+
+impl Render for TotalsView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let state = cx.global::<AppState>();
+        let providers = state.enabled_providers(cx);
+        let currency_code = state.settings.read(cx).currency_code().to_string();
+        let history = state.usage.read(cx).history();
+        let today = Local::now().date_naive();
+        let now = Utc::now();
+
+        let snapshots: Vec<(ProviderKind, UsageSnapshot)> = providers
+            .iter()
+            .filter_map(|&p| state.get_snapshot(p, cx).map(|s| (p, s)))
+            .collect();
+        let spend_today: Vec<f64> = providers
+            .iter()
+            .filter_map(|&p| {
+                history
+                    .daily_spend(p, &Local)
+                    .last()
+                    .filter(|(day, _)| *day == today)
+                    .map(|&(_, spent)| spent)
+            })
+            .collect();
+        let totals = Totals::new(&snapshots, &spend_today, now);
+
+        let spent = totals.spent_today.map_or_else(
+            || "—".to_string(),
+            |spent| format_currency(spent, &currency_code),
+        );
+        let near_limit = format!("{} of {}", totals.near_limit, totals.reporting);
+        let next_reset = totals
+            .next_reset
+            .and_then(|(provider, reset)| {
+                format_countdown(reset, now).map(|countdown| (provider, countdown))
+            })
+            .map_or_else(
+                || "—".to_string(),
+                |(provider, countdown)| format!("{} in {}", provider.display_name(), countdown),
+            );
+        let near_limit_color = if totals.near_limit > 0 {
+            theme::warning()
+        } else {
+            theme::text_primary()
+        };
+
+        div()
+            .px(px(14.))
+            .py(px(10.))
+            .bg(theme::card_background())
+            .border_b_1()
+            .border_color(theme::glass_separator())
+            .flex()
+            .gap(px(12.))
+            .child(stat("Spent today", spent, theme::text_primary()))
+            .child(stat("Above 80%", near_limit, near_limit_color))
+            .child(stat("Next reset", next_reset, theme::text_primary()))
+    }
+}
+
+/// A labelled figure on the totals card.
+fn stat(label: &'static str, value: String, color: Hsla) -> Div {
+    div()
+        .flex_1()
+        .min_w(px(0.))
+        .flex()
+        .flex_col()
+        .gap(px(2.))
+        .child(
+            div()
+                .text_xs()
+                .text_color(theme::text_secondary())
+                .child(label),
+        )
+        .child(
+            div()
+                .text_sm()
+                .font_weight(FontWeight::MEDIUM)
+                .text_color(color)
+                .overflow_hidden()
+                .child(value),
+        )
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use exactobar_core::UsageWindow;
+
+    fn window(used_percent: f64, resets_at: Option<DateTime<Utc>>) -> UsageWindow {
+        let mut window = UsageWindow::new(used_percent);
+        window.resets_at = resets_at;
+        window
+    }
+
+    #[test]
+    fn test_totals() {
+        let now = Utc::now();
+        let mut claude = UsageSnapshot::new();
+        claude.primary = Some(window(92.0, Some(now + Duration::hours(2))));
+        claude.secondary = Some(window(40.0, Some(now + Duration::days(3))));
+        let mut codex = UsageSnapshot::new();
+        codex.primary = Some(window(10.0, Some(now + Duration::minutes(30))));
+        // A reset that already passed doesn't count as upcoming
+        codex.secondary = Some(window(50.0, Some(now - Duration::minutes(5))));
+
+        let totals = Totals::new(
+            &[(ProviderKind::Claude, claude), (ProviderKind::Codex, codex)],
+            &[1.5, 2.25],
+            now,
+        );
+        assert_eq!(totals.spent_today, Some(3.75));
+        assert_eq!(totals.near_limit, 1);
+        assert_eq!(totals.reporting, 2);
+        assert_eq!(
+            totals.next_reset,
+            Some((ProviderKind::Codex, now + Duration::minutes(30)))
+        );
+
+        let empty = Totals::new(&[], &[], now);
+        assert_eq!(empty.spent_today, None);
+        assert_eq!(empty.next_reset, None);
+    }
+}
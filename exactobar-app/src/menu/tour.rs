@@ -0,0 +1,254 @@
+//! First-run tour of the menu panel.
+//!
+//! Coach marks shown over the panel the first time it opens, pointing out
+//! the main controls one at a time. Finishing or skipping the tour sets
+//! `tour_completed` in settings so it isn't shown again.
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+
+use super::breakdown::ToggleHandler;
+use crate::theme;
+
+// ============================================================================
+// Tour Steps
+// ============================================================================
+
+/// A control pointed out by the tour.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TourStep {
+    Tabs,
+    ProgressBars,
+    StaleIndicator,
+    Footer,
+}
+
+impl TourStep {
+    /// Returns the steps to show, in order.
+    ///
+    /// The tab switcher is only shown with more than one provider enabled.
+    pub fn steps(has_switcher: bool) -> Vec<TourStep> {
+        [
+            TourStep::Tabs,
+            TourStep::ProgressBars,
+            TourStep::StaleIndicator,
+            TourStep::Footer,
+        ]
+        .into_iter()
+        .filter(|&step| has_switcher || step != TourStep::Tabs)
+        .collect()
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            TourStep::Tabs => "Switch providers",
+            TourStep::ProgressBars => "Usage at a glance",
+            TourStep::StaleIndicator => "Freshness",
+            TourStep::Footer => "Quick actions",
+        }
+    }
+
+    fn body(self) -> &'static str {
+        match self {
+            TourStep::Tabs => {
+                "Pick a provider, or All to see every card. Right-click a tab to pin it first."
+            }
+            TourStep::ProgressBars => {
+                "Each bar is a usage window. Hover for exact numbers and when it resets."
+            }
+            TourStep::StaleIndicator => {
+                "Shows when data was last updated. It turns red if a refresh failed or the data is stale."
+            }
+            TourStep::Footer => {
+                "Refresh, copy a summary, open the usage dashboard, or change settings."
+            }
+        }
+    }
+
+    /// Where the coach mark sits, relative to the panel.
+    fn placement(self) -> Placement {
+        match self {
+            TourStep::Tabs => Placement::Top(px(84.)),
+            TourStep::StaleIndicator => Placement::Top(px(140.)),
+            TourStep::ProgressBars => Placement::Top(px(190.)),
+            TourStep::Footer => Placement::Bottom(px(52.)),
+        }
+    }
+}
+
+/// Vertical position of a coach mark; the arrow points toward the edge it's measured from.
+#[derive(Clone, Copy)]
+enum Placement {
+    Top(Pixels),
+    Bottom(Pixels),
+}
+
+// ============================================================================
+// Tour Overlay
+// ============================================================================
+
+/// Dimmed overlay with the coach mark for the current step.
+pub struct TourOverlay {
+    step: TourStep,
+    index: usize,
+    total: usize,
+    on_next: Option<ToggleHandler>,
+    on_skip: Option<ToggleHandler>,
+}
+
+impl TourOverlay {
+    pub fn new(step: TourStep, index: usize, total: usize) -> Self {
+        Self {
+            step,
+            index,
+            total,
+            on_next: None,
+            on_skip: None,
+        }
+    }
+
+    /// Sets the handler for "Next" (and "Done" on the last step).
+    pub fn on_next(
+        mut self,
+        handler: impl Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_next = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets the handler for "Skip tour".
+    pub fn on_skip(
+        mut self,
+        handler: impl Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_skip = Some(Box::new(handler));
+        self
+    }
+}
+
+impl IntoElement for TourOverlay {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let placement = self.step.placement();
+        let is_last = self.index + 1 == self.total;
+        let arrow = div()
+            .px(px(24.))
+            .text_sm()
+            .text_color(theme::accent())
+            .child(match placement {
+                Placement::Top(_) => "▲",
+                Placement::Bottom(_) => "▼",
+            });
+
+        let bubble = div()
+            .p(px(12.))
+            .rounded(px(10.))
+            .bg(theme::card_background())
+            .border_1()
+            .border_color(theme::accent())
+            .shadow_lg()
+            .flex()
+            .flex_col()
+            .gap(px(6.))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .text_color(theme::text_primary())
+                            .child(self.step.title()),
+                    )
+                    .child(div().text_xs().text_color(theme::muted()).child(format!(
+                        "{} of {}",
+                        self.index + 1,
+                        self.total
+                    ))),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(theme::text_secondary())
+                    .child(self.step.body()),
+            )
+            .child(
+                div()
+                    .pt(px(4.))
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(
+                        div()
+                            .id("tour-skip")
+                            .text_xs()
+                            .text_color(theme::muted())
+                            .cursor_pointer()
+                            .hover(|s| s.text_color(theme::text_primary()))
+                            .when_some(self.on_skip, |el, handler| {
+                                el.on_mouse_down(MouseButton::Left, handler)
+                            })
+                            .child("Skip tour"),
+                    )
+                    .child(
+                        div()
+                            .id("tour-next")
+                            .px(px(10.))
+                            .py(px(4.))
+                            .rounded(px(6.))
+                            .bg(theme::accent())
+                            .text_xs()
+                            .text_color(gpui::white())
+                            .cursor_pointer()
+                            .when_some(self.on_next, |el, handler| {
+                                el.on_mouse_down(MouseButton::Left, handler)
+                            })
+                            .child(if is_last { "Done" } else { "Next" }),
+                    ),
+            );
+
+        let mark = div()
+            .absolute()
+            .left(px(12.))
+            .right(px(12.))
+            .flex()
+            .flex_col();
+        let mark = match placement {
+            Placement::Top(offset) => mark.top(offset).child(arrow).child(bubble),
+            Placement::Bottom(offset) => mark.bottom(offset).child(bubble).child(arrow),
+        };
+
+        div()
+            .absolute()
+            .top_0()
+            .left_0()
+            .size_full()
+            .bg(theme::transparent(gpui::black(), 0.35))
+            .child(mark)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_skip_tabs_without_switcher() {
+        assert_eq!(TourStep::steps(true).len(), 4);
+        assert_eq!(
+            TourStep::steps(false),
+            vec![
+                TourStep::ProgressBars,
+                TourStep::StaleIndicator,
+                TourStep::Footer
+            ]
+        );
+    }
+}
@@ -3,8 +3,11 @@
 //! Provides progress bars and usage metric rows for displaying
 //! session, weekly, and premium usage limits.
 
+use std::collections::HashMap;
+
 use chrono::{DateTime, Local, Utc};
-use exactobar_core::UsageSnapshot;
+use exactobar_core::{ProviderKind, UsageSnapshot, UsageWindow, WindowKind};
+use exactobar_store::{TimeFormat, format_date};
 use gpui::prelude::FluentBuilder;
 use gpui::*;
 
@@ -15,76 +18,92 @@ use crate::theme;
 // ============================================================================
 
 pub struct UsageMetricsSection {
+    provider: ProviderKind,
     metrics: Vec<UsageMetric>,
 }
 
+/// How reset times are shown.
+#[derive(Debug, Clone, Copy)]
+pub struct ResetDisplay {
+    /// When true, show "Resets Tue 09:00" instead of "Resets in 2h 30m"
+    pub absolute: bool,
+    /// 12- or 24-hour clock
+    pub time_format: TimeFormat,
+}
+
 struct UsageMetric {
     title: String,
-    used_percent: f64,
-    resets_at: Option<DateTime<Utc>>,
-    reset_description: Option<String>,
+    window: UsageWindow,
     /// When true, show "X% used" instead of "X% remaining"
     show_used: bool,
-    /// When true, show "Resets at 3:00 PM" instead of "Resets in 2h 30m"
-    show_absolute: bool,
+    resets: ResetDisplay,
+    /// Recorded usage since the window's last reset, oldest first.
+    sparkline: Vec<f64>,
+    /// Time until the window is used up at the recent burn rate.
+    eta: Option<chrono::Duration>,
 }
 
 impl UsageMetricsSection {
     pub fn new(
+        provider: ProviderKind,
         snapshot: &UsageSnapshot,
         session_label: &str,
         weekly_label: &str,
         search_label: Option<&str>,
         show_used: bool,
-        show_absolute: bool,
+        resets: ResetDisplay,
+        sparklines: &HashMap<WindowKind, Vec<f64>>,
+        etas: &HashMap<WindowKind, chrono::Duration>,
     ) -> Self {
         let mut metrics = Vec::new();
+        let sparkline = |kind: WindowKind| sparklines.get(&kind).cloned().unwrap_or_default();
+        let eta = |kind: WindowKind| etas.get(&kind).copied();
 
         if let Some(primary) = &snapshot.primary {
             metrics.push(UsageMetric {
                 title: session_label.to_string(),
-                used_percent: primary.used_percent,
-                resets_at: primary.resets_at,
-                reset_description: primary.reset_description.clone(),
+                window: primary.clone(),
                 show_used,
-                show_absolute,
+                resets,
+                sparkline: sparkline(WindowKind::Primary),
+                eta: eta(WindowKind::Primary),
             });
         }
 
         if let Some(secondary) = &snapshot.secondary {
             metrics.push(UsageMetric {
                 title: weekly_label.to_string(),
-                used_percent: secondary.used_percent,
-                resets_at: secondary.resets_at,
-                reset_description: secondary.reset_description.clone(),
+                window: secondary.clone(),
                 show_used,
-                show_absolute,
+                resets,
+                sparkline: sparkline(WindowKind::Secondary),
+                eta: eta(WindowKind::Secondary),
             });
         }
 
         if let Some(tertiary) = &snapshot.tertiary {
             metrics.push(UsageMetric {
                 title: "Premium".to_string(),
-                used_percent: tertiary.used_percent,
-                resets_at: tertiary.resets_at,
-                reset_description: tertiary.reset_description.clone(),
+                window: tertiary.clone(),
                 show_used,
-                show_absolute,
+                resets,
+                sparkline: sparkline(WindowKind::Tertiary),
+                eta: eta(WindowKind::Tertiary),
             });
         }
 
         if let Some(search) = &snapshot.search {
             metrics.push(UsageMetric {
                 title: search_label.unwrap_or("Search").to_string(),
-                used_percent: search.used_percent,
-                resets_at: search.resets_at,
-                reset_description: search.reset_description.clone(),
+                window: search.clone(),
                 show_used,
-                show_absolute,
+                resets,
+                sparkline: sparkline(WindowKind::Search),
+                eta: eta(WindowKind::Search),
             });
         }
 
-        Self { metrics }
+        Self { provider, metrics }
     }
 }
 
@@ -105,7 +124,11 @@ impl IntoElement for UsageMetricsSection {
             .flex()
             .flex_col()
             .gap(px(10.))
-            .children(self.metrics.into_iter().map(UsageMetricRow::new))
+            .children(
+                self.metrics
+                    .into_iter()
+                    .map(|metric| UsageMetricRow::new(self.provider, metric)),
+            )
     }
 }
 
@@ -114,48 +137,87 @@ impl IntoElement for UsageMetricsSection {
 // ============================================================================
 
 struct UsageMetricRow {
+    provider: ProviderKind,
     metric: UsageMetric,
 }
 
 impl UsageMetricRow {
-    fn new(metric: UsageMetric) -> Self {
-        Self { metric }
+    fn new(provider: ProviderKind, metric: UsageMetric) -> Self {
+        Self { provider, metric }
+    }
+
+    /// Exact numbers shown when hovering the progress bar.
+    fn tooltip_lines(&self) -> Vec<String> {
+        let used = self.metric.window.used_percent.clamp(0.0, 100.0);
+        let mut lines = vec![format!(
+            "{:.1}% used · {:.1}% remaining",
+            used,
+            100.0 - used
+        )];
+
+        if let Some(reset_at) = self.metric.window.next_reset(Utc::now()) {
+            let local_time: DateTime<Local> = reset_at.into();
+            lines.push(format!(
+                "Resets {}, {} (UTC{})",
+                format_date(reset_at),
+                self.metric.resets.time_format.format_time(reset_at),
+                local_time.format("%:z")
+            ));
+        }
+
+        if let Some(minutes) = self.metric.window.window_minutes {
+            lines.push(format!("{} window", format_window_length(minutes)));
+        }
+
+        lines
+    }
+
+    /// Returns the pace line ("~2.1h of usage left at current pace") and
+    /// whether the limit will be hit before the window resets.
+    fn eta_line(&self) -> Option<(String, bool)> {
+        let eta = self.metric.eta?;
+        if self.metric.window.used_percent >= 100.0 {
+            return None;
+        }
+        let now = Utc::now();
+        let before_reset = self
+            .metric
+            .window
+            .next_reset(now)
+            .is_some_and(|reset_at| now + eta < reset_at);
+        Some((
+            format!("~{} of usage left at current pace", format_eta(eta)),
+            before_reset,
+        ))
     }
 
     /// Format reset time based on settings.
-    /// Returns "Resets at 3:00 PM" or "Resets in 2h 30m" depending on `show_absolute`.
+    /// Returns "Resets Tue 09:00" or "Resets in 2h 30m" depending on `resets.absolute`.
     fn format_reset_time(&self) -> Option<String> {
-        if self.metric.show_absolute {
-            // Absolute time format: "Resets at 3:00 PM"
-            self.metric.resets_at.map(|reset_at| {
-                let local_time: DateTime<Local> = reset_at.into();
-                format!(
-                    "Resets at {}",
-                    local_time.format("%l:%M %p").to_string().trim()
-                )
+        let resets = self.metric.resets;
+        let window = &self.metric.window;
+        let now = Utc::now();
+        // A passed reset with no known window length has nothing to show
+        // until the refresh it triggers arrives
+        if window.resets_at.is_some() && window.next_reset(now).is_none() {
+            return None;
+        }
+        if resets.absolute {
+            // Absolute time format: "Resets 15:00", with the day if not today
+            window.next_reset(now).map(|reset_at| {
+                format!("Resets {}", resets.time_format.format_reset(reset_at, now))
             })
         } else {
             // Relative time format: "Resets in 2h 30m" or use provider's description
-            if let Some(reset_at) = self.metric.resets_at {
-                let now = Utc::now();
-                if reset_at > now {
-                    let duration = reset_at - now;
-                    let total_minutes = duration.num_minutes();
-                    let hours = total_minutes / 60;
-                    let minutes = total_minutes % 60;
-
-                    let time_str = if hours > 0 {
-                        format!("{}h {}m", hours, minutes)
-                    } else {
-                        format!("{}m", minutes)
-                    };
-                    Some(format!("Resets in {}", time_str))
-                } else {
-                    Some("Resets soon".to_string())
-                }
+            if let Some(reset_at) = window.next_reset(now) {
+                // Within the skew tolerance of a reset
+                Some(match format_countdown(reset_at, now) {
+                    Some(time_str) => format!("Resets in {}", time_str),
+                    None => "Resets soon".to_string(),
+                })
             } else {
                 // Fall back to provider's description if no timestamp
-                self.metric
+                window
                     .reset_description
                     .as_ref()
                     .map(|d| format!("Resets {}", d))
@@ -168,19 +230,24 @@ impl IntoElement for UsageMetricRow {
     type Element = Div;
 
     fn into_element(self) -> Self::Element {
-        let used_percent = self.metric.used_percent.clamp(0.0, 100.0);
+        let used_percent = self.metric.window.used_percent.clamp(0.0, 100.0);
 
         // Label always shows "X% used" - it's more intuitive!
         let percent_label = format!("{:.0}% used", used_percent);
 
         // Color based on USAGE: green (low) → yellow → orange → red (high)
-        let color = usage_color(used_percent);
+        let color = theme::color_for_usage(used_percent);
 
         // Progress bar fill = used percentage (fills left to right as usage increases)
         let bar_fill_percent = used_percent;
 
         // Format reset time based on settings
         let reset_text = self.format_reset_time();
+        let tooltip_lines = self.tooltip_lines();
+        let bar_id = SharedString::from(format!(
+            "usage-bar-{:?}-{}",
+            self.provider, self.metric.title
+        ));
 
         // Build footer row with optional reset text
         let mut footer_row = div().flex().items_center().justify_between().child(
@@ -194,22 +261,181 @@ impl IntoElement for UsageMetricRow {
             footer_row = footer_row.child(div().text_xs().text_color(theme::muted()).child(text));
         }
 
+        // Orange when the limit comes before the reset
+        let eta_line = self.eta_line().map(|(text, before_reset)| {
+            div()
+                .text_xs()
+                .text_color(if before_reset {
+                    theme::warning()
+                } else {
+                    theme::muted()
+                })
+                .child(text)
+        });
+
+        // Sparkline needs at least two points to show a trajectory
+        let sparkline = (self.metric.sparkline.len() >= 2)
+            .then(|| Sparkline::new(&self.metric.sparkline, color));
+
         div()
             .flex()
             .flex_col()
             .gap(px(4.))
-            // Title
+            // Title with optional sparkline
             .child(
                 div()
-                    .text_sm()
-                    .font_weight(FontWeight::MEDIUM)
-                    .text_color(theme::text_primary())
-                    .child(self.metric.title),
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_weight(FontWeight::MEDIUM)
+                            .text_color(theme::text_primary())
+                            .child(self.metric.title),
+                    )
+                    .children(sparkline),
+            )
+            // Capsule-shaped progress bar; hover for exact numbers
+            .child(
+                div()
+                    .id(bar_id)
+                    .py(px(2.))
+                    .tooltip(move |_window, cx| {
+                        let lines = tooltip_lines.clone();
+                        cx.new(|_| UsageTooltip { lines }).into()
+                    })
+                    .child(ProgressBar::new(bar_fill_percent, color)),
             )
-            // Capsule-shaped progress bar
-            .child(ProgressBar::new(bar_fill_percent, color))
             // Footer
             .child(footer_row)
+            .children(eta_line)
+    }
+}
+
+/// Formats the time remaining until `reset_at` (e.g., "2h 30m", "45m", "30s").
+///
+/// Switches to seconds in the final minute. Returns `None` once the reset
+/// time has passed.
+pub(crate) fn format_countdown(reset_at: DateTime<Utc>, now: DateTime<Utc>) -> Option<String> {
+    if reset_at <= now {
+        return None;
+    }
+
+    let remaining = reset_at - now;
+    if remaining.num_seconds() < 60 {
+        return Some(format!("{}s", remaining.num_seconds().max(1)));
+    }
+
+    let total_minutes = remaining.num_minutes();
+    let hours = total_minutes / 60;
+    let minutes = total_minutes % 60;
+
+    Some(if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    })
+}
+
+/// Formats a time left at the current pace ("45m", "2.1h", "3.5d").
+fn format_eta(eta: chrono::Duration) -> String {
+    let minutes = eta.num_minutes().max(1);
+    if minutes < 60 {
+        format!("{}m", minutes)
+    } else if minutes < 48 * 60 {
+        format!("{:.1}h", minutes as f64 / 60.0)
+    } else {
+        format!("{:.1}d", minutes as f64 / (60.0 * 24.0))
+    }
+}
+
+/// Formats how long ago `updated_at` was (e.g., "just now", "4m ago", "2h ago").
+pub(crate) fn format_elapsed(updated_at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let minutes = (now - updated_at).num_minutes();
+
+    if minutes < 1 {
+        "just now".to_string()
+    } else if minutes < 60 {
+        format!("{}m ago", minutes)
+    } else if minutes < 60 * 24 {
+        format!("{}h ago", minutes / 60)
+    } else {
+        format!("{}d ago", minutes / (60 * 24))
+    }
+}
+
+/// Returns how long until any countdown to `resets`, or any "updated
+/// ago" label for `updated`, changes.
+///
+/// Ticks every second while a reset is under a minute away, otherwise
+/// waits for the next minute boundary (at most a minute).
+pub(super) fn next_tick(
+    resets: &[DateTime<Utc>],
+    updated: &[DateTime<Utc>],
+    now: DateTime<Utc>,
+) -> std::time::Duration {
+    let countdowns = resets
+        .iter()
+        .map(|&reset_at| (reset_at - now).num_seconds())
+        .filter(|&remaining| remaining > 0)
+        .map(|remaining| {
+            if remaining <= 60 {
+                1
+            } else {
+                match remaining % 60 {
+                    0 => 60,
+                    until_boundary => until_boundary,
+                }
+            }
+        });
+    let elapsed = updated
+        .iter()
+        .map(|&updated_at| 60 - (now - updated_at).num_seconds().rem_euclid(60));
+
+    let seconds = countdowns.chain(elapsed).min().unwrap_or(60);
+    std::time::Duration::from_secs(seconds as u64)
+}
+
+/// Formats a window length (e.g., "5-hour", "7-day", "30-minute").
+fn format_window_length(minutes: u32) -> String {
+    const DAY: u32 = 60 * 24;
+    if minutes >= DAY && minutes % DAY == 0 {
+        format!("{}-day", minutes / DAY)
+    } else if minutes >= 60 && minutes % 60 == 0 {
+        format!("{}-hour", minutes / 60)
+    } else {
+        format!("{}-minute", minutes)
+    }
+}
+
+// ============================================================================
+// Usage Tooltip
+// ============================================================================
+
+/// Hover tooltip listing a usage window's exact numbers.
+struct UsageTooltip {
+    lines: Vec<String>,
+}
+
+impl Render for UsageTooltip {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .px(px(8.))
+            .py(px(6.))
+            .rounded(px(6.))
+            .bg(theme::surface_background())
+            .border_1()
+            .border_color(theme::border())
+            .flex()
+            .flex_col()
+            .gap(px(2.))
+            .children(self.lines.iter().map(|line| {
+                div()
+                    .text_xs()
+                    .text_color(theme::text_primary())
+                    .child(line.clone())
+            }))
     }
 }
 
@@ -217,13 +443,13 @@ impl IntoElement for UsageMetricRow {
 // Progress Bar (Capsule Style like CodexBar)
 // ============================================================================
 
-struct ProgressBar {
+pub(super) struct ProgressBar {
     percent: f64,
     color: Hsla,
 }
 
 impl ProgressBar {
-    fn new(percent: f64, color: Hsla) -> Self {
+    pub(super) fn new(percent: f64, color: Hsla) -> Self {
         Self {
             percent: percent.clamp(0.0, 100.0),
             color,
@@ -255,41 +481,127 @@ impl IntoElement for ProgressBar {
 }
 
 // ============================================================================
-// Color Utilities
+// Sparkline
 // ============================================================================
 
-/// Returns a color based on usage percentage (USED, not remaining).
-/// Smooth gradient: Green (0%) → Yellow (50%) → Orange (80%) → Red (100%)
-///
-/// This makes intuitive sense: low usage = green (good), high usage = red (warning)
-fn usage_color(used_percent: f64) -> Hsla {
-    let used = used_percent as f32;
-    if used < 50.0 {
-        // Green to Yellow (0-50%)
-        let t = used / 50.0;
-        hsla(
-            (120.0 - t * 60.0) / 360.0, // Hue: 120 (green) → 60 (yellow)
-            0.7,
-            0.45,
-            1.0,
-        )
-    } else if used < 80.0 {
-        // Yellow to Orange (50-80%)
-        let t = (used - 50.0) / 30.0;
-        hsla(
-            (60.0 - t * 30.0) / 360.0, // Hue: 60 (yellow) → 30 (orange)
-            0.8,
-            0.5,
-            1.0,
-        )
-    } else {
-        // Orange to Red (80-100%)
-        let t = (used - 80.0) / 20.0;
-        hsla(
-            (30.0 - t * 30.0) / 360.0, // Hue: 30 (orange) → 0 (red)
-            0.85,
-            0.5,
-            1.0,
-        )
+/// Maximum number of points drawn in a sparkline.
+const SPARKLINE_MAX_POINTS: usize = 24;
+
+/// Faint bar-style sparkline showing a window's trajectory since its last reset.
+struct Sparkline {
+    points: Vec<f64>,
+    color: Hsla,
+}
+
+impl Sparkline {
+    fn new(points: &[f64], color: Hsla) -> Self {
+        // Keep only the most recent points so bars stay readable
+        let start = points.len().saturating_sub(SPARKLINE_MAX_POINTS);
+        Self {
+            points: points[start..].to_vec(),
+            color,
+        }
+    }
+}
+
+impl IntoElement for Sparkline {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let color = theme::transparent(self.color, 0.35);
+
+        div()
+            .h(px(12.))
+            .flex()
+            .items_end()
+            .gap(px(1.))
+            .children(self.points.into_iter().map(move |percent| {
+                let fraction = (percent.clamp(0.0, 100.0) / 100.0) as f32;
+                div()
+                    .w(px(2.))
+                    // Keep a sliver visible at 0% so the baseline reads as a line
+                    .h(relative(fraction.max(0.08)))
+                    .bg(color)
+                    .rounded(px(1.))
+            }))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use std::time::Duration as StdDuration;
+
+    #[test]
+    fn test_format_countdown() {
+        let now = Utc::now();
+        assert_eq!(
+            format_countdown(now + Duration::minutes(150), now).as_deref(),
+            Some("2h 30m")
+        );
+        assert_eq!(
+            format_countdown(now + Duration::minutes(45), now).as_deref(),
+            Some("45m")
+        );
+        assert_eq!(
+            format_countdown(now + Duration::seconds(30), now).as_deref(),
+            Some("30s")
+        );
+        assert_eq!(format_countdown(now - Duration::seconds(1), now), None);
+    }
+
+    #[test]
+    fn test_format_eta() {
+        assert_eq!(format_eta(chrono::Duration::seconds(20)), "1m");
+        assert_eq!(format_eta(chrono::Duration::minutes(45)), "45m");
+        assert_eq!(format_eta(chrono::Duration::minutes(126)), "2.1h");
+        assert_eq!(format_eta(chrono::Duration::hours(84)), "3.5d");
+    }
+
+    #[test]
+    fn test_format_window_length() {
+        assert_eq!(format_window_length(300), "5-hour");
+        assert_eq!(format_window_length(10080), "7-day");
+        assert_eq!(format_window_length(45), "45-minute");
+    }
+
+    #[test]
+    fn test_format_elapsed() {
+        let now = Utc::now();
+        assert_eq!(format_elapsed(now - Duration::seconds(20), now), "just now");
+        assert_eq!(format_elapsed(now - Duration::minutes(4), now), "4m ago");
+        assert_eq!(format_elapsed(now - Duration::minutes(150), now), "2h ago");
+        assert_eq!(format_elapsed(now - Duration::days(3), now), "3d ago");
+    }
+
+    #[test]
+    fn test_next_tick() {
+        let now = Utc::now();
+        assert_eq!(next_tick(&[], &[], now), StdDuration::from_secs(60));
+        assert_eq!(
+            next_tick(&[now + Duration::seconds(125)], &[], now),
+            StdDuration::from_secs(5)
+        );
+        assert_eq!(
+            next_tick(
+                &[now + Duration::minutes(10), now + Duration::seconds(40)],
+                &[],
+                now
+            ),
+            StdDuration::from_secs(1)
+        );
+        assert_eq!(
+            next_tick(&[now - Duration::minutes(1)], &[], now),
+            StdDuration::from_secs(60)
+        );
+        assert_eq!(
+            next_tick(&[], &[now - Duration::seconds(130)], now),
+            StdDuration::from_secs(50)
+        );
     }
 }
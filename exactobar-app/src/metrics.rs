@@ -0,0 +1,173 @@
+//! Prometheus metrics exporter.
+//!
+//! Renders the usage shown in the menu in the Prometheus text exposition
+//! format. Served at `/metrics` by the local server (see [`crate::server`]).
+
+use std::fmt::Write;
+
+use crate::server::{ProviderUsage, Response};
+
+/// Content type of the Prometheus text format.
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Handles a request to the metrics endpoint.
+pub fn handle_request(usage: &[ProviderUsage]) -> Response {
+    Response::new("200 OK", CONTENT_TYPE, render(usage))
+}
+
+/// Renders usage as Prometheus gauges.
+pub fn render(usage: &[ProviderUsage]) -> String {
+    let mut out = String::new();
+
+    gauge(
+        &mut out,
+        "exactobar_usage_used_percent",
+        "Percentage of a usage window used.",
+    );
+    for provider in usage {
+        for w in &provider.windows {
+            let labels = window_labels(provider, &w.label, w.window);
+            let _ = writeln!(
+                out,
+                "exactobar_usage_used_percent{{{}}} {}",
+                labels, w.used_percent
+            );
+        }
+    }
+
+    gauge(
+        &mut out,
+        "exactobar_usage_resets_at_seconds",
+        "When a usage window resets, as a Unix timestamp.",
+    );
+    for provider in usage {
+        for w in &provider.windows {
+            if let Some(resets_at) = w.resets_at {
+                let labels = window_labels(provider, &w.label, w.window);
+                let _ = writeln!(
+                    out,
+                    "exactobar_usage_resets_at_seconds{{{}}} {}",
+                    labels,
+                    resets_at.timestamp()
+                );
+            }
+        }
+    }
+
+    gauge(
+        &mut out,
+        "exactobar_provider_error",
+        "Whether the last refresh of a provider failed (1) or succeeded (0).",
+    );
+    for provider in usage {
+        let _ = writeln!(
+            out,
+            "exactobar_provider_error{{provider=\"{}\"}} {}",
+            escape(&provider.provider),
+            u8::from(provider.error.is_some())
+        );
+    }
+
+    gauge(
+        &mut out,
+        "exactobar_fetch_duration_seconds",
+        "How long the last refresh of a provider took.",
+    );
+    for provider in usage {
+        if let Some(duration) = provider.fetch_duration {
+            let _ = writeln!(
+                out,
+                "exactobar_fetch_duration_seconds{{provider=\"{}\"}} {:.3}",
+                escape(&provider.provider),
+                duration.as_secs_f64()
+            );
+        }
+    }
+
+    gauge(
+        &mut out,
+        "exactobar_last_success_timestamp_seconds",
+        "When usage was last fetched successfully, as a Unix timestamp.",
+    );
+    for provider in usage {
+        if let Some(updated_at) = provider.updated_at {
+            let _ = writeln!(
+                out,
+                "exactobar_last_success_timestamp_seconds{{provider=\"{}\"}} {}",
+                escape(&provider.provider),
+                updated_at.timestamp()
+            );
+        }
+    }
+
+    out
+}
+
+fn gauge(out: &mut String, name: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} gauge", name);
+}
+
+fn window_labels(
+    provider: &ProviderUsage,
+    label: &str,
+    window: exactobar_core::WindowKind,
+) -> String {
+    let window = serde_json::to_value(window)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    format!(
+        "provider=\"{}\",window=\"{}\",label=\"{}\"",
+        escape(&provider.provider),
+        window,
+        escape(label)
+    )
+}
+
+/// Escapes a label value.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use exactobar_core::{ProviderKind, UsageSnapshot, UsageWindow};
+    use std::time::Duration;
+
+    #[test]
+    fn test_render() {
+        let mut session = UsageWindow::new(38.0);
+        session.resets_at = Some(Utc.timestamp_opt(1_760_000_000, 0).unwrap());
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(session);
+
+        let mut claude = ProviderUsage::new(ProviderKind::Claude, Some(&snapshot), None);
+        claude.fetch_duration = Some(Duration::from_millis(1250));
+        let codex = ProviderUsage::new(ProviderKind::Codex, None, Some("timed out".into()));
+
+        let text = render(&[claude, codex]);
+        assert!(text.contains("# TYPE exactobar_usage_used_percent gauge"));
+        assert!(text.contains(
+            "exactobar_usage_used_percent{provider=\"claude\",window=\"primary\",label=\"Session\"} 38"
+        ));
+        assert!(text.contains("exactobar_usage_resets_at_seconds{provider=\"claude\",window=\"primary\",label=\"Session\"} 1760000000"));
+        assert!(text.contains("exactobar_provider_error{provider=\"claude\"} 0"));
+        assert!(text.contains("exactobar_provider_error{provider=\"codex\"} 1"));
+        assert!(text.contains("exactobar_fetch_duration_seconds{provider=\"claude\"} 1.250"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a\"b\\c"), "a\\\"b\\\\c");
+    }
+}
@@ -0,0 +1,316 @@
+//! MQTT publisher for home automation.
+//!
+//! When a broker is configured, publishes each provider's usage as retained
+//! messages after every refresh, e.g.:
+//!
+//! ```text
+//! exactobar/status                        online | offline
+//! exactobar/claude/used_percent           38        (first window)
+//! exactobar/claude/primary/used_percent   38
+//! exactobar/claude/primary/resets_at      2025-01-01T17:00:00+00:00
+//! exactobar/claude/error                  (empty unless the last refresh failed)
+//! exactobar/claude/state                  {"provider": "claude", "windows": [...], ...}
+//! ```
+//!
+//! `status` is set to "offline" by the broker (as our last will) when the
+//! app goes away. Speaks MQTT 3.1.1 over plain TCP with QoS 0; the
+//! connection is kept alive in the background and re-established on failure.
+
+use exactobar_store::MqttSettings;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::sync::OnceLock;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+use crate::server::ProviderUsage;
+
+/// Keep-alive interval announced to the broker; we ping at half of it.
+const KEEP_ALIVE: Duration = Duration::from_secs(60);
+
+/// How long to wait before reconnecting after a failure.
+const RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+/// The running publisher, set by [`start`].
+static PUBLISHER: OnceLock<Publisher> = OnceLock::new();
+
+struct Publisher {
+    topic_prefix: String,
+    /// Messages for the connection thread.
+    queue: Sender<Vec<(String, String)>>,
+}
+
+/// Starts publishing. Usage published before this is dropped.
+pub fn start(settings: MqttSettings) {
+    let (queue, rx) = mpsc::channel();
+    let publisher = Publisher {
+        topic_prefix: settings.topic_prefix.clone(),
+        queue,
+    };
+    if PUBLISHER.set(publisher).is_err() {
+        return;
+    }
+    info!(broker = %settings.broker, "Publishing usage over MQTT");
+    std::thread::spawn(move || run(&settings, &rx));
+}
+
+/// Publishes a provider's usage, if MQTT is enabled.
+pub fn publish(usage: &ProviderUsage) {
+    if let Some(publisher) = PUBLISHER.get() {
+        let _ = publisher
+            .queue
+            .send(messages(&publisher.topic_prefix, usage));
+    }
+}
+
+/// Builds the retained `(topic, payload)` messages for a provider.
+pub fn messages(prefix: &str, usage: &ProviderUsage) -> Vec<(String, String)> {
+    let base = format!("{}/{}", prefix.trim_end_matches('/'), usage.provider);
+    let mut messages = Vec::new();
+
+    if let Some(first) = usage.windows.first() {
+        messages.push((
+            format!("{}/used_percent", base),
+            format!("{:.0}", first.used_percent),
+        ));
+    }
+    for w in &usage.windows {
+        let window = serde_json::to_value(w.window)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        messages.push((
+            format!("{}/{}/used_percent", base, window),
+            format!("{:.0}", w.used_percent),
+        ));
+        messages.push((
+            format!("{}/{}/resets_at", base, window),
+            w.resets_at.map(|at| at.to_rfc3339()).unwrap_or_default(),
+        ));
+    }
+
+    let error = usage.error.as_deref().unwrap_or("");
+    messages.push((
+        format!("{}/error", base),
+        error.lines().next().unwrap_or(error).to_string(),
+    ));
+    if let Ok(state) = serde_json::to_string(usage) {
+        messages.push((format!("{}/state", base), state));
+    }
+    messages
+}
+
+// ============================================================================
+// Connection
+// ============================================================================
+
+/// Keeps a broker connection, publishing the latest value of every topic.
+fn run(settings: &MqttSettings, rx: &Receiver<Vec<(String, String)>>) {
+    let status_topic = format!("{}/status", settings.topic_prefix.trim_end_matches('/'));
+    let mut latest: BTreeMap<String, String> = BTreeMap::new();
+    let mut pending: BTreeSet<String> = BTreeSet::new();
+    let mut connection: Option<TcpStream> = None;
+    let mut last_attempt: Option<Instant> = None;
+
+    loop {
+        let ping_due = match rx.recv_timeout(KEEP_ALIVE / 2) {
+            Ok(batch) => {
+                for (topic, payload) in batch {
+                    pending.insert(topic.clone());
+                    latest.insert(topic, payload);
+                }
+                false
+            }
+            Err(RecvTimeoutError::Timeout) => true,
+            Err(RecvTimeoutError::Disconnected) => return,
+        };
+
+        if connection.is_none() && last_attempt.is_none_or(|at| at.elapsed() >= RECONNECT_DELAY) {
+            last_attempt = Some(Instant::now());
+            match connect(settings, &status_topic) {
+                Ok(stream) => {
+                    info!(broker = %settings.broker, "Connected to MQTT broker");
+                    connection = Some(stream);
+                    // The broker may have lost retained messages
+                    pending.extend(latest.keys().cloned());
+                }
+                Err(e) => warn!(broker = %settings.broker, error = %e, "MQTT connection failed"),
+            }
+        }
+
+        let Some(stream) = connection.as_mut() else {
+            continue;
+        };
+        let mut result = pending.iter().try_for_each(|topic| {
+            let payload = latest.get(topic).map_or("", String::as_str);
+            stream.write_all(&publish_packet(topic, payload.as_bytes()))
+        });
+        if result.is_ok() && ping_due {
+            result = ping(stream);
+        }
+        match result {
+            Ok(()) => pending.clear(),
+            Err(e) => {
+                debug!(error = %e, "MQTT connection lost");
+                connection = None;
+            }
+        }
+    }
+}
+
+/// Connects, announces "online" and sets "offline" as the last will.
+fn connect(settings: &MqttSettings, status_topic: &str) -> io::Result<TcpStream> {
+    let mut stream = TcpStream::connect(&settings.broker)?;
+    stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+
+    let client_id = format!("exactobar-{:08x}", rand::random::<u32>());
+    stream.write_all(&connect_packet(
+        &client_id,
+        status_topic,
+        settings.username.as_deref(),
+        settings.password.as_deref(),
+    ))?;
+
+    let mut connack = [0; 4];
+    stream.read_exact(&mut connack)?;
+    if connack[0] != 0x20 || connack[3] != 0 {
+        return Err(io::Error::other(format!(
+            "broker refused connection (code {})",
+            connack[3]
+        )));
+    }
+
+    stream.write_all(&publish_packet(status_topic, b"online"))?;
+    Ok(stream)
+}
+
+fn ping(stream: &mut TcpStream) -> io::Result<()> {
+    stream.write_all(&[0xC0, 0x00])?;
+    let mut pingresp = [0; 2];
+    stream.read_exact(&mut pingresp)?;
+    if pingresp[0] != 0xD0 {
+        return Err(io::Error::other("unexpected reply to ping"));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Packets
+// ============================================================================
+
+fn connect_packet(
+    client_id: &str,
+    will_topic: &str,
+    username: Option<&str>,
+    password: Option<&str>,
+) -> Vec<u8> {
+    // Clean session, retained QoS 0 will
+    let mut flags = 0x02 | 0x04 | 0x20;
+    if username.is_some() {
+        flags |= 0x80;
+    }
+    if password.is_some() {
+        flags |= 0x40;
+    }
+
+    let mut body = Vec::new();
+    put_str(&mut body, "MQTT");
+    body.push(4); // Protocol level 3.1.1
+    body.push(flags);
+    body.extend_from_slice(&(KEEP_ALIVE.as_secs() as u16).to_be_bytes());
+    put_str(&mut body, client_id);
+    put_str(&mut body, will_topic);
+    put_str(&mut body, "offline");
+    if let Some(username) = username {
+        put_str(&mut body, username);
+    }
+    if let Some(password) = password {
+        put_str(&mut body, password);
+    }
+    packet(0x10, &body)
+}
+
+/// A retained QoS 0 PUBLISH.
+fn publish_packet(topic: &str, payload: &[u8]) -> Vec<u8> {
+    let mut body = Vec::new();
+    put_str(&mut body, topic);
+    body.extend_from_slice(payload);
+    packet(0x31, &body)
+}
+
+fn packet(header: u8, body: &[u8]) -> Vec<u8> {
+    let mut packet = vec![header];
+    // Remaining length: 7 bits per byte, high bit set when more follow
+    let mut len = body.len();
+    loop {
+        let mut byte = (len % 128) as u8;
+        len /= 128;
+        if len > 0 {
+            byte |= 0x80;
+        }
+        packet.push(byte);
+        if len == 0 {
+            break;
+        }
+    }
+    packet.extend_from_slice(body);
+    packet
+}
+
+fn put_str(buf: &mut Vec<u8>, s: &str) {
+    buf.extend_from_slice(&(s.len() as u16).to_be_bytes());
+    buf.extend_from_slice(s.as_bytes());
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exactobar_core::{ProviderKind, UsageSnapshot, UsageWindow};
+
+    #[test]
+    fn test_messages() {
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(UsageWindow::new(38.4));
+        let usage = ProviderUsage::new(ProviderKind::Claude, Some(&snapshot), None);
+
+        let messages = messages("exactobar/", &usage);
+        let get = |topic: &str| {
+            messages
+                .iter()
+                .find(|(t, _)| t == topic)
+                .map(|(_, p)| p.as_str())
+        };
+        assert_eq!(get("exactobar/claude/used_percent"), Some("38"));
+        assert_eq!(get("exactobar/claude/primary/used_percent"), Some("38"));
+        assert_eq!(get("exactobar/claude/primary/resets_at"), Some(""));
+        assert_eq!(get("exactobar/claude/error"), Some(""));
+        assert!(
+            get("exactobar/claude/state")
+                .unwrap()
+                .contains("\"claude\"")
+        );
+    }
+
+    #[test]
+    fn test_packets() {
+        assert_eq!(
+            publish_packet("a/b", b"42"),
+            [0x31, 7, 0, 3, b'a', b'/', b'b', b'4', b'2']
+        );
+
+        // Remaining lengths over 127 take more than one byte
+        let long = packet(0x31, &[0; 200]);
+        assert_eq!(&long[..3], &[0x31, 0xC8, 0x01]);
+
+        let connect = connect_packet("id", "s", Some("u"), None);
+        assert_eq!(connect[0], 0x10);
+        assert_eq!(&connect[2..9], &[0, 4, b'M', b'Q', b'T', b'T', 4]);
+        assert_eq!(connect[9], 0x80 | 0x20 | 0x04 | 0x02);
+    }
+}
@@ -1,15 +1,71 @@
 //! Session quota notifications.
 //!
-//! Alerts users when they're approaching provider quota limits.
+//! Alerts users when they're approaching provider quota limits, or when a
+//! provider's refreshes were paused after repeated errors.
+//!
+//! System notifications vanish quickly, so the last [`ALERT_LOG_CAPACITY`]
+//! delivered alerts are also kept for the menu's "Recent Alerts" section.
 
+use chrono::{DateTime, Utc};
 use exactobar_core::{ProviderKind, UsageSnapshot};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, RwLock};
 use tracing::{debug, info};
 
 // Notification thresholds
-const WARNING_THRESHOLD: f64 = 80.0; // Warn at 80% used
+const DEFAULT_WARNING_THRESHOLD: f64 = 80.0; // Warn at 80% used
 const CRITICAL_THRESHOLD: f64 = 95.0; // Critical at 95% used
 
+/// Percent used where warnings start, following where the usage colors
+/// turn red.
+static WARNING_THRESHOLD: RwLock<f64> = RwLock::new(DEFAULT_WARNING_THRESHOLD);
+
+/// Sets the percent used where warnings start.
+pub fn set_warning_threshold(percent: f64) {
+    if let Ok(mut threshold) = WARNING_THRESHOLD.write() {
+        *threshold = percent;
+    }
+}
+
+/// Number of delivered alerts kept.
+pub const ALERT_LOG_CAPACITY: usize = 20;
+
+/// A delivered alert.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alert {
+    pub delivered_at: DateTime<Utc>,
+    pub provider: ProviderKind,
+    pub title: String,
+    pub body: String,
+}
+
+/// Delivered alerts, oldest first.
+static ALERT_LOG: Mutex<VecDeque<Alert>> = Mutex::new(VecDeque::new());
+
+/// Returns the delivered alerts, newest first.
+pub fn recent_alerts() -> Vec<Alert> {
+    ALERT_LOG
+        .lock()
+        .map(|alerts| alerts.iter().rev().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Forgets the delivered alerts.
+pub fn clear_alerts() {
+    if let Ok(mut alerts) = ALERT_LOG.lock() {
+        alerts.clear();
+    }
+}
+
+fn log_alert(alert: Alert) {
+    if let Ok(mut alerts) = ALERT_LOG.lock() {
+        if alerts.len() == ALERT_LOG_CAPACITY {
+            alerts.pop_front();
+        }
+        alerts.push_back(alert);
+    }
+}
+
 /// Tracks notification state to avoid spamming
 #[derive(Default)]
 pub struct NotificationTracker {
@@ -30,6 +86,28 @@ impl Default for NotificationLevel {
     }
 }
 
+impl NotificationLevel {
+    /// Returns the threshold level reached at `used_percent`.
+    pub fn for_percent(used_percent: f64) -> Self {
+        let warning_threshold = WARNING_THRESHOLD
+            .read()
+            .map_or(DEFAULT_WARNING_THRESHOLD, |threshold| *threshold);
+        Self::for_threshold(used_percent, warning_threshold)
+    }
+
+    /// Returns the level reached at `used_percent` when warnings start at
+    /// `warning_threshold`. Critical stays at 95% unless warnings start later.
+    fn for_threshold(used_percent: f64, warning_threshold: f64) -> Self {
+        if used_percent >= CRITICAL_THRESHOLD.max(warning_threshold) {
+            NotificationLevel::Critical
+        } else if used_percent >= warning_threshold {
+            NotificationLevel::Warning
+        } else {
+            NotificationLevel::None
+        }
+    }
+}
+
 impl NotificationTracker {
     pub fn new() -> Self {
         Self::default()
@@ -44,13 +122,7 @@ impl NotificationTracker {
     ) -> Option<NotificationLevel> {
         let used_percent = snapshot.primary.as_ref()?.used_percent;
 
-        let current_level = if used_percent >= CRITICAL_THRESHOLD {
-            NotificationLevel::Critical
-        } else if used_percent >= WARNING_THRESHOLD {
-            NotificationLevel::Warning
-        } else {
-            NotificationLevel::None
-        };
+        let current_level = NotificationLevel::for_percent(used_percent);
 
         let last_level = self
             .last_notified
@@ -120,6 +192,25 @@ pub fn send_quota_notification(
         "Sending quota notification"
     );
 
+    send_notification(provider, title, body);
+}
+
+/// Tells the user a provider's refreshes stopped after repeated errors.
+pub fn send_paused_notification(provider: ProviderKind) {
+    let provider_name = provider.display_name();
+    let title = format!("{} Paused", provider_name);
+    let body = format!(
+        "{} kept failing to refresh, so ExactoBar stopped trying. Resume it from the menu once it's fixed.",
+        provider_name
+    );
+
+    info!(provider = ?provider, "Sending paused notification");
+
+    send_notification(provider, title, body);
+}
+
+/// Shows a system notification and logs it as a recent alert.
+fn send_notification(provider: ProviderKind, title: String, body: String) {
     // Use the system notification API
     #[cfg(target_os = "macos")]
     {
@@ -137,6 +228,13 @@ pub fn send_quota_notification(
     }
 
     debug!("Notification sent: {} - {}", title, body);
+
+    log_alert(Alert {
+        delivered_at: Utc::now(),
+        provider,
+        title,
+        body,
+    });
 }
 
 #[cfg(test)]
@@ -170,6 +268,32 @@ mod tests {
         assert!(tracker.should_notify(ProviderKind::Claude, &snap).is_none());
     }
 
+    #[test]
+    fn test_warning_threshold() {
+        assert_eq!(
+            NotificationLevel::for_threshold(65.0, 60.0),
+            NotificationLevel::Warning
+        );
+        assert_eq!(
+            NotificationLevel::for_threshold(55.0, 60.0),
+            NotificationLevel::None
+        );
+        assert_eq!(
+            NotificationLevel::for_threshold(95.0, 60.0),
+            NotificationLevel::Critical
+        );
+
+        // Past 95%, the first alert is already the critical one
+        assert_eq!(
+            NotificationLevel::for_threshold(96.0, 98.0),
+            NotificationLevel::None
+        );
+        assert_eq!(
+            NotificationLevel::for_threshold(98.0, 98.0),
+            NotificationLevel::Critical
+        );
+    }
+
     #[test]
     fn test_critical_notification() {
         let mut tracker = NotificationTracker::new();
@@ -201,4 +325,26 @@ mod tests {
             Some(NotificationLevel::Warning)
         );
     }
+
+    #[test]
+    fn test_alert_log() {
+        clear_alerts();
+        for i in 0..=ALERT_LOG_CAPACITY {
+            log_alert(Alert {
+                delivered_at: Utc::now(),
+                provider: ProviderKind::Claude,
+                title: format!("Alert {i}"),
+                body: String::new(),
+            });
+        }
+
+        // Newest first, with the oldest dropped
+        let alerts = recent_alerts();
+        assert_eq!(alerts.len(), ALERT_LOG_CAPACITY);
+        assert_eq!(alerts[0].title, format!("Alert {ALERT_LOG_CAPACITY}"));
+        assert_eq!(alerts[ALERT_LOG_CAPACITY - 1].title, "Alert 1");
+
+        clear_alerts();
+        assert!(recent_alerts().is_empty());
+    }
 }
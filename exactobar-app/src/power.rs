@@ -0,0 +1,141 @@
+//! Power saving.
+//!
+//! In macOS Low Power Mode, or while running on battery on Linux, refreshes
+//! are spread out and icon animations pause, unless turned off in settings.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use gpui::App;
+
+use crate::state::AppState;
+
+/// How much longer the refresh interval gets while saving power.
+const REFRESH_STRETCH: u32 = 3;
+
+/// How long the OS power state is trusted before checking again.
+const POWER_STATE_TTL: Duration = Duration::from_secs(30);
+
+/// Last OS power-saving state read, and when.
+static SYSTEM_POWER_SAVING: Mutex<Option<(Instant, bool)>> = Mutex::new(None);
+
+/// Checks whether to save power: the setting is on and the OS is in Low
+/// Power Mode (macOS) or on battery (Linux).
+pub fn saving_power(cx: &App) -> bool {
+    cx.global::<AppState>()
+        .settings
+        .read(cx)
+        .power_saving_enabled()
+        && system_power_saving()
+}
+
+/// Returns the time until the next scheduled refresh.
+pub fn refresh_interval(cadence: Duration, saving_power: bool) -> Duration {
+    if saving_power {
+        cadence * REFRESH_STRETCH
+    } else {
+        cadence
+    }
+}
+
+/// Returns the OS power-saving state, read at most once per
+/// [`POWER_STATE_TTL`].
+fn system_power_saving() -> bool {
+    let Ok(mut cached) = SYSTEM_POWER_SAVING.lock() else {
+        return false;
+    };
+    if let Some((read_at, saving)) = *cached
+        && read_at.elapsed() < POWER_STATE_TTL
+    {
+        return saving;
+    }
+    let saving = read_system_power_saving();
+    *cached = Some((Instant::now(), saving));
+    saving
+}
+
+#[cfg(target_os = "macos")]
+fn read_system_power_saving() -> bool {
+    use cocoa::base::{BOOL, NO, id};
+    use objc::{class, msg_send, sel, sel_impl};
+
+    unsafe {
+        let process_info: id = msg_send![class!(NSProcessInfo), processInfo];
+        // Low Power Mode came to Macs in macOS 12
+        let supported: BOOL =
+            msg_send![process_info, respondsToSelector: sel!(isLowPowerModeEnabled)];
+        if supported == NO {
+            return false;
+        }
+        let enabled: BOOL = msg_send![process_info, isLowPowerModeEnabled];
+        enabled != NO
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn read_system_power_saving() -> bool {
+    on_battery(std::path::Path::new("/sys/class/power_supply"))
+}
+
+/// Checks the power supplies under `dir` (`/sys/class/power_supply`) for a
+/// discharging battery and no charger plugged in.
+#[cfg(not(target_os = "macos"))]
+fn on_battery(dir: &std::path::Path) -> bool {
+    let mut discharging = false;
+    for entry in std::fs::read_dir(dir).into_iter().flatten().flatten() {
+        let path = entry.path();
+        let read = |name: &str| {
+            std::fs::read_to_string(path.join(name))
+                .map(|value| value.trim().to_string())
+                .unwrap_or_default()
+        };
+        match read("type").as_str() {
+            "Mains" | "USB" if read("online") == "1" => return false,
+            "Battery" if read("status") == "Discharging" => discharging = true,
+            _ => {}
+        }
+    }
+    discharging
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refresh_interval() {
+        let cadence = Duration::from_secs(120);
+        assert_eq!(refresh_interval(cadence, false), cadence);
+        assert_eq!(refresh_interval(cadence, true), Duration::from_secs(360));
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn test_on_battery() {
+        let dir = tempfile::tempdir().unwrap();
+        let supply = |name: &str, files: &[(&str, &str)]| {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(&path).unwrap();
+            for (file, value) in files {
+                std::fs::write(path.join(file), format!("{value}\n")).unwrap();
+            }
+        };
+
+        // Desktops have no battery
+        assert!(!on_battery(dir.path()));
+
+        supply("BAT0", &[("type", "Battery"), ("status", "Discharging")]);
+        assert!(on_battery(dir.path()));
+
+        supply("AC", &[("type", "Mains"), ("online", "1")]);
+        assert!(!on_battery(dir.path()));
+
+        supply("AC", &[("type", "Mains"), ("online", "0")]);
+        supply("BAT0", &[("type", "Battery"), ("status", "Charging")]);
+        assert!(!on_battery(dir.path()));
+    }
+}
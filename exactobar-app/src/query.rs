@@ -0,0 +1,238 @@
+//! One-shot query mode for launchers.
+//!
+//! `exactobar-app --query [FILTER]` prints the cached usage of the enabled
+//! providers as Alfred Script Filter JSON, which Raycast extensions can
+//! consume too, and exits without starting the GUI or fetching. `FILTER`
+//! narrows the results to providers whose name contains it.
+
+use chrono::{DateTime, Utc};
+use exactobar_core::ProviderKind;
+use exactobar_store::{SettingsStore, UsageCache};
+use serde::Serialize;
+
+use crate::menu::{format_countdown, format_elapsed};
+use crate::server::ProviderUsage;
+
+/// The command-line flag that selects query mode.
+pub const FLAG: &str = "--query";
+
+/// An Alfred Script Filter result.
+#[derive(Debug, Serialize)]
+pub struct QueryItem {
+    pub uid: String,
+    pub title: String,
+    pub subtitle: String,
+    /// Passed to the next workflow action (the provider's CLI name).
+    pub arg: String,
+    pub valid: bool,
+    /// Text for Alfred's copy (⌘C) and large type (⌘L) actions.
+    pub text: QueryText,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QueryText {
+    pub copy: String,
+    pub largetype: String,
+}
+
+/// Alfred Script Filter output.
+#[derive(Debug, Serialize)]
+struct QueryOutput {
+    items: Vec<QueryItem>,
+}
+
+/// Returns the filter if query mode was requested on the command line.
+///
+/// The filter is empty when `--query` is given without one.
+pub fn parse_args(args: &[String]) -> Option<String> {
+    let index = args.iter().position(|a| a == FLAG)?;
+    Some(
+        args.get(index + 1)
+            .filter(|a| !a.starts_with("--"))
+            .cloned()
+            .unwrap_or_default(),
+    )
+}
+
+/// Prints cached usage as JSON.
+pub fn run(filter: &str) {
+    let runtime = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("Failed to start runtime: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let (providers, cache) = runtime.block_on(async {
+        let providers = match SettingsStore::load_default().await {
+            Ok(store) => store.get().await.sorted_enabled_providers(),
+            Err(_) => Vec::new(),
+        };
+        (providers, UsageCache::load_default().await)
+    });
+
+    let output = QueryOutput {
+        items: items(&providers, &cache, filter, Utc::now()),
+    };
+    match serde_json::to_string(&output) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            eprintln!("Failed to serialize usage: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Builds one result per enabled provider matching `filter`.
+pub fn items(
+    providers: &[ProviderKind],
+    cache: &UsageCache,
+    filter: &str,
+    now: DateTime<Utc>,
+) -> Vec<QueryItem> {
+    if providers.is_empty() {
+        return vec![message_item(
+            "No providers enabled",
+            "Enable providers in ExactoBar's settings",
+        )];
+    }
+
+    let filter = filter.trim().to_lowercase();
+    let items: Vec<QueryItem> = providers
+        .iter()
+        .filter(|p| {
+            filter.is_empty()
+                || p.cli_name().contains(&filter)
+                || p.display_name().to_lowercase().contains(&filter)
+        })
+        .map(|&p| {
+            let usage =
+                ProviderUsage::new(p, cache.snapshots.get(&p), cache.errors.get(&p).cloned());
+            provider_item(&usage, now)
+        })
+        .collect();
+
+    if items.is_empty() {
+        return vec![message_item(
+            "No matching providers",
+            &format!("No enabled provider matches \"{}\"", filter),
+        )];
+    }
+    items
+}
+
+fn provider_item(usage: &ProviderUsage, now: DateTime<Utc>) -> QueryItem {
+    let title = match usage.windows.first() {
+        Some(w) => format!("{}: {:.0}% left", usage.name, w.remaining_percent),
+        None => usage.name.clone(),
+    };
+
+    let mut parts: Vec<String> = usage
+        .windows
+        .iter()
+        .map(|w| {
+            let mut part = format!("{} {:.0}% used", w.label, w.used_percent);
+            if let Some(countdown) = w.resets_at.and_then(|at| format_countdown(at, now)) {
+                part.push_str(&format!(", resets in {}", countdown));
+            }
+            part
+        })
+        .collect();
+    match (&usage.error, usage.updated_at) {
+        (Some(error), _) => parts.push(error.lines().next().unwrap_or(error).to_string()),
+        (None, Some(updated_at)) => {
+            parts.push(format!("Updated {}", format_elapsed(updated_at, now)))
+        }
+        (None, None) => parts.push("No cached usage yet; open ExactoBar to refresh".to_string()),
+    }
+    let subtitle = parts.join(" · ");
+
+    QueryItem {
+        uid: usage.provider.clone(),
+        text: QueryText {
+            copy: format!("{} ({})", title, subtitle),
+            largetype: title.clone(),
+        },
+        title,
+        subtitle,
+        arg: usage.provider.clone(),
+        valid: true,
+    }
+}
+
+fn message_item(title: &str, subtitle: &str) -> QueryItem {
+    QueryItem {
+        uid: String::new(),
+        title: title.to_string(),
+        subtitle: subtitle.to_string(),
+        arg: String::new(),
+        valid: false,
+        text: QueryText {
+            copy: title.to_string(),
+            largetype: title.to_string(),
+        },
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exactobar_core::{UsageSnapshot, UsageWindow};
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse_args(&args(&["exactobar-app"])), None);
+        assert_eq!(
+            parse_args(&args(&["exactobar-app", "--query"])).as_deref(),
+            Some("")
+        );
+        assert_eq!(
+            parse_args(&args(&["exactobar-app", "--query", "cla"])).as_deref(),
+            Some("cla")
+        );
+    }
+
+    #[test]
+    fn test_items() {
+        let now = Utc::now();
+        let mut session = UsageWindow::new(38.0);
+        session.resets_at = Some(now + chrono::Duration::minutes(80));
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(session);
+        snapshot.updated_at = now - chrono::Duration::minutes(4);
+
+        let mut cache = UsageCache::default();
+        cache.snapshots.insert(ProviderKind::Claude, snapshot);
+        cache.errors.insert(
+            ProviderKind::Codex,
+            "Error: not logged in\n\nDetails".into(),
+        );
+
+        let providers = [ProviderKind::Claude, ProviderKind::Codex];
+        let all = items(&providers, &cache, "", now);
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].title, "Claude: 62% left");
+        assert!(all[0].subtitle.contains("resets in 1h 20m"));
+        assert!(all[0].subtitle.ends_with("Updated 4m ago"));
+        assert_eq!(all[1].subtitle, "Error: not logged in");
+
+        let filtered = items(&providers, &cache, "CLA", now);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].arg, "claude");
+
+        let none = items(&[], &cache, "", now);
+        assert!(!none[0].valid);
+    }
+}
@@ -6,24 +6,134 @@
 
 #![allow(dead_code)]
 
-use std::sync::OnceLock;
-use std::time::Duration;
-
-use exactobar_core::{ProviderKind, UsageSnapshot};
-use exactobar_fetch::FetchContext;
-use exactobar_providers::ProviderRegistry;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local, Utc};
+use exactobar_core::{ProviderError, ProviderKind, WindowKind};
+use exactobar_fetch::{FetchContext, FetchResult};
+use exactobar_providers::{ProviderRegistry, demo};
+use exactobar_store::UsageCache;
 use gpui::*;
 use smol::Timer;
-use tracing::{debug, error, info};
-
-use crate::notifications::{NotificationTracker, send_quota_notification};
-use crate::state::{AppState, UsageModel};
+use tracing::{debug, error, info, warn};
+
+use crate::mqtt;
+use crate::notifications::{
+    NotificationTracker, send_paused_notification, send_quota_notification,
+};
+use crate::power;
+use crate::server::ProviderUsage;
+use crate::state::{AppState, SettingsModel, UsageModel};
+use crate::swiftbar;
+use crate::webhooks::{self, WebhookPayload, WebhookTracker};
 
 /// Global notification tracker for quota alerts.
 /// Uses Lazy<Mutex<>> to avoid spamming notifications across refresh cycles.
 static NOTIFICATION_TRACKER: once_cell::sync::Lazy<std::sync::Mutex<NotificationTracker>> =
     once_cell::sync::Lazy::new(|| std::sync::Mutex::new(NotificationTracker::new()));
 
+/// Global tracker turning refresh results into webhook events.
+static WEBHOOK_TRACKER: once_cell::sync::Lazy<std::sync::Mutex<WebhookTracker>> =
+    once_cell::sync::Lazy::new(|| std::sync::Mutex::new(WebhookTracker::new()));
+
+/// Hard limit on a single provider fetch, so a hung probe can't keep the
+/// "Refreshing…" spinner alive forever.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Limit on a status page check.
+const STATUS_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// How often to look for usage windows that have reset.
+const RESET_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Wait before the first refresh after launch.
+const INITIAL_REFRESH_DELAY: Duration = Duration::from_secs(2);
+
+/// Wait before the first refresh after a quiet start, so fetches stay out of
+/// the way while the rest of the login items start.
+const QUIET_START_REFRESH_DELAY: Duration = Duration::from_secs(60);
+
+/// Gap between providers' first refreshes after a quiet start.
+const QUIET_START_REFRESH_SPACING: Duration = Duration::from_secs(10);
+
+/// A failed fetch.
+#[derive(Debug, Clone)]
+pub struct FetchFailure {
+    /// Error shown on the provider's card.
+    pub message: String,
+    /// What kind of failure it was. If the provider rejected the
+    /// credentials, scheduled refreshes stop until the user signs in again.
+    pub error: ProviderError,
+}
+
+impl FetchFailure {
+    pub fn new(error: ProviderError) -> Self {
+        Self {
+            message: format!("Error: {error}"),
+            error,
+        }
+    }
+}
+
+/// A fetch in flight, cancelled by dropping its sender.
+struct InFlightFetch {
+    provider: ProviderKind,
+    _cancel: tokio::sync::oneshot::Sender<()>,
+}
+
+/// Fetches in flight, by id.
+static IN_FLIGHT: once_cell::sync::Lazy<std::sync::Mutex<HashMap<u64, InFlightFetch>>> =
+    once_cell::sync::Lazy::new(|| std::sync::Mutex::new(HashMap::new()));
+
+/// Source of [`IN_FLIGHT`] ids.
+static NEXT_FETCH_ID: AtomicU64 = AtomicU64::new(0);
+
+/// API base URL overrides from the settings, read when fetches start.
+static BASE_URLS: once_cell::sync::Lazy<RwLock<HashMap<ProviderKind, String>>> =
+    once_cell::sync::Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Keeps fetches pointed at the API base URLs set in the settings.
+///
+/// Call once the app state is set up.
+pub fn track_base_urls(cx: &mut App) {
+    let settings = cx.global::<AppState>().settings.clone();
+    let update = |base_urls: HashMap<ProviderKind, String>| {
+        if let Ok(mut current) = BASE_URLS.write() {
+            *current = base_urls;
+        }
+    };
+
+    update(settings.read(cx).base_urls());
+    cx.observe(&settings, move |settings, cx| {
+        update(settings.read(cx).base_urls());
+    })
+    .detach();
+}
+
+/// Keeps HTTP clients using the proxy, TLS options and request budget set
+/// in the settings, and recording requests for the network inspector in
+/// debug mode.
+///
+/// Call once the app state is set up.
+pub fn track_network(cx: &mut App) {
+    let settings = cx.global::<AppState>().settings.clone();
+    let update = |model: &SettingsModel| {
+        exactobar_fetch::network::set_proxy(model.proxy_config());
+        exactobar_fetch::network::set_tls(model.tls_config());
+        exactobar_fetch::ratelimit::set_rate_limit(model.settings().rate_limit());
+        exactobar_fetch::inspector::set_recording(model.settings().debug_mode);
+    };
+
+    update(settings.read(cx));
+    cx.observe(&settings, move |settings, cx| {
+        update(settings.read(cx));
+    })
+    .detach();
+}
+
 /// Global Tokio runtime for fetch operations.
 /// We need this because the fetch/providers libraries use tokio::process::Command
 /// which requires a Tokio runtime, but GPUI runs on smol.
@@ -63,32 +173,52 @@ pub fn spawn_refresh_task(cx: &mut App) {
     let state = cx.global::<AppState>();
     let initial_providers = state.enabled_providers(cx);
     let usage = state.usage.clone();
+    let quiet = state.settings.read(cx).start_silently();
 
-    cx.spawn(async move |mut cx| {
-        // Initial refresh after a short delay
-        Timer::after(Duration::from_secs(2)).await;
+    spawn_reset_watcher(usage.clone(), cx);
 
-        for provider in &initial_providers {
+    cx.spawn(async move |mut cx| {
+        // Initial refresh after a short delay, or spread out on a quiet start
+        let (delay, spacing) = if quiet {
+            (QUIET_START_REFRESH_DELAY, QUIET_START_REFRESH_SPACING)
+        } else {
+            (INITIAL_REFRESH_DELAY, Duration::ZERO)
+        };
+        Timer::after(delay).await;
+
+        for (i, provider) in initial_providers.iter().enumerate() {
+            if i > 0 && !spacing.is_zero() {
+                Timer::after(spacing).await;
+            }
             refresh_provider(*provider, usage.clone(), &mut cx).await;
         }
 
         loop {
             // Get refresh cadence from settings - try to get duration, default to 5 minutes
-            let duration_result = cx.update(|cx| {
-                let state = cx.global::<AppState>();
-                state.settings.read(cx).refresh_cadence().as_duration()
+            let (duration_result, saving_power) = cx.update(|cx| {
+                let settings = cx.global::<AppState>().settings.read(cx);
+                let cadence = if settings.refresh_paused() {
+                    None
+                } else {
+                    settings.refresh_cadence().as_duration()
+                };
+                (cadence, power::saving_power(cx))
             });
 
             let duration: Duration = match duration_result {
-                Some(d) => d,
+                Some(d) => power::refresh_interval(d, saving_power),
                 None => {
-                    // Manual mode or error - sleep 60 seconds and loop
+                    // Manual mode, paused or error - sleep 60 seconds and loop
                     Timer::after(Duration::from_secs(60)).await;
                     continue;
                 }
             };
 
-            debug!("Sleeping {} seconds until next refresh", duration.as_secs());
+            debug!(
+                saving_power,
+                "Sleeping {} seconds until next refresh",
+                duration.as_secs()
+            );
             Timer::after(duration).await;
 
             // Get current providers and refresh
@@ -99,6 +229,73 @@ pub fn spawn_refresh_task(cx: &mut App) {
 
             if let Some(providers) = Some(providers_result) {
                 for provider in providers {
+                    // Rejected credentials won't start working on their own
+                    let (needs_auth, paused) = cx.update(|cx| {
+                        let usage = usage.read(cx);
+                        (usage.needs_auth(provider), usage.is_paused(provider))
+                    });
+                    if needs_auth {
+                        debug!("Provider {:?} needs to sign in again, skipping", provider);
+                        continue;
+                    }
+                    if paused {
+                        debug!(
+                            "Provider {:?} paused after repeated errors, skipping",
+                            provider
+                        );
+                        continue;
+                    }
+                    refresh_provider(provider, usage.clone(), &mut cx).await;
+                }
+            }
+        }
+    })
+    .detach();
+}
+
+/// Spawns the task that refreshes a provider once one of its windows has
+/// reset, rather than showing pre-reset usage until the next scheduled
+/// refresh.
+///
+/// Each passed reset triggers a single refresh, so a provider that keeps
+/// reporting an old reset time isn't refreshed over and over.
+fn spawn_reset_watcher(usage: Entity<UsageModel>, cx: &mut App) {
+    cx.spawn(async move |mut cx| {
+        let mut handled: HashMap<ProviderKind, DateTime<Utc>> = HashMap::new();
+
+        loop {
+            Timer::after(RESET_CHECK_INTERVAL).await;
+
+            let now = Utc::now();
+            let due: Vec<_> = cx.update(|cx| {
+                let providers = cx.global::<AppState>().enabled_providers(cx);
+                let usage = usage.read(cx);
+                providers
+                    .into_iter()
+                    .filter(|&provider| {
+                        !usage.is_refreshing(provider)
+                            && !usage.needs_auth(provider)
+                            && !usage.is_paused(provider)
+                    })
+                    .filter_map(|provider| {
+                        let snapshot = usage.get_snapshot(provider)?;
+                        let reset = WindowKind::all()
+                            .iter()
+                            .filter_map(|&kind| snapshot.window(kind))
+                            .filter(|w| w.reset_passed(now))
+                            .filter_map(|w| w.resets_at)
+                            .max()?;
+                        Some((provider, reset))
+                    })
+                    .collect()
+            });
+
+            for (provider, reset) in due {
+                if handled.insert(provider, reset) != Some(reset) {
+                    debug!(
+                        "Provider {:?} window reset at {}, refreshing",
+                        provider, reset
+                    );
                     refresh_provider(provider, usage.clone(), &mut cx).await;
                 }
             }
@@ -113,77 +310,143 @@ pub fn spawn_refresh_task(cx: &mut App) {
 /// **IMPORTANT**: All fetch operations MUST go through this function!
 /// The fetch/providers libraries use tokio::process::Command which requires
 /// a Tokio runtime. Calling them directly from smol will panic.
-pub async fn fetch_on_tokio(provider: ProviderKind) -> Result<UsageSnapshot, String> {
+///
+/// A fetch taking longer than [`FETCH_TIMEOUT`] fails. Returns `None` if the
+/// fetch was cancelled with [`cancel_fetch`], in which case the caller should
+/// leave the provider's data alone.
+pub async fn fetch_on_tokio(provider: ProviderKind) -> Option<Result<FetchResult, FetchFailure>> {
     let rt = tokio_runtime();
 
+    let id = NEXT_FETCH_ID.fetch_add(1, Ordering::Relaxed);
+    let (cancel, cancelled) = tokio::sync::oneshot::channel();
+    if let Ok(mut in_flight) = IN_FLIGHT.lock() {
+        in_flight.insert(
+            id,
+            InFlightFetch {
+                provider,
+                _cancel: cancel,
+            },
+        );
+    }
+
     // Use spawn_blocking to run the tokio future on the tokio runtime
     // from within a smol context
     let result = smol::unblock(move || {
         rt.block_on(async move {
-            let ctx = FetchContext::new();
-            if let Some(desc) = ProviderRegistry::get(provider) {
-                let pipeline = desc.build_pipeline(&ctx);
-                let outcome = pipeline.execute(&ctx).await;
-
-                match outcome.result {
-                    Ok(fetch_result) => {
-                        debug!(
-                            "Provider {:?} fetch succeeded with strategy {:?}",
-                            provider, fetch_result.strategy_id
-                        );
-                        Ok(fetch_result.snapshot)
-                    }
-                    Err(e) => {
-                        // Build detailed error message including all strategy failures
-                        let mut error_parts = vec![format!("Error: {}", e)];
-
-                        if !outcome.attempts.is_empty() {
-                            error_parts.push(String::new()); // blank line
-                            error_parts
-                                .push(format!("Strategies tried ({}):", outcome.attempts.len()));
-
-                            for attempt in &outcome.attempts {
-                                let status = if attempt.success { "✓" } else { "✗" };
-                                let error_info = attempt
-                                    .error
-                                    .as_ref()
-                                    .map(|e| format!(": {}", e))
-                                    .unwrap_or_default();
-
-                                error_parts.push(format!(
-                                    "  {} {} [{}]{}",
-                                    status, attempt.strategy_id, attempt.kind, error_info
-                                ));
-                            }
-                        }
-
-                        let detailed_error = error_parts.join("\n");
-                        error!("Provider {:?} fetch failed:\n{}", provider, detailed_error);
-                        Err(detailed_error)
-                    }
+            tokio::select! {
+                result = tokio::time::timeout(FETCH_TIMEOUT, fetch(provider)) => {
+                    Some(result.unwrap_or_else(|_| {
+                        warn!("Provider {:?} fetch timed out", provider);
+                        Err(FetchFailure::new(ProviderError::Network(format!(
+                            "Timed out after {}s",
+                            FETCH_TIMEOUT.as_secs()
+                        ))))
+                    }))
+                }
+                // Dropping the fetch kills any CLI it spawned
+                _ = cancelled => {
+                    debug!("Provider {:?} fetch cancelled", provider);
+                    None
                 }
-            } else {
-                Err("Provider not found".to_string())
             }
         })
     })
     .await;
 
+    if let Ok(mut in_flight) = IN_FLIGHT.lock() {
+        in_flight.remove(&id);
+    }
+
     result
 }
 
+/// Cancels the provider's in-flight fetches, if any.
+pub fn cancel_fetch(provider: ProviderKind) {
+    if let Ok(mut in_flight) = IN_FLIGHT.lock() {
+        // Dropping the sender wakes the fetch's cancellation branch
+        in_flight.retain(|_, fetch| fetch.provider != provider);
+    }
+}
+
+/// Runs the provider's fetch pipeline, must be called on the Tokio runtime.
+async fn fetch(provider: ProviderKind) -> Result<FetchResult, FetchFailure> {
+    let mut builder = FetchContext::builder();
+    let base_url = BASE_URLS
+        .read()
+        .ok()
+        .and_then(|base_urls| base_urls.get(&provider).cloned());
+    if let Some(url) = base_url {
+        builder = builder.base_url(provider, url);
+    }
+    let ctx = builder.build();
+    if let Some(desc) = ProviderRegistry::get(provider) {
+        let pipeline = desc.build_pipeline(&ctx);
+        let outcome = pipeline.execute(&ctx).await;
+
+        match outcome.result {
+            Ok(fetch_result) => {
+                debug!(
+                    "Provider {:?} fetch succeeded with strategy {:?}",
+                    provider, fetch_result.strategy_id
+                );
+                Ok(fetch_result)
+            }
+            Err(e) => {
+                // Build detailed error message including all strategy failures
+                let mut error_parts = vec![format!("Error: {}", e.full_message())];
+
+                if !outcome.attempts.is_empty() {
+                    error_parts.push(String::new()); // blank line
+                    error_parts.push(format!("Strategies tried ({}):", outcome.attempts.len()));
+
+                    for attempt in &outcome.attempts {
+                        let status = if attempt.success { "✓" } else { "✗" };
+                        let error_info = attempt
+                            .error
+                            .as_ref()
+                            .map(|e| format!(": {}", e))
+                            .unwrap_or_default();
+
+                        error_parts.push(format!(
+                            "  {} {} [{}]{}",
+                            status, attempt.strategy_id, attempt.kind, error_info
+                        ));
+                    }
+                }
+
+                let detailed_error = error_parts.join("\n");
+                error!("Provider {:?} fetch failed:\n{}", provider, detailed_error);
+                Err(FetchFailure {
+                    message: detailed_error,
+                    error: outcome.provider_error().unwrap_or_else(|| e.classify()),
+                })
+            }
+        }
+    } else {
+        Err(FetchFailure::new(ProviderError::Unexpected(
+            "Provider not found".to_string(),
+        )))
+    }
+}
+
 /// Refreshes a single provider.
 async fn refresh_provider(provider: ProviderKind, usage: Entity<UsageModel>, cx: &mut AsyncApp) {
     debug!("Refreshing provider {:?}", provider);
 
     // Mark as refreshing
     let _ = cx.update_entity(&usage, |model, cx| {
-        model.set_refreshing(provider, true);
-        cx.notify();
+        model.update_provider(provider, cx, |model| model.set_refreshing(provider, true));
     });
 
     // Execute fetch on Tokio runtime
-    let result = fetch_on_tokio(provider).await;
+    let started = Instant::now();
+    let Some(result) = fetch_on_tokio(provider).await else {
+        let _ = cx.update_entity(&usage, |model, cx| {
+            model.update_provider(provider, cx, |model| model.set_refreshing(provider, false));
+        });
+        return;
+    };
+    let elapsed = started.elapsed();
 
     // Check if notifications are enabled before we move result
     let notify_enabled = cx.update(|cx| {
@@ -195,7 +458,8 @@ async fn refresh_provider(provider: ProviderKind, usage: Entity<UsageModel>, cx:
     });
 
     // Check for quota notifications on successful fetch
-    if let Ok(ref snapshot) = result {
+    if let Ok(ref fetched) = result {
+        let snapshot = &fetched.snapshot;
         if notify_enabled {
             if let Ok(mut tracker) = NOTIFICATION_TRACKER.lock() {
                 if let Some(level) = tracker.should_notify(provider, snapshot) {
@@ -210,22 +474,174 @@ async fn refresh_provider(provider: ProviderKind, usage: Entity<UsageModel>, cx:
         }
     }
 
+    let webhooks = cx.update(|cx| {
+        cx.global::<AppState>()
+            .settings
+            .read(cx)
+            .settings()
+            .webhooks
+            .clone()
+    });
+
+    let swiftbar = cx.update(|cx| {
+        let state = cx.global::<AppState>();
+        let path = state
+            .settings
+            .read(cx)
+            .settings()
+            .swiftbar_plugin_path
+            .clone();
+        path.map(|path| (path, state.enabled_providers(cx)))
+    });
+
     // Update state
-    let _ = cx.update_entity(&usage, |model, cx| {
-        model.set_refreshing(provider, false);
-        match result {
-            Ok(snapshot) => {
-                model.set_snapshot(provider, snapshot);
-                model.clear_error(provider);
+    let (events, cache) = cx.update_entity(&usage, |model, cx| {
+        let stale_after = cx.global::<AppState>().stale_after(provider, cx);
+        let events = model.update_provider(provider, cx, |model| {
+            model.set_refreshing(provider, false);
+            model.set_fetch_duration(provider, elapsed);
+            if provider == ProviderKind::Demo {
+                // Demo status steps along with its usage
+                model.set_status(provider, demo::demo_status(demo::current_step()));
             }
-            Err(e) => {
-                model.set_error(provider, e);
+            let mut tracker = WEBHOOK_TRACKER.lock().ok();
+            match result {
+                Ok(fetched) => {
+                    let updated_at = fetched.snapshot.updated_at;
+                    model.set_raw_response(provider, fetched.raw_response);
+                    model.set_snapshot(provider, fetched.snapshot, stale_after);
+                    model.clear_error(provider);
+
+                    // Resets first observed by this snapshot
+                    let resets = WindowKind::all()
+                        .iter()
+                        .filter_map(|&window| model.history().resets(provider, window).pop())
+                        .filter(|reset| reset.observed_at == updated_at)
+                        .collect();
+                    let snapshot = model.get_snapshot(provider);
+                    match (tracker.as_mut(), snapshot) {
+                        (Some(tracker), Some(snapshot)) => {
+                            tracker.on_success(provider, &snapshot, resets)
+                        }
+                        _ => Vec::new(),
+                    }
+                }
+                Err(e) => {
+                    let event = tracker
+                        .as_mut()
+                        .and_then(|t| t.on_error(provider, &e.message));
+                    if model.set_failure(provider, e) {
+                        send_paused_notification(provider);
+                    }
+                    event.into_iter().collect()
+                }
             }
+        });
+        (events, model.usage_cache())
+    });
+
+    if !webhooks.is_empty() {
+        for event in events {
+            webhooks::dispatch(&webhooks, WebhookPayload::new(provider, event));
         }
-        cx.notify();
+    }
+
+    if let Some((path, providers)) = swiftbar {
+        let cache = cache.clone();
+        smol::unblock(move || {
+            if let Err(e) = swiftbar::write_plugin(&path, &providers, &cache, Utc::now()) {
+                warn!(path = %path.display(), error = %e, "Failed to write SwiftBar plugin");
+            }
+        })
+        .await;
+    }
+
+    refresh_projects(provider, &usage, cx).await;
+    refresh_status(provider, &usage, cx).await;
+
+    mqtt::publish(&ProviderUsage::new(
+        provider,
+        cache.snapshots.get(&provider),
+        cache.errors.get(&provider).cloned(),
+    ));
+
+    save_usage_cache(cache).await;
+}
+
+/// Re-reads which projects used a provider's tokens today, for CLI
+/// providers that log their sessions locally.
+async fn refresh_projects(provider: ProviderKind, usage: &Entity<UsageModel>, cx: &mut AsyncApp) {
+    let Some(project_usage) =
+        ProviderRegistry::get(provider).and_then(|d| d.token_cost.project_usage)
+    else {
+        return;
+    };
+    let since = start_of_today();
+    let projects = smol::unblock(move || project_usage(since)).await;
+    let _ = cx.update_entity(usage, |model, cx| {
+        model.update_provider(provider, cx, |model| model.set_projects(provider, projects));
     });
 }
 
+/// Re-reads the provider's status page, with its unresolved incidents,
+/// when status checks are on.
+async fn refresh_status(provider: ProviderKind, usage: &Entity<UsageModel>, cx: &mut AsyncApp) {
+    let enabled = cx.update(|cx| {
+        cx.global::<AppState>()
+            .settings
+            .read(cx)
+            .settings()
+            .status_checks_enabled
+    });
+    let Some(status_url) = ProviderRegistry::get(provider)
+        .and_then(|d| d.metadata.status_page_url.clone())
+        .filter(|_| enabled)
+    else {
+        return;
+    };
+
+    let rt = tokio_runtime();
+    let result = smol::unblock(move || {
+        rt.block_on(async move {
+            let poller = exactobar_fetch::StatusPoller::new();
+            tokio::time::timeout(STATUS_TIMEOUT, poller.fetch_summary(&status_url)).await
+        })
+    })
+    .await;
+
+    match result {
+        Ok(Ok(status)) => {
+            let _ = cx.update_entity(usage, |model, cx| {
+                model.update_provider(provider, cx, |model| model.set_status(provider, status));
+            });
+        }
+        Ok(Err(e)) => debug!(provider = ?provider, error = %e, "Status check failed"),
+        Err(_) => debug!(provider = ?provider, "Status check timed out"),
+    }
+}
+
+/// Returns local midnight today.
+fn start_of_today() -> DateTime<Utc> {
+    Local::now()
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+        .map_or_else(Utc::now, |midnight| midnight.with_timezone(&Utc))
+}
+
+/// Saves the latest usage for `--query` and other readers.
+async fn save_usage_cache(mut cache: UsageCache) {
+    let rt = tokio_runtime();
+    smol::unblock(move || {
+        rt.block_on(async move {
+            if let Err(e) = cache.save_default().await {
+                warn!(error = %e, "Failed to save usage cache");
+            }
+        })
+    })
+    .await;
+}
+
 /// Triggers an immediate refresh of all providers.
 pub fn trigger_refresh(cx: &mut App) {
     let state = cx.global::<AppState>();
@@ -239,3 +655,24 @@ pub fn trigger_refresh(cx: &mut App) {
     })
     .detach();
 }
+
+/// Triggers an immediate refresh of one provider.
+pub fn trigger_provider_refresh(provider: ProviderKind, cx: &mut App) {
+    let usage = cx.global::<AppState>().usage.clone();
+
+    cx.spawn(async move |mut cx| {
+        refresh_provider(provider, usage, &mut cx).await;
+    })
+    .detach();
+}
+
+/// Resumes scheduled refreshes of a provider paused after repeated errors,
+/// refreshing it right away.
+pub fn resume_provider(provider: ProviderKind, cx: &mut App) {
+    info!(provider = ?provider, "Resuming refreshes");
+    let usage = cx.global::<AppState>().usage.clone();
+    usage.update(cx, |model, cx| {
+        model.update_provider(provider, cx, |model| model.resume(provider));
+    });
+    trigger_provider_refresh(provider, cx);
+}
@@ -0,0 +1,176 @@
+//! Headless screenshot rendering.
+//!
+//! `exactobar-app --render-screenshots DIR` renders the menu bar icons, a
+//! usage card per provider and a card of every provider together (the
+//! popup's meters) to PNG files in `DIR`, then exits without opening any
+//! windows. Everything is drawn with the same tiny-skia code as the tray
+//! and the "Share usage" export, so the images can go on the website or be
+//! diffed between builds.
+//!
+//! Usage comes from recorded responses when `EXACTOBAR_REPLAY_FIXTURES` is
+//! set (see [`exactobar_fetch::fixtures`]), and from the demo provider's
+//! generated scenario otherwise. Nothing is fetched from providers.
+
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use exactobar_core::{ProviderKind, UsageSnapshot};
+use exactobar_fetch::{FetchContext, FixtureMode};
+use exactobar_providers::{ProviderRegistry, demo};
+
+use crate::icon::{IconRenderer, RenderMode, UsageCardEntry};
+
+/// The command-line flag that selects screenshot mode.
+pub const FLAG: &str = "--render-screenshots";
+
+/// Demo scenario step used for the demo provider's icon and card.
+const DEMO_STEP: u64 = 4;
+
+/// Returns the output directory if screenshot mode was requested on the
+/// command line.
+pub fn parse_args(args: &[String]) -> Option<PathBuf> {
+    let index = args.iter().position(|a| a == FLAG)?;
+    Some(
+        args.get(index + 1)
+            .filter(|a| !a.starts_with("--"))
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("screenshots")),
+    )
+}
+
+/// Renders the screenshots into `dir`.
+pub fn run(dir: &Path) {
+    let runtime = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("Failed to start runtime: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let mut snapshots = match FixtureMode::from_env() {
+        FixtureMode::Replay(fixtures) => runtime.block_on(replay_all(&fixtures)),
+        _ => Vec::new(),
+    };
+    if let Ok(snapshot) = demo::demo_snapshot(DEMO_STEP, Utc::now()) {
+        snapshots.push((ProviderKind::Demo, snapshot));
+    }
+
+    match write_all(dir, &snapshots) {
+        Ok(count) => println!("Wrote {} screenshots to {}", count, dir.display()),
+        Err(e) => {
+            eprintln!("Failed to write screenshots to {}: {}", dir.display(), e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Replays every provider's recorded responses, skipping providers without
+/// a fixture.
+async fn replay_all(fixtures: &Path) -> Vec<(ProviderKind, UsageSnapshot)> {
+    let ctx = FetchContext::builder()
+        .fixtures(FixtureMode::Replay(fixtures.to_path_buf()))
+        .build();
+
+    let mut snapshots = Vec::new();
+    for desc in ProviderRegistry::all() {
+        let outcome = desc.build_pipeline(&ctx).execute(&ctx).await;
+        match outcome.result {
+            Ok(result) => snapshots.push((desc.id, result.snapshot)),
+            Err(_) => eprintln!("No fixture for {}, skipping", desc.display_name()),
+        }
+    }
+    snapshots
+}
+
+/// Writes the icons and cards, returning how many files were written.
+fn write_all(dir: &Path, snapshots: &[(ProviderKind, UsageSnapshot)]) -> std::io::Result<usize> {
+    let icons = dir.join("icons");
+    let cards = dir.join("cards");
+    std::fs::create_dir_all(&icons)?;
+    std::fs::create_dir_all(&cards)?;
+
+    let template = IconRenderer::new();
+    let colored = IconRenderer::new().with_mode(RenderMode::Colored);
+    let mut written = 0;
+    let mut write = |path: PathBuf, png: Vec<u8>| {
+        written += 1;
+        std::fs::write(path, png)
+    };
+
+    let mut entries = Vec::new();
+    for (provider, snapshot) in snapshots {
+        let name = provider.cli_name();
+        write(
+            icons.join(format!("{name}.png")),
+            template
+                .render(*provider, Some(snapshot), false, None, None)
+                .to_png(),
+        )?;
+        write(
+            icons.join(format!("{name}-colored.png")),
+            colored
+                .render(*provider, Some(snapshot), false, None, None)
+                .to_png(),
+        )?;
+
+        let entry = UsageCardEntry::from_snapshot(*provider, Some(snapshot), false, Utc::now());
+        write(
+            cards.join(format!("{name}.png")),
+            IconRenderer::render_usage_card(std::slice::from_ref(&entry), Default::default())
+                .to_png(),
+        )?;
+        entries.push(entry);
+    }
+
+    // The icon's other states, drawn for the demo provider
+    let demo = ProviderKind::Demo;
+    write(
+        icons.join("demo-loading.png"),
+        colored.render_loading(demo, 0.25).to_png(),
+    )?;
+    write(
+        icons.join("demo-error.png"),
+        colored.render_error(demo).to_png(),
+    )?;
+    write(
+        icons.join("demo-signed-out.png"),
+        colored.render_auth_required(demo).to_png(),
+    )?;
+
+    write(
+        dir.join("popup.png"),
+        IconRenderer::render_usage_card(&entries, Default::default()).to_png(),
+    )?;
+
+    Ok(written)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse_args(&args(&["exactobar-app"])), None);
+        assert_eq!(
+            parse_args(&args(&["exactobar-app", FLAG])),
+            Some(PathBuf::from("screenshots"))
+        );
+        assert_eq!(
+            parse_args(&args(&["exactobar-app", FLAG, "/tmp/shots"])),
+            Some(PathBuf::from("/tmp/shots"))
+        );
+    }
+}
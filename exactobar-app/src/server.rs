@@ -0,0 +1,356 @@
+//! Local HTTP server for integrations.
+//!
+//! Serves the opt-in integrations on localhost: the MCP endpoint (`/mcp`,
+//! see [`crate::mcp`]), Prometheus metrics (`/metrics`, see
+//! [`crate::metrics`]) and the WebSocket API (`/ws`, see
+//! [`crate::websocket`]). Handlers answer from a copy of the usage shown in
+//! the menu, kept current as providers refresh; only WebSocket clients can
+//! ask for a fetch.
+
+use chrono::{DateTime, Utc};
+use exactobar_core::{ProviderKind, UsageSnapshot, UsageWindow, WindowKind};
+use exactobar_providers::ProviderRegistry;
+use gpui::*;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::RwLock;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::state::AppState;
+
+/// Largest request body accepted.
+const MAX_BODY: usize = 1024 * 1024;
+
+/// Usage as last shown in the menu, readable from the server thread.
+static USAGE: Lazy<RwLock<Vec<ProviderUsage>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+// ============================================================================
+// Usage Snapshot
+// ============================================================================
+
+/// Usage for one provider, as served to integrations.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderUsage {
+    /// CLI name, e.g. "claude".
+    pub provider: String,
+    /// Display name, e.g. "Claude".
+    pub name: String,
+    pub windows: Vec<WindowUsage>,
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Last fetch error, if the latest refresh failed.
+    pub error: Option<String>,
+    /// How long the latest refresh took.
+    #[serde(skip)]
+    pub fetch_duration: Option<Duration>,
+}
+
+/// One quota window of a provider.
+#[derive(Debug, Clone, Serialize)]
+pub struct WindowUsage {
+    pub window: WindowKind,
+    /// Window label, e.g. "Session" or "Weekly".
+    pub label: String,
+    pub used_percent: f64,
+    pub remaining_percent: f64,
+    pub window_minutes: Option<u32>,
+    pub resets_at: Option<DateTime<Utc>>,
+}
+
+impl ProviderUsage {
+    pub fn new(
+        provider: ProviderKind,
+        snapshot: Option<&UsageSnapshot>,
+        error: Option<String>,
+    ) -> Self {
+        let mut windows = Vec::new();
+
+        if let Some(snapshot) = snapshot {
+            windows = WindowKind::all()
+                .iter()
+                .filter_map(|&kind| {
+                    snapshot
+                        .window(kind)
+                        .map(|w| WindowUsage::new(kind, &window_label(provider, kind), w))
+                })
+                .collect();
+        }
+
+        Self {
+            provider: provider.cli_name().to_string(),
+            name: provider.display_name().to_string(),
+            windows,
+            updated_at: snapshot.map(|s| s.updated_at),
+            error,
+            fetch_duration: None,
+        }
+    }
+}
+
+/// Returns a provider's label for a window, e.g. "Session" or "Weekly".
+pub fn window_label(provider: ProviderKind, kind: WindowKind) -> String {
+    let metadata = ProviderRegistry::get(provider).map(|d| &d.metadata);
+    let label = match kind {
+        WindowKind::Primary => metadata.map_or("Session", |m| m.session_label.as_str()),
+        WindowKind::Secondary => metadata.map_or("Weekly", |m| m.weekly_label.as_str()),
+        WindowKind::Tertiary => metadata
+            .and_then(|m| m.opus_label.as_deref())
+            .unwrap_or("Opus"),
+        WindowKind::Search => "Search",
+    };
+    label.to_string()
+}
+
+impl WindowUsage {
+    fn new(window: WindowKind, label: &str, usage: &UsageWindow) -> Self {
+        Self {
+            window,
+            label: label.to_string(),
+            used_percent: usage.used_percent,
+            remaining_percent: usage.remaining_percent(),
+            window_minutes: usage.window_minutes,
+            resets_at: usage.resets_at,
+        }
+    }
+}
+
+// ============================================================================
+// Server
+// ============================================================================
+
+/// Endpoints enabled in settings.
+#[derive(Clone, Copy)]
+struct Routes {
+    mcp: bool,
+    metrics: bool,
+    websocket: bool,
+}
+
+/// An HTTP response.
+pub struct Response {
+    pub status: &'static str,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    pub fn new(status: &'static str, content_type: &'static str, body: String) -> Self {
+        Self {
+            status,
+            content_type,
+            body,
+        }
+    }
+
+    /// A response with no body.
+    pub fn empty(status: &'static str) -> Self {
+        Self::new(status, "text/plain", String::new())
+    }
+}
+
+/// Mirrors usage for the server and starts it if any endpoint is enabled.
+///
+/// Enabling endpoints or changing the port takes effect on the next launch.
+pub fn start(cx: &mut App) {
+    let state = cx.global::<AppState>();
+    let settings = state.settings.read(cx).settings();
+    let routes = Routes {
+        mcp: settings.mcp_server_enabled,
+        metrics: settings.metrics_enabled,
+        websocket: settings.websocket_enabled,
+    };
+    let port = settings.server_port;
+    if !routes.mcp && !routes.metrics && !routes.websocket {
+        return;
+    }
+    if routes.websocket {
+        crate::websocket::start(cx);
+    }
+
+    // Keep the mirror current as usage refreshes or providers are toggled
+    let (usage, settings) = (state.usage.clone(), state.settings.clone());
+    cx.observe(&usage, |_, cx| publish(cx)).detach();
+    cx.observe(&settings, |_, cx| publish(cx)).detach();
+    publish(cx);
+
+    let listener = match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            warn!(port, error = %e, "Failed to start local server");
+            return;
+        }
+    };
+    info!(port, "Local server listening on http://127.0.0.1:{}", port);
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            // WebSocket connections stay open, so each gets its own thread
+            std::thread::spawn(move || {
+                if let Err(e) = serve(stream, routes, port) {
+                    debug!(error = %e, "Local server connection failed");
+                }
+            });
+        }
+    });
+}
+
+/// Returns the usage last published from the menu.
+pub fn usage() -> Vec<ProviderUsage> {
+    USAGE.read().map(|u| u.clone()).unwrap_or_default()
+}
+
+/// Copies the enabled providers' usage into [`USAGE`].
+fn publish(cx: &App) {
+    let state = cx.global::<AppState>();
+    let usage = state.usage.read(cx);
+    let providers = state
+        .enabled_providers(cx)
+        .into_iter()
+        .map(|p| ProviderUsage {
+            fetch_duration: usage.get_fetch_duration(p),
+            ..ProviderUsage::new(p, usage.get_snapshot(p).as_ref(), usage.get_error(p))
+        })
+        .collect();
+
+    if let Ok(mut published) = USAGE.write() {
+        *published = providers;
+    }
+    crate::websocket::broadcast();
+}
+
+/// Handles one HTTP request.
+fn serve(mut stream: TcpStream, routes: Routes, port: u16) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+
+    let mut content_length = 0;
+    let mut host = None;
+    let mut origin = None;
+    let mut websocket_key = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.trim().parse().unwrap_or(0),
+                "host" => host = Some(value.trim().to_string()),
+                "origin" => origin = Some(value.trim().to_string()),
+                "sec-websocket-key" => websocket_key = Some(value.trim().to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    // A page on a domain rebound to 127.0.0.1 still sends its own name as
+    // the Host, and browsers send an Origin; only local pages may talk to us
+    if !host.is_some_and(|h| is_local_host(&h, port))
+        || origin.is_some_and(|o| !is_local_origin(&o))
+    {
+        return respond(&mut stream, &Response::empty("403 Forbidden"));
+    }
+    if content_length > MAX_BODY {
+        return respond(&mut stream, &Response::empty("413 Payload Too Large"));
+    }
+
+    let route = path.split('?').next().unwrap_or("");
+    if route == "/ws" && routes.websocket {
+        return match websocket_key {
+            Some(key) if method == "GET" => crate::websocket::serve(stream, reader, &key),
+            _ => respond(&mut stream, &Response::empty("400 Bad Request")),
+        };
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let response = match route {
+        "/mcp" if routes.mcp => crate::mcp::handle_request(method, &body, &usage()),
+        "/metrics" if routes.metrics && method == "GET" => crate::metrics::handle_request(&usage()),
+        "/metrics" if routes.metrics => Response::empty("405 Method Not Allowed"),
+        _ => Response::empty("404 Not Found"),
+    };
+    respond(&mut stream, &response)
+}
+
+fn respond(stream: &mut TcpStream, response: &Response) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.content_type,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+/// Checks a Host header names this server: a loopback address or
+/// `localhost`, on `port`.
+fn is_local_host(host: &str, port: u16) -> bool {
+    let host = host.to_ascii_lowercase();
+    let (name, host_port) = match host.rsplit_once(':') {
+        Some((name, p)) if !p.ends_with(']') => (name, p.parse().ok()),
+        _ => (host.as_str(), Some(80)),
+    };
+    matches!(name, "localhost" | "127.0.0.1" | "[::1]") && host_port == Some(port)
+}
+
+fn is_local_origin(origin: &str) -> bool {
+    let host = origin
+        .split("://")
+        .nth(1)
+        .unwrap_or(origin)
+        .trim_end_matches('/');
+    let host = host.rsplit_once(':').map_or(host, |(host, _)| host);
+    matches!(host, "localhost" | "127.0.0.1" | "[::1]")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_local_host() {
+        assert!(is_local_host("127.0.0.1:47821", 47821));
+        assert!(is_local_host("localhost:47821", 47821));
+        assert!(is_local_host("[::1]:47821", 47821));
+        assert!(is_local_host("localhost", 80));
+        assert!(!is_local_host("localhost", 47821));
+        assert!(!is_local_host("localhost:47822", 47821));
+        assert!(!is_local_host("rebound.evil.example:47821", 47821));
+    }
+
+    #[test]
+    fn test_is_local_origin() {
+        assert!(is_local_origin("http://localhost:3000"));
+        assert!(is_local_origin("http://127.0.0.1"));
+        assert!(!is_local_origin("https://evil.example"));
+        assert!(!is_local_origin("http://localhost.evil.example"));
+    }
+
+    #[test]
+    fn test_provider_usage_windows() {
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(UsageWindow::new(40.0));
+        snapshot.secondary = Some(UsageWindow::new(10.0));
+
+        let usage = ProviderUsage::new(ProviderKind::Claude, Some(&snapshot), None);
+        assert_eq!(usage.provider, "claude");
+        assert_eq!(usage.windows.len(), 2);
+        assert_eq!(usage.windows[0].window, WindowKind::Primary);
+        assert_eq!(usage.windows[0].remaining_percent, 60.0);
+    }
+}
@@ -2,13 +2,22 @@
 //!
 //! Manages settings, usage data, and UI state accessible from GPUI context.
 
-use exactobar_core::{ProviderKind, ProviderStatus, UsageSnapshot};
-use exactobar_store::{CookieSource, DataSourceMode, Settings, SettingsStore};
+use exactobar_core::{ProviderError, ProviderKind, ProviderStatus, UsageSnapshot, WindowKind};
+use exactobar_providers::ProjectUsage;
+use exactobar_providers::claude::LiveSession;
+use exactobar_store::{
+    CookieSource, DataSourceMode, HistoryEntry, HistoryRetention, QuickAction, Settings,
+    SettingsStore, UsageCache, UsageGradient, UsageHistory, WindowPosition, WindowSize,
+};
 use gpui::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 use tokio::sync::RwLock;
-use tracing::{error, info};
+use tracing::{error, warn};
+
+use crate::refresh::FetchFailure;
 
 // ============================================================================
 // Tokio Runtime Bridge
@@ -30,7 +39,7 @@ static TOKIO_RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
 /// 3. Losing the ability to save settings would corrupt user experience
 ///
 /// Uses `OnceLock` so the panic can only occur once at initialization.
-fn tokio_runtime() -> &'static tokio::runtime::Runtime {
+pub(crate) fn tokio_runtime() -> &'static tokio::runtime::Runtime {
     TOKIO_RUNTIME.get_or_init(|| {
         tokio::runtime::Builder::new_multi_thread()
             .worker_threads(1)
@@ -93,11 +102,31 @@ impl AppState {
         self.usage.read(cx).get_snapshot(provider)
     }
 
+    /// Gets how long after a refresh a provider's data is shown as stale.
+    pub fn stale_after(&self, provider: ProviderKind, cx: &App) -> Duration {
+        self.settings.read(cx).settings().stale_after(provider)
+    }
+
     /// Gets the status for a provider.
     pub fn get_status(&self, provider: ProviderKind, cx: &App) -> Option<ProviderStatus> {
         self.usage.read(cx).get_status(provider)
     }
 
+    /// Gets a window's recorded usage since its last reset.
+    pub fn get_trajectory(&self, provider: ProviderKind, window: WindowKind, cx: &App) -> Vec<f64> {
+        self.usage.read(cx).get_trajectory(provider, window)
+    }
+
+    /// Gets how long until a window is used up at its recent burn rate.
+    pub fn get_time_until_limit(
+        &self,
+        provider: ProviderKind,
+        window: WindowKind,
+        cx: &App,
+    ) -> Option<chrono::Duration> {
+        self.usage.read(cx).get_time_until_limit(provider, window)
+    }
+
     /// Checks if a provider is currently refreshing.
     pub fn is_provider_refreshing(&self, provider: ProviderKind, cx: &App) -> bool {
         self.usage.read(cx).is_refreshing(provider)
@@ -108,48 +137,35 @@ impl AppState {
         self.usage.read(cx).get_error(provider)
     }
 
-    /// Refreshes all enabled providers.
-    pub fn refresh_all(&self, cx: &mut App) {
-        let providers = self.enabled_providers(cx);
-        info!(count = providers.len(), "Refreshing all providers");
+    /// Gets what kind of failure a provider's last fetch was.
+    pub fn get_failure(&self, provider: ProviderKind, cx: &App) -> Option<ProviderError> {
+        self.usage.read(cx).failure(provider).cloned()
+    }
 
-        for provider in providers {
-            self.refresh_provider(provider, cx);
-        }
+    /// Checks if a provider rejected its credentials and needs to sign in again.
+    pub fn needs_auth(&self, provider: ProviderKind, cx: &App) -> bool {
+        self.usage.read(cx).needs_auth(provider)
     }
 
-    /// Refreshes a single provider.
-    pub fn refresh_provider(&self, provider: ProviderKind, cx: &mut App) {
-        let usage = self.usage.clone();
+    /// Checks if a provider's scheduled refreshes stopped after repeated
+    /// errors.
+    pub fn is_paused(&self, provider: ProviderKind, cx: &App) -> bool {
+        self.usage.read(cx).is_paused(provider)
+    }
 
-        cx.spawn(async move |mut cx| {
-            // Mark as refreshing
-            let _ = cx.update_entity(&usage, |model, cx| {
-                model.set_refreshing(provider, true);
-                cx.notify();
-            });
+    /// Gets the last raw provider response (for the debug view).
+    pub fn get_raw_response(&self, provider: ProviderKind, cx: &App) -> Option<String> {
+        self.usage.read(cx).get_raw_response(provider)
+    }
 
-            // Execute fetch on Tokio runtime - MUST use this bridge!
-            // Direct pipeline.execute() calls will panic because tokio::process::Command
-            // requires a Tokio runtime, but GPUI runs on smol.
-            let result = crate::refresh::fetch_on_tokio(provider).await;
-
-            // Update state
-            let _ = cx.update_entity(&usage, |model, cx| {
-                model.set_refreshing(provider, false);
-                match result {
-                    Ok(snapshot) => {
-                        model.set_snapshot(provider, snapshot);
-                        model.clear_error(provider);
-                    }
-                    Err(e) => {
-                        model.set_error(provider, e);
-                    }
-                }
-                cx.notify();
-            });
-        })
-        .detach();
+    /// Gets the tokens used per project today, most first.
+    pub fn get_projects(&self, provider: ProviderKind, cx: &App) -> Vec<ProjectUsage> {
+        self.usage.read(cx).get_projects(provider)
+    }
+
+    /// Gets the Claude Code session being written to, if any.
+    pub fn get_live_session(&self, cx: &App) -> Option<LiveSession> {
+        self.usage.read(cx).get_live_session()
     }
 }
 
@@ -162,24 +178,28 @@ impl AppState {
 pub struct SettingsModel {
     store: Arc<RwLock<SettingsStore>>,
     cached_settings: Settings,
+    /// Bumped by every save; a debounced save only runs if it's still the
+    /// latest when its delay is up.
+    save_generation: Arc<AtomicU64>,
 }
 
+/// How long a window has to stop moving or resizing before its new frame
+/// is saved.
+const WINDOW_SAVE_DELAY: Duration = Duration::from_millis(500);
+
 impl SettingsModel {
     pub fn new(store: SettingsStore) -> Self {
         let cached = tokio_runtime().block_on(async { store.get().await });
         Self {
             store: Arc::new(RwLock::new(store)),
             cached_settings: cached,
+            save_generation: Arc::new(AtomicU64::new(0)),
         }
     }
 
-    /// Gets enabled providers.
+    /// Gets enabled providers in display order (pinned first).
     pub fn enabled_providers(&self) -> Vec<ProviderKind> {
-        self.cached_settings
-            .enabled_providers
-            .iter()
-            .copied()
-            .collect()
+        self.cached_settings.sorted_enabled_providers()
     }
 
     /// Checks if a provider is enabled.
@@ -187,6 +207,52 @@ impl SettingsModel {
         self.cached_settings.enabled_providers.contains(&provider)
     }
 
+    /// Checks if a provider is pinned to the top of the menu.
+    pub fn is_provider_pinned(&self, provider: ProviderKind) -> bool {
+        self.cached_settings.is_provider_pinned(provider)
+    }
+
+    /// Pins or unpins a provider.
+    pub fn toggle_provider_pinned(&mut self, provider: ProviderKind) {
+        let pinned = &mut self.cached_settings.pinned_providers;
+        if pinned.contains(&provider) {
+            pinned.retain(|p| *p != provider);
+        } else {
+            pinned.push(provider);
+        }
+        self.save_async();
+    }
+
+    /// Returns enabled providers split into the sections of the "All" view.
+    pub fn provider_sections(&self) -> Vec<(Option<String>, Vec<ProviderKind>)> {
+        self.cached_settings.provider_sections()
+    }
+
+    /// Gets whether provider groups get their own switcher tabs.
+    pub fn group_tabs(&self) -> bool {
+        self.cached_settings.group_tabs
+    }
+
+    /// Sets whether provider groups get their own switcher tabs.
+    pub fn set_group_tabs(&mut self, value: bool) {
+        self.cached_settings.group_tabs = value;
+        self.save_async();
+    }
+
+    /// Checks if a provider's card is collapsed in the "All" view.
+    pub fn is_provider_collapsed(&self, provider: ProviderKind) -> bool {
+        self.cached_settings.is_provider_collapsed(provider)
+    }
+
+    /// Collapses or expands a provider's card in the "All" view.
+    pub fn toggle_provider_collapsed(&mut self, provider: ProviderKind) {
+        let collapsed = &mut self.cached_settings.collapsed_providers;
+        if !collapsed.remove(&provider) {
+            collapsed.insert(provider);
+        }
+        self.save_async();
+    }
+
     /// Gets the refresh cadence.
     pub fn refresh_cadence(&self) -> exactobar_store::RefreshCadence {
         self.cached_settings.refresh_cadence
@@ -201,6 +267,7 @@ impl SettingsModel {
     pub fn toggle_provider(&mut self, provider: ProviderKind) {
         if self.cached_settings.enabled_providers.contains(&provider) {
             self.cached_settings.enabled_providers.remove(&provider);
+            crate::refresh::cancel_fetch(provider);
         } else {
             self.cached_settings.enabled_providers.insert(provider);
         }
@@ -213,6 +280,23 @@ impl SettingsModel {
         self.save_async();
     }
 
+    /// Gets whether scheduled refreshes are paused.
+    pub fn refresh_paused(&self) -> bool {
+        self.cached_settings.refresh_paused
+    }
+
+    /// Pauses or resumes scheduled refreshes.
+    pub fn set_refresh_paused(&mut self, paused: bool) {
+        self.cached_settings.refresh_paused = paused;
+        self.save_async();
+    }
+
+    /// Sets the global stale threshold, in seconds.
+    pub fn set_stale_after_secs(&mut self, secs: u64) {
+        self.cached_settings.stale_after_secs = secs;
+        self.save_async();
+    }
+
     /// Sets merge icons mode.
     pub fn set_merge_icons(&mut self, merge: bool) {
         self.cached_settings.merge_icons = merge;
@@ -230,6 +314,84 @@ impl SettingsModel {
         self.save_async();
     }
 
+    /// Gets what shows through behind the menu windows.
+    pub fn window_background(&self) -> exactobar_store::WindowBackground {
+        self.cached_settings.window_background
+    }
+
+    /// Sets what shows through behind the menu windows.
+    pub fn set_window_background(&mut self, background: exactobar_store::WindowBackground) {
+        self.cached_settings.window_background = background;
+        self.save_async();
+    }
+
+    /// Gets the macOS material of the blurred window background.
+    pub fn vibrancy_material(&self) -> exactobar_store::VibrancyMaterial {
+        self.cached_settings.vibrancy_material
+    }
+
+    /// Sets the macOS material of the blurred window background.
+    pub fn set_vibrancy_material(&mut self, material: exactobar_store::VibrancyMaterial) {
+        self.cached_settings.vibrancy_material = material;
+        self.save_async();
+    }
+
+    /// Gets the release channel checked for updates.
+    pub fn update_channel(&self) -> exactobar_store::UpdateChannel {
+        self.cached_settings.update_channel
+    }
+
+    /// Sets the release channel checked for updates.
+    pub fn set_update_channel(&mut self, channel: exactobar_store::UpdateChannel) {
+        self.cached_settings.update_channel = channel;
+        self.save_async();
+    }
+
+    /// Gets how HTTP requests reach the network.
+    pub fn proxy_mode(&self) -> exactobar_store::ProxyMode {
+        self.cached_settings.proxy_mode
+    }
+
+    /// Sets how HTTP requests reach the network.
+    pub fn set_proxy_mode(&mut self, mode: exactobar_store::ProxyMode) {
+        self.cached_settings.proxy_mode = mode;
+        self.save_async();
+    }
+
+    /// Gets the proxy HTTP clients should use.
+    pub fn proxy_config(&self) -> exactobar_fetch::ProxyConfig {
+        self.cached_settings.proxy_config()
+    }
+
+    /// Gets the TLS options HTTP clients should use.
+    pub fn tls_config(&self) -> exactobar_fetch::TlsConfig {
+        self.cached_settings.tls_config()
+    }
+
+    /// Sets whether the system certificate store is trusted.
+    pub fn set_system_certificates(&mut self, enabled: bool) {
+        self.cached_settings.system_certificates = enabled;
+        self.save_async();
+    }
+
+    /// Sets the requests sent to one host per minute.
+    pub fn set_requests_per_minute(&mut self, requests: u32) {
+        self.cached_settings.requests_per_minute = requests;
+        self.save_async();
+    }
+
+    /// Sets the version skipped in the update dialog.
+    pub fn set_skipped_update_version(&mut self, version: Option<String>) {
+        self.cached_settings.skipped_update_version = version;
+        self.save_async();
+    }
+
+    /// Sets the time before which updates aren't offered again.
+    pub fn set_update_reminder_after(&mut self, after: Option<chrono::DateTime<chrono::Utc>>) {
+        self.cached_settings.update_reminder_after = after;
+        self.save_async();
+    }
+
     /// Gets the underlying settings.
     pub fn settings(&self) -> &Settings {
         &self.cached_settings
@@ -245,12 +407,51 @@ impl SettingsModel {
         self.save_async();
     }
 
+    /// Gets the shortcut that toggles the menu.
+    pub fn global_hotkey(&self) -> Option<&str> {
+        self.cached_settings.global_hotkey.as_deref()
+    }
+
+    /// Sets the shortcut that toggles the menu.
+    pub fn set_global_hotkey(&mut self, hotkey: Option<String>) {
+        self.cached_settings.global_hotkey = hotkey;
+        self.save_async();
+    }
+
+    /// Gets the usage color breakpoints and hues.
+    pub fn usage_gradient(&self) -> UsageGradient {
+        self.cached_settings.usage_gradient
+    }
+
+    /// Sets the usage color breakpoints and hues.
+    pub fn set_usage_gradient(&mut self, gradient: UsageGradient) {
+        self.cached_settings.usage_gradient = gradient;
+        self.save_async();
+    }
+
+    /// Gets how long usage history is kept.
+    pub fn history_retention(&self) -> HistoryRetention {
+        self.cached_settings.history_retention
+    }
+
+    /// Sets how long usage history is kept.
+    pub fn set_history_retention(&mut self, retention: HistoryRetention) {
+        self.cached_settings.history_retention = retention;
+        self.save_async();
+    }
+
     /// Sets whether reset times show as absolute clock values.
     pub fn set_reset_times_show_absolute(&mut self, value: bool) {
         self.cached_settings.reset_times_show_absolute = value;
         self.save_async();
     }
 
+    /// Sets the clock format for reset times.
+    pub fn set_time_format(&mut self, format: exactobar_store::TimeFormat) {
+        self.cached_settings.time_format = format;
+        self.save_async();
+    }
+
     /// Sets whether menu bar shows brand icon with percent.
     pub fn set_menu_bar_shows_brand_icon_with_percent(&mut self, value: bool) {
         self.cached_settings.menu_bar_shows_brand_icon_with_percent = value;
@@ -263,6 +464,142 @@ impl SettingsModel {
         self.save_async();
     }
 
+    /// Gets the last selected provider tab (None = "All").
+    pub fn selected_provider(&self) -> Option<ProviderKind> {
+        self.cached_settings.selected_provider
+    }
+
+    /// Remembers the selected provider tab (None = "All").
+    pub fn set_selected_provider(&mut self, provider: Option<ProviderKind>) {
+        if self.cached_settings.selected_provider != provider {
+            self.cached_settings.selected_provider = provider;
+            self.save_async();
+        }
+    }
+
+    /// Gets whether menu cards are collapsed to a single line.
+    pub fn compact_menu(&self) -> bool {
+        self.cached_settings.compact_menu
+    }
+
+    /// Sets whether menu cards are collapsed to a single line.
+    pub fn set_compact_menu(&mut self, value: bool) {
+        self.cached_settings.compact_menu = value;
+        self.save_async();
+    }
+
+    /// Gets whether the menu's Quit button asks for confirmation.
+    pub fn confirm_quit(&self) -> bool {
+        self.cached_settings.confirm_quit
+    }
+
+    /// Sets whether the menu's Quit button asks for confirmation.
+    pub fn set_confirm_quit(&mut self, value: bool) {
+        self.cached_settings.confirm_quit = value;
+        self.save_async();
+    }
+
+    /// Gets whether the menu's Quit button needs Option (Alt) held.
+    pub fn quit_requires_modifier(&self) -> bool {
+        self.cached_settings.quit_requires_modifier
+    }
+
+    /// Sets whether the menu's Quit button needs Option (Alt) held.
+    pub fn set_quit_requires_modifier(&mut self, value: bool) {
+        self.cached_settings.quit_requires_modifier = value;
+        self.save_async();
+    }
+
+    /// Gets whether the totals card is shown atop the "All" view.
+    pub fn show_totals_card(&self) -> bool {
+        self.cached_settings.show_totals_card
+    }
+
+    /// Sets whether the totals card is shown atop the "All" view.
+    pub fn set_show_totals_card(&mut self, value: bool) {
+        self.cached_settings.show_totals_card = value;
+        self.save_async();
+    }
+
+    /// Gets whether the app shows a Dock icon.
+    pub fn show_dock_icon(&self) -> bool {
+        self.cached_settings.show_dock_icon
+    }
+
+    /// Sets whether the app shows a Dock icon.
+    pub fn set_show_dock_icon(&mut self, value: bool) {
+        self.cached_settings.show_dock_icon = value;
+        self.save_async();
+    }
+
+    /// Sets whether the MCP server is enabled (applies on next launch).
+    pub fn set_mcp_server_enabled(&mut self, value: bool) {
+        self.cached_settings.mcp_server_enabled = value;
+        self.save_async();
+    }
+
+    /// Sets whether the Prometheus metrics endpoint is enabled (applies on next launch).
+    pub fn set_metrics_enabled(&mut self, value: bool) {
+        self.cached_settings.metrics_enabled = value;
+        self.save_async();
+    }
+
+    /// Sets whether the WebSocket API is enabled (applies on next launch).
+    pub fn set_websocket_enabled(&mut self, value: bool) {
+        self.cached_settings.websocket_enabled = value;
+        self.save_async();
+    }
+
+    /// Gets whether the first-run tour has been completed.
+    pub fn tour_completed(&self) -> bool {
+        self.cached_settings.tour_completed
+    }
+
+    /// Sets whether the first-run tour has been completed.
+    pub fn set_tour_completed(&mut self, value: bool) {
+        self.cached_settings.tour_completed = value;
+        self.save_async();
+    }
+
+    /// Gets the last position of the detached panel window.
+    pub fn detached_panel_position(&self) -> Option<WindowPosition> {
+        self.cached_settings.detached_panel_position
+    }
+
+    /// Remembers the position of the detached panel window.
+    pub fn set_detached_panel_position(&mut self, position: WindowPosition) {
+        if self.cached_settings.detached_panel_position == Some(position) {
+            return;
+        }
+        self.cached_settings.detached_panel_position = Some(position);
+        self.save_debounced();
+    }
+
+    /// Gets the user-chosen menu panel size.
+    pub fn menu_panel_size(&self) -> Option<WindowSize> {
+        self.cached_settings.menu_panel_size
+    }
+
+    /// Remembers the menu panel size.
+    pub fn set_menu_panel_size(&mut self, size: WindowSize) {
+        if self.cached_settings.menu_panel_size == Some(size) {
+            return;
+        }
+        self.cached_settings.menu_panel_size = Some(size);
+        self.save_debounced();
+    }
+
+    /// Gets the currency code used to format spend.
+    pub fn currency_code(&self) -> &str {
+        &self.cached_settings.currency_code
+    }
+
+    /// Sets the currency code used to format spend.
+    pub fn set_currency_code(&mut self, code: String) {
+        self.cached_settings.currency_code = code;
+        self.save_async();
+    }
+
     // ========================================================================
     // Feature Toggles
     // ========================================================================
@@ -273,6 +610,17 @@ impl SettingsModel {
         self.save_async();
     }
 
+    /// Shows or hides the demo provider. Showing it also enables it.
+    pub fn set_demo_provider_enabled(&mut self, value: bool) {
+        self.cached_settings.demo_provider_enabled = value;
+        if value {
+            self.cached_settings
+                .enabled_providers
+                .insert(ProviderKind::Demo);
+        }
+        self.save_async();
+    }
+
     /// Sets auto-refresh on wake.
     pub fn set_auto_refresh_on_wake(&mut self, value: bool) {
         self.cached_settings.auto_refresh_on_wake = value;
@@ -308,6 +656,44 @@ impl SettingsModel {
         self.save_async();
     }
 
+    /// Gets whether icon animations are turned off in the settings.
+    ///
+    /// Use [`crate::accessibility::reduce_motion`], which also follows the
+    /// OS preference.
+    pub fn reduce_motion(&self) -> bool {
+        self.cached_settings.reduce_motion
+    }
+
+    /// Sets whether icon animations are turned off.
+    pub fn set_reduce_motion(&mut self, value: bool) {
+        self.cached_settings.reduce_motion = value;
+        self.save_async();
+    }
+
+    /// Gets whether refreshes and animations slow down to save power.
+    ///
+    /// Use [`crate::power::saving_power`], which also checks the power state.
+    pub fn power_saving_enabled(&self) -> bool {
+        self.cached_settings.power_saving_enabled
+    }
+
+    /// Sets whether refreshes and animations slow down to save power.
+    pub fn set_power_saving_enabled(&mut self, value: bool) {
+        self.cached_settings.power_saving_enabled = value;
+        self.save_async();
+    }
+
+    /// Gets whether the app starts without opening windows.
+    pub fn start_silently(&self) -> bool {
+        self.cached_settings.start_silently
+    }
+
+    /// Sets whether the app starts without opening windows.
+    pub fn set_start_silently(&mut self, value: bool) {
+        self.cached_settings.start_silently = value;
+        self.save_async();
+    }
+
     /// Sets whether Claude web extras are enabled.
     pub fn set_claude_web_extras_enabled(&mut self, value: bool) {
         self.cached_settings.claude_web_extras_enabled = value;
@@ -349,6 +735,96 @@ impl SettingsModel {
         self.save_async();
     }
 
+    /// Gets the monthly spend budget for a provider.
+    pub fn monthly_budget(&self, provider: ProviderKind) -> Option<f64> {
+        self.cached_settings
+            .provider_settings
+            .get(&provider)
+            .and_then(|ps| ps.monthly_budget)
+    }
+
+    /// Sets the monthly spend budget for a provider.
+    pub fn set_monthly_budget(&mut self, provider: ProviderKind, budget: Option<f64>) {
+        self.cached_settings
+            .provider_settings
+            .entry(provider)
+            .or_default()
+            .monthly_budget = budget;
+        self.save_async();
+    }
+
+    /// Gets the stale threshold override for a provider, in seconds.
+    pub fn provider_stale_after_secs(&self, provider: ProviderKind) -> Option<u64> {
+        self.cached_settings
+            .provider_settings
+            .get(&provider)
+            .and_then(|ps| ps.stale_after_secs)
+    }
+
+    /// Sets the stale threshold for a provider (None = the global threshold).
+    pub fn set_provider_stale_after_secs(&mut self, provider: ProviderKind, secs: Option<u64>) {
+        self.cached_settings
+            .provider_settings
+            .entry(provider)
+            .or_default()
+            .stale_after_secs = secs;
+        self.save_async();
+    }
+
+    /// Gets the custom quick-action buttons for a provider (None = defaults).
+    pub fn quick_actions(&self, provider: ProviderKind) -> Option<&[QuickAction]> {
+        self.cached_settings
+            .provider_settings
+            .get(&provider)
+            .and_then(|ps| ps.quick_actions.as_deref())
+    }
+
+    /// Sets the custom quick-action buttons for a provider (None = defaults).
+    pub fn set_quick_actions(&mut self, provider: ProviderKind, actions: Option<Vec<QuickAction>>) {
+        self.cached_settings
+            .provider_settings
+            .entry(provider)
+            .or_default()
+            .quick_actions = actions;
+        self.save_async();
+    }
+
+    /// Gets the brand color override for a provider, as a hex string.
+    pub fn provider_color(&self, provider: ProviderKind) -> Option<&str> {
+        self.cached_settings
+            .provider_settings
+            .get(&provider)
+            .and_then(|ps| ps.color.as_deref())
+    }
+
+    /// Gets all brand color overrides, as hex strings.
+    pub fn provider_colors(&self) -> HashMap<ProviderKind, String> {
+        self.cached_settings
+            .provider_settings
+            .iter()
+            .filter_map(|(&provider, ps)| Some((provider, ps.color.clone()?)))
+            .collect()
+    }
+
+    /// Gets all API base URL overrides.
+    pub fn base_urls(&self) -> HashMap<ProviderKind, String> {
+        self.cached_settings
+            .provider_settings
+            .iter()
+            .filter_map(|(&provider, ps)| Some((provider, ps.base_url.clone()?)))
+            .collect()
+    }
+
+    /// Sets the brand color override for a provider (None = the built-in color).
+    pub fn set_provider_color(&mut self, provider: ProviderKind, color: Option<String>) {
+        self.cached_settings
+            .provider_settings
+            .entry(provider)
+            .or_default()
+            .color = color;
+        self.save_async();
+    }
+
     /// Gets the data source mode for Codex.
     pub fn codex_data_source(&self) -> DataSourceMode {
         self.cached_settings.codex_usage_data_source
@@ -372,27 +848,43 @@ impl SettingsModel {
     }
 
     fn save_async(&self) {
-        let store = self.store.clone();
-        let settings = self.cached_settings.clone();
+        // Any pending debounced save holds older settings
+        self.save_generation.fetch_add(1, Ordering::Relaxed);
+        smol::spawn(Self::save(self.store.clone(), self.cached_settings.clone())).detach();
+    }
+
+    /// Saves once the calls stop for [`WINDOW_SAVE_DELAY`], for settings
+    /// that change with every event of a window drag or resize.
+    fn save_debounced(&self) {
+        let generation = self.save_generation.fetch_add(1, Ordering::Relaxed) + 1;
+        let latest = self.save_generation.clone();
+        let (store, settings) = (self.store.clone(), self.cached_settings.clone());
 
+        smol::spawn(async move {
+            smol::Timer::after(WINDOW_SAVE_DELAY).await;
+            if latest.load(Ordering::Relaxed) == generation {
+                Self::save(store, settings).await;
+            }
+        })
+        .detach();
+    }
+
+    async fn save(store: Arc<RwLock<SettingsStore>>, settings: Settings) {
         // Bridge from smol (GPUI's runtime) to tokio (our store's runtime)
         // Direct tokio::spawn() will panic - there's no Tokio runtime running!
-        smol::spawn(async move {
-            smol::unblock(move || {
-                tokio_runtime().block_on(async move {
-                    let s = store.write().await;
-                    s.update(|current| {
-                        *current = settings;
-                    })
-                    .await;
-                    if let Err(e) = s.save().await {
-                        error!(error = %e, "Failed to save settings");
-                    }
+        smol::unblock(move || {
+            tokio_runtime().block_on(async move {
+                let s = store.write().await;
+                s.update(|current| {
+                    *current = settings;
                 })
+                .await;
+                if let Err(e) = s.save().await {
+                    error!(error = %e, "Failed to save settings");
+                }
             })
-            .await;
         })
-        .detach();
+        .await;
     }
 }
 
@@ -400,13 +892,58 @@ impl SettingsModel {
 // Usage Model
 // ============================================================================
 
+/// Failed fetches in a row before a provider's scheduled refreshes stop.
+const PAUSE_AFTER_FAILURES: u32 = 10;
+
+/// How long a provider has to keep failing before it's paused, so a short
+/// outage doesn't pause it.
+const PAUSE_AFTER_FAILING_FOR: Duration = Duration::from_secs(3 * 60 * 60);
+
+/// Failed fetches since a provider's last successful one.
+#[derive(Debug, Clone, Copy)]
+struct FailureStreak {
+    count: u32,
+    since: chrono::DateTime<chrono::Utc>,
+}
+
+impl FailureStreak {
+    /// Checks if the provider has failed often enough, for long enough, to
+    /// stop refreshing it.
+    fn should_pause(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.count >= PAUSE_AFTER_FAILURES
+            && (now - self.since)
+                .to_std()
+                .is_ok_and(|failing_for| failing_for >= PAUSE_AFTER_FAILING_FOR)
+    }
+}
+
 /// Model wrapping usage data for GPUI.
 #[allow(dead_code)]
 pub struct UsageModel {
     snapshots: std::collections::HashMap<ProviderKind, UsageSnapshot>,
     status: std::collections::HashMap<ProviderKind, ProviderStatus>,
     errors: std::collections::HashMap<ProviderKind, String>,
+    /// Why each provider's last fetch failed, until one succeeds.
+    failures: std::collections::HashMap<ProviderKind, ProviderError>,
+    /// Failed fetches in a row per provider, until one succeeds.
+    failure_streaks: std::collections::HashMap<ProviderKind, FailureStreak>,
+    /// Providers whose scheduled refreshes stopped after repeated errors.
+    paused: HashSet<ProviderKind>,
+    /// Last raw provider response per provider (for the debug view).
+    raw_responses: std::collections::HashMap<ProviderKind, String>,
+    /// How long the last fetch took per provider (for metrics).
+    fetch_durations: std::collections::HashMap<ProviderKind, Duration>,
+    /// Tokens used per project today, from local session logs.
+    projects: std::collections::HashMap<ProviderKind, Vec<ProjectUsage>>,
+    /// The Claude Code session being written to, from its session log.
+    live_session: Option<LiveSession>,
     refreshing: HashSet<ProviderKind>,
+    history: UsageHistory,
+    /// History entries recorded since the history file was last saved.
+    unsaved_history: Vec<(ProviderKind, HistoryEntry)>,
+    /// Bumped whenever a provider's shown state changes, so views can skip
+    /// updates for other providers.
+    revisions: std::collections::HashMap<ProviderKind, u64>,
 }
 
 impl UsageModel {
@@ -415,16 +952,126 @@ impl UsageModel {
             snapshots: std::collections::HashMap::new(),
             status: std::collections::HashMap::new(),
             errors: std::collections::HashMap::new(),
+            failures: std::collections::HashMap::new(),
+            failure_streaks: std::collections::HashMap::new(),
+            paused: HashSet::new(),
+            raw_responses: std::collections::HashMap::new(),
+            fetch_durations: std::collections::HashMap::new(),
+            projects: std::collections::HashMap::new(),
+            live_session: None,
             refreshing: HashSet::new(),
+            history: UsageHistory::new(),
+            unsaved_history: Vec::new(),
+            revisions: std::collections::HashMap::new(),
         }
     }
 
+    /// Returns a counter that changes whenever the provider's snapshot,
+    /// status, error, raw response, projects or refreshing state does. Setting a value
+    /// equal to the current one leaves it alone.
+    pub fn revision(&self, provider: ProviderKind) -> u64 {
+        self.revisions.get(&provider).copied().unwrap_or(0)
+    }
+
+    /// Runs `f`, notifying observers only if it changed the provider's state,
+    /// so a refresh that found nothing new doesn't redraw the tray and panel.
+    pub fn update_provider<R>(
+        &mut self,
+        provider: ProviderKind,
+        cx: &mut Context<Self>,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let revision = self.revision(provider);
+        let result = f(self);
+        if self.revision(provider) != revision {
+            cx.notify();
+        }
+        result
+    }
+
+    fn bump_revision(&mut self, provider: ProviderKind) {
+        *self.revisions.entry(provider).or_insert(0) += 1;
+    }
+
     pub fn get_snapshot(&self, provider: ProviderKind) -> Option<UsageSnapshot> {
         self.snapshots.get(&provider).cloned()
     }
 
-    pub fn set_snapshot(&mut self, provider: ProviderKind, snapshot: UsageSnapshot) {
+    /// Stores a fetched snapshot. `stale_after` is the provider's stale
+    /// threshold from the settings.
+    pub fn set_snapshot(
+        &mut self,
+        provider: ProviderKind,
+        snapshot: UsageSnapshot,
+        stale_after: Duration,
+    ) {
+        self.history.record(provider, &snapshot);
+        self.unsaved_history
+            .push((provider, HistoryEntry::from(&snapshot)));
+        // A refresh that found the same usage only moves the timestamp, which
+        // matters to views once the old snapshot had gone stale
+        let changed = self.snapshots.get(&provider).is_none_or(|old| {
+            !old.same_usage(&snapshot)
+                || (snapshot.updated_at - old.updated_at)
+                    .to_std()
+                    .is_ok_and(|age| age > stale_after)
+        });
         self.snapshots.insert(provider, snapshot);
+        if changed {
+            self.bump_revision(provider);
+        }
+    }
+
+    /// Replaces the provider's snapshot without recording it in the usage
+    /// history, for the state simulator.
+    pub fn force_snapshot(&mut self, provider: ProviderKind, snapshot: UsageSnapshot) {
+        self.snapshots.insert(provider, snapshot);
+        self.bump_revision(provider);
+    }
+
+    /// Returns when the provider's snapshot was last refreshed, which can be
+    /// newer than the snapshot views were last notified about.
+    pub fn updated_at(&self, provider: ProviderKind) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.snapshots.get(&provider).map(|s| s.updated_at)
+    }
+
+    pub fn get_trajectory(&self, provider: ProviderKind, window: WindowKind) -> Vec<f64> {
+        self.history.trajectory_since_reset(provider, window)
+    }
+
+    pub fn get_time_until_limit(
+        &self,
+        provider: ProviderKind,
+        window: WindowKind,
+    ) -> Option<chrono::Duration> {
+        self.history
+            .time_until_limit(provider, window, chrono::Utc::now())
+    }
+
+    pub fn history(&self) -> &UsageHistory {
+        &self.history
+    }
+
+    /// Adds history loaded from disk before the entries recorded since
+    /// launch.
+    pub fn merge_history(&mut self, older: UsageHistory) {
+        self.history.merge_older(older);
+    }
+
+    /// Takes the history entries recorded since the last call, for saving.
+    pub fn take_unsaved_history(&mut self) -> Vec<(ProviderKind, HistoryEntry)> {
+        std::mem::take(&mut self.unsaved_history)
+    }
+
+    /// Drops history entries recorded before `cutoff`.
+    pub fn prune_history(&mut self, cutoff: chrono::DateTime<chrono::Utc>) {
+        self.history.prune(cutoff);
+    }
+
+    /// Forgets all recorded history.
+    pub fn clear_history(&mut self) {
+        self.history.clear_all();
+        self.unsaved_history.clear();
     }
 
     pub fn get_status(&self, provider: ProviderKind) -> Option<ProviderStatus> {
@@ -432,7 +1079,16 @@ impl UsageModel {
     }
 
     pub fn set_status(&mut self, provider: ProviderKind, status: ProviderStatus) {
+        let changed = self.status.get(&provider).is_none_or(|old| {
+            old.indicator != status.indicator
+                || old.description != status.description
+                || old.url != status.url
+                || old.incidents != status.incidents
+        });
         self.status.insert(provider, status);
+        if changed {
+            self.bump_revision(provider);
+        }
     }
 
     pub fn get_error(&self, provider: ProviderKind) -> Option<String> {
@@ -440,11 +1096,160 @@ impl UsageModel {
     }
 
     pub fn set_error(&mut self, provider: ProviderKind, error: String) {
-        self.errors.insert(provider, error);
+        if self.errors.get(&provider) != Some(&error) {
+            self.errors.insert(provider, error);
+            self.bump_revision(provider);
+        }
     }
 
     pub fn clear_error(&mut self, provider: ProviderKind) {
-        self.errors.remove(&provider);
+        let had_failure = self.failures.remove(&provider).is_some();
+        self.failure_streaks.remove(&provider);
+        let was_paused = self.paused.remove(&provider);
+        if self.errors.remove(&provider).is_some() || had_failure || was_paused {
+            self.bump_revision(provider);
+        }
+    }
+
+    /// Records a failed fetch, remembering what kind of failure it was
+    /// until a fetch succeeds.
+    ///
+    /// Returns true if the failure paused the provider, after
+    /// [`PAUSE_AFTER_FAILURES`] failures in a row over at least
+    /// [`PAUSE_AFTER_FAILING_FOR`].
+    pub fn set_failure(&mut self, provider: ProviderKind, failure: FetchFailure) -> bool {
+        let paused = self.count_failure(provider, &failure.error, chrono::Utc::now());
+        if self.failures.get(&provider) != Some(&failure.error) {
+            self.failures.insert(provider, failure.error);
+            self.bump_revision(provider);
+        }
+        self.set_error(provider, failure.message);
+        paused
+    }
+
+    /// Adds a failure to the provider's streak, pausing it once the streak
+    /// is long enough. Returns true if the provider was just paused.
+    fn count_failure(
+        &mut self,
+        provider: ProviderKind,
+        error: &ProviderError,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> bool {
+        // Rejected credentials already stop scheduled refreshes
+        if error.is_auth() {
+            return false;
+        }
+        let streak = self
+            .failure_streaks
+            .entry(provider)
+            .or_insert(FailureStreak {
+                count: 0,
+                since: now,
+            });
+        streak.count += 1;
+        let failures = streak.count;
+        if !streak.should_pause(now) || !self.paused.insert(provider) {
+            return false;
+        }
+        warn!(
+            provider = ?provider,
+            failures,
+            "Pausing refreshes after repeated errors"
+        );
+        self.bump_revision(provider);
+        true
+    }
+
+    /// Checks if the provider's scheduled refreshes stopped after repeated
+    /// errors.
+    pub fn is_paused(&self, provider: ProviderKind) -> bool {
+        self.paused.contains(&provider)
+    }
+
+    /// Resumes scheduled refreshes of a paused provider, starting its
+    /// failure count over.
+    pub fn resume(&mut self, provider: ProviderKind) {
+        self.failure_streaks.remove(&provider);
+        if self.paused.remove(&provider) {
+            self.bump_revision(provider);
+        }
+    }
+
+    /// Returns what kind of failure the provider's last fetch was, if it
+    /// failed in this session.
+    pub fn failure(&self, provider: ProviderKind) -> Option<&ProviderError> {
+        self.failures.get(&provider)
+    }
+
+    /// Checks if the provider's credentials were rejected, so it needs to
+    /// sign in again before refreshing can work.
+    pub fn needs_auth(&self, provider: ProviderKind) -> bool {
+        self.failures
+            .get(&provider)
+            .is_some_and(ProviderError::is_auth)
+    }
+
+    /// Copies the latest snapshots and errors for the on-disk cache.
+    pub fn usage_cache(&self) -> UsageCache {
+        UsageCache {
+            snapshots: self.snapshots.clone(),
+            errors: self.errors.clone(),
+            saved_at: None,
+        }
+    }
+
+    pub fn get_fetch_duration(&self, provider: ProviderKind) -> Option<Duration> {
+        self.fetch_durations.get(&provider).copied()
+    }
+
+    pub fn set_fetch_duration(&mut self, provider: ProviderKind, duration: Duration) {
+        self.fetch_durations.insert(provider, duration);
+    }
+
+    pub fn get_raw_response(&self, provider: ProviderKind) -> Option<String> {
+        self.raw_responses.get(&provider).cloned()
+    }
+
+    pub fn set_raw_response(&mut self, provider: ProviderKind, raw: Option<String>) {
+        if self.raw_responses.get(&provider) == raw.as_ref() {
+            return;
+        }
+        match raw {
+            Some(raw) => {
+                self.raw_responses.insert(provider, raw);
+            }
+            None => {
+                self.raw_responses.remove(&provider);
+            }
+        }
+        self.bump_revision(provider);
+    }
+
+    pub fn get_projects(&self, provider: ProviderKind) -> Vec<ProjectUsage> {
+        self.projects.get(&provider).cloned().unwrap_or_default()
+    }
+
+    pub fn set_projects(&mut self, provider: ProviderKind, projects: Vec<ProjectUsage>) {
+        if self.projects.get(&provider).map_or(&[][..], Vec::as_slice) == projects.as_slice() {
+            return;
+        }
+        if projects.is_empty() {
+            self.projects.remove(&provider);
+        } else {
+            self.projects.insert(provider, projects);
+        }
+        self.bump_revision(provider);
+    }
+
+    pub fn get_live_session(&self) -> Option<LiveSession> {
+        self.live_session.clone()
+    }
+
+    pub fn set_live_session(&mut self, session: Option<LiveSession>) {
+        if self.live_session != session {
+            self.live_session = session;
+            self.bump_revision(ProviderKind::Claude);
+        }
     }
 
     pub fn is_refreshing(&self, provider: ProviderKind) -> bool {
@@ -452,10 +1257,13 @@ impl UsageModel {
     }
 
     pub fn set_refreshing(&mut self, provider: ProviderKind, refreshing: bool) {
-        if refreshing {
-            self.refreshing.insert(provider);
+        let changed = if refreshing {
+            self.refreshing.insert(provider)
         } else {
-            self.refreshing.remove(&provider);
+            self.refreshing.remove(&provider)
+        };
+        if changed {
+            self.bump_revision(provider);
         }
     }
 }
@@ -465,3 +1273,54 @@ impl Default for UsageModel {
         Self::new()
     }
 }
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_pause_after_repeated_failures() {
+        let mut model = UsageModel::new();
+        let provider = ProviderKind::Codex;
+        let error = ProviderError::NotInstalled("codex".to_string());
+        let start = Utc.with_ymd_and_hms(2026, 3, 3, 12, 0, 0).unwrap();
+        let minutes = |m: i64| start + chrono::Duration::minutes(m);
+
+        // Many failures in a short time don't pause
+        for i in 0..i64::from(PAUSE_AFTER_FAILURES) {
+            assert!(!model.count_failure(provider, &error, minutes(i)));
+        }
+        assert!(!model.is_paused(provider));
+
+        // Still failing hours later does, once
+        assert!(model.count_failure(provider, &error, minutes(180)));
+        assert!(model.is_paused(provider));
+        assert!(!model.count_failure(provider, &error, minutes(185)));
+
+        // Resuming starts the count over
+        model.resume(provider);
+        assert!(!model.is_paused(provider));
+        assert!(!model.count_failure(provider, &error, minutes(190)));
+
+        // A success does too
+        model.clear_error(provider);
+        assert!(model.failure_streaks.is_empty());
+    }
+
+    #[test]
+    fn test_auth_failures_dont_pause() {
+        let mut model = UsageModel::new();
+        let error = ProviderError::NotLoggedIn("Token expired".to_string());
+        let start = Utc.with_ymd_and_hms(2026, 3, 3, 12, 0, 0).unwrap();
+        for hour in 0..24 {
+            let now = start + chrono::Duration::hours(hour);
+            assert!(!model.count_failure(ProviderKind::Claude, &error, now));
+        }
+        assert!(!model.is_paused(ProviderKind::Claude));
+    }
+}
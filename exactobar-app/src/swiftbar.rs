@@ -0,0 +1,159 @@
+//! SwiftBar/xbar plugin export.
+//!
+//! When a plugin path is set, rewrites a plugin script there after every
+//! refresh. The script prints the cached usage in the SwiftBar/xbar output
+//! format, so it works in either app when saved to its plugin folder (e.g.
+//! `exactobar.1m.sh`); both rerun plugins whose file changes.
+
+use chrono::{DateTime, Utc};
+use exactobar_core::ProviderKind;
+use exactobar_store::UsageCache;
+use std::io;
+use std::path::Path;
+
+use crate::menu::{format_countdown, format_elapsed};
+use crate::notifications::NotificationLevel;
+use crate::server::ProviderUsage;
+
+/// Ends the heredoc holding the plugin output.
+const HEREDOC_END: &str = "EXACTOBAR_OUTPUT";
+
+/// Writes the plugin script for `providers` to `path`.
+pub fn write_plugin(
+    path: &Path,
+    providers: &[ProviderKind],
+    cache: &UsageCache,
+    now: DateTime<Utc>,
+) -> io::Result<()> {
+    let usage: Vec<ProviderUsage> = providers
+        .iter()
+        .map(|&p| ProviderUsage::new(p, cache.snapshots.get(&p), cache.errors.get(&p).cloned()))
+        .collect();
+    let script = format!(
+        "#!/bin/sh\n\
+         # <xbar.title>ExactoBar</xbar.title>\n\
+         # <xbar.desc>LLM usage from ExactoBar</xbar.desc>\n\
+         # Generated by ExactoBar after every refresh; edits will be overwritten.\n\
+         cat <<'{end}'\n{}{end}\n",
+        render(&usage, now),
+        end = HEREDOC_END,
+    );
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    // Write atomically so the plugin host never runs a partial script
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, script)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&temp_path, std::fs::Permissions::from_mode(0o755))?;
+    }
+    std::fs::rename(&temp_path, path)
+}
+
+/// Renders usage in the SwiftBar/xbar output format.
+///
+/// The first line is the menu bar title; the rest is the dropdown.
+pub fn render(usage: &[ProviderUsage], now: DateTime<Utc>) -> String {
+    let title: Vec<String> = usage
+        .iter()
+        .filter_map(|u| {
+            let w = u.windows.first()?;
+            Some(format!("{} {:.0}%", u.name, w.used_percent))
+        })
+        .collect();
+
+    let mut out = String::new();
+    if title.is_empty() {
+        out.push_str("ExactoBar\n");
+    } else {
+        out.push_str(&format!("{}\n", title.join(" · ")));
+    }
+
+    for provider in usage {
+        out.push_str("---\n");
+        out.push_str(&format!("{}\n", sanitize(&provider.name)));
+        for w in &provider.windows {
+            let mut line = format!("{}: {:.0}% used", w.label, w.used_percent);
+            if let Some(countdown) = w.resets_at.and_then(|at| format_countdown(at, now)) {
+                line.push_str(&format!(", resets in {}", countdown));
+            }
+            let params = match NotificationLevel::for_percent(w.used_percent) {
+                NotificationLevel::Critical => " | color=red",
+                NotificationLevel::Warning => " | color=orange",
+                NotificationLevel::None => "",
+            };
+            out.push_str(&format!("{}{}\n", sanitize(&line), params));
+        }
+        if let Some(error) = &provider.error {
+            let summary = error.lines().next().unwrap_or(error);
+            out.push_str(&format!("{} | color=red\n", sanitize(summary)));
+        } else if let Some(updated_at) = provider.updated_at {
+            out.push_str(&format!(
+                "Updated {} | size=11\n",
+                format_elapsed(updated_at, now)
+            ));
+        }
+    }
+
+    out.push_str("---\n");
+    out.push_str("Refresh | refresh=true\n");
+    out
+}
+
+/// Keeps text from being read as plugin parameters or ending the script.
+fn sanitize(text: &str) -> String {
+    text.replace('|', "/").replace(HEREDOC_END, "")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exactobar_core::{UsageSnapshot, UsageWindow};
+
+    #[test]
+    fn test_render() {
+        let now = Utc::now();
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(UsageWindow::new(85.0));
+        snapshot.secondary = Some(UsageWindow::new(10.0));
+        snapshot.updated_at = now;
+
+        let usage = [
+            ProviderUsage::new(ProviderKind::Claude, Some(&snapshot), None),
+            ProviderUsage::new(ProviderKind::Codex, None, Some("HTTP 500 | retry".into())),
+        ];
+        let text = render(&usage, now);
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "Claude 85%");
+        assert!(lines.contains(&"Session: 85% used | color=orange"));
+        assert!(lines.contains(&"HTTP 500 / retry | color=red"));
+        assert_eq!(lines.last(), Some(&"Refresh | refresh=true"));
+    }
+
+    #[test]
+    fn test_write_plugin() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("exactobar.1m.sh");
+
+        write_plugin(
+            &path,
+            &[ProviderKind::Claude],
+            &UsageCache::default(),
+            Utc::now(),
+        )
+        .unwrap();
+
+        let script = std::fs::read_to_string(&path).unwrap();
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains("cat <<'EXACTOBAR_OUTPUT'\nExactoBar\n---\nClaude\n"));
+        assert!(script.ends_with("EXACTOBAR_OUTPUT\n"));
+    }
+}
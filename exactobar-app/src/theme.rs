@@ -9,55 +9,248 @@ use exactobar_core::ProviderKind;
 use gpui::*;
 use std::collections::HashMap;
 
+use crate::custom_theme::{self, CustomTheme, HexColor};
+use crate::notifications;
+use crate::state::AppState;
+use crate::tray::SystemTray;
+
 // ============================================================================
 // Theme Mode
 // ============================================================================
 
-use exactobar_store::ThemeMode;
+use exactobar_store::{ThemeMode, UsageGradient, WindowBackground};
 use gpui::WindowAppearance;
-use std::sync::OnceLock;
-use std::sync::atomic::{AtomicBool, Ordering};
+use once_cell::sync::Lazy;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::Duration;
 
 /// Gets the current theme based on mode and system appearance.
 pub fn current_theme(mode: ThemeMode, appearance: WindowAppearance) -> ExactoBarTheme {
-    match mode {
-        ThemeMode::Dark => ExactoBarTheme::dark(),
-        ThemeMode::Light => ExactoBarTheme::light(),
-        ThemeMode::System => {
-            // Check system appearance
-            let is_dark = matches!(
-                appearance,
-                WindowAppearance::Dark | WindowAppearance::VibrantDark
-            );
-            if is_dark {
-                ExactoBarTheme::dark()
-            } else {
-                ExactoBarTheme::light()
+    match Palette::for_mode(mode, appearance) {
+        Palette::Dark => ExactoBarTheme::dark(),
+        Palette::Light => ExactoBarTheme::light(),
+        Palette::HighContrast => ExactoBarTheme::high_contrast(),
+    }
+}
+
+/// Set of colors the menu is drawn with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum Palette {
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl Palette {
+    fn for_mode(mode: ThemeMode, appearance: WindowAppearance) -> Self {
+        match mode {
+            ThemeMode::Dark => Palette::Dark,
+            ThemeMode::Light => Palette::Light,
+            ThemeMode::HighContrast => Palette::HighContrast,
+            ThemeMode::System => {
+                if matches!(
+                    appearance,
+                    WindowAppearance::Dark | WindowAppearance::VibrantDark
+                ) {
+                    Palette::Dark
+                } else {
+                    Palette::Light
+                }
             }
         }
     }
 }
 
-static CURRENT_DARK_MODE: OnceLock<AtomicBool> = OnceLock::new();
+static CURRENT_PALETTE: AtomicU8 = AtomicU8::new(Palette::Dark as u8);
 
-fn current_dark_mode() -> bool {
-    CURRENT_DARK_MODE
-        .get_or_init(|| AtomicBool::new(true))
-        .load(Ordering::Relaxed)
+fn current_palette() -> Palette {
+    match CURRENT_PALETTE.load(Ordering::Relaxed) {
+        x if x == Palette::Light as u8 => Palette::Light,
+        x if x == Palette::HighContrast as u8 => Palette::HighContrast,
+        _ => Palette::Dark,
+    }
 }
 
 pub fn set_current_theme_mode(mode: ThemeMode, appearance: WindowAppearance) {
-    let is_dark = match mode {
-        ThemeMode::Dark => true,
-        ThemeMode::Light => false,
-        ThemeMode::System => matches!(
-            appearance,
-            WindowAppearance::Dark | WindowAppearance::VibrantDark
-        ),
+    CURRENT_PALETTE.store(Palette::for_mode(mode, appearance) as u8, Ordering::Relaxed);
+}
+
+/// Redraws a window as soon as the system switches between light and dark,
+/// instead of it keeping the old colors until it's reopened.
+pub fn follow_system_appearance<V: 'static>(window: &mut Window, cx: &mut Context<V>) {
+    cx.observe_window_appearance(window, |_, window, cx| {
+        let mode = cx.global::<AppState>().settings.read(cx).theme_mode();
+        set_current_theme_mode(mode, window.appearance());
+        window.refresh();
+    })
+    .detach();
+}
+
+/// Whether the high-contrast theme is active.
+pub fn high_contrast() -> bool {
+    current_palette() == Palette::HighContrast
+}
+
+/// Window background for the menu windows: the user's choice, or opaque for
+/// the high-contrast theme.
+pub fn window_background_appearance(
+    mode: ThemeMode,
+    background: WindowBackground,
+) -> WindowBackgroundAppearance {
+    if mode == ThemeMode::HighContrast {
+        return WindowBackgroundAppearance::Opaque;
+    }
+    match background {
+        WindowBackground::Blurred => WindowBackgroundAppearance::Blurred,
+        WindowBackground::Translucent => WindowBackgroundAppearance::Transparent,
+        WindowBackground::Opaque => WindowBackgroundAppearance::Opaque,
+    }
+}
+
+/// Draws a blurred window's background with the chosen material.
+///
+/// GPUI blurs behind the window with an `NSVisualEffectView` under its
+/// content view; this sets that view's material. Windows without blur have
+/// no such view and are left alone.
+#[cfg(target_os = "macos")]
+pub fn apply_vibrancy_material(window: &Window, material: exactobar_store::VibrancyMaterial) {
+    use cocoa::base::{YES, id, nil};
+    use exactobar_store::VibrancyMaterial;
+    use objc::runtime::BOOL;
+    use objc::{class, msg_send, sel, sel_impl};
+    use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+    // NSVisualEffectMaterial values
+    let value: isize = match material {
+        VibrancyMaterial::Standard => return,
+        VibrancyMaterial::Menu => 5,
+        VibrancyMaterial::Popover => 6,
+        VibrancyMaterial::Sidebar => 7,
+        VibrancyMaterial::WindowBackground => 12,
+        VibrancyMaterial::HudWindow => 13,
+    };
+
+    let Ok(handle) = window.window_handle() else {
+        return;
+    };
+    let RawWindowHandle::AppKit(handle) = handle.as_raw() else {
+        return;
     };
-    CURRENT_DARK_MODE
-        .get_or_init(|| AtomicBool::new(true))
-        .store(is_dark, Ordering::Relaxed);
+
+    unsafe {
+        let view = handle.ns_view.as_ptr() as id;
+        let ns_window: id = msg_send![view, window];
+        if ns_window == nil {
+            return;
+        }
+        let content_view: id = msg_send![ns_window, contentView];
+        let subviews: id = msg_send![content_view, subviews];
+        let count: usize = msg_send![subviews, count];
+        for index in 0..count {
+            let subview: id = msg_send![subviews, objectAtIndex: index];
+            let is_effect_view: BOOL =
+                msg_send![subview, isKindOfClass: class!(NSVisualEffectView)];
+            if is_effect_view == YES {
+                let _: () = msg_send![subview, setMaterial: value];
+            }
+        }
+    }
+}
+
+/// Returns the theme file's override for a color, if it sets one.
+///
+/// The high-contrast theme keeps its own colors.
+fn custom(color: impl FnOnce(&CustomTheme) -> Option<HexColor>) -> Option<Hsla> {
+    if high_contrast() {
+        return None;
+    }
+    custom_theme::lookup(color).map(Hsla::from)
+}
+
+// ============================================================================
+// System Accent Color
+// ============================================================================
+
+/// How often the system accent color is checked for changes.
+const ACCENT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The accent color picked in System Settings, where there is one.
+static SYSTEM_ACCENT: RwLock<Option<Hsla>> = RwLock::new(None);
+
+fn system_accent() -> Option<Hsla> {
+    *SYSTEM_ACCENT.read().ok()?
+}
+
+/// Follows the system accent color, redrawing open windows when the user
+/// changes it.
+pub fn watch_system_accent(cx: &mut App) {
+    update_system_accent();
+    // Only macOS has an accent color to follow
+    if !cfg!(target_os = "macos") {
+        return;
+    }
+
+    cx.spawn(async move |cx| {
+        loop {
+            smol::Timer::after(ACCENT_POLL_INTERVAL).await;
+            // AppKit colors are read on the main thread
+            cx.update(|cx| {
+                if update_system_accent() {
+                    cx.refresh_windows();
+                }
+            });
+        }
+    })
+    .detach();
+}
+
+/// Reads the system accent color again, returning whether it changed.
+fn update_system_accent() -> bool {
+    let accent = read_system_accent();
+    let Ok(mut current) = SYSTEM_ACCENT.write() else {
+        return false;
+    };
+    let changed = *current != accent;
+    *current = accent;
+    changed
+}
+
+#[cfg(target_os = "macos")]
+fn read_system_accent() -> Option<Hsla> {
+    use cocoa::base::{id, nil};
+    use objc::{class, msg_send, sel, sel_impl};
+
+    unsafe {
+        let color: id = msg_send![class!(NSColor), controlAccentColor];
+        if color == nil {
+            return None;
+        }
+        // Named colors have no components until converted to a color space
+        let color_space: id = msg_send![class!(NSColorSpace), sRGBColorSpace];
+        let rgb: id = msg_send![color, colorUsingColorSpace: color_space];
+        if rgb == nil {
+            return None;
+        }
+        let r: f64 = msg_send![rgb, redComponent];
+        let g: f64 = msg_send![rgb, greenComponent];
+        let b: f64 = msg_send![rgb, blueComponent];
+        Some(
+            Rgba {
+                r: r as f32,
+                g: g as f32,
+                b: b as f32,
+                a: 1.0,
+            }
+            .into(),
+        )
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn read_system_accent() -> Option<Hsla> {
+    None
 }
 
 // ============================================================================
@@ -163,187 +356,339 @@ pub fn surface_light() -> Hsla {
     hsla(0.0, 0.0, 0.95, 0.95) // Light grey surface with higher opacity
 }
 
+// ============================================================================
+// High Contrast Colors
+// ============================================================================
+//
+// Opaque colors only, with text at WCAG AA contrast (4.5:1) or better
+// against the background.
+
+/// Surface/background color for menu panels (high contrast).
+pub fn surface_background_high_contrast() -> Hsla {
+    hsla(0.0, 0.0, 0.0, 1.0) // Black
+}
+
+/// Primary text color (high contrast).
+pub fn text_primary_high_contrast() -> Hsla {
+    hsla(0.0, 0.0, 1.0, 1.0) // White
+}
+
+/// Secondary text color (high contrast).
+pub fn text_secondary_high_contrast() -> Hsla {
+    hsla(0.0, 0.0, 0.9, 1.0)
+}
+
+/// Border color (high contrast).
+pub fn border_high_contrast() -> Hsla {
+    hsla(0.0, 0.0, 0.75, 1.0) // Clearly visible outline
+}
+
+/// Muted text color (high contrast).
+pub fn muted_high_contrast() -> Hsla {
+    hsla(0.0, 0.0, 0.8, 1.0)
+}
+
+/// Hover state (high contrast).
+pub fn hover_high_contrast() -> Hsla {
+    hsla(0.0, 0.0, 0.22, 1.0)
+}
+
+/// Active state (high contrast).
+pub fn active_high_contrast() -> Hsla {
+    hsla(0.0, 0.0, 0.32, 1.0)
+}
+
+/// Track color (high contrast).
+pub fn track_high_contrast() -> Hsla {
+    hsla(0.0, 0.0, 0.35, 1.0)
+}
+
+/// Surface color (high contrast).
+pub fn surface_high_contrast() -> Hsla {
+    hsla(0.0, 0.0, 0.12, 1.0)
+}
+
 // ============================================================================
 // Backwards Compatibility - Default to Dark Mode
 // ============================================================================
 
 /// Surface/background color for menu panels (deprecated: use theme-specific versions).
 pub fn surface_background() -> Hsla {
-    if current_dark_mode() {
-        surface_background_dark()
-    } else {
-        surface_background_light()
-    }
+    custom(|theme| theme.surface_background).unwrap_or_else(|| match current_palette() {
+        Palette::Dark => surface_background_dark(),
+        Palette::Light => surface_background_light(),
+        Palette::HighContrast => surface_background_high_contrast(),
+    })
 }
 
 /// Liquid glass panel tint - ultra-subtle dark tint for definition.
 pub fn liquid_glass_tint() -> Hsla {
-    if current_dark_mode() {
-        liquid_glass_tint_dark()
-    } else {
-        hsla(0.0, 0.0, 0.98, 0.9)
+    match current_palette() {
+        Palette::Dark => liquid_glass_tint_dark(),
+        Palette::Light => hsla(0.0, 0.0, 0.98, 0.9),
+        Palette::HighContrast => surface_background_high_contrast(),
     }
 }
 
 /// Primary text color - white for dark mode.
 pub fn text_primary() -> Hsla {
-    if current_dark_mode() {
-        text_primary_dark()
-    } else {
-        text_primary_light()
-    }
+    custom(|theme| theme.text_primary).unwrap_or_else(|| match current_palette() {
+        Palette::Dark => text_primary_dark(),
+        Palette::Light => text_primary_light(),
+        Palette::HighContrast => text_primary_high_contrast(),
+    })
 }
 
 /// Secondary text color - muted white for dark mode.
 pub fn text_secondary() -> Hsla {
-    if current_dark_mode() {
-        text_secondary_dark()
-    } else {
-        text_secondary_light()
-    }
+    custom(|theme| theme.text_secondary).unwrap_or_else(|| match current_palette() {
+        Palette::Dark => text_secondary_dark(),
+        Palette::Light => text_secondary_light(),
+        Palette::HighContrast => text_secondary_high_contrast(),
+    })
 }
 
 /// Border color for dividers and outlines - subtle white glow.
 pub fn border() -> Hsla {
-    if current_dark_mode() {
-        border_dark()
-    } else {
-        border_light()
-    }
+    custom(|theme| theme.border).unwrap_or_else(|| match current_palette() {
+        Palette::Dark => border_dark(),
+        Palette::Light => border_light(),
+        Palette::HighContrast => border_high_contrast(),
+    })
 }
 
 /// Liquid glass separator - ultra-subtle divider instead of hard borders.
 pub fn glass_separator() -> Hsla {
-    if current_dark_mode() {
-        hsla(0.0, 0.0, 1.0, 0.04)
-    } else {
-        hsla(0.0, 0.0, 0.0, 0.06)
-    }
+    custom(|theme| theme.border).unwrap_or_else(|| match current_palette() {
+        Palette::Dark => hsla(0.0, 0.0, 1.0, 0.04),
+        Palette::Light => hsla(0.0, 0.0, 0.0, 0.06),
+        Palette::HighContrast => border_high_contrast(),
+    })
 }
 
 /// Muted text color for secondary information.
 pub fn muted() -> Hsla {
-    if current_dark_mode() {
-        muted_dark()
-    } else {
-        muted_light()
-    }
+    custom(|theme| theme.muted).unwrap_or_else(|| match current_palette() {
+        Palette::Dark => muted_dark(),
+        Palette::Light => muted_light(),
+        Palette::HighContrast => muted_high_contrast(),
+    })
 }
 
 /// Hover state background color - subtle white highlight.
 pub fn hover() -> Hsla {
-    if current_dark_mode() {
-        hover_dark()
-    } else {
-        hover_light()
-    }
+    custom(|theme| theme.hover).unwrap_or_else(|| match current_palette() {
+        Palette::Dark => hover_dark(),
+        Palette::Light => hover_light(),
+        Palette::HighContrast => hover_high_contrast(),
+    })
 }
 
 /// Active/pressed state background.
 pub fn active() -> Hsla {
-    if current_dark_mode() {
-        active_dark()
-    } else {
-        active_light()
-    }
+    custom(|theme| theme.active).unwrap_or_else(|| match current_palette() {
+        Palette::Dark => active_dark(),
+        Palette::Light => active_light(),
+        Palette::HighContrast => active_high_contrast(),
+    })
 }
 
-/// Accent color for selected/active states (macOS blue).
+/// Accent color for selected/active states: the system accent color where
+/// there is one, otherwise macOS blue.
 pub fn accent() -> Hsla {
-    hsla(211.0 / 360.0, 1.0, 0.5, 1.0)
+    custom(|theme| theme.accent).unwrap_or_else(|| {
+        if high_contrast() {
+            hsla(211.0 / 360.0, 1.0, 0.65, 1.0)
+        } else {
+            system_accent().unwrap_or_else(|| hsla(211.0 / 360.0, 1.0, 0.5, 1.0))
+        }
+    })
 }
 
 /// Success color (good usage levels).
 pub fn success() -> Hsla {
-    hsla(142.0 / 360.0, 0.71, 0.45, 1.0) // Green
+    custom(|theme| theme.success).unwrap_or_else(|| {
+        if high_contrast() {
+            hsla(142.0 / 360.0, 0.71, 0.55, 1.0)
+        } else {
+            hsla(142.0 / 360.0, 0.71, 0.45, 1.0) // Green
+        }
+    })
 }
 
 /// Warning color (approaching limits).
 pub fn warning() -> Hsla {
-    hsla(38.0 / 360.0, 0.92, 0.50, 1.0) // Orange/Yellow
+    custom(|theme| theme.warning).unwrap_or_else(|| {
+        if high_contrast() {
+            hsla(38.0 / 360.0, 1.0, 0.6, 1.0)
+        } else {
+            hsla(38.0 / 360.0, 0.92, 0.50, 1.0) // Orange/Yellow
+        }
+    })
 }
 
 /// Error color (exceeded limits or errors).
 pub fn error() -> Hsla {
-    hsla(0.0, 0.72, 0.51, 1.0) // Red
+    custom(|theme| theme.error).unwrap_or_else(|| {
+        if high_contrast() {
+            hsla(0.0, 1.0, 0.67, 1.0)
+        } else {
+            hsla(0.0, 0.72, 0.51, 1.0) // Red
+        }
+    })
 }
 
 /// Surface color for buttons/controls - semi-transparent dark.
 pub fn surface() -> Hsla {
-    if current_dark_mode() {
-        surface_dark()
-    } else {
-        surface_light()
-    }
+    custom(|theme| theme.surface).unwrap_or_else(|| match current_palette() {
+        Palette::Dark => surface_dark(),
+        Palette::Light => surface_light(),
+        Palette::HighContrast => surface_high_contrast(),
+    })
 }
 
 /// Track color for progress bars - subtle on dark background.
 pub fn track() -> Hsla {
-    if current_dark_mode() {
-        track_dark()
-    } else {
-        track_light()
-    }
+    custom(|theme| theme.track).unwrap_or_else(|| match current_palette() {
+        Palette::Dark => track_dark(),
+        Palette::Light => track_light(),
+        Palette::HighContrast => track_high_contrast(),
+    })
 }
 
 /// Card background - for notification-style cards in dark mode.
 pub fn card_background() -> Hsla {
-    if current_dark_mode() {
-        hsla(0.0, 0.0, 0.18, 0.92)
-    } else {
-        hsla(0.0, 0.0, 1.0, 0.9)
-    }
+    custom(|theme| theme.card_background).unwrap_or_else(|| match current_palette() {
+        Palette::Dark => hsla(0.0, 0.0, 0.18, 0.92),
+        Palette::Light => hsla(0.0, 0.0, 1.0, 0.9),
+        Palette::HighContrast => surface_background_high_contrast(),
+    })
 }
 
 /// Opaque window background for platforms without blur support (Linux).
 pub fn window_background() -> Hsla {
-    hsla(0.0, 0.0, 0.12, 0.98)
+    custom(|theme| theme.surface_background).unwrap_or_else(|| {
+        if high_contrast() {
+            surface_background_high_contrast()
+        } else {
+            hsla(0.0, 0.0, 0.12, 0.98)
+        }
+    })
 }
 
 /// Liquid glass card background - even MORE subtle for true glass effect.
 pub fn liquid_card_background() -> Hsla {
-    if current_dark_mode() {
-        hsla(0.0, 0.0, 0.18, 0.9)
+    custom(|theme| theme.card_background).unwrap_or_else(|| match current_palette() {
+        Palette::Dark => hsla(0.0, 0.0, 0.18, 0.9),
+        Palette::Light => hsla(0.0, 0.0, 1.0, 0.95),
+        Palette::HighContrast => surface_background_high_contrast(),
+    })
+}
+
+/// Background for boxes of detail text (error details, logs).
+pub fn code_background() -> Hsla {
+    if high_contrast() {
+        surface_high_contrast()
+    } else {
+        hsla(0., 0., 0.1, 0.5)
+    }
+}
+
+/// Background for hint panels (sign-in, install instructions).
+pub fn hint_background() -> Hsla {
+    if high_contrast() {
+        surface_high_contrast()
+    } else {
+        hsla(0.12, 0.8, 0.35, 0.15) // Warm yellowish tint
+    }
+}
+
+/// Border for hint panels.
+pub fn hint_border() -> Hsla {
+    if high_contrast() {
+        warning()
     } else {
-        hsla(0.0, 0.0, 1.0, 0.95)
+        hsla(0.12, 0.6, 0.5, 0.3)
     }
 }
 
 /// Returns the appropriate color for a usage percentage (USED, not remaining).
 /// Green = low usage (good), Red = high usage (warning)
-/// Smooth gradient: Green (0%) → Yellow (50%) → Orange (80%) → Red (100%)
+/// Smooth gradient: Green (0%) → Yellow (50%) → Orange (80%) → Red (100%) by
+/// default, with the breakpoints and hues from the settings, unless the theme
+/// file sets its own colors.
 pub fn color_for_usage(used_percent: f64) -> Hsla {
     let used = used_percent as f32;
-    if used < 50.0 {
-        // Green to Yellow (0-50%)
-        let t = used / 50.0;
-        hsla(
-            (120.0 - t * 60.0) / 360.0, // Hue: 120 (green) → 60 (yellow)
-            0.7,
-            0.45,
-            1.0,
-        )
-    } else if used < 80.0 {
-        // Yellow to Orange (50-80%)
-        let t = (used - 50.0) / 30.0;
-        hsla(
-            (60.0 - t * 30.0) / 360.0, // Hue: 60 (yellow) → 30 (orange)
-            0.8,
-            0.5,
-            1.0,
-        )
+    if !high_contrast()
+        && let Some(color) = custom_theme::lookup(|theme| theme.usage_color(used))
+    {
+        return color;
+    }
+
+    let color = usage_gradient(used, &current_usage_gradient());
+    if high_contrast() {
+        // Light enough to read as text on black
+        hsla(color.h, color.s, color.l.max(0.6), 1.0)
     } else {
-        // Orange to Red (80-100%)
-        let t = (used - 80.0) / 20.0;
-        hsla(
-            (30.0 - t * 30.0) / 360.0, // Hue: 30 (orange) → 0 (red)
-            0.85,
-            0.5,
-            1.0,
-        )
+        color
     }
 }
 
+fn usage_gradient(used: f32, gradient: &UsageGradient) -> Hsla {
+    // Reds and oranges get more saturated as the limit nears
+    let (saturation, lightness) = if gradient.is_danger(used) {
+        (0.85, 0.5)
+    } else if gradient.is_warning(used) {
+        (0.8, 0.5)
+    } else {
+        (0.7, 0.45)
+    };
+    hsla(
+        gradient.hue_at(used).rem_euclid(360.0) / 360.0,
+        saturation,
+        lightness,
+        1.0,
+    )
+}
+
+/// Usage color breakpoints and hues from the settings.
+static USAGE_GRADIENT: RwLock<UsageGradient> = RwLock::new(UsageGradient::DEFAULT);
+
+/// Returns the usage color breakpoints and hues from the settings.
+pub fn current_usage_gradient() -> UsageGradient {
+    USAGE_GRADIENT
+        .read()
+        .map_or(UsageGradient::DEFAULT, |gradient| *gradient)
+}
+
+/// Keeps the usage colors of the menu, the menu bar icons and quota alerts
+/// in sync with the settings.
+///
+/// Call once the tray is set up.
+pub fn track_usage_gradient(cx: &mut App) {
+    let settings = cx.global::<AppState>().settings.clone();
+    let update = |gradient: UsageGradient, cx: &mut App| {
+        let Ok(mut current) = USAGE_GRADIENT.write() else {
+            return;
+        };
+        if *current == gradient {
+            return;
+        }
+        *current = gradient;
+        drop(current);
+
+        notifications::set_warning_threshold(f64::from(gradient.breakpoints().1));
+        cx.update_global::<SystemTray, _>(|tray, cx| tray.set_usage_gradient(gradient, cx));
+        cx.refresh_windows();
+    };
+
+    update(settings.read(cx).usage_gradient(), cx);
+    cx.observe(&settings, move |settings, cx| {
+        update(settings.read(cx).usage_gradient(), cx);
+    })
+    .detach();
+}
+
 /// Deprecated: Use color_for_usage() instead.
 /// Kept for backwards compatibility.
 #[deprecated(note = "Use color_for_usage() instead - inverted to show used percentage")]
@@ -362,6 +707,8 @@ pub struct ExactoBarTheme {
     pub provider_colors: HashMap<ProviderKind, Hsla>,
     /// Whether dark mode is active.
     pub dark_mode: bool,
+    /// Whether this is the high-contrast theme (which is dark).
+    pub high_contrast: bool,
 }
 
 impl ExactoBarTheme {
@@ -370,6 +717,7 @@ impl ExactoBarTheme {
         Self {
             provider_colors: provider_colors(),
             dark_mode: false,
+            high_contrast: false,
         }
     }
 
@@ -378,20 +726,40 @@ impl ExactoBarTheme {
         Self {
             provider_colors: provider_colors(),
             dark_mode: true,
+            high_contrast: false,
+        }
+    }
+
+    /// Creates the high-contrast theme: opaque, dark, with bright colors.
+    pub fn high_contrast() -> Self {
+        Self {
+            provider_colors: provider_colors(),
+            dark_mode: true,
+            high_contrast: true,
         }
     }
 
-    /// Gets the brand color for a provider.
+    /// Gets the brand color for a provider, as overridden in settings or
+    /// the theme file.
     pub fn provider_color(&self, provider: ProviderKind) -> Hsla {
-        self.provider_colors
-            .get(&provider)
-            .copied()
-            .unwrap_or(hsla(0.0, 0.0, 0.5, 1.0))
+        overridden_provider_color(provider).unwrap_or_else(|| {
+            self.provider_colors
+                .get(&provider)
+                .copied()
+                .unwrap_or(hsla(0.0, 0.0, 0.5, 1.0))
+        })
     }
 
     /// Gets the usage bar colors.
     pub fn usage_colors(&self) -> UsageColors {
-        if self.dark_mode {
+        if self.high_contrast {
+            UsageColors {
+                good: hsla(142.0 / 360.0, 0.71, 0.55, 1.0),  // Green
+                warning: hsla(38.0 / 360.0, 1.0, 0.60, 1.0), // Yellow
+                danger: hsla(0.0, 1.0, 0.67, 1.0),           // Red
+                background: hsla(0.0, 0.0, 0.35, 1.0),       // Gray
+            }
+        } else if self.dark_mode {
             UsageColors {
                 good: hsla(142.0 / 360.0, 0.71, 0.45, 1.0),   // Green
                 warning: hsla(38.0 / 360.0, 0.92, 0.50, 1.0), // Yellow
@@ -421,12 +789,13 @@ impl UsageColors {
     /// Gets the color for a given USAGE percentage (not remaining!).
     /// Green = low usage (good), Red = high usage (warning)
     pub fn for_usage(&self, used_percent: f32) -> Hsla {
-        if used_percent < 50.0 {
-            self.good
-        } else if used_percent < 80.0 {
+        let gradient = current_usage_gradient();
+        if gradient.is_danger(used_percent) {
+            self.danger
+        } else if gradient.is_warning(used_percent) {
             self.warning
         } else {
-            self.danger
+            self.good
         }
     }
 
@@ -484,9 +853,98 @@ fn provider_colors() -> HashMap<ProviderKind, Hsla> {
         hsla(282.0 / 360.0, 1.0, 0.41, 1.0),
     );
 
+    // Synthetic - Teal
+    map.insert(ProviderKind::Synthetic, hsla(168.0 / 360.0, 1.0, 0.40, 1.0));
+
+    // Demo - Slate
+    map.insert(ProviderKind::Demo, hsla(240.0 / 360.0, 0.06, 0.57, 1.0));
+
     map
 }
 
+/// Gets the brand color for a provider, as overridden in settings or the
+/// theme file.
+pub fn provider_color(provider: ProviderKind) -> Hsla {
+    overridden_provider_color(provider).unwrap_or_else(|| {
+        provider_colors()
+            .get(&provider)
+            .copied()
+            .unwrap_or(hsla(0.0, 0.0, 0.5, 1.0))
+    })
+}
+
+/// Brand colors picked in settings, which win over the theme file (and
+/// apply to the high-contrast theme too).
+static PROVIDER_COLOR_OVERRIDES: Lazy<RwLock<HashMap<ProviderKind, Hsla>>> =
+    Lazy::new(RwLock::default);
+
+fn overridden_provider_color(provider: ProviderKind) -> Option<Hsla> {
+    PROVIDER_COLOR_OVERRIDES
+        .read()
+        .ok()
+        .and_then(|overrides| overrides.get(&provider).copied())
+        .or_else(|| custom(|theme| theme.providers.get(&provider).copied()))
+}
+
+/// Parses the brand colors picked in settings, skipping invalid ones.
+fn parse_provider_colors(colors: &HashMap<ProviderKind, String>) -> HashMap<ProviderKind, Hsla> {
+    colors
+        .iter()
+        .filter_map(|(&provider, hex)| Some((provider, custom_theme::parse_hex(hex)?)))
+        .collect()
+}
+
+/// Returns the brand colors picked in settings, for the icon renderer.
+pub fn icon_brand_colors() -> HashMap<ProviderKind, tiny_skia::Color> {
+    let Ok(overrides) = PROVIDER_COLOR_OVERRIDES.read() else {
+        return HashMap::new();
+    };
+    overrides
+        .iter()
+        .filter_map(|(&provider, color)| {
+            let rgb = color.to_rgb();
+            Some((
+                provider,
+                tiny_skia::Color::from_rgba(rgb.r, rgb.g, rgb.b, rgb.a)?,
+            ))
+        })
+        .collect()
+}
+
+/// Keeps the brand color overrides in sync with the settings, redrawing the
+/// menu bar icons and open windows when they change.
+///
+/// Call once the tray is set up.
+pub fn track_provider_colors(cx: &mut App) {
+    let settings = cx.global::<AppState>().settings.clone();
+    let update = |colors: HashMap<ProviderKind, Hsla>, cx: &mut App| {
+        let Ok(mut overrides) = PROVIDER_COLOR_OVERRIDES.write() else {
+            return;
+        };
+        if *overrides == colors {
+            return;
+        }
+        *overrides = colors;
+        drop(overrides);
+
+        let icon_colors = icon_brand_colors();
+        cx.update_global::<SystemTray, _>(|tray, cx| tray.set_brand_colors(icon_colors, cx));
+        cx.refresh_windows();
+    };
+
+    update(
+        parse_provider_colors(&settings.read(cx).provider_colors()),
+        cx,
+    );
+    cx.observe(&settings, move |settings, cx| {
+        update(
+            parse_provider_colors(&settings.read(cx).provider_colors()),
+            cx,
+        );
+    })
+    .detach();
+}
+
 // ============================================================================
 // Color Utilities
 // ============================================================================
@@ -507,6 +965,89 @@ pub fn transparent(color: Hsla, alpha: f32) -> Hsla {
 }
 
 // ============================================================================
-// Tests are in a separate integration test file to avoid binary recursion issues
-// See tests/theme_tests.rs for comprehensive theme system tests
+// Tests
 // ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// WCAG contrast ratio between two opaque colors.
+    fn contrast_ratio(a: Hsla, b: Hsla) -> f32 {
+        fn luminance(color: Hsla) -> f32 {
+            let rgb = color.to_rgb();
+            let channel = |c: f32| {
+                if c <= 0.039_28 {
+                    c / 12.92
+                } else {
+                    ((c + 0.055) / 1.055).powf(2.4)
+                }
+            };
+            0.2126 * channel(rgb.r) + 0.7152 * channel(rgb.g) + 0.0722 * channel(rgb.b)
+        }
+        let (a, b) = (luminance(a), luminance(b));
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    #[test]
+    fn test_high_contrast_text_meets_wcag_aa() {
+        let background = surface_background_high_contrast();
+        for text in [
+            text_primary_high_contrast(),
+            text_secondary_high_contrast(),
+            muted_high_contrast(),
+        ] {
+            assert!(contrast_ratio(text, background) >= 4.5);
+            // Still readable on hovered and pressed rows
+            assert!(contrast_ratio(text, active_high_contrast()) >= 4.5);
+        }
+    }
+
+    #[test]
+    fn test_high_contrast_colors_are_opaque() {
+        for color in [
+            surface_background_high_contrast(),
+            border_high_contrast(),
+            hover_high_contrast(),
+            active_high_contrast(),
+            track_high_contrast(),
+            surface_high_contrast(),
+        ] {
+            assert_eq!(color.a, 1.0);
+        }
+    }
+
+    #[test]
+    fn test_palette_for_mode() {
+        assert_eq!(
+            Palette::for_mode(ThemeMode::HighContrast, WindowAppearance::Light),
+            Palette::HighContrast
+        );
+        assert_eq!(
+            Palette::for_mode(ThemeMode::System, WindowAppearance::VibrantDark),
+            Palette::Dark
+        );
+        assert_eq!(
+            window_background_appearance(ThemeMode::HighContrast, WindowBackground::Blurred),
+            WindowBackgroundAppearance::Opaque
+        );
+        assert_eq!(
+            window_background_appearance(ThemeMode::Dark, WindowBackground::Translucent),
+            WindowBackgroundAppearance::Transparent
+        );
+    }
+
+    #[test]
+    fn test_usage_gradient_breakpoints() {
+        let default = UsageGradient::default();
+        assert!((usage_gradient(0.0, &default).h - 120.0 / 360.0).abs() < 1e-4);
+        assert!((usage_gradient(50.0, &default).h - 60.0 / 360.0).abs() < 1e-4);
+        assert_eq!(usage_gradient(65.0, &default).s, 0.8);
+
+        // Red from 60%
+        let early = UsageGradient::with_breakpoints(40.0, 60.0);
+        let color = usage_gradient(60.0, &early);
+        assert!((color.h - 30.0 / 360.0).abs() < 1e-4);
+        assert_eq!(color.s, 0.85);
+    }
+}
@@ -3,6 +3,8 @@
 //! Handles the menu bar icon(s) with dynamic usage meters using native NSStatusItem APIs.
 //! Uses an Objective-C delegate to handle status item clicks and show GPUI popup windows.
 
+#[cfg(target_os = "macos")]
+use block::ConcreteBlock;
 #[cfg(target_os = "macos")]
 use cocoa::appkit::NSSquareStatusItemLength;
 #[cfg(target_os = "macos")]
@@ -20,8 +22,8 @@ use std::sync::Once;
 
 use exactobar_core::{ProviderKind, StatusIndicator};
 use gpui::*;
+use smol::channel::{Receiver, Sender};
 use std::collections::HashMap;
-use std::sync::mpsc::{self, Receiver, Sender};
 use tracing::{debug, info, warn};
 
 // Linux-specific imports
@@ -30,9 +32,16 @@ use ksni::Icon as KsniIcon;
 #[cfg(target_os = "linux")]
 use ksni::blocking::TrayMethods as KsniTrayMethods;
 
-use crate::icon::{IconAnimationState, IconRenderer, RenderMode, RenderedIcon};
-use crate::menu::TrayMenu;
+use crate::accessibility;
+#[cfg(target_os = "macos")]
+use crate::icon::BACKING_SCALES;
+use crate::icon::{IconAnimationState, IconKey, IconRenderer, RenderMode};
+use crate::menu::{self, TrayMenu};
 use crate::state::AppState;
+use crate::theme;
+
+/// How often "surprise me" mode may start a random animation.
+const SURPRISE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
 
 // ============================================================================
 // Objective-C Delegate for Status Item Clicks
@@ -41,6 +50,10 @@ use crate::state::AppState;
 #[cfg(target_os = "macos")]
 static REGISTER_DELEGATE: Once = Once::new();
 
+/// `NSEventMask` for left, right and other mouse-down events.
+#[cfg(target_os = "macos")]
+const MOUSE_DOWN_MASK: u64 = (1 << 1) | (1 << 3) | (1 << 25);
+
 /// Registers our Objective-C delegate class for handling status item clicks.
 ///
 /// This creates a class called "ExactoBarDelegate" with a `statusItemClicked:` method
@@ -93,7 +106,7 @@ fn register_delegate_class() -> &'static Class {
                         };
 
                         // Send the click event
-                        let _ = sender.send(StatusItemClickEvent { provider });
+                        let _ = sender.try_send(StatusItemClickEvent { provider });
                         debug!(provider = ?provider, "Status item clicked");
                     }
                 }
@@ -140,6 +153,55 @@ fn create_delegate(sender: &Sender<StatusItemClickEvent>, provider: Option<Provi
     }
 }
 
+/// Size of the menu bar icon, in points.
+#[cfg(target_os = "macos")]
+const STATUS_ITEM_IMAGE_SIZE: NSSize = NSSize {
+    width: 18.0,
+    height: 11.0,
+};
+
+/// Adds a status item to the menu bar.
+///
+/// macOS remembers where each status item sits under its autosave name, so
+/// naming it after the provider brings a recreated item back to the same
+/// place (the merged item uses the first provider's name).
+#[cfg(target_os = "macos")]
+fn new_status_item(provider: ProviderKind) -> id {
+    unsafe {
+        let status_bar: id = msg_send![class!(NSStatusBar), systemStatusBar];
+
+        // Square length fits the icon nicely
+        let status_item: id = msg_send![status_bar, statusItemWithLength: NSSquareStatusItemLength];
+
+        // Retain the status item so it doesn't get deallocated
+        let _: () = msg_send![status_item, retain];
+
+        let name = NSString::alloc(nil).init_str(&format!("exactobar.{}", provider.cli_name()));
+        let _: () = msg_send![status_item, setAutosaveName: name];
+        let _: () = msg_send![name, release];
+
+        status_item
+    }
+}
+
+/// Takes a status item off the menu bar and releases it.
+#[cfg(target_os = "macos")]
+fn remove_status_item_from_bar(status_item: id) {
+    unsafe {
+        let status_bar: id = msg_send![class!(NSStatusBar), systemStatusBar];
+        let _: () = msg_send![status_bar, removeStatusItem: status_item];
+    }
+    release_object(status_item);
+}
+
+/// Releases an Objective-C object we own.
+#[cfg(target_os = "macos")]
+fn release_object(object: id) {
+    unsafe {
+        let _: () = msg_send![object, release];
+    }
+}
+
 // ============================================================================
 // Linux SNI (StatusNotifierItem) Implementation
 // ============================================================================
@@ -154,6 +216,8 @@ enum LinuxTrayEvent {
     OpenMenu,
     /// "Refresh" menu item was clicked.
     Refresh,
+    /// "Usage Dashboard" menu item was clicked.
+    Dashboard,
     /// "Settings" menu item was clicked.
     Settings,
     /// "Quit" menu item was clicked.
@@ -171,13 +235,20 @@ struct LinuxTray {
     event_sender: Sender<LinuxTrayEvent>,
     /// The tray icon (ARGB format).
     icon: KsniIcon,
+    /// Description of the providers' state, read by screen readers and
+    /// shown as the tooltip.
+    label: String,
 }
 
 #[cfg(target_os = "linux")]
 impl LinuxTray {
     /// Creates a new Linux tray with the given event sender and icon.
     fn new(event_sender: Sender<LinuxTrayEvent>, icon: KsniIcon) -> Self {
-        Self { event_sender, icon }
+        Self {
+            event_sender,
+            icon,
+            label: "ExactoBar".into(),
+        }
     }
 }
 
@@ -195,8 +266,18 @@ impl ksni::Tray for LinuxTray {
         vec![self.icon.clone()]
     }
 
+    fn tool_tip(&self) -> ksni::ToolTip {
+        ksni::ToolTip {
+            title: "ExactoBar".into(),
+            description: self.label.clone(),
+            ..Default::default()
+        }
+    }
+
     fn activate(&mut self, x: i32, y: i32) {
-        let _ = self.event_sender.send(LinuxTrayEvent::Activate { x, y });
+        let _ = self
+            .event_sender
+            .try_send(LinuxTrayEvent::Activate { x, y });
     }
 
     fn menu(&self) -> Vec<ksni::MenuItem<Self>> {
@@ -205,7 +286,15 @@ impl ksni::Tray for LinuxTray {
             StandardItem {
                 label: "Refresh".into(),
                 activate: Box::new(|tray: &mut Self| {
-                    let _ = tray.event_sender.send(LinuxTrayEvent::Refresh);
+                    let _ = tray.event_sender.try_send(LinuxTrayEvent::Refresh);
+                }),
+                ..Default::default()
+            }
+            .into(),
+            StandardItem {
+                label: "Usage Dashboard".into(),
+                activate: Box::new(|tray: &mut Self| {
+                    let _ = tray.event_sender.try_send(LinuxTrayEvent::Dashboard);
                 }),
                 ..Default::default()
             }
@@ -213,7 +302,7 @@ impl ksni::Tray for LinuxTray {
             StandardItem {
                 label: "Settings".into(),
                 activate: Box::new(|tray: &mut Self| {
-                    let _ = tray.event_sender.send(LinuxTrayEvent::Settings);
+                    let _ = tray.event_sender.try_send(LinuxTrayEvent::Settings);
                 }),
                 ..Default::default()
             }
@@ -222,7 +311,7 @@ impl ksni::Tray for LinuxTray {
             StandardItem {
                 label: "Quit".into(),
                 activate: Box::new(|tray: &mut Self| {
-                    let _ = tray.event_sender.send(LinuxTrayEvent::Quit);
+                    let _ = tray.event_sender.try_send(LinuxTrayEvent::Quit);
                 }),
                 ..Default::default()
             }
@@ -231,6 +320,90 @@ impl ksni::Tray for LinuxTray {
     }
 }
 
+/// Gap between the Linux popup and the screen edges it's anchored to.
+const POPUP_EDGE_MARGIN: f32 = 10.0;
+
+/// Returns true if the app is running under a Wayland compositor, where
+/// windows can't pick their own position.
+#[cfg(target_os = "linux")]
+fn is_wayland_session() -> bool {
+    std::env::var_os("WAYLAND_DISPLAY").is_some_and(|v| !v.is_empty())
+}
+
+/// The screen corner the Linux popup is anchored to under Wayland, nearest
+/// the tray icon that was clicked.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PopupAnchor {
+    /// Anchored to the top edge, else the bottom.
+    top: bool,
+    /// Anchored to the right edge, else the left.
+    right: bool,
+    /// Distance from the anchored side edge, lining the popup up with the
+    /// click.
+    inset: f32,
+}
+
+impl PopupAnchor {
+    /// Picks the corner for a click on the tray icon. Without a usable click
+    /// position (some SNI hosts report 0,0) it assumes a top bar with the
+    /// tray on the right.
+    fn for_click(
+        click_pos: Option<(i32, i32)>,
+        screen: Option<(f32, f32)>,
+        menu_width: f32,
+    ) -> Self {
+        let corner = Self {
+            top: true,
+            right: true,
+            inset: POPUP_EDGE_MARGIN,
+        };
+        let (Some((x, y)), Some((width, height))) =
+            (click_pos.filter(|&pos| pos != (0, 0)), screen)
+        else {
+            return corner;
+        };
+
+        let (x, y) = (x as f32, y as f32);
+        let right = x >= width / 2.0;
+        // The popup's near edge sits at the click, as on X11
+        let inset = if right { width - x } else { x };
+        let max_inset = (width - menu_width - POPUP_EDGE_MARGIN).max(POPUP_EDGE_MARGIN);
+        Self {
+            top: y < height / 2.0,
+            right,
+            inset: inset.clamp(POPUP_EDGE_MARGIN, max_inset),
+        }
+    }
+
+    /// Layer-shell placement for the popup.
+    #[cfg(target_os = "linux")]
+    fn layer_shell_options(self) -> gpui::layer_shell::LayerShellOptions {
+        use gpui::layer_shell::{Anchor, KeyboardInteractivity, Layer, LayerShellOptions};
+
+        let vertical = if self.top {
+            Anchor::TOP
+        } else {
+            Anchor::BOTTOM
+        };
+        let horizontal = if self.right {
+            Anchor::RIGHT
+        } else {
+            Anchor::LEFT
+        };
+        let edge = px(POPUP_EDGE_MARGIN);
+        let inset = px(self.inset);
+        LayerShellOptions {
+            namespace: "exactobar".into(),
+            layer: Layer::Top,
+            anchor: vertical | horizontal,
+            // Only the anchored edges' margins apply
+            margin: Some((edge, inset, edge, inset)),
+            keyboard_interactivity: KeyboardInteractivity::OnDemand,
+            ..Default::default()
+        }
+    }
+}
+
 // ============================================================================
 // System Tray
 // ============================================================================
@@ -254,9 +427,10 @@ pub struct SystemTray {
     #[cfg(target_os = "macos")]
     merged_status_item: Option<id>,
 
-    /// Delegate objects for handling clicks (must be kept alive) (macOS).
+    /// Delegate objects for handling clicks (must be kept alive), keyed
+    /// like `displayed_icons` (macOS).
     #[cfg(target_os = "macos")]
-    delegates: Vec<id>,
+    delegates: HashMap<Option<ProviderKind>, id>,
 
     /// Channel sender for click events (macOS).
     /// IMPORTANT: Must be Box to maintain stable pointer address when SystemTray
@@ -268,6 +442,24 @@ pub struct SystemTray {
     #[cfg(target_os = "macos")]
     click_receiver: Option<Receiver<StatusItemClickEvent>>,
 
+    /// Channel sender for clicks in other apps while the menu is open (macOS).
+    #[cfg(target_os = "macos")]
+    outside_click_sender: Sender<()>,
+
+    /// Channel receiver for clicks in other apps (macOS).
+    #[cfg(target_os = "macos")]
+    outside_click_receiver: Option<Receiver<()>>,
+
+    /// Global event monitor watching for those clicks, while the menu is
+    /// open (macOS).
+    #[cfg(target_os = "macos")]
+    outside_click_monitor: Option<id>,
+
+    /// Renderers for each of [`BACKING_SCALES`], so every display density
+    /// gets a bitmap drawn at its own resolution (macOS).
+    #[cfg(target_os = "macos")]
+    scaled_renderers: Vec<IconRenderer>,
+
     // ========================================================================
     // Linux-specific fields
     // ========================================================================
@@ -289,6 +481,17 @@ pub struct SystemTray {
     /// Icon renderer.
     renderer: IconRenderer,
 
+    /// Icon last shown in each status item, by provider (`None` for the
+    /// merged item, and the single icon on Linux).
+    displayed_icons: HashMap<Option<ProviderKind>, IconKey>,
+
+    /// Screen reader description last given to each status item, keyed
+    /// like `displayed_icons`.
+    accessibility_labels: HashMap<Option<ProviderKind>, String>,
+
+    /// Usage revision each provider's icon was last drawn from.
+    icon_revisions: HashMap<ProviderKind, u64>,
+
     /// Whether icons are merged.
     merge_mode: bool,
 
@@ -304,12 +507,125 @@ pub struct SystemTray {
     /// Whether "surprise me" mode (random animations) is enabled.
     surprise_me_enabled: bool,
 
-    /// Time since last random animation event.
-    last_random_event: std::time::Instant,
+    /// Whether the animation frame timer is running.
+    animation_timer_running: bool,
+
+    /// Timer for "surprise me" animations, while that mode is enabled.
+    surprise_timer: Option<Task<()>>,
 }
 
 impl Global for SystemTray {}
 
+impl SystemTray {
+    /// Follows the usage model, redrawing only the icons of providers whose
+    /// state changed.
+    pub fn observe_usage(&mut self, cx: &mut App) {
+        let usage = cx.global::<AppState>().usage.clone();
+        cx.observe(&usage, |_, cx| {
+            cx.update_global::<SystemTray, _>(|tray, cx| tray.update_changed(cx));
+        })
+        .detach();
+    }
+
+    /// Updates the icons of providers whose usage revision moved since their
+    /// icon was last drawn.
+    fn update_changed(&mut self, cx: &mut App) {
+        let state = cx.global::<AppState>();
+        let usage = state.usage.read(cx);
+        let changed: Vec<ProviderKind> = state
+            .enabled_providers(cx)
+            .into_iter()
+            .filter(|&provider| {
+                let revision = usage.revision(provider);
+                self.icon_revisions.insert(provider, revision) != Some(revision)
+            })
+            .collect();
+
+        // Linux has a single icon, showing the first provider
+        #[cfg(target_os = "linux")]
+        let changed: Vec<ProviderKind> = state
+            .enabled_providers(cx)
+            .first()
+            .filter(|provider| changed.contains(provider))
+            .into_iter()
+            .copied()
+            .collect();
+
+        for provider in changed {
+            self.update_icon(provider, cx);
+        }
+    }
+
+    /// Advances the loading sweep, or holds it on one frame while motion is
+    /// reduced.
+    fn next_loading_phase(&mut self, reduce_motion: bool) -> f64 {
+        if reduce_motion {
+            return 0.0;
+        }
+        self.loading_phase += 0.1;
+        self.loading_phase
+    }
+
+    /// Redraws the icons with the brand colors picked in settings.
+    pub fn set_brand_colors(
+        &mut self,
+        colors: HashMap<ProviderKind, tiny_skia::Color>,
+        cx: &mut App,
+    ) {
+        #[cfg(target_os = "macos")]
+        for renderer in &mut self.scaled_renderers {
+            renderer.set_brand_colors(colors.clone());
+        }
+        self.renderer.set_brand_colors(colors);
+        self.update_all(cx);
+    }
+
+    /// Redraws the icons with new usage color breakpoints.
+    pub fn set_usage_gradient(&mut self, gradient: exactobar_store::UsageGradient, cx: &mut App) {
+        #[cfg(target_os = "macos")]
+        for renderer in &mut self.scaled_renderers {
+            renderer.set_usage_gradient(gradient);
+        }
+        self.renderer.set_usage_gradient(gradient);
+        self.update_all(cx);
+    }
+
+    /// Opens the tray menu unless it's already open.
+    pub fn show_menu(&mut self, cx: &mut App) {
+        let menu_open = self
+            .menu_window
+            .is_some_and(|handle| cx.update_window(handle, |_, _, _| {}).is_ok());
+        if !menu_open {
+            self.toggle_menu(None, cx);
+        }
+    }
+
+    /// Shakes a provider's icon sideways, starting `offset` pixels off
+    /// center.
+    pub fn trigger_wiggle(&mut self, provider: ProviderKind, offset: f32, cx: &mut App) {
+        if accessibility::reduce_motion(cx) {
+            return;
+        }
+        if let Some(state) = self.animation_states.get_mut(&provider) {
+            state.wiggle_offset = offset;
+        }
+        self.ensure_animation_timer(cx);
+        self.update_icon(provider, cx);
+    }
+
+    /// Tilts a provider's icon by `degrees`, settling back upright.
+    pub fn trigger_tilt(&mut self, provider: ProviderKind, degrees: f32, cx: &mut App) {
+        if accessibility::reduce_motion(cx) {
+            return;
+        }
+        if let Some(state) = self.animation_states.get_mut(&provider) {
+            state.tilt_degrees = degrees;
+        }
+        self.ensure_animation_timer(cx);
+        self.update_icon(provider, cx);
+    }
+}
+
 #[cfg(target_os = "macos")]
 impl SystemTray {
     /// Creates a new system tray with native macOS status items.
@@ -327,8 +643,9 @@ impl SystemTray {
 
         // Create channel for click events from Objective-C delegate
         // Box the sender so it has a stable heap address (survives struct moves)
-        let (click_sender, click_receiver) = mpsc::channel();
+        let (click_sender, click_receiver) = smol::channel::unbounded();
         let click_sender = Box::new(click_sender);
+        let (outside_click_sender, outside_click_receiver) = smol::channel::unbounded();
 
         // Initialize animation states for all providers
         let mut animation_states = HashMap::new();
@@ -339,16 +656,31 @@ impl SystemTray {
         let mut tray = Self {
             status_items: HashMap::new(),
             merged_status_item: None,
-            delegates: Vec::new(),
+            delegates: HashMap::new(),
             click_sender,
             click_receiver: Some(click_receiver),
+            outside_click_sender,
+            outside_click_receiver: Some(outside_click_receiver),
+            outside_click_monitor: None,
+            scaled_renderers: BACKING_SCALES
+                .iter()
+                .map(|&scale| {
+                    IconRenderer::new()
+                        .with_mode(RenderMode::Template)
+                        .with_scale(scale)
+                })
+                .collect(),
             renderer,
+            displayed_icons: HashMap::new(),
+            accessibility_labels: HashMap::new(),
+            icon_revisions: HashMap::new(),
             merge_mode,
             menu_window: None,
             loading_phase: 0.0,
             animation_states,
             surprise_me_enabled,
-            last_random_event: std::time::Instant::now(),
+            animation_timer_running: false,
+            surprise_timer: None,
         };
 
         // Create native status items
@@ -367,8 +699,8 @@ impl SystemTray {
     /// Starts the click event listener.
     ///
     /// This should be called after the SystemTray is set as a global.
-    /// It spawns a background task that polls the click channel and
-    /// calls `toggle_menu()` when a status item is clicked.
+    /// It spawns a task that waits on the click channel and calls
+    /// `toggle_menu()` when a status item is clicked.
     pub fn start_click_listener(&mut self, cx: &mut App) {
         // Take ownership of the receiver
         let Some(receiver) = self.click_receiver.take() else {
@@ -376,25 +708,31 @@ impl SystemTray {
             return;
         };
 
-        // Spawn a background task to poll for click events
-        // We use a timer to periodically check the channel
+        // Spawn a task that handles click events as they arrive
         cx.spawn(async move |cx| {
-            loop {
-                // Check for click events (non-blocking)
-                while let Ok(event) = receiver.try_recv() {
-                    debug!(provider = ?event.provider, "Processing status item click");
-                    let _ = cx.update_global::<SystemTray, _>(|tray, cx| {
-                        tray.toggle_menu(event.provider, cx);
-                    });
-                }
-
-                // Sleep briefly to avoid busy-waiting
-                // 16ms ≈ 60fps, responsive enough for UI
-                smol::Timer::after(std::time::Duration::from_millis(16)).await;
+            // Parks until a click arrives; ends when the tray is dropped
+            while let Ok(event) = receiver.recv().await {
+                debug!(provider = ?event.provider, "Processing status item click");
+                let _ = cx.update_global::<SystemTray, _>(|tray, cx| {
+                    tray.toggle_menu(event.provider, cx);
+                });
             }
         })
         .detach();
 
+        // Clicking anywhere outside the app dismisses the menu
+        if let Some(receiver) = self.outside_click_receiver.take() {
+            cx.spawn(async move |cx| {
+                while let Ok(()) = receiver.recv().await {
+                    debug!("Clicked outside the menu, closing");
+                    let _ = cx.update_global::<SystemTray, _>(|tray, cx| {
+                        tray.close_menu(cx);
+                    });
+                }
+            })
+            .detach();
+        }
+
         info!("Status item click listener started");
     }
 
@@ -404,135 +742,115 @@ impl SystemTray {
     /// to call our Objective-C delegate, which sends a message through the
     /// channel to show the GPUI popup window.
     fn create_status_item(&mut self, provider: ProviderKind, cx: &mut App) {
-        unsafe {
-            // Get the system status bar
-            let status_bar: id = msg_send![class!(NSStatusBar), systemStatusBar];
-
-            // Create a status item with square length (fits icon nicely)
-            let status_item: id =
-                msg_send![status_bar, statusItemWithLength: NSSquareStatusItemLength];
-
-            // Retain the status item so it doesn't get deallocated
-            let _: () = msg_send![status_item, retain];
+        let status_item = new_status_item(provider);
+        self.attach_delegate(status_item, Some(provider));
+        self.status_items.insert(provider, status_item);
+        self.update_icon(provider, cx);
 
-            // Render the initial icon
-            let state = cx.global::<AppState>();
-            let snapshot = state.get_snapshot(provider, cx);
-            let status = state.get_status(provider, cx);
-            let status_indicator = status.map(|s| s.indicator).unwrap_or(StatusIndicator::None);
+        debug!(provider = ?provider, "Created native status item with click handler");
+    }
 
-            let rendered = self.renderer.render(
-                provider,
-                snapshot.as_ref(),
-                false,
-                Some(status_indicator),
-                None,
-            );
+    /// Creates a merged status item (for merge mode).
+    ///
+    /// Uses the same delegate pattern as individual status items, and takes
+    /// the first provider's place in the menu bar.
+    fn create_merged_status_item(&mut self, cx: &mut App) {
+        let providers = cx.global::<AppState>().enabled_providers(cx);
 
-            // Set the icon image
-            self.set_status_item_image(status_item, &rendered);
+        if let Some(&first) = providers.first() {
+            let status_item = new_status_item(first);
+            self.attach_delegate(status_item, None);
+            self.merged_status_item = Some(status_item);
+            self.update_icon(first, cx);
 
-            // Create delegate for handling clicks (instead of NSMenu)
-            let delegate = create_delegate(&self.click_sender, Some(provider));
-            let _: () = msg_send![delegate, retain];
-            self.delegates.push(delegate);
+            debug!("Created merged status item with click handler");
+        }
+    }
 
-            // Get the button and set up click handling
+    /// Points a status item's clicks at a new delegate for `slot` (`None`
+    /// for the merged item), releasing the slot's previous delegate.
+    fn attach_delegate(&mut self, status_item: id, slot: Option<ProviderKind>) {
+        let delegate = create_delegate(&self.click_sender, slot);
+        unsafe {
             let button: id = msg_send![status_item, button];
             if button != nil {
-                // Set target and action for the button
                 let _: () = msg_send![button, setTarget: delegate];
                 let _: () = msg_send![button, setAction: sel!(statusItemClicked:)];
-                debug!("Set up click handler for status item button");
             } else {
                 warn!("Status item button is nil, cannot set up click handler");
             }
-
-            // Store the status item
-            self.status_items.insert(provider, status_item);
-
-            debug!(provider = ?provider, "Created native status item with click handler");
+        }
+        if let Some(previous) = self.delegates.insert(slot, delegate) {
+            release_object(previous);
         }
     }
 
-    /// Creates a merged status item (for merge mode).
-    ///
-    /// Uses the same delegate pattern as individual status items.
-    fn create_merged_status_item(&mut self, cx: &mut App) {
-        let state = cx.global::<AppState>();
-        let providers = state.enabled_providers(cx);
-
-        if let Some(first) = providers.first() {
-            unsafe {
-                let status_bar: id = msg_send![class!(NSStatusBar), systemStatusBar];
-                let status_item: id =
-                    msg_send![status_bar, statusItemWithLength: NSSquareStatusItemLength];
-                let _: () = msg_send![status_item, retain];
-
-                let snapshot = state.get_snapshot(*first, cx);
-                let rendered = self
-                    .renderer
-                    .render(*first, snapshot.as_ref(), false, None, None);
-                self.set_status_item_image(status_item, &rendered);
-
-                // Create delegate for handling clicks (provider=None for merged)
-                let delegate = create_delegate(&self.click_sender, None);
-                let _: () = msg_send![delegate, retain];
-                self.delegates.push(delegate);
-
-                // Get the button and set up click handling
-                let button: id = msg_send![status_item, button];
-                if button != nil {
-                    let _: () = msg_send![button, setTarget: delegate];
-                    let _: () = msg_send![button, setAction: sel!(statusItemClicked:)];
-                }
+    /// Forgets everything shown in a slot and releases its delegate, once
+    /// its status item is removed or handed to another slot.
+    fn clear_slot(&mut self, slot: Option<ProviderKind>) {
+        self.displayed_icons.remove(&slot);
+        self.accessibility_labels.remove(&slot);
+        if let Some(delegate) = self.delegates.remove(&slot) {
+            release_object(delegate);
+        }
+    }
 
-                self.merged_status_item = Some(status_item);
-                debug!("Created merged status item with click handler");
+    /// Sets what VoiceOver reads for a status item, also shown as its tooltip.
+    fn set_status_item_label(&self, status_item: id, label: &str) {
+        unsafe {
+            let button: id = msg_send![status_item, button];
+            if button == nil {
+                return;
             }
+            let ns_label = NSString::alloc(nil).init_str(label);
+            let _: () = msg_send![button, setAccessibilityLabel: ns_label];
+            let _: () = msg_send![button, setToolTip: ns_label];
+            let _: () = msg_send![ns_label, release];
         }
     }
 
-    /// Sets the image for a status item from a RenderedIcon.
-    fn set_status_item_image(&self, status_item: id, rendered: &RenderedIcon) {
+    /// Sets the image for a status item from PNG bytes, one bitmap per
+    /// backing scale.
+    ///
+    /// Each bitmap becomes a representation of the same 18x11pt image, and
+    /// macOS draws the one matching the display's pixel density.
+    fn set_status_item_image(&self, status_item: id, pngs: &[&[u8]]) {
         unsafe {
-            // Convert to PNG bytes
-            let png_data = rendered.to_png();
-
-            // Create NSData from PNG bytes
-            let ns_data: id = msg_send![
-                class!(NSData),
-                dataWithBytes: png_data.as_ptr()
-                length: png_data.len()
-            ];
-
-            // Create NSImage from data
             let ns_image: id = msg_send![class!(NSImage), alloc];
-            let ns_image: id = msg_send![ns_image, initWithData: ns_data];
-
-            if ns_image != nil {
-                // Set as template image for proper dark/light mode support
-                // Template images are rendered by macOS in the appropriate color
-                let _: () = msg_send![ns_image, setTemplate: YES];
-
-                // Set size (18x11 points for our icon dimensions)
-                // macOS handles retina scaling automatically
-                let size = NSSize::new(18.0, 11.0);
-                let _: () = msg_send![ns_image, setSize: size];
-
-                // Get the status item's button and set the image
-                let button: id = msg_send![status_item, button];
-                if button != nil {
-                    let _: () = msg_send![button, setImage: ns_image];
-                } else {
-                    warn!("Status item button is nil");
+            let ns_image: id = msg_send![ns_image, initWithSize: STATUS_ITEM_IMAGE_SIZE];
+
+            for png_data in pngs {
+                // Create NSData from PNG bytes
+                let ns_data: id = msg_send![
+                    class!(NSData),
+                    dataWithBytes: png_data.as_ptr()
+                    length: png_data.len()
+                ];
+
+                let rep: id = msg_send![class!(NSBitmapImageRep), imageRepWithData: ns_data];
+                if rep == nil {
+                    warn!("Failed to create image representation from PNG data");
+                    continue;
                 }
+                // Same size in points, so the pixel count sets the density
+                let _: () = msg_send![rep, setSize: STATUS_ITEM_IMAGE_SIZE];
+                let _: () = msg_send![ns_image, addRepresentation: rep];
+            }
 
-                // Release the image (button retains it)
-                let _: () = msg_send![ns_image, release];
+            // Set as template image for proper dark/light mode support
+            // Template images are rendered by macOS in the appropriate color
+            let _: () = msg_send![ns_image, setTemplate: YES];
+
+            // Get the status item's button and set the image
+            let button: id = msg_send![status_item, button];
+            if button != nil {
+                let _: () = msg_send![button, setImage: ns_image];
             } else {
-                warn!("Failed to create NSImage from PNG data");
+                warn!("Status item button is nil");
             }
+
+            // Release the image (button retains it)
+            let _: () = msg_send![ns_image, release];
         }
     }
 
@@ -542,42 +860,69 @@ impl SystemTray {
         let snapshot = state.get_snapshot(provider, cx);
         let is_refreshing = state.is_provider_refreshing(provider, cx);
         let has_error = state.get_error(provider, cx).is_some();
+        let needs_auth = state.needs_auth(provider, cx);
         let status = state.get_status(provider, cx);
 
-        // Check if snapshot is stale (older than 10 minutes)
-        let stale = snapshot.as_ref().is_some_and(|s| {
-            let threshold = chrono::Duration::minutes(10);
-            chrono::Utc::now() - s.updated_at > threshold
-        });
-
-        // Get animation state for this provider
-        let animation = self.animation_states.get(&provider);
-
-        let rendered = if is_refreshing {
-            self.loading_phase += 0.1;
-            self.renderer.render_loading(provider, self.loading_phase)
+        let settings = state.settings.read(cx).settings();
+        let stale = snapshot
+            .as_ref()
+            .is_some_and(|s| settings.is_stale(provider, s.updated_at, chrono::Utc::now()));
+
+        // Get animation state for this provider, unless motion is reduced
+        let reduce_motion = accessibility::reduce_motion(cx);
+        let key = if is_refreshing {
+            IconKey::loading(provider, self.next_loading_phase(reduce_motion))
+        } else if needs_auth {
+            IconKey::auth_required(provider)
         } else if has_error {
-            self.renderer.render_error(provider)
+            IconKey::error(provider)
         } else {
             let status_indicator = status.map(|s| s.indicator).unwrap_or(StatusIndicator::None);
 
-            self.renderer.render(
+            IconKey::usage(
                 provider,
                 snapshot.as_ref(),
                 stale,
                 Some(status_indicator),
-                animation,
+                self.animation_states
+                    .get(&provider)
+                    .filter(|_| !reduce_motion),
             )
         };
 
-        if self.merge_mode {
-            if let Some(status_item) = self.merged_status_item {
-                self.set_status_item_image(status_item, &rendered);
-            }
-        } else if let Some(&status_item) = self.status_items.get(&provider) {
-            self.set_status_item_image(status_item, &rendered);
+        let (slot, status_item) = if self.merge_mode {
+            (None, self.merged_status_item)
+        } else {
+            (Some(provider), self.status_items.get(&provider).copied())
+        };
+        let Some(status_item) = status_item else {
+            return;
+        };
+
+        let label = if self.merge_mode {
+            accessibility::describe_providers(&state.enabled_providers(cx), cx)
+        } else {
+            accessibility::describe_provider(provider, cx)
+        };
+        if self.accessibility_labels.get(&slot) != Some(&label) {
+            self.set_status_item_label(status_item, &label);
+            self.accessibility_labels.insert(slot, label);
         }
 
+        // An unchanged frame needs no new image
+        if self.displayed_icons.get(&slot) == Some(&key) {
+            return;
+        }
+
+        let icons: Vec<_> = self
+            .scaled_renderers
+            .iter_mut()
+            .map(|renderer| renderer.render_cached(key))
+            .collect();
+        let pngs: Vec<&[u8]> = icons.iter().map(|icon| icon.png()).collect();
+        self.set_status_item_image(status_item, &pngs);
+        self.displayed_icons.insert(slot, key);
+
         debug!(provider = ?provider, stale = stale, "Icon updated");
     }
 
@@ -600,9 +945,13 @@ impl SystemTray {
     /// The blink starts with the eye closed (blink_phase = 1.0) and
     /// gradually opens as tick_animations decays the phase.
     pub fn trigger_blink(&mut self, provider: ProviderKind, cx: &mut App) {
+        if accessibility::reduce_motion(cx) {
+            return;
+        }
         if let Some(state) = self.animation_states.get_mut(&provider) {
             state.blink_phase = 1.0; // Start closed
         }
+        self.ensure_animation_timer(cx);
         self.update_icon(provider, cx);
     }
 
@@ -652,28 +1001,21 @@ impl SystemTray {
 
     /// Maybe trigger a random animation if "surprise me" is enabled.
     ///
-    /// Called periodically by the animation timer. Has a chance to trigger
-    /// a random blink, wiggle, or tilt on a random provider.
+    /// Called every [`SURPRISE_INTERVAL`] by the surprise timer. Has a chance
+    /// to trigger a random blink, wiggle, or tilt on a random provider.
     fn maybe_random_animation(&mut self, cx: &mut App) {
-        if !self.surprise_me_enabled {
-            return;
-        }
-
-        // Only check every ~30 seconds
-        if self.last_random_event.elapsed() < std::time::Duration::from_secs(30) {
+        if !self.surprise_me_enabled || accessibility::reduce_motion(cx) {
             return;
         }
 
-        // 30% chance to trigger when the cooldown expires
+        // 30% chance to trigger each interval
         if rand::random::<f32>() >= 0.3 {
-            self.last_random_event = std::time::Instant::now();
             return;
         }
 
         // Pick a random enabled provider
         let providers: Vec<_> = self.animation_states.keys().copied().collect();
         if providers.is_empty() {
-            self.last_random_event = std::time::Instant::now();
             return;
         }
 
@@ -688,29 +1030,48 @@ impl SystemTray {
             }
             1 => {
                 // Wiggle
-                if let Some(state) = self.animation_states.get_mut(&provider) {
-                    state.wiggle_offset = (rand::random::<f32>() - 0.5) * 4.0;
-                    debug!(provider = ?provider, wiggle = state.wiggle_offset, "Random wiggle triggered");
-                }
+                let offset = (rand::random::<f32>() - 0.5) * 4.0;
+                debug!(provider = ?provider, wiggle = offset, "Random wiggle triggered");
+                self.trigger_wiggle(provider, offset, cx);
             }
             _ => {
                 // Tilt
-                if let Some(state) = self.animation_states.get_mut(&provider) {
-                    state.tilt_degrees = (rand::random::<f32>() - 0.5) * 10.0;
-                    debug!(provider = ?provider, tilt = state.tilt_degrees, "Random tilt triggered");
-                }
+                let degrees = (rand::random::<f32>() - 0.5) * 10.0;
+                debug!(provider = ?provider, tilt = degrees, "Random tilt triggered");
+                self.trigger_tilt(provider, degrees, cx);
             }
         }
 
-        self.last_random_event = std::time::Instant::now();
+        self.ensure_animation_timer(cx);
     }
 
-    /// Starts the animation tick timer.
+    /// Starts the animation timers.
     ///
-    /// Spawns a background task that runs at ~30fps and updates animation
-    /// states. Only performs work when animations are actually active.
+    /// Nothing ticks while the icons are still: the ~30fps frame timer runs
+    /// only while an animation is active, and the surprise timer only while
+    /// "surprise me" is enabled.
     pub fn start_animation_timer(&mut self, cx: &mut App) {
-        cx.spawn(async move |mut cx| {
+        self.sync_surprise_timer(cx);
+        self.ensure_animation_timer(cx);
+    }
+
+    /// Returns true while any animation has yet to decay.
+    fn is_animating(&self) -> bool {
+        self.animation_states.values().any(|state| {
+            state.blink_phase > 0.0 || state.wiggle_offset != 0.0 || state.tilt_degrees != 0.0
+        })
+    }
+
+    /// Starts the frame timer if an animation is active.
+    ///
+    /// The timer stops itself once every animation has decayed.
+    fn ensure_animation_timer(&mut self, cx: &mut App) {
+        if self.animation_timer_running || !self.is_animating() {
+            return;
+        }
+        self.animation_timer_running = true;
+
+        cx.spawn(async move |cx| {
             let mut last_tick = std::time::Instant::now();
 
             loop {
@@ -722,20 +1083,49 @@ impl SystemTray {
                 last_tick = now;
 
                 // Update animations in the global SystemTray
-                let _ = cx.update_global::<SystemTray, _>(|tray, cx| {
-                    tray.tick_animations(delta, cx);
-                    tray.maybe_random_animation(cx);
+                let running = cx.update(|cx| {
+                    cx.update_global::<SystemTray, _>(|tray, cx| {
+                        tray.tick_animations(delta, cx);
+                        tray.animation_timer_running = tray.is_animating();
+                        tray.animation_timer_running
+                    })
                 });
+                if !running {
+                    debug!("Animations settled, frame timer stopped");
+                    break;
+                }
             }
         })
         .detach();
+    }
+
+    /// Starts or stops the surprise timer to match the setting.
+    fn sync_surprise_timer(&mut self, cx: &mut App) {
+        if !self.surprise_me_enabled {
+            // Dropping the task cancels it
+            self.surprise_timer = None;
+            return;
+        }
+        if self.surprise_timer.is_some() {
+            return;
+        }
 
-        info!("Animation timer started (~30fps)");
+        self.surprise_timer = Some(cx.spawn(async move |cx| {
+            loop {
+                smol::Timer::after(SURPRISE_INTERVAL).await;
+                cx.update(|cx| {
+                    cx.update_global::<SystemTray, _>(|tray, cx| {
+                        tray.maybe_random_animation(cx);
+                    });
+                });
+            }
+        }));
     }
 
     /// Updates the "surprise me" (random animation) setting.
-    pub fn set_surprise_me_enabled(&mut self, enabled: bool) {
+    pub fn set_surprise_me_enabled(&mut self, enabled: bool, cx: &mut App) {
         self.surprise_me_enabled = enabled;
+        self.sync_surprise_timer(cx);
         info!(surprise_me = enabled, "Surprise me mode changed");
     }
 
@@ -751,27 +1141,56 @@ impl SystemTray {
     // ========================================================================
 
     /// Toggles merge mode.
+    ///
+    /// The status item in the first provider's place is kept and switched
+    /// over, and the others are removed or created around it in settings
+    /// order, so the menu bar doesn't flash or reshuffle.
     pub fn set_merge_mode(&mut self, merge: bool, cx: &mut App) {
         if self.merge_mode == merge {
             return;
         }
-
-        // Remove existing status items
-        self.remove_all_status_items();
-
         self.merge_mode = merge;
 
-        // Create new status items in the appropriate mode
+        let providers = cx.global::<AppState>().enabled_providers(cx);
+        let first = providers.first().copied();
+
         if merge {
-            self.create_merged_status_item(cx);
+            let kept = first.and_then(|provider| self.status_items.remove(&provider));
+            let others: Vec<_> = self.status_items.keys().copied().collect();
+            for provider in others {
+                self.remove_status_item(provider);
+            }
+
+            match kept {
+                Some(status_item) => {
+                    self.clear_slot(first);
+                    self.attach_delegate(status_item, None);
+                    self.merged_status_item = Some(status_item);
+                }
+                None => self.create_merged_status_item(cx),
+            }
         } else {
-            let state = cx.global::<AppState>();
-            let providers = state.enabled_providers(cx);
-            for provider in providers {
-                self.create_status_item(provider, cx);
+            self.clear_slot(None);
+            if let Some(status_item) = self.merged_status_item.take() {
+                match first {
+                    Some(provider) => {
+                        self.attach_delegate(status_item, Some(provider));
+                        self.status_items.insert(provider, status_item);
+                    }
+                    None => remove_status_item_from_bar(status_item),
+                }
+            }
+
+            // New items are placed left of the existing ones, so creating
+            // them in order keeps the first provider closest to the clock
+            for &provider in &providers {
+                if !self.status_items.contains_key(&provider) {
+                    self.create_status_item(provider, cx);
+                }
             }
         }
 
+        self.update_all(cx);
         info!(merge_mode = merge, "Merge mode changed");
     }
 
@@ -789,66 +1208,89 @@ impl SystemTray {
     pub fn remove_provider(&mut self, provider: ProviderKind) {
         // Clean up animation state
         self.animation_states.remove(&provider);
+        self.remove_status_item(provider);
+    }
 
+    /// Removes a provider's status item from the menu bar.
+    fn remove_status_item(&mut self, provider: ProviderKind) {
+        self.clear_slot(Some(provider));
         if let Some(status_item) = self.status_items.remove(&provider) {
-            unsafe {
-                let status_bar: id = msg_send![class!(NSStatusBar), systemStatusBar];
-                let _: () = msg_send![status_bar, removeStatusItem: status_item];
-                let _: () = msg_send![status_item, release];
-            }
+            remove_status_item_from_bar(status_item);
         }
     }
 
     /// Removes all status items from the menu bar.
     fn remove_all_status_items(&mut self) {
-        unsafe {
-            let status_bar: id = msg_send![class!(NSStatusBar), systemStatusBar];
+        self.displayed_icons.clear();
+        self.accessibility_labels.clear();
 
-            // Remove individual provider items
-            for (_, status_item) in self.status_items.drain() {
-                let _: () = msg_send![status_bar, removeStatusItem: status_item];
-                let _: () = msg_send![status_item, release];
-            }
+        // Remove individual provider items
+        for (_, status_item) in self.status_items.drain() {
+            remove_status_item_from_bar(status_item);
+        }
 
-            // Remove merged item if present
-            if let Some(status_item) = self.merged_status_item.take() {
-                let _: () = msg_send![status_bar, removeStatusItem: status_item];
-                let _: () = msg_send![status_item, release];
-            }
+        // Remove merged item if present
+        if let Some(status_item) = self.merged_status_item.take() {
+            remove_status_item_from_bar(status_item);
+        }
 
-            // Release all delegates
-            for delegate in self.delegates.drain(..) {
-                let _: () = msg_send![delegate, release];
-            }
+        // Release all delegates
+        for (_, delegate) in self.delegates.drain() {
+            release_object(delegate);
         }
     }
 
     /// Toggles the tray menu.
     pub fn toggle_menu(&mut self, provider: Option<ProviderKind>, cx: &mut App) {
-        if self.menu_window.is_some() {
+        // The popup may have closed itself (e.g., when detached into a pinned panel)
+        let menu_open = self
+            .menu_window
+            .is_some_and(|handle| cx.update_window(handle, |_, _, _| {}).is_ok());
+        if menu_open {
             self.close_menu(cx);
         } else {
-            self.open_menu(provider, cx);
+            self.open_menu(provider, false, cx);
+        }
+    }
+
+    /// Toggles the tray menu centered under the first status item, for the
+    /// global shortcut.
+    pub fn toggle_menu_from_shortcut(&mut self, cx: &mut App) {
+        let menu_open = self
+            .menu_window
+            .is_some_and(|handle| cx.update_window(handle, |_, _, _| {}).is_ok());
+        if menu_open {
+            self.close_menu(cx);
+        } else {
+            self.open_menu(None, true, cx);
         }
     }
 
     /// Opens the tray menu as a GPUI popup window with native macOS panel styling.
     ///
-    /// Positions the popup directly below the clicked status item, right-aligned.
+    /// Positions the popup directly below the clicked status item, right-aligned
+    /// (or centered, when opened from the keyboard). Without a provider, the
+    /// first status item is used.
     /// Uses blurred background for native macOS vibrancy effect.
     ///
     /// COORDINATE SYSTEM NOTES:
     /// - macOS NSScreen uses bottom-left origin (Y increases upward)
     /// - GPUI uses top-left origin (Y increases downward)
     /// - We must convert: gpui_y = screen_height - macos_y - rect_height
-    fn open_menu(&mut self, provider: Option<ProviderKind>, cx: &mut App) {
+    fn open_menu(&mut self, provider: Option<ProviderKind>, centered: bool, cx: &mut App) {
         info!(provider = ?provider, "Opening GPUI popup menu...");
         self.close_menu(cx);
 
-        let menu = TrayMenu::new(provider);
+        let menu = TrayMenu::restored(provider, cx);
+        let settings = cx.global::<AppState>().settings.read(cx);
+        let theme_mode = settings.theme_mode();
+        let background = settings.window_background();
+        let material = settings.vibrancy_material();
 
-        let menu_width = 340.0_f32; // Match MenuPanel width
-        let menu_height = 600.0_f32; // Match max_h in MenuPanel
+        // Use the user's persisted panel size
+        let panel_size = menu::panel_size(cx);
+        let menu_width = f32::from(panel_size.width);
+        let menu_height = f32::from(panel_size.height);
 
         // Get screen dimensions for coordinate conversion (macOS -> GPUI)
         let (screen_width, screen_height) = unsafe {
@@ -858,7 +1300,13 @@ impl SystemTray {
         };
 
         // Get status item position (macOS coordinates - origin at bottom-left)
-        let frame_info = self.get_status_item_frame(provider);
+        let anchor = provider.or_else(|| {
+            cx.global::<AppState>()
+                .enabled_providers(cx)
+                .first()
+                .copied()
+        });
+        let frame_info = self.get_status_item_frame(anchor);
         debug!(frame = ?frame_info, screen_height = screen_height, "Status item frame (macOS coords)");
 
         let (origin_x, origin_y) = if let Some((mac_x, mac_y, item_w, item_h)) = frame_info {
@@ -875,8 +1323,14 @@ impl SystemTray {
             // Position menu just below the status item
             let menu_y = status_item_bottom_gpui + 2.0; // 2px gap below icon
 
-            // Right-align menu with status item's right edge
-            let menu_x = (mac_x + item_w - menu_width).max(10.0);
+            let menu_x = if centered {
+                (mac_x + (item_w - menu_width) / 2.0)
+                    .min(screen_width - menu_width - 10.0)
+                    .max(10.0)
+            } else {
+                // Right-align menu with status item's right edge
+                (mac_x + item_w - menu_width).max(10.0)
+            };
 
             info!(
                 mac_coords = ?(mac_x, mac_y, item_w, item_h),
@@ -911,18 +1365,26 @@ impl SystemTray {
             kind: WindowKind::PopUp,
             is_movable: false,
             display_id: None,
-            window_background: WindowBackgroundAppearance::Blurred,
+            window_background: theme::window_background_appearance(theme_mode, background),
             app_id: None,
-            window_min_size: None,
+            window_min_size: Some(menu::MIN_PANEL_SIZE),
             window_decorations: Some(WindowDecorations::Client),
             is_minimizable: false,
-            is_resizable: false,
+            is_resizable: true,
             tabbing_identifier: None,
         };
 
-        match cx.open_window(window_options, |_window, cx| cx.new(|_| menu)) {
+        match cx.open_window(window_options, |window, cx| {
+            theme::apply_vibrancy_material(window, material);
+            cx.new(|cx| {
+                menu::remember_panel_size(window, cx);
+                theme::follow_system_appearance(window, cx);
+                menu
+            })
+        }) {
             Ok(handle) => {
                 self.menu_window = Some(handle.into());
+                self.start_outside_click_monitor();
                 info!(x = origin_x, y = origin_y, "✅ Menu opened at position");
             }
             Err(e) => {
@@ -975,6 +1437,7 @@ impl SystemTray {
 
     /// Closes the tray menu.
     fn close_menu(&mut self, cx: &mut App) {
+        self.stop_outside_click_monitor();
         if let Some(handle) = self.menu_window.take() {
             // Actually close the GPUI window, not just drop the handle
             let _ = cx.update_window(handle, |_, window, _| {
@@ -983,6 +1446,47 @@ impl SystemTray {
         }
     }
 
+    /// Starts watching for clicks in other apps, which close the menu like
+    /// a native menu bar extra's.
+    ///
+    /// A global monitor only sees events sent to other apps, so clicks in
+    /// the menu, the detached panel and our own status items are left alone.
+    fn start_outside_click_monitor(&mut self) {
+        if self.outside_click_monitor.is_some() {
+            return;
+        }
+
+        let sender = self.outside_click_sender.clone();
+        let handler = ConcreteBlock::new(move |_event: id| {
+            let _ = sender.try_send(());
+        })
+        .copy();
+
+        unsafe {
+            let monitor: id = msg_send![
+                class!(NSEvent),
+                addGlobalMonitorForEventsMatchingMask: MOUSE_DOWN_MASK
+                handler: &*handler
+            ];
+            if monitor == nil {
+                warn!("Failed to install click-outside monitor");
+                return;
+            }
+            let _: () = msg_send![monitor, retain];
+            self.outside_click_monitor = Some(monitor);
+        }
+    }
+
+    /// Stops watching for clicks in other apps.
+    fn stop_outside_click_monitor(&mut self) {
+        if let Some(monitor) = self.outside_click_monitor.take() {
+            unsafe {
+                let _: () = msg_send![class!(NSEvent), removeMonitor: monitor];
+                let _: () = msg_send![monitor, release];
+            }
+        }
+    }
+
     /// Gets the icon PNG for a provider.
     pub fn get_icon_png(&self, provider: ProviderKind, cx: &App) -> Option<Vec<u8>> {
         let state = cx.global::<AppState>();
@@ -998,6 +1502,7 @@ impl SystemTray {
 impl Drop for SystemTray {
     fn drop(&mut self) {
         // Clean up all native status items
+        self.stop_outside_click_monitor();
         self.remove_all_status_items();
         info!("System tray cleaned up");
     }
@@ -1023,7 +1528,7 @@ impl SystemTray {
         let renderer = IconRenderer::new().with_mode(RenderMode::Colored);
 
         // Create channel for Linux tray events
-        let (linux_event_sender, linux_event_receiver) = mpsc::channel();
+        let (linux_event_sender, linux_event_receiver) = smol::channel::unbounded();
 
         // Initialize animation states for all providers
         let mut animation_states = HashMap::new();
@@ -1036,12 +1541,16 @@ impl SystemTray {
             linux_event_sender,
             linux_event_receiver: Some(linux_event_receiver),
             renderer,
+            displayed_icons: HashMap::new(),
+            accessibility_labels: HashMap::new(),
+            icon_revisions: HashMap::new(),
             merge_mode,
             menu_window: None,
             loading_phase: 0.0,
             animation_states,
             surprise_me_enabled,
-            last_random_event: std::time::Instant::now(),
+            animation_timer_running: false,
+            surprise_timer: None,
         };
 
         // Create the SNI tray
@@ -1126,8 +1635,8 @@ impl SystemTray {
 
     /// Starts the event listener for Linux tray events.
     ///
-    /// Spawns a background task that polls the event channel and
-    /// handles tray actions (clicks, menu items).
+    /// Spawns a task that waits on the event channel and handles tray
+    /// actions (clicks, menu items) as they arrive.
     pub fn start_click_listener(&mut self, cx: &mut App) {
         let Some(receiver) = self.linux_event_receiver.take() else {
             warn!("Linux event listener already started");
@@ -1135,45 +1644,43 @@ impl SystemTray {
         };
 
         cx.spawn(async move |cx| {
-            loop {
-                // Check for events (non-blocking)
-                while let Ok(event) = receiver.try_recv() {
-                    debug!(event = ?event, "Processing Linux tray event");
-                    match event {
-                        LinuxTrayEvent::Activate { x, y } => {
-                            info!(x = x, y = y, "Tray icon activated at position");
-                            let _ = cx.update_global::<SystemTray, _>(|tray, cx| {
-                                tray.toggle_menu_at(None, Some((x, y)), cx);
-                            });
-                        }
-                        LinuxTrayEvent::OpenMenu => {
-                            let _ = cx.update_global::<SystemTray, _>(|tray, cx| {
-                                tray.toggle_menu_at(None, None, cx);
-                            });
-                        }
-                        LinuxTrayEvent::Refresh => {
-                            info!("Refresh requested from tray menu");
-                            let _ = cx.update_global::<AppState, _>(|state, cx| {
-                                state.refresh_all(cx);
-                            });
-                        }
-                        LinuxTrayEvent::Settings => {
-                            info!("Settings requested from tray menu");
-                            let _ = cx.update(|cx| {
-                                crate::actions::open_settings(cx);
-                            });
-                        }
-                        LinuxTrayEvent::Quit => {
-                            info!("Quit requested from tray menu");
-                            let _ = cx.update(|cx| {
-                                cx.quit();
-                            });
-                        }
+            // Parks until an event arrives; ends when the tray is dropped
+            while let Ok(event) = receiver.recv().await {
+                debug!(event = ?event, "Processing Linux tray event");
+                match event {
+                    LinuxTrayEvent::Activate { x, y } => {
+                        info!(x = x, y = y, "Tray icon activated at position");
+                        let _ = cx.update_global::<SystemTray, _>(|tray, cx| {
+                            tray.toggle_menu_at(None, Some((x, y)), cx);
+                        });
+                    }
+                    LinuxTrayEvent::OpenMenu => {
+                        let _ = cx.update_global::<SystemTray, _>(|tray, cx| {
+                            tray.toggle_menu_at(None, None, cx);
+                        });
+                    }
+                    LinuxTrayEvent::Refresh => {
+                        info!("Refresh requested from tray menu");
+                        let _ = cx.update(crate::refresh::trigger_refresh);
+                    }
+                    LinuxTrayEvent::Dashboard => {
+                        info!("Usage dashboard requested from tray menu");
+                        let _ = cx.update(|cx| {
+                            crate::windows::open_dashboard_window(cx);
+                        });
+                    }
+                    LinuxTrayEvent::Settings => {
+                        info!("Settings requested from tray menu");
+                        let _ = cx.update(|cx| {
+                            crate::actions::open_settings(cx);
+                        });
+                    }
+                    LinuxTrayEvent::Quit => {
+                        info!("Quit requested from tray menu");
+                        // The tray menu can't tell whether Alt is held
+                        let _ = cx.update(|cx| crate::menu::request_quit(false, cx));
                     }
                 }
-
-                // Sleep briefly to avoid busy-waiting
-                smol::Timer::after(std::time::Duration::from_millis(16)).await;
             }
         })
         .detach();
@@ -1187,36 +1694,56 @@ impl SystemTray {
         let snapshot = state.get_snapshot(provider, cx);
         let is_refreshing = state.is_provider_refreshing(provider, cx);
         let has_error = state.get_error(provider, cx).is_some();
+        let needs_auth = state.needs_auth(provider, cx);
         let status = state.get_status(provider, cx);
 
-        // Check if snapshot is stale (older than 10 minutes)
-        let stale = snapshot.as_ref().is_some_and(|s| {
-            let threshold = chrono::Duration::minutes(10);
-            chrono::Utc::now() - s.updated_at > threshold
-        });
-
-        // Get animation state for this provider
-        let animation = self.animation_states.get(&provider);
-
-        let rendered = if is_refreshing {
-            self.loading_phase += 0.1;
-            self.renderer.render_loading(provider, self.loading_phase)
+        let settings = state.settings.read(cx).settings();
+        let stale = snapshot
+            .as_ref()
+            .is_some_and(|s| settings.is_stale(provider, s.updated_at, chrono::Utc::now()));
+
+        // Get animation state for this provider, unless motion is reduced
+        let reduce_motion = accessibility::reduce_motion(cx);
+        let key = if is_refreshing {
+            IconKey::loading(provider, self.next_loading_phase(reduce_motion))
+        } else if needs_auth {
+            IconKey::auth_required(provider)
         } else if has_error {
-            self.renderer.render_error(provider)
+            IconKey::error(provider)
         } else {
             let status_indicator = status.map(|s| s.indicator).unwrap_or(StatusIndicator::None);
 
-            self.renderer.render(
+            IconKey::usage(
                 provider,
                 snapshot.as_ref(),
                 stale,
                 Some(status_indicator),
-                animation,
+                self.animation_states
+                    .get(&provider)
+                    .filter(|_| !reduce_motion),
             )
         };
 
+        let label = accessibility::describe_providers(&state.enabled_providers(cx), cx);
+        if self.accessibility_labels.get(&None) != Some(&label) {
+            if let Some(handle) = &self.sni_handle {
+                let tray_label = label.clone();
+                handle.update(|tray| {
+                    tray.label = tray_label;
+                });
+            }
+            self.accessibility_labels.insert(None, label);
+        }
+
+        // An unchanged frame needs no new image
+        if self.displayed_icons.get(&None) == Some(&key) {
+            return;
+        }
+        let icon = self.renderer.render_cached(key);
+        self.displayed_icons.insert(None, key);
+
         // Convert to ARGB for ksni
-        let (width, height, mut pixels) = rendered.to_rgba_pixels();
+        let (width, height, mut pixels) = icon.rendered.to_rgba_pixels();
         for pixel in pixels.chunks_exact_mut(4) {
             let [r, g, b, a] = [pixel[0], pixel[1], pixel[2], pixel[3]];
             pixel[0] = a;
@@ -1258,9 +1785,13 @@ impl SystemTray {
 
     /// Triggers a blink animation for a provider.
     pub fn trigger_blink(&mut self, provider: ProviderKind, cx: &mut App) {
+        if accessibility::reduce_motion(cx) {
+            return;
+        }
         if let Some(state) = self.animation_states.get_mut(&provider) {
             state.blink_phase = 1.0;
         }
+        self.ensure_animation_timer(cx);
         self.update_icon(provider, cx);
     }
 
@@ -1302,22 +1833,16 @@ impl SystemTray {
 
     /// Maybe trigger a random animation if "surprise me" is enabled.
     fn maybe_random_animation(&mut self, cx: &mut App) {
-        if !self.surprise_me_enabled {
-            return;
-        }
-
-        if self.last_random_event.elapsed() < std::time::Duration::from_secs(30) {
+        if !self.surprise_me_enabled || accessibility::reduce_motion(cx) {
             return;
         }
 
         if rand::random::<f32>() >= 0.3 {
-            self.last_random_event = std::time::Instant::now();
             return;
         }
 
         let providers: Vec<_> = self.animation_states.keys().copied().collect();
         if providers.is_empty() {
-            self.last_random_event = std::time::Instant::now();
             return;
         }
 
@@ -1329,25 +1854,41 @@ impl SystemTray {
                 self.trigger_blink(provider, cx);
             }
             1 => {
-                if let Some(state) = self.animation_states.get_mut(&provider) {
-                    state.wiggle_offset = (rand::random::<f32>() - 0.5) * 4.0;
-                    debug!(provider = ?provider, wiggle = state.wiggle_offset, "Random wiggle triggered");
-                }
+                let offset = (rand::random::<f32>() - 0.5) * 4.0;
+                debug!(provider = ?provider, wiggle = offset, "Random wiggle triggered");
+                self.trigger_wiggle(provider, offset, cx);
             }
             _ => {
-                if let Some(state) = self.animation_states.get_mut(&provider) {
-                    state.tilt_degrees = (rand::random::<f32>() - 0.5) * 10.0;
-                    debug!(provider = ?provider, tilt = state.tilt_degrees, "Random tilt triggered");
-                }
+                let degrees = (rand::random::<f32>() - 0.5) * 10.0;
+                debug!(provider = ?provider, tilt = degrees, "Random tilt triggered");
+                self.trigger_tilt(provider, degrees, cx);
             }
         }
 
-        self.last_random_event = std::time::Instant::now();
+        self.ensure_animation_timer(cx);
     }
 
-    /// Starts the animation tick timer.
+    /// Starts the animation timers (see the macOS implementation).
     pub fn start_animation_timer(&mut self, cx: &mut App) {
-        cx.spawn(async move |mut cx| {
+        self.sync_surprise_timer(cx);
+        self.ensure_animation_timer(cx);
+    }
+
+    /// Returns true while any animation has yet to decay.
+    fn is_animating(&self) -> bool {
+        self.animation_states.values().any(|state| {
+            state.blink_phase > 0.0 || state.wiggle_offset != 0.0 || state.tilt_degrees != 0.0
+        })
+    }
+
+    /// Starts the frame timer if an animation is active.
+    fn ensure_animation_timer(&mut self, cx: &mut App) {
+        if self.animation_timer_running || !self.is_animating() {
+            return;
+        }
+        self.animation_timer_running = true;
+
+        cx.spawn(async move |cx| {
             let mut last_tick = std::time::Instant::now();
 
             loop {
@@ -1357,20 +1898,47 @@ impl SystemTray {
                 let delta = (now - last_tick).as_secs_f32();
                 last_tick = now;
 
-                let _ = cx.update_global::<SystemTray, _>(|tray, cx| {
-                    tray.tick_animations(delta, cx);
-                    tray.maybe_random_animation(cx);
+                let running = cx.update(|cx| {
+                    cx.update_global::<SystemTray, _>(|tray, cx| {
+                        tray.tick_animations(delta, cx);
+                        tray.animation_timer_running = tray.is_animating();
+                        tray.animation_timer_running
+                    })
                 });
+                if !running {
+                    break;
+                }
             }
         })
         .detach();
+    }
 
-        info!("Animation timer started (~30fps)");
+    /// Starts or stops the surprise timer to match the setting.
+    fn sync_surprise_timer(&mut self, cx: &mut App) {
+        if !self.surprise_me_enabled {
+            self.surprise_timer = None;
+            return;
+        }
+        if self.surprise_timer.is_some() {
+            return;
+        }
+
+        self.surprise_timer = Some(cx.spawn(async move |cx| {
+            loop {
+                smol::Timer::after(SURPRISE_INTERVAL).await;
+                cx.update(|cx| {
+                    cx.update_global::<SystemTray, _>(|tray, cx| {
+                        tray.maybe_random_animation(cx);
+                    });
+                });
+            }
+        }));
     }
 
     /// Updates the "surprise me" (random animation) setting.
-    pub fn set_surprise_me_enabled(&mut self, enabled: bool) {
+    pub fn set_surprise_me_enabled(&mut self, enabled: bool, cx: &mut App) {
         self.surprise_me_enabled = enabled;
+        self.sync_surprise_timer(cx);
         info!(surprise_me = enabled, "Surprise me mode changed");
     }
 
@@ -1407,6 +1975,14 @@ impl SystemTray {
         self.toggle_menu_at(provider, None, cx);
     }
 
+    /// Toggles the tray menu for the global shortcut.
+    ///
+    /// SNI doesn't say where the tray icon is, so the menu opens in the
+    /// usual fallback spot.
+    pub fn toggle_menu_from_shortcut(&mut self, cx: &mut App) {
+        self.toggle_menu_at(None, None, cx);
+    }
+
     /// Toggles the tray menu with optional click position.
     pub fn toggle_menu_at(
         &mut self,
@@ -1414,8 +1990,15 @@ impl SystemTray {
         click_pos: Option<(i32, i32)>,
         cx: &mut App,
     ) {
-        if self.menu_window.is_some() {
+        // The popup may have closed itself (e.g., when detached into a pinned panel)
+        let menu_open = self
+            .menu_window
+            .is_some_and(|handle| cx.update_window(handle, |_, _, _| {}).is_ok());
+        if menu_open {
             self.close_menu(cx);
+        } else if menu::dismissed_recently() {
+            // The click that took focus from the popup, which closed it
+            debug!("Menu closed on focus loss just now, not reopening");
         } else {
             self.open_menu_at(provider, click_pos, cx);
         }
@@ -1425,6 +2008,13 @@ impl SystemTray {
     ///
     /// On Linux, we position the window near the click position (which is near the tray icon).
     /// The menu appears below and to the left of the click point so it doesn't obscure the icon.
+    ///
+    /// Wayland compositors ignore window positions, so there the menu is a
+    /// layer-shell surface anchored to the screen corner nearest the tray
+    /// instead, falling back to a plain popup on compositors without layer
+    /// shell (e.g. GNOME).
+    ///
+    /// The menu closes when it loses focus, like the macOS panel.
     fn open_menu_at(
         &mut self,
         provider: Option<ProviderKind>,
@@ -1434,21 +2024,20 @@ impl SystemTray {
         info!(provider = ?provider, click_pos = ?click_pos, "Opening GPUI popup menu (Linux)...");
         self.close_menu(cx);
 
-        let menu = TrayMenu::new(provider);
+        // Use the user's persisted panel size
+        let panel_size = menu::panel_size(cx);
+        let menu_width = f32::from(panel_size.width);
+        let menu_height = f32::from(panel_size.height);
 
-        let menu_width = 340.0_f32;
-        let menu_height = 600.0_f32;
+        // Get screen dimensions
+        let screen = cx.primary_display().map(|d| {
+            let b = d.bounds();
+            (f32::from(b.size.width), f32::from(b.size.height))
+        });
 
         // Position menu near the click (tray icon location)
         let (origin_x, origin_y) = if let Some((click_x, click_y)) = click_pos {
-            // Get screen dimensions
-            let (screen_width, screen_height) = cx
-                .primary_display()
-                .map(|d| {
-                    let b = d.bounds();
-                    (f32::from(b.size.width), f32::from(b.size.height))
-                })
-                .unwrap_or((1920.0, 1080.0));
+            let (screen_width, screen_height) = screen.unwrap_or((1920.0, 1080.0));
 
             // Position menu to the left of click point, keeping on screen
             let x = (click_x as f32 - menu_width).clamp(10.0, screen_width - menu_width - 10.0);
@@ -1467,10 +2056,8 @@ impl SystemTray {
                 "Positioning menu above bottom panel"
             );
             (x, y)
-        } else if let Some(display) = cx.primary_display() {
+        } else if let Some((screen_width, _)) = screen {
             // Fallback: top-right of screen
-            let screen_bounds = display.bounds();
-            let screen_width: f32 = screen_bounds.size.width.into();
             let x = screen_width - menu_width - 10.0;
             let y = 30.0_f32;
             info!(
@@ -1491,25 +2078,52 @@ impl SystemTray {
             size(px(menu_width), px(menu_height)),
         );
 
-        let window_options = WindowOptions {
+        let window_options = |kind: WindowKind| WindowOptions {
             titlebar: None,
             window_bounds: Some(WindowBounds::Windowed(bounds)),
             focus: true,
             show: true,
-            kind: WindowKind::PopUp,
+            kind,
             is_movable: false,
             display_id: None,
             // Linux doesn't support blur, so we use opaque background
             window_background: WindowBackgroundAppearance::Opaque,
             app_id: Some("exactobar".into()),
-            window_min_size: None,
+            window_min_size: Some(menu::MIN_PANEL_SIZE),
             window_decorations: Some(WindowDecorations::Client),
             is_minimizable: false,
-            is_resizable: false,
+            is_resizable: true,
             tabbing_identifier: None,
         };
+        let open = |options: WindowOptions, cx: &mut App| {
+            let menu = TrayMenu::restored(provider, cx);
+            cx.open_window(options, |window, cx| {
+                cx.new(|cx| {
+                    menu::remember_panel_size(window, cx);
+                    menu::close_on_focus_loss(window, cx);
+                    theme::follow_system_appearance(window, cx);
+                    menu
+                })
+            })
+        };
 
-        match cx.open_window(window_options, |_window, cx| cx.new(|_| menu)) {
+        let mut result = None;
+        if is_wayland_session() {
+            let anchor = PopupAnchor::for_click(click_pos, screen, menu_width);
+            info!(anchor = ?anchor, "Anchoring menu with layer shell (Wayland)");
+            match open(
+                window_options(WindowKind::LayerShell(anchor.layer_shell_options())),
+                cx,
+            ) {
+                Ok(handle) => result = Some(Ok(handle)),
+                Err(e) => {
+                    warn!(error = ?e, "Layer shell unavailable, opening a plain popup");
+                }
+            }
+        }
+        let result = result.unwrap_or_else(|| open(window_options(WindowKind::PopUp), cx));
+
+        match result {
             Ok(handle) => {
                 self.menu_window = Some(handle.into());
                 info!(
@@ -1551,3 +2165,38 @@ impl Drop for SystemTray {
         info!("Linux system tray cleaned up");
     }
 }
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_popup_anchor_for_click() {
+        let screen = Some((1920.0, 1080.0));
+
+        // Bottom-right tray, as on KDE's default panel
+        let anchor = PopupAnchor::for_click(Some((1800, 1070)), screen, 360.0);
+        assert!(!anchor.top);
+        assert!(anchor.right);
+        assert!((anchor.inset - 120.0).abs() < f32::EPSILON);
+
+        // Top-left tray
+        let anchor = PopupAnchor::for_click(Some((40, 12)), screen, 360.0);
+        assert!(anchor.top);
+        assert!(!anchor.right);
+        assert!((anchor.inset - 40.0).abs() < f32::EPSILON);
+
+        // Clicks at the very edge keep the popup on screen
+        let anchor = PopupAnchor::for_click(Some((1919, 5)), screen, 360.0);
+        assert!((anchor.inset - POPUP_EDGE_MARGIN).abs() < f32::EPSILON);
+
+        // Unknown click positions fall back to the top-right corner
+        let corner = PopupAnchor::for_click(Some((0, 0)), screen, 360.0);
+        assert_eq!(corner, PopupAnchor::for_click(None, screen, 360.0));
+        assert!(corner.top && corner.right);
+    }
+}
@@ -3,10 +3,14 @@
 //! Checks GitHub Releases for new versions and offers to update.
 //! This module uses the GitHub API to fetch release information
 //! and compares semver versions to determine if an update is available.
+//! Which releases count depends on the user's [`UpdateChannel`]: stable
+//! sees published releases only, beta adds pre-releases, and nightly adds
+//! nightly builds.
 //!
 //! Note: We use `reqwest::blocking` with `smol::unblock()` because GPUI uses
 //! the smol async runtime, not Tokio (which reqwest's async client requires).
 
+use exactobar_store::UpdateChannel;
 use semver::Version;
 use std::sync::atomic::{AtomicBool, Ordering};
 use tracing::{debug, error, info, warn};
@@ -20,6 +24,9 @@ const GITHUB_REPO: &str = "exactobar";
 /// Current version from Cargo.toml (set at compile time)
 pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Number of recent releases fetched when looking for updates.
+const RELEASES_PER_PAGE: usize = 30;
+
 /// Global flag to prevent concurrent update checks.
 /// Uses atomic operations for thread-safe access.
 static CHECKING_UPDATE: AtomicBool = AtomicBool::new(false);
@@ -41,6 +48,8 @@ pub enum UpdateCheckResult {
         release_url: String,
         /// Direct download URL for macOS asset (if available).
         download_url: Option<String>,
+        /// Archive for this platform that can be installed in place (if published).
+        asset: Option<UpdateAsset>,
         /// Release notes/changelog (if available).
         release_notes: Option<String>,
     },
@@ -50,11 +59,36 @@ pub enum UpdateCheckResult {
     Error(String),
 }
 
+/// A release archive for this platform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateAsset {
+    /// File name of the archive (e.g., "exactobar-macos-arm64-v0.3.0.tar.gz").
+    pub name: String,
+    /// Download URL of the archive.
+    pub url: String,
+    /// Download URL of the archive's SHA-256 checksum file (if published).
+    pub checksum_url: Option<String>,
+    /// Binary diff from the running version (if published).
+    pub delta: Option<DeltaAsset>,
+}
+
+/// A bsdiff patch that turns the running app binary into the new one.
+///
+/// Only published between consecutive releases, so most users one version
+/// behind can skip downloading the full archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeltaAsset {
+    /// Download URL of the patch.
+    pub url: String,
+    /// Download URL of the SHA-256 checksum of the patched binary.
+    pub checksum_url: String,
+}
+
 // ============================================================================
 // Public API
 // ============================================================================
 
-/// Checks for updates asynchronously.
+/// Checks for updates on the given release channel asynchronously.
 ///
 /// This function is safe to call from any context - it prevents
 /// concurrent update checks using an atomic flag.
@@ -63,13 +97,13 @@ pub enum UpdateCheckResult {
 ///
 /// An `UpdateCheckResult` indicating whether an update is available,
 /// the app is up to date, or an error occurred.
-pub async fn check_for_updates() -> UpdateCheckResult {
+pub async fn check_for_updates(channel: UpdateChannel) -> UpdateCheckResult {
     // Prevent concurrent checks using atomic swap
     if CHECKING_UPDATE.swap(true, Ordering::SeqCst) {
         return UpdateCheckResult::Error("Update check already in progress".to_string());
     }
 
-    let result = do_check_for_updates().await;
+    let result = do_check_for_updates(channel).await;
 
     // Reset the flag when done
     CHECKING_UPDATE.store(false, Ordering::SeqCst);
@@ -149,19 +183,19 @@ pub fn show_update_notification(current: &str, latest: &str) {
 ///
 /// Uses `smol::unblock` to run the blocking HTTP request in a thread pool,
 /// avoiding conflicts between smol (GPUI's runtime) and Tokio (reqwest's runtime).
-async fn do_check_for_updates() -> UpdateCheckResult {
-    info!("Checking for updates...");
+async fn do_check_for_updates(channel: UpdateChannel) -> UpdateCheckResult {
+    info!(channel = %channel, "Checking for updates...");
 
     // Run the blocking HTTP request in a thread pool
-    smol::unblock(|| do_check_for_updates_blocking()).await
+    smol::unblock(move || do_check_for_updates_blocking(channel)).await
 }
 
 /// Blocking implementation of the update check.
 ///
 /// This runs in a thread pool via `smol::unblock`.
-fn do_check_for_updates_blocking() -> UpdateCheckResult {
+fn do_check_for_updates_blocking(channel: UpdateChannel) -> UpdateCheckResult {
     // Build HTTP client with appropriate user agent
-    let client = match reqwest::blocking::Client::builder()
+    let client = match exactobar_fetch::network::blocking_client_builder()
         .user_agent(format!("ExactoBar/{}", CURRENT_VERSION))
         .timeout(std::time::Duration::from_secs(10))
         .build()
@@ -173,13 +207,13 @@ fn do_check_for_updates_blocking() -> UpdateCheckResult {
         }
     };
 
-    // Fetch latest release from GitHub API
+    // Fetch recent releases; `/releases/latest` never returns pre-releases
     let url = format!(
-        "https://api.github.com/repos/{}/{}/releases/latest",
-        GITHUB_OWNER, GITHUB_REPO
+        "https://api.github.com/repos/{}/{}/releases?per_page={}",
+        GITHUB_OWNER, GITHUB_REPO, RELEASES_PER_PAGE
     );
 
-    debug!(url = url, "Fetching latest release info");
+    debug!(url = url, "Fetching release info");
 
     let response = match client.get(&url).send() {
         Ok(r) => r,
@@ -197,7 +231,7 @@ fn do_check_for_updates_blocking() -> UpdateCheckResult {
     }
 
     // Parse JSON response
-    let release: serde_json::Value = match response.json() {
+    let releases: Vec<serde_json::Value> = match response.json() {
         Ok(j) => j,
         Err(e) => {
             error!(error = ?e, "Failed to parse release response");
@@ -205,16 +239,6 @@ fn do_check_for_updates_blocking() -> UpdateCheckResult {
         }
     };
 
-    // Extract version from tag_name (e.g., "v0.2.0" -> "0.2.0")
-    let tag_name = release["tag_name"].as_str().unwrap_or("");
-    let latest_version = tag_name.trim_start_matches('v');
-
-    debug!(
-        current = CURRENT_VERSION,
-        latest = latest_version,
-        "Version comparison"
-    );
-
     // Parse and compare versions using semver
     let current = match Version::parse(CURRENT_VERSION) {
         Ok(v) => v,
@@ -224,31 +248,42 @@ fn do_check_for_updates_blocking() -> UpdateCheckResult {
         }
     };
 
-    let latest = match Version::parse(latest_version) {
-        Ok(v) => v,
-        Err(e) => {
-            warn!(error = ?e, version = latest_version, "Invalid latest version");
-            return UpdateCheckResult::Error(format!("Invalid latest version: {}", latest_version));
-        }
+    let Some((latest, release)) = latest_release(&releases, channel) else {
+        info!(channel = %channel, "No releases found on channel");
+        return UpdateCheckResult::UpToDate;
     };
 
+    debug!(
+        current = CURRENT_VERSION,
+        latest = %latest,
+        channel = %channel,
+        "Version comparison"
+    );
+
     // Compare versions - is there a newer one?
     if latest > current {
         // Find the macOS asset download URL from release assets
-        let download_url = extract_macos_download_url(&release);
+        let download_url = extract_macos_download_url(release);
+        let asset = platform_suffix()
+            .and_then(|suffix| extract_platform_asset(release, suffix, CURRENT_VERSION));
 
         info!(
             current = CURRENT_VERSION,
-            latest = latest_version,
+            latest = %latest,
             "Update available!"
         );
 
+        // Notes for every release since ours, falling back to just the latest
+        let release_notes = changelog_markdown(&releases, &current, channel)
+            .or_else(|| release["body"].as_str().map(|s| s.to_string()));
+
         UpdateCheckResult::UpdateAvailable {
             current: CURRENT_VERSION.to_string(),
-            latest: latest_version.to_string(),
+            latest: latest.to_string(),
             release_url: release["html_url"].as_str().unwrap_or("").to_string(),
             download_url,
-            release_notes: release["body"].as_str().map(|s| s.to_string()),
+            asset,
+            release_notes,
         }
     } else {
         info!(version = CURRENT_VERSION, "Already on latest version");
@@ -256,6 +291,128 @@ fn do_check_for_updates_blocking() -> UpdateCheckResult {
     }
 }
 
+/// Returns the version of `release` if it belongs to `channel`.
+///
+/// Drafts never count. A release is a nightly if its tag's pre-release
+/// part starts with "nightly", and any other pre-release (by tag or by
+/// GitHub's pre-release flag) is a beta.
+fn channel_version(release: &serde_json::Value, channel: UpdateChannel) -> Option<Version> {
+    if release["draft"].as_bool().unwrap_or(false) {
+        return None;
+    }
+
+    let tag = release["tag_name"].as_str()?;
+    let version = Version::parse(tag.trim_start_matches('v')).ok()?;
+
+    let nightly = version.pre.as_str().starts_with("nightly");
+    let prerelease = !version.pre.is_empty() || release["prerelease"].as_bool().unwrap_or(false);
+
+    let accepted = match channel {
+        UpdateChannel::Stable => !prerelease,
+        UpdateChannel::Beta => !nightly,
+        UpdateChannel::Nightly => true,
+    };
+    accepted.then_some(version)
+}
+
+/// Finds the newest release on `channel`.
+fn latest_release(
+    releases: &[serde_json::Value],
+    channel: UpdateChannel,
+) -> Option<(Version, &serde_json::Value)> {
+    releases
+        .iter()
+        .filter_map(|r| Some((channel_version(r, channel)?, r)))
+        .max_by(|a, b| a.0.cmp(&b.0))
+}
+
+/// Combines the notes of every release on `channel` newer than `current`
+/// into one Markdown document, newest first.
+///
+/// Returns `None` if none of those releases have notes.
+fn changelog_markdown(
+    releases: &[serde_json::Value],
+    current: &Version,
+    channel: UpdateChannel,
+) -> Option<String> {
+    let mut newer: Vec<(Version, &str)> = releases
+        .iter()
+        .filter_map(|r| {
+            let version = channel_version(r, channel)?;
+            let notes = r["body"].as_str().map(str::trim).unwrap_or("");
+            (version > *current && !notes.is_empty()).then_some((version, notes))
+        })
+        .collect();
+
+    if newer.is_empty() {
+        return None;
+    }
+
+    newer.sort_by(|a, b| b.0.cmp(&a.0));
+    Some(
+        newer
+            .iter()
+            .map(|(version, notes)| format!("## v{}\n\n{}", version, notes))
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    )
+}
+
+/// Returns the release artifact suffix for this build's platform.
+///
+/// Matches the `artifact_suffix` values in the release workflow.
+pub fn platform_suffix() -> Option<&'static str> {
+    if cfg!(all(target_os = "macos", target_arch = "aarch64")) {
+        Some("macos-arm64")
+    } else if cfg!(all(target_os = "macos", target_arch = "x86_64")) {
+        Some("macos-x64")
+    } else if cfg!(all(target_os = "linux", target_arch = "x86_64")) {
+        Some("linux-x64")
+    } else {
+        None
+    }
+}
+
+/// Finds the release tarball for `suffix` and its `.sha256` checksum file,
+/// plus a delta patch from `current` if the release has one.
+fn extract_platform_asset(
+    release: &serde_json::Value,
+    suffix: &str,
+    current: &str,
+) -> Option<UpdateAsset> {
+    let assets = release["assets"].as_array()?;
+    let url_of = |name: &str| {
+        assets
+            .iter()
+            .find(|a| a["name"].as_str() == Some(name))
+            .and_then(|a| a["browser_download_url"].as_str())
+            .map(|s| s.to_string())
+    };
+
+    let prefix = format!("exactobar-{}-", suffix);
+    let name = assets.iter().find_map(|a| {
+        let name = a["name"].as_str()?;
+        (name.starts_with(&prefix) && name.ends_with(".tar.gz")).then(|| name.to_string())
+    })?;
+
+    // Patches are named for the release they apply to, e.g.
+    // "exactobar-app-macos-arm64-from-v0.2.0.bsdiff"
+    let patch_name = format!("exactobar-app-{}-from-v{}.bsdiff", suffix, current);
+    let delta = url_of(&patch_name).and_then(|url| {
+        Some(DeltaAsset {
+            url,
+            checksum_url: url_of(&format!("{}.sha256", patch_name))?,
+        })
+    });
+
+    Some(UpdateAsset {
+        url: url_of(&name)?,
+        checksum_url: url_of(&format!("{}.sha256", name)),
+        name,
+        delta,
+    })
+}
+
 /// Extracts the macOS download URL from release assets.
 ///
 /// Looks for assets with names containing "macos", "darwin", or ending in ".dmg".
@@ -288,6 +445,113 @@ mod tests {
         assert!(Version::parse(CURRENT_VERSION).is_ok());
     }
 
+    #[test]
+    fn test_changelog_markdown() {
+        let releases = serde_json::json!([
+            { "tag_name": "v0.3.0", "body": "- New dashboard" },
+            { "tag_name": "v0.4.0-beta.1", "prerelease": true, "body": "- Beta only" },
+            { "tag_name": "v0.2.1", "body": "- Fixed crash\r\n" },
+            { "tag_name": "v0.2.0", "body": "- Already installed" },
+            { "tag_name": "v0.2.5", "draft": true, "body": "- Draft" }
+        ]);
+        let releases = releases.as_array().unwrap();
+        let current = Version::parse("0.2.0").unwrap();
+
+        assert_eq!(
+            changelog_markdown(releases, &current, UpdateChannel::Stable).as_deref(),
+            Some("## v0.3.0\n\n- New dashboard\n\n## v0.2.1\n\n- Fixed crash")
+        );
+        assert_eq!(
+            changelog_markdown(
+                releases,
+                &Version::parse("0.3.0").unwrap(),
+                UpdateChannel::Stable
+            ),
+            None
+        );
+        assert_eq!(
+            changelog_markdown(
+                releases,
+                &Version::parse("0.3.0").unwrap(),
+                UpdateChannel::Beta
+            )
+            .as_deref(),
+            Some("## v0.4.0-beta.1\n\n- Beta only")
+        );
+    }
+
+    #[test]
+    fn test_latest_release_per_channel() {
+        let releases = serde_json::json!([
+            { "tag_name": "v0.5.0-nightly.20260101", "prerelease": true },
+            { "tag_name": "v0.4.0-rc.1", "prerelease": true },
+            { "tag_name": "v0.3.5", "prerelease": true },
+            { "tag_name": "v0.3.1" },
+            { "tag_name": "v0.6.0", "draft": true }
+        ]);
+        let releases = releases.as_array().unwrap();
+        let latest = |channel| latest_release(releases, channel).map(|(v, _)| v.to_string());
+
+        assert_eq!(latest(UpdateChannel::Stable).as_deref(), Some("0.3.1"));
+        assert_eq!(latest(UpdateChannel::Beta).as_deref(), Some("0.4.0-rc.1"));
+        assert_eq!(
+            latest(UpdateChannel::Nightly).as_deref(),
+            Some("0.5.0-nightly.20260101")
+        );
+        assert_eq!(latest_release(&[], UpdateChannel::Stable), None);
+    }
+
+    #[test]
+    fn test_extract_platform_asset() {
+        let release = serde_json::json!({
+            "assets": [
+                {
+                    "name": "exactobar-linux-x64-v0.3.0.tar.gz",
+                    "browser_download_url": "https://example.com/linux"
+                },
+                {
+                    "name": "exactobar-macos-arm64-v0.3.0.tar.gz",
+                    "browser_download_url": "https://example.com/arm64"
+                },
+                {
+                    "name": "exactobar-macos-arm64-v0.3.0.tar.gz.sha256",
+                    "browser_download_url": "https://example.com/arm64.sha256"
+                },
+                {
+                    "name": "exactobar-app-macos-arm64-from-v0.2.0.bsdiff",
+                    "browser_download_url": "https://example.com/arm64.bsdiff"
+                },
+                {
+                    "name": "exactobar-app-macos-arm64-from-v0.2.0.bsdiff.sha256",
+                    "browser_download_url": "https://example.com/arm64.bsdiff.sha256"
+                }
+            ]
+        });
+
+        assert_eq!(
+            extract_platform_asset(&release, "macos-arm64", "0.2.0"),
+            Some(UpdateAsset {
+                name: "exactobar-macos-arm64-v0.3.0.tar.gz".into(),
+                url: "https://example.com/arm64".into(),
+                checksum_url: Some("https://example.com/arm64.sha256".into()),
+                delta: Some(DeltaAsset {
+                    url: "https://example.com/arm64.bsdiff".into(),
+                    checksum_url: "https://example.com/arm64.bsdiff.sha256".into(),
+                }),
+            })
+        );
+        // No patch from older versions
+        assert_eq!(
+            extract_platform_asset(&release, "macos-arm64", "0.1.0").and_then(|a| a.delta),
+            None
+        );
+        assert_eq!(
+            extract_platform_asset(&release, "linux-x64", "0.2.0").and_then(|a| a.checksum_url),
+            None
+        );
+        assert_eq!(extract_platform_asset(&release, "macos-x64", "0.2.0"), None);
+    }
+
     #[test]
     fn test_extract_macos_download_url() {
         let release = serde_json::json!({
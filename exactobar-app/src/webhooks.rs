@@ -0,0 +1,503 @@
+//! Outbound webhooks for usage events.
+//!
+//! Posts a JSON payload to each registered webhook when usage crosses the
+//! warning or critical threshold, a provider starts failing to refresh, or a
+//! usage window resets. Slack and Discord webhooks get a formatted chat
+//! message instead of the raw event. When a webhook has a secret, each
+//! delivery carries its Unix time in the `X-ExactoBar-Timestamp` header and
+//! is signed in the `X-ExactoBar-Signature` header as `sha256=<hex HMAC of
+//! "<timestamp>.<body>">`, so receivers can reject replayed requests.
+
+use chrono::{DateTime, Utc};
+use exactobar_core::{ProviderBranding, ProviderColor, ProviderKind, UsageSnapshot, WindowKind};
+use exactobar_providers::ProviderRegistry;
+use exactobar_store::{ResetEvent, Webhook, WebhookEventKind, WebhookFormat};
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::notifications::NotificationLevel;
+use crate::server::window_label;
+use crate::updater::CURRENT_VERSION;
+
+/// Seconds to wait before each retry of a failed delivery.
+const RETRY_DELAYS: [u64; 3] = [5, 30, 120];
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// A usage event, tagged by `event` in the payload.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// Session usage crossed the warning or critical threshold.
+    Threshold {
+        /// "warning" or "critical".
+        level: &'static str,
+        used_percent: f64,
+        resets_at: Option<DateTime<Utc>>,
+    },
+    /// A refresh failed.
+    Error { error: String },
+    /// A usage window reset.
+    Reset {
+        window: WindowKind,
+        /// Highest usage reached before the reset.
+        peak_percent: f64,
+    },
+}
+
+impl WebhookEvent {
+    pub fn kind(&self) -> WebhookEventKind {
+        match self {
+            WebhookEvent::Threshold { .. } => WebhookEventKind::Threshold,
+            WebhookEvent::Error { .. } => WebhookEventKind::Error,
+            WebhookEvent::Reset { .. } => WebhookEventKind::Reset,
+        }
+    }
+}
+
+/// The JSON body posted to webhooks.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookPayload {
+    #[serde(flatten)]
+    pub event: WebhookEvent,
+    /// CLI name, e.g. "claude".
+    pub provider: &'static str,
+    /// Display name, e.g. "Claude".
+    pub provider_name: &'static str,
+    pub timestamp: DateTime<Utc>,
+    #[serde(skip)]
+    pub provider_kind: ProviderKind,
+}
+
+impl WebhookPayload {
+    pub fn new(provider: ProviderKind, event: WebhookEvent) -> Self {
+        Self {
+            event,
+            provider: provider.cli_name(),
+            provider_name: provider.display_name(),
+            timestamp: Utc::now(),
+            provider_kind: provider,
+        }
+    }
+}
+
+// ============================================================================
+// Event Detection
+// ============================================================================
+
+/// Turns refresh results into events, sending each state change once.
+#[derive(Default)]
+pub struct WebhookTracker {
+    /// Last threshold level seen per provider.
+    levels: HashMap<ProviderKind, NotificationLevel>,
+    /// Providers whose last refresh failed.
+    failing: HashSet<ProviderKind>,
+}
+
+impl WebhookTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the events for a successful refresh.
+    ///
+    /// `resets` are the resets observed by this snapshot (see
+    /// [`exactobar_store::UsageHistory::resets`]).
+    pub fn on_success(
+        &mut self,
+        provider: ProviderKind,
+        snapshot: &UsageSnapshot,
+        resets: Vec<ResetEvent>,
+    ) -> Vec<WebhookEvent> {
+        self.failing.remove(&provider);
+
+        let mut events: Vec<WebhookEvent> = resets
+            .into_iter()
+            .map(|reset| WebhookEvent::Reset {
+                window: reset.window,
+                peak_percent: reset.peak_percent,
+            })
+            .collect();
+
+        if let Some(primary) = &snapshot.primary {
+            let level = NotificationLevel::for_percent(primary.used_percent);
+            let last = self.levels.insert(provider, level).unwrap_or_default();
+            let name = match level {
+                NotificationLevel::Warning => Some("warning"),
+                NotificationLevel::Critical => Some("critical"),
+                NotificationLevel::None => None,
+            };
+            if let Some(name) = name.filter(|_| level > last) {
+                events.push(WebhookEvent::Threshold {
+                    level: name,
+                    used_percent: primary.used_percent,
+                    resets_at: primary.resets_at,
+                });
+            }
+        }
+
+        events
+    }
+
+    /// Returns the event for a failed refresh, unless it was already failing.
+    pub fn on_error(&mut self, provider: ProviderKind, error: &str) -> Option<WebhookEvent> {
+        self.failing.insert(provider).then(|| WebhookEvent::Error {
+            // The first line is the summary; the rest lists each strategy tried
+            error: error.lines().next().unwrap_or(error).to_string(),
+        })
+    }
+}
+
+// ============================================================================
+// Delivery
+// ============================================================================
+
+/// Sends `payload` to every webhook that wants it, in the background.
+///
+/// Failed deliveries (network errors, 429 and 5xx responses) are retried
+/// after each of [`RETRY_DELAYS`].
+pub fn dispatch(webhooks: &[Webhook], payload: WebhookPayload) {
+    let kind = payload.event.kind();
+    let targets: Vec<(Webhook, Vec<u8>)> = webhooks
+        .iter()
+        .filter(|w| w.wants(kind))
+        .filter_map(|w| match body(w.format, &payload) {
+            Ok(body) => Some((w.clone(), body)),
+            Err(e) => {
+                warn!(error = %e, "Failed to serialize webhook payload");
+                None
+            }
+        })
+        .collect();
+    if targets.is_empty() {
+        return;
+    }
+
+    std::thread::spawn(move || {
+        let client = match exactobar_fetch::network::blocking_client_builder()
+            .user_agent(format!("ExactoBar/{}", CURRENT_VERSION))
+            .timeout(Duration::from_secs(10))
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => {
+                warn!(error = %e, "Failed to create HTTP client for webhooks");
+                return;
+            }
+        };
+
+        for (webhook, body) in targets {
+            deliver(&client, &webhook, kind, &body);
+        }
+    });
+}
+
+// ============================================================================
+// Formats
+// ============================================================================
+
+/// Renders the body posted to a webhook in `format`.
+fn body(format: WebhookFormat, payload: &WebhookPayload) -> serde_json::Result<Vec<u8>> {
+    match format {
+        WebhookFormat::Json => serde_json::to_vec(payload),
+        WebhookFormat::Slack => serde_json::to_vec(&slack_message(payload)),
+        WebhookFormat::Discord => serde_json::to_vec(&discord_message(payload)),
+    }
+}
+
+/// A Slack message with a side bar in the provider's color.
+fn slack_message(payload: &WebhookPayload) -> Value {
+    // Slack shows dates in each reader's time zone
+    let (title, detail) = chat_text(payload, |at| {
+        format!(
+            "<!date^{}^{{date_short_pretty}} at {{time}}|{}>",
+            at.timestamp(),
+            at.to_rfc3339()
+        )
+    });
+    json!({
+        "text": title,
+        "attachments": [{
+            "color": provider_color(payload.provider_kind).to_hex(),
+            "text": detail,
+            "footer": "ExactoBar",
+            "ts": payload.timestamp.timestamp(),
+        }],
+    })
+}
+
+/// A Discord message with an embed in the provider's color.
+fn discord_message(payload: &WebhookPayload) -> Value {
+    // Discord shows relative times ("in 2 hours") that stay current
+    let (title, detail) = chat_text(payload, |at| format!("<t:{}:R>", at.timestamp()));
+    let (r, g, b) = provider_color(payload.provider_kind).to_rgb8();
+    let mut embed = json!({
+        "title": title,
+        "color": u32::from(r) << 16 | u32::from(g) << 8 | u32::from(b),
+        "timestamp": payload.timestamp.to_rfc3339(),
+        "footer": { "text": "ExactoBar" },
+    });
+    // Discord rejects empty descriptions
+    if !detail.is_empty() {
+        embed["description"] = Value::String(detail);
+    }
+    json!({ "username": "ExactoBar", "embeds": [embed] })
+}
+
+/// Returns the title and detail of a chat message, formatting reset times
+/// with `format_time`.
+fn chat_text(
+    payload: &WebhookPayload,
+    format_time: impl Fn(DateTime<Utc>) -> String,
+) -> (String, String) {
+    let name = payload.provider_name;
+    match &payload.event {
+        WebhookEvent::Threshold {
+            level,
+            used_percent,
+            resets_at,
+        } => {
+            let label = window_label(payload.provider_kind, WindowKind::Primary);
+            (
+                format!(
+                    "{} {} usage at {:.0}% ({})",
+                    name, label, used_percent, level
+                ),
+                resets_at
+                    .map(|at| format!("{} resets {}", label, format_time(at)))
+                    .unwrap_or_default(),
+            )
+        }
+        WebhookEvent::Error { error } => (format!("{} refresh failed", name), error.clone()),
+        WebhookEvent::Reset {
+            window,
+            peak_percent,
+        } => (
+            format!(
+                "{} {} usage reset",
+                name,
+                window_label(payload.provider_kind, *window)
+            ),
+            format!("Peaked at {:.0}% before the reset", peak_percent),
+        ),
+    }
+}
+
+fn provider_color(provider: ProviderKind) -> ProviderColor {
+    ProviderRegistry::get(provider)
+        .map(|d| d.branding.color)
+        .unwrap_or_else(|| ProviderBranding::for_provider(provider).color)
+}
+
+// ============================================================================
+// Delivery Helpers
+// ============================================================================
+
+/// Posts one payload, retrying transient failures.
+fn deliver(
+    client: &reqwest::blocking::Client,
+    webhook: &Webhook,
+    kind: WebhookEventKind,
+    body: &[u8],
+) {
+    // Chat webhook URLs carry their secret in the path
+    let host = url_host(&webhook.url);
+    for attempt in 0..=RETRY_DELAYS.len() {
+        if attempt > 0 {
+            std::thread::sleep(Duration::from_secs(RETRY_DELAYS[attempt - 1]));
+        }
+
+        let mut request = client
+            .post(&webhook.url)
+            .header("Content-Type", "application/json")
+            .header("X-ExactoBar-Event", kind.to_string())
+            .body(body.to_vec());
+        if let Some(secret) = &webhook.secret {
+            let timestamp = Utc::now().timestamp();
+            request = request
+                .header("X-ExactoBar-Timestamp", timestamp.to_string())
+                .header("X-ExactoBar-Signature", sign(secret, timestamp, body));
+        }
+
+        match request.send() {
+            Ok(response) if response.status().is_success() => {
+                info!(%host, event = %kind, "Webhook delivered");
+                return;
+            }
+            Ok(response) => {
+                let status = response.status();
+                if !(status.is_server_error() || status.as_u16() == 429) {
+                    warn!(%host, %status, "Webhook rejected");
+                    return;
+                }
+                debug!(%host, %status, attempt, "Webhook delivery failed");
+            }
+            Err(e) => {
+                debug!(%host, error = %e.without_url(), attempt, "Webhook delivery failed");
+            }
+        }
+    }
+
+    warn!(%host, event = %kind, "Giving up on webhook delivery");
+}
+
+/// Returns the host of a webhook URL, which is safe to log.
+fn url_host(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| "<invalid url>".to_string())
+}
+
+/// Signs `<timestamp>.<body>` with HMAC-SHA256, formatted as `sha256=<hex>`.
+fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, secret.as_bytes());
+    let mut context = ring::hmac::Context::with_key(&key);
+    context.update(format!("{}.", timestamp).as_bytes());
+    context.update(body);
+    let tag = context.sign();
+    let hex: String = tag.as_ref().iter().map(|b| format!("{:02x}", b)).collect();
+    format!("sha256={}", hex)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exactobar_core::UsageWindow;
+
+    fn snapshot(used_percent: f64) -> UsageSnapshot {
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(UsageWindow::new(used_percent));
+        snapshot
+    }
+
+    #[test]
+    fn test_threshold_events_fire_once() {
+        let mut tracker = WebhookTracker::new();
+        let claude = ProviderKind::Claude;
+
+        assert!(
+            tracker
+                .on_success(claude, &snapshot(50.0), vec![])
+                .is_empty()
+        );
+        let events = tracker.on_success(claude, &snapshot(85.0), vec![]);
+        assert!(matches!(
+            events[..],
+            [WebhookEvent::Threshold {
+                level: "warning",
+                ..
+            }]
+        ));
+        assert!(
+            tracker
+                .on_success(claude, &snapshot(88.0), vec![])
+                .is_empty()
+        );
+
+        // Dropping back below re-arms the threshold
+        tracker.on_success(claude, &snapshot(10.0), vec![]);
+        assert_eq!(tracker.on_success(claude, &snapshot(96.0), vec![]).len(), 1);
+    }
+
+    #[test]
+    fn test_error_events_fire_once_until_recovery() {
+        let mut tracker = WebhookTracker::new();
+        let codex = ProviderKind::Codex;
+
+        let event = tracker.on_error(codex, "Error: timed out\n\nStrategies tried (1):");
+        assert_eq!(
+            event,
+            Some(WebhookEvent::Error {
+                error: "Error: timed out".into()
+            })
+        );
+        assert!(tracker.on_error(codex, "Error: timed out").is_none());
+
+        tracker.on_success(codex, &snapshot(10.0), vec![]);
+        assert!(tracker.on_error(codex, "Error: timed out").is_some());
+    }
+
+    #[test]
+    fn test_payload_json() {
+        let payload = WebhookPayload::new(
+            ProviderKind::Claude,
+            WebhookEvent::Reset {
+                window: WindowKind::Primary,
+                peak_percent: 97.0,
+            },
+        );
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["event"], "reset");
+        assert_eq!(json["window"], "primary");
+        assert_eq!(json["provider"], "claude");
+    }
+
+    #[test]
+    fn test_chat_formats() {
+        let resets_at = Utc::now();
+        let payload = WebhookPayload::new(
+            ProviderKind::Claude,
+            WebhookEvent::Threshold {
+                level: "critical",
+                used_percent: 96.0,
+                resets_at: Some(resets_at),
+            },
+        );
+        let color = provider_color(ProviderKind::Claude);
+
+        let slack: Value =
+            serde_json::from_slice(&body(WebhookFormat::Slack, &payload).unwrap()).unwrap();
+        assert_eq!(slack["text"], "Claude Session usage at 96% (critical)");
+        assert_eq!(slack["attachments"][0]["color"], color.to_hex());
+        assert!(
+            slack["attachments"][0]["text"]
+                .as_str()
+                .unwrap()
+                .starts_with(&format!("Session resets <!date^{}^", resets_at.timestamp()))
+        );
+
+        let discord: Value =
+            serde_json::from_slice(&body(WebhookFormat::Discord, &payload).unwrap()).unwrap();
+        let embed = &discord["embeds"][0];
+        let (r, g, b) = color.to_rgb8();
+        assert_eq!(
+            embed["color"],
+            u32::from(r) << 16 | u32::from(g) << 8 | u32::from(b)
+        );
+        assert_eq!(
+            embed["description"],
+            format!("Session resets <t:{}:R>", resets_at.timestamp())
+        );
+    }
+
+    #[test]
+    fn test_url_host() {
+        assert_eq!(
+            url_host("https://hooks.slack.com/services/T000/B000/XXXX"),
+            "hooks.slack.com"
+        );
+        assert_eq!(url_host("not a url"), "<invalid url>");
+    }
+
+    #[test]
+    fn test_sign() {
+        assert_eq!(
+            sign(
+                "key",
+                1_700_000_000,
+                b"The quick brown fox jumps over the lazy dog"
+            ),
+            "sha256=2f658d6aef4f246e91cd741bbcded7479e9605f9d41c9e248122a117e0e1765b"
+        );
+    }
+}
@@ -0,0 +1,377 @@
+//! WebSocket API for Stream Deck and other local tools.
+//!
+//! Served at `ws://127.0.0.1:<port>/ws` by the local server (see
+//! [`crate::server`]). Messages are JSON text frames tagged by `type`; each
+//! must fit in a single frame.
+//!
+//! Server to client:
+//!
+//! - `{"type": "usage", "providers": [...]}`: usage of every enabled
+//!   provider, sent on connect and whenever usage or the enabled providers
+//!   change. Each provider has the same fields as the MCP `get_usage` tool:
+//!   `provider` (CLI name, e.g. "claude"), `name`, `windows` (each with
+//!   `window`, `label`, `used_percent`, `remaining_percent`,
+//!   `window_minutes` and `resets_at`), `updated_at` and `error`.
+//! - `{"type": "error", "message": "..."}`: a client message was rejected.
+//!
+//! Client to server:
+//!
+//! - `{"type": "get_usage"}`: resend the current usage.
+//! - `{"type": "refresh"}` or `{"type": "refresh", "provider": "claude"}`:
+//!   refresh all enabled providers, or one. The new usage arrives as a
+//!   `usage` message once the fetch completes.
+//!
+//! Connections from browser pages are only accepted from local origins.
+
+use base64::Engine;
+use exactobar_core::ProviderKind;
+use gpui::*;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufReader, Read, Write};
+use std::net::{Shutdown, TcpStream};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Mutex, OnceLock};
+use tracing::{debug, info};
+
+use crate::server::{self, ProviderUsage};
+
+/// GUID appended to the client key in the opening handshake (RFC 6455).
+const HANDSHAKE_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Largest client message accepted.
+const MAX_MESSAGE: u64 = 64 * 1024;
+
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xA;
+
+/// Outgoing queues of the connected clients.
+static CLIENTS: Lazy<Mutex<Vec<Sender<Outgoing>>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Forwards client commands to the app.
+static COMMANDS: OnceLock<smol::channel::Sender<Command>> = OnceLock::new();
+
+// ============================================================================
+// Messages
+// ============================================================================
+
+/// A message sent to clients.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    Usage { providers: Vec<ProviderUsage> },
+    Error { message: String },
+}
+
+/// A message received from a client.
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    GetUsage,
+    Refresh {
+        #[serde(default)]
+        provider: Option<ProviderKind>,
+    },
+}
+
+/// A command for the app, run on the main thread.
+#[derive(Debug, Clone, Copy)]
+enum Command {
+    /// Refresh one provider, or all enabled providers.
+    Refresh(Option<ProviderKind>),
+}
+
+/// A frame queued for a client.
+enum Outgoing {
+    Message(ServerMessage),
+    Pong(Vec<u8>),
+    Close,
+}
+
+// ============================================================================
+// App Side
+// ============================================================================
+
+/// Runs client commands on the app. Call once before serving connections.
+pub fn start(cx: &mut App) {
+    let (tx, rx) = smol::channel::unbounded();
+    if COMMANDS.set(tx).is_err() {
+        return;
+    }
+
+    cx.spawn(async move |cx| {
+        while let Ok(command) = rx.recv().await {
+            cx.update(|cx| match command {
+                Command::Refresh(Some(provider)) => {
+                    crate::refresh::trigger_provider_refresh(provider, cx);
+                }
+                Command::Refresh(None) => crate::refresh::trigger_refresh(cx),
+            });
+        }
+    })
+    .detach();
+}
+
+/// Sends the current usage to every connected client.
+pub fn broadcast() {
+    let Ok(mut clients) = CLIENTS.lock() else {
+        return;
+    };
+    if clients.is_empty() {
+        return;
+    }
+
+    let usage = server::usage();
+    // Disconnected clients have dropped their queue
+    clients.retain(|client| {
+        client
+            .send(Outgoing::Message(ServerMessage::Usage {
+                providers: usage.clone(),
+            }))
+            .is_ok()
+    });
+}
+
+// ============================================================================
+// Connection
+// ============================================================================
+
+/// Completes the opening handshake and serves one client until it leaves.
+pub fn serve(mut stream: TcpStream, reader: BufReader<TcpStream>, key: &str) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+        accept_key(key)
+    )?;
+    stream.flush()?;
+    // Clients may stay idle between updates
+    stream.set_read_timeout(None)?;
+    info!("WebSocket client connected");
+
+    let (tx, rx) = mpsc::channel();
+    let _ = tx.send(Outgoing::Message(ServerMessage::Usage {
+        providers: server::usage(),
+    }));
+    if let Ok(mut clients) = CLIENTS.lock() {
+        clients.push(tx.clone());
+    }
+
+    std::thread::spawn(move || read_loop(reader, tx));
+
+    for outgoing in rx {
+        let result = match outgoing {
+            Outgoing::Message(message) => match serde_json::to_vec(&message) {
+                Ok(json) => write_frame(&mut stream, OPCODE_TEXT, &json),
+                Err(e) => Err(io::Error::other(e)),
+            },
+            Outgoing::Pong(payload) => write_frame(&mut stream, OPCODE_PONG, &payload),
+            Outgoing::Close => {
+                let _ = write_frame(&mut stream, OPCODE_CLOSE, &[]);
+                break;
+            }
+        };
+        if let Err(e) = result {
+            debug!(error = %e, "WebSocket write failed");
+            break;
+        }
+    }
+
+    info!("WebSocket client disconnected");
+    // Unblocks the read loop if the client is still connected
+    stream.shutdown(Shutdown::Both)
+}
+
+/// Reads client frames, queueing replies, until the client closes.
+fn read_loop(mut reader: BufReader<TcpStream>, tx: Sender<Outgoing>) {
+    loop {
+        let (opcode, payload) = match read_frame(&mut reader) {
+            Ok(frame) => frame,
+            Err(e) => {
+                debug!(error = %e, "WebSocket read failed");
+                let _ = tx.send(Outgoing::Close);
+                return;
+            }
+        };
+
+        let reply = match opcode {
+            OPCODE_TEXT => handle_message(&payload).map(Outgoing::Message),
+            OPCODE_PING => Some(Outgoing::Pong(payload)),
+            OPCODE_CLOSE => Some(Outgoing::Close),
+            // Pongs, binary and continuation frames are ignored
+            _ => None,
+        };
+        let closing = matches!(reply, Some(Outgoing::Close));
+        if let Some(reply) = reply
+            && tx.send(reply).is_err()
+        {
+            return;
+        }
+        if closing {
+            return;
+        }
+    }
+}
+
+/// Handles a client message, returning the reply, if any.
+fn handle_message(payload: &[u8]) -> Option<ServerMessage> {
+    let message = match serde_json::from_slice::<ClientMessage>(payload) {
+        Ok(message) => message,
+        Err(e) => {
+            return Some(ServerMessage::Error {
+                message: format!("Invalid message: {}", e),
+            });
+        }
+    };
+
+    match message {
+        ClientMessage::GetUsage => Some(ServerMessage::Usage {
+            providers: server::usage(),
+        }),
+        ClientMessage::Refresh { provider } => {
+            if let Some(provider) = provider {
+                let enabled = server::usage()
+                    .iter()
+                    .any(|u| u.provider == provider.cli_name());
+                if !enabled {
+                    return Some(ServerMessage::Error {
+                        message: format!("{} is not enabled", provider.display_name()),
+                    });
+                }
+            }
+            COMMANDS
+                .get()
+                .and_then(|commands| commands.try_send(Command::Refresh(provider)).ok());
+            None
+        }
+    }
+}
+
+// ============================================================================
+// Framing
+// ============================================================================
+
+/// Computes `Sec-WebSocket-Accept` for a client's `Sec-WebSocket-Key`.
+fn accept_key(key: &str) -> String {
+    let digest = ring::digest::digest(
+        &ring::digest::SHA1_FOR_LEGACY_USE_ONLY,
+        format!("{}{}", key.trim(), HANDSHAKE_GUID).as_bytes(),
+    );
+    base64::engine::general_purpose::STANDARD.encode(digest.as_ref())
+}
+
+/// Reads one frame, unmasking its payload.
+fn read_frame(reader: &mut impl Read) -> io::Result<(u8, Vec<u8>)> {
+    let mut head = [0; 2];
+    reader.read_exact(&mut head)?;
+    let opcode = head[0] & 0x0F;
+    let masked = head[1] & 0x80 != 0;
+
+    let len = match head[1] & 0x7F {
+        126 => {
+            let mut bytes = [0; 2];
+            reader.read_exact(&mut bytes)?;
+            u64::from(u16::from_be_bytes(bytes))
+        }
+        127 => {
+            let mut bytes = [0; 8];
+            reader.read_exact(&mut bytes)?;
+            u64::from_be_bytes(bytes)
+        }
+        len => u64::from(len),
+    };
+    if len > MAX_MESSAGE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "WebSocket message too large",
+        ));
+    }
+
+    let mut mask = [0; 4];
+    if masked {
+        reader.read_exact(&mut mask)?;
+    }
+    let mut payload = vec![0; len as usize];
+    reader.read_exact(&mut payload)?;
+    if masked {
+        for (i, byte) in payload.iter_mut().enumerate() {
+            *byte ^= mask[i % 4];
+        }
+    }
+
+    Ok((opcode, payload))
+}
+
+/// Writes one unmasked, final frame.
+fn write_frame(writer: &mut impl Write, opcode: u8, payload: &[u8]) -> io::Result<()> {
+    let mut frame = vec![0x80 | opcode];
+    match payload.len() {
+        len @ 0..=125 => frame.push(len as u8),
+        len @ 126..=0xFFFF => {
+            frame.push(126);
+            frame.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        len => {
+            frame.push(127);
+            frame.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+    frame.extend_from_slice(payload);
+    writer.write_all(&frame)?;
+    writer.flush()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_key() {
+        // Example from RFC 6455, section 1.3
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+
+    #[test]
+    fn test_frame_round_trip() {
+        // A masked client "Hello" (RFC 6455, section 5.7)
+        let masked = [
+            0x81, 0x85, 0x37, 0xfa, 0x21, 0x3d, 0x7f, 0x9f, 0x4d, 0x51, 0x58,
+        ];
+        let (opcode, payload) = read_frame(&mut &masked[..]).unwrap();
+        assert_eq!(opcode, OPCODE_TEXT);
+        assert_eq!(payload, b"Hello");
+
+        let mut written = Vec::new();
+        write_frame(&mut written, OPCODE_TEXT, &[b'x'; 300]).unwrap();
+        assert_eq!(&written[..4], &[0x81, 126, 0x01, 0x2C]);
+        let (_, payload) = read_frame(&mut &written[..]).unwrap();
+        assert_eq!(payload.len(), 300);
+    }
+
+    #[test]
+    fn test_client_messages() {
+        assert_eq!(
+            serde_json::from_str::<ClientMessage>(r#"{"type":"refresh","provider":"claude"}"#)
+                .unwrap(),
+            ClientMessage::Refresh {
+                provider: Some(ProviderKind::Claude)
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<ClientMessage>(r#"{"type":"refresh"}"#).unwrap(),
+            ClientMessage::Refresh { provider: None }
+        );
+        assert!(matches!(
+            handle_message(br#"{"type":"launch"}"#),
+            Some(ServerMessage::Error { .. })
+        ));
+    }
+}
@@ -0,0 +1,96 @@
+//! About window.
+//!
+//! A small standalone window with version, build, and license details,
+//! opened from the menu footer and the app menu's "About ExactoBar".
+
+use gpui::*;
+use std::sync::Mutex;
+use tracing::info;
+
+use super::settings::{AboutPane, SettingsTheme};
+use crate::state::AppState;
+use crate::theme;
+
+/// Global handle to the About window (if open).
+static ABOUT_WINDOW: Mutex<Option<AnyWindowHandle>> = Mutex::new(None);
+
+/// About window content.
+pub struct AboutWindow;
+
+impl Render for AboutWindow {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme_mode = cx.global::<AppState>().settings.read(cx).theme_mode();
+        let theme = SettingsTheme::for_mode(theme_mode, window.appearance());
+
+        div()
+            .id("about-window")
+            .size_full()
+            .overflow_y_scroll()
+            .bg(theme.bg)
+            .text_color(theme.text_primary)
+            .child(AboutPane::new(theme))
+    }
+}
+
+/// Opens the About window, or focuses it if already open.
+pub fn open_about_window(cx: &mut App) {
+    {
+        let guard = ABOUT_WINDOW.lock().unwrap();
+        if let Some(handle) = *guard {
+            if cx
+                .update_window(handle, |_, window, _| {
+                    window.activate_window();
+                })
+                .is_ok()
+            {
+                info!("Focused existing About window");
+                cx.activate(true);
+                return;
+            }
+            // Window was closed, continue to create new one
+        }
+    }
+
+    info!("Opening About window");
+
+    // Menu bar apps must activate before showing a regular window
+    cx.activate(true);
+
+    let bounds = Bounds::centered(None, size(px(420.0), px(620.0)), cx);
+
+    let options = WindowOptions {
+        titlebar: Some(TitlebarOptions {
+            title: Some("About ExactoBar".into()),
+            appears_transparent: false,
+            traffic_light_position: None,
+        }),
+        window_bounds: Some(WindowBounds::Windowed(bounds)),
+        focus: true,
+        show: true,
+        kind: WindowKind::Normal,
+        is_movable: true,
+        display_id: None,
+        window_background: WindowBackgroundAppearance::Opaque,
+        app_id: None,
+        window_min_size: None,
+        window_decorations: None,
+        is_minimizable: false,
+        is_resizable: false,
+        tabbing_identifier: None,
+    };
+
+    match cx.open_window(options, |window, cx| {
+        cx.new(|cx| {
+            theme::follow_system_appearance(window, cx);
+            AboutWindow
+        })
+    }) {
+        Ok(handle) => {
+            let mut guard = ABOUT_WINDOW.lock().unwrap();
+            *guard = Some(handle.into());
+        }
+        Err(e) => {
+            tracing::error!(error = ?e, "Failed to open About window");
+        }
+    }
+}
@@ -0,0 +1,728 @@
+//! Usage dashboard window.
+//!
+//! A full-size window for looking back over recorded usage: a chart of
+//! each provider's windows by day, week or month, spend per day stacked by
+//! provider, and a table of past resets.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use exactobar_core::{ProviderKind, WindowKind, format_currency};
+use exactobar_store::{AggregatePeriod, ResetEvent, UsageAggregate};
+use gpui::prelude::*;
+use gpui::*;
+use tracing::info;
+
+use super::settings::SettingsTheme;
+use crate::state::AppState;
+use crate::theme::{self, ExactoBarTheme, color_for_usage};
+
+/// Global handle to the dashboard window (if open).
+static DASHBOARD_WINDOW: Mutex<Option<AnyWindowHandle>> = Mutex::new(None);
+
+/// How far back the spend chart and reset table look.
+const DASHBOARD_DAYS: i64 = 30;
+
+/// Periods the usage charts can be grouped by, with their labels.
+const CHART_PERIODS: [(AggregatePeriod, &str); 3] = [
+    (AggregatePeriod::Day, "Daily"),
+    (AggregatePeriod::Week, "Weekly"),
+    (AggregatePeriod::Month, "Monthly"),
+];
+
+/// Height of the usage and spend charts.
+const CHART_HEIGHT: f32 = 72.0;
+
+/// Windows charted per provider, with their labels.
+const CHARTED_WINDOWS: [(WindowKind, &str); 3] = [
+    (WindowKind::Primary, "Session"),
+    (WindowKind::Secondary, "Weekly"),
+    (WindowKind::Tertiary, "Premium"),
+];
+
+// ============================================================================
+// Dashboard Data
+// ============================================================================
+
+/// One provider window's chart.
+struct UsageChart {
+    label: &'static str,
+    /// Peak usage per period; `None` where nothing was recorded.
+    buckets: Vec<Option<f64>>,
+    /// Average usage over the charted periods.
+    average: Option<f64>,
+    latest: f64,
+}
+
+/// One provider's section of the dashboard.
+struct ProviderHistory {
+    provider: ProviderKind,
+    charts: Vec<UsageChart>,
+    /// Spend per local day.
+    spend: Vec<(NaiveDate, f64)>,
+    currency_code: String,
+    resets: Vec<ResetEvent>,
+}
+
+impl ProviderHistory {
+    fn collect(
+        provider: ProviderKind,
+        period: AggregatePeriod,
+        since: DateTime<Utc>,
+        cx: &App,
+    ) -> Self {
+        let state = cx.global::<AppState>();
+        let history = state.usage.read(cx).history();
+
+        let starts = chart_starts(period, Local::now().date_naive());
+        let charts = CHARTED_WINDOWS
+            .iter()
+            .filter_map(|&(window, label)| {
+                let aggregates: Vec<_> = history
+                    .aggregate(provider, window, period, &Local)
+                    .into_iter()
+                    .filter(|a| a.start >= starts[0])
+                    .collect();
+                if aggregates.is_empty() {
+                    return None;
+                }
+                let latest = history
+                    .usage_points(provider, window)
+                    .last()
+                    .map_or(0.0, |&(_, percent)| percent);
+                Some(UsageChart {
+                    label,
+                    buckets: period_peaks(&aggregates, &starts),
+                    average: average_percent(&aggregates),
+                    latest,
+                })
+            })
+            .collect();
+
+        let since_day = since.with_timezone(&Local).date_naive();
+        let spend = history
+            .daily_spend(provider, &Local)
+            .into_iter()
+            .filter(|&(day, _)| day >= since_day)
+            .collect();
+
+        let resets = CHARTED_WINDOWS
+            .iter()
+            .flat_map(|&(window, _)| history.resets(provider, window))
+            .filter(|reset| reset.observed_at >= since)
+            .collect();
+
+        let currency_code = state
+            .get_snapshot(provider, cx)
+            .and_then(|s| s.cost)
+            .and_then(|c| c.currency_code)
+            .unwrap_or_else(|| "USD".to_string());
+
+        Self {
+            provider,
+            charts,
+            spend,
+            currency_code,
+            resets,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.charts.is_empty() && self.spend.is_empty()
+    }
+}
+
+/// Returns the start of each period charted, oldest first: the last 30
+/// days, 26 weeks or 12 months up to `today`.
+fn chart_starts(period: AggregatePeriod, today: NaiveDate) -> Vec<NaiveDate> {
+    let first = match period {
+        AggregatePeriod::Day => today - Duration::days(29),
+        AggregatePeriod::Week => today - Duration::weeks(25),
+        AggregatePeriod::Month => today
+            .checked_sub_months(chrono::Months::new(11))
+            .unwrap_or(today),
+    };
+    period.starts_between(first, today)
+}
+
+/// Returns the peak recorded in each period starting at `starts`.
+fn period_peaks(aggregates: &[UsageAggregate], starts: &[NaiveDate]) -> Vec<Option<f64>> {
+    starts
+        .iter()
+        .map(|start| {
+            aggregates
+                .iter()
+                .find(|a| a.start == *start)
+                .map(|a| a.peak_percent)
+        })
+        .collect()
+}
+
+/// Averages usage across periods, weighted by how many readings each has.
+fn average_percent(aggregates: &[UsageAggregate]) -> Option<f64> {
+    let samples: u32 = aggregates.iter().map(|a| a.samples).sum();
+    if samples == 0 {
+        return None;
+    }
+    let total: f64 = aggregates
+        .iter()
+        .map(|a| a.average_percent * f64::from(a.samples))
+        .sum();
+    Some(total / f64::from(samples))
+}
+
+fn window_label(window: WindowKind) -> &'static str {
+    CHARTED_WINDOWS
+        .iter()
+        .find(|(kind, _)| *kind == window)
+        .map(|(_, label)| *label)
+        .unwrap_or("Search")
+}
+
+// ============================================================================
+// Dashboard Window
+// ============================================================================
+
+/// Usage dashboard window content.
+pub struct DashboardWindow {
+    usage_subscription: Option<Subscription>,
+    /// What each bar of the usage charts covers.
+    period: AggregatePeriod,
+}
+
+impl DashboardWindow {
+    fn render_period_picker(&self, theme: SettingsTheme, cx: &mut Context<Self>) -> Div {
+        div()
+            .flex()
+            .gap(px(2.0))
+            .p(px(2.0))
+            .rounded(px(6.0))
+            .bg(theme.code_bg)
+            .children(CHART_PERIODS.iter().map(|&(period, label)| {
+                let selected = self.period == period;
+                div()
+                    .id(SharedString::from(format!("dashboard-period-{label}")))
+                    .px(px(10.0))
+                    .py(px(3.0))
+                    .rounded(px(4.0))
+                    .text_xs()
+                    .cursor_pointer()
+                    .when(selected, |el| el.bg(theme.selected))
+                    .when(!selected, |el| {
+                        el.text_color(theme.text_muted)
+                            .hover(move |s| s.bg(theme.hover))
+                    })
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |this, _, _window, cx| {
+                            this.period = period;
+                            cx.notify();
+                        }),
+                    )
+                    .child(label)
+            }))
+    }
+}
+
+impl Render for DashboardWindow {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Redraw as new snapshots are recorded
+        if self.usage_subscription.is_none() {
+            let usage = cx.global::<AppState>().usage.clone();
+            self.usage_subscription = Some(cx.observe(&usage, |_this, _model, cx| {
+                cx.notify();
+            }));
+        }
+
+        let theme_mode = cx.global::<AppState>().settings.read(cx).theme_mode();
+        let theme = SettingsTheme::for_mode(theme_mode, window.appearance());
+
+        let since = Utc::now() - Duration::days(DASHBOARD_DAYS);
+        let providers: Vec<ProviderHistory> = cx
+            .global::<AppState>()
+            .enabled_providers(cx)
+            .into_iter()
+            .map(|provider| ProviderHistory::collect(provider, self.period, since, cx))
+            .filter(|history| !history.is_empty())
+            .collect();
+        let period_picker = self.render_period_picker(theme, cx);
+
+        div()
+            .id("dashboard-window")
+            .size_full()
+            .overflow_y_scroll()
+            .bg(theme.bg)
+            .text_color(theme.text_primary)
+            .p(px(24.0))
+            .flex()
+            .flex_col()
+            .gap(px(24.0))
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap(px(4.0))
+                    .child(
+                        div()
+                            .text_xl()
+                            .font_weight(FontWeight::BOLD)
+                            .child("Usage Dashboard"),
+                    )
+                    .child(div().text_sm().text_color(theme.text_muted).child(format!(
+                        "From usage recorded while ExactoBar was running. Spend and \
+                                 resets cover the last {DASHBOARD_DAYS} days."
+                    ))),
+            )
+            .when(providers.is_empty(), |el| {
+                el.child(
+                    div()
+                        .p(px(24.0))
+                        .rounded(px(8.0))
+                        .bg(theme.surface)
+                        .text_sm()
+                        .text_color(theme.text_muted)
+                        .child("No usage recorded yet. Charts fill in as providers refresh."),
+                )
+            })
+            .when(!providers.is_empty(), |el| {
+                el.child(render_usage_section(
+                    &providers,
+                    self.period,
+                    period_picker,
+                    theme,
+                ))
+                .child(render_spend_section(&providers, since, theme))
+                .child(render_resets_section(&providers, theme))
+            })
+    }
+}
+
+fn section(title: &'static str, theme: SettingsTheme) -> Div {
+    div().flex().flex_col().gap(px(12.0)).child(
+        div()
+            .text_base()
+            .font_weight(FontWeight::SEMIBOLD)
+            .text_color(theme.text_primary)
+            .child(title),
+    )
+}
+
+fn provider_label(provider: ProviderKind, color: Hsla) -> Div {
+    div()
+        .flex()
+        .items_center()
+        .gap(px(6.0))
+        .child(div().w(px(8.0)).h(px(8.0)).rounded_full().bg(color))
+        .child(
+            div()
+                .text_sm()
+                .font_weight(FontWeight::MEDIUM)
+                .child(provider.display_name()),
+        )
+}
+
+// ============================================================================
+// Usage Charts
+// ============================================================================
+
+fn render_usage_section(
+    providers: &[ProviderHistory],
+    period: AggregatePeriod,
+    period_picker: Div,
+    theme: SettingsTheme,
+) -> Div {
+    let brand = ExactoBarTheme::dark();
+    let caption = match period {
+        AggregatePeriod::Day => "Peak per day, last 30 days",
+        AggregatePeriod::Week => "Peak per week, last 26 weeks",
+        AggregatePeriod::Month => "Peak per month, last 12 months",
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .gap(px(12.0))
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    div()
+                        .flex()
+                        .items_end()
+                        .gap(px(8.0))
+                        .child(
+                            div()
+                                .text_base()
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .text_color(theme.text_primary)
+                                .child("Usage Over Time"),
+                        )
+                        .child(div().text_xs().text_color(theme.text_muted).child(caption)),
+                )
+                .child(period_picker),
+        )
+        .children(
+            providers
+                .iter()
+                .filter(|history| !history.charts.is_empty())
+                .map(|history| {
+                    div()
+                        .p(px(16.0))
+                        .rounded(px(8.0))
+                        .bg(theme.surface)
+                        .border_1()
+                        .border_color(theme.border)
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(provider_label(
+                            history.provider,
+                            brand.provider_color(history.provider),
+                        ))
+                        .children(
+                            history
+                                .charts
+                                .iter()
+                                .map(|chart| render_usage_chart(chart, theme)),
+                        )
+                }),
+        )
+}
+
+fn render_usage_chart(chart: &UsageChart, theme: SettingsTheme) -> Div {
+    let bar_bg = theme.code_bg;
+
+    div()
+        .flex()
+        .flex_col()
+        .gap(px(4.0))
+        .child(
+            div()
+                .flex()
+                .justify_between()
+                .text_xs()
+                .text_color(theme.text_muted)
+                .child(chart.label)
+                .child(match chart.average {
+                    Some(average) => {
+                        format!("now {:.0}% used · average {:.0}%", chart.latest, average)
+                    }
+                    None => format!("now {:.0}% used", chart.latest),
+                }),
+        )
+        .child(
+            div()
+                .h(px(CHART_HEIGHT))
+                .flex()
+                .items_end()
+                .gap(px(2.0))
+                .children(chart.buckets.iter().map(move |bucket| {
+                    let bar = div().flex_1().rounded(px(1.0));
+                    match *bucket {
+                        Some(percent) => {
+                            let fraction = (percent.clamp(0.0, 100.0) / 100.0) as f32;
+                            bar.h(relative(fraction.max(0.03)))
+                                .bg(color_for_usage(percent))
+                        }
+                        None => bar.h(px(1.0)).bg(bar_bg),
+                    }
+                })),
+        )
+}
+
+// ============================================================================
+// Spend
+// ============================================================================
+
+fn render_spend_section(
+    providers: &[ProviderHistory],
+    since: DateTime<Utc>,
+    theme: SettingsTheme,
+) -> Div {
+    let brand = ExactoBarTheme::dark();
+    let spenders: Vec<&ProviderHistory> =
+        providers.iter().filter(|h| !h.spend.is_empty()).collect();
+
+    let section = section("Spend per Day", theme);
+    if spenders.is_empty() {
+        return section.child(
+            div()
+                .text_sm()
+                .text_color(theme.text_muted)
+                .child("None of your providers report spend."),
+        );
+    }
+
+    // Amount per provider for each day in range
+    let mut days: BTreeMap<NaiveDate, Vec<(ProviderKind, f64)>> = BTreeMap::new();
+    let first_day = since.with_timezone(&Local).date_naive();
+    let today = Local::now().date_naive();
+    for day in first_day.iter_days().take_while(|day| *day <= today) {
+        days.insert(day, Vec::new());
+    }
+    for history in &spenders {
+        for &(day, amount) in &history.spend {
+            days.entry(day)
+                .or_default()
+                .push((history.provider, amount));
+        }
+    }
+
+    let max_total = days
+        .values()
+        .map(|amounts| amounts.iter().map(|(_, a)| a).sum::<f64>())
+        .fold(0.0, f64::max)
+        .max(f64::EPSILON);
+
+    section.child(
+        div()
+            .p(px(16.0))
+            .rounded(px(8.0))
+            .bg(theme.surface)
+            .border_1()
+            .border_color(theme.border)
+            .flex()
+            .flex_col()
+            .gap(px(12.0))
+            .child(
+                div()
+                    .h(px(CHART_HEIGHT * 1.5))
+                    .flex()
+                    .items_end()
+                    .gap(px(2.0))
+                    .children(days.into_values().map(move |amounts| {
+                        let total: f64 = amounts.iter().map(|(_, a)| a).sum();
+                        div()
+                            .flex_1()
+                            .h(relative((total / max_total) as f32))
+                            .flex()
+                            .flex_col()
+                            .justify_end()
+                            .children(amounts.into_iter().map(move |(provider, amount)| {
+                                div()
+                                    .w_full()
+                                    .h(relative((amount / total.max(f64::EPSILON)) as f32))
+                                    .bg(brand.provider_color(provider))
+                            }))
+                    })),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap(px(16.0))
+                    .children(spenders.iter().map(|history| {
+                        let total: f64 = history.spend.iter().map(|(_, a)| a).sum();
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap(px(6.0))
+                            .child(provider_label(
+                                history.provider,
+                                brand.provider_color(history.provider),
+                            ))
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .text_color(theme.text_muted)
+                                    .child(format_currency(total, &history.currency_code)),
+                            )
+                    })),
+            ),
+    )
+}
+
+// ============================================================================
+// Resets
+// ============================================================================
+
+fn render_resets_section(providers: &[ProviderHistory], theme: SettingsTheme) -> Div {
+    let mut resets: Vec<(ProviderKind, &ResetEvent)> = providers
+        .iter()
+        .flat_map(|h| h.resets.iter().map(move |reset| (h.provider, reset)))
+        .collect();
+    resets.sort_by(|a, b| b.1.observed_at.cmp(&a.1.observed_at));
+
+    let section = section("Past Resets", theme);
+    if resets.is_empty() {
+        return section.child(
+            div()
+                .text_sm()
+                .text_color(theme.text_muted)
+                .child("No resets recorded yet."),
+        );
+    }
+
+    let row = |cells: [String; 4], header: bool| {
+        div()
+            .flex()
+            .py(px(6.0))
+            .border_b_1()
+            .border_color(theme.border)
+            .text_sm()
+            .when(header, |el| {
+                el.text_xs()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(theme.text_muted)
+            })
+            .children(cells.into_iter().map(|cell| div().flex_1().child(cell)))
+    };
+
+    section.child(
+        div()
+            .px(px(16.0))
+            .py(px(8.0))
+            .rounded(px(8.0))
+            .bg(theme.surface)
+            .border_1()
+            .border_color(theme.border)
+            .child(row(
+                [
+                    "Provider".into(),
+                    "Window".into(),
+                    "Reset".into(),
+                    "Peak Before Reset".into(),
+                ],
+                true,
+            ))
+            .children(resets.into_iter().map(|(provider, reset)| {
+                row(
+                    [
+                        provider.display_name().to_string(),
+                        window_label(reset.window).to_string(),
+                        reset
+                            .observed_at
+                            .with_timezone(&Local)
+                            .format("%b %-d, %H:%M")
+                            .to_string(),
+                        format!("{:.0}%", reset.peak_percent),
+                    ],
+                    false,
+                )
+            })),
+    )
+}
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Opens the usage dashboard, or focuses it if already open.
+pub fn open_dashboard_window(cx: &mut App) {
+    {
+        let guard = DASHBOARD_WINDOW.lock().unwrap();
+        if let Some(handle) = *guard {
+            if cx
+                .update_window(handle, |_, window, _| {
+                    window.activate_window();
+                })
+                .is_ok()
+            {
+                info!("Focused existing dashboard window");
+                cx.activate(true);
+                return;
+            }
+            // Window was closed, continue to create new one
+        }
+    }
+
+    info!("Opening dashboard window");
+
+    // Menu bar apps must activate before showing a regular window
+    cx.activate(true);
+
+    let bounds = Bounds::centered(None, size(px(860.0), px(720.0)), cx);
+
+    let options = WindowOptions {
+        titlebar: Some(TitlebarOptions {
+            title: Some("Usage Dashboard".into()),
+            appears_transparent: false,
+            traffic_light_position: None,
+        }),
+        window_bounds: Some(WindowBounds::Windowed(bounds)),
+        focus: true,
+        show: true,
+        kind: gpui::WindowKind::Normal,
+        is_movable: true,
+        display_id: None,
+        window_background: WindowBackgroundAppearance::Opaque,
+        app_id: None,
+        window_min_size: Some(size(px(560.0), px(420.0))),
+        window_decorations: None,
+        is_minimizable: true,
+        is_resizable: true,
+        tabbing_identifier: None,
+    };
+
+    match cx.open_window(options, |window, cx| {
+        cx.new(|cx| {
+            theme::follow_system_appearance(window, cx);
+            DashboardWindow {
+                usage_subscription: None,
+                period: AggregatePeriod::Day,
+            }
+        })
+    }) {
+        Ok(handle) => {
+            let mut guard = DASHBOARD_WINDOW.lock().unwrap();
+            *guard = Some(handle.into());
+        }
+        Err(e) => {
+            tracing::error!(error = ?e, "Failed to open dashboard window");
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aggregate(start: NaiveDate, peak: f64, average: f64, samples: u32) -> UsageAggregate {
+        UsageAggregate {
+            start,
+            peak_percent: peak,
+            average_percent: average,
+            samples,
+        }
+    }
+
+    #[test]
+    fn test_chart_starts() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 4).unwrap();
+
+        let days = chart_starts(AggregatePeriod::Day, today);
+        assert_eq!(days.len(), 30);
+        assert_eq!(days.last(), Some(&today));
+
+        let weeks = chart_starts(AggregatePeriod::Week, today);
+        assert_eq!(weeks.len(), 26);
+        assert_eq!(weeks.last(), NaiveDate::from_ymd_opt(2026, 3, 2).as_ref());
+
+        let months = chart_starts(AggregatePeriod::Month, today);
+        assert_eq!(months.len(), 12);
+        assert_eq!(months[0], NaiveDate::from_ymd_opt(2025, 4, 1).unwrap());
+    }
+
+    #[test]
+    fn test_period_peaks_and_average() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+        let aggregates = [
+            aggregate(day(1), 40.0, 20.0, 3),
+            aggregate(day(3), 90.0, 60.0, 1),
+        ];
+
+        assert_eq!(
+            period_peaks(&aggregates, &[day(1), day(2), day(3)]),
+            vec![Some(40.0), None, Some(90.0)]
+        );
+        assert_eq!(average_percent(&aggregates), Some(30.0));
+        assert_eq!(average_percent(&[]), None);
+    }
+}
@@ -0,0 +1,364 @@
+//! Network inspector window.
+//!
+//! A developer window, opened from the Advanced settings pane while debug
+//! mode is on, listing the provider HTTP exchanges recorded by
+//! [`exactobar_fetch::inspector`], newest first. Clicking an exchange shows
+//! the start of its response body, with credentials redacted.
+
+use std::sync::Mutex;
+
+use chrono::Local;
+use exactobar_fetch::inspector::{self, Exchange};
+use gpui::prelude::*;
+use gpui::*;
+use tracing::info;
+
+use super::settings::SettingsTheme;
+use crate::menu::copy_to_clipboard;
+use crate::state::AppState;
+use crate::theme;
+
+/// Global handle to the inspector window (if open).
+static INSPECTOR_WINDOW: Mutex<Option<AnyWindowHandle>> = Mutex::new(None);
+
+/// Short summary of an exchange's outcome: the status code, or "Failed".
+fn outcome_label(exchange: &Exchange) -> String {
+    match exchange.status {
+        Some(status) => status.to_string(),
+        None => "Failed".to_string(),
+    }
+}
+
+/// Text copied for an exchange, for pasting into a bug report.
+fn exchange_report(exchange: &Exchange) -> String {
+    format!(
+        "{} {}\n{} in {} ms at {}\n\n{}",
+        exchange.method,
+        exchange.url,
+        outcome_label(exchange),
+        exchange.duration.as_millis(),
+        exchange.started_at.to_rfc3339(),
+        exchange.error.as_deref().unwrap_or(&exchange.body)
+    )
+}
+
+// ============================================================================
+// Inspector Window
+// ============================================================================
+
+/// Network inspector content.
+pub struct InspectorWindow {
+    /// Start time of the expanded exchange, if any.
+    expanded: Option<chrono::DateTime<chrono::Utc>>,
+    usage_subscription: Option<Subscription>,
+}
+
+impl InspectorWindow {
+    fn new() -> Self {
+        Self {
+            expanded: None,
+            usage_subscription: None,
+        }
+    }
+
+    fn render_exchange(
+        &self,
+        index: usize,
+        exchange: Exchange,
+        theme: SettingsTheme,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        let expanded = self.expanded == Some(exchange.started_at);
+        let status_color = match exchange.status {
+            Some(200..=299) => theme.success,
+            Some(300..=499) => theme.warning,
+            _ => theme.error,
+        };
+        let started_at = exchange.started_at;
+        let report = exchange_report(&exchange);
+
+        div()
+            .flex()
+            .flex_col()
+            .border_b_1()
+            .border_color(theme.border)
+            .child(
+                div()
+                    .id(format!("exchange-{index}"))
+                    .px(px(12.0))
+                    .py(px(6.0))
+                    .flex()
+                    .items_center()
+                    .gap(px(10.0))
+                    .text_xs()
+                    .cursor_pointer()
+                    .hover(move |s| s.bg(theme.hover))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |this, _, _window, cx| {
+                            this.expanded =
+                                (this.expanded != Some(started_at)).then_some(started_at);
+                            cx.notify();
+                        }),
+                    )
+                    .child(
+                        div()
+                            .w(px(60.0))
+                            .flex_shrink_0()
+                            .text_color(theme.text_muted)
+                            .child(
+                                exchange
+                                    .started_at
+                                    .with_timezone(&Local)
+                                    .format("%H:%M:%S")
+                                    .to_string(),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .w(px(44.0))
+                            .flex_shrink_0()
+                            .font_family("monospace")
+                            .child(exchange.method.clone()),
+                    )
+                    .child(
+                        div()
+                            .w(px(44.0))
+                            .flex_shrink_0()
+                            .text_color(status_color)
+                            .child(outcome_label(&exchange)),
+                    )
+                    .child(
+                        div()
+                            .w(px(60.0))
+                            .flex_shrink_0()
+                            .text_color(theme.text_muted)
+                            .child(format!("{} ms", exchange.duration.as_millis())),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w(px(0.))
+                            .overflow_hidden()
+                            .child(exchange.url.clone()),
+                    ),
+            )
+            .when(expanded, |el| {
+                el.child(
+                    div()
+                        .px(px(12.0))
+                        .pb(px(10.0))
+                        .flex()
+                        .flex_col()
+                        .gap(px(6.0))
+                        .child(
+                            div()
+                                .id(format!("copy-exchange-{index}"))
+                                .text_xs()
+                                .text_color(theme.link)
+                                .cursor_pointer()
+                                .on_mouse_down(MouseButton::Left, move |_, _window, _cx| {
+                                    copy_to_clipboard(&report);
+                                })
+                                .child("Copy"),
+                        )
+                        .child(
+                            div()
+                                .p(px(8.0))
+                                .rounded(px(6.0))
+                                .bg(theme.code_bg)
+                                .text_xs()
+                                .font_family("SF Mono, Menlo, monospace")
+                                .when_some(exchange.error.clone(), |el, error| {
+                                    el.text_color(theme.error).child(error)
+                                })
+                                .when(exchange.error.is_none(), |el| {
+                                    el.child(if exchange.body.is_empty() {
+                                        "(empty body)".to_string()
+                                    } else {
+                                        exchange.body.clone()
+                                    })
+                                }),
+                        ),
+                )
+            })
+    }
+}
+
+impl Render for InspectorWindow {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Refreshes record new exchanges, so re-render as they finish
+        if self.usage_subscription.is_none() {
+            let usage = cx.global::<AppState>().usage.clone();
+            self.usage_subscription = Some(cx.observe(&usage, |_this, _model, cx| {
+                cx.notify();
+            }));
+        }
+
+        let theme = SettingsTheme::for_mode(
+            cx.global::<AppState>().settings.read(cx).theme_mode(),
+            window.appearance(),
+        );
+        let mut exchanges = inspector::exchanges();
+        exchanges.reverse();
+
+        let header = div()
+            .px(px(12.0))
+            .py(px(8.0))
+            .flex()
+            .items_center()
+            .justify_between()
+            .border_b_1()
+            .border_color(theme.border)
+            .child(div().text_xs().text_color(theme.text_muted).child(format!(
+                "Last {} of up to {} requests; credentials are redacted",
+                exchanges.len(),
+                inspector::CAPACITY
+            )))
+            .child(
+                div()
+                    .id("inspector-clear")
+                    .px(px(10.0))
+                    .py(px(4.0))
+                    .rounded(px(6.0))
+                    .border_1()
+                    .border_color(theme.border)
+                    .bg(theme.surface)
+                    .text_xs()
+                    .cursor_pointer()
+                    .hover(move |s| s.bg(theme.hover))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|this, _, _window, cx| {
+                            inspector::clear();
+                            this.expanded = None;
+                            cx.notify();
+                        }),
+                    )
+                    .child("Clear"),
+            );
+
+        let rows: Vec<Div> = exchanges
+            .into_iter()
+            .enumerate()
+            .map(|(index, exchange)| self.render_exchange(index, exchange, theme, cx))
+            .collect();
+
+        div()
+            .size_full()
+            .bg(theme.bg)
+            .text_color(theme.text_primary)
+            .flex()
+            .flex_col()
+            .child(header)
+            .child(
+                div()
+                    .id("inspector-exchanges")
+                    .flex_1()
+                    .overflow_y_scroll()
+                    .when(rows.is_empty(), |el| {
+                        el.p(px(24.0))
+                            .text_sm()
+                            .text_color(theme.text_muted)
+                            .child("No requests yet. Refresh a provider to record its requests.")
+                    })
+                    .children(rows),
+            )
+    }
+}
+
+/// Opens the network inspector, or focuses it if already open.
+pub fn open_inspector_window(cx: &mut App) {
+    {
+        let guard = INSPECTOR_WINDOW.lock().unwrap();
+        if let Some(handle) = *guard {
+            if cx
+                .update_window(handle, |_, window, _| {
+                    window.activate_window();
+                })
+                .is_ok()
+            {
+                info!("Focused existing inspector window");
+                cx.activate(true);
+                return;
+            }
+            // Window was closed, continue to create new one
+        }
+    }
+
+    info!("Opening inspector window");
+
+    // Menu bar apps must activate before showing a regular window
+    cx.activate(true);
+
+    let bounds = Bounds::centered(None, size(px(760.0), px(480.0)), cx);
+
+    let options = WindowOptions {
+        titlebar: Some(TitlebarOptions {
+            title: Some("Network Inspector".into()),
+            appears_transparent: false,
+            traffic_light_position: None,
+        }),
+        window_bounds: Some(WindowBounds::Windowed(bounds)),
+        focus: true,
+        show: true,
+        kind: gpui::WindowKind::Normal,
+        is_movable: true,
+        display_id: None,
+        window_background: WindowBackgroundAppearance::Opaque,
+        app_id: None,
+        window_min_size: Some(size(px(480.0), px(320.0))),
+        window_decorations: None,
+        is_minimizable: true,
+        is_resizable: true,
+        tabbing_identifier: None,
+    };
+
+    match cx.open_window(options, |window, cx| {
+        cx.new(|cx| {
+            theme::follow_system_appearance(window, cx);
+            InspectorWindow::new()
+        })
+    }) {
+        Ok(handle) => {
+            let mut guard = INSPECTOR_WINDOW.lock().unwrap();
+            *guard = Some(handle.into());
+        }
+        Err(e) => {
+            tracing::error!(error = ?e, "Failed to open inspector window");
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_exchange_report() {
+        let mut exchange = Exchange {
+            started_at: Utc::now(),
+            method: "GET".to_string(),
+            url: "https://api.example.com/usage".to_string(),
+            status: Some(200),
+            duration: Duration::from_millis(120),
+            body: "{}".to_string(),
+            error: None,
+        };
+        let report = exchange_report(&exchange);
+        assert!(report.starts_with("GET https://api.example.com/usage\n200 in 120 ms"));
+        assert!(report.ends_with("\n\n{}"));
+
+        exchange.status = None;
+        exchange.error = Some("connection refused".to_string());
+        let report = exchange_report(&exchange);
+        assert!(report.contains("Failed in 120 ms"));
+        assert!(report.ends_with("connection refused"));
+    }
+}
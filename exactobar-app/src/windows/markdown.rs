@@ -0,0 +1,297 @@
+//! Minimal Markdown rendering for release notes.
+//!
+//! Handles the subset GitHub release notes use in practice: headings,
+//! bullet and numbered lists, code blocks, rules, and paragraphs. Inline
+//! emphasis, code spans, and links are reduced to plain text.
+
+use gpui::prelude::*;
+use gpui::*;
+
+/// A block-level Markdown element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkdownBlock {
+    /// `#` to `######` heading.
+    Heading { level: usize, text: String },
+    /// `-`, `*`, or `+` list item; `depth` counts indentation levels.
+    Bullet { depth: usize, text: String },
+    /// `1.` style list item.
+    Numbered { number: String, text: String },
+    /// Fenced code block.
+    Code(String),
+    /// `---` horizontal rule.
+    Rule,
+    /// Consecutive text lines joined with spaces.
+    Paragraph(String),
+}
+
+/// Parses Markdown text into blocks.
+pub fn parse_markdown(text: &str) -> Vec<MarkdownBlock> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut code: Option<Vec<&str>> = None;
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<MarkdownBlock>| {
+        if !paragraph.is_empty() {
+            blocks.push(MarkdownBlock::Paragraph(strip_inline(&paragraph.join(" "))));
+            paragraph.clear();
+        }
+    };
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+
+        if let Some(lines) = code.as_mut() {
+            if trimmed.starts_with("```") {
+                blocks.push(MarkdownBlock::Code(lines.join("\n")));
+                code = None;
+            } else {
+                lines.push(line);
+            }
+            continue;
+        }
+
+        if trimmed.starts_with("```") {
+            flush(&mut paragraph, &mut blocks);
+            code = Some(Vec::new());
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+            continue;
+        }
+
+        let block = if let Some(heading) = parse_heading(trimmed) {
+            heading
+        } else if is_rule(trimmed) {
+            MarkdownBlock::Rule
+        } else if let Some(rest) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|marker| trimmed.strip_prefix(marker))
+        {
+            let indent = line.len() - line.trim_start().len();
+            MarkdownBlock::Bullet {
+                depth: indent / 2,
+                text: strip_inline(rest.trim()),
+            }
+        } else if let Some((number, rest)) = parse_numbered(trimmed) {
+            MarkdownBlock::Numbered {
+                number: number.to_string(),
+                text: strip_inline(rest),
+            }
+        } else {
+            paragraph.push(trimmed);
+            continue;
+        };
+
+        flush(&mut paragraph, &mut blocks);
+        blocks.push(block);
+    }
+
+    // An unterminated fence still shows its contents
+    if let Some(lines) = code {
+        blocks.push(MarkdownBlock::Code(lines.join("\n")));
+    }
+    flush(&mut paragraph, &mut blocks);
+
+    blocks
+}
+
+fn parse_heading(line: &str) -> Option<MarkdownBlock> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let text = line[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then(|| MarkdownBlock::Heading {
+        level,
+        text: strip_inline(text.trim().trim_end_matches('#').trim()),
+    })
+}
+
+fn parse_numbered(line: &str) -> Option<(&str, &str)> {
+    let (number, rest) = line.split_once(". ")?;
+    (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+        .then(|| (number, rest.trim()))
+}
+
+fn is_rule(line: &str) -> bool {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&c| compact.chars().all(|x| x == c))
+}
+
+/// Reduces inline Markdown to plain text.
+///
+/// Removes emphasis markers and code backticks, and replaces links and
+/// images with their text.
+pub fn strip_inline(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '*' | '`' => rest = &rest[1..],
+            '_' if rest.starts_with("__") => rest = &rest[2..],
+            '!' if rest[1..].starts_with('[') => rest = &rest[1..],
+            '[' => match parse_link(rest) {
+                Some((label, len)) => {
+                    out.push_str(label);
+                    rest = &rest[len..];
+                }
+                None => {
+                    out.push(c);
+                    rest = &rest[1..];
+                }
+            },
+            _ => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    out
+}
+
+/// Parses `[label](url)` at the start of `text`, returning the label and
+/// the length consumed.
+fn parse_link(text: &str) -> Option<(&str, usize)> {
+    let close = text.find("](")?;
+    let end = text[close..].find(')')? + close;
+    Some((&text[1..close], end + 1))
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+/// Renders parsed Markdown blocks.
+pub struct MarkdownView {
+    blocks: Vec<MarkdownBlock>,
+    text_color: Hsla,
+    muted_color: Hsla,
+    code_bg: Hsla,
+}
+
+impl MarkdownView {
+    /// Creates a view of the given Markdown text.
+    pub fn new(text: &str) -> Self {
+        Self {
+            blocks: parse_markdown(text),
+            text_color: hsla(0.0, 0.0, 0.85, 1.0),
+            muted_color: hsla(0.0, 0.0, 0.6, 1.0),
+            code_bg: hsla(0.0, 0.0, 0.08, 1.0),
+        }
+    }
+}
+
+impl IntoElement for MarkdownView {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let text_color = self.text_color;
+        let muted_color = self.muted_color;
+        let code_bg = self.code_bg;
+
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(6.0))
+            .text_sm()
+            .text_color(text_color)
+            .children(self.blocks.into_iter().map(move |block| {
+                match block {
+                    MarkdownBlock::Heading { level, text } => div()
+                        .when(level <= 2, |el| el.mt(px(6.0)).text_base())
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(white())
+                        .child(text),
+                    MarkdownBlock::Bullet { depth, text } => list_item("•".into(), text, depth),
+                    MarkdownBlock::Numbered { number, text } => {
+                        list_item(format!("{}.", number), text, 0)
+                    }
+                    MarkdownBlock::Code(code) => div()
+                        .p(px(8.0))
+                        .rounded(px(4.0))
+                        .bg(code_bg)
+                        .font_family("SF Mono, Menlo, monospace")
+                        .text_xs()
+                        .children(code.lines().map(|line| div().child(line.to_string()))),
+                    MarkdownBlock::Rule => div().h(px(1.0)).bg(muted_color),
+                    MarkdownBlock::Paragraph(text) => div().child(text),
+                }
+            }))
+    }
+}
+
+fn list_item(marker: String, text: String, depth: usize) -> Div {
+    div()
+        .flex()
+        .gap(px(6.0))
+        .pl(px(depth as f32 * 14.0))
+        .child(div().flex_none().child(marker))
+        .child(div().flex_1().child(text))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_markdown() {
+        let text = "## v0.3.0\n\n\
+            ### What's Changed\n\
+            - **New** usage dashboard\n  \
+            - nested item\n\
+            1. First step\n\
+            Some text\ncontinues here.\n\n\
+            ---\n\
+            ```\ncargo install\n```";
+
+        assert_eq!(
+            parse_markdown(text),
+            vec![
+                MarkdownBlock::Heading {
+                    level: 2,
+                    text: "v0.3.0".into()
+                },
+                MarkdownBlock::Heading {
+                    level: 3,
+                    text: "What's Changed".into()
+                },
+                MarkdownBlock::Bullet {
+                    depth: 0,
+                    text: "New usage dashboard".into()
+                },
+                MarkdownBlock::Bullet {
+                    depth: 1,
+                    text: "nested item".into()
+                },
+                MarkdownBlock::Numbered {
+                    number: "1".into(),
+                    text: "First step".into()
+                },
+                MarkdownBlock::Paragraph("Some text continues here.".into()),
+                MarkdownBlock::Rule,
+                MarkdownBlock::Code("cargo install".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strip_inline() {
+        assert_eq!(
+            strip_inline("Fix `fetch` in [#42](https://example.com/42) by __me__"),
+            "Fix fetch in #42 by me"
+        );
+        assert_eq!(
+            strip_inline("![logo](logo.png) [not a link"),
+            "logo [not a link"
+        );
+        assert_eq!(strip_inline("snake_case stays"), "snake_case stays");
+    }
+}
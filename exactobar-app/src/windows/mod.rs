@@ -2,9 +2,20 @@
 
 #![allow(dead_code)]
 
+pub mod about;
+pub mod dashboard;
+pub mod inspector;
+mod markdown;
+pub mod panel;
 pub mod settings;
+pub mod simulator;
 pub mod update;
 
+pub use about::open_about_window;
+pub use dashboard::open_dashboard_window;
+pub use inspector::open_inspector_window;
+pub use panel::open_detached_panel;
+pub use simulator::open_simulator_window;
 pub use update::show_update_dialog;
 
 use gpui::*;
@@ -13,6 +24,8 @@ use tracing::info;
 
 use settings::SettingsWindow;
 
+use crate::theme;
+
 /// Global handle to the settings window (if open).
 static SETTINGS_WINDOW: Mutex<Option<AnyWindowHandle>> = Mutex::new(None);
 
@@ -67,7 +80,10 @@ pub fn open_settings(cx: &mut App) {
 
     let result = cx.open_window(options, |window, cx| {
         window.activate_window();
-        cx.new(|_| SettingsWindow::new())
+        cx.new(|cx| {
+            theme::follow_system_appearance(window, cx);
+            SettingsWindow::new()
+        })
     });
 
     match result {
@@ -0,0 +1,105 @@
+//! Detached (pinned) menu panel window.
+//!
+//! A floating copy of the popup menu that stays open when clicking
+//! elsewhere and remembers where it was last placed.
+
+use exactobar_store::WindowPosition;
+use gpui::*;
+use std::sync::Mutex;
+use tracing::info;
+
+use crate::menu::{self, MenuPanel, SelectedTab};
+use crate::state::AppState;
+use crate::theme;
+
+/// Global handle to the detached panel window (if open).
+static PANEL_WINDOW: Mutex<Option<AnyWindowHandle>> = Mutex::new(None);
+
+/// Opens the detached panel showing `tab`, or focuses it if already open.
+pub fn open_detached_panel(tab: SelectedTab, cx: &mut App) {
+    {
+        let guard = PANEL_WINDOW.lock().unwrap();
+        if let Some(handle) = *guard {
+            if cx
+                .update_window(handle, |_, window, _| {
+                    window.activate_window();
+                })
+                .is_ok()
+            {
+                info!("Focused existing detached panel");
+                return;
+            }
+            // Window was closed, continue to create new one
+        }
+    }
+
+    info!(tab = ?tab, "Opening detached panel");
+
+    let settings = cx.global::<AppState>().settings.read(cx);
+    let saved = settings.detached_panel_position();
+    let theme_mode = settings.theme_mode();
+    let background = settings.window_background();
+    #[cfg(target_os = "macos")]
+    let material = settings.vibrancy_material();
+    let panel_size = menu::panel_size(cx);
+    let bounds = match saved {
+        Some(pos) => Bounds::new(point(px(pos.x), px(pos.y)), panel_size),
+        None => Bounds::centered(None, panel_size, cx),
+    };
+
+    let options = WindowOptions {
+        titlebar: Some(TitlebarOptions {
+            title: Some("ExactoBar".into()),
+            appears_transparent: true,
+            traffic_light_position: None,
+        }),
+        window_bounds: Some(WindowBounds::Windowed(bounds)),
+        focus: true,
+        show: true,
+        kind: WindowKind::Floating,
+        is_movable: true,
+        display_id: None,
+        window_background: theme::window_background_appearance(theme_mode, background),
+        app_id: None,
+        window_min_size: Some(menu::MIN_PANEL_SIZE),
+        window_decorations: None,
+        is_minimizable: true,
+        is_resizable: true,
+        tabbing_identifier: None,
+    };
+
+    let result = cx.open_window(options, |window, cx| {
+        #[cfg(target_os = "macos")]
+        theme::apply_vibrancy_material(window, material);
+        cx.new(|cx| {
+            // Remember where the user drags the panel
+            cx.observe_window_bounds(window, |_this: &mut MenuPanel, window, cx| {
+                let origin = window.bounds().origin;
+                let position = WindowPosition {
+                    x: origin.x.into(),
+                    y: origin.y.into(),
+                };
+                cx.update_global::<AppState, _>(|state, cx| {
+                    state.settings.update(cx, |model, _| {
+                        model.set_detached_panel_position(position);
+                    });
+                });
+            })
+            .detach();
+            menu::remember_panel_size(window, cx);
+            theme::follow_system_appearance(window, cx);
+
+            MenuPanel::detached(tab)
+        })
+    });
+
+    match result {
+        Ok(handle) => {
+            let mut guard = PANEL_WINDOW.lock().unwrap();
+            *guard = Some(handle.into());
+        }
+        Err(e) => {
+            tracing::error!(error = ?e, "Failed to open detached panel");
+        }
+    }
+}
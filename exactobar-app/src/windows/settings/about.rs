@@ -1,17 +1,71 @@
 //! About pane.
+//!
+//! Shown in the Settings window and in the standalone About window.
 
 use gpui::*;
 
 use super::SettingsTheme;
+use crate::menu::{copy_to_clipboard, open_url};
+
+/// Source repository URL.
+const REPOSITORY_URL: &str = env!("CARGO_PKG_REPOSITORY");
+
+/// Version and build details shown in the About pane.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub commit: &'static str,
+    pub gpui: &'static str,
+    pub platform: String,
+    pub license: &'static str,
+}
+
+impl BuildInfo {
+    /// Returns the details of the running build.
+    pub fn current() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            commit: env!("EXACTOBAR_COMMIT"),
+            gpui: env!("EXACTOBAR_GPUI_VERSION"),
+            platform: format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+            license: env!("CARGO_PKG_LICENSE"),
+        }
+    }
+
+    /// Label/value rows, in display order.
+    fn rows(&self) -> [(&'static str, String); 4] {
+        [
+            ("Commit", self.commit.to_string()),
+            ("GPUI", self.gpui.to_string()),
+            ("Platform", self.platform.clone()),
+            ("License", self.license.to_string()),
+        ]
+    }
+
+    /// Plain-text summary for pasting into bug reports.
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!("ExactoBar {}", self.version)];
+        lines.extend(
+            self.rows()
+                .into_iter()
+                .map(|(label, value)| format!("{}: {}", label, value)),
+        );
+        lines.join("\n")
+    }
+}
 
 /// About settings pane.
 pub struct AboutPane {
+    info: BuildInfo,
     theme: SettingsTheme,
 }
 
 impl AboutPane {
     pub fn new(theme: SettingsTheme) -> Self {
-        Self { theme }
+        Self {
+            info: BuildInfo::current(),
+            theme,
+        }
     }
 }
 
@@ -58,7 +112,7 @@ impl IntoElement for AboutPane {
                         div()
                             .text_sm()
                             .text_color(theme.text_muted)
-                            .child(format!("Version {}", env!("CARGO_PKG_VERSION"))),
+                            .child(format!("Version {}", self.info.version)),
                     ),
             )
             .child(
@@ -76,9 +130,19 @@ impl IntoElement for AboutPane {
                     .flex()
                     .gap(px(16.0))
                     .mt(px(16.0))
-                    .child(render_link("GitHub", theme))
-                    .child(render_link("Report Issue", theme)),
+                    .child(render_link("GitHub", REPOSITORY_URL.to_string(), theme))
+                    .child(render_link(
+                        "Report Issue",
+                        format!("{}/issues", REPOSITORY_URL),
+                        theme,
+                    ))
+                    .child(render_link(
+                        "License",
+                        format!("{}/blob/main/LICENSE", REPOSITORY_URL),
+                        theme,
+                    )),
             )
+            .child(render_build_info(&self.info, theme))
             .child(
                 div()
                     .mt(px(40.0))
@@ -111,15 +175,54 @@ impl IntoElement for AboutPane {
     }
 }
 
-fn render_link(label: &'static str, theme: SettingsTheme) -> Div {
+fn render_link(label: &'static str, url: String, theme: SettingsTheme) -> Stateful<Div> {
     div()
+        .id(SharedString::from(format!("about-link-{}", label)))
         .text_sm()
         .text_color(theme.link)
         .cursor_pointer()
         .hover(|s| s.underline())
+        .on_mouse_down(MouseButton::Left, move |_, _window, _cx| {
+            open_url(&url);
+        })
         .child(label)
 }
 
+fn render_build_info(info: &BuildInfo, theme: SettingsTheme) -> Div {
+    let summary = info.summary();
+
+    div()
+        .w(px(320.0))
+        .p(px(12.0))
+        .rounded(px(8.0))
+        .bg(theme.code_bg)
+        .flex()
+        .flex_col()
+        .gap(px(6.0))
+        .children(info.rows().into_iter().map(|(label, value)| {
+            div()
+                .flex()
+                .justify_between()
+                .gap(px(12.0))
+                .text_xs()
+                .child(div().text_color(theme.text_muted).child(label))
+                .child(div().font_family("monospace").child(value))
+        }))
+        .child(
+            div()
+                .id("about-copy-build-info")
+                .mt(px(4.0))
+                .text_xs()
+                .text_color(theme.link)
+                .cursor_pointer()
+                .hover(|s| s.underline())
+                .on_mouse_down(MouseButton::Left, move |_, _window, _cx| {
+                    copy_to_clipboard(&summary);
+                })
+                .child("Copy build info"),
+        )
+}
+
 fn render_tech_badge(name: &'static str, theme: SettingsTheme) -> Div {
     div()
         .px(px(8.0))
@@ -129,3 +232,28 @@ fn render_tech_badge(name: &'static str, theme: SettingsTheme) -> Div {
         .text_xs()
         .child(name)
 }
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_info_summary() {
+        let info = BuildInfo {
+            version: "1.2.3",
+            commit: "abc1234",
+            gpui: "0.2.2 (c67328a)",
+            platform: "macos aarch64".to_string(),
+            license: "MIT",
+        };
+
+        assert_eq!(
+            info.summary(),
+            "ExactoBar 1.2.3\nCommit: abc1234\nGPUI: 0.2.2 (c67328a)\nPlatform: macos aarch64\nLicense: MIT"
+        );
+    }
+}
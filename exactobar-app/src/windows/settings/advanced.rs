@@ -1,42 +1,220 @@
 //! Advanced settings pane.
 
+use exactobar_store::{HistoryRetention, ProxyMode, UpdateChannel};
+use gpui::prelude::*;
 use gpui::*;
 
 use super::SettingsTheme;
-use crate::components::Toggle;
-use crate::state::AppState;
+use super::rows::{ToggleSetting, group_header, pane_header, radio_option, toggle_rows};
+use super::search::matches_query;
+use crate::history;
+use crate::state::{AppState, SettingsModel};
+use crate::windows::{self, update::format_bytes};
+
+const CHANNEL_OPTIONS: [(UpdateChannel, &str, &str); 3] = [
+    (UpdateChannel::Stable, "Stable", "Published releases only"),
+    (
+        UpdateChannel::Beta,
+        "Beta",
+        "Pre-releases and release candidates as well",
+    ),
+    (
+        UpdateChannel::Nightly,
+        "Nightly",
+        "Every build, including untested nightlies",
+    ),
+];
+
+const PROXY_OPTIONS: [(ProxyMode, &str, &str); 3] = [
+    (
+        ProxyMode::System,
+        "System",
+        "The proxy set in System Settings or HTTPS_PROXY",
+    ),
+    (
+        ProxyMode::Manual,
+        "Manual",
+        "The HTTP or SOCKS proxy in the settings file",
+    ),
+    (ProxyMode::Off, "Off", "Connect directly"),
+];
+
+const RETENTION_OPTIONS: [(HistoryRetention, &str); 3] = [
+    (HistoryRetention::NinetyDays, "90 Days"),
+    (HistoryRetention::OneYear, "1 Year"),
+    (HistoryRetention::Forever, "Forever"),
+];
 
 /// Advanced settings pane.
 pub struct AdvancedPane {
-    debug_mode: bool,
-    auto_refresh_on_wake: bool,
-    status_checks_enabled: bool,
-    session_quota_notifications_enabled: bool,
-    cost_usage_enabled: bool,
-    random_blink_enabled: bool,
-    claude_web_extras_enabled: bool,
-    show_optional_credits_and_extra_usage: bool,
-    openai_web_access_enabled: bool,
+    toggles: Vec<ToggleSetting>,
+    /// Whether to offer the state simulator, which only debug mode shows.
+    show_simulator: bool,
+    /// Whether to offer the network inspector, which only debug mode shows.
+    show_inspector: bool,
+    channel: UpdateChannel,
+    show_channel: bool,
+    proxy_mode: ProxyMode,
+    /// URL of the manual proxy, if one is set.
+    proxy_url: Option<String>,
+    show_proxy: bool,
+    retention: HistoryRetention,
+    show_history: bool,
+    /// Size of the saved history, in bytes.
+    history_size: u64,
+    show_paths: bool,
+    /// URL agents connect to, shown while the MCP server is enabled.
+    mcp_endpoint: Option<String>,
+    /// URL Prometheus scrapes, shown while the metrics endpoint is enabled.
+    metrics_endpoint: Option<String>,
+    /// URL WebSocket clients connect to, shown while the WebSocket API is enabled.
+    websocket_endpoint: Option<String>,
     theme: SettingsTheme,
 }
 
 impl AdvancedPane {
-    pub fn new<V: 'static>(cx: &Context<V>, theme: SettingsTheme) -> Self {
-        let state = cx.global::<AppState>();
-        let settings = state.settings.read(cx).settings();
+    pub fn new<V: 'static>(cx: &Context<V>, theme: SettingsTheme, query: &str) -> Self {
+        let settings = cx.global::<AppState>().settings.read(cx).settings();
+
+        let toggles = vec![
+            ToggleSetting {
+                id: "toggle-debug-mode",
+                title: "Debug Mode",
+                description: "Enable verbose logging and the state simulator for troubleshooting",
+                checked: settings.debug_mode,
+                set: SettingsModel::set_debug_mode,
+            },
+            ToggleSetting {
+                id: "toggle-demo-provider",
+                title: "Demo Provider",
+                description: "Show a provider with made-up usage, for UI work and screenshots",
+                checked: settings.demo_provider_enabled,
+                set: SettingsModel::set_demo_provider_enabled,
+            },
+            ToggleSetting {
+                id: "toggle-cost-tracking",
+                title: "Cost Tracking",
+                description: "Track provider costs from local usage logs",
+                checked: settings.cost_usage_enabled,
+                set: SettingsModel::set_cost_usage_enabled,
+            },
+            ToggleSetting {
+                id: "toggle-claude-web-extras",
+                title: "Claude Web Extras",
+                description: "Fetch extra Claude usage via browser cookies",
+                checked: settings.claude_web_extras_enabled,
+                set: SettingsModel::set_claude_web_extras_enabled,
+            },
+            ToggleSetting {
+                id: "toggle-openai-web-access",
+                title: "OpenAI Web Access",
+                description: "Enable OpenAI dashboard access for Codex",
+                checked: settings.openai_web_access_enabled,
+                set: SettingsModel::set_openai_web_access_enabled,
+            },
+            ToggleSetting {
+                id: "toggle-mcp-server",
+                title: "MCP Server",
+                description: "Let coding agents query usage over MCP on localhost (applies after restart)",
+                checked: settings.mcp_server_enabled,
+                set: SettingsModel::set_mcp_server_enabled,
+            },
+            ToggleSetting {
+                id: "toggle-metrics",
+                title: "Prometheus Metrics",
+                description: "Serve usage metrics for Prometheus on localhost (applies after restart)",
+                checked: settings.metrics_enabled,
+                set: SettingsModel::set_metrics_enabled,
+            },
+            ToggleSetting {
+                id: "toggle-websocket",
+                title: "WebSocket API",
+                description: "Stream usage to Stream Deck and other local tools (applies after restart)",
+                checked: settings.websocket_enabled,
+                set: SettingsModel::set_websocket_enabled,
+            },
+            ToggleSetting {
+                id: "toggle-system-certificates",
+                title: "System Certificates",
+                description: "Trust certificates installed on this computer, e.g. a corporate proxy's CA",
+                checked: settings.system_certificates,
+                set: SettingsModel::set_system_certificates,
+            },
+        ];
+
+        let channel_texts: Vec<&str> = ["Update Channel", "updates", "pre-release"]
+            .into_iter()
+            .chain(CHANNEL_OPTIONS.iter().map(|(_, label, _)| *label))
+            .collect();
+
+        let proxy_texts: Vec<&str> = ["Proxy", "network", "SOCKS", "HTTP"]
+            .into_iter()
+            .chain(PROXY_OPTIONS.iter().map(|(_, label, _)| *label))
+            .collect();
+
+        let history_texts: Vec<&str> = ["Usage History", "retention", "Clear History", "disk"]
+            .into_iter()
+            .chain(RETENTION_OPTIONS.iter().map(|(_, label)| *label))
+            .collect();
+        let show_history = matches_query(query, &history_texts);
+
         Self {
-            debug_mode: settings.debug_mode,
-            auto_refresh_on_wake: settings.auto_refresh_on_wake,
-            status_checks_enabled: settings.status_checks_enabled,
-            session_quota_notifications_enabled: settings.session_quota_notifications_enabled,
-            cost_usage_enabled: settings.cost_usage_enabled,
-            random_blink_enabled: settings.random_blink_enabled,
-            claude_web_extras_enabled: settings.claude_web_extras_enabled,
-            show_optional_credits_and_extra_usage: settings.show_optional_credits_and_extra_usage,
-            openai_web_access_enabled: settings.openai_web_access_enabled,
+            toggles: toggles.into_iter().filter(|s| s.matches(query)).collect(),
+            show_simulator: settings.debug_mode
+                && matches_query(query, &["State Simulator", "debug", "simulate"]),
+            show_inspector: settings.debug_mode
+                && matches_query(query, &["Network Inspector", "debug", "requests"]),
+            channel: settings.update_channel,
+            show_channel: matches_query(query, &channel_texts),
+            proxy_mode: settings.proxy_mode,
+            proxy_url: settings
+                .proxy
+                .as_ref()
+                .map(|proxy| proxy.url.trim().to_string())
+                .filter(|url| !url.is_empty()),
+            show_proxy: matches_query(query, &proxy_texts),
+            retention: settings.history_retention,
+            show_history,
+            history_size: if show_history {
+                history::disk_usage()
+            } else {
+                0
+            },
+            show_paths: matches_query(
+                query,
+                &[
+                    "Data Locations",
+                    "Config Directory",
+                    "Cache Directory",
+                    "History File",
+                    "MCP Endpoint",
+                    "Metrics Endpoint",
+                    "WebSocket Endpoint",
+                ],
+            ),
+            mcp_endpoint: settings
+                .mcp_server_enabled
+                .then(|| format!("http://127.0.0.1:{}/mcp", settings.server_port)),
+            metrics_endpoint: settings
+                .metrics_enabled
+                .then(|| format!("http://127.0.0.1:{}/metrics", settings.server_port)),
+            websocket_endpoint: settings
+                .websocket_enabled
+                .then(|| format!("ws://127.0.0.1:{}/ws", settings.server_port)),
             theme,
         }
     }
+
+    /// Returns true if nothing in the pane matches the search query.
+    pub fn is_empty(&self) -> bool {
+        self.toggles.is_empty()
+            && !self.show_simulator
+            && !self.show_inspector
+            && !self.show_channel
+            && !self.show_proxy
+            && !self.show_history
+            && !self.show_paths
+    }
 }
 
 impl IntoElement for AdvancedPane {
@@ -45,7 +223,24 @@ impl IntoElement for AdvancedPane {
     fn into_element(self) -> Self::Element {
         let config_dir = exactobar_store::default_config_dir();
         let cache_dir = exactobar_store::default_cache_dir();
+        let history_path = exactobar_store::default_history_path();
         let theme = self.theme;
+        let show_paths = self.show_paths;
+        let mcp_endpoint = self.mcp_endpoint;
+        let metrics_endpoint = self.metrics_endpoint;
+        let websocket_endpoint = self.websocket_endpoint;
+        let current_channel = self.channel;
+        let current_proxy = self.proxy_mode;
+        let proxy_note = match (&self.proxy_url, current_proxy) {
+            (Some(url), ProxyMode::Manual) => Some(format!("Requests go through {url}")),
+            (None, ProxyMode::Manual) => Some(
+                "Set \"proxy.url\" in settings.json; the system proxy is used until then"
+                    .to_string(),
+            ),
+            _ => None,
+        };
+        let current_retention = self.retention;
+        let history_size = self.history_size;
 
         div()
             .w_full()
@@ -53,433 +248,270 @@ impl IntoElement for AdvancedPane {
             .flex_col()
             .gap(px(24.0))
             .pb(px(24.0))
-            .child(
-                div()
-                    .flex()
-                    .flex_col()
-                    .gap(px(4.0))
-                    .child(
-                        div()
-                            .text_xl()
-                            .font_weight(FontWeight::BOLD)
-                            .child("Advanced"),
-                    )
-                    .child(
-                        div()
-                            .text_sm()
-                            .text_color(theme.text_muted)
-                            .child("Advanced configuration options"),
-                    ),
-            )
-            // Debug Mode
-            .child(
-                div()
-                    .flex()
-                    .items_center()
-                    .justify_between()
-                    .py(px(12.0))
-                    .border_b_1()
-                    .border_color(theme.border)
-                    .child(
-                        div()
-                            .flex()
-                            .flex_col()
-                            .gap(px(2.0))
-                            .child(
-                                div()
-                                    .text_sm()
-                                    .font_weight(FontWeight::MEDIUM)
-                                    .child("Debug Mode"),
-                            )
-                            .child(
-                                div()
-                                    .text_xs()
-                                    .text_color(theme.text_muted)
-                                    .child("Enable verbose logging for troubleshooting"),
-                            ),
-                    )
-                    .child(
-                        Toggle::new("toggle-debug-mode")
-                            .checked(self.debug_mode)
-                            .on_toggle(|enabled, cx| {
-                                cx.update_global::<AppState, _>(|state, cx| {
-                                    state.settings.update(cx, |model, _| {
-                                        model.set_debug_mode(enabled);
-                                    });
-                                });
-                            }),
-                    ),
-            )
-            // Auto-refresh on Wake
-            .child(
-                div()
-                    .flex()
-                    .items_center()
-                    .justify_between()
-                    .py(px(12.0))
-                    .border_b_1()
-                    .border_color(theme.border)
-                    .child(
-                        div()
-                            .flex()
-                            .flex_col()
-                            .gap(px(2.0))
-                            .child(
-                                div()
-                                    .text_sm()
-                                    .font_weight(FontWeight::MEDIUM)
-                                    .child("Auto-refresh on Wake"),
-                            )
-                            .child(
-                                div()
-                                    .text_xs()
-                                    .text_color(theme.text_muted)
-                                    .child("Refresh usage data when your Mac wakes from sleep"),
-                            ),
-                    )
-                    .child(
-                        Toggle::new("toggle-auto-refresh-on-wake")
-                            .checked(self.auto_refresh_on_wake)
-                            .on_toggle(|enabled, cx| {
-                                cx.update_global::<AppState, _>(|state, cx| {
-                                    state.settings.update(cx, |model, _| {
-                                        model.set_auto_refresh_on_wake(enabled);
-                                    });
-                                });
-                            }),
-                    ),
-            )
-            // Status Page Checks
-            .child(
-                div()
-                    .flex()
-                    .items_center()
-                    .justify_between()
-                    .py(px(12.0))
-                    .border_b_1()
-                    .border_color(theme.border)
-                    .child(
-                        div()
-                            .flex()
-                            .flex_col()
-                            .gap(px(2.0))
-                            .child(
-                                div()
-                                    .text_sm()
-                                    .font_weight(FontWeight::MEDIUM)
-                                    .child("Status Page Checks"),
-                            )
-                            .child(
-                                div()
-                                    .text_xs()
-                                    .text_color(theme.text_muted)
-                                    .child("Check provider status pages for outages"),
-                            ),
-                    )
-                    .child(
-                        Toggle::new("toggle-status-checks")
-                            .checked(self.status_checks_enabled)
-                            .on_toggle(|enabled, cx| {
-                                cx.update_global::<AppState, _>(|state, cx| {
-                                    state.settings.update(cx, |model, _| {
-                                        model.set_status_checks_enabled(enabled);
-                                    });
-                                });
-                            }),
-                    ),
-            )
-            // Quota Notifications
-            .child(
-                div()
-                    .flex()
-                    .items_center()
-                    .justify_between()
-                    .py(px(12.0))
-                    .border_b_1()
-                    .border_color(theme.border)
-                    .child(
-                        div()
-                            .flex()
-                            .flex_col()
-                            .gap(px(2.0))
-                            .child(
-                                div()
-                                    .text_sm()
-                                    .font_weight(FontWeight::MEDIUM)
-                                    .child("Quota Notifications"),
-                            )
-                            .child(
-                                div()
-                                    .text_xs()
-                                    .text_color(theme.text_muted)
-                                    .child("Notify when approaching quota limits"),
-                            ),
-                    )
-                    .child(
-                        Toggle::new("toggle-quota-notifications")
-                            .checked(self.session_quota_notifications_enabled)
-                            .on_toggle(|enabled, cx| {
-                                cx.update_global::<AppState, _>(|state, cx| {
-                                    state.settings.update(cx, |model, _| {
-                                        model.set_session_quota_notifications_enabled(enabled);
-                                    });
-                                });
-                            }),
-                    ),
-            )
-            // Cost Tracking
-            .child(
-                div()
-                    .flex()
-                    .items_center()
-                    .justify_between()
-                    .py(px(12.0))
-                    .border_b_1()
-                    .border_color(theme.border)
-                    .child(
-                        div()
-                            .flex()
-                            .flex_col()
-                            .gap(px(2.0))
-                            .child(
-                                div()
-                                    .text_sm()
-                                    .font_weight(FontWeight::MEDIUM)
-                                    .child("Cost Tracking"),
-                            )
-                            .child(
-                                div()
-                                    .text_xs()
-                                    .text_color(theme.text_muted)
-                                    .child("Track provider costs from local usage logs"),
+            .child(pane_header(
+                "Advanced",
+                "Advanced configuration options",
+                theme,
+            ))
+            .when(!self.toggles.is_empty(), |el| {
+                el.child(toggle_rows(self.toggles, theme))
+            })
+            .when(self.show_simulator, |el| {
+                el.child(window_row(
+                    "State Simulator",
+                    "Force providers into edge-case states to test the menu and icons",
+                    "open-simulator",
+                    "Open Simulator…",
+                    theme,
+                    windows::open_simulator_window,
+                ))
+            })
+            .when(self.show_inspector, |el| {
+                el.child(window_row(
+                    "Network Inspector",
+                    "Recent provider requests and responses, with credentials redacted",
+                    "open-inspector",
+                    "Open Inspector…",
+                    theme,
+                    windows::open_inspector_window,
+                ))
+            })
+            .when(self.show_channel, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(group_header(
+                            "Update Channel",
+                            Some("Which releases ExactoBar offers when checking for updates"),
+                            theme,
+                        ))
+                        .child(
+                            div().flex().flex_col().gap(px(4.0)).children(
+                                CHANNEL_OPTIONS.iter().map(
+                                    move |&(channel, label, description)| {
+                                        radio_option(
+                                            label,
+                                            Some(description),
+                                            current_channel == channel,
+                                            theme,
+                                            move |cx| {
+                                                cx.update_global::<AppState, _>(|state, cx| {
+                                                    state.settings.update(cx, |model, _| {
+                                                        model.set_update_channel(channel);
+                                                    });
+                                                });
+                                            },
+                                        )
+                                    },
+                                ),
                             ),
-                    )
-                    .child(
-                        Toggle::new("toggle-cost-tracking")
-                            .checked(self.cost_usage_enabled)
-                            .on_toggle(|enabled, cx| {
-                                cx.update_global::<AppState, _>(|state, cx| {
-                                    state.settings.update(cx, |model, _| {
-                                        model.set_cost_usage_enabled(enabled);
-                                    });
-                                });
-                            }),
-                    ),
-            )
-            // Random Blink
-            .child(
-                div()
-                    .flex()
-                    .items_center()
-                    .justify_between()
-                    .py(px(12.0))
-                    .border_b_1()
-                    .border_color(theme.border)
-                    .child(
-                        div()
-                            .flex()
-                            .flex_col()
-                            .gap(px(2.0))
-                            .child(
-                                div()
-                                    .text_sm()
-                                    .font_weight(FontWeight::MEDIUM)
-                                    .child("Random Blink"),
-                            )
-                            .child(
-                                div()
-                                    .text_xs()
-                                    .text_color(theme.text_muted)
-                                    .child("Enable random blink animation on status icon"),
+                        ),
+                )
+            })
+            .when(self.show_proxy, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(group_header(
+                            "Proxy",
+                            Some("How ExactoBar reaches providers and update servers"),
+                            theme,
+                        ))
+                        .child(
+                            div().flex().flex_col().gap(px(4.0)).children(
+                                PROXY_OPTIONS
+                                    .iter()
+                                    .map(move |&(mode, label, description)| {
+                                        radio_option(
+                                            label,
+                                            Some(description),
+                                            current_proxy == mode,
+                                            theme,
+                                            move |cx| {
+                                                cx.update_global::<AppState, _>(|state, cx| {
+                                                    state.settings.update(cx, |model, _| {
+                                                        model.set_proxy_mode(mode);
+                                                    });
+                                                });
+                                            },
+                                        )
+                                    }),
                             ),
-                    )
-                    .child(
-                        Toggle::new("toggle-random-blink")
-                            .checked(self.random_blink_enabled)
-                            .on_toggle(|enabled, cx| {
-                                cx.update_global::<AppState, _>(|state, cx| {
-                                    state.settings.update(cx, |model, _| {
-                                        model.set_random_blink_enabled(enabled);
-                                    });
-                                });
+                        )
+                        .when_some(proxy_note, |el, note| {
+                            el.child(div().text_xs().text_color(theme.text_muted).child(note))
+                        }),
+                )
+            })
+            .when(self.show_history, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(group_header(
+                            "Usage History",
+                            Some("How long recorded usage is kept for the dashboard's charts"),
+                            theme,
+                        ))
+                        .child(div().flex().flex_col().gap(px(4.0)).children(
+                            RETENTION_OPTIONS.iter().map(move |&(retention, label)| {
+                                radio_option(
+                                    label,
+                                    None,
+                                    current_retention == retention,
+                                    theme,
+                                    move |cx| {
+                                        cx.update_global::<AppState, _>(|state, cx| {
+                                            state.settings.update(cx, |model, _| {
+                                                model.set_history_retention(retention);
+                                            });
+                                        });
+                                    },
+                                )
                             }),
-                    ),
-            )
-            // Claude Web Extras
-            .child(
-                div()
-                    .flex()
-                    .items_center()
-                    .justify_between()
-                    .py(px(12.0))
-                    .border_b_1()
-                    .border_color(theme.border)
-                    .child(
-                        div()
-                            .flex()
-                            .flex_col()
-                            .gap(px(2.0))
-                            .child(
-                                div()
-                                    .text_sm()
-                                    .font_weight(FontWeight::MEDIUM)
-                                    .child("Claude Web Extras"),
-                            )
-                            .child(
-                                div()
-                                    .text_xs()
-                                    .text_color(theme.text_muted)
-                                    .child("Fetch extra Claude usage via browser cookies"),
-                            ),
-                    )
-                    .child(
-                        Toggle::new("toggle-claude-web-extras")
-                            .checked(self.claude_web_extras_enabled)
-                            .on_toggle(|enabled, cx| {
-                                cx.update_global::<AppState, _>(|state, cx| {
-                                    state.settings.update(cx, |model, _| {
-                                        model.set_claude_web_extras_enabled(enabled);
-                                    });
-                                });
-                            }),
-                    ),
-            )
-            // Show Credits & Extras
-            .child(
-                div()
-                    .flex()
-                    .items_center()
-                    .justify_between()
-                    .py(px(12.0))
-                    .border_b_1()
-                    .border_color(theme.border)
-                    .child(
-                        div()
-                            .flex()
-                            .flex_col()
-                            .gap(px(2.0))
-                            .child(
-                                div()
-                                    .text_sm()
-                                    .font_weight(FontWeight::MEDIUM)
-                                    .child("Show Credits & Extras"),
-                            )
-                            .child(
-                                div()
-                                    .text_xs()
-                                    .text_color(theme.text_muted)
-                                    .child("Show credits and extra usage in menu"),
-                            ),
-                    )
-                    .child(
-                        Toggle::new("toggle-show-credits-extras")
-                            .checked(self.show_optional_credits_and_extra_usage)
-                            .on_toggle(|enabled, cx| {
-                                cx.update_global::<AppState, _>(|state, cx| {
-                                    state.settings.update(cx, |model, _| {
-                                        model.set_show_optional_credits_and_extra_usage(enabled);
-                                    });
-                                });
-                            }),
-                    ),
-            )
-            // OpenAI Web Access
-            .child(
-                div()
-                    .flex()
-                    .items_center()
-                    .justify_between()
-                    .py(px(12.0))
-                    .border_b_1()
-                    .border_color(theme.border)
-                    .child(
-                        div()
-                            .flex()
-                            .flex_col()
-                            .gap(px(2.0))
-                            .child(
-                                div()
-                                    .text_sm()
-                                    .font_weight(FontWeight::MEDIUM)
-                                    .child("OpenAI Web Access"),
-                            )
-                            .child(
-                                div()
-                                    .text_xs()
-                                    .text_color(theme.text_muted)
-                                    .child("Enable OpenAI dashboard access for Codex"),
-                            ),
-                    )
-                    .child(
-                        Toggle::new("toggle-openai-web-access")
-                            .checked(self.openai_web_access_enabled)
-                            .on_toggle(|enabled, cx| {
-                                cx.update_global::<AppState, _>(|state, cx| {
-                                    state.settings.update(cx, |model, _| {
-                                        model.set_openai_web_access_enabled(enabled);
-                                    });
-                                });
-                            }),
-                    ),
-            )
+                        ))
+                        .child(
+                            div()
+                                .flex()
+                                .items_center()
+                                .justify_between()
+                                .gap(px(12.0))
+                                .child(div().text_xs().text_color(theme.text_muted).child(format!(
+                                    "History uses {} on disk",
+                                    format_bytes(history_size as f64)
+                                )))
+                                .child(
+                                    div()
+                                        .id("clear-history")
+                                        .px(px(12.0))
+                                        .py(px(6.0))
+                                        .rounded(px(6.0))
+                                        .bg(theme.surface)
+                                        .border_1()
+                                        .border_color(theme.border)
+                                        .text_sm()
+                                        .cursor_pointer()
+                                        .hover(move |s| s.bg(theme.hover))
+                                        .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                                            confirm_clear_history(window, cx);
+                                        })
+                                        .child("Clear History…"),
+                                ),
+                        ),
+                )
+            })
             // Paths section
-            .child(
-                div()
-                    .mt(px(12.0))
-                    .flex()
-                    .flex_col()
-                    .gap(px(12.0))
-                    .child(
-                        div()
-                            .text_base()
-                            .font_weight(FontWeight::SEMIBOLD)
-                            .child("Data Locations"),
-                    )
-                    .child(
-                        div()
-                            .p(px(12.0))
-                            .rounded(px(8.0))
-                            .bg(theme.code_bg)
-                            .flex()
-                            .flex_col()
-                            .gap(px(8.0))
-                            .child(
-                                div()
-                                    .flex()
-                                    .flex_col()
-                                    .gap(px(2.0))
-                                    .child(
-                                        div()
-                                            .text_xs()
-                                            .text_color(theme.text_muted)
-                                            .child("Config Directory"),
-                                    )
-                                    .child(
-                                        div()
-                                            .text_xs()
-                                            .font_family("monospace")
-                                            .child(config_dir.display().to_string()),
-                                    ),
-                            )
-                            .child(
-                                div()
-                                    .flex()
-                                    .flex_col()
-                                    .gap(px(2.0))
-                                    .child(
-                                        div()
-                                            .text_xs()
-                                            .text_color(theme.text_muted)
-                                            .child("Cache Directory"),
-                                    )
-                                    .child(
-                                        div()
-                                            .text_xs()
-                                            .font_family("monospace")
-                                            .child(cache_dir.display().to_string()),
-                                    ),
-                            ),
-                    ),
-            )
+            .when(show_paths, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(
+                            div()
+                                .text_base()
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .child("Data Locations"),
+                        )
+                        .child(
+                            div()
+                                .p(px(12.0))
+                                .rounded(px(8.0))
+                                .bg(theme.code_bg)
+                                .flex()
+                                .flex_col()
+                                .gap(px(8.0))
+                                .child(location_row(
+                                    "Config Directory",
+                                    config_dir.display().to_string(),
+                                    theme,
+                                ))
+                                .child(location_row(
+                                    "Cache Directory",
+                                    cache_dir.display().to_string(),
+                                    theme,
+                                ))
+                                .child(location_row(
+                                    "History File",
+                                    history_path.display().to_string(),
+                                    theme,
+                                ))
+                                .when_some(mcp_endpoint, |el, endpoint| {
+                                    el.child(location_row("MCP Endpoint", endpoint, theme))
+                                })
+                                .when_some(metrics_endpoint, |el, endpoint| {
+                                    el.child(location_row("Metrics Endpoint", endpoint, theme))
+                                })
+                                .when_some(websocket_endpoint, |el, endpoint| {
+                                    el.child(location_row("WebSocket Endpoint", endpoint, theme))
+                                }),
+                        ),
+                )
+            })
     }
 }
+
+/// Asks before deleting all recorded history.
+fn confirm_clear_history(window: &mut Window, cx: &mut App) {
+    let answer = window.prompt(
+        PromptLevel::Warning,
+        "Clear usage history?",
+        Some("The dashboard's charts and reset history start over. This can't be undone."),
+        &["Clear History", "Cancel"],
+        cx,
+    );
+    cx.spawn(async move |cx| {
+        if answer.await == Ok(0) {
+            let _ = cx.update(|cx| history::clear(cx));
+        }
+    })
+    .detach();
+}
+
+/// A developer window's description and the button that opens it.
+fn window_row(
+    title: &'static str,
+    description: &'static str,
+    button_id: &'static str,
+    button_label: &'static str,
+    theme: SettingsTheme,
+    open: fn(&mut App),
+) -> Div {
+    div()
+        .flex()
+        .items_center()
+        .justify_between()
+        .gap(px(12.0))
+        .child(group_header(title, Some(description), theme))
+        .child(
+            div()
+                .id(button_id)
+                .flex_shrink_0()
+                .px(px(12.0))
+                .py(px(6.0))
+                .rounded(px(6.0))
+                .bg(theme.surface)
+                .border_1()
+                .border_color(theme.border)
+                .text_sm()
+                .cursor_pointer()
+                .hover(move |s| s.bg(theme.hover))
+                .on_mouse_down(MouseButton::Left, move |_, _window, cx| open(cx))
+                .child(button_label),
+        )
+}
+
+/// A labeled path or URL in the Data Locations box.
+fn location_row(label: &'static str, value: String, theme: SettingsTheme) -> Div {
+    div()
+        .flex()
+        .flex_col()
+        .gap(px(2.0))
+        .child(div().text_xs().text_color(theme.text_muted).child(label))
+        .child(div().text_xs().font_family("monospace").child(value))
+}
@@ -0,0 +1,574 @@
+//! Display settings pane.
+
+use exactobar_store::{ThemeMode, TimeFormat, UsageGradient, VibrancyMaterial, WindowBackground};
+use gpui::prelude::*;
+use gpui::*;
+
+use super::SettingsTheme;
+use super::rows::{ToggleSetting, group_header, pane_header, radio_option, toggle_rows};
+use super::search::matches_query;
+use crate::hotkey::Hotkey;
+use crate::state::{AppState, SettingsModel};
+
+const THEME_OPTIONS: [(ThemeMode, &str, &str); 4] = [
+    (
+        ThemeMode::Dark,
+        "Dark",
+        "Always use dark theme (recommended for liquid glass effect)",
+    ),
+    (
+        ThemeMode::Light,
+        "Light",
+        "Use light theme for better readability on bright backgrounds",
+    ),
+    (
+        ThemeMode::System,
+        "System",
+        "Follow system appearance (auto-switch based on OS setting)",
+    ),
+    (
+        ThemeMode::HighContrast,
+        "High Contrast",
+        "Opaque backgrounds, stronger borders, and brighter text",
+    ),
+];
+
+const WINDOW_BACKGROUND_OPTIONS: [(WindowBackground, &str, &str); 3] = [
+    (
+        WindowBackground::Blurred,
+        "Blurred",
+        "Liquid glass: the desktop shows through, blurred",
+    ),
+    (
+        WindowBackground::Translucent,
+        "Translucent",
+        "A tinted background the desktop shows through unblurred",
+    ),
+    (
+        WindowBackground::Opaque,
+        "Opaque",
+        "A solid background, easiest to read over busy wallpapers",
+    ),
+];
+
+const VIBRANCY_MATERIAL_OPTIONS: [(VibrancyMaterial, &str, &str); 6] = [
+    (
+        VibrancyMaterial::Standard,
+        "Standard",
+        "The default ExactoBar glass",
+    ),
+    (VibrancyMaterial::Menu, "Menu", "Matches the system menus"),
+    (
+        VibrancyMaterial::Popover,
+        "Popover",
+        "Matches system popovers",
+    ),
+    (
+        VibrancyMaterial::Sidebar,
+        "Sidebar",
+        "Lighter, like a Finder sidebar",
+    ),
+    (
+        VibrancyMaterial::HudWindow,
+        "HUD",
+        "Dark and heavily blurred, like the volume overlay",
+    ),
+    (
+        VibrancyMaterial::WindowBackground,
+        "Window",
+        "Nearly solid, with only a hint of the desktop",
+    ),
+];
+
+const TIME_FORMAT_OPTIONS: [(TimeFormat, &str, &str); 3] = [
+    (
+        TimeFormat::System,
+        "System",
+        "Follow the clock format of your language and region",
+    ),
+    (
+        TimeFormat::TwelveHour,
+        "12-Hour",
+        "Show reset times like 3:00 PM",
+    ),
+    (
+        TimeFormat::TwentyFourHour,
+        "24-Hour",
+        "Show reset times like 15:00",
+    ),
+];
+
+/// Usage color breakpoints offered in the pane: where usage turns yellow,
+/// and where it turns red (and quota alerts start).
+const USAGE_COLOR_OPTIONS: [(f32, f32, &str, &str); 3] = [
+    (50.0, 80.0, "Standard", "Yellow from 50% used, red from 80%"),
+    (40.0, 60.0, "Early", "Yellow from 40% used, red from 60%"),
+    (70.0, 90.0, "Late", "Yellow from 70% used, red from 90%"),
+];
+
+/// Global shortcuts offered in the pane; others can be set in the settings
+/// file (e.g. "ctrl+alt+space").
+#[cfg(target_os = "macos")]
+const HOTKEY_OPTIONS: [(Option<&str>, &str); 4] = [
+    (None, "Off"),
+    (Some("alt+shift+e"), "⌥⇧E"),
+    (Some("ctrl+alt+e"), "⌃⌥E"),
+    (Some("shift+cmd+e"), "⇧⌘E"),
+];
+
+#[cfg(not(target_os = "macos"))]
+const HOTKEY_OPTIONS: [(Option<&str>, &str); 4] = [
+    (None, "Off"),
+    (Some("alt+shift+e"), "Alt+Shift+E"),
+    (Some("ctrl+alt+e"), "Ctrl+Alt+E"),
+    (Some("shift+super+e"), "Shift+Super+E"),
+];
+
+/// Display settings pane (theme, menu bar icon, and menu layout).
+pub struct DisplayPane {
+    theme_mode: ThemeMode,
+    show_theme: bool,
+    window_background: WindowBackground,
+    show_window_background: bool,
+    vibrancy_material: VibrancyMaterial,
+    show_vibrancy_material: bool,
+    time_format: TimeFormat,
+    show_time_format: bool,
+    usage_gradient: UsageGradient,
+    show_usage_colors: bool,
+    hotkey: Option<Hotkey>,
+    show_hotkey: bool,
+    icon_settings: Vec<ToggleSetting>,
+    menu_settings: Vec<ToggleSetting>,
+    theme: SettingsTheme,
+}
+
+impl DisplayPane {
+    pub fn new<V: 'static>(cx: &Context<V>, theme: SettingsTheme, query: &str) -> Self {
+        let settings = cx.global::<AppState>().settings.read(cx).settings();
+
+        let mut icon_settings = vec![
+            ToggleSetting {
+                id: "toggle-merge-icons",
+                title: "Merge Icons",
+                description: "Show a single icon instead of one per provider",
+                checked: settings.merge_icons,
+                set: SettingsModel::set_merge_icons,
+            },
+            ToggleSetting {
+                id: "toggle-menu-bar-brand-icon-percent",
+                title: "Brand Icon with Percent",
+                description: "Show provider icon and percentage in menu bar",
+                checked: settings.menu_bar_shows_brand_icon_with_percent,
+                set: SettingsModel::set_menu_bar_shows_brand_icon_with_percent,
+            },
+            ToggleSetting {
+                id: "toggle-random-blink",
+                title: "Random Blink",
+                description: "Enable random blink animation on status icon",
+                checked: settings.random_blink_enabled,
+                set: SettingsModel::set_random_blink_enabled,
+            },
+            ToggleSetting {
+                id: "toggle-reduce-motion",
+                title: "Reduce Motion",
+                description: "Turn off icon animations (always on when the system reduces motion)",
+                checked: settings.reduce_motion,
+                set: SettingsModel::set_reduce_motion,
+            },
+        ];
+
+        if cfg!(target_os = "macos") {
+            icon_settings.push(ToggleSetting {
+                id: "toggle-show-dock-icon",
+                title: "Show in Dock",
+                description: "Run as a regular app with a Dock icon and app menu",
+                checked: settings.show_dock_icon,
+                set: SettingsModel::set_show_dock_icon,
+            });
+        }
+
+        let menu_settings = vec![
+            ToggleSetting {
+                id: "toggle-usage-bars-show-used",
+                title: "Show Used Percent",
+                description: "Progress bars show percent used instead of percent remaining",
+                checked: settings.usage_bars_show_used,
+                set: SettingsModel::set_usage_bars_show_used,
+            },
+            ToggleSetting {
+                id: "toggle-reset-times-absolute",
+                title: "Absolute Reset Times",
+                description: "Show reset times as clock values instead of countdowns",
+                checked: settings.reset_times_show_absolute,
+                set: SettingsModel::set_reset_times_show_absolute,
+            },
+            ToggleSetting {
+                id: "toggle-switcher-shows-icons",
+                title: "Switcher Shows Icons",
+                description: "Show provider icons in the quick switcher",
+                checked: settings.switcher_shows_icons,
+                set: SettingsModel::set_switcher_shows_icons,
+            },
+            ToggleSetting {
+                id: "toggle-compact-menu",
+                title: "Compact Menu",
+                description: "Collapse each provider card to a single line",
+                checked: settings.compact_menu,
+                set: SettingsModel::set_compact_menu,
+            },
+            ToggleSetting {
+                id: "toggle-totals-card",
+                title: "Totals Card",
+                description: "Show spend today, providers near their limits and the next reset atop the All view",
+                checked: settings.show_totals_card,
+                set: SettingsModel::set_show_totals_card,
+            },
+            ToggleSetting {
+                id: "toggle-group-tabs",
+                title: "Group Tabs",
+                description: "Add a switcher tab for each provider group in the settings file",
+                checked: settings.group_tabs,
+                set: SettingsModel::set_group_tabs,
+            },
+            ToggleSetting {
+                id: "toggle-confirm-quit",
+                title: "Confirm Quit",
+                description: "Click Quit in the menu twice to quit, since monitoring stops until the next launch",
+                checked: settings.confirm_quit,
+                set: SettingsModel::set_confirm_quit,
+            },
+            ToggleSetting {
+                id: "toggle-quit-requires-modifier",
+                title: "Hold Option to Quit",
+                description: "Quit in the menu only works while holding Option (Alt on Linux and Windows)",
+                checked: settings.quit_requires_modifier,
+                set: SettingsModel::set_quit_requires_modifier,
+            },
+            ToggleSetting {
+                id: "toggle-show-credits-extras",
+                title: "Show Credits & Extras",
+                description: "Show credits and extra usage in menu",
+                checked: settings.show_optional_credits_and_extra_usage,
+                set: SettingsModel::set_show_optional_credits_and_extra_usage,
+            },
+        ];
+
+        let theme_texts: Vec<&str> = ["Theme", "appearance"]
+            .into_iter()
+            .chain(THEME_OPTIONS.iter().map(|(_, label, _)| *label))
+            .collect();
+
+        let window_background_texts: Vec<&str> =
+            ["Window Background", "transparency", "blur", "glass"]
+                .into_iter()
+                .chain(WINDOW_BACKGROUND_OPTIONS.iter().map(|(_, label, _)| *label))
+                .collect();
+
+        let vibrancy_material_texts: Vec<&str> = ["Glass Material", "vibrancy", "blur"]
+            .into_iter()
+            .chain(VIBRANCY_MATERIAL_OPTIONS.iter().map(|(_, label, _)| *label))
+            .collect();
+
+        let time_format_texts: Vec<&str> = ["Time Format", "clock", "24-hour", "reset"]
+            .into_iter()
+            .chain(TIME_FORMAT_OPTIONS.iter().map(|(_, label, _)| *label))
+            .collect();
+
+        let usage_color_texts: Vec<&str> = ["Usage Colors", "gradient", "red", "threshold"]
+            .into_iter()
+            .chain(USAGE_COLOR_OPTIONS.iter().map(|(_, _, label, _)| *label))
+            .collect();
+
+        let hotkey_texts: Vec<&str> = ["Keyboard Shortcut", "hotkey", "global", "toggle menu"]
+            .into_iter()
+            .chain(HOTKEY_OPTIONS.iter().map(|(_, label)| *label))
+            .collect();
+
+        Self {
+            theme_mode: settings.theme_mode,
+            show_theme: matches_query(query, &theme_texts),
+            window_background: settings.window_background,
+            show_window_background: matches_query(query, &window_background_texts),
+            vibrancy_material: settings.vibrancy_material,
+            // Only blurred macOS windows have a material
+            show_vibrancy_material: cfg!(target_os = "macos")
+                && settings.window_background == WindowBackground::Blurred
+                && matches_query(query, &vibrancy_material_texts),
+            time_format: settings.time_format,
+            show_time_format: matches_query(query, &time_format_texts),
+            usage_gradient: settings.usage_gradient,
+            show_usage_colors: matches_query(query, &usage_color_texts),
+            hotkey: settings
+                .global_hotkey
+                .as_deref()
+                .and_then(|value| Hotkey::parse(value).ok()),
+            show_hotkey: matches_query(query, &hotkey_texts),
+            icon_settings: icon_settings
+                .into_iter()
+                .filter(|s| s.matches(query))
+                .collect(),
+            menu_settings: menu_settings
+                .into_iter()
+                .filter(|s| s.matches(query))
+                .collect(),
+            theme,
+        }
+    }
+
+    /// Returns true if nothing in the pane matches the search query.
+    pub fn is_empty(&self) -> bool {
+        !self.show_theme
+            && !self.show_window_background
+            && !self.show_vibrancy_material
+            && !self.show_time_format
+            && !self.show_usage_colors
+            && !self.show_hotkey
+            && self.icon_settings.is_empty()
+            && self.menu_settings.is_empty()
+    }
+}
+
+impl IntoElement for DisplayPane {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let theme = self.theme;
+        let current = self.theme_mode;
+        let background = self.window_background;
+        let material = self.vibrancy_material;
+        let time_format = self.time_format;
+        let gradient = self.usage_gradient;
+        let hotkey = self.hotkey;
+
+        div()
+            .w_full()
+            .flex()
+            .flex_col()
+            .gap(px(24.0))
+            .pb(px(24.0))
+            .child(pane_header(
+                "Display",
+                "Customize how ExactoBar looks",
+                theme,
+            ))
+            .when(self.show_theme, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(group_header(
+                            "Theme",
+                            Some("Choose your preferred appearance"),
+                            theme,
+                        ))
+                        .child(
+                            div().flex().flex_col().gap(px(4.0)).children(
+                                THEME_OPTIONS
+                                    .iter()
+                                    .map(move |&(mode, label, description)| {
+                                        radio_option(
+                                            label,
+                                            Some(description),
+                                            current == mode,
+                                            theme,
+                                            move |cx| {
+                                                // Get settings entity first, then update (avoids nested borrows)
+                                                let settings =
+                                                    cx.global::<AppState>().settings.clone();
+                                                settings.update(cx, |model, cx| {
+                                                    model.set_theme_mode(mode);
+                                                    cx.notify();
+                                                });
+                                            },
+                                        )
+                                    }),
+                            ),
+                        ),
+                )
+            })
+            .when(self.show_window_background, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(group_header(
+                            "Window Background",
+                            Some("What shows through behind the menu (applies when reopened)"),
+                            theme,
+                        ))
+                        .child(div().flex().flex_col().gap(px(4.0)).children(
+                            WINDOW_BACKGROUND_OPTIONS.iter().map(
+                                move |&(option, label, description)| {
+                                    radio_option(
+                                        label,
+                                        Some(description),
+                                        background == option,
+                                        theme,
+                                        move |cx| {
+                                            let settings = cx.global::<AppState>().settings.clone();
+                                            settings.update(cx, |model, cx| {
+                                                model.set_window_background(option);
+                                                cx.notify();
+                                            });
+                                        },
+                                    )
+                                },
+                            ),
+                        )),
+                )
+            })
+            .when(self.show_vibrancy_material, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(group_header(
+                            "Glass Material",
+                            Some("How the blurred background is tinted"),
+                            theme,
+                        ))
+                        .child(div().flex().flex_col().gap(px(4.0)).children(
+                            VIBRANCY_MATERIAL_OPTIONS.iter().map(
+                                move |&(option, label, description)| {
+                                    radio_option(
+                                        label,
+                                        Some(description),
+                                        material == option,
+                                        theme,
+                                        move |cx| {
+                                            let settings = cx.global::<AppState>().settings.clone();
+                                            settings.update(cx, |model, cx| {
+                                                model.set_vibrancy_material(option);
+                                                cx.notify();
+                                            });
+                                        },
+                                    )
+                                },
+                            ),
+                        )),
+                )
+            })
+            .when(self.show_usage_colors, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(group_header(
+                            "Usage Colors",
+                            Some("When bars, icons, and alerts turn yellow and red"),
+                            theme,
+                        ))
+                        .child(div().flex().flex_col().gap(px(4.0)).children(
+                            USAGE_COLOR_OPTIONS.iter().map(
+                                move |&(warning_percent, danger_percent, label, description)| {
+                                    radio_option(
+                                        label,
+                                        Some(description),
+                                        gradient.breakpoints() == (warning_percent, danger_percent),
+                                        theme,
+                                        move |cx| {
+                                            let settings = cx.global::<AppState>().settings.clone();
+                                            settings.update(cx, |model, cx| {
+                                                // Hues edited in the settings file are kept
+                                                model.set_usage_gradient(UsageGradient {
+                                                    warning_percent,
+                                                    danger_percent,
+                                                    ..model.usage_gradient()
+                                                });
+                                                cx.notify();
+                                            });
+                                        },
+                                    )
+                                },
+                            ),
+                        )),
+                )
+            })
+            .when(!self.icon_settings.is_empty(), |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(4.0))
+                        .child(group_header("Menu Bar Icon", None, theme))
+                        .child(toggle_rows(self.icon_settings, theme)),
+                )
+            })
+            .when(!self.menu_settings.is_empty(), |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(4.0))
+                        .child(group_header("Menu", None, theme))
+                        .child(toggle_rows(self.menu_settings, theme)),
+                )
+            })
+            .when(self.show_hotkey, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(group_header(
+                            "Keyboard Shortcut",
+                            Some("Opens and closes the menu from any app"),
+                            theme,
+                        ))
+                        .child(div().flex().flex_col().gap(px(4.0)).children(
+                            HOTKEY_OPTIONS.iter().map(move |&(value, label)| {
+                                let selected =
+                                    value.and_then(|value| Hotkey::parse(value).ok()) == hotkey;
+                                radio_option(label, None, selected, theme, move |cx| {
+                                    let settings = cx.global::<AppState>().settings.clone();
+                                    settings.update(cx, |model, cx| {
+                                        model.set_global_hotkey(value.map(String::from));
+                                        cx.notify();
+                                    });
+                                })
+                            }),
+                        )),
+                )
+            })
+            .when(self.show_time_format, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(group_header(
+                            "Time Format",
+                            Some("Clock used for reset times"),
+                            theme,
+                        ))
+                        .child(
+                            div().flex().flex_col().gap(px(4.0)).children(
+                                TIME_FORMAT_OPTIONS.iter().map(
+                                    move |&(format, label, description)| {
+                                        radio_option(
+                                            label,
+                                            Some(description),
+                                            time_format == format,
+                                            theme,
+                                            move |cx| {
+                                                let settings =
+                                                    cx.global::<AppState>().settings.clone();
+                                                settings.update(cx, |model, cx| {
+                                                    model.set_time_format(format);
+                                                    cx.notify();
+                                                });
+                                            },
+                                        )
+                                    },
+                                ),
+                            ),
+                        ),
+                )
+            })
+    }
+}
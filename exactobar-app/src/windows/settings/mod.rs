@@ -1,27 +1,43 @@
 //! Settings window.
+//!
+//! Settings are grouped into sidebar sections; the search field above them
+//! filters every section by setting name.
 
 mod about;
 mod advanced;
-mod general;
+mod display;
+mod notifications;
 mod providers;
+mod refresh;
+mod rows;
+mod search;
+mod setup;
 mod theme;
 
 use gpui::prelude::*;
 use gpui::*;
 
 use exactobar_core::ProviderKind;
-use exactobar_store::{CookieSource, DataSourceMode};
+use exactobar_store::{CookieSource, DataSourceMode, QuickAction};
 
-use about::AboutPane;
+pub use about::AboutPane;
 use advanced::AdvancedPane;
-use general::GeneralPane;
+use display::DisplayPane;
+use notifications::NotificationsPane;
 use providers::{
-    COOKIE_SOURCES, DATA_SOURCE_MODES, ProviderRowData, ProviderStatus, collect_provider_data,
-    get_install_command, prompt_for_api_key_async,
+    COLOR_SWATCHES, COOKIE_SOURCES, DATA_SOURCE_MODES, ProviderRowData, ProviderStatus,
+    STALE_AFTER_OPTIONS, collect_provider_data, get_install_command, prompt_for_api_key_async,
+    prompt_for_color_async, prompt_for_quick_action_async,
 };
+use refresh::RefreshPane;
+use rows::pane_header;
+use search::matches_query;
+use setup::ProviderSetup;
+pub use setup::reauthenticate;
 pub use theme::SettingsTheme;
 
 use crate::components::ProviderIcon;
+use crate::menu::default_quick_actions;
 use crate::state::AppState;
 
 // ============================================================================
@@ -32,23 +48,70 @@ use crate::state::AppState;
 pub struct SettingsWindow {
     active_pane: SettingsPane,
     settings_subscription: Option<gpui::Subscription>,
+    /// Text typed into the sidebar search field.
+    search_query: String,
+    /// Focus handle for the search field (created on first render).
+    search_focus: Option<FocusHandle>,
+    /// Provider being set up, shown in place of the providers list.
+    setup: Option<ProviderSetup>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum SettingsPane {
     #[default]
-    General,
     Providers,
+    Display,
+    Notifications,
+    Refresh,
     Advanced,
     About,
 }
 
+impl SettingsPane {
+    /// All panes, in sidebar order.
+    pub const ALL: [SettingsPane; 6] = [
+        SettingsPane::Providers,
+        SettingsPane::Display,
+        SettingsPane::Notifications,
+        SettingsPane::Refresh,
+        SettingsPane::Advanced,
+        SettingsPane::About,
+    ];
+
+    /// Sidebar label.
+    pub fn label(self) -> &'static str {
+        match self {
+            SettingsPane::Providers => "Providers",
+            SettingsPane::Display => "Display",
+            SettingsPane::Notifications => "Notifications",
+            SettingsPane::Refresh => "Refresh",
+            SettingsPane::Advanced => "Advanced",
+            SettingsPane::About => "About",
+        }
+    }
+
+    /// Sidebar icon.
+    pub fn icon(self) -> &'static str {
+        match self {
+            SettingsPane::Providers => "◉",
+            SettingsPane::Display => "◐",
+            SettingsPane::Notifications => "🔔",
+            SettingsPane::Refresh => "↻",
+            SettingsPane::Advanced => "⌘",
+            SettingsPane::About => "ℹ",
+        }
+    }
+}
+
 impl SettingsWindow {
     pub fn new() -> Self {
         println!("🎯 [SW-1] SettingsWindow::new() called!");
         let result = Self {
             active_pane: SettingsPane::default(),
             settings_subscription: None,
+            search_query: String::new(),
+            search_focus: None,
+            setup: None,
         };
         println!("🎯 [SW-2] SettingsWindow::new() returning!");
         result
@@ -79,32 +142,45 @@ impl Render for SettingsWindow {
         let theme_mode = cx.global::<AppState>().settings.read(cx).theme_mode();
 
         // Determine theme based on user's preference
-        let theme = match theme_mode {
-            exactobar_store::ThemeMode::Dark => SettingsTheme::dark(),
-            exactobar_store::ThemeMode::Light => SettingsTheme::light(),
-            exactobar_store::ThemeMode::System => {
-                let is_dark = matches!(
-                    window.appearance(),
-                    WindowAppearance::Dark | WindowAppearance::VibrantDark
-                );
-                if is_dark {
-                    SettingsTheme::dark()
-                } else {
-                    SettingsTheme::light()
-                }
-            }
-        };
+        let theme = SettingsTheme::for_mode(theme_mode, window.appearance());
 
-        let active = self.active_pane;
+        let query = self.search_query.clone();
+
+        // While searching, show every section with a match; otherwise just the active one
+        let panes: Vec<SettingsPane> = if query.is_empty() {
+            vec![self.active_pane]
+        } else {
+            SettingsPane::ALL.to_vec()
+        };
+        let contents: Vec<(SettingsPane, AnyElement)> = panes
+            .into_iter()
+            .filter_map(|pane| {
+                self.render_pane(pane, &query, theme, cx)
+                    .map(|content| (pane, content))
+            })
+            .collect();
+        let matching: Vec<SettingsPane> = contents.iter().map(|(pane, _)| *pane).collect();
 
-        let content = match self.active_pane {
-            SettingsPane::General => GeneralPane::new(cx, theme).into_any_element(),
-            SettingsPane::Providers => self.render_providers_pane(cx, theme).into_any_element(),
-            SettingsPane::Advanced => AdvancedPane::new(cx, theme).into_any_element(),
-            SettingsPane::About => AboutPane::new(theme).into_any_element(),
+        let content = if contents.is_empty() {
+            div()
+                .pt(px(40.0))
+                .flex()
+                .justify_center()
+                .text_sm()
+                .text_color(theme.text_muted)
+                .child(format!("No settings match \u{201c}{}\u{201d}", query))
+        } else {
+            div()
+                .flex()
+                .flex_col()
+                .gap(px(16.0))
+                .children(contents.into_iter().map(|(_, content)| content))
         };
 
-        // Build sidebar items with click handlers inline
+        // Sidebar: search field, then one item per section
+        let search_field = self.render_search_field(theme, window, cx);
+        let active = self.active_pane;
+        let searching = !query.is_empty();
         let sidebar = div()
             .w(px(180.0))
             .h_full()
@@ -116,38 +192,16 @@ impl Render for SettingsWindow {
             .flex_col()
             .gap(px(4.0))
             .text_color(theme.text_primary)
-            .child(self.sidebar_item(
-                SettingsPane::General,
-                "General",
-                "⚙",
-                active == SettingsPane::General,
-                &theme,
-                cx,
-            ))
-            .child(self.sidebar_item(
-                SettingsPane::Providers,
-                "Providers",
-                "◉",
-                active == SettingsPane::Providers,
-                &theme,
-                cx,
-            ))
-            .child(self.sidebar_item(
-                SettingsPane::Advanced,
-                "Advanced",
-                "⌘",
-                active == SettingsPane::Advanced,
-                &theme,
-                cx,
-            ))
-            .child(self.sidebar_item(
-                SettingsPane::About,
-                "About",
-                "ℹ",
-                active == SettingsPane::About,
-                &theme,
-                cx,
-            ));
+            .child(search_field)
+            .children(SettingsPane::ALL.into_iter().map(|pane| {
+                self.sidebar_item(
+                    pane,
+                    !searching && active == pane,
+                    searching && !matching.contains(&pane),
+                    &theme,
+                    cx,
+                )
+            }));
 
         div()
             .size_full()
@@ -168,69 +222,68 @@ impl Render for SettingsWindow {
 
 impl SettingsWindow {
     /// Renders the providers pane with proper cx.listener() click handlers.
+    ///
+    /// Returns `None` if no provider matches the search query.
     fn render_providers_pane(
         &self,
+        query: &str,
         cx: &mut Context<Self>,
         theme: SettingsTheme,
-    ) -> impl IntoElement {
-        let providers = collect_provider_data(cx);
+    ) -> Option<Div> {
+        let show_all = matches_query(query, &["Providers"]);
+        let providers: Vec<ProviderRowData> = collect_provider_data(cx)
+            .into_iter()
+            .filter(|p| show_all || matches_query(query, &[&p.name, &p.cli_name]))
+            .collect();
+        if providers.is_empty() {
+            return None;
+        }
 
         // Separate primary and additional providers
         let (primary, additional): (Vec<_>, Vec<_>) =
             providers.into_iter().partition(|p| p.is_primary);
 
-        div()
+        let pane = div()
             .w_full()
             .flex()
             .flex_col()
             .gap(px(24.0))
             .pb(px(24.0))
-            .child(
-                div()
-                    .flex()
-                    .flex_col()
-                    .gap(px(4.0))
-                    .child(
-                        div()
-                            .text_xl()
-                            .font_weight(FontWeight::BOLD)
-                            .child("Providers"),
-                    )
-                    .child(
-                        div()
-                            .text_sm()
-                            .text_color(theme.text_muted)
-                            .child("Enable the LLM providers you want to monitor"),
-                    ),
-            )
+            .child(pane_header(
+                "Providers",
+                "Enable the LLM providers you want to monitor",
+                theme,
+            ))
             // Primary Providers section
-            .child(
-                div()
-                    .flex()
-                    .flex_col()
-                    .gap(px(8.0))
-                    .child(
-                        div()
-                            .text_sm()
-                            .font_weight(FontWeight::SEMIBOLD)
-                            .text_color(theme.text_muted)
-                            .child("Primary Providers"),
-                    )
-                    .child(
-                        div()
-                            .flex()
-                            .flex_col()
-                            .rounded(px(8.0))
-                            .border_1()
-                            .border_color(theme.border)
-                            .overflow_hidden()
-                            .children(
-                                primary
-                                    .into_iter()
-                                    .map(|data| self.render_provider_row(data, theme, cx)),
-                            ),
-                    ),
-            )
+            .when(!primary.is_empty(), |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(8.0))
+                        .child(
+                            div()
+                                .text_sm()
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .text_color(theme.text_muted)
+                                .child("Primary Providers"),
+                        )
+                        .child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .rounded(px(8.0))
+                                .border_1()
+                                .border_color(theme.border)
+                                .overflow_hidden()
+                                .children(
+                                    primary
+                                        .into_iter()
+                                        .map(|data| self.render_provider_row(data, theme, cx)),
+                                ),
+                        ),
+                )
+            })
             // Additional Providers section
             .when(!additional.is_empty(), |el| {
                 el.child(
@@ -260,6 +313,129 @@ impl SettingsWindow {
                                 ),
                         ),
                 )
+            });
+
+        Some(pane)
+    }
+
+    /// Renders a settings pane, or `None` if nothing in it matches the search query.
+    fn render_pane(
+        &self,
+        pane: SettingsPane,
+        query: &str,
+        theme: SettingsTheme,
+        cx: &mut Context<Self>,
+    ) -> Option<AnyElement> {
+        match pane {
+            SettingsPane::Providers => match &self.setup {
+                Some(setup) if query.is_empty() => {
+                    Some(self.render_setup_pane(setup, theme, cx).into_any_element())
+                }
+                _ => self
+                    .render_providers_pane(query, cx, theme)
+                    .map(IntoElement::into_any_element),
+            },
+            SettingsPane::Display => {
+                let pane = DisplayPane::new(cx, theme, query);
+                (!pane.is_empty()).then(|| pane.into_any_element())
+            }
+            SettingsPane::Notifications => {
+                let pane = NotificationsPane::new(cx, theme, query);
+                (!pane.is_empty()).then(|| pane.into_any_element())
+            }
+            SettingsPane::Refresh => {
+                let pane = RefreshPane::new(cx, theme, query);
+                (!pane.is_empty()).then(|| pane.into_any_element())
+            }
+            SettingsPane::Advanced => {
+                let pane = AdvancedPane::new(cx, theme, query);
+                (!pane.is_empty()).then(|| pane.into_any_element())
+            }
+            SettingsPane::About => matches_query(query, &["About", "version", "license"])
+                .then(|| AboutPane::new(theme).into_any_element()),
+        }
+    }
+
+    /// Renders the sidebar search field.
+    ///
+    /// Typing filters the settings by name; Escape clears the query.
+    fn render_search_field(
+        &mut self,
+        theme: SettingsTheme,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        // Focus the search field on first render so typing filters right away
+        let focus_handle = match &self.search_focus {
+            Some(handle) => handle.clone(),
+            None => {
+                let handle = cx.focus_handle();
+                window.focus(&handle);
+                self.search_focus = Some(handle.clone());
+                handle
+            }
+        };
+        let focused = focus_handle.is_focused(window);
+        let query = self.search_query.clone();
+
+        div()
+            .id("settings-search")
+            .track_focus(&focus_handle)
+            .mb(px(8.0))
+            .px(px(8.0))
+            .py(px(6.0))
+            .rounded(px(6.0))
+            .bg(theme.bg)
+            .border_1()
+            .border_color(if focused { theme.link } else { theme.border })
+            .cursor_text()
+            .flex()
+            .items_center()
+            .gap(px(6.0))
+            .text_sm()
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|this, _, window, cx| {
+                    if let Some(handle) = &this.search_focus {
+                        window.focus(handle);
+                    }
+                    cx.notify();
+                }),
+            )
+            .on_key_down(cx.listener(|this, event: &KeyDownEvent, _window, cx| {
+                if search::apply_key(&mut this.search_query, &event.keystroke) {
+                    cx.stop_propagation();
+                    cx.notify();
+                }
+            }))
+            .child(div().text_color(theme.text_muted).child("⌕"))
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .when(query.is_empty(), |el| {
+                        el.text_color(theme.text_muted).child("Search")
+                    })
+                    .when(!query.is_empty(), |el| {
+                        el.child(format!("{}{}", query, if focused { "|" } else { "" }))
+                    }),
+            )
+            .when(!query.is_empty(), |el| {
+                el.child(
+                    div()
+                        .id("settings-search-clear")
+                        .text_color(theme.text_muted)
+                        .cursor_pointer()
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(|this, _, _window, cx| {
+                                this.search_query.clear();
+                                cx.stop_propagation();
+                                cx.notify();
+                            }),
+                        )
+                        .child("×"),
+                )
             })
     }
 
@@ -346,45 +522,73 @@ impl SettingsWindow {
                                     ),
                             ),
                     )
-                    // Toggle switch with cx.listener()!
                     .child(
                         div()
-                            .id(SharedString::from(format!("toggle-{:?}", provider)))
-                            .w(px(32.0))
-                            .h(px(18.0))
-                            .rounded(px(9.0))
-                            .bg(track_color)
-                            .border_1()
-                            .border_color(hsla(0.0, 0.0, 0.7, 1.0))
-                            .relative()
-                            .cursor_pointer()
-                            .on_mouse_down(
-                                MouseButton::Left,
-                                cx.listener(move |_this, _, _window, cx| {
-                                    println!("🎯 [TOGGLE] Provider {:?} clicked!", provider);
-                                    cx.update_global::<AppState, _>(|state, cx| {
-                                        let enabling =
-                                            !state.settings.read(cx).is_provider_enabled(provider);
-                                        state.settings.update(cx, |model, _| {
-                                            model.toggle_provider(provider);
-                                        });
-                                        if enabling {
-                                            state.refresh_provider(provider, cx);
-                                        }
-                                    });
-                                    cx.notify();
-                                }),
-                            )
+                            .flex()
+                            .items_center()
+                            .gap(px(12.0))
+                            // Re-run guided setup (e.g., to test or replace credentials)
+                            .when(is_enabled, |el| {
+                                el.child(
+                                    div()
+                                        .id(SharedString::from(format!("setup-{:?}", provider)))
+                                        .text_xs()
+                                        .text_color(theme.link)
+                                        .cursor_pointer()
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(move |this, _, _window, cx| {
+                                                this.start_setup(provider);
+                                                cx.notify();
+                                            }),
+                                        )
+                                        .child("Set Up…"),
+                                )
+                            })
+                            // Toggle switch with cx.listener()!
                             .child(
                                 div()
-                                    .absolute()
-                                    .top(px(1.0))
-                                    .left(knob_offset)
-                                    .w(px(14.0))
-                                    .h(px(14.0))
-                                    .rounded_full()
-                                    .bg(white())
-                                    .shadow_sm(),
+                                    .id(SharedString::from(format!("toggle-{:?}", provider)))
+                                    .w(px(32.0))
+                                    .h(px(18.0))
+                                    .rounded(px(9.0))
+                                    .bg(track_color)
+                                    .border_1()
+                                    .border_color(hsla(0.0, 0.0, 0.7, 1.0))
+                                    .relative()
+                                    .cursor_pointer()
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(move |this, _, _window, cx| {
+                                            println!(
+                                                "🎯 [TOGGLE] Provider {:?} clicked!",
+                                                provider
+                                            );
+                                            // Turning a provider on goes through guided setup
+                                            if !is_enabled {
+                                                this.start_setup(provider);
+                                                cx.notify();
+                                                return;
+                                            }
+                                            cx.update_global::<AppState, _>(|state, cx| {
+                                                state.settings.update(cx, |model, _| {
+                                                    model.toggle_provider(provider);
+                                                });
+                                            });
+                                            cx.notify();
+                                        }),
+                                    )
+                                    .child(
+                                        div()
+                                            .absolute()
+                                            .top(px(1.0))
+                                            .left(knob_offset)
+                                            .w(px(14.0))
+                                            .h(px(14.0))
+                                            .rounded_full()
+                                            .bg(white())
+                                            .shadow_sm(),
+                                    ),
                             ),
                     ),
             )
@@ -563,6 +767,169 @@ impl SettingsWindow {
                         }),
                 )
             })
+            // When the provider's data is shown as stale
+            .when(is_enabled, |el| {
+                el.child(
+                    div()
+                        .px(px(16.0))
+                        .pb(px(12.0))
+                        .child(self.render_stale_after_selector(
+                            provider,
+                            data.stale_after_secs,
+                            theme,
+                            cx,
+                        )),
+                )
+            })
+            // Brand color used for the icon and cards
+            .when(is_enabled, |el| {
+                el.child(
+                    div()
+                        .px(px(16.0))
+                        .pb(px(12.0))
+                        .child(self.render_color_selector(provider, data.color.clone(), theme, cx)),
+                )
+            })
+            // Quick-action buttons shown on the menu card
+            .when(is_enabled, |el| {
+                el.child(self.render_quick_actions_editor(provider, theme, cx))
+            })
+    }
+
+    /// Renders the editable list of quick-action buttons for a provider.
+    ///
+    /// Click a button to edit it, × to remove it. Editing the defaults
+    /// turns them into a custom list; Reset goes back to the defaults.
+    fn render_quick_actions_editor(
+        &self,
+        provider: ProviderKind,
+        theme: SettingsTheme,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        let settings = cx.global::<AppState>().settings.read(cx);
+        let custom = settings
+            .quick_actions(provider)
+            .map(<[QuickAction]>::to_vec);
+        let is_custom = custom.is_some();
+        let actions =
+            custom.unwrap_or_else(|| default_quick_actions(provider, settings.settings()));
+
+        let chip = move |id: String| {
+            div()
+                .id(SharedString::from(id))
+                .text_xs()
+                .px(px(8.0))
+                .py(px(4.0))
+                .rounded(px(4.0))
+                .cursor_pointer()
+                .bg(theme.bg)
+                .border_1()
+                .border_color(theme.border)
+                .hover(move |s| s.bg(theme.hover))
+        };
+
+        div()
+            .px(px(16.0))
+            .pb(px(12.0))
+            .pl(px(60.0)) // Indent to align with content
+            .flex()
+            .items_center()
+            .gap(px(8.0))
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(theme.text_muted)
+                    .child("Buttons:"),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap(px(4.0))
+                    .children(actions.iter().enumerate().map(|(index, action)| {
+                        let edit_actions = actions.clone();
+                        let remove_actions = actions.clone();
+
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap(px(1.0))
+                            .child(
+                                chip(format!("quick-action-{:?}-{}", provider, index))
+                                    .child(action.label.clone())
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(move |_this, _, _window, cx| {
+                                            let mut actions = edit_actions.clone();
+                                            let existing = actions[index].clone();
+                                            cx.spawn(async move |this, cx| {
+                                                let Some(action) =
+                                                    prompt_for_quick_action_async(Some(existing))
+                                                        .await
+                                                else {
+                                                    return;
+                                                };
+                                                actions[index] = action;
+                                                let _ = this.update(cx, |_this, cx| {
+                                                    save_quick_actions(provider, Some(actions), cx);
+                                                });
+                                            })
+                                            .detach();
+                                        }),
+                                    ),
+                            )
+                            .child(
+                                chip(format!("quick-action-remove-{:?}-{}", provider, index))
+                                    .text_color(theme.text_muted)
+                                    .child("×")
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(move |_this, _, _window, cx| {
+                                            let mut actions = remove_actions.clone();
+                                            actions.remove(index);
+                                            save_quick_actions(provider, Some(actions), cx);
+                                        }),
+                                    ),
+                            )
+                    }))
+                    .child({
+                        let add_actions = actions.clone();
+                        chip(format!("quick-action-add-{:?}", provider))
+                            .text_color(theme.link)
+                            .child("+ Add")
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |_this, _, _window, cx| {
+                                    let mut actions = add_actions.clone();
+                                    cx.spawn(async move |this, cx| {
+                                        let Some(action) =
+                                            prompt_for_quick_action_async(None).await
+                                        else {
+                                            return;
+                                        };
+                                        actions.push(action);
+                                        let _ = this.update(cx, |_this, cx| {
+                                            save_quick_actions(provider, Some(actions), cx);
+                                        });
+                                    })
+                                    .detach();
+                                }),
+                            )
+                    })
+                    .when(is_custom, |el| {
+                        el.child(
+                            chip(format!("quick-action-reset-{:?}", provider))
+                                .text_color(theme.text_muted)
+                                .child("Reset")
+                                .on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(move |_this, _, _window, cx| {
+                                        save_quick_actions(provider, None, cx);
+                                    }),
+                                ),
+                        )
+                    }),
+            )
     }
 
     /// Renders the cookie source selector chips.
@@ -626,6 +993,173 @@ impl SettingsWindow {
             )
     }
 
+    /// Renders the stale threshold selector chips.
+    fn render_stale_after_selector(
+        &self,
+        provider: ProviderKind,
+        current: Option<u64>,
+        theme: SettingsTheme,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        div()
+            .pl(px(44.0)) // Indent to align with name
+            .flex()
+            .items_center()
+            .gap(px(8.0))
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(theme.text_muted)
+                    .child("Stale after:"),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap(px(4.0))
+                    .children(STALE_AFTER_OPTIONS.iter().map(|&(secs, label)| {
+                        let is_selected = current == secs;
+                        let selected_bg = theme.selected;
+                        let default_bg = theme.bg;
+                        let accent = theme.link;
+                        let border = theme.border;
+
+                        div()
+                            .id(SharedString::from(format!(
+                                "stale-{:?}-{}",
+                                provider, label
+                            )))
+                            .text_xs()
+                            .px(px(8.0))
+                            .py(px(4.0))
+                            .rounded(px(4.0))
+                            .cursor_pointer()
+                            .bg(if is_selected { selected_bg } else { default_bg })
+                            .border_1()
+                            .border_color(if is_selected { accent } else { border })
+                            .child(label)
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |_this, _, _window, cx| {
+                                    cx.update_global::<AppState, _>(|state, cx| {
+                                        state.settings.update(cx, |model, _| {
+                                            model.set_provider_stale_after_secs(provider, secs);
+                                        });
+                                    });
+                                    cx.notify();
+                                }),
+                            )
+                    })),
+            )
+    }
+
+    /// Renders the brand color selector: the built-in color, a few swatches,
+    /// or any hex color.
+    fn render_color_selector(
+        &self,
+        provider: ProviderKind,
+        current: Option<String>,
+        theme: SettingsTheme,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        let is_swatch = COLOR_SWATCHES
+            .iter()
+            .any(|(hex, _)| current.as_deref() == Some(*hex));
+        let is_custom = current.is_some() && !is_swatch;
+        let chip = |id: String, is_selected: bool| {
+            div()
+                .id(SharedString::from(id))
+                .text_xs()
+                .px(px(8.0))
+                .py(px(4.0))
+                .rounded(px(4.0))
+                .cursor_pointer()
+                .bg(if is_selected {
+                    theme.selected
+                } else {
+                    theme.bg
+                })
+                .border_1()
+                .border_color(if is_selected {
+                    theme.link
+                } else {
+                    theme.border
+                })
+        };
+
+        div()
+            .pl(px(44.0)) // Indent to align with name
+            .flex()
+            .items_center()
+            .gap(px(8.0))
+            .child(div().text_xs().text_color(theme.text_muted).child("Color:"))
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .items_center()
+                    .gap(px(4.0))
+                    .child(
+                        chip(format!("color-{:?}-default", provider), current.is_none())
+                            .child("Default")
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |_this, _, _window, cx| {
+                                    save_provider_color(provider, None, cx);
+                                }),
+                            ),
+                    )
+                    .children(COLOR_SWATCHES.iter().map(|&(hex, name)| {
+                        let is_selected = current.as_deref() == Some(hex);
+                        let color = crate::custom_theme::parse_hex(hex).unwrap_or(theme.border);
+
+                        div()
+                            .id(SharedString::from(format!("color-{:?}-{}", provider, name)))
+                            .size(px(18.0))
+                            .rounded(px(9.0))
+                            .cursor_pointer()
+                            .bg(color)
+                            .border_2()
+                            .border_color(if is_selected {
+                                theme.text_primary
+                            } else {
+                                theme.bg
+                            })
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |_this, _, _window, cx| {
+                                    save_provider_color(provider, Some(hex.to_string()), cx);
+                                }),
+                            )
+                    }))
+                    .child({
+                        let existing = current.clone();
+                        chip(format!("color-{:?}-custom", provider), is_custom)
+                            .child(if is_custom {
+                                current.clone().unwrap_or_default()
+                            } else {
+                                "Custom…".to_string()
+                            })
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |_this, _, _window, cx| {
+                                    let existing = existing.clone();
+                                    cx.spawn(async move |this, cx| {
+                                        let Some(color) = prompt_for_color_async(existing).await
+                                        else {
+                                            return;
+                                        };
+                                        let _ = this.update(cx, |_this, cx| {
+                                            save_provider_color(provider, Some(color), cx);
+                                        });
+                                    })
+                                    .detach();
+                                }),
+                            )
+                    }),
+            )
+    }
+
     /// Renders the data source mode selector chips.
     fn render_data_source_selector(
         &self,
@@ -697,9 +1231,8 @@ impl SettingsWindow {
     fn sidebar_item(
         &self,
         pane: SettingsPane,
-        label: &'static str,
-        icon: &'static str,
         is_selected: bool,
+        is_dimmed: bool,
         theme: &SettingsTheme,
         cx: &mut Context<Self>,
     ) -> impl IntoElement {
@@ -715,19 +1248,22 @@ impl SettingsWindow {
             .flex()
             .items_center()
             .gap(px(8.0))
+            .when(is_dimmed, |el| el.opacity(0.4))
             .on_mouse_down(
                 MouseButton::Left,
                 cx.listener(move |this, _, _window, cx| {
+                    // Picking a section ends the search
+                    this.search_query.clear();
                     this.active_pane = pane;
                     cx.notify();
                 }),
             )
-            .child(div().text_base().child(icon))
+            .child(div().text_base().child(pane.icon()))
             .child(
                 div()
                     .text_sm()
                     .when(is_selected, |el| el.font_weight(FontWeight::SEMIBOLD))
-                    .child(label),
+                    .child(pane.label()),
             );
 
         if is_selected {
@@ -737,3 +1273,31 @@ impl SettingsWindow {
         }
     }
 }
+
+/// Saves a provider's brand color and re-renders the settings window.
+fn save_provider_color(
+    provider: ProviderKind,
+    color: Option<String>,
+    cx: &mut Context<SettingsWindow>,
+) {
+    cx.update_global::<AppState, _>(|state, cx| {
+        state.settings.update(cx, |model, _| {
+            model.set_provider_color(provider, color);
+        });
+    });
+    cx.notify();
+}
+
+/// Saves a provider's quick-action buttons and re-renders the settings window.
+fn save_quick_actions(
+    provider: ProviderKind,
+    actions: Option<Vec<QuickAction>>,
+    cx: &mut Context<SettingsWindow>,
+) {
+    cx.update_global::<AppState, _>(|state, cx| {
+        state.settings.update(cx, |model, _| {
+            model.set_quick_actions(provider, actions);
+        });
+    });
+    cx.notify();
+}
@@ -0,0 +1,70 @@
+//! Notifications settings pane.
+
+use gpui::prelude::*;
+use gpui::*;
+
+use super::SettingsTheme;
+use super::rows::{ToggleSetting, pane_header, toggle_rows};
+use crate::state::{AppState, SettingsModel};
+
+/// Notifications settings pane (quota alerts and status checks).
+pub struct NotificationsPane {
+    toggles: Vec<ToggleSetting>,
+    theme: SettingsTheme,
+}
+
+impl NotificationsPane {
+    pub fn new<V: 'static>(cx: &Context<V>, theme: SettingsTheme, query: &str) -> Self {
+        let settings = cx.global::<AppState>().settings.read(cx).settings();
+
+        let toggles = vec![
+            ToggleSetting {
+                id: "toggle-quota-notifications",
+                title: "Quota Notifications",
+                description: "Notify when approaching quota limits",
+                checked: settings.session_quota_notifications_enabled,
+                set: SettingsModel::set_session_quota_notifications_enabled,
+            },
+            ToggleSetting {
+                id: "toggle-status-checks",
+                title: "Status Page Checks",
+                description: "Check provider status pages for outages",
+                checked: settings.status_checks_enabled,
+                set: SettingsModel::set_status_checks_enabled,
+            },
+        ];
+
+        Self {
+            toggles: toggles.into_iter().filter(|s| s.matches(query)).collect(),
+            theme,
+        }
+    }
+
+    /// Returns true if nothing in the pane matches the search query.
+    pub fn is_empty(&self) -> bool {
+        self.toggles.is_empty()
+    }
+}
+
+impl IntoElement for NotificationsPane {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let theme = self.theme;
+
+        div()
+            .w_full()
+            .flex()
+            .flex_col()
+            .gap(px(24.0))
+            .pb(px(24.0))
+            .child(pane_header(
+                "Notifications",
+                "Choose what ExactoBar alerts you about",
+                theme,
+            ))
+            .when(!self.toggles.is_empty(), |el| {
+                el.child(toggle_rows(self.toggles, theme))
+            })
+    }
+}
@@ -4,9 +4,10 @@ use std::process::Command;
 
 use exactobar_core::ProviderKind;
 use exactobar_providers::ProviderRegistry;
-use exactobar_store::{CookieSource, DataSourceMode};
+use exactobar_store::{CookieSource, DataSourceMode, QuickAction, QuickActionTarget};
 use gpui::{Context, Hsla};
 
+use crate::custom_theme;
 use crate::state::AppState;
 
 // ============================================================================
@@ -134,6 +135,8 @@ pub fn detect_provider_status(provider: ProviderKind) -> ProviderStatus {
             // These use local credentials/probes
             return ProviderStatus::Unknown;
         }
+        // Generates its own usage
+        ProviderKind::Demo => return ProviderStatus::Available,
     };
 
     // Check if CLI exists using the which crate
@@ -253,6 +256,91 @@ pub async fn prompt_for_api_key_async(provider_name: &str) -> Option<String> {
     smol::unblock(move || prompt_for_api_key(&name)).await
 }
 
+/// Shows a text input dialog using osascript.
+///
+/// Returns `Some(text)` if the user entered non-empty text and clicked OK,
+/// `None` if cancelled or empty.
+fn prompt_for_text(message: &str, default: &str) -> Option<String> {
+    let script = format!(
+        r#"
+        set dialogResult to display dialog "{}" default answer "{}" buttons {{"Cancel", "OK"}} default button "OK"
+        if button returned of dialogResult is "OK" then
+            return text returned of dialogResult
+        else
+            return ""
+        end if
+        "#,
+        applescript_escape(message),
+        applescript_escape(default)
+    );
+
+    let output = Command::new("osascript")
+        .arg("-e")
+        .arg(&script)
+        .output()
+        .ok()?;
+
+    if output.status.success() {
+        let result = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if !result.is_empty() {
+            return Some(result);
+        }
+    }
+    None
+}
+
+/// Escapes a string for use inside an AppleScript string literal.
+fn applescript_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Prompt for a quick-action button's label and target.
+///
+/// Targets starting with `http://` or `https://` open in the browser;
+/// anything else is run as a shell command. `existing` pre-fills the
+/// dialogs when editing a button.
+pub fn prompt_for_quick_action(existing: Option<&QuickAction>) -> Option<QuickAction> {
+    let label = prompt_for_text(
+        "Button label:",
+        existing.map(|a| a.label.as_str()).unwrap_or(""),
+    )?;
+    let target = prompt_for_text(
+        "URL or shell command:",
+        existing
+            .map(|a| match &a.target {
+                QuickActionTarget::Url(url) => url.as_str(),
+                QuickActionTarget::Command(command) => command.as_str(),
+            })
+            .unwrap_or(""),
+    )?;
+
+    Some(
+        if target.starts_with("http://") || target.starts_with("https://") {
+            QuickAction::url(label, target)
+        } else {
+            QuickAction::command(label, target)
+        },
+    )
+}
+
+/// Async version of `prompt_for_quick_action` that runs on a background thread.
+pub async fn prompt_for_quick_action_async(existing: Option<QuickAction>) -> Option<QuickAction> {
+    smol::unblock(move || prompt_for_quick_action(existing.as_ref())).await
+}
+
+/// Prompt for a brand color as a hex string, e.g. `#a6adc8`.
+///
+/// Returns `None` if cancelled or if the text isn't a color.
+pub fn prompt_for_color(existing: Option<&str>) -> Option<String> {
+    let color = prompt_for_text("Color (hex, e.g. #a6adc8):", existing.unwrap_or("#"))?;
+    custom_theme::parse_hex(&color).map(|_| color)
+}
+
+/// Async version of `prompt_for_color` that runs on a background thread.
+pub async fn prompt_for_color_async(existing: Option<String>) -> Option<String> {
+    smol::unblock(move || prompt_for_color(existing.as_deref())).await
+}
+
 // ============================================================================
 // Provider Row Data
 // ============================================================================
@@ -268,6 +356,10 @@ pub struct ProviderRowData {
     pub supports_data_source: bool,
     pub current_cookie_source: CookieSource,
     pub current_data_source: Option<DataSourceMode>,
+    /// Stale threshold override in seconds (None = global threshold)
+    pub stale_after_secs: Option<u64>,
+    /// Brand color override as a hex string (None = built-in color)
+    pub color: Option<String>,
     /// Provider availability status
     pub status: ProviderStatus,
     /// Whether this provider needs an API key
@@ -304,6 +396,7 @@ pub fn collect_provider_data<V: 'static>(cx: &Context<V>) -> Vec<ProviderRowData
 
     all_providers
         .iter()
+        .filter(|desc| settings.settings().shows_provider(desc.id))
         .map(|desc| {
             let provider = desc.id;
             let is_enabled = settings.is_provider_enabled(provider);
@@ -338,6 +431,8 @@ pub fn collect_provider_data<V: 'static>(cx: &Context<V>) -> Vec<ProviderRowData
                 supports_data_source,
                 current_cookie_source,
                 current_data_source,
+                stale_after_secs: settings.provider_stale_after_secs(provider),
+                color: settings.provider_color(provider).map(str::to_string),
                 status,
                 needs_api_key,
                 has_api_key,
@@ -357,6 +452,27 @@ pub const COOKIE_SOURCES: [CookieSource; 6] = [
     CookieSource::Off,
 ];
 
+/// Stale threshold options for the selector, in seconds (None = global).
+pub const STALE_AFTER_OPTIONS: [(Option<u64>, &str); 5] = [
+    (None, "Default"),
+    (Some(30), "30s"),
+    (Some(300), "5m"),
+    (Some(1800), "30m"),
+    (Some(3600), "1h"),
+];
+
+/// Brand color swatches for the selector, readable on dark and light menus.
+pub const COLOR_SWATCHES: [(&str, &str); 8] = [
+    ("#e5484d", "Red"),
+    ("#f76b15", "Orange"),
+    ("#ffc53d", "Yellow"),
+    ("#46a758", "Green"),
+    ("#12a594", "Teal"),
+    ("#3e63dd", "Blue"),
+    ("#8e4ec6", "Purple"),
+    ("#d6409f", "Pink"),
+];
+
 /// Data source mode options for the selector.
 pub const DATA_SOURCE_MODES: [DataSourceMode; 4] = [
     DataSourceMode::Auto,
@@ -0,0 +1,221 @@
+//! Refresh settings pane.
+
+use exactobar_store::RefreshCadence;
+use gpui::prelude::*;
+use gpui::*;
+
+use super::SettingsTheme;
+use super::rows::{ToggleSetting, group_header, pane_header, radio_option, toggle_rows};
+use super::search::matches_query;
+use crate::state::{AppState, SettingsModel};
+
+const CADENCE_OPTIONS: [(RefreshCadence, &str); 5] = [
+    (RefreshCadence::Manual, "Manual"),
+    (RefreshCadence::OneMinute, "Every minute"),
+    (RefreshCadence::TwoMinutes, "Every 2 minutes"),
+    (RefreshCadence::FiveMinutes, "Every 5 minutes"),
+    (RefreshCadence::FifteenMinutes, "Every 15 minutes"),
+];
+
+const STALE_AFTER_OPTIONS: [(u64, &str); 5] = [
+    (60, "After 1 minute"),
+    (300, "After 5 minutes"),
+    (600, "After 10 minutes"),
+    (1800, "After 30 minutes"),
+    (3600, "After 1 hour"),
+];
+
+const REQUEST_LIMIT_OPTIONS: [(u32, &str); 3] = [
+    (10, "10 per minute"),
+    (20, "20 per minute"),
+    (60, "60 per minute"),
+];
+
+/// Refresh settings pane (cadence, staleness, request limit, startup, wake
+/// and power behavior).
+pub struct RefreshPane {
+    cadence: RefreshCadence,
+    show_cadence: bool,
+    stale_after_secs: u64,
+    show_stale_after: bool,
+    requests_per_minute: u32,
+    show_request_limit: bool,
+    toggles: Vec<ToggleSetting>,
+    theme: SettingsTheme,
+}
+
+impl RefreshPane {
+    pub fn new<V: 'static>(cx: &Context<V>, theme: SettingsTheme, query: &str) -> Self {
+        let settings = cx.global::<AppState>().settings.read(cx).settings();
+
+        let toggles = vec![
+            ToggleSetting {
+                id: "toggle-auto-refresh-on-wake",
+                title: "Auto-refresh on Wake",
+                description: "Refresh usage data when your Mac wakes from sleep",
+                checked: settings.auto_refresh_on_wake,
+                set: SettingsModel::set_auto_refresh_on_wake,
+            },
+            ToggleSetting {
+                id: "toggle-power-saving",
+                title: "Save Power",
+                description: if cfg!(target_os = "macos") {
+                    "Refresh less often and pause animations in Low Power Mode"
+                } else {
+                    "Refresh less often and pause animations on battery"
+                },
+                checked: settings.power_saving_enabled,
+                set: SettingsModel::set_power_saving_enabled,
+            },
+            ToggleSetting {
+                id: "toggle-start-silently",
+                title: "Start Silently",
+                description: "Open no windows at launch and spread out the first refresh",
+                checked: settings.start_silently,
+                set: SettingsModel::set_start_silently,
+            },
+        ];
+
+        let cadence_texts: Vec<&str> = ["Refresh Cadence", "interval"]
+            .into_iter()
+            .chain(CADENCE_OPTIONS.iter().map(|(_, label)| *label))
+            .collect();
+        let stale_texts: Vec<&str> = ["Stale Data", "stale", "outdated"]
+            .into_iter()
+            .chain(STALE_AFTER_OPTIONS.iter().map(|(_, label)| *label))
+            .collect();
+        let request_limit_texts: Vec<&str> = ["Request Limit", "rate limit", "network"]
+            .into_iter()
+            .chain(REQUEST_LIMIT_OPTIONS.iter().map(|(_, label)| *label))
+            .collect();
+
+        Self {
+            cadence: settings.refresh_cadence,
+            show_cadence: matches_query(query, &cadence_texts),
+            stale_after_secs: settings.stale_after_secs,
+            show_stale_after: matches_query(query, &stale_texts),
+            requests_per_minute: settings.requests_per_minute,
+            show_request_limit: matches_query(query, &request_limit_texts),
+            toggles: toggles.into_iter().filter(|s| s.matches(query)).collect(),
+            theme,
+        }
+    }
+
+    /// Returns true if nothing in the pane matches the search query.
+    pub fn is_empty(&self) -> bool {
+        !self.show_cadence
+            && !self.show_stale_after
+            && !self.show_request_limit
+            && self.toggles.is_empty()
+    }
+}
+
+impl IntoElement for RefreshPane {
+    type Element = Div;
+
+    fn into_element(self) -> Self::Element {
+        let theme = self.theme;
+        let current = self.cadence;
+        let stale_after_secs = self.stale_after_secs;
+        let requests_per_minute = self.requests_per_minute;
+
+        div()
+            .w_full()
+            .flex()
+            .flex_col()
+            .gap(px(24.0))
+            .pb(px(24.0))
+            .child(pane_header(
+                "Refresh",
+                "Control when usage data is fetched",
+                theme,
+            ))
+            .when(self.show_cadence, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(group_header(
+                            "Refresh Cadence",
+                            Some("How often to automatically refresh usage data"),
+                            theme,
+                        ))
+                        .child(div().flex().flex_col().gap(px(4.0)).children(
+                            CADENCE_OPTIONS.iter().map(move |&(cadence, label)| {
+                                radio_option(label, None, current == cadence, theme, move |cx| {
+                                    cx.update_global::<AppState, _>(|state, cx| {
+                                        state.settings.update(cx, |model, _| {
+                                            model.set_refresh_cadence(cadence);
+                                        });
+                                    });
+                                })
+                            }),
+                        )),
+                )
+            })
+            .when(self.show_stale_after, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(group_header(
+                            "Stale Data",
+                            Some("How long after a refresh usage is shown as outdated"),
+                            theme,
+                        ))
+                        .child(div().flex().flex_col().gap(px(4.0)).children(
+                            STALE_AFTER_OPTIONS.iter().map(move |&(secs, label)| {
+                                radio_option(
+                                    label,
+                                    None,
+                                    stale_after_secs == secs,
+                                    theme,
+                                    move |cx| {
+                                        cx.update_global::<AppState, _>(|state, cx| {
+                                            state.settings.update(cx, |model, _| {
+                                                model.set_stale_after_secs(secs);
+                                            });
+                                        });
+                                    },
+                                )
+                            }),
+                        )),
+                )
+            })
+            .when(self.show_request_limit, |el| {
+                el.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap(px(12.0))
+                        .child(group_header(
+                            "Request Limit",
+                            Some("How many requests can go to one provider per minute"),
+                            theme,
+                        ))
+                        .child(div().flex().flex_col().gap(px(4.0)).children(
+                            REQUEST_LIMIT_OPTIONS.iter().map(move |&(requests, label)| {
+                                radio_option(
+                                    label,
+                                    None,
+                                    requests_per_minute == requests,
+                                    theme,
+                                    move |cx| {
+                                        cx.update_global::<AppState, _>(|state, cx| {
+                                            state.settings.update(cx, |model, _| {
+                                                model.set_requests_per_minute(requests);
+                                            });
+                                        });
+                                    },
+                                )
+                            }),
+                        )),
+                )
+            })
+            .when(!self.toggles.is_empty(), |el| {
+                el.child(toggle_rows(self.toggles, theme))
+            })
+    }
+}
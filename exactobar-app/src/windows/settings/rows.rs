@@ -0,0 +1,188 @@
+//! Shared building blocks for the settings panes.
+//!
+//! Panes describe their settings as data ([`ToggleSetting`]) so each row
+//! can be matched against the search query before it's rendered.
+
+use gpui::prelude::*;
+use gpui::*;
+
+use super::SettingsTheme;
+use super::search::matches_query;
+use crate::components::Toggle;
+use crate::state::{AppState, SettingsModel};
+
+// ============================================================================
+// Pane Header
+// ============================================================================
+
+/// Title and subtitle shown at the top of a pane.
+pub fn pane_header(title: &'static str, subtitle: &'static str, theme: SettingsTheme) -> Div {
+    div()
+        .flex()
+        .flex_col()
+        .gap(px(4.0))
+        .child(div().text_xl().font_weight(FontWeight::BOLD).child(title))
+        .child(div().text_sm().text_color(theme.text_muted).child(subtitle))
+}
+
+/// Heading for a group of settings within a pane.
+pub fn group_header(
+    title: &'static str,
+    description: Option<&'static str>,
+    theme: SettingsTheme,
+) -> Div {
+    div()
+        .flex()
+        .flex_col()
+        .gap(px(4.0))
+        .child(
+            div()
+                .text_base()
+                .font_weight(FontWeight::SEMIBOLD)
+                .child(title),
+        )
+        .when_some(description, |el, description| {
+            el.child(
+                div()
+                    .text_sm()
+                    .text_color(theme.text_muted)
+                    .child(description),
+            )
+        })
+}
+
+// ============================================================================
+// Toggle Settings
+// ============================================================================
+
+/// A boolean setting shown as a row with a toggle switch.
+#[derive(Clone, Copy)]
+pub struct ToggleSetting {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub checked: bool,
+    pub set: fn(&mut SettingsModel, bool),
+}
+
+impl ToggleSetting {
+    /// Returns true if the setting matches the search query.
+    pub fn matches(&self, query: &str) -> bool {
+        matches_query(query, &[self.title, self.description])
+    }
+}
+
+/// Renders toggle settings as a list of rows separated by dividers.
+pub fn toggle_rows(settings: Vec<ToggleSetting>, theme: SettingsTheme) -> Div {
+    let count = settings.len();
+    div().flex().flex_col().children(
+        settings
+            .into_iter()
+            .enumerate()
+            .map(move |(i, setting)| toggle_row(setting, i + 1 < count, theme)),
+    )
+}
+
+fn toggle_row(setting: ToggleSetting, divider: bool, theme: SettingsTheme) -> Div {
+    let set = setting.set;
+
+    div()
+        .flex()
+        .items_center()
+        .justify_between()
+        .py(px(12.0))
+        .when(divider, |el| el.border_b_1().border_color(theme.border))
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .gap(px(2.0))
+                .child(
+                    div()
+                        .text_sm()
+                        .font_weight(FontWeight::MEDIUM)
+                        .child(setting.title),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(theme.text_muted)
+                        .child(setting.description),
+                ),
+        )
+        .child(
+            Toggle::new(setting.id)
+                .checked(setting.checked)
+                .on_toggle(move |enabled, cx| {
+                    cx.update_global::<AppState, _>(|state, cx| {
+                        state.settings.update(cx, |model, _| {
+                            set(model, enabled);
+                        });
+                    });
+                }),
+        )
+}
+
+// ============================================================================
+// Radio Options
+// ============================================================================
+
+/// A single radio option with an optional description.
+pub fn radio_option(
+    label: &'static str,
+    description: Option<&'static str>,
+    selected: bool,
+    theme: SettingsTheme,
+    on_select: impl Fn(&mut App) + 'static,
+) -> Div {
+    let hover_bg = theme.hover;
+    div()
+        .px(px(12.0))
+        .py(px(8.0))
+        .rounded(px(6.0))
+        .cursor_pointer()
+        .flex()
+        .items_center()
+        .gap(px(12.0))
+        .when(selected, |el| el.bg(theme.selected))
+        .when(!selected, |el| el.hover(move |s| s.bg(hover_bg)))
+        .on_mouse_down(MouseButton::Left, move |_, _window, cx| {
+            on_select(cx);
+        })
+        .child(
+            div()
+                .w(px(16.0))
+                .h(px(16.0))
+                .rounded_full()
+                .border_2()
+                .border_color(if selected { theme.link } else { theme.border })
+                .flex()
+                .items_center()
+                .justify_center()
+                .when(selected, |el| {
+                    el.child(div().w(px(8.0)).h(px(8.0)).rounded_full().bg(theme.link))
+                }),
+        )
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .gap(px(2.0))
+                .child(
+                    div()
+                        .text_sm()
+                        .when(description.is_some(), |el| {
+                            el.font_weight(FontWeight::MEDIUM)
+                        })
+                        .child(label),
+                )
+                .when_some(description, |el, description| {
+                    el.child(
+                        div()
+                            .text_xs()
+                            .text_color(theme.text_muted)
+                            .child(description),
+                    )
+                }),
+        )
+}
@@ -0,0 +1,84 @@
+//! Settings search.
+//!
+//! Matches settings against the sidebar search field and edits the
+//! query from key presses (GPUI has no built-in text input).
+
+use gpui::Keystroke;
+
+/// Returns true if every word of `query` appears in one of `texts`.
+///
+/// Matching is case-insensitive; an empty query matches everything.
+pub fn matches_query(query: &str, texts: &[&str]) -> bool {
+    let haystack = texts.join(" ").to_lowercase();
+    query
+        .to_lowercase()
+        .split_whitespace()
+        .all(|word| haystack.contains(word))
+}
+
+/// Applies a key press to the search query.
+///
+/// Returns true if the query changed.
+pub fn apply_key(query: &mut String, keystroke: &Keystroke) -> bool {
+    let modifiers = &keystroke.modifiers;
+    if modifiers.platform || modifiers.control {
+        return false;
+    }
+
+    match keystroke.key.as_str() {
+        "backspace" => query.pop().is_some(),
+        "escape" => {
+            let changed = !query.is_empty();
+            query.clear();
+            changed
+        }
+        _ => match keystroke.key_char.as_deref() {
+            Some(text) if !text.chars().any(char::is_control) => {
+                query.push_str(text);
+                true
+            }
+            _ => false,
+        },
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_query() {
+        let texts = ["Show Used Percent", "Progress bars show percent used"];
+        assert!(matches_query("", &texts));
+        assert!(matches_query("percent", &texts));
+        assert!(matches_query("USED bars", &texts));
+        assert!(!matches_query("percent theme", &texts));
+    }
+
+    #[test]
+    fn test_apply_key() {
+        let key = |s: &str| Keystroke::parse(s).unwrap();
+        let typed = |s: &str, text: &str| {
+            let mut keystroke = key(s);
+            keystroke.key_char = Some(text.to_string());
+            keystroke
+        };
+        let mut query = String::new();
+
+        assert!(apply_key(&mut query, &typed("t", "t")));
+        assert!(apply_key(&mut query, &typed("shift-h", "H")));
+        assert_eq!(query, "tH");
+
+        assert!(!apply_key(&mut query, &key("cmd-a")));
+        assert!(apply_key(&mut query, &key("backspace")));
+        assert_eq!(query, "t");
+
+        assert!(apply_key(&mut query, &key("escape")));
+        assert!(query.is_empty());
+        assert!(!apply_key(&mut query, &key("backspace")));
+    }
+}
@@ -0,0 +1,725 @@
+//! Guided provider setup.
+//!
+//! Turning a provider on opens a setup pane with the steps that provider
+//! needs (install and sign in to its CLI, paste an API key, or sign in on
+//! the web), plus a "Test connection" button that runs a real fetch and
+//! previews the result before the provider is enabled.
+
+use std::process::Command;
+
+use exactobar_core::{ProviderKind, UsageSnapshot, format_currency};
+use gpui::prelude::*;
+use gpui::*;
+use tracing::{info, warn};
+
+use super::SettingsWindow;
+use super::providers::{
+    get_install_command, prompt_for_api_key_async, provider_api_key_name, provider_has_api_key,
+};
+use super::rows::{group_header, pane_header};
+use super::theme::SettingsTheme;
+use crate::menu::open_url;
+use crate::state::AppState;
+
+// ============================================================================
+// Setup Steps
+// ============================================================================
+
+/// One way of giving ExactoBar access to a provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetupStep {
+    /// Install the provider's CLI and sign in through it.
+    Cli {
+        command: &'static str,
+        login: &'static str,
+    },
+    /// Paste an API key, stored in the system keychain.
+    ApiKey,
+    /// Sign in on the provider's website; cookies are read from the browser.
+    Browser,
+    /// Credentials come from the provider's desktop app.
+    DesktopApp,
+    /// Nothing to sign in to; usage is generated (the demo provider).
+    Generated,
+}
+
+/// Returns the setup steps for a provider, most common first.
+pub fn setup_steps(provider: ProviderKind) -> Vec<SetupStep> {
+    use SetupStep::*;
+
+    match provider {
+        ProviderKind::Codex => vec![
+            Cli {
+                command: "codex",
+                login: "codex login",
+            },
+            ApiKey,
+        ],
+        ProviderKind::Claude => vec![
+            Cli {
+                command: "claude",
+                login: "claude",
+            },
+            Browser,
+        ],
+        ProviderKind::Copilot => vec![Cli {
+            command: "gh",
+            login: "gh auth login",
+        }],
+        ProviderKind::Gemini => vec![Cli {
+            command: "gemini",
+            login: "gemini",
+        }],
+        ProviderKind::Kiro => vec![Cli {
+            command: "kiro-cli",
+            login: "kiro-cli login",
+        }],
+        ProviderKind::VertexAI => vec![Cli {
+            command: "gcloud",
+            login: "gcloud auth application-default login",
+        }],
+        ProviderKind::Synthetic | ProviderKind::Zai => vec![ApiKey],
+        ProviderKind::Cursor
+        | ProviderKind::Factory
+        | ProviderKind::MiniMax
+        | ProviderKind::Augment => vec![Browser],
+        ProviderKind::Antigravity => vec![DesktopApp],
+        ProviderKind::Demo => vec![Generated],
+    }
+}
+
+/// Opens a terminal window running `command` (used for CLI sign-in).
+fn open_in_terminal(command: &str) {
+    info!(command = %command, "Opening terminal for provider sign-in");
+
+    #[cfg(target_os = "macos")]
+    let result = Command::new("osascript")
+        .args([
+            "-e",
+            &format!(
+                "tell application \"Terminal\" to do script \"{}\"",
+                command.replace('\\', "\\\\").replace('"', "\\\"")
+            ),
+            "-e",
+            "tell application \"Terminal\" to activate",
+        ])
+        .spawn();
+
+    #[cfg(not(target_os = "macos"))]
+    let result = Command::new("x-terminal-emulator")
+        .args(["-e", "sh", "-c", command])
+        .spawn();
+
+    if let Err(e) = result {
+        warn!(error = %e, command = %command, "Failed to open terminal");
+    }
+}
+
+/// Starts signing in again after the provider rejected its credentials,
+/// following its first setup step: runs the CLI's login in a terminal, asks
+/// for a new API key, or opens the sign-in page.
+pub fn reauthenticate(provider: ProviderKind, cx: &mut App) {
+    info!(provider = ?provider, "Re-authenticating provider");
+
+    match setup_steps(provider).first() {
+        Some(SetupStep::Cli { login, .. }) => open_in_terminal(login),
+        Some(SetupStep::ApiKey) => {
+            cx.spawn(async move |cx| {
+                let Some(key) = prompt_for_api_key_async(provider.display_name()).await else {
+                    return;
+                };
+                if let Err(e) =
+                    exactobar_store::store_api_key(provider_api_key_name(provider), &key)
+                {
+                    warn!(provider = ?provider, error = %e, "Failed to store API key");
+                    return;
+                }
+                let _ = cx.update(|cx| crate::refresh::trigger_provider_refresh(provider, cx));
+            })
+            .detach();
+        }
+        Some(SetupStep::Browser) => {
+            if let Some(url) = exactobar_providers::ProviderRegistry::get(provider)
+                .and_then(|desc| desc.metadata.dashboard_url.clone())
+            {
+                open_url(&url);
+            }
+        }
+        Some(SetupStep::DesktopApp | SetupStep::Generated) | None => {
+            crate::windows::open_settings(cx)
+        }
+    }
+}
+
+// ============================================================================
+// Setup State
+// ============================================================================
+
+/// Result of the "Test connection" button.
+#[derive(Debug, Clone, Default)]
+pub enum TestState {
+    #[default]
+    Untested,
+    Testing,
+    Passed(Box<UsageSnapshot>),
+    Failed(String),
+}
+
+/// An API key saved during setup, put back as it was on cancel.
+#[derive(Clone)]
+pub struct SavedKey {
+    /// Keychain name of the key.
+    pub name: &'static str,
+    /// The key it replaced, if there was one.
+    pub previous: Option<String>,
+}
+
+impl std::fmt::Debug for SavedKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SavedKey")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A provider being set up in the settings window.
+#[derive(Debug, Clone)]
+pub struct ProviderSetup {
+    pub provider: ProviderKind,
+    pub test: TestState,
+    /// API key saved during this setup, restored on cancel.
+    pub saved_key: Option<SavedKey>,
+}
+
+impl ProviderSetup {
+    pub fn new(provider: ProviderKind) -> Self {
+        Self {
+            provider,
+            test: TestState::Untested,
+            saved_key: None,
+        }
+    }
+}
+
+/// Summarizes a test fetch as label/value rows.
+pub fn snapshot_summary(snapshot: &UsageSnapshot) -> Vec<(String, String)> {
+    let mut rows = Vec::new();
+
+    if let Some(identity) = &snapshot.identity {
+        if let Some(email) = &identity.account_email {
+            rows.push(("Account".to_string(), email.clone()));
+        }
+        if let Some(plan) = &identity.plan_name {
+            rows.push(("Plan".to_string(), plan.clone()));
+        }
+    }
+
+    let windows = [
+        ("Session", &snapshot.primary),
+        ("Weekly", &snapshot.secondary),
+        ("Premium", &snapshot.tertiary),
+        ("Search", &snapshot.search),
+    ];
+    for (label, window) in windows {
+        let Some(window) = window else {
+            continue;
+        };
+        let mut value = format!("{:.0}% used", window.used_percent);
+        if let Some(reset) = &window.reset_description {
+            value.push_str(&format!(", resets {}", reset));
+        }
+        rows.push((label.to_string(), value));
+    }
+
+    if let Some(cost) = &snapshot.cost {
+        let code = cost.currency_code.as_deref().unwrap_or("USD");
+        rows.push(("Spend".to_string(), format_currency(cost.used, code)));
+    }
+
+    rows.push((
+        "Source".to_string(),
+        snapshot.fetch_source.label().to_string(),
+    ));
+    rows
+}
+
+// ============================================================================
+// Setup Pane
+// ============================================================================
+
+impl SettingsWindow {
+    /// Starts guided setup for a provider instead of enabling it directly.
+    pub(super) fn start_setup(&mut self, provider: ProviderKind) {
+        info!(provider = ?provider, "Starting provider setup");
+        self.setup = Some(ProviderSetup::new(provider));
+    }
+
+    /// Leaves setup without enabling the provider.
+    fn cancel_setup(&mut self) {
+        if let Some(setup) = self.setup.take() {
+            // Nothing is saved until the provider is enabled
+            if let Some(saved) = setup.saved_key {
+                let result = match saved.previous {
+                    Some(previous) => exactobar_store::store_api_key(saved.name, &previous),
+                    None => exactobar_store::delete_api_key(saved.name),
+                };
+                if let Err(e) = result {
+                    warn!(key = saved.name, error = %e, "Failed to restore API key");
+                }
+            }
+        }
+    }
+
+    /// Runs a real fetch and records the result on the setup pane.
+    fn test_connection(&mut self, cx: &mut Context<Self>) {
+        let Some(setup) = self.setup.as_mut() else {
+            return;
+        };
+        let provider = setup.provider;
+        setup.test = TestState::Testing;
+        cx.notify();
+
+        cx.spawn(async move |this, cx| {
+            let result = crate::refresh::fetch_on_tokio(provider).await;
+            let _ = this.update(cx, |this, cx| {
+                if let Some(setup) = this.setup.as_mut().filter(|s| s.provider == provider) {
+                    setup.test = match result {
+                        Some(Ok(fetched)) => TestState::Passed(Box::new(fetched.snapshot)),
+                        Some(Err(e)) => TestState::Failed(e.message),
+                        None => TestState::Failed("Cancelled".to_string()),
+                    };
+                    cx.notify();
+                }
+            });
+        })
+        .detach();
+    }
+
+    /// Enables the provider, reusing the test snapshot if there is one.
+    fn finish_setup(&mut self, cx: &mut Context<Self>) {
+        let Some(setup) = self.setup.take() else {
+            return;
+        };
+        let provider = setup.provider;
+        info!(provider = ?provider, "Finished provider setup");
+
+        let tested = cx.update_global::<AppState, _>(|state, cx| {
+            if !state.settings.read(cx).is_provider_enabled(provider) {
+                state.settings.update(cx, |model, _| {
+                    model.toggle_provider(provider);
+                });
+            }
+            match setup.test {
+                TestState::Passed(snapshot) => {
+                    let stale_after = state.stale_after(provider, cx);
+                    state.usage.update(cx, |model, cx| {
+                        model.update_provider(provider, cx, |model| {
+                            model.set_snapshot(provider, *snapshot, stale_after);
+                            model.clear_error(provider);
+                        });
+                    });
+                    true
+                }
+                _ => false,
+            }
+        });
+        if !tested {
+            crate::refresh::trigger_provider_refresh(provider, cx);
+        }
+        cx.notify();
+    }
+
+    /// Renders the setup pane for the provider being set up.
+    pub(super) fn render_setup_pane(
+        &self,
+        setup: &ProviderSetup,
+        theme: SettingsTheme,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        let provider = setup.provider;
+        let name = provider.display_name();
+
+        div()
+            .w_full()
+            .flex()
+            .flex_col()
+            .gap(px(24.0))
+            .pb(px(24.0))
+            .child(
+                div()
+                    .id("setup-back")
+                    .text_sm()
+                    .text_color(theme.link)
+                    .cursor_pointer()
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|this, _, _window, cx| {
+                            this.cancel_setup();
+                            cx.notify();
+                        }),
+                    )
+                    .child("‹ Providers"),
+            )
+            .child(pane_header(
+                "Set Up Provider",
+                "Connect your account, then test it before turning the provider on",
+                theme,
+            ))
+            .child(div().text_xl().font_weight(FontWeight::BOLD).child(name))
+            .children(
+                setup_steps(provider)
+                    .into_iter()
+                    .map(|step| self.render_setup_step(setup, step, theme, cx)),
+            )
+            .child(self.render_test_section(setup, theme, cx))
+            .child(
+                div()
+                    .flex()
+                    .justify_end()
+                    .gap(px(8.0))
+                    .child(
+                        setup_button("setup-cancel", "Cancel", theme.surface, theme).on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(|this, _, _window, cx| {
+                                this.cancel_setup();
+                                cx.notify();
+                            }),
+                        ),
+                    )
+                    .child(
+                        setup_button(
+                            "setup-enable",
+                            format!("Enable {}", name),
+                            theme.link,
+                            theme,
+                        )
+                        .text_color(white())
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(|this, _, _window, cx| this.finish_setup(cx)),
+                        ),
+                    ),
+            )
+    }
+
+    fn render_setup_step(
+        &self,
+        setup: &ProviderSetup,
+        step: SetupStep,
+        theme: SettingsTheme,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        let provider = setup.provider;
+        let card = div()
+            .p(px(16.0))
+            .rounded(px(8.0))
+            .bg(theme.surface)
+            .border_1()
+            .border_color(theme.border)
+            .flex()
+            .flex_col()
+            .gap(px(12.0));
+
+        match step {
+            SetupStep::Cli { command, login } => {
+                let found = which::which(command).ok();
+                card.child(group_header(
+                    "Sign in with the CLI",
+                    Some("ExactoBar reads usage through the provider's command-line tool"),
+                    theme,
+                ))
+                .child(match &found {
+                    Some(path) => status_line(
+                        "✓",
+                        format!("Found {} at {}", command, path.display()),
+                        theme.success,
+                        theme,
+                    ),
+                    None => status_line(
+                        "✗",
+                        format!(
+                            "{} not found. Install: {}",
+                            command,
+                            get_install_command(provider)
+                        ),
+                        theme.warning,
+                        theme,
+                    ),
+                })
+                .child(
+                    div()
+                        .flex()
+                        .gap(px(8.0))
+                        .child(
+                            setup_button("setup-detect", "Detect Again", theme.selected, theme)
+                                .on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(|_this, _, _window, cx| cx.notify()),
+                                ),
+                        )
+                        .when(found.is_some(), |el| {
+                            el.child(
+                                setup_button("setup-login", "Sign In…", theme.selected, theme)
+                                    .on_mouse_down(MouseButton::Left, move |_, _window, _cx| {
+                                        open_in_terminal(login);
+                                    }),
+                            )
+                        }),
+                )
+            }
+            SetupStep::ApiKey => {
+                let has_key = provider_has_api_key(provider);
+                card.child(group_header(
+                    "Paste an API key",
+                    Some("The key is stored in your system keychain"),
+                    theme,
+                ))
+                .child(if has_key {
+                    status_line("✓", "API key configured".to_string(), theme.success, theme)
+                } else {
+                    status_line("•", "No API key yet".to_string(), theme.text_muted, theme)
+                })
+                .child(
+                    div().flex().child(
+                        setup_button(
+                            "setup-api-key",
+                            if has_key {
+                                "Replace Key…"
+                            } else {
+                                "Paste Key…"
+                            },
+                            theme.selected,
+                            theme,
+                        )
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(move |_this, _, _window, cx| {
+                                let key_name = provider_api_key_name(provider);
+                                cx.spawn(async move |this, cx| {
+                                    let Some(key) =
+                                        prompt_for_api_key_async(provider.display_name()).await
+                                    else {
+                                        return;
+                                    };
+                                    let previous = exactobar_store::get_api_key(key_name);
+                                    if exactobar_store::store_api_key(key_name, &key).is_err() {
+                                        return;
+                                    }
+                                    let _ = this.update(cx, |this, cx| {
+                                        if let Some(setup) = this.setup.as_mut() {
+                                            // Keep the key from before setup
+                                            // if it was replaced twice
+                                            setup.saved_key.get_or_insert(SavedKey {
+                                                name: key_name,
+                                                previous,
+                                            });
+                                            setup.test = TestState::Untested;
+                                        }
+                                        cx.notify();
+                                    });
+                                })
+                                .detach();
+                            }),
+                        ),
+                    ),
+                )
+            }
+            SetupStep::Browser => {
+                let url = exactobar_providers::ProviderRegistry::get(provider)
+                    .and_then(|desc| desc.metadata.dashboard_url.clone());
+                card.child(group_header(
+                    "Sign in on the web",
+                    Some("ExactoBar reads your session cookies from the browser you pick in Providers"),
+                    theme,
+                ))
+                .when_some(url, |el, url| {
+                    el.child(
+                        div().flex().child(
+                            setup_button("setup-browser", "Open Sign-In Page", theme.selected, theme)
+                                .on_mouse_down(MouseButton::Left, move |_, _window, _cx| {
+                                    open_url(&url);
+                                }),
+                        ),
+                    )
+                })
+            }
+            SetupStep::DesktopApp => card.child(group_header(
+                "Open the desktop app",
+                Some("Sign in to the app and keep it running; ExactoBar reads usage from it"),
+                theme,
+            )),
+            SetupStep::Generated => card.child(group_header(
+                "Nothing to set up",
+                Some("Each refresh steps through made-up usage, errors, and status changes"),
+                theme,
+            )),
+        }
+    }
+
+    fn render_test_section(
+        &self,
+        setup: &ProviderSetup,
+        theme: SettingsTheme,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        let testing = matches!(setup.test, TestState::Testing);
+
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(12.0))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(group_header(
+                        "Test connection",
+                        Some("Fetches your current usage without saving anything"),
+                        theme,
+                    ))
+                    .child(
+                        setup_button(
+                            "setup-test",
+                            if testing {
+                                "Testing…"
+                            } else {
+                                "Test Connection"
+                            },
+                            theme.selected,
+                            theme,
+                        )
+                        .when(!testing, |el| {
+                            el.on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(|this, _, _window, cx| this.test_connection(cx)),
+                            )
+                        }),
+                    ),
+            )
+            .child(match &setup.test {
+                TestState::Untested | TestState::Testing => div(),
+                TestState::Passed(snapshot) => div()
+                    .p(px(12.0))
+                    .rounded(px(8.0))
+                    .bg(theme.code_bg)
+                    .flex()
+                    .flex_col()
+                    .gap(px(6.0))
+                    .child(status_line(
+                        "✓",
+                        "Connected".to_string(),
+                        theme.success,
+                        theme,
+                    ))
+                    .children(
+                        snapshot_summary(snapshot)
+                            .into_iter()
+                            .map(|(label, value)| {
+                                div()
+                                    .flex()
+                                    .gap(px(8.0))
+                                    .text_sm()
+                                    .child(
+                                        div().w(px(80.0)).text_color(theme.text_muted).child(label),
+                                    )
+                                    .child(div().child(value))
+                            }),
+                    ),
+                TestState::Failed(error) => div()
+                    .p(px(12.0))
+                    .rounded(px(8.0))
+                    .bg(theme.code_bg)
+                    .flex()
+                    .flex_col()
+                    .gap(px(6.0))
+                    .child(status_line(
+                        "✗",
+                        "Connection failed".to_string(),
+                        theme.error,
+                        theme,
+                    ))
+                    .child(
+                        div()
+                            .text_xs()
+                            .font_family("SF Mono, Menlo, monospace")
+                            .text_color(theme.text_muted)
+                            .child(error.clone()),
+                    ),
+            })
+    }
+}
+
+fn status_line(icon: &'static str, text: String, color: Hsla, theme: SettingsTheme) -> Div {
+    div()
+        .flex()
+        .items_center()
+        .gap(px(6.0))
+        .text_sm()
+        .child(div().text_color(color).child(icon))
+        .child(div().text_color(theme.text_primary).child(text))
+}
+
+fn setup_button(
+    id: &'static str,
+    label: impl Into<SharedString>,
+    bg: Hsla,
+    theme: SettingsTheme,
+) -> Stateful<Div> {
+    let hover_bg = theme.hover;
+    div()
+        .id(id)
+        .px(px(12.0))
+        .py(px(6.0))
+        .rounded(px(6.0))
+        .bg(bg)
+        .border_1()
+        .border_color(theme.border)
+        .text_sm()
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover_bg))
+        .child(label.into())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exactobar_core::{ProviderCost, ProviderIdentity, UsageWindow};
+
+    #[test]
+    fn test_every_provider_has_setup_steps() {
+        for desc in exactobar_providers::ProviderRegistry::all() {
+            assert!(!setup_steps(desc.id).is_empty(), "{:?}", desc.id);
+        }
+        assert!(setup_steps(ProviderKind::Synthetic).contains(&SetupStep::ApiKey));
+    }
+
+    #[test]
+    fn test_snapshot_summary() {
+        let mut snapshot = UsageSnapshot::new();
+        let mut identity = ProviderIdentity::new(ProviderKind::Claude);
+        identity.account_email = Some("me@example.com".to_string());
+        snapshot.identity = Some(identity);
+        let mut primary = UsageWindow::new(42.4);
+        primary.reset_description = Some("in 2 hours".to_string());
+        snapshot.primary = Some(primary);
+        snapshot.cost = Some(ProviderCost::new(3.5));
+
+        assert_eq!(
+            snapshot_summary(&snapshot),
+            vec![
+                ("Account".to_string(), "me@example.com".to_string()),
+                (
+                    "Session".to_string(),
+                    "42% used, resets in 2 hours".to_string()
+                ),
+                ("Spend".to_string(), format_currency(3.5, "USD")),
+                ("Source".to_string(), "Auto".to_string()),
+            ]
+        );
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! Provides light and dark mode colors that adapt to the system appearance.
 
-use gpui::Hsla;
+use exactobar_store::ThemeMode;
+use gpui::{Hsla, WindowAppearance};
 
 /// Theme colors for the settings window.
 #[derive(Clone, Copy, Debug)]
@@ -36,6 +37,26 @@ pub struct SettingsTheme {
 }
 
 impl SettingsTheme {
+    /// Theme for the user's theme mode, following the window's appearance
+    /// when set to System.
+    pub fn for_mode(mode: ThemeMode, appearance: WindowAppearance) -> Self {
+        match mode {
+            ThemeMode::Dark => Self::dark(),
+            ThemeMode::Light => Self::light(),
+            ThemeMode::HighContrast => Self::high_contrast(),
+            ThemeMode::System => {
+                if matches!(
+                    appearance,
+                    WindowAppearance::Dark | WindowAppearance::VibrantDark
+                ) {
+                    Self::dark()
+                } else {
+                    Self::light()
+                }
+            }
+        }
+    }
+
     /// Light theme colors (default macOS light appearance)
     pub fn light() -> Self {
         Self {
@@ -203,4 +224,88 @@ impl SettingsTheme {
             },
         }
     }
+
+    /// High-contrast colors: black background, white text, visible borders
+    pub fn high_contrast() -> Self {
+        Self {
+            bg: Hsla {
+                h: 0.0,
+                s: 0.0,
+                l: 0.0,
+                a: 1.0,
+            },
+            surface: Hsla {
+                h: 0.0,
+                s: 0.0,
+                l: 0.08,
+                a: 1.0,
+            },
+            border: Hsla {
+                h: 0.0,
+                s: 0.0,
+                l: 0.75,
+                a: 1.0,
+            },
+            text_primary: Hsla {
+                h: 0.0,
+                s: 0.0,
+                l: 1.0,
+                a: 1.0,
+            },
+            text_muted: Hsla {
+                h: 0.0,
+                s: 0.0,
+                l: 0.8,
+                a: 1.0,
+            },
+            selected: Hsla {
+                h: 217.0 / 360.0,
+                s: 0.8,
+                l: 0.3,
+                a: 1.0,
+            },
+            hover: Hsla {
+                h: 0.0,
+                s: 0.0,
+                l: 0.22,
+                a: 1.0,
+            },
+            link: Hsla {
+                h: 217.0 / 360.0,
+                s: 1.0,
+                l: 0.75,
+                a: 1.0,
+            },
+            brand: Hsla {
+                h: 160.0 / 360.0,
+                s: 0.72,
+                l: 0.55,
+                a: 1.0,
+            },
+            code_bg: Hsla {
+                h: 0.0,
+                s: 0.0,
+                l: 0.12,
+                a: 1.0,
+            },
+            warning: Hsla {
+                h: 45.0 / 360.0,
+                s: 1.0,
+                l: 0.6,
+                a: 1.0,
+            },
+            error: Hsla {
+                h: 0.0,
+                s: 1.0,
+                l: 0.67,
+                a: 1.0,
+            },
+            success: Hsla {
+                h: 120.0 / 360.0,
+                s: 0.65,
+                l: 0.55,
+                a: 1.0,
+            },
+        }
+    }
 }
@@ -0,0 +1,535 @@
+//! State simulator window.
+//!
+//! A developer window, opened from the Advanced settings pane while debug
+//! mode is on, that forces a provider into states that are hard to reach
+//! for real: any usage percentage, stale data, a refresh that never ends,
+//! errors, status incidents and icon animations. Forced values live until
+//! the provider's next refresh replaces them, and never reach the usage
+//! history.
+
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use exactobar_core::{
+    ProviderError, ProviderKind, ProviderStatus, StatusIncident, StatusIndicator, UsageSnapshot,
+    UsageWindow, WindowKind,
+};
+use gpui::prelude::*;
+use gpui::*;
+use tracing::info;
+
+use super::settings::SettingsTheme;
+use crate::refresh::FetchFailure;
+use crate::state::AppState;
+use crate::theme;
+use crate::tray::SystemTray;
+
+/// Global handle to the simulator window (if open).
+static SIMULATOR_WINDOW: Mutex<Option<AnyWindowHandle>> = Mutex::new(None);
+
+/// Usage percentages offered for each window.
+const PERCENTS: [f64; 7] = [0.0, 25.0, 50.0, 75.0, 90.0, 100.0, 120.0];
+
+/// Windows that can be forced, with their labels.
+const WINDOWS: [(WindowKind, &str); 3] = [
+    (WindowKind::Primary, "Session"),
+    (WindowKind::Secondary, "Weekly"),
+    (WindowKind::Tertiary, "Premium"),
+];
+
+/// Status indicators that can be forced, with their labels.
+const STATUSES: [(StatusIndicator, &str); 5] = [
+    (StatusIndicator::None, "Operational"),
+    (StatusIndicator::Minor, "Minor"),
+    (StatusIndicator::Major, "Major"),
+    (StatusIndicator::Critical, "Critical"),
+    (StatusIndicator::Maintenance, "Maintenance"),
+];
+
+/// Error presets: a short one of each kind, and one long enough to wrap.
+fn error_presets() -> [(&'static str, ProviderError); 4] {
+    [
+        (
+            "Signed out",
+            ProviderError::NotLoggedIn("Session expired, sign in again".into()),
+        ),
+        (
+            "Network",
+            ProviderError::Network("Timed out after 90s".into()),
+        ),
+        (
+            "Rate limited",
+            ProviderError::RateLimited("Too many requests, retry in 60s".into()),
+        ),
+        (
+            "Long",
+            ProviderError::Parse(
+                "Unexpected response from the usage endpoint: expected an object with \
+                 `five_hour` and `seven_day` fields but found an HTML page, which usually \
+                 means a captive portal or a proxy is intercepting requests"
+                    .into(),
+            ),
+        ),
+    ]
+}
+
+// ============================================================================
+// Forced State
+// ============================================================================
+
+/// Returns `snapshot` with one window's usage set to `percent`, adding the
+/// window if the snapshot didn't report it.
+fn with_window_percent(
+    snapshot: Option<UsageSnapshot>,
+    kind: WindowKind,
+    percent: f64,
+    now: DateTime<Utc>,
+) -> UsageSnapshot {
+    let mut snapshot = snapshot.unwrap_or_default();
+    let window = match kind {
+        WindowKind::Primary => &mut snapshot.primary,
+        WindowKind::Secondary => &mut snapshot.secondary,
+        WindowKind::Tertiary => &mut snapshot.tertiary,
+        WindowKind::Search => &mut snapshot.search,
+    };
+    window
+        .get_or_insert_with(|| UsageWindow::new(percent))
+        .used_percent = percent;
+    snapshot.updated_at = now;
+    snapshot
+}
+
+/// Returns `snapshot` as if it was fetched just over `stale_after` ago.
+fn aged(
+    mut snapshot: UsageSnapshot,
+    stale_after: std::time::Duration,
+    now: DateTime<Utc>,
+) -> UsageSnapshot {
+    let age = Duration::from_std(stale_after).unwrap_or(Duration::zero()) + Duration::minutes(1);
+    snapshot.updated_at = now - age;
+    snapshot
+}
+
+/// Sets one window of the provider's snapshot to `percent`.
+fn force_percent(provider: ProviderKind, kind: WindowKind, percent: f64, cx: &mut App) {
+    let usage = cx.global::<AppState>().usage.clone();
+    usage.update(cx, |model, cx| {
+        let snapshot = with_window_percent(model.get_snapshot(provider), kind, percent, Utc::now());
+        model.update_provider(provider, cx, |model| {
+            model.force_snapshot(provider, snapshot);
+        });
+    });
+}
+
+/// Makes the provider's snapshot stale, or fresh again.
+fn force_stale(provider: ProviderKind, stale: bool, cx: &mut App) {
+    let state = cx.global::<AppState>();
+    let stale_after = state.stale_after(provider, cx);
+    let usage = state.usage.clone();
+    usage.update(cx, |model, cx| {
+        let mut snapshot = model.get_snapshot(provider).unwrap_or_default();
+        if stale {
+            snapshot = aged(snapshot, stale_after, Utc::now());
+        } else {
+            snapshot.updated_at = Utc::now();
+        }
+        model.update_provider(provider, cx, |model| {
+            model.force_snapshot(provider, snapshot);
+        });
+    });
+}
+
+/// Shows the provider as refreshing, or stops showing it.
+fn force_refreshing(provider: ProviderKind, refreshing: bool, cx: &mut App) {
+    let usage = cx.global::<AppState>().usage.clone();
+    usage.update(cx, |model, cx| {
+        model.update_provider(provider, cx, |model| {
+            model.set_refreshing(provider, refreshing);
+        });
+    });
+}
+
+/// Fails the provider with `error`, or clears its error.
+fn force_error(provider: ProviderKind, error: Option<ProviderError>, cx: &mut App) {
+    let usage = cx.global::<AppState>().usage.clone();
+    usage.update(cx, |model, cx| {
+        model.update_provider(provider, cx, |model| match error {
+            Some(error) => {
+                model.set_failure(provider, FetchFailure::new(error));
+            }
+            None => model.clear_error(provider),
+        });
+    });
+}
+
+/// Reports an incident on the provider's status page.
+fn force_status(provider: ProviderKind, indicator: StatusIndicator, label: &str, cx: &mut App) {
+    let status = if indicator == StatusIndicator::None {
+        ProviderStatus::operational()
+    } else {
+        let description = format!("Simulated {} incident", label.to_lowercase());
+        let mut status = ProviderStatus::new(indicator, description.clone());
+        status.incidents.push(StatusIncident {
+            name: description,
+            status: "investigating".to_string(),
+            started_at: Some(Utc::now()),
+            components: vec![provider.display_name().to_string()],
+            latest_update: Some("Forced from the state simulator.".to_string()),
+            latest_update_at: Some(Utc::now()),
+        });
+        status
+    };
+    let usage = cx.global::<AppState>().usage.clone();
+    usage.update(cx, |model, cx| {
+        model.update_provider(provider, cx, |model| model.set_status(provider, status));
+    });
+}
+
+// ============================================================================
+// Simulator Window
+// ============================================================================
+
+/// State simulator content.
+pub struct SimulatorWindow {
+    /// Provider the controls apply to; the first enabled one if unset.
+    provider: Option<ProviderKind>,
+    usage_subscription: Option<Subscription>,
+}
+
+impl SimulatorWindow {
+    fn new() -> Self {
+        Self {
+            provider: None,
+            usage_subscription: None,
+        }
+    }
+}
+
+/// A clickable chip.
+fn chip(
+    id: impl Into<SharedString>,
+    label: impl Into<SharedString>,
+    selected: bool,
+    theme: SettingsTheme,
+    on_click: impl Fn(&mut App) + 'static,
+) -> Stateful<Div> {
+    div()
+        .id(id.into())
+        .px(px(10.0))
+        .py(px(4.0))
+        .rounded(px(6.0))
+        .border_1()
+        .border_color(theme.border)
+        .text_xs()
+        .cursor_pointer()
+        .when(selected, |el| el.bg(theme.selected))
+        .when(!selected, |el| {
+            el.bg(theme.surface).hover(move |s| s.bg(theme.hover))
+        })
+        .on_mouse_down(MouseButton::Left, move |_, _window, cx| on_click(cx))
+        .child(label.into())
+}
+
+/// A labelled row of chips.
+fn control_row(label: &'static str, theme: SettingsTheme, chips: Vec<Stateful<Div>>) -> Div {
+    div()
+        .flex()
+        .items_center()
+        .gap(px(12.0))
+        .child(
+            div()
+                .w(px(90.0))
+                .flex_shrink_0()
+                .text_sm()
+                .text_color(theme.text_muted)
+                .child(label),
+        )
+        .child(div().flex().flex_wrap().gap(px(6.0)).children(chips))
+}
+
+impl Render for SimulatorWindow {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Show the provider's state as it's forced
+        if self.usage_subscription.is_none() {
+            let usage = cx.global::<AppState>().usage.clone();
+            self.usage_subscription = Some(cx.observe(&usage, |_this, _model, cx| {
+                cx.notify();
+            }));
+        }
+
+        let state = cx.global::<AppState>();
+        let theme =
+            SettingsTheme::for_mode(state.settings.read(cx).theme_mode(), window.appearance());
+        let providers = state.enabled_providers(cx);
+        let Some(provider) = self
+            .provider
+            .filter(|p| providers.contains(p))
+            .or_else(|| providers.first().copied())
+        else {
+            return div()
+                .id("simulator-window")
+                .size_full()
+                .p(px(24.0))
+                .bg(theme.bg)
+                .text_color(theme.text_muted)
+                .child("Enable a provider to simulate its states");
+        };
+
+        let snapshot = state.get_snapshot(provider, cx);
+        let refreshing = state.is_provider_refreshing(provider, cx);
+        let indicator = state
+            .get_status(provider, cx)
+            .map(|s| s.indicator)
+            .unwrap_or_default();
+        let failure = state.get_failure(provider, cx);
+        let settings = state.settings.read(cx).settings();
+        let stale = snapshot
+            .as_ref()
+            .is_some_and(|s| settings.is_stale(provider, s.updated_at, Utc::now()));
+
+        let provider_chips = providers
+            .iter()
+            .map(|&p| {
+                let handle = cx.entity();
+                chip(
+                    format!("sim-provider-{}", p.cli_name()),
+                    p.display_name(),
+                    p == provider,
+                    theme,
+                    move |cx| {
+                        handle.update(cx, |this, cx| {
+                            this.provider = Some(p);
+                            cx.notify();
+                        });
+                    },
+                )
+            })
+            .collect();
+
+        let window_rows = WINDOWS.iter().map(|&(kind, label)| {
+            let current = snapshot
+                .as_ref()
+                .and_then(|s| s.window(kind))
+                .map(|w| w.used_percent);
+            control_row(
+                label,
+                theme,
+                PERCENTS
+                    .iter()
+                    .map(|&percent| {
+                        chip(
+                            format!("sim-{label}-{percent}"),
+                            format!("{percent:.0}%"),
+                            current.is_some_and(|c| (c - percent).abs() < f64::EPSILON),
+                            theme,
+                            move |cx| force_percent(provider, kind, percent, cx),
+                        )
+                    })
+                    .collect(),
+            )
+        });
+
+        let mut error_chips: Vec<Stateful<Div>> = error_presets()
+            .into_iter()
+            .map(|(label, error)| {
+                let selected = failure.as_ref() == Some(&error);
+                chip(
+                    format!("sim-error-{label}"),
+                    label,
+                    selected,
+                    theme,
+                    move |cx| force_error(provider, Some(error.clone()), cx),
+                )
+            })
+            .collect();
+        error_chips.push(chip(
+            "sim-error-clear",
+            "None",
+            failure.is_none(),
+            theme,
+            move |cx| force_error(provider, None, cx),
+        ));
+
+        div()
+            .id("simulator-window")
+            .size_full()
+            .overflow_y_scroll()
+            .p(px(24.0))
+            .bg(theme.bg)
+            .text_color(theme.text_primary)
+            .flex()
+            .flex_col()
+            .gap(px(16.0))
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(theme.text_muted)
+                    .child("Forced states last until the provider's next refresh."),
+            )
+            .child(control_row("Provider", theme, provider_chips))
+            .children(window_rows)
+            .child(control_row(
+                "Data",
+                theme,
+                vec![
+                    chip(
+                        "sim-fresh",
+                        "Fresh",
+                        snapshot.is_some() && !stale,
+                        theme,
+                        move |cx| force_stale(provider, false, cx),
+                    ),
+                    chip("sim-stale", "Stale", stale, theme, move |cx| {
+                        force_stale(provider, true, cx)
+                    }),
+                    chip(
+                        "sim-refreshing",
+                        "Refreshing",
+                        refreshing,
+                        theme,
+                        move |cx| force_refreshing(provider, !refreshing, cx),
+                    ),
+                ],
+            ))
+            .child(control_row("Error", theme, error_chips))
+            .child(control_row(
+                "Status",
+                theme,
+                STATUSES
+                    .iter()
+                    .map(|&(status, label)| {
+                        chip(
+                            format!("sim-status-{label}"),
+                            label,
+                            indicator == status,
+                            theme,
+                            move |cx| force_status(provider, status, label, cx),
+                        )
+                    })
+                    .collect(),
+            ))
+            .child(control_row(
+                "Animate",
+                theme,
+                vec![
+                    chip("sim-blink", "Blink", false, theme, move |cx| {
+                        cx.update_global::<SystemTray, _>(|tray, cx| {
+                            tray.trigger_blink(provider, cx);
+                        });
+                    }),
+                    chip("sim-wiggle", "Wiggle", false, theme, move |cx| {
+                        cx.update_global::<SystemTray, _>(|tray, cx| {
+                            tray.trigger_wiggle(provider, 2.0, cx);
+                        });
+                    }),
+                    chip("sim-tilt", "Tilt", false, theme, move |cx| {
+                        cx.update_global::<SystemTray, _>(|tray, cx| {
+                            tray.trigger_tilt(provider, 5.0, cx);
+                        });
+                    }),
+                ],
+            ))
+    }
+}
+
+/// Opens the state simulator, or focuses it if already open.
+pub fn open_simulator_window(cx: &mut App) {
+    {
+        let guard = SIMULATOR_WINDOW.lock().unwrap();
+        if let Some(handle) = *guard {
+            if cx
+                .update_window(handle, |_, window, _| {
+                    window.activate_window();
+                })
+                .is_ok()
+            {
+                info!("Focused existing simulator window");
+                cx.activate(true);
+                return;
+            }
+            // Window was closed, continue to create new one
+        }
+    }
+
+    info!("Opening simulator window");
+
+    // Menu bar apps must activate before showing a regular window
+    cx.activate(true);
+
+    let bounds = Bounds::centered(None, size(px(620.0), px(440.0)), cx);
+
+    let options = WindowOptions {
+        titlebar: Some(TitlebarOptions {
+            title: Some("State Simulator".into()),
+            appears_transparent: false,
+            traffic_light_position: None,
+        }),
+        window_bounds: Some(WindowBounds::Windowed(bounds)),
+        focus: true,
+        show: true,
+        kind: gpui::WindowKind::Normal,
+        is_movable: true,
+        display_id: None,
+        window_background: WindowBackgroundAppearance::Opaque,
+        app_id: None,
+        window_min_size: Some(size(px(480.0), px(320.0))),
+        window_decorations: None,
+        is_minimizable: true,
+        is_resizable: true,
+        tabbing_identifier: None,
+    };
+
+    match cx.open_window(options, |window, cx| {
+        cx.new(|cx| {
+            theme::follow_system_appearance(window, cx);
+            SimulatorWindow::new()
+        })
+    }) {
+        Ok(handle) => {
+            let mut guard = SIMULATOR_WINDOW.lock().unwrap();
+            *guard = Some(handle.into());
+        }
+        Err(e) => {
+            tracing::error!(error = ?e, "Failed to open simulator window");
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_window_percent() {
+        let now = Utc::now();
+        let mut base = UsageSnapshot::new();
+        let mut session = UsageWindow::new(10.0);
+        session.window_minutes = Some(300);
+        base.primary = Some(session);
+
+        let forced = with_window_percent(Some(base), WindowKind::Primary, 90.0, now);
+        let primary = forced.primary.unwrap();
+        assert!((primary.used_percent - 90.0).abs() < f64::EPSILON);
+        assert_eq!(primary.window_minutes, Some(300));
+        assert_eq!(forced.updated_at, now);
+
+        let added = with_window_percent(None, WindowKind::Secondary, 50.0, now);
+        assert!(added.primary.is_none());
+        assert!((added.secondary.unwrap().used_percent - 50.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_aged() {
+        let now = Utc::now();
+        let snapshot = aged(
+            UsageSnapshot::new(),
+            std::time::Duration::from_secs(600),
+            now,
+        );
+        assert_eq!(snapshot.updated_at, now - Duration::minutes(11));
+    }
+}
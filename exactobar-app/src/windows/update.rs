@@ -1,18 +1,46 @@
 //! Update available dialog window.
 //!
 //! Displays a modal dialog when a new version of ExactoBar is available,
-//! showing version info, release notes, and download options.
+//! showing version info, release notes, and install or download options.
 
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::{Duration, Utc};
 use gpui::prelude::*;
 use gpui::*;
 use tracing::info;
 
-use crate::updater::{UpdateCheckResult, open_release_page};
+use super::markdown::MarkdownView;
+use crate::installer::{self, DownloadProgress, InstallMethod};
+use crate::state::AppState;
+use crate::updater::{UpdateAsset, UpdateCheckResult, open_release_page};
+
+/// How long "Remind Me Later" holds off the update dialog.
+const REMIND_AFTER_HOURS: i64 = 24;
+
+/// How often download progress is redrawn.
+const PROGRESS_POLL_MS: u64 = 250;
 
 // ============================================================================
 // Update Dialog
 // ============================================================================
 
+/// Progress of an in-place install.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstallState {
+    /// Not started.
+    Idle,
+    /// Downloading, verifying, and swapping binaries.
+    Installing,
+    /// Download paused by the user; resumes where it stopped.
+    Paused,
+    /// Installed; takes effect on relaunch.
+    Installed,
+    /// Install failed with a user-facing message.
+    Failed(String),
+}
+
 /// The update dialog window content.
 pub struct UpdateDialog {
     /// Current installed version.
@@ -21,8 +49,20 @@ pub struct UpdateDialog {
     pub latest: String,
     /// URL to the GitHub release page.
     pub release_url: String,
-    /// Release notes/changelog (optional).
+    /// Markdown changelog for every release since `current` (optional).
     pub release_notes: Option<String>,
+    /// Archive that can be installed in place (if this platform has one).
+    pub asset: Option<UpdateAsset>,
+    /// Whether "Install Update" is offered for this install method.
+    pub can_install: bool,
+    /// Progress of the in-place install.
+    pub install: InstallState,
+    /// Download progress of the current install attempt.
+    progress: Arc<DownloadProgress>,
+    /// Smoothed download speed in bytes per second.
+    speed: f64,
+    /// Last (time, bytes downloaded) sample used for `speed`.
+    speed_sample: Option<(Instant, u64)>,
 }
 
 impl UpdateDialog {
@@ -35,23 +75,136 @@ impl UpdateDialog {
             latest,
             release_url,
             release_notes,
+            asset,
             ..
         } = result
         {
+            // Only verifiable archives are installed automatically
+            let asset = asset.clone().filter(|a| a.checksum_url.is_some());
+            let can_install = installer::install_method().can_install(asset.is_some());
+
+            // Package installs update from their repository, not GitHub
+            let release_url = match installer::install_method() {
+                InstallMethod::Deb => env!("CARGO_PKG_REPOSITORY").to_string(),
+                _ => release_url.clone(),
+            };
+
             Some(Self {
                 current: current.clone(),
                 latest: latest.clone(),
-                release_url: release_url.clone(),
+                release_url,
                 release_notes: release_notes.clone(),
+                asset,
+                can_install,
+                install: InstallState::Idle,
+                progress: Arc::default(),
+                speed: 0.0,
+                speed_sample: None,
             })
         } else {
             None
         }
     }
+
+    /// Downloads and installs the update in the background, resuming a
+    /// paused or failed download.
+    fn start_install(&mut self, cx: &mut Context<Self>) {
+        if !self.can_install {
+            return;
+        }
+        let asset = self.asset.clone();
+        info!(
+            version = self.latest.as_str(),
+            "User started update install"
+        );
+        self.install = InstallState::Installing;
+        self.progress = Arc::default();
+        self.speed = 0.0;
+        self.speed_sample = None;
+        cx.notify();
+
+        let progress = self.progress.clone();
+        cx.spawn(async move |this, cx| {
+            let result = installer::install_update(asset, progress.clone()).await;
+            let _ = this.update(cx, |this, cx| {
+                this.install = if progress.is_cancelled() {
+                    installer::discard_download();
+                    InstallState::Idle
+                } else if progress.is_paused() {
+                    InstallState::Paused
+                } else {
+                    match result {
+                        Ok(_) => InstallState::Installed,
+                        Err(message) => {
+                            tracing::warn!(error = message.as_str(), "Update install failed");
+                            InstallState::Failed(message)
+                        }
+                    }
+                };
+                cx.notify();
+            });
+        })
+        .detach();
+
+        // Redraw progress until the install finishes
+        cx.spawn(async move |this, cx| {
+            loop {
+                smol::Timer::after(std::time::Duration::from_millis(PROGRESS_POLL_MS)).await;
+                let installing = this
+                    .update(cx, |this, cx| {
+                        this.sample_speed();
+                        cx.notify();
+                        this.install == InstallState::Installing
+                    })
+                    .unwrap_or(false);
+                if !installing {
+                    break;
+                }
+            }
+        })
+        .detach();
+    }
+
+    /// Pauses the running download.
+    fn pause_install(&mut self) {
+        info!("User paused update download");
+        self.progress.pause();
+    }
+
+    /// Cancels the download and discards what was downloaded.
+    fn cancel_install(&mut self, cx: &mut Context<Self>) {
+        info!("User cancelled update download");
+        if self.install == InstallState::Installing {
+            // The install task discards the download once it stops
+            self.progress.cancel();
+        } else {
+            installer::discard_download();
+            self.install = InstallState::Idle;
+            cx.notify();
+        }
+    }
+
+    /// Updates the smoothed download speed from the latest progress.
+    fn sample_speed(&mut self) {
+        let now = Instant::now();
+        let downloaded = self.progress.downloaded();
+        if let Some((at, previous)) = self.speed_sample {
+            let secs = now.duration_since(at).as_secs_f64();
+            if secs > 0.0 {
+                let current = downloaded.saturating_sub(previous) as f64 / secs;
+                self.speed = if self.speed == 0.0 {
+                    current
+                } else {
+                    self.speed * 0.7 + current * 0.3
+                };
+            }
+        }
+        self.speed_sample = Some((now, downloaded));
+    }
 }
 
 impl Render for UpdateDialog {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let release_url = self.release_url.clone();
         let current = self.current.clone();
         let latest = self.latest.clone();
@@ -79,8 +232,16 @@ impl Render for UpdateDialog {
             .when(has_notes, |el| {
                 el.child(Self::render_release_notes(release_notes.clone()))
             })
-            // Action buttons
-            .child(Self::render_buttons(release_url))
+            // Download progress (while installing or paused)
+            .when(
+                matches!(
+                    self.install,
+                    InstallState::Installing | InstallState::Paused
+                ),
+                |el| el.child(self.render_progress()),
+            )
+            // Install status and action buttons
+            .child(self.render_buttons(release_url, cx))
     }
 }
 
@@ -107,67 +268,254 @@ impl UpdateDialog {
     /// Renders the release notes section with scrollable content.
     fn render_release_notes(notes: String) -> impl IntoElement {
         div()
-            .id("release-notes-scroll")
-            .mt(px(8.0))
-            .p(px(12.0))
-            .rounded(px(8.0))
-            .bg(hsla(0.0, 0.0, 0.15, 1.0))
-            .max_h(px(150.0))
-            .overflow_y_scroll()
+            .flex()
+            .flex_col()
+            .gap(px(6.0))
             .child(
                 div()
                     .text_sm()
-                    .text_color(hsla(0.0, 0.0, 0.8, 1.0))
-                    .child(notes),
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .child("What's New"),
+            )
+            .child(
+                div()
+                    .id("release-notes-scroll")
+                    .max_h(px(340.0))
+                    .p(px(12.0))
+                    .rounded(px(8.0))
+                    .bg(hsla(0.0, 0.0, 0.15, 1.0))
+                    .overflow_y_scroll()
+                    .child(MarkdownView::new(&notes)),
             )
     }
 
-    /// Renders the action buttons (Download and Later).
-    fn render_buttons(release_url: String) -> impl IntoElement {
+    /// Renders the download progress bar with size and speed.
+    fn render_progress(&self) -> impl IntoElement {
+        let downloaded = self.progress.downloaded();
+        let fraction = self.progress.fraction();
+        let paused = self.install == InstallState::Paused;
+
+        let mut detail = match self.progress.total() {
+            Some(total) => format!(
+                "{} of {}",
+                format_bytes(downloaded as f64),
+                format_bytes(total as f64)
+            ),
+            None if downloaded > 0 => format_bytes(downloaded as f64),
+            None => "Preparing…".to_string(),
+        };
+        if paused {
+            detail = format!("Paused · {}", detail);
+        } else if self.speed > 0.0 {
+            detail = format!("{} · {}/s", detail, format_bytes(self.speed));
+        }
+
         div()
-            .mt(px(8.0))
             .flex()
-            .gap(px(12.0))
-            // Download button - primary action
+            .flex_col()
+            .gap(px(6.0))
             .child(
                 div()
-                    .id("download-btn")
-                    .px(px(16.0))
-                    .py(px(8.0))
-                    .rounded(px(6.0))
-                    .bg(hsla(217.0 / 360.0, 0.9, 0.5, 1.0))
-                    .text_sm()
-                    .font_weight(FontWeight::SEMIBOLD)
-                    .cursor_pointer()
-                    .hover(|s| s.bg(hsla(217.0 / 360.0, 0.9, 0.6, 1.0)))
-                    .on_mouse_down(MouseButton::Left, move |_, window, _| {
-                        info!(url = release_url.as_str(), "User clicked download update");
-                        open_release_page(&release_url);
-                        // Close the dialog after opening the page
-                        window.remove_window();
-                    })
-                    .child("Download Update"),
+                    .h(px(6.0))
+                    .w_full()
+                    .rounded(px(3.0))
+                    .bg(hsla(0.0, 0.0, 0.2, 1.0))
+                    .child(
+                        div()
+                            .h_full()
+                            .rounded(px(3.0))
+                            .w(relative(fraction.unwrap_or(0.0)))
+                            .bg(if paused {
+                                hsla(0.0, 0.0, 0.5, 1.0)
+                            } else {
+                                hsla(217.0 / 360.0, 0.9, 0.5, 1.0)
+                            }),
+                    ),
             )
-            // Later button - dismiss
             .child(
                 div()
-                    .id("later-btn")
-                    .px(px(16.0))
-                    .py(px(8.0))
-                    .rounded(px(6.0))
-                    .bg(hsla(0.0, 0.0, 0.2, 1.0))
-                    .text_sm()
-                    .cursor_pointer()
-                    .hover(|s| s.bg(hsla(0.0, 0.0, 0.25, 1.0)))
-                    .on_mouse_down(MouseButton::Left, |_, window, _| {
-                        info!("User dismissed update dialog");
-                        window.remove_window();
+                    .text_xs()
+                    .text_color(hsla(0.0, 0.0, 0.7, 1.0))
+                    .child(detail),
+            )
+    }
+
+    /// Renders the install status and action buttons.
+    ///
+    /// Offers "Install Update" when the app can update itself, and
+    /// "Download Update" (the release page) or "View Repository" (deb
+    /// installs) otherwise.
+    fn render_buttons(&self, release_url: String, cx: &mut Context<Self>) -> impl IntoElement {
+        let can_install = self.can_install;
+        let hint = installer::install_method().update_hint();
+        let is_deb = *installer::install_method() == InstallMethod::Deb;
+        let status = match &self.install {
+            InstallState::Idle => hint.map(|hint| (hint.to_string(), false)),
+            InstallState::Installing | InstallState::Paused => None,
+            InstallState::Installed => Some((
+                format!("v{} is installed. Relaunch to start using it.", self.latest),
+                false,
+            )),
+            InstallState::Failed(message) => Some((message.clone(), true)),
+        };
+
+        let start = |id, label| {
+            dialog_button(id, label, true).on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|this, _, _, cx| this.start_install(cx)),
+            )
+        };
+        let primary = match self.install {
+            InstallState::Idle if can_install => Some(start("install-btn", "Install Update")),
+            InstallState::Paused => Some(start("resume-btn", "Resume")),
+            InstallState::Failed(_) if can_install => Some(start("retry-btn", "Retry")),
+            InstallState::Installing => {
+                Some(dialog_button("pause-btn", "Pause", false).on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(|this, _, _, _| this.pause_install()),
+                ))
+            }
+            InstallState::Installed => Some(
+                dialog_button("relaunch-btn", "Relaunch Now", true)
+                    .on_mouse_down(MouseButton::Left, |_, _, cx| installer::relaunch(cx)),
+            ),
+            _ => None,
+        };
+        let cancel = matches!(
+            self.install,
+            InstallState::Installing | InstallState::Paused
+        )
+        .then(|| {
+            dialog_button("cancel-btn", "Cancel", false).on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|this, _, _, cx| this.cancel_install(cx)),
+            )
+        });
+
+        // The release page stays available as a fallback
+        let show_download = !can_install || matches!(self.install, InstallState::Failed(_));
+        let deciding = matches!(self.install, InstallState::Idle | InstallState::Failed(_));
+        let installed = self.install == InstallState::Installed;
+
+        div()
+            .mt(px(8.0))
+            .flex()
+            .flex_col()
+            .gap(px(12.0))
+            .when_some(status, |el, (text, is_error)| {
+                el.child(
+                    div()
+                        .text_sm()
+                        .text_color(if is_error {
+                            hsla(0.0, 0.7, 0.6, 1.0)
+                        } else {
+                            hsla(0.0, 0.0, 0.7, 1.0)
+                        })
+                        .child(text),
+                )
+            })
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .gap(px(12.0))
+                    .children(primary)
+                    .children(cancel)
+                    .when(show_download, |el| {
+                        el.child(
+                            dialog_button(
+                                "download-btn",
+                                if is_deb {
+                                    "View Repository"
+                                } else {
+                                    "Download Update"
+                                },
+                                !can_install,
+                            )
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                move |_, window, _| {
+                                    info!(
+                                        url = release_url.as_str(),
+                                        "User clicked download update"
+                                    );
+                                    open_release_page(&release_url);
+                                    // Close the dialog after opening the page
+                                    window.remove_window();
+                                },
+                            ),
+                        )
                     })
-                    .child("Later"),
+                    // Snooze or skip (only before installing, or after a failure)
+                    .when(deciding, |el| {
+                        let skipped = self.latest.clone();
+                        el.child(
+                            dialog_button("remind-btn", "Remind Me Later", false).on_mouse_down(
+                                MouseButton::Left,
+                                |_, window, cx| {
+                                    info!("User postponed update");
+                                    let after = Utc::now() + Duration::hours(REMIND_AFTER_HOURS);
+                                    cx.update_global::<AppState, _>(|state, cx| {
+                                        state.settings.update(cx, |model, _| {
+                                            model.set_update_reminder_after(Some(after));
+                                        });
+                                    });
+                                    window.remove_window();
+                                },
+                            ),
+                        )
+                        .child(
+                            dialog_button("skip-btn", "Skip This Version", false).on_mouse_down(
+                                MouseButton::Left,
+                                move |_, window, cx| {
+                                    info!(version = skipped.as_str(), "User skipped update");
+                                    cx.update_global::<AppState, _>(|state, cx| {
+                                        state.settings.update(cx, |model, _| {
+                                            model.set_skipped_update_version(Some(skipped.clone()));
+                                        });
+                                    });
+                                    window.remove_window();
+                                },
+                            ),
+                        )
+                    })
+                    .when(installed, |el| {
+                        el.child(dialog_button("later-btn", "Later", false).on_mouse_down(
+                            MouseButton::Left,
+                            |_, window, _| {
+                                info!("User dismissed update dialog");
+                                window.remove_window();
+                            },
+                        ))
+                    }),
             )
     }
 }
 
+/// A dialog button; `primary` buttons are filled with the accent color.
+fn dialog_button(id: &'static str, label: &'static str, primary: bool) -> Stateful<Div> {
+    let (bg, hover_bg) = if primary {
+        (
+            hsla(217.0 / 360.0, 0.9, 0.5, 1.0),
+            hsla(217.0 / 360.0, 0.9, 0.6, 1.0),
+        )
+    } else {
+        (hsla(0.0, 0.0, 0.2, 1.0), hsla(0.0, 0.0, 0.25, 1.0))
+    };
+
+    div()
+        .id(id)
+        .px(px(16.0))
+        .py(px(8.0))
+        .rounded(px(6.0))
+        .bg(bg)
+        .text_sm()
+        .when(primary, |el| el.font_weight(FontWeight::SEMIBOLD))
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover_bg))
+        .child(label)
+}
+
 // ============================================================================
 // Public API
 // ============================================================================
@@ -192,7 +540,12 @@ pub fn show_update_dialog(result: &UpdateCheckResult, cx: &mut App) {
     // Activate the app first (required for menu bar apps)
     cx.activate(true);
 
-    let bounds = Bounds::centered(None, size(px(400.0), px(300.0)), cx);
+    let height = if dialog.release_notes.is_some() {
+        520.0
+    } else {
+        300.0
+    };
+    let bounds = Bounds::centered(None, size(px(480.0), px(height)), cx);
 
     let options = WindowOptions {
         titlebar: Some(TitlebarOptions {
@@ -211,7 +564,7 @@ pub fn show_update_dialog(result: &UpdateCheckResult, cx: &mut App) {
         window_min_size: None,
         window_decorations: None,
         is_minimizable: true,
-        is_resizable: false,
+        is_resizable: true,
         tabbing_identifier: None,
     };
 
@@ -232,3 +585,28 @@ pub fn show_update_dialog(result: &UpdateCheckResult, cx: &mut App) {
         }
     }
 }
+
+/// Formats a byte count (or rate) as KB or MB.
+pub(crate) fn format_bytes(bytes: f64) -> String {
+    const MB: f64 = 1024.0 * 1024.0;
+    if bytes >= MB {
+        format!("{:.1} MB", bytes / MB)
+    } else {
+        format!("{:.0} KB", bytes / 1024.0)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512.0 * 1024.0), "512 KB");
+        assert_eq!(format_bytes(12.34 * 1024.0 * 1024.0), "12.3 MB");
+    }
+}
@@ -3,7 +3,11 @@
 use anyhow::Result;
 use clap::{Args, Subcommand};
 use exactobar_providers::ProviderRegistry;
-use exactobar_store::{SettingsStore, default_config_dir, default_settings_path};
+use exactobar_store::{
+    MqttSettings, SettingsStore, Webhook, WebhookEventKind, WebhookFormat, default_config_dir,
+    default_settings_path,
+};
+use std::path::{Path, PathBuf};
 use tracing::info;
 
 use crate::output::JsonFormatter;
@@ -43,10 +47,80 @@ pub enum ConfigAction {
         cadence: String,
     },
 
+    /// Manage webhooks notified of usage events.
+    Webhook {
+        #[command(subcommand)]
+        action: WebhookAction,
+    },
+
+    /// Show or set where the SwiftBar/xbar plugin is written.
+    Swiftbar {
+        /// Plugin file, e.g. in SwiftBar's plugin folder as `exactobar.1m.sh`.
+        path: Option<PathBuf>,
+
+        /// Stop writing the plugin.
+        #[arg(long, conflicts_with = "path")]
+        disable: bool,
+    },
+
+    /// Show or set the MQTT broker usage is published to.
+    Mqtt {
+        /// Broker address as host:port, e.g. "homeassistant.local:1883".
+        broker: Option<String>,
+
+        /// Topic prefix (default: exactobar).
+        #[arg(long, requires = "broker")]
+        topic_prefix: Option<String>,
+
+        /// Broker username.
+        #[arg(long, requires = "broker")]
+        username: Option<String>,
+
+        /// Broker password.
+        #[arg(long, requires = "broker")]
+        password: Option<String>,
+
+        /// Stop publishing.
+        #[arg(long, conflicts_with = "broker")]
+        disable: bool,
+    },
+
     /// Reset to defaults.
     Reset,
 }
 
+/// Webhook subcommands.
+#[derive(Subcommand)]
+pub enum WebhookAction {
+    /// List registered webhooks.
+    List,
+
+    /// Register a webhook (replaces one with the same URL).
+    Add {
+        /// URL that receives the JSON payloads.
+        url: String,
+
+        /// Secret for signing payloads (X-ExactoBar-Signature header, over the timestamp and body).
+        #[arg(long)]
+        secret: Option<String>,
+
+        /// Events to send: threshold, error, reset (default: all).
+        /// Can be comma-separated: "threshold,reset"
+        #[arg(long)]
+        events: Option<String>,
+
+        /// Message format: json, slack, discord (default: detected from the URL).
+        #[arg(long)]
+        format: Option<String>,
+    },
+
+    /// Remove a webhook.
+    Remove {
+        /// URL of the webhook to remove.
+        url: String,
+    },
+}
+
 /// Runs the config command.
 pub async fn run(args: &ConfigArgs, cli: &Cli) -> Result<()> {
     match &args.action {
@@ -55,6 +129,28 @@ pub async fn run(args: &ConfigArgs, cli: &Cli) -> Result<()> {
         ConfigAction::Enable { provider } => enable_provider(provider, cli).await,
         ConfigAction::Disable { provider } => disable_provider(provider, cli).await,
         ConfigAction::Refresh { cadence } => set_refresh(cadence, cli).await,
+        ConfigAction::Webhook { action } => manage_webhooks(action, cli).await,
+        ConfigAction::Swiftbar { path, disable } => {
+            set_swiftbar_plugin(path.as_deref(), *disable).await
+        }
+        ConfigAction::Mqtt {
+            broker,
+            topic_prefix,
+            username,
+            password,
+            disable,
+        } => {
+            let mqtt = broker.as_ref().map(|broker| {
+                let mut mqtt = MqttSettings::new(broker.clone());
+                if let Some(prefix) = topic_prefix {
+                    mqtt.topic_prefix.clone_from(prefix);
+                }
+                mqtt.username.clone_from(username);
+                mqtt.password.clone_from(password);
+                mqtt
+            });
+            set_mqtt(mqtt, *disable, cli).await
+        }
         ConfigAction::Reset => reset_config(cli).await,
     }
 }
@@ -64,7 +160,7 @@ async fn show_config(cli: &Cli) -> Result<()> {
     let settings = store.get().await;
 
     match cli.format {
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Table => {
             println!("ExactoBar Configuration");
             println!("{}", "─".repeat(40));
             println!();
@@ -95,7 +191,7 @@ fn show_paths(cli: &Cli) -> Result<()> {
     let settings_path = default_settings_path();
 
     match cli.format {
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Table => {
             println!("Configuration Paths");
             println!("{}", "─".repeat(40));
             println!();
@@ -165,6 +261,187 @@ async fn set_refresh(cadence: &str, _cli: &Cli) -> Result<()> {
     Ok(())
 }
 
+async fn manage_webhooks(action: &WebhookAction, cli: &Cli) -> Result<()> {
+    let store = SettingsStore::load_default().await?;
+
+    match action {
+        WebhookAction::List => {
+            let webhooks = store.webhooks().await;
+            match cli.format {
+                OutputFormat::Text | OutputFormat::Table => {
+                    if webhooks.is_empty() {
+                        println!("No webhooks registered");
+                    }
+                    for webhook in &webhooks {
+                        let events = if webhook.events.is_empty() {
+                            "all events".to_string()
+                        } else {
+                            let names: Vec<String> =
+                                webhook.events.iter().map(|e| e.to_string()).collect();
+                            names.join(", ")
+                        };
+                        let signed = if webhook.secret.is_some() {
+                            ", signed"
+                        } else {
+                            ""
+                        };
+                        println!("{} ({}, {}{})", webhook.url, webhook.format, events, signed);
+                    }
+                }
+                OutputFormat::Json => {
+                    // Never print secrets
+                    let hooks: Vec<_> = webhooks
+                        .iter()
+                        .map(|w| {
+                            serde_json::json!({
+                                "url": w.url,
+                                "events": w.events,
+                                "format": w.format,
+                                "signed": w.secret.is_some(),
+                            })
+                        })
+                        .collect();
+                    let formatter = JsonFormatter::new(cli.pretty);
+                    println!("{}", formatter.format(&hooks)?);
+                }
+            }
+            return Ok(());
+        }
+        WebhookAction::Add {
+            url,
+            secret,
+            events,
+            format,
+        } => {
+            if !(url.starts_with("https://") || url.starts_with("http://")) {
+                anyhow::bail!("Webhook URL must start with http:// or https://");
+            }
+            let events = match events {
+                Some(events) => parse_webhook_events(events)?,
+                None => Vec::new(),
+            };
+            let format = match format {
+                Some(format) => parse_webhook_format(format)?,
+                None => WebhookFormat::detect(url),
+            };
+            store
+                .add_webhook(Webhook {
+                    url: url.clone(),
+                    secret: secret.clone(),
+                    events,
+                    format,
+                })
+                .await;
+            store.save().await?;
+            println!("Added webhook: {} ({})", url, format);
+        }
+        WebhookAction::Remove { url } => {
+            if !store.remove_webhook(url).await {
+                anyhow::bail!("No webhook registered for {}", url);
+            }
+            store.save().await?;
+            println!("Removed webhook: {}", url);
+        }
+    }
+
+    println!("Restart ExactoBar to apply");
+    Ok(())
+}
+
+/// Parses a comma-separated list of webhook event kinds.
+fn parse_webhook_events(s: &str) -> Result<Vec<WebhookEventKind>> {
+    s.split(',')
+        .map(|name| match name.trim().to_lowercase().as_str() {
+            "threshold" => Ok(WebhookEventKind::Threshold),
+            "error" => Ok(WebhookEventKind::Error),
+            "reset" => Ok(WebhookEventKind::Reset),
+            other => anyhow::bail!("Unknown event: {}. Use: threshold, error, reset", other),
+        })
+        .collect()
+}
+
+/// Parses a webhook message format.
+fn parse_webhook_format(s: &str) -> Result<WebhookFormat> {
+    match s.trim().to_lowercase().as_str() {
+        "json" => Ok(WebhookFormat::Json),
+        "slack" => Ok(WebhookFormat::Slack),
+        "discord" => Ok(WebhookFormat::Discord),
+        other => anyhow::bail!("Unknown format: {}. Use: json, slack, discord", other),
+    }
+}
+
+async fn set_swiftbar_plugin(path: Option<&Path>, disable: bool) -> Result<()> {
+    let store = SettingsStore::load_default().await?;
+
+    if disable {
+        store.set_swiftbar_plugin_path(None).await;
+        store.save().await?;
+        println!("SwiftBar plugin disabled");
+    } else if let Some(path) = path {
+        // The app resolves paths from its own working directory
+        let path = std::path::absolute(path)?;
+        println!("SwiftBar plugin: {}", path.display());
+        store.set_swiftbar_plugin_path(Some(path)).await;
+        store.save().await?;
+    } else {
+        match store.swiftbar_plugin_path().await {
+            Some(path) => println!("SwiftBar plugin: {}", path.display()),
+            None => println!("SwiftBar plugin: disabled"),
+        }
+        return Ok(());
+    }
+
+    println!("Restart ExactoBar to apply");
+    Ok(())
+}
+
+async fn set_mqtt(mqtt: Option<MqttSettings>, disable: bool, cli: &Cli) -> Result<()> {
+    let store = SettingsStore::load_default().await?;
+
+    if disable {
+        store.set_mqtt(None).await;
+        store.save().await?;
+        println!("MQTT publishing disabled");
+    } else if let Some(mqtt) = mqtt {
+        if !mqtt.broker.contains(':') {
+            anyhow::bail!("Broker must be host:port, e.g. localhost:1883");
+        }
+        println!("Publishing to {} under {}/", mqtt.broker, mqtt.topic_prefix);
+        store.set_mqtt(Some(mqtt)).await;
+        store.save().await?;
+    } else {
+        let mqtt = store.mqtt().await;
+        match cli.format {
+            OutputFormat::Text | OutputFormat::Table => match mqtt {
+                Some(mqtt) => {
+                    println!("Broker:       {}", mqtt.broker);
+                    println!("Topic prefix: {}", mqtt.topic_prefix);
+                    if let Some(username) = &mqtt.username {
+                        println!("Username:     {}", username);
+                    }
+                }
+                None => println!("MQTT publishing: disabled"),
+            },
+            OutputFormat::Json => {
+                // Never print the password
+                let output = mqtt.map(|m| {
+                    serde_json::json!({
+                        "broker": m.broker,
+                        "topic_prefix": m.topic_prefix,
+                        "username": m.username,
+                    })
+                });
+                let formatter = JsonFormatter::new(cli.pretty);
+                println!("{}", formatter.format(&output)?);
+            }
+        }
+        return Ok(());
+    }
+
+    println!("Restart ExactoBar to apply");
+    Ok(())
+}
+
 async fn reset_config(_cli: &Cli) -> Result<()> {
     let path = default_settings_path();
 
@@ -178,3 +455,27 @@ async fn reset_config(_cli: &Cli) -> Result<()> {
 
     Ok(())
 }
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_webhook_events() {
+        assert_eq!(
+            parse_webhook_events("threshold, reset").unwrap(),
+            vec![WebhookEventKind::Threshold, WebhookEventKind::Reset]
+        );
+        assert!(parse_webhook_events("threshold,bogus").is_err());
+    }
+
+    #[test]
+    fn test_parse_webhook_format() {
+        assert_eq!(parse_webhook_format("Slack").unwrap(), WebhookFormat::Slack);
+        assert!(parse_webhook_format("teams").is_err());
+    }
+}
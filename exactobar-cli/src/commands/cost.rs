@@ -227,7 +227,7 @@ fn output_cost_results(
     }
 
     match cli.format {
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Table => {
             let formatter = TextFormatter::new(!cli.no_color);
 
             let mut first = true;
@@ -16,7 +16,7 @@ pub async fn run(cli: &Cli) -> Result<()> {
     let _ctx = FetchContext::builder().build();
 
     match cli.format {
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Table => {
             let formatter = TextFormatter::new(!cli.no_color);
 
             println!("{}", formatter.format_providers_header());
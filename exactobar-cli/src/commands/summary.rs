@@ -48,7 +48,7 @@ pub async fn run(cli: &Cli) -> Result<()> {
 
     // Output
     match cli.format {
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Table => {
             let formatter = TextFormatter::new(!cli.no_color);
             println!("{}", formatter.format_summary(&results));
         }
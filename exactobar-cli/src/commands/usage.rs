@@ -5,6 +5,7 @@ use clap::Args;
 use exactobar_core::{ProviderKind, UsageSnapshot};
 use exactobar_fetch::{FetchContext, SourceMode};
 use exactobar_providers::ProviderRegistry;
+use exactobar_store::{Settings, SettingsStore};
 use std::collections::HashMap;
 use tracing::{debug, info, warn};
 
@@ -12,10 +13,11 @@ use crate::output::{JsonFormatter, TextFormatter};
 use crate::{Cli, ExitCode, OutputFormat};
 
 /// Arguments for the usage command.
-#[derive(Args, Default)]
+#[derive(Args)]
 pub struct UsageArgs {
     /// Provider to query (or "all", "both" for multiple).
-    /// Can be comma-separated: "codex,claude"
+    /// Can be comma-separated: "codex,claude". Defaults to the providers
+    /// enabled in the app.
     #[arg(long, short)]
     pub provider: Option<String>,
 
@@ -36,11 +38,25 @@ pub struct UsageArgs {
     pub debug: bool,
 }
 
+impl Default for UsageArgs {
+    /// Matches the clap defaults, for running usage without a subcommand.
+    fn default() -> Self {
+        Self {
+            provider: None,
+            no_credits: false,
+            web_timeout: 60,
+            source: "auto".to_string(),
+            debug: false,
+        }
+    }
+}
+
 /// Runs the usage command.
 pub async fn run(args: &UsageArgs, cli: &Cli) -> Result<()> {
     // Determine which providers to query
     let provider_arg = args.provider.as_ref().or(cli.provider.as_ref());
-    let providers = parse_provider_selection(provider_arg)?;
+    let configured = load_settings().await.sorted_enabled_providers();
+    let providers = parse_provider_selection(provider_arg, &configured)?;
 
     info!(providers = ?providers, "Fetching usage");
 
@@ -58,7 +74,7 @@ pub async fn run(args: &UsageArgs, cli: &Cli) -> Result<()> {
     let has_success = results.values().any(|r| r.is_ok());
 
     // Format and output
-    output_results(&results, args, cli)?;
+    output_results(&providers, &results, args, cli)?;
 
     // Exit code based on results
     if !has_success {
@@ -110,11 +126,36 @@ async fn fetch_one(provider: ProviderKind, ctx: &FetchContext) -> Result<UsageSn
     }
 }
 
+/// Loads the app's settings and applies their proxy and TLS options to HTTP
+/// clients.
+///
+/// Falls back to the defaults if the settings can't be read.
+pub async fn load_settings() -> Settings {
+    let settings = match SettingsStore::load_default().await {
+        Ok(store) => store.get().await,
+        Err(e) => {
+            warn!(error = %e, "Failed to load settings");
+            Settings::default()
+        }
+    };
+    exactobar_fetch::network::set_proxy(settings.proxy_config());
+    exactobar_fetch::network::set_tls(settings.tls_config());
+    exactobar_fetch::ratelimit::set_rate_limit(settings.rate_limit());
+    settings
+}
+
 /// Parses provider selection from argument.
-fn parse_provider_selection(arg: Option<&String>) -> Result<Vec<ProviderKind>> {
+///
+/// Without an argument, uses the `configured` providers, falling back to
+/// Codex and Claude when none are configured.
+fn parse_provider_selection(
+    arg: Option<&String>,
+    configured: &[ProviderKind],
+) -> Result<Vec<ProviderKind>> {
     match arg.map(|s| s.to_lowercase()).as_deref() {
+        None | Some("default") if !configured.is_empty() => Ok(configured.to_vec()),
         None | Some("both") | Some("default") => {
-            // Default: Codex and Claude (primary providers)
+            // Codex and Claude (primary providers)
             Ok(vec![ProviderKind::Codex, ProviderKind::Claude])
         }
         Some("all") => {
@@ -158,26 +199,29 @@ fn parse_source_mode(s: &str) -> Result<SourceMode> {
 
 /// Outputs results in the appropriate format.
 fn output_results(
+    providers: &[ProviderKind],
     results: &HashMap<ProviderKind, Result<UsageSnapshot, String>>,
     args: &UsageArgs,
     cli: &Cli,
 ) -> Result<()> {
+    // Keep the selection order (the app's menu order by default)
+    let ordered: Vec<_> = providers
+        .iter()
+        .filter_map(|p| results.get(p).map(|r| (*p, r)))
+        .collect();
+
     match cli.format {
         OutputFormat::Text => {
             let formatter = TextFormatter::new(!cli.no_color);
 
-            // Sort providers for consistent output
-            let mut sorted: Vec<_> = results.iter().collect();
-            sorted.sort_by_key(|(k, _)| format!("{:?}", k));
-
             let mut first = true;
-            for (provider, result) in sorted {
+            for (provider, result) in ordered {
                 if !first {
                     println!(); // Blank line between providers
                 }
                 first = false;
 
-                let desc = ProviderRegistry::get(*provider);
+                let desc = ProviderRegistry::get(provider);
                 match result {
                     Ok(snapshot) => {
                         let output = formatter.format_usage(snapshot, desc, !args.no_credits);
@@ -190,6 +234,10 @@ fn output_results(
                 }
             }
         }
+        OutputFormat::Table => {
+            let formatter = TextFormatter::new(!cli.no_color);
+            println!("{}", formatter.format_usage_table(&ordered));
+        }
         OutputFormat::Json => {
             let formatter = JsonFormatter::new(cli.pretty);
             let output = formatter.format_results(results)?;
@@ -210,27 +258,38 @@ mod tests {
 
     #[test]
     fn test_parse_provider_default() {
-        let providers = parse_provider_selection(None).unwrap();
+        let providers = parse_provider_selection(None, &[]).unwrap();
         assert!(providers.contains(&ProviderKind::Codex));
         assert!(providers.contains(&ProviderKind::Claude));
     }
 
+    #[test]
+    fn test_parse_provider_configured() {
+        let configured = [ProviderKind::Cursor, ProviderKind::Claude];
+        let providers = parse_provider_selection(None, &configured).unwrap();
+        assert_eq!(providers, configured);
+
+        // "both" still means Codex and Claude
+        let providers = parse_provider_selection(Some(&"both".to_string()), &configured).unwrap();
+        assert_eq!(providers, vec![ProviderKind::Codex, ProviderKind::Claude]);
+    }
+
     #[test]
     fn test_parse_provider_all() {
-        let providers = parse_provider_selection(Some(&"all".to_string())).unwrap();
+        let providers = parse_provider_selection(Some(&"all".to_string()), &[]).unwrap();
         assert!(providers.len() >= 2);
     }
 
     #[test]
     fn test_parse_provider_single() {
-        let providers = parse_provider_selection(Some(&"codex".to_string())).unwrap();
+        let providers = parse_provider_selection(Some(&"codex".to_string()), &[]).unwrap();
         assert_eq!(providers.len(), 1);
         assert_eq!(providers[0], ProviderKind::Codex);
     }
 
     #[test]
     fn test_parse_provider_comma_separated() {
-        let providers = parse_provider_selection(Some(&"codex,claude".to_string())).unwrap();
+        let providers = parse_provider_selection(Some(&"codex,claude".to_string()), &[]).unwrap();
         assert_eq!(providers.len(), 2);
     }
 
@@ -11,6 +11,7 @@ use tokio::time::{Duration, interval};
 use tracing::info;
 
 use crate::Cli;
+use crate::commands::usage::load_settings;
 use crate::output::TextFormatter;
 
 /// Arguments for watch command.
@@ -35,6 +36,8 @@ pub async fn run(args: &WatchArgs, cli: &Cli) -> Result<()> {
 
     info!(interval = refresh_interval, "Starting watch mode");
 
+    let settings = load_settings().await;
+
     // Determine providers
     let providers = match &args.provider {
         Some(name) if name == "all" => ProviderRegistry::kinds(),
@@ -45,7 +48,14 @@ pub async fn run(args: &WatchArgs, cli: &Cli) -> Result<()> {
                 anyhow::bail!("Unknown provider: {}", name);
             }
         }
-        None => vec![ProviderKind::Codex, ProviderKind::Claude],
+        None => {
+            let configured = settings.sorted_enabled_providers();
+            if configured.is_empty() {
+                vec![ProviderKind::Codex, ProviderKind::Claude]
+            } else {
+                configured
+            }
+        }
     };
 
     let ctx = FetchContext::builder()
@@ -35,7 +35,7 @@
 //! # Examples
 //!
 //! ```bash
-//! # Show usage for default providers (Codex + Claude)
+//! # Show usage for the providers enabled in the app
 //! exactobar
 //!
 //! # Show usage for a specific provider
@@ -45,7 +45,10 @@
 //! exactobar --provider all
 //!
 //! # JSON output
-//! exactobar --format json --pretty
+//! exactobar --json --pretty
+//!
+//! # One row per provider
+//! exactobar --format table
 //!
 //! # Force CLI source
 //! exactobar usage --source cli
@@ -78,7 +81,8 @@ use commands::{config, cost, providers, summary, usage, watch};
 #[command(name = "exactobar")]
 #[command(about = "LLM provider usage monitoring CLI")]
 #[command(long_about = r#"
-ExactoBar monitors LLM provider usage and costs.
+ExactoBar monitors LLM provider usage and costs. The CLI reads the same
+settings and keychain credentials as the menu bar app.
 
 Supported providers:
   • Claude Code (claude)
@@ -95,10 +99,11 @@ Supported providers:
   • MiniMax (minimax)
 
 Examples:
-  exactobar                      # Default providers (Codex + Claude)
+  exactobar                      # Providers enabled in the app
   exactobar --provider all       # All providers
   exactobar --provider codex     # Single provider
-  exactobar --format json        # JSON output
+  exactobar --json               # JSON output
+  exactobar --format table       # One row per provider
   exactobar cost                 # Token cost report
 "#)]
 #[command(version)]
@@ -108,10 +113,14 @@ pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Commands>,
 
-    /// Output format (text or json).
+    /// Output format (text, table, or json).
     #[arg(long, short = 'f', default_value = "text", global = true)]
     pub format: OutputFormat,
 
+    /// Shorthand for `--format json`.
+    #[arg(long, global = true, conflicts_with = "format")]
+    pub json: bool,
+
     /// Pretty-print JSON output.
     #[arg(long, global = true)]
     pub pretty: bool,
@@ -182,6 +191,8 @@ pub enum OutputFormat {
     /// Human-readable text with colors.
     #[default]
     Text,
+    /// Compact table, one row per provider.
+    Table,
     /// JSON output for scripting.
     Json,
 }
@@ -233,10 +244,16 @@ fn setup_logging(verbose: bool, quiet: bool) {
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+    if cli.json {
+        cli.format = OutputFormat::Json;
+    }
 
     setup_logging(cli.verbose, cli.quiet);
 
+    // Use the provider metadata corrections the app last downloaded
+    exactobar_providers::manifest::load_cached();
+
     let result = match &cli.command {
         Some(Commands::Usage(args)) => usage::run(args, &cli).await,
         Some(Commands::Cost(args)) => cost::run(args, &cli).await,
@@ -3,7 +3,7 @@
 use chrono::{DateTime, Duration, Local, Utc};
 use exactobar_core::{FetchSource, ProviderKind, UsageSnapshot, UsageWindow};
 use exactobar_providers::ProviderDescriptor;
-use exactobar_store::CostUsageSnapshot;
+use exactobar_store::{CostUsageSnapshot, TimeFormat};
 use std::collections::HashMap;
 
 // ============================================================================
@@ -27,6 +27,7 @@ const BAR_EMPTY: char = '░';
 pub struct TextFormatter {
     use_colors: bool,
     show_reset_countdown: bool,
+    time_format: TimeFormat,
     bar_width: usize,
 }
 
@@ -36,6 +37,7 @@ impl TextFormatter {
         Self {
             use_colors,
             show_reset_countdown: true,
+            time_format: TimeFormat::System,
             bar_width: 10,
         }
     }
@@ -105,6 +107,76 @@ impl TextFormatter {
         lines.join("\n")
     }
 
+    /// Formats usage as a table with one row per provider.
+    pub fn format_usage_table(
+        &self,
+        results: &[(ProviderKind, &Result<UsageSnapshot, String>)],
+    ) -> String {
+        let rows: Vec<_> = results
+            .iter()
+            .map(|(provider, result)| {
+                let name = exactobar_providers::ProviderRegistry::get(*provider)
+                    .map(|d| d.display_name())
+                    .unwrap_or("Unknown");
+                (name, *result)
+            })
+            .collect();
+        let name_width = rows
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .chain([8])
+            .max()
+            .unwrap_or(8);
+
+        let mut lines = vec![format!(
+            "{} {} {} {} {}",
+            self.bold(&format!("{:<name_width$}", "Provider")),
+            self.bold(&format!("{:<9}", "Session")),
+            self.bold(&format!("{:<9}", "Weekly")),
+            self.bold(&format!("{:<6}", "Source")),
+            self.bold("Resets")
+        )];
+
+        for (name, result) in rows {
+            let name = format!("{:<name_width$}", name);
+            match result {
+                Ok(snapshot) => {
+                    let resets = snapshot
+                        .primary
+                        .as_ref()
+                        .and_then(|w| w.resets_at)
+                        .map(|at| self.format_reset_time(at))
+                        .unwrap_or_else(|| "−".to_string());
+                    lines.push(format!(
+                        "{} {} {} {:<6} {}",
+                        name,
+                        self.table_cell(snapshot.primary.as_ref()),
+                        self.table_cell(snapshot.secondary.as_ref()),
+                        self.format_source(&snapshot.fetch_source),
+                        self.dim(&resets)
+                    ));
+                }
+                Err(e) => lines.push(format!("{} {} {}", name, self.red("Error"), self.dim(e))),
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    /// Formats a window's remaining percentage as a padded table cell.
+    fn table_cell(&self, window: Option<&UsageWindow>) -> String {
+        match window {
+            Some(window) => {
+                let remaining = 100.0 - window.used_percent;
+                self.color_for_percent(
+                    remaining,
+                    &format!("{:<9}", format!("{:.0}% left", remaining)),
+                )
+            }
+            None => self.dim(&format!("{:<9}", "−")),
+        }
+    }
+
     /// Formats a usage window with progress bar.
     fn format_window(&self, window: &UsageWindow, label: &str) -> String {
         let remaining = 100.0 - window.used_percent;
@@ -169,20 +241,11 @@ impl TextFormatter {
             let reset_date = local_reset.date_naive();
 
             if reset_date == today {
-                format!(
-                    "today at {}",
-                    local_reset.format("%l:%M %p").to_string().trim()
-                )
+                format!("today at {}", self.time_format.format_time(resets_at))
             } else if reset_date == today + chrono::Days::new(1) {
-                format!(
-                    "tomorrow at {}",
-                    local_reset.format("%l:%M %p").to_string().trim()
-                )
+                format!("tomorrow at {}", self.time_format.format_time(resets_at))
             } else {
-                format!(
-                    "{}",
-                    local_reset.format("%a at %l:%M %p").to_string().trim()
-                )
+                self.time_format.format_reset(resets_at, now)
             }
         }
     }
@@ -454,6 +517,23 @@ mod tests {
         assert!(high.contains(GREEN));
     }
 
+    #[test]
+    fn test_format_usage_table() {
+        let formatter = TextFormatter::new(false);
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(UsageWindow::new(28.0));
+        let ok = Ok(snapshot);
+        let err = Err("not logged in".to_string());
+
+        let table = formatter
+            .format_usage_table(&[(ProviderKind::Codex, &ok), (ProviderKind::Claude, &err)]);
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("Provider"));
+        assert!(lines[1].contains("72% left"));
+        assert!(lines[2].contains("Error not logged in"));
+    }
+
     #[test]
     fn test_format_window() {
         let formatter = TextFormatter::new(false);
@@ -25,3 +25,84 @@ pub enum CoreError {
     #[error("{0}")]
     Other(String),
 }
+
+/// Why fetching a provider's usage failed.
+///
+/// Each provider's own error type maps into this, so the menu, icon badges
+/// and install hints can handle failures the same way for every provider.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ProviderError {
+    /// The CLI or app the provider needs isn't installed or running.
+    #[error("Not installed: {0}")]
+    NotInstalled(String),
+
+    /// No credentials were found, or they were rejected or expired.
+    #[error("Not logged in: {0}")]
+    NotLoggedIn(String),
+
+    /// The provider couldn't be reached (connection failure, timeout,
+    /// server error).
+    #[error("Network error: {0}")]
+    Network(String),
+
+    /// The provider is throttling requests.
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
+    /// The provider's response couldn't be understood.
+    #[error("Parse error: {0}")]
+    Parse(String),
+
+    /// Anything else.
+    #[error("{0}")]
+    Unexpected(String),
+}
+
+impl ProviderError {
+    /// Returns the underlying message, without the category prefix.
+    pub fn message(&self) -> &str {
+        match self {
+            ProviderError::NotInstalled(message)
+            | ProviderError::NotLoggedIn(message)
+            | ProviderError::Network(message)
+            | ProviderError::RateLimited(message)
+            | ProviderError::Parse(message)
+            | ProviderError::Unexpected(message) => message,
+        }
+    }
+
+    /// Returns true if signing in again is what fixes this.
+    pub fn is_auth(&self) -> bool {
+        matches!(self, ProviderError::NotLoggedIn(_))
+    }
+
+    /// Returns a short label for the error, e.g. for a card's status line.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProviderError::NotInstalled(_) => "Not installed",
+            ProviderError::NotLoggedIn(_) => "Sign-in expired",
+            ProviderError::Network(_) => "Offline",
+            ProviderError::RateLimited(_) => "Rate limited",
+            ProviderError::Parse(_) => "Unexpected response",
+            ProviderError::Unexpected(_) => "Error",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_error() {
+        let error = ProviderError::NotLoggedIn("Token expired".to_string());
+        assert_eq!(error.to_string(), "Not logged in: Token expired");
+        assert_eq!(error.message(), "Token expired");
+        assert!(error.is_auth());
+        assert_eq!(error.label(), "Sign-in expired");
+
+        let error = ProviderError::Unexpected("boom".to_string());
+        assert_eq!(error.to_string(), "boom");
+        assert!(!error.is_auth());
+    }
+}
@@ -29,6 +29,7 @@
 //! ### Usage Types
 //! - [`UsageSnapshot`] - Main container for usage data with multiple windows
 //! - [`UsageWindow`] - Individual usage window (session, weekly, opus)
+//! - [`WindowKind`] - Identifies a window within a snapshot
 //! - [`UsageData`] - Legacy simple usage data format
 //! - [`Quota`] - Quota information
 //! - [`Credits`] - Credit-based usage tracking
@@ -37,6 +38,8 @@
 //! - [`CostUsageSnapshot`] - Token cost tracking from local logs
 //! - [`DailyUsageEntry`] - Daily usage entry
 //! - [`ModelBreakdown`] - Per-model cost breakdown
+//! - [`ProviderCost`] - Provider-reported spend against a budget
+//! - [`PricingTable`] - Per-model token prices for estimating spend
 //!
 //! ### Status & Fetch
 //! - [`ProviderStatus`] - Provider service health
@@ -48,7 +51,7 @@ pub mod models;
 pub mod traits;
 
 // Re-export error types
-pub use error::CoreError;
+pub use error::{CoreError, ProviderError};
 
 // Re-export all model types
 pub use models::{
@@ -63,18 +66,25 @@ pub use models::{
     IconStyle,
     LoginMethod,
     ModelBreakdown,
+    ModelPrice,
+    PricingTable,
     Provider,
     ProviderBranding,
     ProviderColor,
+    ProviderCost,
     ProviderIdentity,
     ProviderKind,
     ProviderMetadata,
     ProviderStatus,
     Quota,
+    RESET_SKEW_TOLERANCE,
+    StatusIncident,
     StatusIndicator,
     UsageData,
     UsageSnapshot,
     UsageWindow,
+    WindowKind,
+    format_currency,
 };
 
 // Re-export traits
@@ -4,6 +4,7 @@
 //! - [`CostUsageSnapshot`] - Container for cost data
 //! - [`DailyUsageEntry`] - Per-day usage breakdown
 //! - [`ModelBreakdown`] - Per-model cost breakdown
+//! - [`ProviderCost`] - Provider-reported spend against a budget
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -165,10 +166,13 @@ impl DailyUsageEntry {
 // ============================================================================
 
 /// Per-model cost breakdown.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModelBreakdown {
     /// Model name (e.g., "claude-3-opus", "gpt-4").
     pub model_name: String,
+    /// Number of requests made with this model.
+    #[serde(default)]
+    pub requests: Option<u64>,
     /// Cost in USD for this model.
     pub cost_usd: Option<f64>,
     /// Input tokens for this model.
@@ -182,6 +186,7 @@ impl ModelBreakdown {
     pub fn new(model_name: impl Into<String>) -> Self {
         Self {
             model_name: model_name.into(),
+            requests: None,
             cost_usd: None,
             input_tokens: None,
             output_tokens: None,
@@ -194,6 +199,65 @@ impl ModelBreakdown {
     }
 }
 
+// ============================================================================
+// Provider Cost
+// ============================================================================
+
+/// Spend reported by a provider alongside its usage windows.
+///
+/// Amounts are in major currency units (e.g., dollars, not cents).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProviderCost {
+    /// Amount spent in the current period.
+    pub used: f64,
+    /// Spending limit for the period, if the provider reports one.
+    pub limit: Option<f64>,
+    /// ISO 4217 currency code reported by the provider (e.g., "USD").
+    pub currency_code: Option<String>,
+    /// Budget period label (e.g., "monthly").
+    pub period: Option<String>,
+}
+
+impl ProviderCost {
+    /// Creates a new cost entry with the given spend.
+    pub fn new(used: f64) -> Self {
+        Self {
+            used,
+            limit: None,
+            currency_code: None,
+            period: None,
+        }
+    }
+
+    /// Returns the percentage of the limit that has been spent.
+    pub fn used_percent(&self) -> Option<f64> {
+        self.limit
+            .filter(|limit| *limit > 0.0)
+            .map(|limit| (self.used / limit) * 100.0)
+    }
+}
+
+/// Formats an amount for display in the given ISO 4217 currency.
+///
+/// Common currencies use their symbol (`$14.20`); others fall back to
+/// the code as a suffix (`14.20 CHF`). Whole amounts drop the decimals.
+pub fn format_currency(amount: f64, currency_code: &str) -> String {
+    let code = currency_code.to_ascii_uppercase();
+    let number = if amount.fract().abs() < f64::EPSILON || code == "JPY" {
+        format!("{amount:.0}")
+    } else {
+        format!("{amount:.2}")
+    };
+
+    match code.as_str() {
+        "USD" => format!("${number}"),
+        "EUR" => format!("€{number}"),
+        "GBP" => format!("£{number}"),
+        "JPY" => format!("¥{number}"),
+        _ => format!("{number} {code}"),
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -278,4 +342,25 @@ mod tests {
 
         assert_eq!(breakdown.total_tokens(), 800);
     }
+
+    #[test]
+    fn test_provider_cost_used_percent() {
+        let mut cost = ProviderCost::new(14.2);
+        assert!(cost.used_percent().is_none());
+
+        cost.limit = Some(50.0);
+        assert!((cost.used_percent().unwrap() - 28.4).abs() < 1e-9);
+
+        cost.limit = Some(0.0);
+        assert!(cost.used_percent().is_none());
+    }
+
+    #[test]
+    fn test_format_currency() {
+        assert_eq!(format_currency(14.2, "USD"), "$14.20");
+        assert_eq!(format_currency(50.0, "usd"), "$50");
+        assert_eq!(format_currency(9.5, "EUR"), "€9.50");
+        assert_eq!(format_currency(1200.4, "JPY"), "¥1200");
+        assert_eq!(format_currency(3.25, "CHF"), "3.25 CHF");
+    }
 }
@@ -8,21 +8,26 @@
 //!
 //! - [`provider`] - Provider types (`ProviderKind`, Identity, Metadata, Branding)
 //! - [`usage`] - Usage types (`UsageSnapshot`, `UsageWindow`, Credits, Quota)
-//! - [`cost`] - Cost tracking (`CostUsageSnapshot`, `DailyUsageEntry`)
+//! - [`cost`] - Cost tracking (`CostUsageSnapshot`, `DailyUsageEntry`, `ProviderCost`)
+//! - [`pricing`] - Per-model token prices (`PricingTable`, `ModelPrice`)
 //! - [`status`] - Status and fetch types (`ProviderStatus`, `FetchSource`)
 
 mod cost;
+mod pricing;
 mod provider;
 mod status;
 mod usage;
 
 // Re-export everything at the models level
-pub use cost::{CostUsageSnapshot, DailyUsageEntry, ModelBreakdown};
+pub use cost::{CostUsageSnapshot, DailyUsageEntry, ModelBreakdown, ProviderCost, format_currency};
+pub use pricing::{ModelPrice, PricingTable};
 pub use provider::{
     IconStyle, LoginMethod, Provider, ProviderBranding, ProviderColor, ProviderIdentity,
     ProviderKind, ProviderMetadata,
 };
-pub use status::{FetchSource, ProviderStatus, StatusIndicator};
-pub use usage::{Credits, Quota, UsageData, UsageSnapshot, UsageWindow};
+pub use status::{FetchSource, ProviderStatus, StatusIncident, StatusIndicator};
+pub use usage::{
+    Credits, Quota, RESET_SKEW_TOLERANCE, UsageData, UsageSnapshot, UsageWindow, WindowKind,
+};
 #[cfg(test)]
 mod serde_tests;
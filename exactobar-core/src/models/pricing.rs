@@ -0,0 +1,199 @@
+//! Per-model token prices.
+//!
+//! Some providers report how many tokens each model used but not what it
+//! cost. A [`PricingTable`] turns those counts into an estimated spend,
+//! starting from shipped list prices that users can override per model.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use super::cost::ModelBreakdown;
+
+/// Price of a model's tokens, in USD per million.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModelPrice {
+    /// Price per million input tokens.
+    pub input_per_million: f64,
+    /// Price per million output tokens.
+    pub output_per_million: f64,
+}
+
+impl ModelPrice {
+    /// Creates a price from per-million input and output rates.
+    pub const fn new(input_per_million: f64, output_per_million: f64) -> Self {
+        Self {
+            input_per_million,
+            output_per_million,
+        }
+    }
+
+    /// Returns the cost of the given tokens, in USD.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn cost(&self, input_tokens: u64, output_tokens: u64) -> f64 {
+        (input_tokens as f64 * self.input_per_million
+            + output_tokens as f64 * self.output_per_million)
+            / 1_000_000.0
+    }
+}
+
+/// List prices shipped with the app, by model name prefix.
+const DEFAULT_PRICES: &[(&str, ModelPrice)] = &[
+    // Anthropic
+    ("claude-opus-4-5", ModelPrice::new(5.0, 25.0)),
+    ("claude-opus-4", ModelPrice::new(15.0, 75.0)),
+    ("claude-sonnet-4", ModelPrice::new(3.0, 15.0)),
+    ("claude-3-7-sonnet", ModelPrice::new(3.0, 15.0)),
+    ("claude-3-5-sonnet", ModelPrice::new(3.0, 15.0)),
+    ("claude-haiku-4-5", ModelPrice::new(1.0, 5.0)),
+    ("claude-3-5-haiku", ModelPrice::new(0.8, 4.0)),
+    ("claude-3-opus", ModelPrice::new(15.0, 75.0)),
+    ("claude-3-haiku", ModelPrice::new(0.25, 1.25)),
+    // OpenAI
+    ("gpt-5-mini", ModelPrice::new(0.25, 2.0)),
+    ("gpt-5-nano", ModelPrice::new(0.05, 0.4)),
+    ("gpt-5", ModelPrice::new(1.25, 10.0)),
+    ("gpt-4.1-mini", ModelPrice::new(0.4, 1.6)),
+    ("gpt-4.1", ModelPrice::new(2.0, 8.0)),
+    ("gpt-4o-mini", ModelPrice::new(0.15, 0.6)),
+    ("gpt-4o", ModelPrice::new(2.5, 10.0)),
+    ("o4-mini", ModelPrice::new(1.1, 4.4)),
+    ("o3", ModelPrice::new(2.0, 8.0)),
+    // Google
+    ("gemini-2.5-pro", ModelPrice::new(1.25, 10.0)),
+    ("gemini-2.5-flash-lite", ModelPrice::new(0.1, 0.4)),
+    ("gemini-2.5-flash", ModelPrice::new(0.3, 2.5)),
+];
+
+/// Token prices per model, matched by name prefix.
+///
+/// The longest matching prefix wins, so `claude-opus-4-5` can be priced
+/// apart from the older `claude-opus-4` models. Matching ignores case and
+/// any vendor path before the last `/` (e.g. `anthropic/claude-sonnet-4`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PricingTable {
+    prices: BTreeMap<String, ModelPrice>,
+}
+
+impl Default for PricingTable {
+    fn default() -> Self {
+        Self {
+            prices: DEFAULT_PRICES
+                .iter()
+                .map(|&(model, price)| (model.to_string(), price))
+                .collect(),
+        }
+    }
+}
+
+impl PricingTable {
+    /// Returns the shipped prices with `overrides` added on top.
+    pub fn with_overrides(overrides: &HashMap<String, ModelPrice>) -> Self {
+        let mut table = Self::default();
+        for (model, price) in overrides {
+            table.prices.insert(model.to_ascii_lowercase(), *price);
+        }
+        table
+    }
+
+    /// Returns the price for a model, if any prefix matches.
+    pub fn price_for(&self, model: &str) -> Option<ModelPrice> {
+        let model = model.to_ascii_lowercase();
+        let model = model.rsplit('/').next().unwrap_or(&model);
+        self.prices
+            .iter()
+            .filter(|(prefix, _)| model.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, price)| *price)
+    }
+
+    /// Estimates the spend across a per-model breakdown, in USD. Reported
+    /// costs are used as they are; tokens are priced for the rest.
+    ///
+    /// Returns `None` if no model has a cost or a known price.
+    pub fn estimate(&self, models: &[ModelBreakdown]) -> Option<f64> {
+        models
+            .iter()
+            .filter_map(|model| {
+                model.cost_usd.or_else(|| {
+                    self.price_for(&model.model_name).map(|price| {
+                        price.cost(
+                            model.input_tokens.unwrap_or(0),
+                            model.output_tokens.unwrap_or(0),
+                        )
+                    })
+                })
+            })
+            .reduce(|a, b| a + b)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breakdown(model: &str, input: u64, output: u64, cost: Option<f64>) -> ModelBreakdown {
+        let mut breakdown = ModelBreakdown::new(model);
+        breakdown.input_tokens = Some(input);
+        breakdown.output_tokens = Some(output);
+        breakdown.cost_usd = cost;
+        breakdown
+    }
+
+    #[test]
+    fn test_price_for_longest_prefix() {
+        let table = PricingTable::default();
+        assert_eq!(
+            table.price_for("claude-opus-4-5-20251101"),
+            Some(ModelPrice::new(5.0, 25.0))
+        );
+        assert_eq!(
+            table.price_for("claude-opus-4-1-20250805"),
+            Some(ModelPrice::new(15.0, 75.0))
+        );
+        assert_eq!(
+            table.price_for("openai/GPT-5-mini"),
+            Some(ModelPrice::new(0.25, 2.0))
+        );
+        assert_eq!(table.price_for("llama-3"), None);
+    }
+
+    #[test]
+    fn test_overrides() {
+        let overrides = HashMap::from([
+            ("GPT-5".to_string(), ModelPrice::new(1.0, 8.0)),
+            ("llama-3".to_string(), ModelPrice::new(0.1, 0.1)),
+        ]);
+        let table = PricingTable::with_overrides(&overrides);
+        assert_eq!(
+            table.price_for("gpt-5-codex"),
+            Some(ModelPrice::new(1.0, 8.0))
+        );
+        assert_eq!(
+            table.price_for("llama-3-70b"),
+            Some(ModelPrice::new(0.1, 0.1))
+        );
+        // More specific shipped prices still win
+        assert_eq!(
+            table.price_for("gpt-5-mini"),
+            Some(ModelPrice::new(0.25, 2.0))
+        );
+    }
+
+    #[test]
+    fn test_estimate() {
+        let table = PricingTable::default();
+        let models = [
+            breakdown("claude-sonnet-4-5", 1_000_000, 100_000, None),
+            breakdown("claude-opus-4-1", 5_000, 5_000, Some(0.42)),
+            breakdown("mystery-model", 1_000_000, 1_000_000, None),
+        ];
+        // $3 + $1.50 for Sonnet, plus Opus's reported cost
+        assert!((table.estimate(&models).unwrap() - 4.92).abs() < 1e-9);
+        assert_eq!(table.estimate(&models[2..]), None);
+        assert_eq!(table.estimate(&[]), None);
+    }
+}
@@ -43,6 +43,8 @@ pub enum ProviderKind {
     MiniMax,
     /// Synthetic.new
     Synthetic,
+    /// Generated usage for development and demos
+    Demo,
 }
 
 impl ProviderKind {
@@ -62,6 +64,7 @@ impl ProviderKind {
             Self::Antigravity => "Antigravity",
             Self::MiniMax => "MiniMax",
             Self::Synthetic => "Synthetic.new",
+            Self::Demo => "Demo",
         }
     }
 
@@ -81,6 +84,7 @@ impl ProviderKind {
             Self::Antigravity,
             Self::MiniMax,
             Self::Synthetic,
+            Self::Demo,
         ]
     }
 
@@ -100,6 +104,7 @@ impl ProviderKind {
             Self::Antigravity => "antigravity",
             Self::MiniMax => "minimax",
             Self::Synthetic => "synthetic",
+            Self::Demo => "demo",
         }
     }
 
@@ -166,7 +171,7 @@ impl Provider {
 ///
 /// **Important**: This is siloed per provider - never mix identity from
 /// different providers. Each provider has its own authentication context.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProviderIdentity {
     /// The provider this identity belongs to.
     pub provider_id: ProviderKind,
@@ -327,6 +332,7 @@ impl ProviderBranding {
             }
             ProviderKind::MiniMax => (IconStyle::MiniMax, ProviderColor::new(0.9, 0.1, 0.3)),
             ProviderKind::Synthetic => (IconStyle::Synthetic, ProviderColor::new(0.0, 0.8, 0.7)),
+            ProviderKind::Demo => (IconStyle::Demo, ProviderColor::new(0.55, 0.55, 0.6)),
         };
 
         Self {
@@ -408,6 +414,8 @@ pub enum IconStyle {
     MiniMax,
     /// Synthetic.new icon.
     Synthetic,
+    /// Demo provider icon.
+    Demo,
     /// Combined/aggregate view icon.
     Combined,
 }
@@ -320,6 +320,22 @@ fn test_model_breakdown_roundtrip() {
     assert_eq!(deserialized.total_tokens(), 15000);
 }
 
+#[test]
+fn test_usage_snapshot_model_breakdown_roundtrip() {
+    let mut breakdown = ModelBreakdown::new("gpt-4o");
+    breakdown.requests = Some(42);
+    breakdown.cost_usd = Some(1.25);
+
+    let mut snapshot = UsageSnapshot::new();
+    snapshot.model_breakdown.push(breakdown);
+
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let deserialized: UsageSnapshot = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.model_breakdown.len(), 1);
+    assert_eq!(deserialized.model_breakdown[0].requests, Some(42));
+}
+
 // ============================================================================
 // Provider Serde Tests
 // ============================================================================
@@ -485,6 +501,8 @@ fn test_deserialize_minimal_usage_snapshot() {
     let snapshot: UsageSnapshot = serde_json::from_str(json).unwrap();
     assert!(snapshot.primary.is_none());
     assert!(snapshot.identity.is_none());
+    assert!(snapshot.cost.is_none());
+    assert!(snapshot.model_breakdown.is_empty());
 }
 
 #[test]
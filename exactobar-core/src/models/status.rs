@@ -2,6 +2,7 @@
 //!
 //! This module contains types for provider status and data fetching:
 //! - [`ProviderStatus`] - Service health information
+//! - [`StatusIncident`] - An ongoing incident on a status page
 //! - [`StatusIndicator`] - Status levels
 //! - [`FetchSource`] - How data was obtained
 
@@ -23,6 +24,9 @@ pub struct ProviderStatus {
     pub updated_at: DateTime<Utc>,
     /// URL to the full status page.
     pub url: Option<String>,
+    /// Unresolved incidents, most recent first.
+    #[serde(default)]
+    pub incidents: Vec<StatusIncident>,
 }
 
 impl ProviderStatus {
@@ -33,6 +37,7 @@ impl ProviderStatus {
             description: "All systems operational".to_string(),
             updated_at: Utc::now(),
             url: None,
+            incidents: Vec::new(),
         }
     }
 
@@ -43,6 +48,7 @@ impl ProviderStatus {
             description: description.into(),
             updated_at: Utc::now(),
             url: None,
+            incidents: Vec::new(),
         }
     }
 
@@ -71,6 +77,28 @@ impl Default for ProviderStatus {
     }
 }
 
+// ============================================================================
+// Status Incident
+// ============================================================================
+
+/// An unresolved incident from a provider's status page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusIncident {
+    /// Incident title, e.g. "Elevated errors on Claude Opus".
+    pub name: String,
+    /// Stage of the incident, e.g. "investigating" or "monitoring".
+    pub status: String,
+    /// When the incident started.
+    pub started_at: Option<DateTime<Utc>>,
+    /// Names of the affected components.
+    #[serde(default)]
+    pub components: Vec<String>,
+    /// Text of the latest update.
+    pub latest_update: Option<String>,
+    /// When the latest update was posted.
+    pub latest_update_at: Option<DateTime<Utc>>,
+}
+
 // ============================================================================
 // Status Indicator
 // ============================================================================
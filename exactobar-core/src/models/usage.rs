@@ -11,6 +11,7 @@ use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
 use super::ProviderIdentity;
+use super::cost::{ModelBreakdown, ProviderCost};
 use super::provider::ProviderKind;
 use super::status::FetchSource;
 use crate::error::CoreError;
@@ -26,7 +27,7 @@ use crate::error::CoreError;
 /// - **Secondary** = weekly/monthly window
 /// - **Tertiary** = opus/premium tier (Claude-specific)
 /// - **Search** = search sub-system quota (e.g., hourly search limits)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UsageSnapshot {
     /// Primary usage window (session-based).
     pub primary: Option<UsageWindow>,
@@ -43,6 +44,12 @@ pub struct UsageSnapshot {
     /// How this data was fetched.
     #[serde(default)]
     pub fetch_source: FetchSource,
+    /// Spend reported by the provider, if any.
+    #[serde(default)]
+    pub cost: Option<ProviderCost>,
+    /// Per-model usage breakdown, if the provider reports one.
+    #[serde(default)]
+    pub model_breakdown: Vec<ModelBreakdown>,
 }
 
 impl UsageSnapshot {
@@ -56,6 +63,8 @@ impl UsageSnapshot {
             updated_at: Utc::now(),
             identity: None,
             fetch_source: FetchSource::default(),
+            cost: None,
+            model_breakdown: Vec::new(),
         }
     }
 
@@ -75,10 +84,7 @@ impl UsageSnapshot {
                 .tertiary
                 .as_ref()
                 .is_some_and(|w| w.used_percent > 80.0)
-            || self
-                .search
-                .as_ref()
-                .is_some_and(|w| w.used_percent > 80.0)
+            || self.search.as_ref().is_some_and(|w| w.used_percent > 80.0)
     }
 
     /// Returns the highest usage percentage across all windows.
@@ -106,6 +112,55 @@ impl UsageSnapshot {
             || self.tertiary.is_some()
             || self.search.is_some()
     }
+
+    /// Returns the window of the given kind, if present.
+    pub fn window(&self, kind: WindowKind) -> Option<&UsageWindow> {
+        match kind {
+            WindowKind::Primary => self.primary.as_ref(),
+            WindowKind::Secondary => self.secondary.as_ref(),
+            WindowKind::Tertiary => self.tertiary.as_ref(),
+            WindowKind::Search => self.search.as_ref(),
+        }
+    }
+
+    /// Returns true if both snapshots report the same usage, ignoring when
+    /// they were taken.
+    pub fn same_usage(&self, other: &Self) -> bool {
+        self.primary == other.primary
+            && self.secondary == other.secondary
+            && self.tertiary == other.tertiary
+            && self.search == other.search
+            && self.identity == other.identity
+            && self.fetch_source == other.fetch_source
+            && self.cost == other.cost
+            && self.model_breakdown == other.model_breakdown
+    }
+}
+
+/// Identifies one of the usage windows carried by a [`UsageSnapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowKind {
+    /// Session window.
+    Primary,
+    /// Weekly/monthly window.
+    Secondary,
+    /// Opus/premium tier window.
+    Tertiary,
+    /// Search sub-system window.
+    Search,
+}
+
+impl WindowKind {
+    /// All window kinds, in display order.
+    pub fn all() -> &'static [WindowKind] {
+        &[
+            WindowKind::Primary,
+            WindowKind::Secondary,
+            WindowKind::Tertiary,
+            WindowKind::Search,
+        ]
+    }
 }
 
 impl Default for UsageSnapshot {
@@ -170,8 +225,12 @@ impl UsageSnapshot {
     }
 }
 
+/// How far a reset may be in the past before it counts as passed, since
+/// provider clocks can run ahead of ours.
+pub const RESET_SKEW_TOLERANCE: Duration = Duration::minutes(2);
+
 /// Represents a single usage window (session, weekly, or tier).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UsageWindow {
     /// Percentage of quota used (0-100).
     pub used_percent: f64,
@@ -218,6 +277,28 @@ impl UsageWindow {
     pub fn time_until_reset(&self) -> Option<Duration> {
         self.resets_at.map(|reset| reset - Utc::now())
     }
+
+    /// Returns true once the reset is further in the past than clock skew
+    /// between us and the provider can explain.
+    pub fn reset_passed(&self, now: DateTime<Utc>) -> bool {
+        self.resets_at
+            .is_some_and(|reset| reset < now - RESET_SKEW_TOLERANCE)
+    }
+
+    /// Returns the reset to display at `now`.
+    ///
+    /// A reset within [`RESET_SKEW_TOLERANCE`] of `now` is returned as is. A
+    /// reset that has passed is rolled forward by whole windows when the
+    /// window length is known, and is `None` otherwise.
+    pub fn next_reset(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let reset = self.resets_at?;
+        if !self.reset_passed(now) {
+            return Some(reset);
+        }
+        let window = self.window_duration().filter(|w| *w > Duration::zero())?;
+        let windows = (now - reset).num_seconds() / window.num_seconds() + 1;
+        Some(reset + window * i32::try_from(windows).ok()?)
+    }
 }
 
 impl Default for UsageWindow {
@@ -384,6 +465,8 @@ impl UsageData {
             updated_at: self.fetched_at,
             identity: None,
             fetch_source: FetchSource::Auto,
+            cost: None,
+            model_breakdown: Vec::new(),
         }
     }
 }
@@ -453,6 +536,29 @@ mod tests {
         assert!(window.is_over_limit());
     }
 
+    #[test]
+    fn test_usage_window_next_reset() {
+        let now = Utc::now();
+        let mut window = UsageWindow::new(40.0);
+        window.window_minutes = Some(300);
+
+        // Within the skew tolerance the reset stands
+        window.resets_at = Some(now - Duration::seconds(30));
+        assert!(!window.reset_passed(now));
+        assert_eq!(window.next_reset(now), window.resets_at);
+
+        // Passed resets roll forward to the next window
+        window.resets_at = Some(now - Duration::minutes(10));
+        assert!(window.reset_passed(now));
+        assert_eq!(window.next_reset(now), Some(now + Duration::minutes(290)));
+        window.resets_at = Some(now - Duration::minutes(610));
+        assert_eq!(window.next_reset(now), Some(now + Duration::minutes(290)));
+
+        // Without a window length a passed reset is unknown
+        window.window_minutes = None;
+        assert_eq!(window.next_reset(now), None);
+    }
+
     #[test]
     fn test_usage_snapshot_max_usage() {
         let mut snapshot = UsageSnapshot::new();
@@ -464,6 +570,36 @@ mod tests {
         assert!(snapshot.is_approaching_limit());
     }
 
+    #[test]
+    fn test_usage_snapshot_same_usage() {
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(UsageWindow::new(50.0));
+
+        let mut later = snapshot.clone();
+        later.updated_at += Duration::minutes(5);
+        assert!(snapshot.same_usage(&later));
+
+        later.primary = Some(UsageWindow::new(51.0));
+        assert!(!snapshot.same_usage(&later));
+    }
+
+    #[test]
+    fn test_usage_snapshot_window_by_kind() {
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(UsageWindow::new(10.0));
+        snapshot.search = Some(UsageWindow::new(40.0));
+
+        assert_eq!(
+            snapshot.window(WindowKind::Primary).unwrap().used_percent,
+            10.0
+        );
+        assert!(snapshot.window(WindowKind::Secondary).is_none());
+        assert_eq!(
+            snapshot.window(WindowKind::Search).unwrap().used_percent,
+            40.0
+        );
+    }
+
     #[test]
     fn test_credits_percentage() {
         let mut credits = Credits::new(25.0);
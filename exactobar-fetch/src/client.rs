@@ -24,7 +24,7 @@ impl HttpClient {
 
     /// Creates a new HTTP client with a custom timeout.
     pub fn with_timeout(timeout: Duration) -> Result<Self, FetchError> {
-        let client = Client::builder()
+        let client = crate::network::client_builder()
             .timeout(timeout)
             .user_agent(concat!("exactobar/", env!("CARGO_PKG_VERSION")))
             .build()?;
@@ -54,12 +54,12 @@ impl HttpClient {
             attempts += 1;
             debug!(url = %url, attempt = attempts, "Making GET request");
 
-            let result = self
-                .inner
-                .get(url)
-                .header(header::AUTHORIZATION, auth_header)
-                .send()
-                .await;
+            let result = crate::network::send(
+                self.inner
+                    .get(url)
+                    .header(header::AUTHORIZATION, auth_header),
+            )
+            .await;
 
             match result {
                 Ok(response) => {
@@ -117,7 +117,7 @@ impl HttpClient {
 
     /// Performs a simple GET request without authentication.
     pub async fn get(&self, url: &str) -> Result<Response, FetchError> {
-        Ok(self.inner.get(url).send().await?)
+        Ok(crate::network::send(self.inner.get(url)).await?)
     }
 }
 
@@ -3,11 +3,15 @@
 //! The fetch context is passed to all strategies and provides unified
 //! access to system resources like keychain, HTTP client, process runner, etc.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
+use exactobar_core::ProviderKind;
+
 use tracing::warn;
 
+use crate::fixtures::FixtureMode;
 use crate::host::{
     browser::BrowserCookieImporter, http::HttpClient, keychain::KeychainApi,
     keychain::SystemKeychain, process::ProcessRunner, status::StatusPoller,
@@ -72,6 +76,11 @@ pub struct FetchSettings {
     pub max_retries: u32,
     /// Delay between retries.
     pub retry_delay: Duration,
+    /// Whether responses are fetched, recorded, or replayed.
+    pub fixtures: FixtureMode,
+    /// API base URLs used instead of the providers' own hosts, e.g. a
+    /// corporate gateway.
+    pub base_urls: HashMap<ProviderKind, String>,
 }
 
 impl Default for FetchSettings {
@@ -82,6 +91,8 @@ impl Default for FetchSettings {
             web_debug_dump_html: false,
             max_retries: 2,
             retry_delay: Duration::from_secs(1),
+            fixtures: FixtureMode::from_env(),
+            base_urls: HashMap::new(),
         }
     }
 }
@@ -179,6 +190,16 @@ impl FetchContext {
         self.settings.timeout
     }
 
+    /// Returns the API base URL override for a provider, without a trailing
+    /// slash.
+    pub fn base_url(&self, provider: ProviderKind) -> Option<&str> {
+        self.settings
+            .base_urls
+            .get(&provider)
+            .map(|url| url.trim().trim_end_matches('/'))
+            .filter(|url| !url.is_empty())
+    }
+
     /// Returns true if the given source mode is allowed.
     pub fn allows_source(&self, mode: SourceMode) -> bool {
         self.settings.source_mode == SourceMode::Auto || self.settings.source_mode == mode
@@ -274,6 +295,18 @@ impl FetchContextBuilder {
         self
     }
 
+    /// Sets whether responses are fetched, recorded, or replayed.
+    pub fn fixtures(mut self, mode: FixtureMode) -> Self {
+        self.settings.fixtures = mode;
+        self
+    }
+
+    /// Sends a provider's API requests to `url` instead of its own host.
+    pub fn base_url(mut self, provider: ProviderKind, url: impl Into<String>) -> Self {
+        self.settings.base_urls.insert(provider, url.into());
+        self
+    }
+
     /// Builds the fetch context.
     pub fn build(self) -> FetchContext {
         FetchContext {
@@ -332,6 +365,24 @@ mod tests {
         assert_eq!(ctx.settings.timeout, Duration::from_secs(60));
     }
 
+    #[test]
+    fn test_base_url() {
+        let ctx = FetchContext::builder()
+            .base_url(
+                ProviderKind::Claude,
+                "https://gateway.example.com/anthropic/",
+            )
+            .base_url(ProviderKind::Synthetic, "  ")
+            .build();
+
+        assert_eq!(
+            ctx.base_url(ProviderKind::Claude),
+            Some("https://gateway.example.com/anthropic")
+        );
+        assert_eq!(ctx.base_url(ProviderKind::Synthetic), None);
+        assert_eq!(ctx.base_url(ProviderKind::Codex), None);
+    }
+
     #[test]
     fn test_default_context() {
         let ctx = FetchContext::new();
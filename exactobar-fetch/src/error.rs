@@ -1,6 +1,8 @@
 //! Fetch error types.
 
 use std::time::Duration;
+
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 // ============================================================================
@@ -72,6 +74,66 @@ pub enum FetchError {
     /// Domain not allowed.
     #[error("Domain not allowed: {0}")]
     DomainNotAllowed(String),
+
+    /// Provider-specific error.
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+}
+
+impl FetchError {
+    /// Returns true if the provider rejected the credentials.
+    pub fn is_auth_error(&self) -> bool {
+        self.classify().is_auth()
+    }
+
+    /// Maps the error into the shared [`ProviderError`] categories.
+    pub fn classify(&self) -> ProviderError {
+        let message = self.full_message();
+        match self {
+            FetchError::Provider(e) => e.clone(),
+            FetchError::AuthenticationFailed(_) => ProviderError::NotLoggedIn(message),
+            FetchError::RateLimited { .. } => ProviderError::RateLimited(message),
+            FetchError::Timeout(_) | FetchError::Status(_) => ProviderError::Network(message),
+            FetchError::InvalidResponse(_) | FetchError::Json(_) => ProviderError::Parse(message),
+            FetchError::Http(e) => match e.status() {
+                Some(reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN) => {
+                    ProviderError::NotLoggedIn(message)
+                }
+                Some(reqwest::StatusCode::TOO_MANY_REQUESTS) => ProviderError::RateLimited(message),
+                Some(status) if status.is_client_error() => ProviderError::Unexpected(message),
+                _ if e.is_decode() => ProviderError::Parse(message),
+                _ => ProviderError::Network(message),
+            },
+            FetchError::Process(ProcessError::NotFound(_))
+            | FetchError::Pty(PtyError::NotFound(_))
+            | FetchError::Browser(
+                BrowserError::BrowserNotFound(_) | BrowserError::NoBrowsersAvailable,
+            ) => ProviderError::NotInstalled(message),
+            FetchError::Keychain(KeychainError::NotFound { .. })
+            | FetchError::Browser(BrowserError::NoCookiesFound(_)) => {
+                ProviderError::NotLoggedIn(message)
+            }
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+
+    /// Returns the message followed by any underlying causes not already in it.
+    ///
+    /// Surfaces details like the connection error behind an HTTP failure
+    /// that the top-level message leaves out.
+    pub fn full_message(&self) -> String {
+        let mut message = self.to_string();
+        let mut source = std::error::Error::source(self);
+        while let Some(cause) = source {
+            let text = cause.to_string();
+            if !message.contains(&text) {
+                message.push_str(": ");
+                message.push_str(&text);
+            }
+            source = cause.source();
+        }
+        message
+    }
 }
 
 // ============================================================================
@@ -299,3 +361,44 @@ pub enum StatusError {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 }
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_message_skips_repeated_causes() {
+        let error = FetchError::Process(ProcessError::Io(std::io::Error::other("boom")));
+        assert_eq!(error.full_message(), "Process error: IO error: boom");
+    }
+
+    #[test]
+    fn test_classify() {
+        let error = FetchError::AuthenticationFailed("Token expired".to_string());
+        assert!(error.is_auth_error());
+        assert_eq!(
+            error.classify(),
+            ProviderError::NotLoggedIn("Authentication failed: Token expired".to_string())
+        );
+
+        let error = FetchError::Process(ProcessError::NotFound("codex".to_string()));
+        assert!(matches!(error.classify(), ProviderError::NotInstalled(_)));
+        assert!(matches!(
+            FetchError::InvalidResponse("bad".to_string()).classify(),
+            ProviderError::Parse(_)
+        ));
+        assert!(matches!(
+            FetchError::RateLimited { retry_after: None }.classify(),
+            ProviderError::RateLimited(_)
+        ));
+
+        // Provider errors keep their category
+        let error = FetchError::from(ProviderError::NotLoggedIn("No API key".to_string()));
+        assert!(error.is_auth_error());
+        assert_eq!(error.to_string(), "Not logged in: No API key");
+    }
+}
@@ -0,0 +1,168 @@
+//! Recorded provider responses.
+//!
+//! In record mode, the raw response of every successful fetch is saved to a
+//! fixture file named after the strategy that made it. In replay mode, the
+//! pipeline hands those files to their strategies instead of reaching the
+//! provider, and the strategies parse them as they would a live response.
+//! This allows working offline, and turns a changed response shape into a
+//! fixture a regression test can load.
+//!
+//! Only strategies that implement [`FetchStrategy::parse_raw`] can be
+//! replayed.
+//!
+//! ## Environment
+//!
+//! - `EXACTOBAR_RECORD_FIXTURES=<dir>` records into `dir`
+//! - `EXACTOBAR_REPLAY_FIXTURES=<dir>` replays from `dir`
+
+use std::path::{Path, PathBuf};
+
+use tracing::{debug, warn};
+
+use crate::error::FetchError;
+use crate::strategy::{FetchResult, FetchStrategy};
+
+/// Environment variable naming the directory to record fixtures into.
+pub const RECORD_ENV: &str = "EXACTOBAR_RECORD_FIXTURES";
+
+/// Environment variable naming the directory to replay fixtures from.
+pub const REPLAY_ENV: &str = "EXACTOBAR_REPLAY_FIXTURES";
+
+/// Whether fetches go to providers, are recorded, or are replayed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum FixtureMode {
+    /// Fetch from providers as usual.
+    #[default]
+    Live,
+    /// Fetch from providers and save raw responses in the directory.
+    Record(PathBuf),
+    /// Serve raw responses saved in the directory; nothing is fetched.
+    Replay(PathBuf),
+}
+
+impl FixtureMode {
+    /// Reads the mode from [`RECORD_ENV`] and [`REPLAY_ENV`]. Replaying wins
+    /// if both are set.
+    pub fn from_env() -> Self {
+        let dir = |name| std::env::var_os(name).filter(|v| !v.is_empty());
+        if let Some(dir) = dir(REPLAY_ENV) {
+            Self::Replay(dir.into())
+        } else if let Some(dir) = dir(RECORD_ENV) {
+            Self::Record(dir.into())
+        } else {
+            Self::Live
+        }
+    }
+
+    /// Returns true if fetches are served from fixtures.
+    pub fn is_replay(&self) -> bool {
+        matches!(self, Self::Replay(_))
+    }
+}
+
+/// Returns the fixture file for a strategy, e.g. `claude.oauth.raw`.
+pub fn fixture_path(dir: &Path, strategy_id: &str) -> PathBuf {
+    dir.join(format!("{strategy_id}.raw"))
+}
+
+/// Saves a successful fetch's raw response, if it kept one.
+pub fn record(dir: &Path, result: &FetchResult) {
+    let Some(raw) = result.raw_response.as_deref() else {
+        debug!(strategy = %result.strategy_id, "No raw response to record");
+        return;
+    };
+    let path = fixture_path(dir, &result.strategy_id);
+    let saved = std::fs::create_dir_all(dir).and_then(|()| std::fs::write(&path, raw));
+    match saved {
+        Ok(()) => debug!(path = %path.display(), "Recorded fixture"),
+        Err(e) => warn!(path = %path.display(), error = %e, "Failed to record fixture"),
+    }
+}
+
+/// Runs a strategy against its recorded fixture.
+pub fn replay(strategy: &dyn FetchStrategy, dir: &Path) -> Result<FetchResult, FetchError> {
+    let path = fixture_path(dir, strategy.id());
+    let raw = std::fs::read_to_string(&path).map_err(|_| {
+        FetchError::StrategyNotAvailable(format!("No fixture at {}", path.display()))
+    })?;
+    let snapshot = strategy.parse_raw(&raw).ok_or_else(|| {
+        FetchError::StrategyNotAvailable(format!("{} can't be replayed", strategy.id()))
+    })??;
+
+    debug!(path = %path.display(), "Replayed fixture");
+    Ok(FetchResult::new(snapshot, strategy.id(), strategy.kind()).with_raw_response(Some(raw)))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::FetchContext;
+    use crate::strategy::FetchKind;
+    use async_trait::async_trait;
+    use exactobar_core::{UsageSnapshot, UsageWindow};
+
+    /// Strategy whose raw response is the used percentage.
+    struct PercentStrategy;
+
+    #[async_trait]
+    impl FetchStrategy for PercentStrategy {
+        fn id(&self) -> &'static str {
+            "test.percent"
+        }
+
+        fn kind(&self) -> FetchKind {
+            FetchKind::ApiKey
+        }
+
+        async fn is_available(&self, _ctx: &FetchContext) -> bool {
+            false
+        }
+
+        async fn fetch(&self, _ctx: &FetchContext) -> Result<FetchResult, FetchError> {
+            Err(FetchError::AllStrategiesFailed)
+        }
+
+        fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+            Some(
+                raw.trim()
+                    .parse()
+                    .map(|percent| {
+                        let mut snapshot = UsageSnapshot::new();
+                        snapshot.primary = Some(UsageWindow::new(percent));
+                        snapshot
+                    })
+                    .map_err(|e| FetchError::InvalidResponse(format!("{e}"))),
+            )
+        }
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let dir = std::env::temp_dir().join(format!("exactobar-fixtures-{}", std::process::id()));
+        let fetched = FetchResult::new(UsageSnapshot::new(), "test.percent", FetchKind::ApiKey)
+            .with_raw_response(Some("42.5".to_string()));
+        record(&dir, &fetched);
+
+        let replayed = replay(&PercentStrategy, &dir).unwrap();
+        assert_eq!(replayed.strategy_id, "test.percent");
+        assert_eq!(replayed.raw_response.as_deref(), Some("42.5"));
+        let primary = replayed.snapshot.primary.unwrap();
+        assert!((primary.used_percent - 42.5).abs() < f64::EPSILON);
+
+        std::fs::write(fixture_path(&dir, "test.percent"), "not a number").unwrap();
+        assert!(matches!(
+            replay(&PercentStrategy, &dir),
+            Err(FetchError::InvalidResponse(_))
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(
+            replay(&PercentStrategy, &dir),
+            Err(FetchError::StrategyNotAvailable(_))
+        ));
+    }
+}
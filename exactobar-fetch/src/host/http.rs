@@ -45,7 +45,7 @@ impl HttpClient {
     /// making network operations impossible. This is considered
     /// unrecoverable at runtime.
     pub fn with_timeout(timeout: Duration) -> Self {
-        let client = Client::builder()
+        let client = crate::network::client_builder()
             .timeout(timeout)
             .user_agent(USER_AGENT)
             .build()
@@ -101,7 +101,7 @@ impl HttpClient {
         self.is_domain_allowed(url)?;
         debug!("GET request");
 
-        let response = self.inner.get(url).send().await?;
+        let response = crate::network::send(self.inner.get(url)).await?;
         debug!(status = %response.status(), "Response received");
         Ok(response)
     }
@@ -116,7 +116,7 @@ impl HttpClient {
         self.is_domain_allowed(url)?;
         debug!("GET request with headers");
 
-        let response = self.inner.get(url).headers(headers).send().await?;
+        let response = crate::network::send(self.inner.get(url).headers(headers)).await?;
         debug!(status = %response.status(), "Response received");
         Ok(response)
     }
@@ -127,12 +127,12 @@ impl HttpClient {
         self.is_domain_allowed(url)?;
         debug!("GET request with auth");
 
-        let response = self
-            .inner
-            .get(url)
-            .header(header::AUTHORIZATION, auth_header)
-            .send()
-            .await?;
+        let response = crate::network::send(
+            self.inner
+                .get(url)
+                .header(header::AUTHORIZATION, auth_header),
+        )
+        .await?;
         debug!(status = %response.status(), "Response received");
         Ok(response)
     }
@@ -145,12 +145,8 @@ impl HttpClient {
         self.is_domain_allowed(url)?;
         debug!("GET request with cookies");
 
-        let response = self
-            .inner
-            .get(url)
-            .header(header::COOKIE, cookies)
-            .send()
-            .await?;
+        let response =
+            crate::network::send(self.inner.get(url).header(header::COOKIE, cookies)).await?;
         debug!(status = %response.status(), "Response received");
         Ok(response)
     }
@@ -165,7 +161,7 @@ impl HttpClient {
         self.is_domain_allowed(url)?;
         debug!("POST request with JSON");
 
-        let response = self.inner.post(url).json(body).send().await?;
+        let response = crate::network::send(self.inner.post(url).json(body)).await?;
         debug!(status = %response.status(), "Response received");
         Ok(response)
     }
@@ -180,7 +176,7 @@ impl HttpClient {
         self.is_domain_allowed(url)?;
         debug!("POST request with form data");
 
-        let response = self.inner.post(url).form(form).send().await?;
+        let response = crate::network::send(self.inner.post(url).form(form)).await?;
         debug!(status = %response.status(), "Response received");
         Ok(response)
     }
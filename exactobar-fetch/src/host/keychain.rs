@@ -7,12 +7,23 @@
 //!
 //! ## Caching
 //!
-//! To avoid multiple keychain password prompts on startup, this module provides
-//! a caching layer. Use `get_password_cached()` for sync access that caches results.
-//! The cache is global and persists for the lifetime of the application.
+//! To avoid repeated keychain password prompts, reads go through a global
+//! in-memory cache: `get_password_cached()` for sync access, and
+//! [`SystemKeychain`] for the async API. Each credential is read from the
+//! keychain once per session, and concurrent reads of the same credential
+//! wait for the first one instead of prompting again. The async API does
+//! this on Tokio's blocking pool, so a slow keychain (or a password prompt)
+//! never stalls a runtime worker.
+//!
+//! Cached credentials are dropped when they're written or deleted, and when
+//! a fetch that read them fails authentication (see [`track_reads`]), so a
+//! rotated key is picked up on the next refresh.
 
-use std::collections::HashMap;
-use std::sync::{Mutex, OnceLock};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
 
 use async_trait::async_trait;
 use keyring::Entry;
@@ -27,15 +38,74 @@ const SERVICE_PREFIX: &str = "exactobar";
 // Keychain Cache (Global)
 // ============================================================================
 
+/// A cached keychain value: `None` until read, then `Some(None)` if there
+/// was no entry. Readers hold its lock while reading the keychain.
+type CacheSlot = Arc<Mutex<Option<Option<String>>>>;
+
 /// Global cache for keychain values to avoid repeated password prompts.
-/// Key: "service:account", Value: Option<String> (None means "no entry found")
-static KEYCHAIN_CACHE: OnceLock<Mutex<HashMap<String, Option<String>>>> = OnceLock::new();
+/// Key: "service:account".
+static KEYCHAIN_CACHE: OnceLock<Mutex<HashMap<String, CacheSlot>>> = OnceLock::new();
+
+tokio::task_local! {
+    /// Cache keys read by the fetch running in [`track_reads`].
+    static READ_KEYS: RefCell<HashSet<String>>;
+}
 
 /// Get the global keychain cache.
-fn get_cache() -> &'static Mutex<HashMap<String, Option<String>>> {
+fn get_cache() -> &'static Mutex<HashMap<String, CacheSlot>> {
     KEYCHAIN_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
 }
 
+fn cache_key(service: &str, account: &str) -> String {
+    format!("{service}:{account}")
+}
+
+/// Returns the cached value for `service`/`account`, calling `read` on a
+/// miss. Errors aren't cached, so the next lookup reads again.
+fn get_or_read<E>(
+    service: &str,
+    account: &str,
+    read: impl FnOnce() -> Result<Option<String>, E>,
+) -> Result<Option<String>, E> {
+    let key = cache_key(service, account);
+    note_read(&key);
+
+    let slot = get_cache()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(key)
+        .or_default()
+        .clone();
+    // Held across the read so concurrent lookups of this entry wait for it
+    let mut value = slot.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(cached) = value.as_ref() {
+        trace!(service = %service, account = %account, hit = true, "Keychain cache lookup");
+        return Ok(cached.clone());
+    }
+
+    trace!(service = %service, account = %account, hit = false, "Keychain cache miss, reading from keychain");
+    let result = read()?;
+    // Store even None values to avoid repeated lookups
+    *value = Some(result.clone());
+    Ok(result)
+}
+
+/// [`get_or_read`] for async callers, run on the blocking pool.
+async fn get_or_read_blocking<E: Send + 'static>(
+    service: String,
+    account: String,
+    read: impl FnOnce() -> Result<Option<String>, E> + Send + 'static,
+) -> Result<Result<Option<String>, E>, tokio::task::JoinError> {
+    // The blocking thread can't see the task's read tracking
+    note_read(&cache_key(&service, &account));
+    tokio::task::spawn_blocking(move || get_or_read(&service, &account, read)).await
+}
+
+/// Records a read for [`track_reads`], if the current task is tracking.
+fn note_read(key: &str) {
+    let _ = READ_KEYS.try_with(|keys| keys.borrow_mut().insert(key.to_string()));
+}
+
 /// Get a password from the keychain with caching (sync).
 ///
 /// This function caches keychain lookups to avoid multiple password prompts.
@@ -50,11 +120,6 @@ fn get_cache() -> &'static Mutex<HashMap<String, Option<String>>> {
 /// * `Some(password)` - Password found (from cache or keychain)
 /// * `None` - No password found
 ///
-/// # Panics
-///
-/// Panics if the cache mutex is poisoned (only happens if a thread panicked
-/// while holding the lock, which indicates a serious bug).
-///
 /// # Example
 /// ```ignore
 /// use exactobar_fetch::host::keychain::get_password_cached;
@@ -64,21 +129,15 @@ fn get_cache() -> &'static Mutex<HashMap<String, Option<String>>> {
 /// }
 /// ```
 pub fn get_password_cached(service: &str, account: &str) -> Option<String> {
-    let cache_key = format!("{service}:{account}");
-
-    // Check cache first
-    {
-        let cache = get_cache().lock().unwrap();
-        if let Some(cached) = cache.get(&cache_key) {
-            trace!(service = %service, account = %account, hit = true, "Keychain cache lookup");
-            return cached.clone();
-        }
-    }
-
-    trace!(service = %service, account = %account, hit = false, "Keychain cache miss, reading from keychain");
+    let Ok(result) = get_or_read(service, account, || {
+        Ok::<_, Infallible>(read_password(service, account))
+    });
+    result
+}
 
-    // Not in cache, read from keychain
-    let result = match Entry::new(service, account) {
+/// Reads a password from the keychain, treating failures as "not found".
+fn read_password(service: &str, account: &str) -> Option<String> {
+    match Entry::new(service, account) {
         Ok(entry) => match entry.get_password() {
             Ok(password) if !password.is_empty() => Some(password),
             // Empty password or no entry both mean "not found"
@@ -92,24 +151,15 @@ pub fn get_password_cached(service: &str, account: &str) -> Option<String> {
             warn!(service = %service, account = %account, error = %e, "Failed to create keychain entry");
             None
         }
-    };
-
-    // Store in cache (even None values to avoid repeated lookups)
-    {
-        let mut cache = get_cache().lock().unwrap();
-        cache.insert(cache_key, result.clone());
     }
-
-    result
 }
 
 /// Invalidate a specific cache entry.
 ///
 /// Call this when you know a credential has changed (e.g., after storing a new value).
 pub fn invalidate_cache_entry(service: &str, account: &str) {
-    let cache_key = format!("{service}:{account}");
     if let Ok(mut cache) = get_cache().lock() {
-        cache.remove(&cache_key);
+        cache.remove(&cache_key(service, account));
         debug!(service = %service, account = %account, "Invalidated keychain cache entry");
     }
 }
@@ -124,6 +174,35 @@ pub fn clear_cache() {
     }
 }
 
+/// Runs `fetch`, returning its output along with the cache keys of the
+/// keychain entries it read, for [`invalidate_reads`].
+///
+/// Only reads made on the calling task are seen, not those on tasks it
+/// spawns.
+pub async fn track_reads<F: Future>(fetch: F) -> (F::Output, Vec<String>) {
+    READ_KEYS
+        .scope(RefCell::new(HashSet::new()), async {
+            let output = fetch.await;
+            let keys = READ_KEYS.with(|keys| keys.take().into_iter().collect());
+            (output, keys)
+        })
+        .await
+}
+
+/// Drops the cache entries returned by [`track_reads`], e.g. after the
+/// fetch that read them failed authentication.
+pub fn invalidate_reads(keys: &[String]) {
+    if keys.is_empty() {
+        return;
+    }
+    if let Ok(mut cache) = get_cache().lock() {
+        for key in keys {
+            cache.remove(key);
+        }
+        debug!(count = keys.len(), "Invalidated keychain cache entries");
+    }
+}
+
 // ============================================================================
 // Keychain API Trait
 // ============================================================================
@@ -201,24 +280,30 @@ impl SystemKeychain {
 #[async_trait]
 impl KeychainApi for SystemKeychain {
     async fn get(&self, service: &str, account: &str) -> Result<Option<String>, KeychainError> {
-        debug!(service = %service, account = %account, "Getting credential from keychain");
-
-        let entry = Self::entry(service, account)?;
-
-        match entry.get_password() {
-            Ok(secret) => {
-                debug!(service = %service, account = %account, "Credential found");
-                Ok(Some(secret))
-            }
-            Err(keyring::Error::NoEntry) => {
-                debug!(service = %service, account = %account, "Credential not found");
-                Ok(None)
-            }
-            Err(e) => {
-                warn!(service = %service, account = %account, error = %e, "Failed to get credential");
-                Err(e.into())
+        let full_service = Self::full_service(service);
+        let (service, account) = (service.to_string(), account.to_string());
+        get_or_read_blocking(full_service, account.clone(), move || {
+            debug!(service = %service, account = %account, "Getting credential from keychain");
+
+            let entry = Self::entry(&service, &account)?;
+
+            match entry.get_password() {
+                Ok(secret) => {
+                    debug!(service = %service, account = %account, "Credential found");
+                    Ok(Some(secret))
+                }
+                Err(keyring::Error::NoEntry) => {
+                    debug!(service = %service, account = %account, "Credential not found");
+                    Ok(None)
+                }
+                Err(e) => {
+                    warn!(service = %service, account = %account, error = %e, "Failed to get credential");
+                    Err(e.into())
+                }
             }
-        }
+        })
+        .await
+        .map_err(|e| KeychainError::Platform(format!("Task join error: {e}")))?
     }
 
     async fn set(&self, service: &str, account: &str, secret: &str) -> Result<(), KeychainError> {
@@ -230,6 +315,7 @@ impl KeychainApi for SystemKeychain {
             warn!(service = %service, account = %account, error = %e, "Failed to set credential");
             KeychainError::from(e)
         })?;
+        invalidate_cache_entry(&Self::full_service(service), account);
 
         debug!(service = %service, account = %account, "Credential stored successfully");
         Ok(())
@@ -239,6 +325,7 @@ impl KeychainApi for SystemKeychain {
         debug!(service = %service, account = %account, "Deleting credential from keychain");
 
         let entry = Self::entry(service, account)?;
+        invalidate_cache_entry(&Self::full_service(service), account);
 
         match entry.delete_credential() {
             Ok(()) => {
@@ -310,6 +397,7 @@ pub mod accounts {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[test]
     fn test_full_service_name() {
@@ -317,6 +405,76 @@ mod tests {
         assert_eq!(SystemKeychain::full_service("openai"), "exactobar:openai");
     }
 
+    #[test]
+    fn test_cache_reads_once() {
+        let reads = AtomicUsize::new(0);
+        let read = || {
+            reads.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, Infallible>(Some("secret".to_string()))
+        };
+
+        // Concurrent lookups wait for the first read
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| get_or_read("test-once", "key", read));
+            }
+        });
+        assert_eq!(reads.load(Ordering::SeqCst), 1);
+
+        invalidate_cache_entry("test-once", "key");
+        assert_eq!(
+            get_or_read("test-once", "key", read),
+            Ok(Some("secret".to_string()))
+        );
+        assert_eq!(reads.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_cache_skips_errors() {
+        assert_eq!(
+            get_or_read("test-errors", "key", || Err("locked")),
+            Err("locked")
+        );
+        assert_eq!(
+            get_or_read("test-errors", "key", || Ok::<_, &str>(None)),
+            Ok(None)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_track_reads() {
+        let read = || Ok::<_, Infallible>(Some("old".to_string()));
+        let (_, keys) = track_reads(async { get_or_read("test-track", "key", read) }).await;
+        assert_eq!(keys, vec!["test-track:key".to_string()]);
+
+        // Dropping the entries makes the next lookup read again
+        invalidate_reads(&keys);
+        let read = || Ok::<_, Infallible>(Some("new".to_string()));
+        assert_eq!(
+            get_or_read("test-track", "key", read),
+            Ok(Some("new".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_blocking_read_is_tracked() {
+        let read = || Ok::<_, Infallible>(Some("secret".to_string()));
+        let (value, keys) = track_reads(get_or_read_blocking(
+            "test-blocking".to_string(),
+            "key".to_string(),
+            read,
+        ))
+        .await;
+        assert_eq!(value.unwrap(), Ok(Some("secret".to_string())));
+        assert_eq!(keys, vec!["test-blocking:key".to_string()]);
+
+        // Cached for sync readers too
+        assert_eq!(
+            get_or_read("test-blocking", "key", || Err("not read")),
+            Ok(Some("secret".to_string()))
+        );
+    }
+
     // Note: Actual keychain tests require platform access and are typically
     // run as integration tests, not unit tests.
 }
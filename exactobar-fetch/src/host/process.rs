@@ -134,7 +134,9 @@ impl ProcessRunner {
         command
             .args(args)
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped());
+            .stderr(Stdio::piped())
+            // Don't leave a hung CLI behind when the fetch is cancelled
+            .kill_on_drop(true);
 
         // Add environment variables
         for (key, value) in env {
@@ -4,7 +4,7 @@
 //! provider status pages, primarily using the statuspage.io format.
 
 use chrono::{DateTime, Utc};
-use exactobar_core::{ProviderStatus, StatusIndicator};
+use exactobar_core::{ProviderStatus, StatusIncident, StatusIndicator};
 use serde::Deserialize;
 use tracing::{debug, instrument, warn};
 
@@ -38,6 +38,40 @@ struct StatuspagePage {
     updated_at: Option<String>,
 }
 
+/// Response from statuspage.io /api/v2/summary.json endpoint.
+#[derive(Debug, Deserialize)]
+struct StatuspageSummary {
+    status: StatuspageIndicator,
+    page: StatuspagePage,
+    /// Unresolved incidents.
+    #[serde(default)]
+    incidents: Vec<StatuspageIncident>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StatuspageIncident {
+    name: String,
+    status: String,
+    started_at: Option<String>,
+    created_at: Option<String>,
+    #[serde(default)]
+    components: Vec<StatuspageComponent>,
+    /// Updates, newest first.
+    #[serde(default)]
+    incident_updates: Vec<StatuspageIncidentUpdate>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StatuspageComponent {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct StatuspageIncidentUpdate {
+    body: String,
+    created_at: Option<String>,
+}
+
 /// Response from Google Workspace Status Dashboard.
 #[allow(dead_code)]
 #[derive(Debug, Deserialize)]
@@ -94,27 +128,50 @@ impl StatusPoller {
         }
 
         let data: StatuspageStatus = response.json().await?;
-
-        let indicator = parse_statuspage_indicator(&data.status.indicator);
-        let updated_at = data
-            .page
-            .updated_at
-            .as_ref()
-            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
-            .map_or_else(Utc::now, |dt| dt.with_timezone(&Utc));
+        let status = to_provider_status(data.status, data.page, Vec::new());
 
         debug!(
-            indicator = ?indicator,
-            description = %data.status.description,
+            indicator = ?status.indicator,
+            description = %status.description,
             "Status fetched successfully"
         );
 
-        Ok(ProviderStatus {
-            indicator,
-            description: data.status.description,
-            updated_at,
-            url: Some(data.page.url),
-        })
+        Ok(status)
+    }
+
+    /// Fetch status with the unresolved incidents from a statuspage.io
+    /// summary endpoint.
+    ///
+    /// Takes the same URL as [`Self::fetch_status`], e.g.
+    /// `https://status.openai.com/api/v2/status.json`, and reads the
+    /// `summary.json` next to it. Other URLs are fetched without incidents.
+    #[instrument(skip(self), fields(url = %status_url))]
+    pub async fn fetch_summary(&self, status_url: &str) -> Result<ProviderStatus, StatusError> {
+        let Some(summary_url) = summary_url(status_url) else {
+            return self.fetch_status(status_url).await;
+        };
+        debug!("Fetching status summary from statuspage.io endpoint");
+
+        let response = self.client.get(&summary_url).await.map_err(|e| {
+            warn!(error = %e, "Failed to fetch status summary");
+            StatusError::Unavailable(e.to_string())
+        })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            return Err(StatusError::Unavailable(format!("HTTP {status}")));
+        }
+
+        let data: StatuspageSummary = response.json().await?;
+        let status = to_provider_status(data.status, data.page, data.incidents);
+
+        debug!(
+            indicator = ?status.indicator,
+            incidents = status.incidents.len(),
+            "Status summary fetched successfully"
+        );
+
+        Ok(status)
     }
 
     /// Fetch status for a Google Workspace product.
@@ -148,6 +205,7 @@ impl StatusPoller {
             description: "Operational".to_string(),
             updated_at: Utc::now(),
             url: Some("https://www.google.com/appsstatus/dashboard/".to_string()),
+            incidents: Vec::new(),
         })
     }
 
@@ -180,6 +238,7 @@ impl StatusPoller {
             description: worst_description,
             updated_at: Utc::now(),
             url: first_url,
+            incidents: Vec::new(),
         })
     }
 }
@@ -206,6 +265,51 @@ fn parse_statuspage_indicator(indicator: &str) -> StatusIndicator {
     }
 }
 
+/// Returns the summary endpoint next to a statuspage.io status endpoint.
+fn summary_url(status_url: &str) -> Option<String> {
+    status_url
+        .strip_suffix("/status.json")
+        .map(|base| format!("{base}/summary.json"))
+}
+
+fn parse_time(time: Option<&str>) -> Option<DateTime<Utc>> {
+    time.and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn to_provider_status(
+    status: StatuspageIndicator,
+    page: StatuspagePage,
+    incidents: Vec<StatuspageIncident>,
+) -> ProviderStatus {
+    ProviderStatus {
+        indicator: parse_statuspage_indicator(&status.indicator),
+        description: status.description,
+        updated_at: parse_time(page.updated_at.as_deref()).unwrap_or_else(Utc::now),
+        url: Some(page.url),
+        incidents: incidents.into_iter().map(to_incident).collect(),
+    }
+}
+
+fn to_incident(incident: StatuspageIncident) -> StatusIncident {
+    let latest = incident.incident_updates.into_iter().next();
+    StatusIncident {
+        name: incident.name,
+        status: incident.status,
+        started_at: parse_time(
+            incident
+                .started_at
+                .as_deref()
+                .or(incident.created_at.as_deref()),
+        ),
+        components: incident.components.into_iter().map(|c| c.name).collect(),
+        latest_update_at: latest
+            .as_ref()
+            .and_then(|u| parse_time(u.created_at.as_deref())),
+        latest_update: latest.map(|u| u.body),
+    }
+}
+
 // ============================================================================
 // Known Status Page URLs
 // ============================================================================
@@ -287,6 +391,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_summary() {
+        let json = r#"{
+            "page": {"id": "abc", "name": "Anthropic", "url": "https://status.anthropic.com", "updated_at": "2025-03-04T10:15:00Z"},
+            "status": {"indicator": "minor", "description": "Partially Degraded Service"},
+            "components": [],
+            "incidents": [{
+                "name": "Elevated errors on Claude Opus",
+                "status": "identified",
+                "created_at": "2025-03-04T09:40:00Z",
+                "started_at": "2025-03-04T09:30:00Z",
+                "components": [{"name": "claude.ai"}, {"name": "API"}],
+                "incident_updates": [
+                    {"body": "A fix is being rolled out.", "created_at": "2025-03-04T10:10:00Z"},
+                    {"body": "We are investigating.", "created_at": "2025-03-04T09:40:00Z"}
+                ]
+            }]
+        }"#;
+        let data: StatuspageSummary = serde_json::from_str(json).unwrap();
+        let status = to_provider_status(data.status, data.page, data.incidents);
+
+        assert_eq!(status.indicator, StatusIndicator::Minor);
+        assert_eq!(status.incidents.len(), 1);
+        let incident = &status.incidents[0];
+        assert_eq!(incident.name, "Elevated errors on Claude Opus");
+        assert_eq!(incident.status, "identified");
+        assert_eq!(incident.components, vec!["claude.ai", "API"]);
+        assert_eq!(
+            incident.started_at,
+            parse_time(Some("2025-03-04T09:30:00Z"))
+        );
+        assert_eq!(
+            incident.latest_update.as_deref(),
+            Some("A fix is being rolled out.")
+        );
+        assert_eq!(
+            incident.latest_update_at,
+            parse_time(Some("2025-03-04T10:10:00Z"))
+        );
+    }
+
+    #[test]
+    fn test_summary_url() {
+        assert_eq!(
+            summary_url(urls::ANTHROPIC).as_deref(),
+            Some("https://status.anthropic.com/api/v2/summary.json")
+        );
+        assert_eq!(summary_url("https://example.com/health"), None);
+    }
+
     #[test]
     fn test_api_url_for_provider() {
         assert_eq!(urls::api_url_for_provider("codex"), Some(urls::OPENAI));
@@ -0,0 +1,273 @@
+//! Recorder of recent HTTP exchanges, for debugging.
+//!
+//! Provider requests are sent with [`crate::network::send`], which, while
+//! recording is on, keeps the method, URL, status, duration and start of the body of the
+//! last [`CAPACITY`] exchanges in memory. Query parameters and JSON fields
+//! that look like credentials are redacted before anything is kept, and
+//! request headers and bodies are never kept.
+//!
+//! When a provider suddenly shows 0%, the recorded responses show whether
+//! it answered with an error page, an empty object or a changed shape.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+/// Number of exchanges kept.
+pub const CAPACITY: usize = 100;
+
+/// Number of body characters kept per exchange.
+const BODY_LIMIT: usize = 4096;
+
+/// Replaces redacted values.
+const REDACTED: &str = "[redacted]";
+
+/// Substrings of query parameter and JSON field names whose values are
+/// redacted.
+const SECRET_NAMES: [&str; 9] = [
+    "token",
+    "secret",
+    "password",
+    "key",
+    "cookie",
+    "session",
+    "auth",
+    "credential",
+    "email",
+];
+
+/// One recorded request and its response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exchange {
+    /// When the request was sent.
+    pub started_at: DateTime<Utc>,
+    /// HTTP method, e.g. "GET".
+    pub method: String,
+    /// Request URL, with secret query parameters redacted.
+    pub url: String,
+    /// Response status, or `None` if no response arrived.
+    pub status: Option<u16>,
+    /// Time until the whole response was read.
+    pub duration: Duration,
+    /// Start of the response body, with secret JSON fields redacted.
+    pub body: String,
+    /// Why the request failed, if it did.
+    pub error: Option<String>,
+}
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+
+/// Recorded exchanges, oldest first.
+static EXCHANGES: Mutex<VecDeque<Exchange>> = Mutex::new(VecDeque::new());
+
+/// Turns recording on or off. Turning it off forgets recorded exchanges.
+pub fn set_recording(enabled: bool) {
+    if !RECORDING.swap(enabled, Ordering::Relaxed) || enabled {
+        return;
+    }
+    clear();
+}
+
+/// Returns true if exchanges are being recorded.
+pub fn is_recording() -> bool {
+    RECORDING.load(Ordering::Relaxed)
+}
+
+/// Returns the recorded exchanges, oldest first.
+pub fn exchanges() -> Vec<Exchange> {
+    EXCHANGES
+        .lock()
+        .map(|exchanges| exchanges.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Forgets the recorded exchanges.
+pub fn clear() {
+    if let Ok(mut exchanges) = EXCHANGES.lock() {
+        exchanges.clear();
+    }
+}
+
+fn record(exchange: Exchange) {
+    if let Ok(mut exchanges) = EXCHANGES.lock() {
+        if exchanges.len() == CAPACITY {
+            exchanges.pop_front();
+        }
+        exchanges.push_back(exchange);
+    }
+}
+
+/// Sends a request, recording it and its response while recording is on.
+///
+/// While recording, the response body is read up front and handed back in
+/// a rebuilt response.
+pub(crate) async fn execute(
+    client: &reqwest::Client,
+    request: reqwest::Request,
+) -> reqwest::Result<reqwest::Response> {
+    if !is_recording() {
+        return client.execute(request).await;
+    }
+
+    let url = request.url().to_string();
+    let redacted_url = redact_url(request.url());
+    let start = Instant::now();
+    let mut exchange = Exchange {
+        started_at: Utc::now(),
+        method: request.method().to_string(),
+        url: redacted_url.clone(),
+        status: None,
+        duration: Duration::ZERO,
+        body: String::new(),
+        error: None,
+    };
+
+    let result = read_response(client.execute(request).await).await;
+    exchange.duration = start.elapsed();
+    match &result {
+        Ok((response, body)) => {
+            exchange.status = Some(response.status().as_u16());
+            exchange.body = redact_body(body);
+        }
+        Err(e) => exchange.error = Some(e.to_string().replace(&url, &redacted_url)),
+    }
+    record(exchange);
+
+    result.map(|(response, body)| reqwest::Response::from(response.map(|()| body)))
+}
+
+/// Reads a response's body, keeping the rest of it to rebuild the response.
+async fn read_response(
+    response: reqwest::Result<reqwest::Response>,
+) -> reqwest::Result<(http::Response<()>, Vec<u8>)> {
+    let response = response?;
+    let mut parts = http::Response::new(());
+    *parts.status_mut() = response.status();
+    *parts.version_mut() = response.version();
+    *parts.headers_mut() = response.headers().clone();
+    let body = response.bytes().await?.to_vec();
+    Ok((parts, body))
+}
+
+fn is_secret(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SECRET_NAMES.iter().any(|secret| name.contains(secret))
+}
+
+/// Returns the URL with secret query parameter values redacted.
+fn redact_url(url: &url::Url) -> String {
+    if url.query().is_none() {
+        return url.to_string();
+    }
+
+    let mut redacted = url.clone();
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(name, value)| {
+            let value = if is_secret(&name) {
+                REDACTED.to_string()
+            } else {
+                value.into_owned()
+            };
+            (name.into_owned(), value)
+        })
+        .collect();
+    redacted.query_pairs_mut().clear().extend_pairs(pairs);
+    redacted.to_string()
+}
+
+/// Returns the start of the body as text, with secret JSON fields redacted.
+fn redact_body(body: &[u8]) -> String {
+    let text = match serde_json::from_slice::<Value>(body) {
+        Ok(mut json) => {
+            redact_json(&mut json);
+            serde_json::to_string_pretty(&json).unwrap_or_default()
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    };
+
+    match text.char_indices().nth(BODY_LIMIT) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text,
+    }
+}
+
+fn redact_json(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (name, value) in map.iter_mut() {
+                if is_secret(name) && !value.is_object() && !value.is_array() {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_url() {
+        let url =
+            url::Url::parse("https://api.example.com/usage?api_key=abc123&period=7d").unwrap();
+        assert_eq!(
+            redact_url(&url),
+            "https://api.example.com/usage?api_key=%5Bredacted%5D&period=7d"
+        );
+
+        let plain = url::Url::parse("https://api.example.com/usage").unwrap();
+        assert_eq!(redact_url(&plain), "https://api.example.com/usage");
+    }
+
+    #[test]
+    fn test_redact_body() {
+        let body = br#"{"access_token":"sk-123","usage":{"used":5,"sessionKey":"abc"},"accounts":[{"email":"a@b.c"}]}"#;
+        let redacted: Value = serde_json::from_str(&redact_body(body)).unwrap();
+        assert_eq!(redacted["access_token"], REDACTED);
+        assert_eq!(redacted["usage"]["used"], 5);
+        assert_eq!(redacted["usage"]["sessionKey"], REDACTED);
+        assert_eq!(redacted["accounts"][0]["email"], REDACTED);
+
+        let html = "<html>".repeat(1000);
+        let truncated = redact_body(html.as_bytes());
+        assert_eq!(truncated.chars().count(), BODY_LIMIT + 1);
+        assert!(truncated.ends_with('…'));
+    }
+
+    #[test]
+    fn test_ring_buffer() {
+        clear();
+        for i in 0..=CAPACITY {
+            record(Exchange {
+                started_at: Utc::now(),
+                method: "GET".to_string(),
+                url: format!("https://example.com/{i}"),
+                status: Some(200),
+                duration: Duration::ZERO,
+                body: String::new(),
+                error: None,
+            });
+        }
+        let recorded = exchanges();
+        assert_eq!(recorded.len(), CAPACITY);
+        assert_eq!(recorded[0].url, "https://example.com/1");
+
+        RECORDING.store(true, Ordering::Relaxed);
+        set_recording(false);
+        assert!(exchanges().is_empty());
+    }
+}
@@ -32,6 +32,10 @@
 //! - [`strategy::FetchStrategy`] - Trait for fetch implementations
 //! - [`pipeline::FetchPipeline`] - Executes strategies in order
 //! - [`context::FetchContext`] - Provides access to host APIs
+//! - [`fixtures`] - Records raw responses, and replays them offline
+//! - [`network`] - Proxy and TLS settings applied to every HTTP client
+//! - [`inspector`] - Records recent HTTP exchanges for debugging
+//! - [`ratelimit`] - Keeps requests to each host within a budget
 //!
 //! ## Example
 //!
@@ -55,9 +59,13 @@
 pub mod client;
 pub mod context;
 pub mod error;
+pub mod fixtures;
 pub mod host;
+pub mod inspector;
+pub mod network;
 pub mod pipeline;
 pub mod probe;
+pub mod ratelimit;
 pub mod retry;
 pub mod strategy;
 
@@ -80,7 +88,10 @@ pub use host::{
 
 // Strategy & Pipeline
 pub use context::{FetchContext, FetchContextBuilder, FetchSettings, SourceMode};
+pub use fixtures::FixtureMode;
+pub use network::{ProxyConfig, TlsConfig};
 pub use pipeline::{FetchAttempt, FetchOutcome, FetchPipeline};
+pub use ratelimit::RateLimit;
 pub use strategy::{FetchKind, FetchResult, FetchStrategy, StrategyInfo};
 
 // Legacy exports (for compatibility)
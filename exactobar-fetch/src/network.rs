@@ -0,0 +1,422 @@
+//! Network settings shared by every HTTP client.
+//!
+//! HTTP clients are built from [`client_builder`] or
+//! [`blocking_client_builder`], which apply the process-wide proxy set with
+//! [`set_proxy`] and the TLS options set with [`set_tls`]. Clients pick up
+//! changed settings the next time they are built.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use rustls::client::WebPkiServerVerifier;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, RootCertStore, SignatureScheme};
+use tracing::{debug, warn};
+
+use crate::{inspector, ratelimit};
+
+/// How HTTP requests reach the network.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ProxyConfig {
+    /// Use the system proxy settings and the `HTTP_PROXY`, `HTTPS_PROXY`
+    /// and `NO_PROXY` environment variables.
+    #[default]
+    System,
+    /// Send every request through an explicit proxy.
+    Manual {
+        /// Proxy URL, e.g. `http://proxy.corp:3128` or `socks5://localhost:1080`.
+        url: String,
+        /// Username for proxy authentication.
+        username: Option<String>,
+        /// Password for proxy authentication.
+        password: Option<String>,
+    },
+    /// Connect directly, ignoring any system proxy.
+    Off,
+}
+
+impl ProxyConfig {
+    /// Builds the explicit proxy, or `None` if this config doesn't name one
+    /// or its URL is invalid. Hosts in `NO_PROXY` still bypass it.
+    fn manual_proxy(&self) -> Option<reqwest::Proxy> {
+        let Self::Manual {
+            url,
+            username,
+            password,
+        } = self
+        else {
+            return None;
+        };
+
+        let mut proxy = match reqwest::Proxy::all(url.trim()) {
+            Ok(proxy) => proxy,
+            Err(e) => {
+                warn!(error = %e, "Invalid proxy URL, using the system proxy");
+                return None;
+            }
+        };
+        if let Some(username) = username.as_deref().filter(|u| !u.is_empty()) {
+            proxy = proxy.basic_auth(username, password.as_deref().unwrap_or_default());
+        }
+        Some(proxy.no_proxy(reqwest::NoProxy::from_env()))
+    }
+}
+
+static PROXY: RwLock<ProxyConfig> = RwLock::new(ProxyConfig::System);
+
+/// Sets the proxy used by clients built from now on.
+pub fn set_proxy(config: ProxyConfig) {
+    if let Ok(mut current) = PROXY.write() {
+        *current = config;
+    }
+}
+
+/// Returns the current proxy config.
+pub fn proxy() -> ProxyConfig {
+    PROXY.read().map(|c| c.clone()).unwrap_or_default()
+}
+
+/// Which certificates HTTPS connections trust.
+///
+/// By default only the built-in Mozilla roots are trusted. TLS-inspecting
+/// corporate proxies re-sign traffic with their own CA, which has to be
+/// added here (or installed in the system store) for any provider to work.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TlsConfig {
+    /// PEM file of extra CA certificates to trust.
+    pub ca_bundle: Option<PathBuf>,
+    /// Whether to trust the certificates in the system store as well.
+    pub system_certificates: bool,
+    /// SHA-256 fingerprints, in hex, that a host's certificate chain must
+    /// contain, keyed by host name.
+    pub pins: HashMap<String, Vec<String>>,
+}
+
+impl TlsConfig {
+    /// Builds the rustls config, or `None` if the defaults apply.
+    fn client_config(&self) -> Result<Option<rustls::ClientConfig>, rustls::Error> {
+        if *self == Self::default() {
+            return Ok(None);
+        }
+
+        let mut roots = RootCertStore {
+            roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+        };
+        if self.system_certificates {
+            let native = rustls_native_certs::load_native_certs();
+            for error in &native.errors {
+                warn!(error = %error, "Failed to load system certificates");
+            }
+            let (added, _) = roots.add_parsable_certificates(native.certs);
+            debug!(count = added, "Trusting system certificates");
+        }
+        if let Some(path) = &self.ca_bundle {
+            match CertificateDer::pem_file_iter(path) {
+                Ok(certs) => {
+                    let (added, ignored) =
+                        roots.add_parsable_certificates(certs.filter_map(Result::ok));
+                    if ignored > 0 || added == 0 {
+                        warn!(
+                            path = %path.display(),
+                            added,
+                            ignored,
+                            "CA bundle has unusable certificates"
+                        );
+                    }
+                }
+                Err(e) => warn!(path = %path.display(), error = %e, "Failed to read CA bundle"),
+            }
+        }
+
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let inner = WebPkiServerVerifier::builder_with_provider(Arc::new(roots), provider.clone())
+            .build()
+            .map_err(|e| rustls::Error::General(e.to_string()))?;
+        let verifier = PinnedVerifier {
+            inner,
+            pins: parse_pins(&self.pins),
+        };
+
+        Ok(Some(
+            rustls::ClientConfig::builder_with_provider(provider)
+                .with_safe_default_protocol_versions()?
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(verifier))
+                .with_no_client_auth(),
+        ))
+    }
+}
+
+/// Parses pins into fingerprints, dropping (and logging) malformed ones.
+fn parse_pins(pins: &HashMap<String, Vec<String>>) -> HashMap<String, Vec<[u8; 32]>> {
+    pins.iter()
+        .map(|(host, fingerprints)| {
+            let parsed = fingerprints
+                .iter()
+                .filter_map(|fingerprint| {
+                    let parsed = parse_fingerprint(fingerprint);
+                    if parsed.is_none() {
+                        warn!(host = %host, "Ignoring malformed certificate pin");
+                    }
+                    parsed
+                })
+                .collect();
+            (host.trim().to_ascii_lowercase(), parsed)
+        })
+        .collect()
+}
+
+/// Parses a hex SHA-256 fingerprint, with or without colons, as printed by
+/// `openssl x509 -noout -fingerprint -sha256`.
+fn parse_fingerprint(fingerprint: &str) -> Option<[u8; 32]> {
+    let hex: Vec<u8> = fingerprint
+        .bytes()
+        .filter(|b| *b != b':' && !b.is_ascii_whitespace())
+        .collect();
+    if hex.len() != 64 {
+        return None;
+    }
+
+    let mut bytes = [0u8; 32];
+    for (byte, pair) in bytes.iter_mut().zip(hex.chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// Verifies certificates as usual, then requires pinned hosts to present a
+/// chain containing one of their pinned certificates.
+#[derive(Debug)]
+struct PinnedVerifier {
+    inner: Arc<WebPkiServerVerifier>,
+    pins: HashMap<String, Vec<[u8; 32]>>,
+}
+
+impl ServerCertVerifier for PinnedVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let verified = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        )?;
+
+        let ServerName::DnsName(host) = server_name else {
+            return Ok(verified);
+        };
+        let Some(pins) = self.pins.get(&host.as_ref().to_ascii_lowercase()) else {
+            return Ok(verified);
+        };
+        let pinned = std::iter::once(end_entity)
+            .chain(intermediates)
+            .any(|cert| {
+                let digest = ring::digest::digest(&ring::digest::SHA256, cert.as_ref());
+                pins.iter().any(|pin| pin.as_slice() == digest.as_ref())
+            });
+        if pinned {
+            Ok(verified)
+        } else {
+            Err(rustls::Error::General(format!(
+                "certificate for {} doesn't match its pinned fingerprints",
+                host.as_ref()
+            )))
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// The TLS options in use, and the rustls config built from them.
+struct TlsState {
+    config: TlsConfig,
+    client_config: Option<rustls::ClientConfig>,
+}
+
+static TLS: RwLock<Option<TlsState>> = RwLock::new(None);
+
+/// Sets the TLS options used by clients built from now on.
+///
+/// Certificates are loaded here rather than per client, so setting the
+/// options that are already in use does nothing.
+pub fn set_tls(config: TlsConfig) {
+    let unchanged = TLS.read().is_ok_and(|current| {
+        current
+            .as_ref()
+            .map_or(config == TlsConfig::default(), |state| {
+                state.config == config
+            })
+    });
+    if unchanged {
+        return;
+    }
+
+    let client_config = config.client_config().unwrap_or_else(|e| {
+        warn!(error = %e, "Failed to apply TLS settings, using defaults");
+        None
+    });
+    if let Ok(mut current) = TLS.write() {
+        *current = Some(TlsState {
+            config,
+            client_config,
+        });
+    }
+}
+
+/// Returns the rustls config clients should use, if not the default.
+fn tls_client_config() -> Option<rustls::ClientConfig> {
+    TLS.read()
+        .ok()?
+        .as_ref()
+        .and_then(|state| state.client_config.clone())
+}
+
+/// Returns an async client builder with the network settings applied.
+pub fn client_builder() -> reqwest::ClientBuilder {
+    let mut builder = reqwest::Client::builder();
+    if let Some(tls) = tls_client_config() {
+        builder = builder.use_preconfigured_tls(tls);
+    }
+    let config = proxy();
+    match config.manual_proxy() {
+        Some(proxy) => builder.proxy(proxy),
+        None if config == ProxyConfig::Off => builder.no_proxy(),
+        None => builder,
+    }
+}
+
+/// Returns a blocking client builder with the network settings applied.
+pub fn blocking_client_builder() -> reqwest::blocking::ClientBuilder {
+    let mut builder = reqwest::blocking::Client::builder();
+    if let Some(tls) = tls_client_config() {
+        builder = builder.use_preconfigured_tls(tls);
+    }
+    let config = proxy();
+    match config.manual_proxy() {
+        Some(proxy) => builder.proxy(proxy),
+        None if config == ProxyConfig::Off => builder.no_proxy(),
+        None => builder,
+    }
+}
+
+/// Sends a request once it fits in its host's rate limit, recording it for
+/// the network inspector.
+///
+/// Provider requests should be sent with this rather than
+/// [`reqwest::RequestBuilder::send`].
+pub async fn send(request: reqwest::RequestBuilder) -> reqwest::Result<reqwest::Response> {
+    let (client, request) = request.build_split();
+    let request = request?;
+    if let Some(host) = request.url().host_str() {
+        ratelimit::acquire(host).await;
+    }
+    inspector::execute(&client, request).await
+}
+
+/// Returns an async client with the network settings applied, falling back
+/// to a default client if it can't be built.
+pub fn client() -> reqwest::Client {
+    client_builder().build().unwrap_or_else(|e| {
+        warn!(error = %e, "Failed to build HTTP client, using defaults");
+        reqwest::Client::new()
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manual(url: &str) -> ProxyConfig {
+        ProxyConfig::Manual {
+            url: url.to_string(),
+            username: Some("alice".to_string()),
+            password: Some("secret".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_manual_proxy() {
+        assert!(ProxyConfig::System.manual_proxy().is_none());
+        assert!(ProxyConfig::Off.manual_proxy().is_none());
+        assert!(manual("http://proxy.corp:3128").manual_proxy().is_some());
+        assert!(manual(" socks5://localhost:1080 ").manual_proxy().is_some());
+        assert!(manual("not a url").manual_proxy().is_none());
+    }
+
+    #[test]
+    fn test_parse_fingerprint() {
+        let hex = "AB".repeat(32);
+        assert_eq!(parse_fingerprint(&hex), Some([0xAB; 32]));
+
+        let colons = vec!["ab"; 32].join(":");
+        assert_eq!(parse_fingerprint(&colons), Some([0xAB; 32]));
+
+        assert_eq!(parse_fingerprint("abcd"), None);
+        assert_eq!(parse_fingerprint(&"zz".repeat(32)), None);
+    }
+
+    #[test]
+    fn test_tls_client_config() {
+        assert!(TlsConfig::default().client_config().unwrap().is_none());
+
+        let pinned = TlsConfig {
+            pins: HashMap::from([("api.example.com".to_string(), vec!["AB".repeat(32)])]),
+            ..TlsConfig::default()
+        };
+        assert!(pinned.client_config().unwrap().is_some());
+
+        let missing_bundle = TlsConfig {
+            ca_bundle: Some(PathBuf::from("/nonexistent/ca.pem")),
+            ..TlsConfig::default()
+        };
+        assert!(missing_bundle.client_config().unwrap().is_some());
+    }
+
+    #[test]
+    fn test_client_builds_with_each_proxy() {
+        for config in [
+            ProxyConfig::System,
+            ProxyConfig::Off,
+            manual("http://proxy.corp:3128"),
+        ] {
+            set_proxy(config.clone());
+            assert_eq!(proxy(), config);
+            assert!(client_builder().build().is_ok());
+        }
+        set_proxy(ProxyConfig::System);
+    }
+}
@@ -4,10 +4,14 @@
 //! priority order until one succeeds.
 
 use std::time::{Duration, Instant};
+
+use exactobar_core::ProviderError;
 use tracing::{debug, info, instrument, warn};
 
 use crate::context::FetchContext;
 use crate::error::FetchError;
+use crate::fixtures::{self, FixtureMode};
+use crate::host::keychain;
 use crate::strategy::{FetchKind, FetchResult, FetchStrategy};
 
 // ============================================================================
@@ -25,6 +29,8 @@ pub struct FetchAttempt {
     pub success: bool,
     /// Error if the attempt failed.
     pub error: Option<String>,
+    /// What kind of failure it was (`None` if it succeeded or was skipped).
+    pub provider_error: Option<ProviderError>,
     /// How long the attempt took.
     pub duration: Duration,
 }
@@ -37,6 +43,7 @@ impl FetchAttempt {
             kind,
             success: true,
             error: None,
+            provider_error: None,
             duration,
         }
     }
@@ -53,9 +60,23 @@ impl FetchAttempt {
             kind,
             success: false,
             error: Some(error.into()),
+            provider_error: None,
             duration,
         }
     }
+
+    /// Creates a record of an attempt that failed with `error`.
+    pub fn from_error(
+        strategy_id: impl Into<String>,
+        kind: FetchKind,
+        error: &FetchError,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            provider_error: Some(error.classify()),
+            ..Self::failure(strategy_id, kind, error.full_message(), duration)
+        }
+    }
 }
 
 // ============================================================================
@@ -89,6 +110,38 @@ impl FetchOutcome {
         self.result.as_ref().ok().map(|r| r.strategy_id.as_str())
     }
 
+    /// Returns true if the fetch failed because the provider rejected the
+    /// credentials, so retrying won't help until the user signs in again.
+    pub fn is_auth_failure(&self) -> bool {
+        self.provider_error().is_some_and(|e| e.is_auth())
+    }
+
+    /// Returns what kind of failure the fetch ended with, if it failed.
+    ///
+    /// When every strategy failed, this is the most actionable of their
+    /// errors: rejected credentials first, a missing CLI or app last.
+    pub fn provider_error(&self) -> Option<ProviderError> {
+        let error = self.result.as_ref().err()?;
+        if !matches!(
+            error,
+            FetchError::AllStrategiesFailed | FetchError::StrategyNotAvailable(_)
+        ) {
+            return Some(error.classify());
+        }
+        self.attempts
+            .iter()
+            .filter_map(|a| a.provider_error.clone())
+            .min_by_key(|e| match e {
+                ProviderError::NotLoggedIn(_) => 0,
+                ProviderError::RateLimited(_) => 1,
+                ProviderError::Network(_) => 2,
+                ProviderError::Parse(_) => 3,
+                ProviderError::Unexpected(_) => 4,
+                ProviderError::NotInstalled(_) => 5,
+            })
+            .or_else(|| Some(error.classify()))
+    }
+
     /// Returns all errors that occurred.
     pub fn errors(&self) -> Vec<&str> {
         self.attempts
@@ -180,8 +233,8 @@ impl FetchPipeline {
 
             debug!(strategy = %strategy_id, kind = %kind, "Checking strategy availability");
 
-            // Check if strategy is available
-            if !strategy.is_available(ctx).await {
+            // Check if strategy is available (replayed fixtures always are)
+            if !ctx.settings.fixtures.is_replay() && !strategy.is_available(ctx).await {
                 debug!(strategy = %strategy_id, "Strategy not available, skipping");
                 attempts.push(FetchAttempt::failure(
                     strategy_id,
@@ -196,7 +249,7 @@ impl FetchPipeline {
             let attempt_start = Instant::now();
             debug!(strategy = %strategy_id, "Executing strategy");
 
-            match strategy.fetch(ctx).await {
+            match fetch_strategy(strategy.as_ref(), ctx).await {
                 Ok(result) => {
                     let duration = attempt_start.elapsed();
                     info!(
@@ -222,10 +275,10 @@ impl FetchPipeline {
                         "Strategy failed"
                     );
 
-                    attempts.push(FetchAttempt::failure(
+                    attempts.push(FetchAttempt::from_error(
                         strategy_id,
                         kind,
-                        error.to_string(),
+                        &error,
                         duration,
                     ));
 
@@ -262,7 +315,7 @@ impl FetchPipeline {
         // Filter to available strategies
         let mut available = Vec::new();
         for strategy in &self.strategies {
-            if strategy.is_available(ctx).await {
+            if ctx.settings.fixtures.is_replay() || strategy.is_available(ctx).await {
                 available.push(strategy);
             }
         }
@@ -283,7 +336,7 @@ impl FetchPipeline {
             let kind = strategy.kind();
             let attempt_start = Instant::now();
 
-            match strategy.fetch(ctx).await {
+            match fetch_strategy(strategy.as_ref(), ctx).await {
                 Ok(result) => {
                     let duration = attempt_start.elapsed();
                     attempts.push(FetchAttempt::success(strategy_id, kind, duration));
@@ -295,10 +348,10 @@ impl FetchPipeline {
                 }
                 Err(error) => {
                     let duration = attempt_start.elapsed();
-                    attempts.push(FetchAttempt::failure(
+                    attempts.push(FetchAttempt::from_error(
                         strategy_id,
                         kind,
-                        error.to_string(),
+                        &error,
                         duration,
                     ));
 
@@ -327,6 +380,30 @@ impl Default for FetchPipeline {
     }
 }
 
+/// Runs a strategy's fetch. If authentication fails, the keychain
+/// credentials it read are dropped from the cache so the next attempt reads
+/// them again instead of reusing a revoked or rotated key.
+///
+/// When replaying fixtures, the strategy parses its recorded response
+/// instead; when recording, a successful fetch's response is saved.
+async fn fetch_strategy(
+    strategy: &dyn FetchStrategy,
+    ctx: &FetchContext,
+) -> Result<FetchResult, FetchError> {
+    if let FixtureMode::Replay(dir) = &ctx.settings.fixtures {
+        return fixtures::replay(strategy, dir);
+    }
+
+    let (result, keys) = keychain::track_reads(strategy.fetch(ctx)).await;
+    if result.as_ref().is_err_and(FetchError::is_auth_error) {
+        keychain::invalidate_reads(&keys);
+    }
+    if let (FixtureMode::Record(dir), Ok(fetched)) = (&ctx.settings.fixtures, &result) {
+        fixtures::record(dir, fetched);
+    }
+    result
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -389,6 +466,7 @@ mod tests {
         id: String,
         should_fallback: bool,
         priority: u32,
+        auth: bool,
     }
 
     impl MockFailStrategy {
@@ -397,6 +475,7 @@ mod tests {
                 id: id.to_string(),
                 should_fallback,
                 priority: 100, // Default high priority (tried first)
+                auth: false,
             }
         }
 
@@ -404,6 +483,11 @@ mod tests {
             self.priority = priority;
             self
         }
+
+        fn with_auth_error(mut self) -> Self {
+            self.auth = true;
+            self
+        }
     }
 
     #[async_trait]
@@ -421,7 +505,13 @@ mod tests {
         }
 
         async fn fetch(&self, _ctx: &FetchContext) -> Result<FetchResult, FetchError> {
-            Err(FetchError::InvalidResponse("Mock error".to_string()))
+            if self.auth {
+                Err(FetchError::AuthenticationFailed(
+                    "Token expired".to_string(),
+                ))
+            } else {
+                Err(FetchError::InvalidResponse("Mock error".to_string()))
+            }
         }
 
         fn should_fallback(&self, _error: &FetchError) -> bool {
@@ -508,4 +598,49 @@ mod tests {
         assert!(outcome.is_success());
         assert_eq!(outcome.successful_strategy(), Some("test.available"));
     }
+
+    #[tokio::test]
+    async fn test_auth_failure() {
+        let pipeline = FetchPipeline::with_strategies(vec![
+            Box::new(MockFailStrategy::new("test.oauth", true).with_auth_error()),
+            Box::new(MockFailStrategy::new("test.cli", true).with_priority(50)),
+        ]);
+
+        let ctx = FetchContext::new();
+        let outcome = pipeline.execute(&ctx).await;
+
+        assert!(outcome.is_auth_failure());
+        assert!(matches!(
+            outcome.provider_error(),
+            Some(ProviderError::NotLoggedIn(_))
+        ));
+        assert!(matches!(
+            outcome.attempts[1].provider_error,
+            Some(ProviderError::Parse(_))
+        ));
+
+        // Credentials rejected by one strategy don't matter once another succeeds
+        let pipeline = FetchPipeline::with_strategies(vec![
+            Box::new(MockFailStrategy::new("test.oauth", true).with_auth_error()),
+            Box::new(MockSuccessStrategy::new("test.cli", true)),
+        ]);
+        assert!(!pipeline.execute(&ctx).await.is_auth_failure());
+    }
+
+    #[tokio::test]
+    async fn test_replay_never_fetches() {
+        // Neither strategy can be replayed, so nothing succeeds
+        let pipeline = FetchPipeline::with_strategies(vec![
+            Box::new(MockSuccessStrategy::new("test.success", true)),
+            Box::new(MockSuccessStrategy::new("test.unavailable", false)),
+        ]);
+        let ctx = FetchContext::builder()
+            .fixtures(FixtureMode::Replay(std::env::temp_dir()))
+            .build();
+        let outcome = pipeline.execute(&ctx).await;
+
+        assert!(!outcome.is_success());
+        assert_eq!(outcome.attempts_count(), 2);
+        assert!(outcome.errors().iter().all(|e| e.contains("No fixture")));
+    }
 }
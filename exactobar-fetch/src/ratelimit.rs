@@ -0,0 +1,156 @@
+//! Client-side rate limiting of provider requests.
+//!
+//! Every request sent with [`crate::network::send`] takes a token from its
+//! host's bucket first. Buckets hold up to [`RateLimit::burst`] tokens and
+//! refill at [`RateLimit::per_minute`]; a request that finds its bucket
+//! empty waits for its token instead of being sent. However refreshes are
+//! triggered, `ExactoBar` never sends more than the budget to one host.
+//!
+//! The budget comes from the settings, through [`set_rate_limit`]. Buckets
+//! that have refilled are dropped, since a new bucket starts out full.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use tracing::debug;
+
+/// Request budget for each host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Requests that can be sent back to back.
+    pub burst: u32,
+    /// Requests allowed per minute once the burst is spent.
+    pub per_minute: u32,
+}
+
+/// Enough for a few refreshes in a row, with token refreshes.
+const DEFAULT_LIMIT: RateLimit = RateLimit {
+    burst: 10,
+    per_minute: 20,
+};
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        DEFAULT_LIMIT
+    }
+}
+
+/// Tokens left for one host.
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    /// Negative while requests are waiting for tokens.
+    tokens: f64,
+    updated: Instant,
+}
+
+static LIMIT: RwLock<RateLimit> = RwLock::new(DEFAULT_LIMIT);
+
+static BUCKETS: Mutex<Option<HashMap<String, Bucket>>> = Mutex::new(None);
+
+/// Sets the budget used from now on.
+pub fn set_rate_limit(limit: RateLimit) {
+    if let Ok(mut current) = LIMIT.write() {
+        *current = limit;
+    }
+}
+
+/// Returns the current budget.
+pub fn rate_limit() -> RateLimit {
+    LIMIT.read().map(|l| *l).unwrap_or_default()
+}
+
+/// Returns true if the bucket has refilled, so it can be dropped.
+fn is_full(bucket: &Bucket, limit: RateLimit, now: Instant) -> bool {
+    let per_second = f64::from(limit.per_minute.max(1)) / 60.0;
+    let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+    bucket.tokens + elapsed * per_second >= f64::from(limit.burst.max(1))
+}
+
+/// Takes a token from the bucket, returning how long to wait until it's
+/// actually available.
+fn reserve(bucket: &mut Bucket, limit: RateLimit, now: Instant) -> Duration {
+    let per_second = f64::from(limit.per_minute.max(1)) / 60.0;
+    let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+    bucket.tokens = (bucket.tokens + elapsed * per_second).min(f64::from(limit.burst.max(1)));
+    bucket.updated = now;
+
+    bucket.tokens -= 1.0;
+    if bucket.tokens >= 0.0 {
+        Duration::ZERO
+    } else {
+        Duration::from_secs_f64(-bucket.tokens / per_second)
+    }
+}
+
+/// Waits until a request to `host` fits in the budget.
+pub async fn acquire(host: &str) {
+    let limit = rate_limit();
+    let now = Instant::now();
+    let wait = BUCKETS.lock().map_or(Duration::ZERO, |mut buckets| {
+        let buckets = buckets.get_or_insert_with(HashMap::new);
+        buckets.retain(|_, bucket| !is_full(bucket, limit, now));
+        let bucket = buckets.entry(host.to_string()).or_insert(Bucket {
+            tokens: f64::from(limit.burst),
+            updated: now,
+        });
+        reserve(bucket, limit, now)
+    });
+
+    if !wait.is_zero() {
+        debug!(host = %host, wait_ms = wait.as_millis(), "Rate limiting request");
+        tokio::time::sleep(wait).await;
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve() {
+        let limit = RateLimit {
+            burst: 2,
+            per_minute: 60,
+        };
+        let start = Instant::now();
+        let mut bucket = Bucket {
+            tokens: 2.0,
+            updated: start,
+        };
+
+        // The burst goes out immediately, then requests queue a second apart
+        assert_eq!(reserve(&mut bucket, limit, start), Duration::ZERO);
+        assert_eq!(reserve(&mut bucket, limit, start), Duration::ZERO);
+        assert_eq!(reserve(&mut bucket, limit, start), Duration::from_secs(1));
+        assert_eq!(reserve(&mut bucket, limit, start), Duration::from_secs(2));
+
+        // Idle time refills the bucket, but never beyond the burst
+        let later = start + Duration::from_secs(60);
+        assert_eq!(reserve(&mut bucket, limit, later), Duration::ZERO);
+        assert!((bucket.tokens - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_is_full() {
+        let limit = RateLimit {
+            burst: 2,
+            per_minute: 60,
+        };
+        let start = Instant::now();
+        let mut bucket = Bucket {
+            tokens: 2.0,
+            updated: start,
+        };
+        assert!(is_full(&bucket, limit, start));
+
+        // A used bucket is kept until it has refilled
+        reserve(&mut bucket, limit, start);
+        assert!(!is_full(&bucket, limit, start));
+        assert!(is_full(&bucket, limit, start + Duration::from_secs(1)));
+    }
+}
@@ -78,6 +78,8 @@ pub struct FetchResult {
     pub strategy_id: String,
     /// The kind of fetch used.
     pub kind: FetchKind,
+    /// The raw provider response body, kept for debugging.
+    pub raw_response: Option<String>,
 }
 
 impl FetchResult {
@@ -87,8 +89,15 @@ impl FetchResult {
             snapshot,
             strategy_id: strategy_id.into(),
             kind,
+            raw_response: None,
         }
     }
+
+    /// Attaches the raw provider response body.
+    pub fn with_raw_response(mut self, raw: Option<String>) -> Self {
+        self.raw_response = raw;
+        self
+    }
 }
 
 // ============================================================================
@@ -153,6 +162,14 @@ pub trait FetchStrategy: Send + Sync {
     /// Returns a `FetchResult` on success, or `FetchError` on failure.
     async fn fetch(&self, ctx: &FetchContext) -> Result<FetchResult, FetchError>;
 
+    /// Parses a raw response recorded by an earlier fetch (see
+    /// [`crate::fixtures`]), as `fetch` would parse a live one.
+    ///
+    /// Returns `None` if this strategy can't be replayed, the default.
+    fn parse_raw(&self, _raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        None
+    }
+
     /// Whether to try the next strategy if this one fails with the given error.
     ///
     /// Override this to prevent fallback on certain errors (e.g., rate limiting).
@@ -235,4 +252,13 @@ mod tests {
         assert_eq!(FetchKind::CLI.to_fetch_source(), FetchSource::CLI);
         assert_eq!(FetchKind::OAuth.to_fetch_source(), FetchSource::OAuth);
     }
+
+    #[test]
+    fn test_fetch_result_raw_response() {
+        let result = FetchResult::new(UsageSnapshot::new(), "test.oauth", FetchKind::OAuth);
+        assert!(result.raw_response.is_none());
+
+        let result = result.with_raw_response(Some("{}".to_string()));
+        assert_eq!(result.raw_response.as_deref(), Some("{}"));
+    }
 }
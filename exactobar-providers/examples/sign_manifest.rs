@@ -0,0 +1,86 @@
+//! Generates the manifest signing key and signs provider manifests.
+//!
+//! The private key is held by the maintainers and never committed. To
+//! publish corrections, edit `manifest/payload.json`, bump its version and
+//! sign it:
+//!
+//! ```text
+//! cargo run -p exactobar-providers --example sign_manifest -- sign KEY manifest/payload.json manifest/providers.json
+//! ```
+//!
+//! To rotate the key, run `keygen KEY`, put the printed public key in
+//! `MANIFEST_PUBLIC_KEY` and re-sign the manifest.
+
+use std::fs;
+use std::process::ExitCode;
+
+use exactobar_providers::manifest::{MANIFEST_PUBLIC_KEY, MetadataManifest, SignedManifest};
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.as_slice() {
+        [command, key] if command == "keygen" => keygen(key),
+        [command, key, payload, out] if command == "sign" => sign(key, payload, out),
+        _ => {
+            eprintln!("usage: sign_manifest keygen KEY");
+            eprintln!("       sign_manifest sign KEY PAYLOAD OUT");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("sign_manifest: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Writes a new PKCS#8 key pair to `key` and prints its public key.
+fn keygen(key: &str) -> Result<(), String> {
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+        .map_err(|_| "couldn't generate a key".to_string())?;
+    let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).map_err(|e| e.to_string())?;
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(key).map_err(|e| format!("{}: {}", key, e))?;
+    std::io::Write::write_all(&mut file, pkcs8.as_ref()).map_err(|e| e.to_string())?;
+
+    println!("wrote {}", key);
+    println!("public key: {}", encode_hex(key_pair.public_key().as_ref()));
+    Ok(())
+}
+
+/// Signs the manifest payload in `payload` and writes the published form.
+fn sign(key: &str, payload: &str, out: &str) -> Result<(), String> {
+    let pkcs8 = fs::read(key).map_err(|e| format!("{}: {}", key, e))?;
+    let key_pair = Ed25519KeyPair::from_pkcs8(&pkcs8).map_err(|e| e.to_string())?;
+
+    let payload = fs::read_to_string(payload).map_err(|e| format!("{}: {}", payload, e))?;
+    let payload = payload.trim().to_string();
+    let manifest: MetadataManifest =
+        serde_json::from_str(&payload).map_err(|e| format!("invalid payload: {}", e))?;
+
+    let signed = SignedManifest {
+        signature: encode_hex(key_pair.sign(payload.as_bytes()).as_ref()),
+        payload,
+    };
+    let json = serde_json::to_string_pretty(&signed).map_err(|e| e.to_string())?;
+    fs::write(out, json + "\n").map_err(|e| format!("{}: {}", out, e))?;
+    println!("wrote {} (version {})", out, manifest.version);
+
+    if signed.verify(MANIFEST_PUBLIC_KEY).is_err() {
+        eprintln!("warning: KEY doesn't match MANIFEST_PUBLIC_KEY; clients will reject it");
+    }
+    Ok(())
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -1,5 +1,6 @@
 //! Antigravity-specific errors.
 
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// Antigravity-specific errors.
@@ -33,3 +34,17 @@ pub enum AntigravityError {
     #[error("API error: {0}")]
     ApiError(String),
 }
+
+impl From<AntigravityError> for ProviderError {
+    fn from(err: AntigravityError) -> Self {
+        let message = err.to_string();
+        match err {
+            AntigravityError::NotRunning | AntigravityError::PortDetectionFailed(_) => {
+                ProviderError::NotInstalled(message)
+            }
+            AntigravityError::ConnectionFailed(_) => ProviderError::Network(message),
+            AntigravityError::InvalidResponse(_) => ProviderError::Parse(message),
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+}
@@ -421,7 +421,7 @@ impl AntigravityProbe {
     /// Create a new probe.
     pub fn new() -> Self {
         // Accept self-signed certs for localhost HTTPS
-        let http = reqwest::Client::builder()
+        let http = exactobar_fetch::network::client_builder()
             .danger_accept_invalid_certs(true)
             .timeout(std::time::Duration::from_secs(8))
             .build()
@@ -543,16 +543,16 @@ impl AntigravityProbe {
         csrf_token: &str,
         body: &serde_json::Value,
     ) -> Result<Vec<u8>, AntigravityError> {
-        let response = self
-            .http
-            .post(url)
-            .header(CONTENT_TYPE, "application/json")
-            .header("Connect-Protocol-Version", "1")
-            .header("X-Codeium-Csrf-Token", csrf_token)
-            .json(body)
-            .send()
-            .await
-            .map_err(|e| AntigravityError::ConnectionFailed(e.to_string()))?;
+        let response = exactobar_fetch::network::send(
+            self.http
+                .post(url)
+                .header(CONTENT_TYPE, "application/json")
+                .header("Connect-Protocol-Version", "1")
+                .header("X-Codeium-Csrf-Token", csrf_token)
+                .json(body),
+        )
+        .await
+        .map_err(|e| AntigravityError::ConnectionFailed(e.to_string()))?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -1,7 +1,7 @@
 //! Antigravity fetch strategies.
 
 use async_trait::async_trait;
-use exactobar_core::UsageSnapshot;
+use exactobar_core::{ProviderError, UsageSnapshot};
 use exactobar_fetch::{FetchContext, FetchError, FetchKind, FetchResult, FetchStrategy};
 use tracing::{debug, instrument};
 
@@ -52,7 +52,7 @@ impl FetchStrategy for AntigravityLocalStrategy {
             .probe
             .fetch_usage()
             .await
-            .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
+            .map_err(ProviderError::from)?;
 
         Ok(FetchResult::new(snapshot, self.id(), self.kind()))
     }
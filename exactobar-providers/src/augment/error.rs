@@ -1,5 +1,6 @@
 //! Augment-specific errors.
 
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// Augment-specific errors.
@@ -43,3 +44,17 @@ impl From<reqwest::Error> for AugmentError {
         AugmentError::HttpError(err.to_string())
     }
 }
+
+impl From<AugmentError> for ProviderError {
+    fn from(err: AugmentError) -> Self {
+        let message = err.to_string();
+        match err {
+            AugmentError::AuthenticationFailed(_)
+            | AugmentError::NoSessionCookie
+            | AugmentError::SessionExpired => ProviderError::NotLoggedIn(message),
+            AugmentError::HttpError(_) => ProviderError::Network(message),
+            AugmentError::InvalidResponse(_) => ProviderError::Parse(message),
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+}
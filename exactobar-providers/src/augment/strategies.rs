@@ -82,13 +82,13 @@ impl FetchStrategy for AugmentWebStrategy {
         // Send keepalive to maintain session before fetching usage
         // This is important as Augment sessions can timeout quickly
         debug!("Sending Augment session keepalive");
-        let keepalive_result = ctx
-            .http
-            .inner()
-            .post(AUGMENT_KEEPALIVE)
-            .header(reqwest::header::COOKIE, &cookie_header)
-            .send()
-            .await;
+        let keepalive_result = exactobar_fetch::network::send(
+            ctx.http
+                .inner()
+                .post(AUGMENT_KEEPALIVE)
+                .header(reqwest::header::COOKIE, &cookie_header),
+        )
+        .await;
 
         match keepalive_result {
             Ok(response) if response.status().is_success() => {
@@ -129,7 +129,11 @@ impl FetchStrategy for AugmentWebStrategy {
 
         let snapshot = parse_augment_response(&body)?;
         info!("Fetched Augment usage successfully");
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(Some(body)))
+    }
+
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(parse_augment_response(raw))
     }
 
     fn priority(&self) -> u32 {
@@ -140,6 +144,7 @@ impl FetchStrategy for AugmentWebStrategy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use exactobar_core::ProviderError;
 
     #[test]
     fn test_web_strategy() {
@@ -162,4 +167,17 @@ mod tests {
         assert!(AugmentWebClient::has_session_cookie("connect.sid=xyz"));
         assert!(!AugmentWebClient::has_session_cookie("random=value"));
     }
+
+    #[test]
+    fn test_parse_raw() {
+        let raw = r#"{"credits": {"used": 25.0, "total": 100.0}}"#;
+        let snapshot = AugmentWebStrategy::new().parse_raw(raw).unwrap().unwrap();
+        assert_eq!(snapshot.primary.unwrap().used_percent, 25.0);
+
+        let error = AugmentWebStrategy::new()
+            .parse_raw("{")
+            .unwrap()
+            .unwrap_err();
+        assert!(matches!(error.classify(), ProviderError::Parse(_)));
+    }
 }
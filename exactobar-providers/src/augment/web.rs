@@ -118,7 +118,7 @@ pub struct AugmentWebClient {
 impl AugmentWebClient {
     /// Creates a new client.
     pub fn new() -> Self {
-        let http = reqwest::Client::builder()
+        let http = exactobar_fetch::network::client_builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
             .expect("Failed to build HTTP client");
@@ -156,7 +156,8 @@ impl AugmentWebClient {
         let url = format!("{}{}", AUGMENT_API_BASE, KEEPALIVE_ENDPOINT);
         let headers = self.build_headers(cookie_header)?;
 
-        let response = self.http.post(&url).headers(headers).send().await?;
+        let response =
+            exactobar_fetch::network::send(self.http.post(&url).headers(headers)).await?;
 
         if !response.status().is_success() {
             return Err(AugmentError::SessionExpired);
@@ -181,7 +182,7 @@ impl AugmentWebClient {
         let url = format!("{}{}", AUGMENT_API_BASE, USAGE_ENDPOINT);
         let headers = self.build_headers(cookie_header)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
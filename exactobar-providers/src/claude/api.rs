@@ -57,6 +57,9 @@ pub struct OAuthUsageResponse {
     /// 7-day OAuth apps usage window (optional).
     #[allow(dead_code)]
     pub seven_day_oauth_apps: Option<OAuthUsageWindow>,
+    /// Extra usage (overage spend) info.
+    #[serde(default)]
+    pub extra_usage: Option<ExtraUsage>,
 }
 
 /// Individual usage window from OAuth API.
@@ -108,8 +111,9 @@ impl OAuthUsageResponse {
                 remaining: None,
                 used_percent: None,
             }),
-            extra_usage: None,
+            extra_usage: self.extra_usage,
             account: None,
+            raw_body: None,
         }
     }
 }
@@ -132,6 +136,9 @@ pub struct UsageApiResponse {
     pub extra_usage: Option<ExtraUsage>,
     /// Account info.
     pub account: Option<AccountInfo>,
+    /// Raw response body (for the debug view).
+    #[serde(skip)]
+    pub raw_body: Option<String>,
 }
 
 /// Individual usage window.
@@ -179,10 +186,13 @@ impl UsageWindow {
 #[serde(rename_all = "camelCase")]
 pub struct ExtraUsage {
     /// Whether extra usage is enabled.
+    #[serde(alias = "is_enabled")]
     pub is_enabled: Option<bool>,
-    /// Credits used this month.
+    /// Credits used this month (in cents).
+    #[serde(alias = "used_credits")]
     pub used_credits: Option<f64>,
-    /// Monthly credit limit.
+    /// Monthly credit limit (in cents).
+    #[serde(alias = "monthly_limit")]
     pub monthly_limit: Option<f64>,
     /// Currency (e.g., "USD").
     pub currency: Option<String>,
@@ -243,23 +253,24 @@ impl ClaudeApiClient {
 
         debug!(url = %url, "Fetching Claude usage via OAuth");
 
-        let client = reqwest::Client::builder()
+        let client = exactobar_fetch::network::client_builder()
             .build()
             .map_err(|e| ClaudeError::HttpError(e.to_string()))?;
 
-        let response = client
-            .get(&url)
-            .header(
-                "Authorization",
-                format!("Bearer {}", credentials.access_token),
-            )
-            .header("anthropic-beta", ANTHROPIC_BETA_HEADER) // ESSENTIAL!
-            .header("Content-Type", "application/json")
-            .header("User-Agent", "claude-code/2.0.32")
-            .header("Accept", "application/json, text/plain, */*")
-            .send()
-            .await
-            .map_err(|e| ClaudeError::HttpError(e.to_string()))?;
+        let response = exactobar_fetch::network::send(
+            client
+                .get(&url)
+                .header(
+                    "Authorization",
+                    format!("Bearer {}", credentials.access_token),
+                )
+                .header("anthropic-beta", ANTHROPIC_BETA_HEADER) // ESSENTIAL!
+                .header("Content-Type", "application/json")
+                .header("User-Agent", "claude-code/2.0.32")
+                .header("Accept", "application/json, text/plain, */*"),
+        )
+        .await
+        .map_err(|e| ClaudeError::HttpError(e.to_string()))?;
 
         let status = response.status();
 
@@ -290,7 +301,11 @@ impl ClaudeApiClient {
             &body[..body.len().min(2000)]
         );
 
-        // Parse OAuth usage response
+        Self::parse_usage(body)
+    }
+
+    /// Parses a usage response body, keeping the body for the debug view.
+    pub fn parse_usage(body: String) -> Result<UsageApiResponse, ClaudeError> {
         let oauth_response: OAuthUsageResponse = serde_json::from_str(&body).map_err(|e| {
             ClaudeError::ParseError(format!(
                 "Failed to parse OAuth response: {} - body: {}",
@@ -305,7 +320,9 @@ impl ClaudeApiClient {
         );
 
         // Convert to UsageApiResponse for internal compatibility
-        Ok(oauth_response.into_usage_api_response())
+        let mut usage = oauth_response.into_usage_api_response();
+        usage.raw_body = Some(body);
+        Ok(usage)
     }
 
     /// Fetch usage using the access token directly.
@@ -318,20 +335,21 @@ impl ClaudeApiClient {
 
         debug!(url = %url, "Fetching usage from API with token");
 
-        let client = reqwest::Client::builder()
+        let client = exactobar_fetch::network::client_builder()
             .build()
             .map_err(|e| ClaudeError::HttpError(e.to_string()))?;
 
-        let response = client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", access_token))
-            .header("anthropic-beta", ANTHROPIC_BETA_HEADER) // ESSENTIAL!
-            .header("Content-Type", "application/json")
-            .header("User-Agent", "claude-code/2.0.32")
-            .header("Accept", "application/json, text/plain, */*")
-            .send()
-            .await
-            .map_err(|e| ClaudeError::HttpError(e.to_string()))?;
+        let response = exactobar_fetch::network::send(
+            client
+                .get(&url)
+                .header("Authorization", format!("Bearer {}", access_token))
+                .header("anthropic-beta", ANTHROPIC_BETA_HEADER) // ESSENTIAL!
+                .header("Content-Type", "application/json")
+                .header("User-Agent", "claude-code/2.0.32")
+                .header("Accept", "application/json, text/plain, */*"),
+        )
+        .await
+        .map_err(|e| ClaudeError::HttpError(e.to_string()))?;
 
         let status = response.status();
 
@@ -356,11 +374,7 @@ impl ClaudeApiClient {
             &body[..body.len().min(2000)]
         );
 
-        // Parse OAuth usage response
-        let oauth_response: OAuthUsageResponse =
-            serde_json::from_str(&body).map_err(|e| ClaudeError::ParseError(e.to_string()))?;
-
-        Ok(oauth_response.into_usage_api_response())
+        Self::parse_usage(body)
     }
 }
 
@@ -406,6 +420,18 @@ impl UsageApiResponse {
             });
         }
 
+        // Extra usage spend (credits are reported in cents)
+        if let Some(ref extra) = self.extra_usage
+            && extra.is_enabled.unwrap_or(false)
+            && let Some(used) = extra.used_credits
+        {
+            let mut cost = exactobar_core::ProviderCost::new(used / 100.0);
+            cost.limit = extra.monthly_limit.map(|limit| limit / 100.0);
+            cost.currency_code = extra.currency.clone();
+            cost.period = Some("monthly".to_string());
+            snapshot.cost = Some(cost);
+        }
+
         // Account identity
         if let Some(ref account) = self.account {
             let mut identity = ProviderIdentity::new(ProviderKind::Claude);
@@ -476,6 +502,7 @@ mod tests {
                 resets_at: None,
             }),
             seven_day_oauth_apps: None,
+            extra_usage: None,
         };
 
         let response = oauth_response.into_usage_api_response();
@@ -543,6 +570,7 @@ mod tests {
                 plan: Some("pro".to_string()),
                 organization: None,
             }),
+            raw_body: None,
         };
 
         let snapshot = response.to_snapshot();
@@ -562,6 +590,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_oauth_extra_usage_to_cost() {
+        let json = r#"{
+            "five_hour": {"utilization": 10.0, "resets_at": null},
+            "extra_usage": {
+                "is_enabled": true,
+                "used_credits": 1420.0,
+                "monthly_limit": 5000.0,
+                "currency": "USD"
+            }
+        }"#;
+        let response: OAuthUsageResponse = serde_json::from_str(json).unwrap();
+        let snapshot = response.into_usage_api_response().to_snapshot();
+
+        let cost = snapshot.cost.expect("cost should be mapped");
+        assert!((cost.used - 14.2).abs() < 0.001);
+        assert!((cost.limit.unwrap() - 50.0).abs() < 0.001);
+        assert_eq!(cost.currency_code.as_deref(), Some("USD"));
+    }
+
     #[test]
     fn test_client_creation() {
         let client = ClaudeApiClient::new();
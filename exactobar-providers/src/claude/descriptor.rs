@@ -58,6 +58,7 @@ fn claude_token_cost() -> TokenCostConfig {
     TokenCostConfig {
         supports_token_cost: true,
         log_directory: Some(claude_log_directory),
+        project_usage: Some(super::sessions::project_usage),
     }
 }
 
@@ -1,6 +1,8 @@
 //! Claude-specific error types.
 
 use std::time::Duration;
+
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// Errors specific to Claude operations.
@@ -90,3 +92,19 @@ impl From<reqwest::Error> for ClaudeError {
         ClaudeError::HttpError(e.to_string())
     }
 }
+
+impl From<ClaudeError> for ProviderError {
+    fn from(err: ClaudeError) -> Self {
+        let message = err.to_string();
+        match err {
+            ClaudeError::BinaryNotFound(_) => ProviderError::NotInstalled(message),
+            ClaudeError::CredentialsNotFound
+            | ClaudeError::TokenExpired(_)
+            | ClaudeError::MissingScope(_)
+            | ClaudeError::AuthenticationFailed(_) => ProviderError::NotLoggedIn(message),
+            ClaudeError::Timeout(_) | ClaudeError::HttpError(_) => ProviderError::Network(message),
+            ClaudeError::ParseError(_) => ProviderError::Parse(message),
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+}
@@ -35,6 +35,11 @@
 //! }
 //! ```
 //!
+//! ## Project Usage
+//!
+//! Tokens per project are summed from Claude Code's session logs in
+//! `~/.claude/projects/`.
+//!
 //! ## Usage
 //!
 //! ```ignore
@@ -52,6 +57,7 @@ mod fetcher;
 mod oauth;
 pub(crate) mod parser;
 mod pty_probe;
+mod sessions;
 mod strategies;
 mod web;
 
@@ -62,6 +68,7 @@ pub use error::ClaudeError;
 pub use fetcher::{ClaudeDataSource, ClaudeUsageFetcher};
 pub use oauth::{ClaudeOAuthCredentials, CredentialSource};
 pub use pty_probe::{ClaudePtyProbe, ClaudeStatusSnapshot, parse_usage_output};
+pub use sessions::{CONTEXT_WINDOW_TOKENS, LiveSession, active_session, read_live_session};
 pub use strategies::{
     ClaudeCliStrategy, ClaudeOAuthStrategy, ClaudePtyStrategy, ClaudeWebStrategy,
 };
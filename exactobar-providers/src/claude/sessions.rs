@@ -0,0 +1,290 @@
+//! Claude Code session logs.
+//!
+//! Claude Code writes each session to
+//! `~/.claude/projects/<project>/<session>.jsonl`. Assistant messages carry
+//! the working directory and the tokens the reply used, so usage can be
+//! attributed per project, and the session being written to can be
+//! followed as it grows.
+
+use chrono::{DateTime, Duration, Utc};
+use exactobar_core::ModelBreakdown;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tracing::{debug, warn};
+
+use crate::projects::{ProjectTotals, ProjectUsage, parse_timestamp, session_logs};
+
+/// A line of a session log. Only assistant messages matter here.
+#[derive(Debug, Deserialize)]
+struct SessionLine {
+    #[serde(default)]
+    cwd: Option<PathBuf>,
+    #[serde(default)]
+    timestamp: Option<String>,
+    #[serde(default)]
+    message: Option<SessionMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionMessage {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    model: Option<String>,
+    #[serde(default)]
+    usage: Option<MessageUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MessageUsage {
+    #[serde(default)]
+    input_tokens: u64,
+    #[serde(default)]
+    output_tokens: u64,
+    #[serde(default)]
+    cache_creation_input_tokens: u64,
+    #[serde(default)]
+    cache_read_input_tokens: u64,
+}
+
+impl MessageUsage {
+    /// Tokens used, leaving out cache reads, which would otherwise drown
+    /// out everything else.
+    fn tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens + self.cache_creation_input_tokens
+    }
+
+    /// Size of the prompt the reply was generated from, cache included.
+    fn context_tokens(&self) -> u64 {
+        self.input_tokens + self.cache_creation_input_tokens + self.cache_read_input_tokens
+    }
+}
+
+// ============================================================================
+// Live Session
+// ============================================================================
+
+/// Context window of Claude models, in tokens.
+pub const CONTEXT_WINDOW_TOKENS: u64 = 200_000;
+
+/// The session Claude Code is writing to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveSession {
+    /// The session's log.
+    pub path: PathBuf,
+    /// When the first message was logged.
+    pub started_at: Option<DateTime<Utc>>,
+    /// Tokens used so far, not counting cache reads.
+    pub tokens: u64,
+    /// Prompt size of the latest reply, i.e. how full the context is.
+    pub context_tokens: u64,
+    /// Input and output tokens per model, for pricing.
+    pub models: Vec<ModelBreakdown>,
+}
+
+impl LiveSession {
+    /// Returns how full the context window is, in percent.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn context_percent(&self) -> f64 {
+        (self.context_tokens as f64 / CONTEXT_WINDOW_TOKENS as f64 * 100.0).min(100.0)
+    }
+}
+
+/// Returns the session log written to most recently, if it was written to
+/// within `idle`.
+pub fn active_session(idle: Duration) -> Option<PathBuf> {
+    let dir = sessions_dir()?;
+    session_logs(&dir, Utc::now() - idle)
+        .into_iter()
+        .filter_map(|path| Some((modified_at(&path)?, path)))
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+/// Reads a session log into its running totals.
+pub fn read_live_session(path: &Path) -> Option<LiveSession> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Some(live_session(path, &content)),
+        Err(e) => {
+            warn!(path = %path.display(), error = %e, "Failed to read session log");
+            None
+        }
+    }
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Sums up a session log's messages.
+fn live_session(path: &Path, content: &str) -> LiveSession {
+    let mut session = LiveSession {
+        path: path.to_path_buf(),
+        started_at: None,
+        tokens: 0,
+        context_tokens: 0,
+        models: Vec::new(),
+    };
+    let mut seen = HashSet::new();
+
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        let Ok(line) = serde_json::from_str::<SessionLine>(line) else {
+            continue;
+        };
+        if session.started_at.is_none() {
+            session.started_at = line.timestamp.as_deref().and_then(parse_timestamp);
+        }
+        let Some(message) = line.message else {
+            continue;
+        };
+        let Some(usage) = message.usage else {
+            continue;
+        };
+        if let Some(id) = message.id
+            && !seen.insert(id)
+        {
+            continue;
+        }
+
+        session.tokens += usage.tokens();
+        session.context_tokens = usage.context_tokens();
+
+        let model_name = message.model.unwrap_or_else(|| "unknown".to_string());
+        let index = session
+            .models
+            .iter()
+            .position(|m| m.model_name == model_name)
+            .unwrap_or_else(|| {
+                session.models.push(ModelBreakdown::new(model_name));
+                session.models.len() - 1
+            });
+        let model = &mut session.models[index];
+        model.requests = Some(model.requests.unwrap_or(0) + 1);
+        model.input_tokens = Some(
+            model.input_tokens.unwrap_or(0)
+                + usage.input_tokens
+                + usage.cache_creation_input_tokens,
+        );
+        model.output_tokens = Some(model.output_tokens.unwrap_or(0) + usage.output_tokens);
+    }
+
+    session
+}
+
+/// Returns the directory Claude Code keeps session logs in.
+fn sessions_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".claude").join("projects"))
+}
+
+/// Sums the tokens used per project since `since`, most first.
+pub fn project_usage(since: DateTime<Utc>) -> Vec<ProjectUsage> {
+    let Some(dir) = sessions_dir() else {
+        return Vec::new();
+    };
+
+    let mut totals = ProjectTotals::default();
+    let mut seen = HashSet::new();
+    for path in session_logs(&dir, since) {
+        match std::fs::read_to_string(&path) {
+            Ok(content) => add_session(&content, since, &mut seen, &mut totals),
+            Err(e) => warn!(path = %path.display(), error = %e, "Failed to read session log"),
+        }
+    }
+
+    let projects = totals.ranked();
+    debug!(count = projects.len(), "Read Claude project usage");
+    projects
+}
+
+/// Adds the tokens in one session log to `totals`. `seen` holds message ids
+/// already counted, since a message is logged once per content block.
+fn add_session(
+    content: &str,
+    since: DateTime<Utc>,
+    seen: &mut HashSet<String>,
+    totals: &mut ProjectTotals,
+) {
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        let Ok(line) = serde_json::from_str::<SessionLine>(line) else {
+            continue;
+        };
+        let (Some(cwd), Some(message)) = (line.cwd, line.message) else {
+            continue;
+        };
+        let Some(usage) = message.usage else {
+            continue;
+        };
+        if line
+            .timestamp
+            .as_deref()
+            .and_then(parse_timestamp)
+            .is_none_or(|at| at < since)
+        {
+            continue;
+        }
+        if let Some(id) = message.id
+            && !seen.insert(id)
+        {
+            continue;
+        }
+        totals.add(&cwd, usage.tokens());
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_add_session() {
+        let content = r#"
+{"type":"user","cwd":"/src/app","timestamp":"2026-03-04T09:00:00Z","message":{"role":"user","content":"hi"}}
+{"type":"assistant","cwd":"/src/app","timestamp":"2026-03-04T09:00:05Z","message":{"id":"msg_1","usage":{"input_tokens":100,"output_tokens":50,"cache_creation_input_tokens":20,"cache_read_input_tokens":9000}}}
+{"type":"assistant","cwd":"/src/app","timestamp":"2026-03-04T09:00:06Z","message":{"id":"msg_1","usage":{"input_tokens":100,"output_tokens":50,"cache_creation_input_tokens":20,"cache_read_input_tokens":9000}}}
+{"type":"assistant","cwd":"/src/lib","timestamp":"2026-03-04T10:00:00Z","message":{"id":"msg_2","usage":{"input_tokens":10,"output_tokens":5}}}
+{"type":"assistant","cwd":"/src/lib","timestamp":"2026-03-03T23:00:00Z","message":{"id":"msg_0","usage":{"input_tokens":999,"output_tokens":999}}}
+not json
+"#;
+        let since = Utc.with_ymd_and_hms(2026, 3, 4, 0, 0, 0).unwrap();
+        let mut totals = ProjectTotals::default();
+        add_session(content, since, &mut HashSet::new(), &mut totals);
+
+        let projects = totals.ranked();
+        assert_eq!(projects.len(), 2);
+        assert_eq!(projects[0].path, PathBuf::from("/src/app"));
+        assert_eq!(projects[0].tokens, 170);
+        assert_eq!(projects[1].tokens, 15);
+    }
+
+    #[test]
+    fn test_live_session() {
+        let content = r#"
+{"type":"user","cwd":"/src/app","timestamp":"2026-03-04T09:00:00Z","message":{"role":"user","content":"hi"}}
+{"type":"assistant","timestamp":"2026-03-04T09:00:05Z","message":{"id":"msg_1","model":"claude-sonnet-4-5","usage":{"input_tokens":100,"output_tokens":50,"cache_creation_input_tokens":20000,"cache_read_input_tokens":0}}}
+{"type":"assistant","timestamp":"2026-03-04T09:00:06Z","message":{"id":"msg_1","model":"claude-sonnet-4-5","usage":{"input_tokens":100,"output_tokens":50,"cache_creation_input_tokens":20000,"cache_read_input_tokens":0}}}
+{"type":"assistant","timestamp":"2026-03-04T09:01:00Z","message":{"id":"msg_2","model":"claude-sonnet-4-5","usage":{"input_tokens":10,"output_tokens":500,"cache_creation_input_tokens":1000,"cache_read_input_tokens":49000}}}
+"#;
+        let session = live_session(Path::new("/tmp/session.jsonl"), content);
+
+        assert_eq!(
+            session.started_at,
+            Some(Utc.with_ymd_and_hms(2026, 3, 4, 9, 0, 0).unwrap())
+        );
+        assert_eq!(session.tokens, 20_150 + 1_510);
+        // The context is the latest prompt, cache reads included
+        assert_eq!(session.context_tokens, 50_010);
+        assert!((session.context_percent() - 25.005).abs() < 1e-9);
+
+        assert_eq!(session.models.len(), 1);
+        assert_eq!(session.models[0].requests, Some(2));
+        assert_eq!(session.models[0].input_tokens, Some(21_110));
+        assert_eq!(session.models[0].output_tokens, Some(550));
+    }
+}
@@ -8,6 +8,7 @@
 //! 4. **Web Strategy** - Browser cookies for claude.ai
 
 use async_trait::async_trait;
+use exactobar_core::{ProviderError, ProviderKind, UsageSnapshot};
 use exactobar_fetch::{
     FetchContext, FetchError, FetchKind, FetchResult, FetchStrategy, host::browser::Browser,
 };
@@ -61,12 +62,11 @@ impl FetchStrategy for ClaudeOAuthStrategy {
         true
     }
 
-    #[instrument(skip(self, _ctx))]
-    async fn fetch(&self, _ctx: &FetchContext) -> Result<FetchResult, FetchError> {
+    #[instrument(skip(self, ctx))]
+    async fn fetch(&self, ctx: &FetchContext) -> Result<FetchResult, FetchError> {
         debug!("Fetching Claude usage via OAuth");
 
-        let credentials = ClaudeOAuthCredentials::load()
-            .map_err(|e| FetchError::AuthenticationFailed(e.to_string()))?;
+        let credentials = ClaudeOAuthCredentials::load().map_err(ProviderError::from)?;
 
         if credentials.is_expired() {
             return Err(FetchError::AuthenticationFailed(
@@ -74,11 +74,13 @@ impl FetchStrategy for ClaudeOAuthStrategy {
             ));
         }
 
-        let client = ClaudeApiClient::new();
+        let client = ctx
+            .base_url(ProviderKind::Claude)
+            .map_or_else(ClaudeApiClient::new, ClaudeApiClient::with_base_url);
         let response = client
             .fetch_usage(&credentials)
             .await
-            .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
+            .map_err(ProviderError::from)?;
 
         // Debug logging to trace data flow
         info!(
@@ -93,7 +95,15 @@ impl FetchStrategy for ClaudeOAuthStrategy {
             snapshot.primary, snapshot.secondary, snapshot.tertiary
         );
 
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(response.raw_body))
+    }
+
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(
+            ClaudeApiClient::parse_usage(raw.to_string())
+                .map(|response| response.to_snapshot())
+                .map_err(|e| ProviderError::from(e).into()),
+        )
     }
 
     fn priority(&self) -> u32 {
@@ -322,7 +332,7 @@ impl FetchStrategy for ClaudeWebStrategy {
         let response = client
             .fetch_usage(&cookie_header, None)
             .await
-            .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
+            .map_err(ProviderError::from)?;
 
         let snapshot = response.to_snapshot();
 
@@ -355,6 +365,18 @@ mod tests {
         assert_eq!(strategy.priority(), 100);
     }
 
+    #[test]
+    fn test_oauth_parse_raw() {
+        let strategy = ClaudeOAuthStrategy::new();
+        let raw = r#"{"five_hour": {"utilization": 6.0, "resets_at": null}, "seven_day": {"utilization": 35.0, "resets_at": null}}"#;
+        let snapshot = strategy.parse_raw(raw).unwrap().unwrap();
+        assert_eq!(snapshot.primary.unwrap().used_percent, 6.0);
+        assert_eq!(snapshot.secondary.unwrap().used_percent, 35.0);
+
+        assert!(strategy.parse_raw("<html>").unwrap().is_err());
+        assert!(ClaudeCliStrategy::new().parse_raw(raw).is_none());
+    }
+
     #[test]
     fn test_pty_strategy_id() {
         let strategy = ClaudePtyStrategy::new();
@@ -145,19 +145,20 @@ impl ClaudeWebClient {
 
         debug!(url = %url, "Fetching usage from web API");
 
-        let client = reqwest::Client::new();
-        let response = client
-            .get(&url)
-            .header("Cookie", cookie_header)
-            .header("Content-Type", "application/json")
-            .header("Accept", "application/json")
-            .header(
-                "User-Agent",
-                "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36",
-            )
-            .send()
-            .await
-            .map_err(|e| ClaudeError::HttpError(e.to_string()))?;
+        let client = exactobar_fetch::network::client();
+        let response = exactobar_fetch::network::send(
+            client
+                .get(&url)
+                .header("Cookie", cookie_header)
+                .header("Content-Type", "application/json")
+                .header("Accept", "application/json")
+                .header(
+                    "User-Agent",
+                    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36",
+                ),
+        )
+        .await
+        .map_err(|e| ClaudeError::HttpError(e.to_string()))?;
 
         let status = response.status();
 
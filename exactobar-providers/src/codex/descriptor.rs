@@ -58,6 +58,7 @@ fn codex_token_cost() -> TokenCostConfig {
     TokenCostConfig {
         supports_token_cost: true,
         log_directory: Some(codex_log_directory),
+        project_usage: Some(super::sessions::project_usage),
     }
 }
 
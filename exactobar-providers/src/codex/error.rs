@@ -1,6 +1,8 @@
 //! Codex-specific error types.
 
 use std::time::Duration;
+
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// Errors specific to Codex operations.
@@ -88,3 +90,19 @@ impl From<exactobar_fetch::PtyError> for CodexError {
         CodexError::PtyError(e.to_string())
     }
 }
+
+impl From<CodexError> for ProviderError {
+    fn from(err: CodexError) -> Self {
+        let message = err.to_string();
+        match err {
+            CodexError::BinaryNotFound(_) => ProviderError::NotInstalled(message),
+            CodexError::AuthNotFound(_) | CodexError::InvalidAuth(_) | CodexError::JwtError(_) => {
+                ProviderError::NotLoggedIn(message)
+            }
+            CodexError::EmptyResponse
+            | CodexError::SerializationError(_)
+            | CodexError::ParseError(_) => ProviderError::Parse(message),
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+}
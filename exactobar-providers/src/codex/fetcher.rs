@@ -98,6 +98,16 @@ impl CodexUsageFetcher {
         }
     }
 
+    /// Fetch usage data via RPC only, returning the rate limits as
+    /// received along with the snapshot.
+    #[instrument(skip(self))]
+    pub async fn fetch_rpc(&self) -> Result<(UsageSnapshot, String), CodexError> {
+        if !Self::is_available() {
+            return Err(CodexError::BinaryNotFound("codex".to_string()));
+        }
+        self.fetch_via_rpc().await
+    }
+
     /// Fetch usage data, trying RPC first then PTY.
     #[instrument(skip(self))]
     pub async fn fetch_usage(&self) -> Result<UsageSnapshot, CodexError> {
@@ -108,7 +118,7 @@ impl CodexUsageFetcher {
         // Try RPC first
         if !self.skip_rpc {
             match self.fetch_via_rpc().await {
-                Ok(snapshot) => {
+                Ok((snapshot, _)) => {
                     info!(source = "rpc", "Fetched usage via RPC");
                     return Ok(snapshot);
                 }
@@ -150,14 +160,14 @@ impl CodexUsageFetcher {
 
     /// Fetch using JSON-RPC to app-server.
     #[instrument(skip(self))]
-    async fn fetch_via_rpc(&self) -> Result<UsageSnapshot, CodexError> {
+    async fn fetch_via_rpc(&self) -> Result<(UsageSnapshot, String), CodexError> {
         debug!("Attempting RPC fetch");
 
         // Spawn app-server (this is blocking, so wrap in spawn_blocking)
         let result = tokio::task::spawn_blocking(|| {
             let mut client = CodexRpcClient::spawn()?;
             client.initialize()?;
-            let limits = client.fetch_rate_limits()?;
+            let limits = client.fetch_rate_limits_raw()?;
             let account = client.fetch_account().ok();
             client.shutdown();
             Ok::<_, CodexError>((limits, account))
@@ -165,11 +175,8 @@ impl CodexUsageFetcher {
         .await
         .map_err(|e| CodexError::SpawnFailed(format!("Task join error: {}", e)))??;
 
-        let (limits, rpc_account) = result;
-
-        // Convert to UsageSnapshot
-        let mut snapshot = convert_rpc_to_snapshot(limits);
-        snapshot.fetch_source = FetchSource::CLI;
+        let (raw, rpc_account) = result;
+        let mut snapshot = parse_rate_limits(&raw)?;
 
         // Try to get account info from auth.json (more reliable than RPC)
         let auth_account = auth::try_read_account_info();
@@ -200,7 +207,7 @@ impl CodexUsageFetcher {
 
         snapshot.identity = Some(identity);
 
-        Ok(snapshot)
+        Ok((snapshot, raw))
     }
 
     /// Fetch using PTY with /status command.
@@ -226,6 +233,13 @@ impl CodexUsageFetcher {
 // Conversion Functions
 // ============================================================================
 
+/// Parses rate limits as returned by the app-server into a snapshot.
+pub fn parse_rate_limits(raw: &str) -> Result<UsageSnapshot, CodexError> {
+    let mut snapshot = convert_rpc_to_snapshot(RateLimitsResult::parse(raw)?);
+    snapshot.fetch_source = FetchSource::CLI;
+    Ok(snapshot)
+}
+
 /// Convert RPC rate limits to UsageSnapshot.
 fn convert_rpc_to_snapshot(limits: RateLimitsResult) -> UsageSnapshot {
     let mut snapshot = UsageSnapshot::new();
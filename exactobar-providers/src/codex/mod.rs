@@ -26,6 +26,11 @@
 //! - Reads `~/.codex/auth.json` for account info
 //! - Extracts email and plan from JWT tokens
 //!
+//! ## Project Usage
+//!
+//! Tokens per project are summed from the session logs in
+//! `~/.codex/sessions/`.
+//!
 //! ## Usage
 //!
 //! ```ignore
@@ -44,6 +49,7 @@ mod fetcher;
 pub(crate) mod parser;
 mod pty_probe;
 mod rpc;
+mod sessions;
 mod strategies;
 
 // Re-exports
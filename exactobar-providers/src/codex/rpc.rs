@@ -148,6 +148,13 @@ pub struct RateLimitsResult {
     pub rate_limits: RateLimits,
 }
 
+impl RateLimitsResult {
+    /// Parses the result of an account/rateLimits/read request.
+    pub fn parse(raw: &str) -> Result<Self, CodexError> {
+        Ok(serde_json::from_str(raw)?)
+    }
+}
+
 /// Rate limits container.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -300,6 +307,19 @@ impl CodexRpcClient {
         self.call("account/rateLimits/read", EmptyParams {})
     }
 
+    /// Fetch rate limits from the server as received, for
+    /// [`RateLimitsResult::parse`].
+    #[instrument(skip(self))]
+    pub fn fetch_rate_limits_raw(&mut self) -> Result<String, CodexError> {
+        if !self.initialized {
+            return Err(CodexError::NotInitialized);
+        }
+
+        debug!("Fetching rate limits");
+        let result: serde_json::Value = self.call("account/rateLimits/read", EmptyParams {})?;
+        Ok(result.to_string())
+    }
+
     /// Fetch account information from the server.
     #[instrument(skip(self))]
     pub fn fetch_account(&mut self) -> Result<AccountResult, CodexError> {
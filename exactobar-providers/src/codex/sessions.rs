@@ -0,0 +1,144 @@
+//! Codex CLI session logs.
+//!
+//! Codex writes each session to
+//! `~/.codex/sessions/YYYY/MM/DD/rollout-*.jsonl`. The session's working
+//! directory is logged when it starts (and again when it changes), and a
+//! `token_count` event follows each model request, so requests and tokens
+//! can be attributed per project.
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::path::PathBuf;
+use tracing::{debug, warn};
+
+use crate::projects::{ProjectTotals, ProjectUsage, parse_timestamp, session_logs};
+
+/// A line of a session log.
+#[derive(Debug, Deserialize)]
+struct SessionLine {
+    #[serde(default)]
+    timestamp: Option<String>,
+    #[serde(rename = "type", default)]
+    kind: String,
+    #[serde(default)]
+    payload: Option<serde_json::Value>,
+}
+
+/// Token usage of one turn.
+#[derive(Debug, Deserialize)]
+struct TurnUsage {
+    #[serde(default)]
+    input_tokens: u64,
+    #[serde(default)]
+    cached_input_tokens: u64,
+    #[serde(default)]
+    output_tokens: u64,
+}
+
+impl TurnUsage {
+    /// Tokens used, leaving out cached input.
+    fn tokens(&self) -> u64 {
+        self.input_tokens.saturating_sub(self.cached_input_tokens) + self.output_tokens
+    }
+}
+
+/// Returns the directory Codex keeps session logs in.
+fn sessions_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".codex").join("sessions"))
+}
+
+/// Sums the tokens used per project since `since`, most first.
+pub fn project_usage(since: DateTime<Utc>) -> Vec<ProjectUsage> {
+    let Some(dir) = sessions_dir() else {
+        return Vec::new();
+    };
+
+    let mut totals = ProjectTotals::default();
+    for path in session_logs(&dir, since) {
+        match std::fs::read_to_string(&path) {
+            Ok(content) => add_session(&content, since, &mut totals),
+            Err(e) => warn!(path = %path.display(), error = %e, "Failed to read session log"),
+        }
+    }
+
+    let projects = totals.ranked();
+    debug!(count = projects.len(), "Read Codex project usage");
+    projects
+}
+
+/// Adds the tokens in one session log to `totals`.
+fn add_session(content: &str, since: DateTime<Utc>, totals: &mut ProjectTotals) {
+    let mut cwd: Option<PathBuf> = None;
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        let Ok(line) = serde_json::from_str::<SessionLine>(line) else {
+            continue;
+        };
+        let Some(payload) = line.payload else {
+            continue;
+        };
+
+        match line.kind.as_str() {
+            "session_meta" | "turn_context" => {
+                if let Some(dir) = payload.get("cwd").and_then(|v| v.as_str()) {
+                    cwd = Some(PathBuf::from(dir));
+                }
+            }
+            "event_msg" if payload.get("type").and_then(|v| v.as_str()) == Some("token_count") => {
+                let Some(dir) = cwd.as_ref() else {
+                    continue;
+                };
+                if line
+                    .timestamp
+                    .as_deref()
+                    .and_then(parse_timestamp)
+                    .is_none_or(|at| at < since)
+                {
+                    continue;
+                }
+                let usage = payload
+                    .pointer("/info/last_token_usage")
+                    .cloned()
+                    .and_then(|v| serde_json::from_value::<TurnUsage>(v).ok());
+                if let Some(usage) = usage {
+                    totals.add(dir, usage.tokens());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_add_session() {
+        let content = r#"
+{"timestamp":"2026-03-03T23:50:00Z","type":"session_meta","payload":{"id":"abc","cwd":"/src/app"}}
+{"timestamp":"2026-03-03T23:55:00Z","type":"event_msg","payload":{"type":"token_count","info":{"last_token_usage":{"input_tokens":5000,"output_tokens":5000}}}}
+{"timestamp":"2026-03-04T00:05:00Z","type":"event_msg","payload":{"type":"token_count","info":{"last_token_usage":{"input_tokens":1200,"cached_input_tokens":1000,"output_tokens":300,"total_tokens":1500}}}}
+{"timestamp":"2026-03-04T00:06:00Z","type":"event_msg","payload":{"type":"token_count","info":null}}
+{"timestamp":"2026-03-04T00:10:00Z","type":"turn_context","payload":{"cwd":"/src/lib","model":"gpt-5"}}
+{"timestamp":"2026-03-04T00:15:00Z","type":"event_msg","payload":{"type":"token_count","info":{"last_token_usage":{"input_tokens":40,"output_tokens":10}}}}
+{"timestamp":"2026-03-04T00:16:00Z","type":"event_msg","payload":{"type":"agent_message","message":"done"}}
+"#;
+        let since = Utc.with_ymd_and_hms(2026, 3, 4, 0, 0, 0).unwrap();
+        let mut totals = ProjectTotals::default();
+        add_session(content, since, &mut totals);
+
+        let projects = totals.ranked();
+        assert_eq!(projects.len(), 2);
+        assert_eq!(projects[0].path, PathBuf::from("/src/app"));
+        assert_eq!(projects[0].tokens, 500);
+        assert_eq!(projects[0].requests, 1);
+        assert_eq!(projects[1].path, PathBuf::from("/src/lib"));
+        assert_eq!(projects[1].tokens, 50);
+        assert_eq!(projects[1].requests, 1);
+    }
+}
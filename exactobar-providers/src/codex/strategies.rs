@@ -8,14 +8,14 @@
 //! 4. **API Strategy** - OpenAI API with API key
 
 use async_trait::async_trait;
-use exactobar_core::{FetchSource, UsageSnapshot};
+use exactobar_core::{FetchSource, ProviderError, UsageSnapshot};
 use exactobar_fetch::{
     FetchContext, FetchError, FetchKind, FetchResult, FetchStrategy,
     host::keychain::{accounts, services},
 };
 use tracing::{debug, instrument, warn};
 
-use super::fetcher::CodexUsageFetcher;
+use super::fetcher::{CodexUsageFetcher, parse_rate_limits};
 use super::parser::parse_codex_cli_output;
 use super::pty_probe::CodexPtyProbe;
 
@@ -62,13 +62,17 @@ impl FetchStrategy for CodexRpcStrategy {
         debug!("Fetching Codex usage via RPC");
 
         let fetcher = CodexUsageFetcher::rpc_only();
-        let snapshot = fetcher.fetch_usage().await.map_err(|e| {
+        let (snapshot, raw) = fetcher.fetch_rpc().await.map_err(|e| {
             FetchError::Process(exactobar_fetch::ProcessError::ExecutionFailed(
                 e.to_string(),
             ))
         })?;
 
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(Some(raw)))
+    }
+
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(parse_rate_limits(raw).map_err(|e| ProviderError::from(e).into()))
     }
 
     fn priority(&self) -> u32 {
@@ -294,16 +298,20 @@ impl FetchStrategy for CodexApiStrategy {
                 "API key validation failed".to_string(),
             ));
         }
-
-        // We can verify the key is valid but can't get usage from this endpoint
-        // Return minimal snapshot indicating connection works
-        let mut snapshot = UsageSnapshot::new();
-        snapshot.fetch_source = FetchSource::Api;
+        let body = response
+            .text()
+            .await
+            .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
+        let snapshot = parse_models(&body)?;
 
         // Note: Real usage would require dashboard scraping or different auth
         warn!("OpenAI API key validated but usage data requires dashboard access");
 
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(Some(body)))
+    }
+
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(parse_models(raw))
     }
 
     fn priority(&self) -> u32 {
@@ -316,6 +324,22 @@ impl FetchStrategy for CodexApiStrategy {
     }
 }
 
+/// Checks a `/models` response. The key is valid but this endpoint has no
+/// usage, so the snapshot only shows that the connection works.
+fn parse_models(raw: &str) -> Result<UsageSnapshot, FetchError> {
+    let models: serde_json::Value =
+        serde_json::from_str(raw).map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
+    if !models.get("data").is_some_and(serde_json::Value::is_array) {
+        return Err(FetchError::InvalidResponse(
+            "Model list is missing".to_string(),
+        ));
+    }
+
+    let mut snapshot = UsageSnapshot::new();
+    snapshot.fetch_source = FetchSource::Api;
+    Ok(snapshot)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -368,4 +392,25 @@ mod tests {
         assert!(pty > cli);
         assert!(cli > api);
     }
+
+    #[test]
+    fn test_rpc_parse_raw() {
+        let raw =
+            r#"{"rateLimits": {"primary": {"usedPercent": 28.5, "windowDurationMins": 300}}}"#;
+        let snapshot = CodexRpcStrategy::new().parse_raw(raw).unwrap().unwrap();
+        assert_eq!(snapshot.primary.unwrap().used_percent, 28.5);
+        assert_eq!(snapshot.fetch_source, FetchSource::CLI);
+
+        let error = CodexRpcStrategy::new().parse_raw("{").unwrap().unwrap_err();
+        assert!(matches!(error.classify(), ProviderError::Parse(_)));
+    }
+
+    #[test]
+    fn test_api_parse_raw() {
+        let raw = r#"{"object": "list", "data": [{"id": "gpt-4o"}]}"#;
+        let snapshot = CodexApiStrategy::new().parse_raw(raw).unwrap().unwrap();
+        assert_eq!(snapshot.fetch_source, FetchSource::Api);
+
+        assert!(CodexApiStrategy::new().parse_raw("{}").unwrap().is_err());
+    }
 }
@@ -223,7 +223,7 @@ pub struct CopilotApiClient {
 impl CopilotApiClient {
     /// Creates a new Copilot API client.
     pub fn new() -> Self {
-        let http = reqwest::Client::builder()
+        let http = exactobar_fetch::network::client_builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
             .expect("Failed to build HTTP client");
@@ -263,7 +263,7 @@ impl CopilotApiClient {
         let url = format!("{}{}", GITHUB_API_BASE, USER_ENDPOINT);
         let headers = self.build_headers(token)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
@@ -294,7 +294,7 @@ impl CopilotApiClient {
         let url = format!("{}{}", GITHUB_API_BASE, COPILOT_SUBSCRIPTION_ENDPOINT);
         let headers = self.build_headers(token)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
@@ -330,7 +330,7 @@ impl CopilotApiClient {
         let url = format!("{}{}", GITHUB_API_BASE, COPILOT_USAGE_ENDPOINT);
         let headers = self.build_headers(token)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
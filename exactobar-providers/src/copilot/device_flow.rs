@@ -130,7 +130,7 @@ pub struct CopilotDeviceFlow {
 impl CopilotDeviceFlow {
     /// Creates a new device flow handler.
     pub fn new() -> Self {
-        let http = reqwest::Client::builder()
+        let http = exactobar_fetch::network::client_builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
             .expect("Failed to build HTTP client");
@@ -143,7 +143,7 @@ impl CopilotDeviceFlow {
 
     /// Creates a device flow with a custom client ID.
     pub fn with_client_id(client_id: impl Into<String>) -> Self {
-        let http = reqwest::Client::builder()
+        let http = exactobar_fetch::network::client_builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
             .expect("Failed to build HTTP client");
@@ -175,13 +175,13 @@ impl CopilotDeviceFlow {
 
         let body = format!("client_id={}&scope={}", self.client_id, COPILOT_SCOPES);
 
-        let response = self
-            .http
-            .post(DEVICE_CODE_URL)
-            .headers(Self::build_headers())
-            .body(body)
-            .send()
-            .await?;
+        let response = exactobar_fetch::network::send(
+            self.http
+                .post(DEVICE_CODE_URL)
+                .headers(Self::build_headers())
+                .body(body),
+        )
+        .await?;
 
         let status = response.status();
 
@@ -224,13 +224,13 @@ impl CopilotDeviceFlow {
             self.client_id, device_code
         );
 
-        let response = self
-            .http
-            .post(ACCESS_TOKEN_URL)
-            .headers(Self::build_headers())
-            .body(body)
-            .send()
-            .await?;
+        let response = exactobar_fetch::network::send(
+            self.http
+                .post(ACCESS_TOKEN_URL)
+                .headers(Self::build_headers())
+                .body(body),
+        )
+        .await?;
 
         let body = response.text().await?;
 
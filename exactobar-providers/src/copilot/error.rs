@@ -1,5 +1,6 @@
 //! Copilot-specific errors.
 
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// Copilot-specific errors.
@@ -65,3 +66,20 @@ impl From<reqwest::Error> for CopilotError {
         }
     }
 }
+
+impl From<CopilotError> for ProviderError {
+    fn from(err: CopilotError) -> Self {
+        let message = err.to_string();
+        match err {
+            CopilotError::AuthenticationFailed(_)
+            | CopilotError::NoToken
+            | CopilotError::TokenExpired(_)
+            | CopilotError::DeviceFlowFailed(_)
+            | CopilotError::DeviceFlowExpired => ProviderError::NotLoggedIn(message),
+            CopilotError::HttpError(_) => ProviderError::Network(message),
+            CopilotError::RateLimited(_) => ProviderError::RateLimited(message),
+            CopilotError::InvalidResponse(_) => ProviderError::Parse(message),
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+}
@@ -36,6 +36,9 @@
 //! - `GET /user` - Get user info
 //! - `GET /user/copilot_billing/seat` - Get Copilot subscription status
 //! - `GET /user/copilot_billing/usage` - Get usage statistics
+//! - `GET /copilot_internal/user` - Premium request allowance and overage
+//! - `GET /users/{login}/settings/billing/premium_request/usage` - Premium
+//!   requests per model
 
 // Modules
 mod api;
@@ -44,6 +47,7 @@ mod device_flow;
 mod error;
 mod fetcher;
 pub(crate) mod parser;
+mod premium;
 mod strategies;
 mod token_store;
 
@@ -53,5 +57,6 @@ pub use descriptor::copilot_descriptor;
 pub use device_flow::{AccessTokenResponse, CopilotDeviceFlow, DeviceFlowResult, DeviceFlowStart};
 pub use error::CopilotError;
 pub use fetcher::{CopilotDataSource, CopilotUsageFetcher};
+pub use premium::{PremiumQuota, PremiumResponses, PremiumUsage};
 pub use strategies::{CopilotApiStrategy, CopilotEnvStrategy};
 pub use token_store::CopilotTokenStore;
@@ -0,0 +1,420 @@
+//! Copilot premium requests.
+//!
+//! Chat with models other than the base one spends premium requests from a
+//! monthly allowance. Two GitHub endpoints report them:
+//!
+//! - `GET /copilot_internal/user` - the allowance, what's left of it, and
+//!   whether requests past it are billed as overage (the endpoint the
+//!   Copilot editor extensions use)
+//! - `GET /users/{login}/settings/billing/premium_request/usage` - this
+//!   month's premium requests per model, with what they cost
+//!
+//! The allowance becomes the tertiary ("Premium") window and the billing
+//! report the per-model breakdown. Both are best effort: tokens without
+//! access just don't get them.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use exactobar_core::{ModelBreakdown, ProviderCost, UsageSnapshot, UsageWindow};
+use exactobar_fetch::FetchContext;
+use reqwest::header::{ACCEPT, AUTHORIZATION, HeaderMap, HeaderValue, USER_AGENT};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Premium request allowance endpoint.
+const PREMIUM_QUOTA_ENDPOINT: &str = "/copilot_internal/user";
+
+/// Editor the allowance endpoint expects requests from.
+const EDITOR_VERSION: &str = "vscode/1.99.0";
+
+/// Copilot Chat version the allowance endpoint expects.
+const EDITOR_PLUGIN_VERSION: &str = "copilot-chat/0.26.0";
+
+/// GitHub API version header for the billing endpoint.
+const GITHUB_API_VERSION: &str = "2022-11-28";
+
+// ============================================================================
+// API Response Types
+// ============================================================================
+
+/// Response from the allowance endpoint.
+#[derive(Debug, Deserialize)]
+struct CopilotUserResponse {
+    #[serde(default)]
+    login: Option<String>,
+    /// First day of the next allowance, e.g. "2026-04-01".
+    #[serde(default)]
+    quota_reset_date: Option<String>,
+    #[serde(default)]
+    quota_snapshots: Option<QuotaSnapshots>,
+}
+
+#[derive(Debug, Deserialize)]
+struct QuotaSnapshots {
+    #[serde(default)]
+    premium_interactions: Option<QuotaSnapshot>,
+}
+
+#[derive(Debug, Deserialize)]
+struct QuotaSnapshot {
+    #[serde(default)]
+    entitlement: f64,
+    #[serde(default)]
+    remaining: f64,
+    #[serde(default)]
+    unlimited: bool,
+    #[serde(default)]
+    overage_permitted: bool,
+    #[serde(default)]
+    overage_count: f64,
+}
+
+/// Response from the billing endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PremiumUsageResponse {
+    #[serde(default)]
+    usage_items: Vec<PremiumUsageItem>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PremiumUsageItem {
+    #[serde(default)]
+    model: Option<String>,
+    #[serde(default)]
+    gross_quantity: f64,
+    #[serde(default)]
+    net_amount: f64,
+}
+
+// ============================================================================
+// Premium Usage
+// ============================================================================
+
+/// The premium request allowance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PremiumQuota {
+    /// Premium requests included per month.
+    pub entitlement: f64,
+    /// Premium requests left this month.
+    pub remaining: f64,
+    /// Whether premium requests aren't limited.
+    pub unlimited: bool,
+    /// Whether requests past the allowance are billed instead of refused.
+    pub overage_permitted: bool,
+    /// Requests billed past the allowance this month.
+    pub overage_count: f64,
+    /// When the allowance renews.
+    pub resets_at: Option<DateTime<Utc>>,
+}
+
+impl PremiumQuota {
+    /// Returns the premium requests used this month.
+    pub fn used(&self) -> f64 {
+        (self.entitlement - self.remaining).max(0.0) + self.overage_count
+    }
+
+    /// Returns the allowance as a usage window, `None` if it's unlimited.
+    pub fn to_window(&self) -> Option<UsageWindow> {
+        if self.unlimited || self.entitlement <= 0.0 {
+            return None;
+        }
+
+        let used_percent = (self.used() / self.entitlement * 100.0).min(100.0);
+        let mut description = format!("{:.0} of {:.0} requests", self.used(), self.entitlement);
+        if self.overage_count > 0.0 {
+            let _ = write!(description, " · {:.0} over", self.overage_count);
+        } else if !self.overage_permitted && used_percent >= 100.0 {
+            description.push_str(" · overage off");
+        }
+
+        Some(UsageWindow {
+            used_percent,
+            window_minutes: None,
+            resets_at: self.resets_at,
+            reset_description: Some(description),
+        })
+    }
+}
+
+/// The premium request response bodies, kept for the debug view and
+/// replay.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PremiumResponses {
+    /// Body of the allowance response.
+    #[serde(default)]
+    pub quota: Option<String>,
+    /// Body of the billing response.
+    #[serde(default)]
+    pub billing: Option<String>,
+}
+
+/// Premium requests: the allowance and this month's use per model.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PremiumUsage {
+    /// The allowance, if the token can read it.
+    pub quota: Option<PremiumQuota>,
+    /// Premium requests per model, most first.
+    pub models: Vec<ModelBreakdown>,
+    /// What the premium requests were billed this month, in USD.
+    pub billed_usd: Option<f64>,
+}
+
+impl PremiumUsage {
+    /// Fetches the responses the token can read; failures are logged and
+    /// left out.
+    pub async fn fetch(ctx: &FetchContext, api_base: &str, token: &str) -> PremiumResponses {
+        let url = format!("{}{}", api_base, PREMIUM_QUOTA_ENDPOINT);
+        let quota = get(ctx, &url, quota_headers(token)).await;
+
+        let login = quota
+            .as_deref()
+            .and_then(parse_quota)
+            .and_then(|(_, login)| login);
+        let billing = match login {
+            Some(login) => {
+                let url = format!(
+                    "{}/users/{}/settings/billing/premium_request/usage",
+                    api_base, login
+                );
+                get(ctx, &url, billing_headers(token)).await
+            }
+            None => None,
+        };
+
+        PremiumResponses { quota, billing }
+    }
+
+    /// Parses the fetched responses; invalid ones are logged and left out.
+    pub fn parse(responses: &PremiumResponses) -> Self {
+        let mut usage = Self::default();
+        if let Some((quota, _)) = responses.quota.as_deref().and_then(parse_quota) {
+            usage.quota = quota;
+        }
+        if let Some((models, billed)) = responses.billing.as_deref().and_then(parse_billing) {
+            usage.models = models;
+            usage.billed_usd = Some(billed);
+        }
+        usage
+    }
+
+    /// Returns true if nothing was read.
+    pub fn is_empty(&self) -> bool {
+        self.quota.is_none() && self.models.is_empty()
+    }
+
+    /// Adds the premium window, per-model breakdown and billed amount to
+    /// `snapshot`.
+    pub fn apply(self, snapshot: &mut UsageSnapshot) {
+        if let Some(window) = self.quota.as_ref().and_then(PremiumQuota::to_window) {
+            snapshot.tertiary = Some(window);
+        }
+        if !self.models.is_empty() {
+            snapshot.model_breakdown = self.models;
+        }
+        if let Some(billed) = self.billed_usd.filter(|_| snapshot.cost.is_none()) {
+            let mut cost = ProviderCost::new(billed);
+            cost.currency_code = Some("USD".to_string());
+            cost.period = Some("monthly".to_string());
+            snapshot.cost = Some(cost);
+        }
+    }
+}
+
+// ============================================================================
+// Requests
+// ============================================================================
+
+async fn get(ctx: &FetchContext, url: &str, headers: Option<HeaderMap>) -> Option<String> {
+    let response = match ctx.http.get_with_headers(url, headers?).await {
+        Ok(response) => response,
+        Err(e) => {
+            debug!(url, error = %e, "Copilot premium request failed");
+            return None;
+        }
+    };
+
+    if !response.status().is_success() {
+        debug!(url, status = %response.status(), "Copilot premium data not available");
+        return None;
+    }
+
+    response.text().await.ok()
+}
+
+fn quota_headers(token: &str) -> Option<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        AUTHORIZATION,
+        HeaderValue::from_str(&format!("token {}", token)).ok()?,
+    );
+    headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+    headers.insert(USER_AGENT, HeaderValue::from_static("ExactoBar/1.0"));
+    headers.insert("Editor-Version", HeaderValue::from_static(EDITOR_VERSION));
+    headers.insert(
+        "Editor-Plugin-Version",
+        HeaderValue::from_static(EDITOR_PLUGIN_VERSION),
+    );
+    Some(headers)
+}
+
+fn billing_headers(token: &str) -> Option<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        AUTHORIZATION,
+        HeaderValue::from_str(&format!("Bearer {}", token)).ok()?,
+    );
+    headers.insert(
+        ACCEPT,
+        HeaderValue::from_static("application/vnd.github+json"),
+    );
+    headers.insert(USER_AGENT, HeaderValue::from_static("ExactoBar/1.0"));
+    headers.insert(
+        "X-GitHub-Api-Version",
+        HeaderValue::from_static(GITHUB_API_VERSION),
+    );
+    Some(headers)
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+/// Parses the allowance response into the allowance, if there is one, and
+/// the user's login.
+fn parse_quota(body: &str) -> Option<(Option<PremiumQuota>, Option<String>)> {
+    let response: CopilotUserResponse = serde_json::from_str(body)
+        .map_err(|e| debug!(error = %e, "Invalid Copilot allowance response"))
+        .ok()?;
+
+    let resets_at = response
+        .quota_reset_date
+        .as_deref()
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|at| at.and_utc());
+
+    let quota = response
+        .quota_snapshots
+        .and_then(|s| s.premium_interactions)
+        .map(|snapshot| PremiumQuota {
+            entitlement: snapshot.entitlement,
+            remaining: snapshot.remaining,
+            unlimited: snapshot.unlimited,
+            overage_permitted: snapshot.overage_permitted,
+            overage_count: snapshot.overage_count,
+            resets_at,
+        });
+
+    Some((quota, response.login))
+}
+
+/// Parses the billing report into premium requests per model, most first,
+/// and the total billed.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn parse_billing(body: &str) -> Option<(Vec<ModelBreakdown>, f64)> {
+    let response: PremiumUsageResponse = serde_json::from_str(body)
+        .map_err(|e| debug!(error = %e, "Invalid Copilot premium usage response"))
+        .ok()?;
+
+    let mut models: Vec<ModelBreakdown> = Vec::new();
+    let mut billed = 0.0;
+    for item in response.usage_items {
+        billed += item.net_amount;
+        let name = item.model.unwrap_or_else(|| "Other".to_string());
+        let index = models
+            .iter()
+            .position(|m| m.model_name == name)
+            .unwrap_or_else(|| {
+                models.push(ModelBreakdown::new(name));
+                models.len() - 1
+            });
+        let model = &mut models[index];
+        model.requests = Some(model.requests.unwrap_or(0) + item.gross_quantity.round() as u64);
+        model.cost_usd = Some(model.cost_usd.unwrap_or(0.0) + item.net_amount);
+    }
+    models.retain(|m| m.requests.unwrap_or(0) > 0);
+    models.sort_by_key(|m| std::cmp::Reverse(m.requests));
+
+    Some((models, billed))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_quota() {
+        let body = r#"{
+            "login": "octocat",
+            "copilot_plan": "individual",
+            "quota_reset_date": "2026-04-01",
+            "quota_snapshots": {
+                "chat": {"entitlement": 0, "remaining": 0, "unlimited": true},
+                "premium_interactions": {
+                    "entitlement": 300,
+                    "remaining": 0,
+                    "percent_remaining": 0.0,
+                    "unlimited": false,
+                    "overage_permitted": true,
+                    "overage_count": 12
+                }
+            }
+        }"#;
+
+        let (quota, login) = parse_quota(body).unwrap();
+        let quota = quota.unwrap();
+        assert_eq!(login.as_deref(), Some("octocat"));
+        assert!((quota.used() - 312.0).abs() < 1e-9);
+
+        let window = quota.to_window().unwrap();
+        assert!((window.used_percent - 100.0).abs() < 1e-9);
+        assert_eq!(
+            window.resets_at,
+            Some(Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            window.reset_description.as_deref(),
+            Some("312 of 300 requests · 12 over")
+        );
+
+        // Unlimited allowances have no window
+        let unlimited = PremiumQuota {
+            unlimited: true,
+            ..quota
+        };
+        assert!(unlimited.to_window().is_none());
+    }
+
+    #[test]
+    fn test_parse_billing() {
+        let body = r#"{
+            "timePeriod": {"year": 2026, "month": 3},
+            "user": "octocat",
+            "usageItems": [
+                {"product": "Copilot", "sku": "Copilot Premium Request", "model": "Claude Sonnet 4", "unitType": "requests", "pricePerUnit": 0.04, "grossQuantity": 40, "grossAmount": 1.6, "discountQuantity": 40, "discountAmount": 1.6, "netQuantity": 0, "netAmount": 0},
+                {"product": "Copilot", "sku": "Copilot Premium Request", "model": "GPT-4.5", "unitType": "requests", "pricePerUnit": 0.04, "grossQuantity": 50, "grossAmount": 2.0, "discountQuantity": 38, "discountAmount": 1.52, "netQuantity": 12, "netAmount": 0.48},
+                {"product": "Copilot", "sku": "Copilot Premium Request", "model": "Claude Sonnet 4", "unitType": "requests", "pricePerUnit": 0.04, "grossQuantity": 20, "grossAmount": 0.8, "discountQuantity": 20, "discountAmount": 0.8, "netQuantity": 0, "netAmount": 0}
+            ]
+        }"#;
+
+        let (models, billed) = parse_billing(body).unwrap();
+        assert!((billed - 0.48).abs() < 1e-9);
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[0].model_name, "Claude Sonnet 4");
+        assert_eq!(models[0].requests, Some(60));
+        assert_eq!(models[1].requests, Some(50));
+        assert!((models[1].cost_usd.unwrap() - 0.48).abs() < 1e-9);
+    }
+}
@@ -1,18 +1,121 @@
 //! Copilot fetch strategies.
 
 use async_trait::async_trait;
-#[allow(unused_imports)]
 use exactobar_core::{FetchSource, UsageSnapshot};
 use exactobar_fetch::{
     FetchContext, FetchError, FetchKind, FetchResult, FetchStrategy,
     host::keychain::{accounts, services},
 };
+use serde::{Deserialize, Serialize};
 use tracing::{debug, instrument};
 
 use super::parser::parse_copilot_response;
+use super::premium::{PremiumResponses, PremiumUsage};
 
 const COPILOT_API_BASE: &str = "https://api.github.com";
 
+/// The response bodies a Copilot fetch reads, one per endpoint.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CopilotResponses {
+    /// Body of the completion usage response.
+    #[serde(default)]
+    usage: Option<String>,
+    #[serde(flatten)]
+    premium: PremiumResponses,
+}
+
+impl CopilotResponses {
+    /// Parses the raw response of a fetch.
+    fn parse(raw: &str) -> Result<UsageSnapshot, FetchError> {
+        let responses: Self = serde_json::from_str(raw)?;
+        responses.to_snapshot()
+    }
+
+    fn to_snapshot(&self) -> Result<UsageSnapshot, FetchError> {
+        let premium = PremiumUsage::parse(&self.premium);
+        let usage = match self.usage.as_deref() {
+            Some(body) => parse_copilot_response(body),
+            None => Err(FetchError::InvalidResponse(
+                "Copilot usage not available".to_string(),
+            )),
+        };
+
+        let mut snapshot = match usage {
+            Ok(snapshot) => snapshot,
+            // Premium requests alone are worth showing
+            Err(e) if !premium.is_empty() => {
+                debug!(error = %e, "Copilot usage unavailable, showing premium requests");
+                let mut snapshot = UsageSnapshot::new();
+                snapshot.fetch_source = FetchSource::OAuth;
+                snapshot
+            }
+            Err(e) => return Err(e),
+        };
+
+        premium.apply(&mut snapshot);
+        Ok(snapshot)
+    }
+}
+
+/// Fetches usage with a GitHub token, adding premium requests when the
+/// token can read them. Returns the snapshot and the response bodies.
+async fn fetch_with_token(
+    ctx: &FetchContext,
+    api_base: &str,
+    token: &str,
+) -> Result<(UsageSnapshot, String), FetchError> {
+    let premium = PremiumUsage::fetch(ctx, api_base, token).await;
+
+    let usage = match fetch_completions(ctx, api_base, token).await {
+        Ok(body) => Some(body),
+        Err(e) if !PremiumUsage::parse(&premium).is_empty() => {
+            debug!(error = %e, "Copilot usage request failed");
+            None
+        }
+        Err(e) => return Err(e),
+    };
+
+    let responses = CopilotResponses { usage, premium };
+    let snapshot = responses.to_snapshot()?;
+    let raw = serde_json::to_string(&responses)
+        .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
+    Ok((snapshot, raw))
+}
+
+/// Fetches the completion usage response body.
+async fn fetch_completions(
+    ctx: &FetchContext,
+    api_base: &str,
+    token: &str,
+) -> Result<String, FetchError> {
+    let url = format!("{}/copilot/usage", api_base);
+    let auth_header = format!("Bearer {}", token);
+
+    let response = ctx
+        .http
+        .get_with_auth(&url, &auth_header)
+        .await
+        .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
+
+    if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(FetchError::AuthenticationFailed(
+            "Token rejected".to_string(),
+        ));
+    }
+
+    if !response.status().is_success() {
+        return Err(FetchError::InvalidResponse(format!(
+            "API returned {}",
+            response.status()
+        )));
+    }
+
+    response
+        .text()
+        .await
+        .map_err(|e| FetchError::InvalidResponse(e.to_string()))
+}
+
 // ============================================================================
 // API Strategy (Device Flow OAuth)
 // ============================================================================
@@ -81,35 +184,12 @@ impl FetchStrategy for CopilotApiStrategy {
             .await
             .ok_or_else(|| FetchError::AuthenticationFailed("No GitHub token".to_string()))?;
 
-        let url = format!("{}/copilot/usage", self.api_base);
-        let auth_header = format!("Bearer {}", token);
-
-        let response = ctx
-            .http
-            .get_with_auth(&url, &auth_header)
-            .await
-            .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
-
-        if response.status() == reqwest::StatusCode::UNAUTHORIZED {
-            return Err(FetchError::AuthenticationFailed(
-                "Token rejected".to_string(),
-            ));
-        }
-
-        if !response.status().is_success() {
-            return Err(FetchError::InvalidResponse(format!(
-                "API returned {}",
-                response.status()
-            )));
-        }
-
-        let body = response
-            .text()
-            .await
-            .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
+        let (snapshot, raw) = fetch_with_token(ctx, self.api_base, &token).await?;
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(Some(raw)))
+    }
 
-        let snapshot = parse_copilot_response(&body)?;
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(CopilotResponses::parse(raw))
     }
 
     fn priority(&self) -> u32 {
@@ -163,29 +243,12 @@ impl FetchStrategy for CopilotEnvStrategy {
         let token = Self::get_env_token()
             .ok_or_else(|| FetchError::AuthenticationFailed("No env token".to_string()))?;
 
-        let url = format!("{}/copilot/usage", COPILOT_API_BASE);
-        let auth_header = format!("Bearer {}", token);
-
-        let response = ctx
-            .http
-            .get_with_auth(&url, &auth_header)
-            .await
-            .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
-
-        if !response.status().is_success() {
-            return Err(FetchError::InvalidResponse(format!(
-                "API returned {}",
-                response.status()
-            )));
-        }
-
-        let body = response
-            .text()
-            .await
-            .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
+        let (snapshot, raw) = fetch_with_token(ctx, COPILOT_API_BASE, &token).await?;
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(Some(raw)))
+    }
 
-        let snapshot = parse_copilot_response(&body)?;
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(CopilotResponses::parse(raw))
     }
 
     fn priority(&self) -> u32 {
@@ -196,6 +259,7 @@ impl FetchStrategy for CopilotEnvStrategy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use exactobar_core::ProviderError;
 
     #[test]
     fn test_api_strategy() {
@@ -210,4 +274,31 @@ mod tests {
         assert_eq!(s.id(), "copilot.env");
         assert_eq!(s.priority(), 60);
     }
+
+    #[test]
+    fn test_parse_raw() {
+        let raw = serde_json::json!({
+            "usage": r#"{"completions": {"acceptance_rate": 0.25}, "user": {"login": "octocat"}}"#,
+            "quota": r#"{"login": "octocat", "quota_snapshots": {"premium_interactions": {"entitlement": 300, "remaining": 150}}}"#,
+        })
+        .to_string();
+        let snapshot = CopilotApiStrategy::new().parse_raw(&raw).unwrap().unwrap();
+        assert_eq!(snapshot.primary.unwrap().used_percent, 25.0);
+        assert_eq!(snapshot.tertiary.unwrap().used_percent, 50.0);
+
+        // Premium requests alone are still a snapshot
+        let raw = serde_json::json!({
+            "quota": r#"{"quota_snapshots": {"premium_interactions": {"entitlement": 300, "remaining": 300}}}"#,
+        })
+        .to_string();
+        let snapshot = CopilotEnvStrategy::new().parse_raw(&raw).unwrap().unwrap();
+        assert!(snapshot.primary.is_none());
+        assert_eq!(snapshot.tertiary.unwrap().used_percent, 0.0);
+
+        let error = CopilotApiStrategy::new()
+            .parse_raw("{}")
+            .unwrap()
+            .unwrap_err();
+        assert!(matches!(error.classify(), ProviderError::Parse(_)));
+    }
 }
@@ -242,6 +242,7 @@ impl CopilotTokenStore {
         entry
             .set_password(token)
             .map_err(|e| CopilotError::KeychainError(e.to_string()))?;
+        exactobar_fetch::host::keychain::invalidate_cache_entry(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT);
 
         debug!("Token saved to keychain");
         Ok(())
@@ -311,6 +312,7 @@ impl CopilotTokenStore {
         entry
             .delete_credential()
             .map_err(|e| CopilotError::KeychainError(e.to_string()))?;
+        exactobar_fetch::host::keychain::invalidate_cache_entry(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT);
 
         // Also delete from our cache
         if let Ok(cache_entry) = keyring::Entry::new(OUR_COPILOT_CACHE_SERVICE, "token") {
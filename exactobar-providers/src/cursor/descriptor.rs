@@ -56,6 +56,7 @@ fn cursor_token_cost() -> TokenCostConfig {
     TokenCostConfig {
         supports_token_cost: false, // Cursor uses credits, not tokens
         log_directory: None,
+        project_usage: None,
     }
 }
 
@@ -1,5 +1,6 @@
 //! Cursor-specific errors.
 
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// Cursor-specific errors.
@@ -57,3 +58,21 @@ impl From<reqwest::Error> for CursorError {
         }
     }
 }
+
+impl From<CursorError> for ProviderError {
+    fn from(err: CursorError) -> Self {
+        let message = err.to_string();
+        match err {
+            CursorError::ConfigNotFound(_) => ProviderError::NotInstalled(message),
+            CursorError::AuthenticationFailed(_) | CursorError::NoSessionCookie => {
+                ProviderError::NotLoggedIn(message)
+            }
+            CursorError::HttpError(_) => ProviderError::Network(message),
+            CursorError::RateLimited(_) => ProviderError::RateLimited(message),
+            CursorError::InvalidResponse(_) | CursorError::ConfigParseError(_) => {
+                ProviderError::Parse(message)
+            }
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+}
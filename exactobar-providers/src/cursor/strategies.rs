@@ -1,7 +1,7 @@
 //! Cursor fetch strategies.
 
 use async_trait::async_trait;
-use exactobar_core::{FetchSource, UsageSnapshot};
+use exactobar_core::{FetchSource, ProviderError, UsageSnapshot};
 use exactobar_fetch::{
     FetchContext, FetchError, FetchKind, FetchResult, FetchStrategy, host::browser::Browser,
 };
@@ -87,11 +87,19 @@ impl FetchStrategy for CursorWebStrategy {
         let response = client
             .fetch_usage(&cookie_header)
             .await
-            .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
+            .map_err(ProviderError::from)?;
 
         let snapshot = response.to_snapshot();
 
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(response.raw_body))
+    }
+
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(
+            CursorWebClient::parse_usage(raw.to_string())
+                .map(|response| response.to_snapshot())
+                .map_err(|e| ProviderError::from(e).into()),
+        )
     }
 
     fn priority(&self) -> u32 {
@@ -183,6 +191,19 @@ mod tests {
         assert_eq!(strategy.priority(), 100);
     }
 
+    #[test]
+    fn test_web_parse_raw() {
+        let raw = r#"{"numRequests": 100, "maxRequests": 500, "plan": "pro"}"#;
+        let snapshot = CursorWebStrategy::new().parse_raw(raw).unwrap().unwrap();
+        assert_eq!(snapshot.primary.unwrap().used_percent, 20.0);
+
+        let error = CursorWebStrategy::new()
+            .parse_raw("{")
+            .unwrap()
+            .unwrap_err();
+        assert!(matches!(error.classify(), ProviderError::Parse(_)));
+    }
+
     #[test]
     fn test_local_strategy_id() {
         let strategy = CursorLocalStrategy::new();
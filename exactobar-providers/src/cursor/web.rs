@@ -86,6 +86,10 @@ pub struct CursorUsageResponse {
     /// User email.
     #[serde(default)]
     pub email: Option<String>,
+
+    /// Raw response body (for the debug view).
+    #[serde(skip)]
+    pub raw_body: Option<String>,
 }
 
 impl CursorUsageResponse {
@@ -209,7 +213,7 @@ pub struct CursorWebClient {
 impl CursorWebClient {
     /// Creates a new Cursor web client.
     pub fn new() -> Self {
-        let http = reqwest::Client::builder()
+        let http = exactobar_fetch::network::client_builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
             .expect("Failed to build HTTP client");
@@ -239,7 +243,7 @@ impl CursorWebClient {
         let url = format!("{}{}", CURSOR_API_BASE, USAGE_ENDPOINT);
         let headers = self.build_headers(cookie_header)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
@@ -264,11 +268,16 @@ impl CursorWebClient {
         let body = response.text().await?;
         debug!(len = body.len(), "Got usage response");
 
-        let usage: CursorUsageResponse = serde_json::from_str(&body).map_err(|e| {
+        Self::parse_usage(body)
+    }
+
+    /// Parses a usage response body, keeping the body for the debug view.
+    pub fn parse_usage(body: String) -> Result<CursorUsageResponse, CursorError> {
+        let mut usage: CursorUsageResponse = serde_json::from_str(&body).map_err(|e| {
             warn!(error = %e, body = %body, "Failed to parse usage response");
             CursorError::InvalidResponse(format!("JSON parse error: {}", e))
         })?;
-
+        usage.raw_body = Some(body);
         Ok(usage)
     }
 
@@ -280,7 +289,7 @@ impl CursorWebClient {
         let url = format!("{}{}", CURSOR_API_BASE, AUTH_ME_ENDPOINT);
         let headers = self.build_headers(cookie_header)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
@@ -401,6 +410,7 @@ mod tests {
             monthly_cost_usd: None,
             plan: Some("pro".to_string()),
             email: Some("user@example.com".to_string()),
+            raw_body: None,
         };
 
         let snapshot = response.to_snapshot();
@@ -434,6 +444,7 @@ mod tests {
             monthly_cost_usd: None,
             plan: None,
             email: None,
+            raw_body: None,
         };
 
         let reset = response.get_reset_time();
@@ -0,0 +1,89 @@
+//! Demo provider descriptor.
+
+use exactobar_core::{IconStyle, ProviderBranding, ProviderColor, ProviderKind, ProviderMetadata};
+use exactobar_fetch::{FetchContext, FetchPipeline, SourceMode};
+
+use super::strategies::DemoStrategy;
+use crate::descriptor::{CliConfig, FetchPlan, ProviderDescriptor, TokenCostConfig};
+
+// ============================================================================
+// Descriptor
+// ============================================================================
+
+/// Creates the demo provider descriptor.
+pub fn demo_descriptor() -> ProviderDescriptor {
+    ProviderDescriptor {
+        id: ProviderKind::Demo,
+        metadata: demo_metadata(),
+        branding: demo_branding(),
+        token_cost: TokenCostConfig::default(),
+        fetch_plan: demo_fetch_plan(),
+        cli: demo_cli_config(),
+    }
+}
+
+// ============================================================================
+// Metadata
+// ============================================================================
+
+fn demo_metadata() -> ProviderMetadata {
+    ProviderMetadata {
+        id: ProviderKind::Demo,
+        display_name: "Demo".to_string(),
+        session_label: "Session".to_string(),
+        weekly_label: "Weekly".to_string(),
+        opus_label: Some("Premium".to_string()),
+        supports_opus: true,
+        supports_credits: false,
+        credits_hint: String::new(),
+        toggle_title: "Show demo usage".to_string(),
+        cli_name: "demo".to_string(),
+        default_enabled: false,
+        is_primary_provider: false,
+        uses_account_fallback: false,
+        dashboard_url: None,
+        subscription_dashboard_url: None,
+        status_page_url: None,
+        status_link_url: None,
+    }
+}
+
+// ============================================================================
+// Branding
+// ============================================================================
+
+fn demo_branding() -> ProviderBranding {
+    ProviderBranding {
+        icon_style: IconStyle::Demo,
+        icon_resource_name: "icon_demo".to_string(),
+        color: ProviderColor::new(0.55, 0.55, 0.6),
+    }
+}
+
+// ============================================================================
+// Fetch Plan
+// ============================================================================
+
+fn demo_fetch_plan() -> FetchPlan {
+    FetchPlan {
+        source_modes: vec![SourceMode::Auto],
+        build_pipeline: build_demo_pipeline,
+    }
+}
+
+fn build_demo_pipeline(_ctx: &FetchContext) -> FetchPipeline {
+    FetchPipeline::with_strategies(vec![Box::new(DemoStrategy::new())])
+}
+
+// ============================================================================
+// CLI Config
+// ============================================================================
+
+fn demo_cli_config() -> CliConfig {
+    CliConfig {
+        name: "demo",
+        aliases: &[],
+        version_args: &[],
+        usage_args: &[],
+    }
+}
@@ -0,0 +1,14 @@
+//! Demo provider implementation.
+//!
+//! The demo provider needs no account. Each refresh moves it one step
+//! through a fixed script of usage levels, fetch errors, and status page
+//! states, so UI work, screenshots, and demos can show every state of a
+//! card without waiting for a real provider to get there.
+
+mod descriptor;
+mod scenario;
+mod strategies;
+
+pub use descriptor::demo_descriptor;
+pub use scenario::{DEMO_CYCLE, current_step, demo_snapshot, demo_status};
+pub use strategies::DemoStrategy;
@@ -0,0 +1,223 @@
+//! The demo provider's script.
+//!
+//! Everything here is derived from the step number alone, apart from reset
+//! times, which are placed relative to the time of the fetch so they are
+//! always in the future.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use chrono::{DateTime, Duration, Utc};
+use exactobar_core::{
+    FetchSource, LoginMethod, ModelBreakdown, ProviderCost, ProviderError, ProviderIdentity,
+    ProviderKind, ProviderStatus, StatusIncident, StatusIndicator, UsageSnapshot, UsageWindow,
+};
+
+/// Number of steps before the usage sweep starts over.
+pub const DEMO_CYCLE: u64 = 12;
+
+/// Session usage at each step of the cycle, in percent.
+const SESSION_PERCENTS: [f64; 12] = [
+    0.0, 12.0, 25.0, 38.0, 50.0, 63.0, 75.0, 84.0, 92.0, 97.0, 100.0, 100.0,
+];
+
+/// Every step this far apart fails instead of returning usage.
+const ERROR_EVERY: u64 = 8;
+
+/// Status page states, each shown for [`STATUS_STEPS`] steps.
+const STATUSES: [(StatusIndicator, &str); 6] = [
+    (StatusIndicator::None, "All systems operational"),
+    (StatusIndicator::None, "All systems operational"),
+    (
+        StatusIndicator::Minor,
+        "Elevated error rates on the demo API",
+    ),
+    (StatusIndicator::Major, "Partial outage of the demo API"),
+    (StatusIndicator::Critical, "Demo API unavailable"),
+    (StatusIndicator::Maintenance, "Scheduled demo maintenance"),
+];
+
+/// How many steps each status lasts.
+const STATUS_STEPS: u64 = 3;
+
+/// Length of the session window, in minutes.
+const SESSION_MINUTES: u32 = 300;
+
+/// Length of the weekly window, in minutes.
+const WEEKLY_MINUTES: u32 = 7 * 24 * 60;
+
+/// Monthly spend limit, in USD.
+const SPEND_LIMIT: f64 = 100.0;
+
+/// Step the next fetch reports.
+static NEXT_STEP: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the step for the next fetch and moves the script along.
+pub(super) fn next_step() -> u64 {
+    NEXT_STEP.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Returns the step the last fetch reported.
+pub fn current_step() -> u64 {
+    NEXT_STEP.load(Ordering::Relaxed).saturating_sub(1)
+}
+
+/// Returns the snapshot the demo provider reports at `step`, or the error
+/// it fails with.
+pub fn demo_snapshot(step: u64, now: DateTime<Utc>) -> Result<UsageSnapshot, ProviderError> {
+    if step % ERROR_EVERY == ERROR_EVERY - 1 {
+        // Neither error pauses refreshes or asks to sign in again
+        return Err(if (step / ERROR_EVERY) % 2 == 0 {
+            ProviderError::Network("Demo connection reset".to_string())
+        } else {
+            ProviderError::RateLimited("Demo rate limit, try again shortly".to_string())
+        });
+    }
+
+    let position = step % DEMO_CYCLE;
+    let session = SESSION_PERCENTS[position as usize];
+    let weekly = 30.0 + position as f64 * 5.0;
+
+    let mut snapshot = UsageSnapshot::new();
+    snapshot.updated_at = now;
+    snapshot.fetch_source = FetchSource::LocalProbe;
+    snapshot.primary = Some(window(
+        session,
+        SESSION_MINUTES,
+        now + Duration::minutes(((DEMO_CYCLE - position) * 25) as i64),
+    ));
+    snapshot.secondary = Some(window(
+        weekly,
+        WEEKLY_MINUTES,
+        now + Duration::hours((7 * 24 - position * 12) as i64),
+    ));
+    // The premium window comes and goes, like an add-on plan
+    if position % 2 == 0 {
+        snapshot.tertiary = Some(window(
+            weekly / 2.0,
+            WEEKLY_MINUTES,
+            now + Duration::hours((7 * 24 - position * 12) as i64),
+        ));
+    }
+
+    let mut identity = ProviderIdentity::new(ProviderKind::Demo);
+    identity.account_email = Some("demo@example.com".to_string());
+    identity.plan_name = Some("Demo Pro".to_string());
+    identity.login_method = Some(LoginMethod::ApiKey);
+    snapshot.identity = Some(identity);
+
+    snapshot.cost = Some(ProviderCost {
+        used: SPEND_LIMIT * weekly / 100.0,
+        limit: Some(SPEND_LIMIT),
+        currency_code: Some("USD".to_string()),
+        period: Some("monthly".to_string()),
+    });
+    snapshot.model_breakdown = vec![
+        model(
+            "demo-large",
+            40_000 * (position + 1),
+            8_000 * (position + 1),
+        ),
+        model(
+            "demo-small",
+            90_000 * (position + 1),
+            12_000 * (position + 1),
+        ),
+    ];
+
+    Ok(snapshot)
+}
+
+/// Returns the status page state the demo provider shows at `step`.
+pub fn demo_status(step: u64) -> ProviderStatus {
+    let (indicator, description) = STATUSES[((step / STATUS_STEPS) as usize) % STATUSES.len()];
+    let mut status = ProviderStatus::new(indicator, description);
+    status.url = Some("https://example.com/status".to_string());
+    if status.has_issues() {
+        status.incidents.push(StatusIncident {
+            name: description.to_string(),
+            status: "investigating".to_string(),
+            started_at: None,
+            components: vec!["Demo API".to_string()],
+            latest_update: Some("We're looking into it.".to_string()),
+            latest_update_at: None,
+        });
+    }
+    status
+}
+
+fn window(used_percent: f64, minutes: u32, resets_at: DateTime<Utc>) -> UsageWindow {
+    UsageWindow {
+        used_percent,
+        window_minutes: Some(minutes),
+        resets_at: Some(resets_at),
+        reset_description: None,
+    }
+}
+
+fn model(name: &str, input_tokens: u64, output_tokens: u64) -> ModelBreakdown {
+    let mut breakdown = ModelBreakdown::new(name);
+    breakdown.input_tokens = Some(input_tokens);
+    breakdown.output_tokens = Some(output_tokens);
+    breakdown
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo_snapshot_is_deterministic() {
+        let now = Utc::now();
+        assert_eq!(
+            demo_snapshot(5, now).unwrap(),
+            demo_snapshot(5, now).unwrap()
+        );
+
+        let snapshot = demo_snapshot(DEMO_CYCLE + 2, now).unwrap();
+        assert_eq!(snapshot.primary.as_ref().unwrap().used_percent, 25.0);
+        assert!(snapshot.tertiary.is_some());
+        assert!(snapshot.validate().is_ok());
+        assert!(snapshot.primary.unwrap().resets_at.unwrap() > now);
+    }
+
+    #[test]
+    fn test_demo_sweep_reaches_the_limit() {
+        let now = Utc::now();
+        let peak = (0..DEMO_CYCLE)
+            .filter_map(|step| demo_snapshot(step, now).ok())
+            .map(|snapshot| snapshot.max_usage_percent())
+            .fold(0.0, f64::max);
+        assert_eq!(peak, 100.0);
+    }
+
+    #[test]
+    fn test_demo_errors() {
+        let now = Utc::now();
+        assert!(matches!(
+            demo_snapshot(ERROR_EVERY - 1, now),
+            Err(ProviderError::Network(_))
+        ));
+        assert!(matches!(
+            demo_snapshot(2 * ERROR_EVERY - 1, now),
+            Err(ProviderError::RateLimited(_))
+        ));
+    }
+
+    #[test]
+    fn test_demo_status_cycles() {
+        assert!(demo_status(0).is_operational());
+        assert_eq!(
+            demo_status(2 * STATUS_STEPS).indicator,
+            StatusIndicator::Minor
+        );
+        assert_eq!(
+            demo_status(4 * STATUS_STEPS).indicator,
+            StatusIndicator::Critical
+        );
+        assert!(demo_status(STATUSES.len() as u64 * STATUS_STEPS).is_operational());
+    }
+}
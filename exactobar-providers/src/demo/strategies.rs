@@ -0,0 +1,75 @@
+//! Demo fetch strategies.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use exactobar_fetch::{FetchContext, FetchError, FetchKind, FetchResult, FetchStrategy};
+use tracing::{debug, instrument};
+
+use super::scenario::{demo_snapshot, next_step};
+
+// ============================================================================
+// Demo Strategy
+// ============================================================================
+
+/// Strategy that reports the next step of the demo script.
+///
+/// Always available; it reads nothing and makes no requests.
+pub struct DemoStrategy;
+
+impl DemoStrategy {
+    /// Creates a new strategy.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for DemoStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl FetchStrategy for DemoStrategy {
+    fn id(&self) -> &str {
+        "demo.generated"
+    }
+
+    fn kind(&self) -> FetchKind {
+        FetchKind::LocalProbe
+    }
+
+    async fn is_available(&self, _ctx: &FetchContext) -> bool {
+        true
+    }
+
+    #[instrument(skip(self, _ctx))]
+    async fn fetch(&self, _ctx: &FetchContext) -> Result<FetchResult, FetchError> {
+        let step = next_step();
+        debug!(step, "Generating demo usage");
+
+        let snapshot = demo_snapshot(step, Utc::now())?;
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+    }
+
+    fn priority(&self) -> u32 {
+        100
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo_strategy() {
+        let s = DemoStrategy::new();
+        assert_eq!(s.id(), "demo.generated");
+        assert_eq!(s.kind(), FetchKind::LocalProbe);
+        assert_eq!(s.priority(), 100);
+    }
+}
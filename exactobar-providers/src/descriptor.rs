@@ -7,6 +7,7 @@
 //! - Fetch plan (how to get usage data)
 //! - CLI configuration
 
+use chrono::{DateTime, Utc};
 use exactobar_core::{ProviderBranding, ProviderKind, ProviderMetadata};
 use exactobar_fetch::{FetchContext, FetchPipeline, SourceMode};
 use std::path::PathBuf;
@@ -66,6 +67,9 @@ pub struct TokenCostConfig {
     pub supports_token_cost: bool,
     /// Function to get the log directory for this provider.
     pub log_directory: Option<fn() -> Option<PathBuf>>,
+    /// Function to sum the tokens used per project directory since a time,
+    /// from local session logs.
+    pub project_usage: Option<fn(DateTime<Utc>) -> Vec<ProjectUsage>>,
 }
 
 impl Default for TokenCostConfig {
@@ -73,10 +77,33 @@ impl Default for TokenCostConfig {
         Self {
             supports_token_cost: false,
             log_directory: None,
+            project_usage: None,
         }
     }
 }
 
+/// Tokens used in one project directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectUsage {
+    /// Directory the sessions ran in.
+    pub path: PathBuf,
+    /// Tokens used, not counting cached input.
+    pub tokens: u64,
+    /// Model requests made.
+    pub requests: u64,
+}
+
+impl ProjectUsage {
+    /// Returns the directory's name, e.g. "exactobar" for
+    /// `/Users/me/src/exactobar`.
+    pub fn name(&self) -> String {
+        self.path.file_name().map_or_else(
+            || self.path.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        )
+    }
+}
+
 // ============================================================================
 // Fetch Plan
 // ============================================================================
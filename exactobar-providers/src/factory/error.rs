@@ -1,5 +1,6 @@
 //! Factory-specific errors.
 
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// Factory-specific errors.
@@ -47,3 +48,18 @@ impl From<reqwest::Error> for FactoryError {
         FactoryError::HttpError(err.to_string())
     }
 }
+
+impl From<FactoryError> for ProviderError {
+    fn from(err: FactoryError) -> Self {
+        let message = err.to_string();
+        match err {
+            FactoryError::ConfigNotFound(_) => ProviderError::NotInstalled(message),
+            FactoryError::AuthenticationFailed(_)
+            | FactoryError::NoSessionCookie
+            | FactoryError::NoWorkOSToken => ProviderError::NotLoggedIn(message),
+            FactoryError::HttpError(_) => ProviderError::Network(message),
+            FactoryError::InvalidResponse(_) => ProviderError::Parse(message),
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+}
@@ -96,7 +96,11 @@ impl FetchStrategy for FactoryWebStrategy {
             .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
 
         let snapshot = parse_factory_response(&body)?;
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(Some(body)))
+    }
+
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(parse_factory_response(raw))
     }
 
     fn priority(&self) -> u32 {
@@ -163,6 +167,7 @@ impl FetchStrategy for FactoryLocalStrategy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use exactobar_core::ProviderError;
 
     #[test]
     fn test_web_strategy() {
@@ -177,4 +182,17 @@ mod tests {
         assert_eq!(s.id(), "factory.local");
         assert_eq!(s.priority(), 60);
     }
+
+    #[test]
+    fn test_parse_raw() {
+        let raw = r#"{"usage": {"session_percent": 30.0}}"#;
+        let snapshot = FactoryWebStrategy::new().parse_raw(raw).unwrap().unwrap();
+        assert_eq!(snapshot.primary.unwrap().used_percent, 30.0);
+
+        let error = FactoryWebStrategy::new()
+            .parse_raw("{")
+            .unwrap()
+            .unwrap_err();
+        assert!(matches!(error.classify(), ProviderError::Parse(_)));
+    }
 }
@@ -167,7 +167,7 @@ pub struct FactoryWebClient {
 impl FactoryWebClient {
     /// Creates a new Factory web client.
     pub fn new() -> Self {
-        let http = reqwest::Client::builder()
+        let http = exactobar_fetch::network::client_builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
             .expect("Failed to build HTTP client");
@@ -237,7 +237,7 @@ impl FactoryWebClient {
         let url = format!("{}{}", FACTORY_API_BASE, USAGE_ENDPOINT);
         let headers = self.build_headers(auth, is_bearer)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
@@ -272,7 +272,7 @@ impl FactoryWebClient {
         let url = format!("{}{}", FACTORY_API_BASE, USER_ENDPOINT);
         let headers = self.build_headers(auth, is_bearer)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
@@ -210,7 +210,7 @@ pub struct GeminiApiClient {
 impl GeminiApiClient {
     /// Creates a new Gemini API client.
     pub fn new() -> Self {
-        let http = reqwest::Client::builder()
+        let http = exactobar_fetch::network::client_builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
             .expect("Failed to build HTTP client");
@@ -243,7 +243,7 @@ impl GeminiApiClient {
         let url = format!("{}/v1beta/models", GEMINI_API_BASE);
         let headers = self.build_headers(token)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
@@ -284,7 +284,7 @@ impl GeminiApiClient {
         let url = format!("{}/v1beta/models", GEMINI_API_BASE);
         let headers = self.build_headers(token)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         // Check for rate limit headers
         if let Some(rpm) = response
@@ -1,5 +1,6 @@
 //! Gemini-specific errors.
 
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// Gemini-specific errors.
@@ -85,3 +86,27 @@ impl From<reqwest::Error> for GeminiError {
         }
     }
 }
+
+impl From<GeminiError> for ProviderError {
+    fn from(err: GeminiError) -> Self {
+        let message = err.to_string();
+        match err {
+            GeminiError::GcloudNotFound | GeminiError::CliNotFound => {
+                ProviderError::NotInstalled(message)
+            }
+            GeminiError::AuthenticationFailed(_)
+            | GeminiError::NoCredentials
+            | GeminiError::NotLoggedIn
+            | GeminiError::UnsupportedAuthType(_)
+            | GeminiError::TokenExpired(_)
+            | GeminiError::RefreshFailed(_)
+            | GeminiError::AdcNotFound => ProviderError::NotLoggedIn(message),
+            GeminiError::HttpError(_) | GeminiError::Timeout => ProviderError::Network(message),
+            GeminiError::RateLimited(_) => ProviderError::RateLimited(message),
+            GeminiError::InvalidResponse(_) | GeminiError::CredentialsParseError(_) => {
+                ProviderError::Parse(message)
+            }
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+}
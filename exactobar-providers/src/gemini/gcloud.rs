@@ -266,7 +266,7 @@ impl GcloudCredentials {
     async fn refresh_token(&self, adc: &AdcCredentials) -> Result<GcloudToken, GeminiError> {
         debug!("Refreshing access token");
 
-        let client = reqwest::Client::new();
+        let client = exactobar_fetch::network::client();
 
         let params = [
             ("client_id", adc.client_id.as_str()),
@@ -275,7 +275,8 @@ impl GcloudCredentials {
             ("grant_type", "refresh_token"),
         ];
 
-        let response = client.post(GOOGLE_TOKEN_URL).form(&params).send().await?;
+        let response =
+            exactobar_fetch::network::send(client.post(GOOGLE_TOKEN_URL).form(&params)).await?;
 
         if !response.status().is_success() {
             let body = response.text().await.unwrap_or_default();
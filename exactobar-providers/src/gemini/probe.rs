@@ -9,6 +9,19 @@
 //! - `~/.gemini/oauth_creds.json` - OAuth credentials (access/refresh tokens)
 //! - `~/.gemini/settings.json` - Auth type settings
 //!
+//! ## Quota Flow
+//!
+//! 1. `loadCodeAssist` returns the user's Code Assist project and tier
+//! 2. `retrieveUserQuota` returns a bucket per model with the fraction of
+//!    the daily request limit left and when it resets
+//!
+//! ## Token Refresh
+//!
+//! The Gemini CLI doesn't store its OAuth client in `oauth_creds.json`, so
+//! refreshing falls back to the client in the installed CLI's `oauth2.js`.
+//! Refreshed tokens are kept in memory until they expire rather than
+//! written back, so the CLI's own file is never touched.
+//!
 //! ## Auth Types
 //!
 //! The Gemini CLI supports multiple auth types:
@@ -17,8 +30,9 @@
 //! - `vertex-ai` - Vertex AI (not supported for quota fetch)
 
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::Mutex;
 use tracing::{debug, info, warn};
 
 use super::error::GeminiError;
@@ -160,15 +174,71 @@ impl GeminiCredentials {
     pub fn has_access_token(&self) -> bool {
         self.access_token.as_ref().is_some_and(|t| !t.is_empty())
     }
+
+    /// Returns the OAuth client ID and secret to refresh with: the ones in
+    /// the credentials file, or else the installed Gemini CLI's.
+    fn oauth_client(&self) -> Option<(String, String)> {
+        if let (Some(id), Some(secret)) = (&self.client_id, &self.client_secret) {
+            return Some((id.clone(), secret.clone()));
+        }
+        let path = cli_oauth_script()?;
+        debug!(path = %path.display(), "Reading OAuth client from Gemini CLI");
+        parse_oauth_client(&std::fs::read_to_string(path).ok()?)
+    }
+}
+
+/// Where the Gemini CLI's OAuth client is defined, relative to the package
+/// the `gemini` binary belongs to.
+const OAUTH_SCRIPT: &str = "node_modules/@google/gemini-cli-core/dist/src/code_assist/oauth2.js";
+
+/// Finds `oauth2.js` in the installed Gemini CLI, looking up from the
+/// resolved `gemini` binary (npm and Homebrew both symlink it).
+fn cli_oauth_script() -> Option<PathBuf> {
+    let binary = which::which("gemini").ok()?;
+    let binary = std::fs::canonicalize(&binary).unwrap_or(binary);
+    binary
+        .ancestors()
+        .skip(1)
+        .flat_map(|dir| {
+            [
+                dir.join(OAUTH_SCRIPT),
+                dir.join("lib/node_modules/@google/gemini-cli")
+                    .join(OAUTH_SCRIPT),
+            ]
+        })
+        .find(|path| path.exists())
+}
+
+/// Reads `OAUTH_CLIENT_ID` and `OAUTH_CLIENT_SECRET` from the CLI's
+/// `oauth2.js`.
+fn parse_oauth_client(script: &str) -> Option<(String, String)> {
+    let constant = |name: &str| {
+        let start = script.find(&format!("{name} = "))? + name.len() + 3;
+        let rest = &script[start..];
+        let quote = rest.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+        let value = &rest[1..];
+        Some(value[..value.find(quote)?].to_string())
+    };
+    Some((
+        constant("OAUTH_CLIENT_ID")?,
+        constant("OAUTH_CLIENT_SECRET")?,
+    ))
 }
 
 // ============================================================================
 // API Endpoints
 // ============================================================================
 
+/// Cloud Code Private API endpoint for the user's project and tier.
+const LOAD_CODE_ASSIST_ENDPOINT: &str =
+    "https://cloudcode-pa.googleapis.com/v1internal:loadCodeAssist";
+
 /// Cloud Code Private API endpoint for quota retrieval.
 const QUOTA_ENDPOINT: &str = "https://cloudcode-pa.googleapis.com/v1internal:retrieveUserQuota";
 
+/// Refresh tokens this long before they expire.
+const EXPIRY_MARGIN_MS: i64 = 60_000;
+
 /// Google OAuth token refresh endpoint.
 const TOKEN_REFRESH_ENDPOINT: &str = "https://oauth2.googleapis.com/token";
 
@@ -176,14 +246,43 @@ const TOKEN_REFRESH_ENDPOINT: &str = "https://oauth2.googleapis.com/token";
 // API Response Types
 // ============================================================================
 
-/// Response from the quota endpoint.
+/// Response from the loadCodeAssist endpoint.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LoadCodeAssistResponse {
+    /// Project the user's Code Assist requests are billed to.
+    cloudaicompanion_project: Option<String>,
+    /// The user's tier, e.g. "Gemini Code Assist for individuals".
+    current_tier: Option<CodeAssistTier>,
+}
+
 #[derive(Debug, Deserialize)]
+struct CodeAssistTier {
+    name: Option<String>,
+}
+
+/// Response from the quota endpoint.
+#[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct QuotaResponse {
-    /// List of model quota usages.
+    /// Daily request limits per model.
+    buckets: Option<Vec<QuotaBucket>>,
+    /// List of model quota usages (older response format).
     model_quota_usages: Option<Vec<ModelQuotaUsage>>,
 }
 
+/// The daily request limit of one model.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QuotaBucket {
+    /// Model identifier (e.g., "gemini-2.5-pro").
+    model_id: Option<String>,
+    /// Fraction of the limit left (0-1).
+    remaining_fraction: Option<f64>,
+    /// When the quota resets (RFC3339 timestamp).
+    reset_time: Option<String>,
+}
+
 /// Individual model quota usage.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -198,14 +297,86 @@ struct ModelQuotaUsage {
     requests_reset_time: Option<String>,
 }
 
+/// The response bodies a quota fetch reads, kept for the debug view and
+/// replay.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QuotaResponses {
+    load_code_assist: String,
+    quota: String,
+}
+
 /// Token refresh response.
 #[derive(Debug, Deserialize)]
 struct TokenRefreshResponse {
     /// The new access token.
     access_token: String,
     /// Token expiry in seconds (optional).
-    #[allow(dead_code)]
-    expires_in: Option<u64>,
+    expires_in: Option<i64>,
+}
+
+/// A refreshed access token and its expiry in Unix milliseconds, with the
+/// refresh token it came from.
+struct RefreshedToken {
+    refresh_token: String,
+    access_token: String,
+    expiry_ms: i64,
+}
+
+/// The last refreshed token, reused until it expires.
+static REFRESHED_TOKEN: Mutex<Option<RefreshedToken>> = Mutex::new(None);
+
+/// Parses the quota response into per-model quotas.
+///
+/// Models with several buckets report the one with the least left.
+fn parse_quotas(response: QuotaResponse) -> Vec<GeminiModelQuota> {
+    let parse_time = |s: Option<String>| {
+        s.and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|dt| dt.with_timezone(&Utc))
+    };
+
+    let mut quotas: Vec<GeminiModelQuota> = Vec::new();
+    for bucket in response.buckets.unwrap_or_default() {
+        let (Some(model_id), Some(fraction)) = (bucket.model_id, bucket.remaining_fraction) else {
+            continue;
+        };
+        let quota = GeminiModelQuota {
+            model_id,
+            percent_left: (fraction * 100.0).clamp(0.0, 100.0),
+            reset_time: parse_time(bucket.reset_time),
+        };
+        match quotas.iter_mut().find(|q| q.model_id == quota.model_id) {
+            Some(existing) if quota.percent_left < existing.percent_left => *existing = quota,
+            Some(_) => {}
+            None => quotas.push(quota),
+        }
+    }
+    if !quotas.is_empty() {
+        return quotas;
+    }
+
+    response
+        .model_quota_usages
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|m| {
+            let model_id = m.model_id?;
+            let remaining = m.remaining_requests.unwrap_or(0) as f64;
+            let limit = m.request_limit.unwrap_or(1) as f64;
+
+            let percent_left = if limit > 0.0 {
+                (remaining / limit) * 100.0
+            } else {
+                100.0
+            };
+
+            Some(GeminiModelQuota {
+                model_id,
+                percent_left,
+                reset_time: parse_time(m.requests_reset_time),
+            })
+        })
+        .collect()
 }
 
 // ============================================================================
@@ -253,9 +424,33 @@ pub struct GeminiSnapshot {
     pub account_email: Option<String>,
     /// Account plan (if known).
     pub account_plan: Option<String>,
+    /// Raw response bodies (for the debug view).
+    pub raw_body: Option<String>,
 }
 
 impl GeminiSnapshot {
+    /// Parses the raw bodies of a quota fetch, as kept in `raw_body`.
+    pub fn parse(raw: &str) -> Result<Self, GeminiError> {
+        let responses: QuotaResponses = parse_body(raw)?;
+        let load: LoadCodeAssistResponse = parse_body(&responses.load_code_assist)?;
+        let model_quotas = parse_quotas(parse_body(&responses.quota)?);
+
+        for quota in &model_quotas {
+            debug!(
+                model = %quota.model_id,
+                percent_left = quota.percent_left,
+                "Parsed model quota"
+            );
+        }
+
+        Ok(Self {
+            model_quotas,
+            account_email: None, // Could extract from JWT token if needed
+            account_plan: load.current_tier.and_then(|tier| tier.name),
+            raw_body: Some(raw.to_string()),
+        })
+    }
+
     /// Check if we have any quota data.
     pub fn has_data(&self) -> bool {
         !self.model_quotas.is_empty()
@@ -323,7 +518,7 @@ pub struct GeminiProbe {
 impl GeminiProbe {
     /// Create a new Gemini probe.
     pub fn new() -> Self {
-        let http = reqwest::Client::builder()
+        let http = exactobar_fetch::network::client_builder()
             .timeout(std::time::Duration::from_secs(10))
             .build()
             .expect("Failed to build HTTP client");
@@ -361,8 +556,15 @@ impl GeminiProbe {
         // Get a valid access token (refreshing if needed)
         let access_token = self.get_valid_token(&creds).await?;
 
-        // Fetch quotas from the API
-        self.fetch_quotas(&access_token).await
+        // The token can be revoked before it expires; refresh once and retry
+        match self.fetch_quotas(&access_token).await {
+            Err(GeminiError::NotLoggedIn) if creds.has_refresh_token() => {
+                info!("Gemini access token rejected, refreshing...");
+                let access_token = self.refresh_token(&creds).await?;
+                self.fetch_quotas(&access_token).await
+            }
+            result => result,
+        }
     }
 
     /// Get a valid access token, refreshing if expired.
@@ -376,6 +578,9 @@ impl GeminiProbe {
         // If token is expired and we have a refresh token, refresh it
         if creds.is_expired() {
             if creds.has_refresh_token() {
+                if let Some(token) = cached_token(creds) {
+                    return Ok(token);
+                }
                 info!("Gemini access token expired, refreshing...");
                 return self.refresh_token(creds).await;
             } else {
@@ -396,31 +601,27 @@ impl GeminiProbe {
             .as_ref()
             .ok_or(GeminiError::NotLoggedIn)?;
 
-        let client_id = creds
-            .client_id
-            .as_ref()
-            .ok_or_else(|| GeminiError::CredentialsParseError("Missing client_id".to_string()))?;
-
-        let client_secret = creds.client_secret.as_ref().ok_or_else(|| {
-            GeminiError::CredentialsParseError("Missing client_secret".to_string())
+        let (client_id, client_secret) = creds.oauth_client().ok_or_else(|| {
+            GeminiError::RefreshFailed(
+                "No OAuth client in credentials or the Gemini CLI install".to_string(),
+            )
         })?;
 
         debug!("Refreshing Gemini OAuth token");
 
         let params = [
             ("grant_type", "refresh_token"),
-            ("refresh_token", refresh_token),
-            ("client_id", client_id),
-            ("client_secret", client_secret),
+            ("refresh_token", refresh_token.as_str()),
+            ("client_id", client_id.as_str()),
+            ("client_secret", client_secret.as_str()),
         ];
 
-        let response = self
-            .http
-            .post(TOKEN_REFRESH_ENDPOINT)
-            .form(&params)
-            .send()
-            .await
-            .map_err(|e| GeminiError::HttpError(format!("Token refresh request failed: {}", e)))?;
+        let response =
+            exactobar_fetch::network::send(self.http.post(TOKEN_REFRESH_ENDPOINT).form(&params))
+                .await
+                .map_err(|e| {
+                    GeminiError::HttpError(format!("Token refresh request failed: {}", e))
+                })?;
 
         let status = response.status();
         if !status.is_success() {
@@ -437,24 +638,36 @@ impl GeminiProbe {
 
         info!("Successfully refreshed Gemini OAuth token");
 
-        // Note: We could save the new token back to oauth_creds.json here,
-        // but that might interfere with the Gemini CLI. Let's just use it.
+        // Keep the token for the next fetches instead of writing it back to
+        // oauth_creds.json, which belongs to the Gemini CLI
+        let expires_in_ms = refresh_response.expires_in.unwrap_or(3600) * 1000;
+        if let Ok(mut cached) = REFRESHED_TOKEN.lock() {
+            *cached = Some(RefreshedToken {
+                refresh_token: refresh_token.clone(),
+                access_token: refresh_response.access_token.clone(),
+                expiry_ms: Utc::now().timestamp_millis() + expires_in_ms,
+            });
+        }
 
         Ok(refresh_response.access_token)
     }
 
-    /// Fetch quota information from the Cloud Code Private API.
-    async fn fetch_quotas(&self, access_token: &str) -> Result<GeminiSnapshot, GeminiError> {
-        debug!("Fetching Gemini quotas from Cloud Code API");
-
-        let response = self
-            .http
-            .post(QUOTA_ENDPOINT)
-            .bearer_auth(access_token)
-            .json(&serde_json::json!({}))
-            .send()
-            .await
-            .map_err(|e| GeminiError::HttpError(format!("Quota request failed: {}", e)))?;
+    /// Posts `body` to a Cloud Code Private API endpoint and returns the
+    /// response body.
+    async fn post(
+        &self,
+        endpoint: &str,
+        access_token: &str,
+        body: &serde_json::Value,
+    ) -> Result<String, GeminiError> {
+        let response = exactobar_fetch::network::send(
+            self.http
+                .post(endpoint)
+                .bearer_auth(access_token)
+                .json(body),
+        )
+        .await
+        .map_err(|e| GeminiError::HttpError(format!("Request failed: {}", e)))?;
 
         let status = response.status();
 
@@ -462,6 +675,10 @@ impl GeminiProbe {
             return Err(GeminiError::NotLoggedIn);
         }
 
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(GeminiError::RateLimited(format!("HTTP {}", status)));
+        }
+
         if !status.is_success() {
             let body = response.text().await.unwrap_or_default();
             return Err(GeminiError::InvalidResponse(format!(
@@ -470,55 +687,65 @@ impl GeminiProbe {
             )));
         }
 
-        let quota_response: QuotaResponse = response.json().await.map_err(|e| {
-            GeminiError::InvalidResponse(format!("Failed to parse quota response: {}", e))
-        })?;
+        response
+            .text()
+            .await
+            .map_err(|e| GeminiError::HttpError(format!("Failed to read response: {}", e)))
+    }
 
-        // Parse model quotas
-        let model_quotas = quota_response
-            .model_quota_usages
-            .unwrap_or_default()
-            .into_iter()
-            .filter_map(|m| {
-                let model_id = m.model_id?;
-                let remaining = m.remaining_requests.unwrap_or(0) as f64;
-                let limit = m.request_limit.unwrap_or(1) as f64;
-
-                let percent_left = if limit > 0.0 {
-                    (remaining / limit) * 100.0
-                } else {
-                    100.0
-                };
-
-                let reset_time = m
-                    .requests_reset_time
-                    .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
-                    .map(|dt| dt.with_timezone(&Utc));
-
-                debug!(
-                    model = %model_id,
-                    remaining = remaining,
-                    limit = limit,
-                    percent_left = percent_left,
-                    "Parsed model quota"
-                );
-
-                Some(GeminiModelQuota {
-                    model_id,
-                    percent_left,
-                    reset_time,
-                })
-            })
-            .collect();
+    /// Fetch quota information from the Cloud Code Private API.
+    async fn fetch_quotas(&self, access_token: &str) -> Result<GeminiSnapshot, GeminiError> {
+        debug!("Loading Gemini Code Assist project");
+
+        let load_code_assist = self
+            .post(
+                LOAD_CODE_ASSIST_ENDPOINT,
+                access_token,
+                &serde_json::json!({
+                    "metadata": {
+                        "ideType": "IDE_UNSPECIFIED",
+                        "platform": "PLATFORM_UNSPECIFIED",
+                        "pluginType": "GEMINI",
+                    }
+                }),
+            )
+            .await?;
+        let load: LoadCodeAssistResponse = parse_body(&load_code_assist)?;
+
+        debug!(project = ?load.cloudaicompanion_project, "Fetching Gemini quotas from Cloud Code API");
+
+        let body = match &load.cloudaicompanion_project {
+            Some(project) => serde_json::json!({ "project": project }),
+            None => serde_json::json!({}),
+        };
+        let quota = self.post(QUOTA_ENDPOINT, access_token, &body).await?;
 
-        Ok(GeminiSnapshot {
-            model_quotas,
-            account_email: None, // Could extract from JWT token if needed
-            account_plan: None,
-        })
+        let responses = QuotaResponses {
+            load_code_assist,
+            quota,
+        };
+        let raw = serde_json::to_string(&responses)
+            .map_err(|e| GeminiError::InvalidResponse(e.to_string()))?;
+        GeminiSnapshot::parse(&raw)
     }
 }
 
+/// Parses a JSON response body.
+fn parse_body<T: for<'de> Deserialize<'de>>(body: &str) -> Result<T, GeminiError> {
+    serde_json::from_str(body)
+        .map_err(|e| GeminiError::InvalidResponse(format!("Failed to parse response: {}", e)))
+}
+
+/// Returns the refreshed token for these credentials, if it's still valid.
+fn cached_token(creds: &GeminiCredentials) -> Option<String> {
+    let cached = REFRESHED_TOKEN.lock().ok()?;
+    cached
+        .as_ref()
+        .filter(|t| creds.refresh_token.as_ref() == Some(&t.refresh_token))
+        .filter(|t| t.expiry_ms - EXPIRY_MARGIN_MS > Utc::now().timestamp_millis())
+        .map(|t| t.access_token.clone())
+}
+
 impl Default for GeminiProbe {
     fn default() -> Self {
         Self::new()
@@ -613,6 +840,7 @@ mod tests {
             ],
             account_email: Some("test@example.com".to_string()),
             account_plan: None,
+            raw_body: None,
         };
 
         let usage = snapshot.to_usage_snapshot();
@@ -633,6 +861,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_quotas() {
+        let response: QuotaResponse = serde_json::from_str(
+            r#"{"buckets":[
+                {"modelId":"gemini-2.5-pro","remainingFraction":0.8,"resetTime":"2026-03-05T00:00:00Z","tokenType":"REQUESTS"},
+                {"modelId":"gemini-2.5-pro","remainingFraction":0.25,"tokenType":"TOKENS"},
+                {"modelId":"gemini-2.5-flash","remainingFraction":1.0},
+                {"remainingFraction":0.5}
+            ]}"#,
+        )
+        .unwrap();
+
+        let quotas = parse_quotas(response);
+        assert_eq!(quotas.len(), 2);
+        assert_eq!(quotas[0].model_id, "gemini-2.5-pro");
+        assert!((quotas[0].percent_left - 25.0).abs() < 1e-9);
+        assert!(quotas[0].reset_time.is_none());
+        assert!((quotas[1].percent_used()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_oauth_client() {
+        let script = r#"
+const OAUTH_CLIENT_ID = '1234-abc.apps.googleusercontent.com';
+// OAuth Secret value used to initiate OAuth2Client class.
+const OAUTH_CLIENT_SECRET = "not-a-secret";
+"#;
+        assert_eq!(
+            parse_oauth_client(script),
+            Some((
+                "1234-abc.apps.googleusercontent.com".to_string(),
+                "not-a-secret".to_string()
+            ))
+        );
+        assert_eq!(parse_oauth_client("const OAUTH_CLIENT_ID = 'x';"), None);
+    }
+
     #[test]
     fn test_probe_creation() {
         let probe = GeminiProbe::new();
@@ -1,12 +1,12 @@
 //! Gemini fetch strategies.
 
 use async_trait::async_trait;
-// UsageSnapshot and FetchSource are used via the probe's to_usage_snapshot()
+use exactobar_core::{ProviderError, UsageSnapshot};
 use exactobar_fetch::{FetchContext, FetchError, FetchKind, FetchResult, FetchStrategy};
 use tracing::{debug, info, instrument, warn};
 
 use super::parser::parse_gemini_response;
-use super::probe::{GeminiCredentials, GeminiProbe};
+use super::probe::{GeminiCredentials, GeminiProbe, GeminiSnapshot};
 
 // ============================================================================
 // OAuth Strategy
@@ -56,37 +56,22 @@ impl FetchStrategy for GeminiOAuthStrategy {
 
         let snapshot_data = self.probe.fetch().await.map_err(|e| {
             warn!(error = %e, "Gemini OAuth probe failed");
-            match e {
-                super::error::GeminiError::NotLoggedIn => {
-                    FetchError::AuthenticationFailed("Not logged in to Gemini CLI".to_string())
-                }
-                super::error::GeminiError::TokenExpired(msg) => {
-                    FetchError::AuthenticationFailed(format!("Token expired: {}", msg))
-                }
-                super::error::GeminiError::UnsupportedAuthType(msg) => {
-                    FetchError::AuthenticationFailed(format!("Unsupported auth: {}", msg))
-                }
-                super::error::GeminiError::RefreshFailed(msg) => {
-                    FetchError::AuthenticationFailed(format!("Token refresh failed: {}", msg))
-                }
-                super::error::GeminiError::InvalidResponse(msg) => FetchError::InvalidResponse(msg),
-                super::error::GeminiError::HttpError(msg) => {
-                    FetchError::AuthenticationFailed(format!("HTTP error: {}", msg))
-                }
-                other => FetchError::AuthenticationFailed(other.to_string()),
-            }
+            ProviderError::from(e)
         })?;
 
-        if !snapshot_data.has_data() {
-            return Err(FetchError::InvalidResponse(
-                "No quota data returned".to_string(),
-            ));
-        }
-
-        let snapshot = snapshot_data.to_usage_snapshot();
+        let snapshot = to_snapshot(&snapshot_data)?;
         info!("Successfully fetched Gemini quota via OAuth");
 
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+        Ok(FetchResult::new(snapshot, self.id(), self.kind())
+            .with_raw_response(snapshot_data.raw_body))
+    }
+
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(
+            GeminiSnapshot::parse(raw)
+                .map_err(|e| ProviderError::from(e).into())
+                .and_then(|snapshot_data| to_snapshot(&snapshot_data)),
+        )
     }
 
     fn priority(&self) -> u32 {
@@ -94,6 +79,16 @@ impl FetchStrategy for GeminiOAuthStrategy {
     }
 }
 
+/// Converts the probe's quota data, failing if it has none.
+fn to_snapshot(snapshot_data: &GeminiSnapshot) -> Result<UsageSnapshot, FetchError> {
+    if !snapshot_data.has_data() {
+        return Err(FetchError::InvalidResponse(
+            "No quota data returned".to_string(),
+        ));
+    }
+    Ok(snapshot_data.to_usage_snapshot())
+}
+
 // ============================================================================
 // CLI Strategy
 // ============================================================================
@@ -167,6 +162,29 @@ mod tests {
         assert_eq!(s.priority(), 100);
     }
 
+    #[test]
+    fn test_oauth_parse_raw() {
+        let raw = serde_json::json!({
+            "loadCodeAssist": r#"{"currentTier": {"name": "Gemini Code Assist for individuals"}}"#,
+            "quota": r#"{"buckets": [{"modelId": "gemini-2.5-pro", "remainingFraction": 0.75}]}"#,
+        })
+        .to_string();
+        let snapshot = GeminiOAuthStrategy::new().parse_raw(&raw).unwrap().unwrap();
+        assert_eq!(snapshot.primary.unwrap().used_percent, 25.0);
+        assert_eq!(
+            snapshot.identity.unwrap().plan_name.as_deref(),
+            Some("Gemini Code Assist for individuals")
+        );
+
+        // A response without quotas is an error, as it is when fetching
+        let raw = serde_json::json!({"loadCodeAssist": "{}", "quota": "{}"}).to_string();
+        let error = GeminiOAuthStrategy::new()
+            .parse_raw(&raw)
+            .unwrap()
+            .unwrap_err();
+        assert!(matches!(error.classify(), ProviderError::Parse(_)));
+    }
+
     #[test]
     fn test_cli_strategy() {
         let s = GeminiCliStrategy::new();
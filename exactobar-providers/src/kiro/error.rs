@@ -1,5 +1,6 @@
 //! Kiro-specific errors.
 
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// Kiro-specific errors.
@@ -33,3 +34,15 @@ pub enum KiroError {
     #[error("All fetch strategies failed")]
     AllStrategiesFailed,
 }
+
+impl From<KiroError> for ProviderError {
+    fn from(err: KiroError) -> Self {
+        let message = err.to_string();
+        match err {
+            KiroError::CliNotFound => ProviderError::NotInstalled(message),
+            KiroError::NotLoggedIn => ProviderError::NotLoggedIn(message),
+            KiroError::ParseError(_) => ProviderError::Parse(message),
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+}
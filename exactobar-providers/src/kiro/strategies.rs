@@ -1,13 +1,11 @@
 //! Kiro fetch strategies.
 
 use async_trait::async_trait;
-use exactobar_fetch::{
-    FetchContext, FetchError, FetchKind, FetchResult, FetchStrategy, ProcessError,
-};
+use exactobar_core::ProviderError;
+use exactobar_fetch::{FetchContext, FetchError, FetchKind, FetchResult, FetchStrategy};
 use tracing::{debug, instrument, warn};
 
 use super::cli::ensure_logged_in;
-use super::error::KiroError;
 use super::parser::parse_kiro_response;
 
 // ============================================================================
@@ -54,16 +52,9 @@ impl FetchStrategy for KiroCliStrategy {
         debug!("Fetching Kiro usage via CLI");
 
         // Check login first
-        ensure_logged_in().await.map_err(|e| match e {
-            KiroError::NotLoggedIn => {
-                warn!("Kiro: user not logged in");
-                FetchError::AuthenticationFailed("Not logged in to Kiro".to_string())
-            }
-            KiroError::CliNotFound => {
-                FetchError::Process(ProcessError::NotFound("kiro-cli".to_string()))
-            }
-            KiroError::CliFailed(msg) => FetchError::Process(ProcessError::ExecutionFailed(msg)),
-            _ => FetchError::InvalidResponse(e.to_string()),
+        ensure_logged_in().await.map_err(|e| {
+            warn!(error = %e, "Kiro login check failed");
+            ProviderError::from(e)
         })?;
 
         // Then fetch usage
@@ -68,6 +68,16 @@
 //! | MiniMax | ❌ | ❌ | ❌ | ✅ | ✅ | Active |
 //! | Antigravity | ❌ | ❌ | ❌ | ❌ | ✅ | Active |
 //!
+//! ## Features
+//!
+//! Each provider sits behind a cargo feature of the same name (`claude`,
+//! `codex`, ...), all enabled by default through `all-providers`. A build
+//! without a provider's feature leaves out its code and the dependencies only
+//! it uses, and the registry doesn't list it.
+//!
+//! The `demo` feature adds a provider that generates fake usage, for UI work
+//! and screenshots. It isn't part of `all-providers`.
+//!
 //! ## Usage
 //!
 //! ```ignore
@@ -85,57 +95,105 @@
 //! ```
 
 pub mod descriptor;
+pub mod manifest;
+#[cfg(any(feature = "claude", feature = "codex"))]
+pub mod projects;
 pub mod registry;
 
 // Provider modules (alphabetical)
+#[cfg(feature = "antigravity")]
 pub mod antigravity;
+#[cfg(feature = "augment")]
 pub mod augment;
+#[cfg(feature = "claude")]
 pub mod claude;
+#[cfg(feature = "codex")]
 pub mod codex;
+#[cfg(feature = "copilot")]
 pub mod copilot;
+#[cfg(feature = "cursor")]
 pub mod cursor;
+#[cfg(feature = "demo")]
+pub mod demo;
+#[cfg(feature = "factory")]
 pub mod factory;
+#[cfg(feature = "gemini")]
 pub mod gemini;
+#[cfg(feature = "kiro")]
 pub mod kiro;
+#[cfg(feature = "minimax")]
 pub mod minimax;
+#[cfg(feature = "synthetic")]
 pub mod synthetic;
+#[cfg(feature = "vertexai")]
 pub mod vertexai;
+#[cfg(feature = "zai")]
 pub mod zai;
 
 // Re-export key types
 pub use descriptor::{
-    CliConfig, FetchPlan, ProviderDescriptor, ProviderDescriptorBuilder, TokenCostConfig,
+    CliConfig, FetchPlan, ProjectUsage, ProviderDescriptor, ProviderDescriptorBuilder,
+    TokenCostConfig,
 };
+pub use manifest::{MetadataCorrection, MetadataManifest, SignedManifest};
 pub use registry::ProviderRegistry;
 
 // Re-export provider descriptors
+#[cfg(feature = "antigravity")]
 pub use antigravity::antigravity_descriptor;
+#[cfg(feature = "augment")]
 pub use augment::augment_descriptor;
+#[cfg(feature = "claude")]
 pub use claude::claude_descriptor;
+#[cfg(feature = "codex")]
 pub use codex::codex_descriptor;
+#[cfg(feature = "copilot")]
 pub use copilot::copilot_descriptor;
+#[cfg(feature = "cursor")]
 pub use cursor::cursor_descriptor;
+#[cfg(feature = "demo")]
+pub use demo::demo_descriptor;
+#[cfg(feature = "factory")]
 pub use factory::factory_descriptor;
+#[cfg(feature = "gemini")]
 pub use gemini::gemini_descriptor;
+#[cfg(feature = "kiro")]
 pub use kiro::kiro_descriptor;
+#[cfg(feature = "minimax")]
 pub use minimax::minimax_descriptor;
+#[cfg(feature = "synthetic")]
 pub use synthetic::synthetic_descriptor;
+#[cfg(feature = "vertexai")]
 pub use vertexai::vertexai_descriptor;
+#[cfg(feature = "zai")]
 pub use zai::zai_descriptor;
 
 // Re-export strategy types for convenience
+#[cfg(feature = "antigravity")]
 pub use antigravity::AntigravityLocalStrategy;
+#[cfg(feature = "augment")]
 pub use augment::AugmentWebStrategy;
+#[cfg(feature = "claude")]
 pub use claude::{ClaudeCliStrategy, ClaudeOAuthStrategy, ClaudeWebStrategy};
+#[cfg(feature = "codex")]
 pub use codex::{CodexApiStrategy, CodexCliStrategy};
+#[cfg(feature = "copilot")]
 pub use copilot::{CopilotApiStrategy, CopilotEnvStrategy};
+#[cfg(feature = "cursor")]
 pub use cursor::{CursorLocalStrategy, CursorWebStrategy};
+#[cfg(feature = "factory")]
 pub use factory::{FactoryLocalStrategy, FactoryWebStrategy};
+#[cfg(feature = "gemini")]
 pub use gemini::{GeminiCliStrategy, GeminiOAuthStrategy};
+#[cfg(feature = "kiro")]
 pub use kiro::KiroCliStrategy;
+#[cfg(feature = "minimax")]
 pub use minimax::{MiniMaxLocalStrategy, MiniMaxWebStrategy};
+#[cfg(feature = "synthetic")]
 pub use synthetic::SyntheticApiStrategy;
+#[cfg(feature = "vertexai")]
 pub use vertexai::{VertexAILocalStrategy, VertexAIOAuthStrategy};
+#[cfg(feature = "zai")]
 pub use zai::ZaiApiStrategy;
 #[cfg(test)]
 mod parser_edge_tests;
@@ -0,0 +1,327 @@
+//! Provider metadata corrections from a signed remote manifest.
+//!
+//! Vendors rename plans, move dashboards and change status pages between
+//! releases. The manifest published at [`MANIFEST_URL`] corrects the
+//! compiled-in [`ProviderMetadata`] (labels, URLs and the credits hint)
+//! without shipping a new version.
+//!
+//! The manifest is signed with the project's Ed25519 key and only applied
+//! if the signature checks out against [`MANIFEST_PUBLIC_KEY`]. Verified
+//! manifests are cached and applied again at startup, so corrections hold
+//! offline; without a valid manifest the compiled-in defaults are used.
+//!
+//! The private key is held by the maintainers, outside the repository.
+//! `manifest/providers.json` is signed from `manifest/payload.json` with
+//! the `sign_manifest` example, which also generates a new key pair when
+//! the key is rotated.
+//!
+//! ```json
+//! {
+//!   "payload": "{\"version\":3,\"providers\":{\"claude\":{\"weekly_label\":\"7-day\"}}}",
+//!   "signature": "<hex Ed25519 signature of the payload>"
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use exactobar_core::{ProviderKind, ProviderMetadata};
+use ring::signature::{ED25519, UnparsedPublicKey};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{debug, info, warn};
+
+use crate::registry::ProviderRegistry;
+
+/// Where the manifest is published.
+pub const MANIFEST_URL: &str =
+    "https://raw.githubusercontent.com/janfeddersen/exactobar/main/manifest/providers.json";
+
+/// Ed25519 public key the manifest is signed with.
+pub const MANIFEST_PUBLIC_KEY: &str =
+    "3c80c3db9ff3506353759fdcb373a7405cfa45e8c254f9d1bd77d17fb819d4a9";
+
+/// Version of the manifest applied, 0 if none.
+static APPLIED_VERSION: AtomicU64 = AtomicU64::new(0);
+
+/// Errors reading a manifest.
+#[derive(Debug, Error)]
+pub enum ManifestError {
+    /// The manifest isn't valid JSON or is missing fields.
+    #[error("Malformed manifest: {0}")]
+    Malformed(#[from] serde_json::Error),
+    /// The signature doesn't match the payload or the key.
+    #[error("Manifest signature is invalid")]
+    InvalidSignature,
+    /// The cached manifest couldn't be read or written.
+    #[error("Manifest cache error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+// ============================================================================
+// Manifest
+// ============================================================================
+
+/// A manifest as published: the payload and its signature.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedManifest {
+    /// The [`MetadataManifest`] as JSON, exactly as signed.
+    pub payload: String,
+    /// Hex-encoded Ed25519 signature of the payload.
+    pub signature: String,
+}
+
+impl SignedManifest {
+    /// Parses a published manifest.
+    pub fn parse(bytes: &[u8]) -> Result<Self, ManifestError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    /// Checks the signature against the hex-encoded `public_key` and
+    /// returns the payload.
+    pub fn verify(&self, public_key: &str) -> Result<MetadataManifest, ManifestError> {
+        let key = decode_hex(public_key).ok_or(ManifestError::InvalidSignature)?;
+        let signature = decode_hex(&self.signature).ok_or(ManifestError::InvalidSignature)?;
+        UnparsedPublicKey::new(&ED25519, key)
+            .verify(self.payload.as_bytes(), &signature)
+            .map_err(|_| ManifestError::InvalidSignature)?;
+
+        Ok(serde_json::from_str(&self.payload)?)
+    }
+}
+
+/// Metadata corrections, by provider CLI name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataManifest {
+    /// Increases with every published manifest; older ones are ignored.
+    pub version: u64,
+    /// Corrections by provider CLI name (e.g. "claude"). Providers this
+    /// version doesn't know are ignored.
+    #[serde(default)]
+    pub providers: HashMap<String, MetadataCorrection>,
+}
+
+impl MetadataManifest {
+    /// Returns the corrections for a provider.
+    pub fn correction(&self, provider: ProviderKind) -> Option<&MetadataCorrection> {
+        self.providers.get(provider.cli_name())
+    }
+}
+
+/// Replacements for a provider's metadata. Fields left out keep the
+/// compiled-in value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetadataCorrection {
+    pub display_name: Option<String>,
+    pub session_label: Option<String>,
+    pub weekly_label: Option<String>,
+    pub opus_label: Option<String>,
+    pub credits_hint: Option<String>,
+    pub dashboard_url: Option<String>,
+    pub subscription_dashboard_url: Option<String>,
+    pub status_page_url: Option<String>,
+    pub status_link_url: Option<String>,
+}
+
+impl MetadataCorrection {
+    /// Applies the corrections to `metadata`.
+    pub fn apply(&self, metadata: &mut ProviderMetadata) {
+        let replace = |field: &mut String, value: &Option<String>| {
+            if let Some(value) = value {
+                field.clone_from(value);
+            }
+        };
+        replace(&mut metadata.display_name, &self.display_name);
+        replace(&mut metadata.session_label, &self.session_label);
+        replace(&mut metadata.weekly_label, &self.weekly_label);
+        replace(&mut metadata.credits_hint, &self.credits_hint);
+
+        let replace_optional = |field: &mut Option<String>, value: &Option<String>| {
+            if value.is_some() {
+                field.clone_from(value);
+            }
+        };
+        replace_optional(&mut metadata.opus_label, &self.opus_label);
+        replace_optional(&mut metadata.dashboard_url, &self.dashboard_url);
+        replace_optional(
+            &mut metadata.subscription_dashboard_url,
+            &self.subscription_dashboard_url,
+        );
+        replace_optional(&mut metadata.status_page_url, &self.status_page_url);
+        replace_optional(&mut metadata.status_link_url, &self.status_link_url);
+    }
+}
+
+// ============================================================================
+// Installing
+// ============================================================================
+
+/// Returns the path the verified manifest is cached at.
+pub fn cache_path() -> PathBuf {
+    exactobar_store::default_cache_dir().join("provider-manifest.json")
+}
+
+/// Returns the version of the manifest applied, if any.
+pub fn applied_version() -> Option<u64> {
+    match APPLIED_VERSION.load(Ordering::Relaxed) {
+        0 => None,
+        version => Some(version),
+    }
+}
+
+/// Applies the cached manifest, if there's a valid one.
+///
+/// Call at startup, before showing provider metadata.
+pub fn load_cached() {
+    let path = cache_path();
+    let Ok(bytes) = std::fs::read(&path) else {
+        debug!(path = %path.display(), "No cached provider manifest");
+        return;
+    };
+
+    match SignedManifest::parse(&bytes).and_then(|m| m.verify(MANIFEST_PUBLIC_KEY)) {
+        Ok(manifest) => {
+            apply(&manifest);
+        }
+        Err(e) => warn!(error = %e, "Ignoring cached provider manifest"),
+    }
+}
+
+/// Verifies a freshly downloaded manifest and, if it's newer than the one
+/// applied, applies and caches it. Returns true if it was applied.
+pub fn install(bytes: &[u8]) -> Result<bool, ManifestError> {
+    let manifest = SignedManifest::parse(bytes)?.verify(MANIFEST_PUBLIC_KEY)?;
+    if !apply(&manifest) {
+        return Ok(false);
+    }
+
+    write_cache(&cache_path(), bytes)?;
+    Ok(true)
+}
+
+/// Applies the manifest unless one at least as new is applied already.
+fn apply(manifest: &MetadataManifest) -> bool {
+    if applied_version().is_some_and(|applied| manifest.version <= applied) {
+        debug!(
+            version = manifest.version,
+            "Provider manifest already applied"
+        );
+        return false;
+    }
+
+    ProviderRegistry::apply_manifest(manifest);
+    APPLIED_VERSION.store(manifest.version, Ordering::Relaxed);
+    info!(version = manifest.version, "Applied provider manifest");
+    true
+}
+
+fn write_cache(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, bytes)
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    fn encode_hex(bytes: &[u8]) -> String {
+        use std::fmt::Write;
+        bytes.iter().fold(String::new(), |mut hex, b| {
+            let _ = write!(hex, "{b:02x}");
+            hex
+        })
+    }
+
+    fn sign(payload: &str) -> (SignedManifest, String) {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        let manifest = SignedManifest {
+            payload: payload.to_string(),
+            signature: encode_hex(key_pair.sign(payload.as_bytes()).as_ref()),
+        };
+        (manifest, encode_hex(key_pair.public_key().as_ref()))
+    }
+
+    #[test]
+    fn test_verify() {
+        let payload =
+            r#"{"version":3,"providers":{"claude":{"weekly_label":"7-day"},"someday":{}}}"#;
+        let (signed, public_key) = sign(payload);
+
+        let manifest = signed.verify(&public_key).unwrap();
+        assert_eq!(manifest.version, 3);
+        assert_eq!(
+            manifest
+                .correction(ProviderKind::Claude)
+                .and_then(|c| c.weekly_label.as_deref()),
+            Some("7-day")
+        );
+        assert!(manifest.correction(ProviderKind::Codex).is_none());
+
+        // A changed payload or another key fails
+        let tampered = SignedManifest {
+            payload: payload.replace("7-day", "1-day"),
+            ..signed.clone()
+        };
+        assert!(matches!(
+            tampered.verify(&public_key),
+            Err(ManifestError::InvalidSignature)
+        ));
+        assert!(matches!(
+            signed.verify(MANIFEST_PUBLIC_KEY),
+            Err(ManifestError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn test_published_manifest() {
+        let bytes = include_bytes!("../../manifest/providers.json");
+        let manifest = SignedManifest::parse(bytes)
+            .unwrap()
+            .verify(MANIFEST_PUBLIC_KEY)
+            .unwrap();
+        assert!(manifest.version > 0);
+    }
+
+    #[test]
+    fn test_apply_correction() {
+        let mut metadata = ProviderMetadata::for_provider(ProviderKind::Claude);
+        metadata.opus_label = Some("Opus".to_string());
+        metadata.dashboard_url = Some("https://old.example.com".to_string());
+
+        let correction = MetadataCorrection {
+            session_label: Some("5-hour".to_string()),
+            dashboard_url: Some("https://new.example.com".to_string()),
+            ..Default::default()
+        };
+        correction.apply(&mut metadata);
+
+        assert_eq!(metadata.session_label, "5-hour");
+        assert_eq!(metadata.weekly_label, "Weekly");
+        assert_eq!(metadata.opus_label.as_deref(), Some("Opus"));
+        assert_eq!(
+            metadata.dashboard_url.as_deref(),
+            Some("https://new.example.com")
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! MiniMax-specific errors.
 
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// MiniMax-specific errors.
@@ -47,3 +48,17 @@ impl From<reqwest::Error> for MiniMaxError {
         MiniMaxError::HttpError(err.to_string())
     }
 }
+
+impl From<MiniMaxError> for ProviderError {
+    fn from(err: MiniMaxError) -> Self {
+        let message = err.to_string();
+        match err {
+            MiniMaxError::AuthenticationFailed(_)
+            | MiniMaxError::NoSessionCookie
+            | MiniMaxError::NoToken => ProviderError::NotLoggedIn(message),
+            MiniMaxError::HttpError(_) => ProviderError::Network(message),
+            MiniMaxError::InvalidResponse(_) => ProviderError::Parse(message),
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+}
@@ -109,7 +109,11 @@ impl FetchStrategy for MiniMaxWebStrategy {
             .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
 
         let snapshot = parse_minimax_response(&body)?;
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(Some(body)))
+    }
+
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(parse_minimax_response(raw))
     }
 
     fn priority(&self) -> u32 {
@@ -210,7 +214,11 @@ impl FetchStrategy for HailuoaiWebStrategy {
 
         let snapshot = parse_minimax_response(&body)?;
         info!("Fetched MiniMax usage from hailuoai.com");
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(Some(body)))
+    }
+
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(parse_minimax_response(raw))
     }
 
     fn priority(&self) -> u32 {
@@ -291,7 +299,11 @@ impl FetchStrategy for MiniMaxLocalStorageStrategy {
 
         let snapshot = parse_minimax_response(&body)?;
         info!("Fetched MiniMax usage from localStorage token");
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(Some(body)))
+    }
+
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(parse_minimax_response(raw))
     }
 
     fn priority(&self) -> u32 {
@@ -358,6 +370,7 @@ impl FetchStrategy for MiniMaxLocalStrategy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use exactobar_core::ProviderError;
 
     #[test]
     fn test_web_strategy() {
@@ -399,4 +412,22 @@ mod tests {
         assert!(hailuoai.priority() > localstorage.priority());
         assert!(localstorage.priority() > local.priority());
     }
+
+    #[test]
+    fn test_parse_raw() {
+        let raw = r#"{"tokens": {"used": 5000, "limit": 10000}}"#;
+        let strategies: [&dyn FetchStrategy; 3] = [
+            &MiniMaxWebStrategy::new(),
+            &HailuoaiWebStrategy::new(),
+            &MiniMaxLocalStorageStrategy::new(),
+        ];
+
+        for strategy in strategies {
+            let snapshot = strategy.parse_raw(raw).unwrap().unwrap();
+            assert_eq!(snapshot.primary.unwrap().used_percent, 50.0);
+
+            let error = strategy.parse_raw("{").unwrap().unwrap_err();
+            assert!(matches!(error.classify(), ProviderError::Parse(_)));
+        }
+    }
 }
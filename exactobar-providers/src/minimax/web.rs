@@ -409,7 +409,7 @@ pub struct MiniMaxWebClient {
 impl MiniMaxWebClient {
     /// Creates a new client.
     pub fn new() -> Self {
-        let http = reqwest::Client::builder()
+        let http = exactobar_fetch::network::client_builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
             .expect("Failed to build HTTP client");
@@ -479,7 +479,7 @@ impl MiniMaxWebClient {
         let url = format!("{}{}", MINIMAX_API_BASE, USAGE_ENDPOINT);
         let headers = self.build_cookie_headers(cookie_header)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
@@ -513,7 +513,7 @@ impl MiniMaxWebClient {
         let url = format!("{}{}", MINIMAX_API_BASE, USAGE_ENDPOINT);
         let headers = self.build_token_headers(token)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
@@ -550,7 +550,7 @@ impl MiniMaxWebClient {
         let url = format!("{}{}", HAILUOAI_API_BASE, HAILUOAI_USAGE_ENDPOINT);
         let headers = self.build_cookie_headers(cookie_header)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
@@ -4,7 +4,7 @@
 
 #![allow(clippy::float_cmp)]
 
-#[cfg(test)]
+#[cfg(all(test, feature = "claude"))]
 mod claude_parser_edge_tests {
     use crate::claude::parser::{
         parse_claude_api_response, parse_claude_cli_output, parse_text_usage_line,
@@ -192,7 +192,7 @@ mod claude_parser_edge_tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "cursor"))]
 mod cursor_parser_edge_tests {
     use crate::cursor::parser::{parse_cursor_api_response, parse_cursor_local_config};
 
@@ -255,7 +255,7 @@ mod cursor_parser_edge_tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "codex"))]
 mod codex_parser_edge_tests {
     use crate::codex::parser::parse_codex_cli_output;
 
@@ -307,7 +307,7 @@ mod codex_parser_edge_tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "gemini"))]
 mod gemini_parser_edge_tests {
     use crate::gemini::parser::parse_gemini_response;
 
@@ -331,7 +331,7 @@ mod gemini_parser_edge_tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "factory"))]
 mod factory_parser_edge_tests {
     use crate::factory::parser::parse_factory_response;
 
@@ -343,7 +343,7 @@ mod factory_parser_edge_tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "copilot"))]
 mod copilot_parser_edge_tests {
     use crate::copilot::parser::parse_copilot_response;
 
@@ -367,7 +367,7 @@ mod copilot_parser_edge_tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "augment"))]
 mod augment_parser_edge_tests {
     use crate::augment::parser::parse_augment_response;
 
@@ -384,7 +384,7 @@ mod augment_parser_edge_tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "minimax"))]
 mod minimax_parser_edge_tests {
     use crate::minimax::parser::parse_minimax_response;
 
@@ -401,7 +401,7 @@ mod minimax_parser_edge_tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "zai"))]
 mod zai_parser_edge_tests {
     use crate::zai::parser::parse_zai_response;
 
@@ -413,7 +413,7 @@ mod zai_parser_edge_tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "kiro"))]
 mod kiro_parser_edge_tests {
     use crate::kiro::parser::parse_kiro_response;
 
@@ -425,7 +425,7 @@ mod kiro_parser_edge_tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "vertexai"))]
 mod vertexai_parser_edge_tests {
     use crate::vertexai::parser::parse_vertexai_response;
 
@@ -437,7 +437,7 @@ mod vertexai_parser_edge_tests {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "antigravity"))]
 mod antigravity_parser_edge_tests {
     // Antigravity likely uses local probe, test that module
 }
@@ -0,0 +1,100 @@
+//! Per-project token attribution.
+//!
+//! CLI providers (Claude Code, Codex) log each session locally along with
+//! the directory it ran in. Summing the tokens and requests in those logs
+//! per directory shows which projects are using up the quota.
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+pub use crate::descriptor::ProjectUsage;
+
+/// Token and request totals per project directory.
+#[derive(Debug, Default)]
+pub(crate) struct ProjectTotals(HashMap<PathBuf, (u64, u64)>);
+
+impl ProjectTotals {
+    /// Adds a request made in `path` and the tokens it used.
+    pub(crate) fn add(&mut self, path: &Path, tokens: u64) {
+        if tokens > 0 {
+            let (total, requests) = self.0.entry(path.to_path_buf()).or_default();
+            *total += tokens;
+            *requests += 1;
+        }
+    }
+
+    /// Returns the projects, most tokens first.
+    pub(crate) fn ranked(self) -> Vec<ProjectUsage> {
+        let mut projects: Vec<_> = self
+            .0
+            .into_iter()
+            .map(|(path, (tokens, requests))| ProjectUsage {
+                path,
+                tokens,
+                requests,
+            })
+            .collect();
+        projects.sort_by(|a, b| b.tokens.cmp(&a.tokens).then_with(|| a.path.cmp(&b.path)));
+        projects
+    }
+}
+
+/// Lists the `.jsonl` files under `dir`, at any depth, modified at or after
+/// `since`. Older files can't hold entries from after `since`.
+pub(crate) fn session_logs(dir: &Path, since: DateTime<Utc>) -> Vec<PathBuf> {
+    let since = SystemTime::from(since);
+    let mut logs = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                dirs.push(path);
+            } else if path.extension().and_then(|e| e.to_str()) == Some("jsonl")
+                && metadata.modified().is_ok_and(|modified| modified >= since)
+            {
+                logs.push(path);
+            }
+        }
+    }
+    logs
+}
+
+/// Parses an RFC 3339 log timestamp.
+pub(crate) fn parse_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranked() {
+        let mut totals = ProjectTotals::default();
+        totals.add(Path::new("/src/small"), 10);
+        totals.add(Path::new("/src/big"), 500);
+        totals.add(Path::new("/src/small"), 15);
+        totals.add(Path::new("/src/idle"), 0);
+
+        let projects = totals.ranked();
+        assert_eq!(projects.len(), 2);
+        assert_eq!(projects[0].name(), "big");
+        assert_eq!(projects[1].tokens, 25);
+        assert_eq!(projects[1].requests, 2);
+    }
+}
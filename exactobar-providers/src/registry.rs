@@ -5,21 +5,37 @@
 
 use exactobar_core::ProviderKind;
 use std::collections::HashMap;
-use std::sync::OnceLock;
+use std::sync::{OnceLock, RwLock};
 
+#[cfg(feature = "antigravity")]
 use crate::antigravity::antigravity_descriptor;
+#[cfg(feature = "augment")]
 use crate::augment::augment_descriptor;
+#[cfg(feature = "claude")]
 use crate::claude::claude_descriptor;
+#[cfg(feature = "codex")]
 use crate::codex::codex_descriptor;
+#[cfg(feature = "copilot")]
 use crate::copilot::copilot_descriptor;
+#[cfg(feature = "cursor")]
 use crate::cursor::cursor_descriptor;
+#[cfg(feature = "demo")]
+use crate::demo::demo_descriptor;
 use crate::descriptor::ProviderDescriptor;
+#[cfg(feature = "factory")]
 use crate::factory::factory_descriptor;
+#[cfg(feature = "gemini")]
 use crate::gemini::gemini_descriptor;
+#[cfg(feature = "kiro")]
 use crate::kiro::kiro_descriptor;
+use crate::manifest::MetadataManifest;
+#[cfg(feature = "minimax")]
 use crate::minimax::minimax_descriptor;
+#[cfg(feature = "synthetic")]
 use crate::synthetic::synthetic_descriptor;
+#[cfg(feature = "vertexai")]
 use crate::vertexai::vertexai_descriptor;
+#[cfg(feature = "zai")]
 use crate::zai::zai_descriptor;
 
 // ============================================================================
@@ -27,7 +43,11 @@ use crate::zai::zai_descriptor;
 // ============================================================================
 
 /// Static storage for all provider descriptors.
-static DESCRIPTORS: OnceLock<Vec<ProviderDescriptor>> = OnceLock::new();
+///
+/// Replaced when a metadata manifest is applied. Replaced descriptors are
+/// leaked rather than freed, since callers hold `'static` references to
+/// them; manifests are applied at most a few times per run.
+static DESCRIPTORS: RwLock<Option<&'static [ProviderDescriptor]>> = RwLock::new(None);
 
 /// Static storage for CLI name to provider kind mapping.
 static CLI_NAME_MAP: OnceLock<HashMap<String, ProviderKind>> = OnceLock::new();
@@ -39,25 +59,44 @@ static CLI_NAME_MAP: OnceLock<HashMap<String, ProviderKind>> = OnceLock::new();
 /// 2. Popular IDE providers (Cursor, Copilot)
 /// 3. Cloud providers (Gemini, VertexAI)
 /// 4. Other providers (Factory, Zai, Augment, Kiro, MiniMax, Antigravity)
+/// 5. The demo provider, when built with the `demo` feature
+///
+/// Providers whose cargo feature is off are left out.
 fn init_descriptors() -> Vec<ProviderDescriptor> {
     vec![
         // Primary providers
+        #[cfg(feature = "codex")]
         codex_descriptor(),
+        #[cfg(feature = "claude")]
         claude_descriptor(),
         // IDE providers
+        #[cfg(feature = "cursor")]
         cursor_descriptor(),
+        #[cfg(feature = "copilot")]
         copilot_descriptor(),
         // Cloud providers
+        #[cfg(feature = "gemini")]
         gemini_descriptor(),
+        #[cfg(feature = "vertexai")]
         vertexai_descriptor(),
         // Other providers
+        #[cfg(feature = "factory")]
         factory_descriptor(),
+        #[cfg(feature = "zai")]
         zai_descriptor(),
+        #[cfg(feature = "augment")]
         augment_descriptor(),
+        #[cfg(feature = "kiro")]
         kiro_descriptor(),
+        #[cfg(feature = "minimax")]
         minimax_descriptor(),
+        #[cfg(feature = "antigravity")]
         antigravity_descriptor(),
+        #[cfg(feature = "synthetic")]
         synthetic_descriptor(),
+        // Development
+        #[cfg(feature = "demo")]
+        demo_descriptor(),
     ]
 }
 
@@ -91,7 +130,31 @@ pub struct ProviderRegistry;
 impl ProviderRegistry {
     /// Returns all provider descriptors.
     pub fn all() -> &'static [ProviderDescriptor] {
-        DESCRIPTORS.get_or_init(init_descriptors)
+        if let Some(descriptors) = DESCRIPTORS.read().ok().and_then(|d| *d) {
+            return descriptors;
+        }
+
+        let mut current = DESCRIPTORS.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(descriptors) = *current {
+            return descriptors;
+        }
+        let descriptors: &'static [ProviderDescriptor] = Vec::leak(init_descriptors());
+        *current = Some(descriptors);
+        descriptors
+    }
+
+    /// Replaces the providers' metadata with the compiled-in defaults
+    /// corrected by `manifest`.
+    pub fn apply_manifest(manifest: &MetadataManifest) {
+        let mut descriptors = init_descriptors();
+        for descriptor in &mut descriptors {
+            if let Some(correction) = manifest.correction(descriptor.id) {
+                correction.apply(&mut descriptor.metadata);
+            }
+        }
+
+        let mut current = DESCRIPTORS.write().unwrap_or_else(|e| e.into_inner());
+        *current = Some(Vec::leak(descriptors));
     }
 
     /// Gets a provider descriptor by kind.
@@ -149,14 +212,17 @@ impl ProviderRegistry {
 // Tests
 // ============================================================================
 
-#[cfg(test)]
+#[cfg(all(test, feature = "all-providers"))]
 mod tests {
     use super::*;
 
+    /// Registered providers, counting the demo provider if it's built in.
+    const PROVIDER_COUNT: usize = if cfg!(feature = "demo") { 14 } else { 13 };
+
     #[test]
     fn test_registry_all_13_providers() {
         let all = ProviderRegistry::all();
-        assert_eq!(all.len(), 13, "Should have exactly 13 providers");
+        assert_eq!(all.len(), PROVIDER_COUNT);
     }
 
     #[test]
@@ -231,12 +297,12 @@ mod tests {
 
     #[test]
     fn test_provider_count() {
-        assert_eq!(ProviderRegistry::count(), 13);
+        assert_eq!(ProviderRegistry::count(), PROVIDER_COUNT);
     }
 
     #[test]
     fn test_all_kinds_returned() {
         let kinds = ProviderRegistry::kinds();
-        assert_eq!(kinds.len(), 13);
+        assert_eq!(kinds.len(), PROVIDER_COUNT);
     }
 }
@@ -52,6 +52,11 @@ pub struct SearchInfo {
 }
 
 impl SyntheticQuotaResponse {
+    /// Parses a quota response body.
+    pub fn parse(body: &str) -> Result<Self, SyntheticError> {
+        serde_json::from_str(body).map_err(|e| SyntheticError::ParseError(e.to_string()))
+    }
+
     /// Convert to UsageSnapshot.
     pub fn to_snapshot(&self) -> exactobar_core::UsageSnapshot {
         use exactobar_core::{FetchSource, LoginMethod, ProviderIdentity, ProviderKind};
@@ -139,6 +144,13 @@ impl SyntheticApiClient {
         }
     }
 
+    /// Creates a client with a custom base URL.
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+        }
+    }
+
     /// Get API key from Keychain first, then environment variable.
     ///
     /// The lookup order is:
@@ -155,23 +167,30 @@ impl SyntheticApiClient {
     }
 
     /// Fetch quota from the API.
-    #[instrument(skip(self, api_key))]
     pub async fn fetch_quota(
         &self,
         api_key: &str,
     ) -> Result<SyntheticQuotaResponse, SyntheticError> {
+        let body = self.fetch_quota_body(api_key).await?;
+        SyntheticQuotaResponse::parse(&body)
+    }
+
+    /// Fetch the quota response body from the API, unparsed.
+    #[instrument(skip(self, api_key))]
+    pub async fn fetch_quota_body(&self, api_key: &str) -> Result<String, SyntheticError> {
         let url = format!("{}{}", self.base_url, QUOTA_ENDPOINT);
 
         debug!(url = %url, "Fetching Synthetic.new quota");
 
-        let client = reqwest::Client::new();
-        let response = client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", api_key))
-            .header("Content-Type", "application/json")
-            .send()
-            .await
-            .map_err(|e| SyntheticError::HttpError(e.to_string()))?;
+        let client = exactobar_fetch::network::client();
+        let response = exactobar_fetch::network::send(
+            client
+                .get(&url)
+                .header("Authorization", format!("Bearer {}", api_key))
+                .header("Content-Type", "application/json"),
+        )
+        .await
+        .map_err(|e| SyntheticError::HttpError(e.to_string()))?;
 
         let status = response.status();
 
@@ -190,9 +209,9 @@ impl SyntheticApiClient {
         }
 
         response
-            .json()
+            .text()
             .await
-            .map_err(|e| SyntheticError::ParseError(e.to_string()))
+            .map_err(|e| SyntheticError::HttpError(e.to_string()))
     }
 }
 
@@ -209,6 +228,9 @@ mod tests {
     fn test_client_creation() {
         let client = SyntheticApiClient::new();
         assert_eq!(client.base_url, API_BASE_URL);
+
+        let client = SyntheticApiClient::with_base_url("https://gateway.example.com");
+        assert_eq!(client.base_url, "https://gateway.example.com");
     }
 
     #[test]
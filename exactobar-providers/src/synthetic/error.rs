@@ -1,5 +1,6 @@
 //! Synthetic.new-specific errors.
 
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// Synthetic.new-specific errors.
@@ -31,3 +32,17 @@ impl From<reqwest::Error> for SyntheticError {
         SyntheticError::HttpError(err.to_string())
     }
 }
+
+impl From<SyntheticError> for ProviderError {
+    fn from(err: SyntheticError) -> Self {
+        let message = err.to_string();
+        match err {
+            SyntheticError::ApiKeyNotFound | SyntheticError::AuthenticationFailed(_) => {
+                ProviderError::NotLoggedIn(message)
+            }
+            SyntheticError::HttpError(_) => ProviderError::Network(message),
+            SyntheticError::ParseError(_) => ProviderError::Parse(message),
+            SyntheticError::ApiError(_) => ProviderError::Unexpected(message),
+        }
+    }
+}
@@ -1,12 +1,11 @@
 //! Synthetic.new fetch strategies.
 
 use async_trait::async_trait;
-#[allow(unused_imports)]
-use exactobar_core::UsageSnapshot;
+use exactobar_core::{ProviderError, ProviderKind, UsageSnapshot};
 use exactobar_fetch::{FetchContext, FetchError, FetchKind, FetchResult, FetchStrategy};
 use tracing::{debug, instrument};
 
-use super::api::SyntheticApiClient;
+use super::api::{SyntheticApiClient, SyntheticQuotaResponse};
 
 // ============================================================================
 // API Key Strategy
@@ -45,23 +44,33 @@ impl FetchStrategy for SyntheticApiStrategy {
         SyntheticApiClient::get_api_key().is_ok()
     }
 
-    #[instrument(skip(self, _ctx))]
-    async fn fetch(&self, _ctx: &FetchContext) -> Result<FetchResult, FetchError> {
+    #[instrument(skip(self, ctx))]
+    async fn fetch(&self, ctx: &FetchContext) -> Result<FetchResult, FetchError> {
         debug!("Fetching Synthetic.new usage via API key");
 
-        let api_key = SyntheticApiClient::get_api_key()
-            .map_err(|e| FetchError::AuthenticationFailed(e.to_string()))?;
+        let api_key = SyntheticApiClient::get_api_key().map_err(ProviderError::from)?;
 
-        let client = SyntheticApiClient::new();
-        let response = client
-            .fetch_quota(&api_key)
+        let client = ctx
+            .base_url(ProviderKind::Synthetic)
+            .map_or_else(SyntheticApiClient::new, SyntheticApiClient::with_base_url);
+        let body = client
+            .fetch_quota_body(&api_key)
             .await
-            .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
+            .map_err(ProviderError::from)?;
+        let response = SyntheticQuotaResponse::parse(&body).map_err(ProviderError::from)?;
 
         debug!("Synthetic.new quota fetched successfully");
         let snapshot = response.to_snapshot();
 
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(Some(body)))
+    }
+
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(
+            SyntheticQuotaResponse::parse(raw)
+                .map(|response| response.to_snapshot())
+                .map_err(|e| ProviderError::from(e).into()),
+        )
     }
 
     fn priority(&self) -> u32 {
@@ -90,4 +99,32 @@ mod tests {
         let s = SyntheticApiStrategy;
         assert_eq!(s.id(), "synthetic.api");
     }
+
+    #[test]
+    fn test_parse_raw() {
+        let raw = r#"{"subscription": {"limit": 100, "requests": 25.0, "renewsAt": null}}"#;
+        let snapshot = SyntheticApiStrategy::new().parse_raw(raw).unwrap().unwrap();
+        assert_eq!(snapshot.primary.unwrap().used_percent, 25.0);
+
+        let error = SyntheticApiStrategy::new()
+            .parse_raw("{")
+            .unwrap()
+            .unwrap_err();
+        assert!(matches!(error.classify(), ProviderError::Parse(_)));
+    }
+
+    #[test]
+    fn test_errors_keep_their_kind() {
+        use super::super::error::SyntheticError;
+
+        let error = FetchError::from(ProviderError::from(SyntheticError::AuthenticationFailed(
+            "invalid key".to_string(),
+        )));
+        assert!(error.is_auth_error());
+
+        let error = FetchError::from(ProviderError::from(SyntheticError::HttpError(
+            "connection reset".to_string(),
+        )));
+        assert!(matches!(error.classify(), ProviderError::Network(_)));
+    }
 }
@@ -138,7 +138,7 @@ pub struct VertexAITokenRefresher {
 impl VertexAITokenRefresher {
     /// Create a new token refresher.
     pub fn new() -> Self {
-        let http = reqwest::Client::builder()
+        let http = exactobar_fetch::network::client_builder()
             .timeout(std::time::Duration::from_secs(HTTP_TIMEOUT_SECS))
             .build()
             .expect("Failed to build HTTP client");
@@ -170,11 +170,7 @@ impl VertexAITokenRefresher {
 
         info!("Refreshing VertexAI OAuth token");
 
-        let response = self
-            .http
-            .post(TOKEN_ENDPOINT)
-            .form(&params)
-            .send()
+        let response = exactobar_fetch::network::send(self.http.post(TOKEN_ENDPOINT).form(&params))
             .await
             .map_err(|e| {
                 if e.is_timeout() {
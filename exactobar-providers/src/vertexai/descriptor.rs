@@ -52,6 +52,7 @@ fn vertexai_token_cost() -> TokenCostConfig {
     TokenCostConfig {
         supports_token_cost: true,
         log_directory: Some(vertexai_log_directory),
+        project_usage: None,
     }
 }
 
@@ -1,5 +1,6 @@
 //! VertexAI-specific errors.
 
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// VertexAI-specific errors.
@@ -69,3 +70,19 @@ impl From<reqwest::Error> for VertexAIError {
         }
     }
 }
+
+impl From<VertexAIError> for ProviderError {
+    fn from(err: VertexAIError) -> Self {
+        let message = err.to_string();
+        match err {
+            VertexAIError::NotLoggedIn
+            | VertexAIError::AuthenticationFailed(_)
+            | VertexAIError::NoCredentials => ProviderError::NotLoggedIn(message),
+            VertexAIError::Timeout | VertexAIError::HttpError(_) => ProviderError::Network(message),
+            VertexAIError::ParseError(_)
+            | VertexAIError::InvalidResponse(_)
+            | VertexAIError::LogParseError(_) => ProviderError::Parse(message),
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+}
@@ -139,6 +139,7 @@ impl VertexAIUsageFetcher {
             usage.total_tokens, usage.total_cost_usd
         ));
         snapshot.identity = Some(identity);
+        snapshot.model_breakdown = usage.models.into_values().collect();
 
         Ok(snapshot)
     }
@@ -3,9 +3,11 @@
 //! Reads Claude usage logs from local storage to track token costs.
 //! Log path: `~/.local/share/claude/logs/*.jsonl`
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use chrono::{DateTime, Utc};
+use exactobar_core::ModelBreakdown;
 use serde::Deserialize;
 use tracing::{debug, instrument, warn};
 
@@ -70,6 +72,10 @@ pub struct TokenUsage {
 
     /// Latest entry.
     pub latest: Option<DateTime<Utc>>,
+
+    /// Tokens and cost per model. Cost is only set where every entry
+    /// logged one.
+    pub models: BTreeMap<String, ModelBreakdown>,
 }
 
 impl TokenUsage {
@@ -88,6 +94,21 @@ impl TokenUsage {
         self.total_cost_usd += entry.cost_usd.unwrap_or(0.0);
         self.request_count += 1;
 
+        if let Some(ref model) = entry.model {
+            let breakdown = self.models.entry(model.clone()).or_insert_with(|| {
+                let mut breakdown = ModelBreakdown::new(model.clone());
+                breakdown.cost_usd = Some(0.0);
+                breakdown
+            });
+            breakdown.requests = Some(breakdown.requests.unwrap_or(0) + 1);
+            breakdown.input_tokens =
+                Some(breakdown.input_tokens.unwrap_or(0) + entry.input_tokens.unwrap_or(0));
+            breakdown.output_tokens =
+                Some(breakdown.output_tokens.unwrap_or(0) + entry.output_tokens.unwrap_or(0));
+            // Unlogged costs are left for the pricing table to estimate
+            breakdown.cost_usd = breakdown.cost_usd.zip(entry.cost_usd).map(|(a, b)| a + b);
+        }
+
         // Track time range
         if let Some(ref ts) = entry.timestamp {
             if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(ts) {
@@ -295,5 +316,16 @@ mod tests {
         assert_eq!(usage.output_tokens, 50);
         assert_eq!(usage.total_tokens, 150);
         assert_eq!(usage.request_count, 1);
+        assert_eq!(usage.models["claude-3"].cost_usd, Some(0.01));
+
+        // A request without a logged cost leaves the model's cost unknown
+        usage.add_entry(&ClaudeLogEntry {
+            cost_usd: None,
+            ..entry
+        });
+        let model = &usage.models["claude-3"];
+        assert_eq!(model.requests, Some(2));
+        assert_eq!(model.total_tokens(), 300);
+        assert_eq!(model.cost_usd, None);
     }
 }
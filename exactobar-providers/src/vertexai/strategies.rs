@@ -1,7 +1,7 @@
 //! VertexAI fetch strategies.
 
 use async_trait::async_trait;
-use exactobar_core::{FetchSource, UsageSnapshot};
+use exactobar_core::{FetchSource, ProviderError, UsageSnapshot};
 use exactobar_fetch::{FetchContext, FetchError, FetchKind, FetchResult, FetchStrategy};
 use std::path::PathBuf;
 use tracing::{debug, info, instrument};
@@ -79,14 +79,9 @@ impl FetchStrategy for VertexAIOAuthStrategy {
     async fn fetch(&self, _ctx: &FetchContext) -> Result<FetchResult, FetchError> {
         debug!("Fetching VertexAI usage via OAuth (ADC)");
 
-        let token = self
-            .get_access_token()
-            .await
-            .map_err(|e| FetchError::AuthenticationFailed(e.to_string()))?;
+        let token = self.get_access_token().await.map_err(ProviderError::from)?;
 
-        let project = self
-            .get_project_id()
-            .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
+        let project = self.get_project_id().map_err(ProviderError::from)?;
 
         info!(
             project = %project,
@@ -122,7 +122,7 @@ pub struct ZaiApiClient {
 impl ZaiApiClient {
     /// Creates a new client.
     pub fn new() -> Self {
-        let http = reqwest::Client::builder()
+        let http = exactobar_fetch::network::client_builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
             .expect("Failed to build HTTP client");
@@ -155,7 +155,7 @@ impl ZaiApiClient {
         let url = format!("{}{}", ZAI_API_BASE, USAGE_ENDPOINT);
         let headers = self.build_headers(token)?;
 
-        let response = self.http.get(&url).headers(headers).send().await?;
+        let response = exactobar_fetch::network::send(self.http.get(&url).headers(headers)).await?;
 
         let status = response.status();
 
@@ -1,5 +1,6 @@
 //! z.ai-specific errors.
 
+use exactobar_core::ProviderError;
 use thiserror::Error;
 
 /// z.ai-specific errors.
@@ -39,3 +40,17 @@ impl From<reqwest::Error> for ZaiError {
         ZaiError::HttpError(err.to_string())
     }
 }
+
+impl From<ZaiError> for ProviderError {
+    fn from(err: ZaiError) -> Self {
+        let message = err.to_string();
+        match err {
+            ZaiError::AuthenticationFailed(_) | ZaiError::NoToken => {
+                ProviderError::NotLoggedIn(message)
+            }
+            ZaiError::HttpError(_) => ProviderError::Network(message),
+            ZaiError::InvalidResponse(_) => ProviderError::Parse(message),
+            _ => ProviderError::Unexpected(message),
+        }
+    }
+}
@@ -77,7 +77,11 @@ impl FetchStrategy for ZaiApiStrategy {
             .map_err(|e| FetchError::InvalidResponse(e.to_string()))?;
 
         let snapshot = parse_zai_response(&body)?;
-        Ok(FetchResult::new(snapshot, self.id(), self.kind()))
+        Ok(FetchResult::new(snapshot, self.id(), self.kind()).with_raw_response(Some(body)))
+    }
+
+    fn parse_raw(&self, raw: &str) -> Option<Result<UsageSnapshot, FetchError>> {
+        Some(parse_zai_response(raw))
     }
 
     fn priority(&self) -> u32 {
@@ -88,6 +92,7 @@ impl FetchStrategy for ZaiApiStrategy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use exactobar_core::ProviderError;
 
     #[test]
     fn test_api_strategy() {
@@ -95,4 +100,14 @@ mod tests {
         assert_eq!(s.id(), "zai.api");
         assert_eq!(s.priority(), 100);
     }
+
+    #[test]
+    fn test_parse_raw() {
+        let raw = r#"{"usage": {"requests": 50, "limit": 100}}"#;
+        let snapshot = ZaiApiStrategy::new().parse_raw(raw).unwrap().unwrap();
+        assert_eq!(snapshot.primary.unwrap().used_percent, 50.0);
+
+        let error = ZaiApiStrategy::new().parse_raw("{").unwrap().unwrap_err();
+        assert!(matches!(error.classify(), ProviderError::Parse(_)));
+    }
 }
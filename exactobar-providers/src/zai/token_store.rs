@@ -167,6 +167,10 @@ impl ZaiTokenStore {
         entry
             .set_password(token)
             .map_err(|e| ZaiError::KeychainError(e.to_string()))?;
+        exactobar_fetch::host::keychain::invalidate_cache_entry(
+            LEGACY_KEYCHAIN_SERVICES[0],
+            LEGACY_KEYCHAIN_ACCOUNT,
+        );
 
         debug!("z.ai token saved to keychain");
         Ok(())
@@ -180,6 +184,10 @@ impl ZaiTokenStore {
                 // Ignore errors - token might not exist in all locations
                 let _ = entry.delete_credential();
             }
+            exactobar_fetch::host::keychain::invalidate_cache_entry(
+                service,
+                LEGACY_KEYCHAIN_ACCOUNT,
+            );
         }
 
         debug!("z.ai token deleted from keychain");
@@ -1,38 +1,164 @@
 //! Usage history tracking.
+//!
+//! Records usage snapshots per provider so the UI can show how each
+//! window has moved over time (e.g., sparklines since the last reset,
+//! and the charts and reset table in the usage dashboard).
+//!
+//! Only the latest snapshots are kept in full. Every snapshot is also rolled
+//! up into hourly peaks and averages, which stay until pruned, so daily,
+//! weekly and monthly views don't need every raw entry.
 
-use chrono::{DateTime, Utc};
-use exactobar_core::{ProviderKind, UsageData};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike, Utc};
+use exactobar_core::{ProviderKind, UsageSnapshot, WindowKind};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 
 /// Maximum number of history entries per provider.
 const MAX_HISTORY_ENTRIES: usize = 1000;
 
+/// A drop in usage larger than this (in percentage points) is treated as a reset.
+const RESET_DROP_THRESHOLD: f64 = 1.0;
+
+/// How far back the burn rate looks.
+const BURN_RATE_LOOKBACK: Duration = Duration::hours(1);
+
+/// Shortest span of readings a burn rate is measured over.
+const BURN_RATE_MIN_SPAN: Duration = Duration::minutes(10);
+
 /// A single history entry.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoryEntry {
-    /// Timestamp of the entry.
-    pub timestamp: DateTime<Utc>,
-    /// Usage value at this point.
-    pub value: f64,
-    /// Unit of measurement.
-    pub unit: String,
+    /// When the snapshot was recorded.
+    pub recorded_at: DateTime<Utc>,
+    /// The recorded snapshot.
+    pub snapshot: UsageSnapshot,
 }
 
-impl From<&UsageData> for HistoryEntry {
-    fn from(usage: &UsageData) -> Self {
+impl From<&UsageSnapshot> for HistoryEntry {
+    fn from(snapshot: &UsageSnapshot) -> Self {
         Self {
-            timestamp: usage.fetched_at,
-            value: usage.current_usage,
-            unit: usage.unit.clone(),
+            recorded_at: snapshot.updated_at,
+            snapshot: snapshot.clone(),
+        }
+    }
+}
+
+/// A detected reset of a usage window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResetEvent {
+    /// The window that reset.
+    pub window: WindowKind,
+    /// When the reset was first observed.
+    pub observed_at: DateTime<Utc>,
+    /// Highest usage reached before the reset.
+    pub peak_percent: f64,
+}
+
+/// Length of the periods usage is aggregated over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AggregatePeriod {
+    /// Calendar days.
+    Day,
+    /// Weeks starting on Monday.
+    Week,
+    /// Calendar months.
+    Month,
+}
+
+impl AggregatePeriod {
+    /// Returns the first day of the period containing `day`.
+    pub fn start_of(self, day: NaiveDate) -> NaiveDate {
+        match self {
+            AggregatePeriod::Day => day,
+            AggregatePeriod::Week => {
+                day - Duration::days(i64::from(day.weekday().num_days_from_monday()))
+            }
+            AggregatePeriod::Month => day.with_day(1).unwrap_or(day),
         }
     }
+
+    /// Returns the first day of the period after the one starting on `start`.
+    pub fn next_start(self, start: NaiveDate) -> NaiveDate {
+        match self {
+            AggregatePeriod::Day => start + Duration::days(1),
+            AggregatePeriod::Week => start + Duration::days(7),
+            AggregatePeriod::Month => start
+                .checked_add_months(chrono::Months::new(1))
+                .unwrap_or(start),
+        }
+    }
+
+    /// Returns the start of each period from the one containing `first` to
+    /// the one containing `last`.
+    pub fn starts_between(self, first: NaiveDate, last: NaiveDate) -> Vec<NaiveDate> {
+        let mut starts = Vec::new();
+        let mut start = self.start_of(first);
+        while start <= last {
+            starts.push(start);
+            let next = self.next_start(start);
+            if next <= start {
+                break;
+            }
+            start = next;
+        }
+        starts
+    }
+}
+
+/// Usage of a window over one period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageAggregate {
+    /// First day of the period.
+    pub start: NaiveDate,
+    /// Highest used percentage recorded.
+    pub peak_percent: f64,
+    /// Mean of the used percentages recorded.
+    pub average_percent: f64,
+    /// Number of readings.
+    pub samples: u32,
+}
+
+/// Readings of one window within one hour.
+#[derive(Debug, Clone, Copy, Default)]
+struct HourStats {
+    peak: f64,
+    sum: f64,
+    count: u32,
 }
 
+impl HourStats {
+    fn add(&mut self, percent: f64) {
+        self.peak = if self.count == 0 {
+            percent
+        } else {
+            self.peak.max(percent)
+        };
+        self.sum += percent;
+        self.count += 1;
+    }
+
+    fn merge(&mut self, other: HourStats) {
+        if other.count == 0 {
+            return;
+        }
+        self.peak = if self.count == 0 {
+            other.peak
+        } else {
+            self.peak.max(other.peak)
+        };
+        self.sum += other.sum;
+        self.count += other.count;
+    }
+}
+
+/// Hourly stats per provider window, keyed by the start of the hour.
+type Rollups = HashMap<(ProviderKind, WindowKind), BTreeMap<DateTime<Utc>, HourStats>>;
+
 /// Tracks usage history for all providers.
 #[derive(Debug, Default)]
 pub struct UsageHistory {
     entries: HashMap<ProviderKind, VecDeque<HistoryEntry>>,
+    rollups: Rollups,
 }
 
 impl UsageHistory {
@@ -41,13 +167,26 @@ impl UsageHistory {
         Self::default()
     }
 
-    /// Records a new usage data point.
-    pub fn record(&mut self, usage: &UsageData) {
-        let entry = HistoryEntry::from(usage);
-        let entries = self
-            .entries
-            .entry(usage.provider_kind)
-            .or_insert_with(VecDeque::new);
+    /// Records a new snapshot for a provider.
+    pub fn record(&mut self, provider: ProviderKind, snapshot: &UsageSnapshot) {
+        self.push(provider, HistoryEntry::from(snapshot));
+    }
+
+    /// Adds an entry after the provider's existing ones.
+    pub fn push(&mut self, provider: ProviderKind, entry: HistoryEntry) {
+        let hour = start_of_hour(entry.recorded_at);
+        for &window in WindowKind::all() {
+            if let Some(w) = entry.snapshot.window(window) {
+                self.rollups
+                    .entry((provider, window))
+                    .or_default()
+                    .entry(hour)
+                    .or_default()
+                    .add(w.used_percent);
+            }
+        }
+
+        let entries = self.entries.entry(provider).or_default();
 
         entries.push_back(entry);
 
@@ -57,6 +196,86 @@ impl UsageHistory {
         }
     }
 
+    /// Adds entries from `older` (e.g. loaded from disk) before the ones
+    /// already recorded, skipping any that aren't older than those.
+    ///
+    /// Rollups are combined as they are, so `older` shouldn't repeat
+    /// entries recorded here.
+    pub fn merge_older(&mut self, older: UsageHistory) {
+        for (key, hours) in older.rollups {
+            let rollup = self.rollups.entry(key).or_default();
+            for (hour, stats) in hours {
+                rollup.entry(hour).or_default().merge(stats);
+            }
+        }
+
+        for (provider, older_entries) in older.entries {
+            let entries = self.entries.entry(provider).or_default();
+            let first = entries.front().map(|e| e.recorded_at);
+            let room = MAX_HISTORY_ENTRIES.saturating_sub(entries.len());
+            let merged: Vec<HistoryEntry> = older_entries
+                .into_iter()
+                .filter(|e| first.is_none_or(|first| e.recorded_at < first))
+                .collect();
+            for entry in merged.into_iter().rev().take(room) {
+                entries.push_front(entry);
+            }
+        }
+    }
+
+    /// Drops entries recorded before `cutoff`.
+    pub fn prune(&mut self, cutoff: DateTime<Utc>) {
+        for entries in self.entries.values_mut() {
+            entries.retain(|e| e.recorded_at >= cutoff);
+        }
+        self.entries.retain(|_, entries| !entries.is_empty());
+
+        // The hour containing the cutoff is kept whole
+        let cutoff_hour = start_of_hour(cutoff);
+        for hours in self.rollups.values_mut() {
+            *hours = hours.split_off(&cutoff_hour);
+        }
+        self.rollups.retain(|_, hours| !hours.is_empty());
+    }
+
+    /// Returns a window's peak and average usage per period, oldest first,
+    /// with periods following the calendar in `tz`.
+    ///
+    /// Built from hourly rollups, so it covers history older than the
+    /// entries kept in full. Hours start on the hour in UTC, so in time
+    /// zones offset by a half hour an hour's readings count toward the day
+    /// it started in.
+    pub fn aggregate<Tz: TimeZone>(
+        &self,
+        kind: ProviderKind,
+        window: WindowKind,
+        period: AggregatePeriod,
+        tz: &Tz,
+    ) -> Vec<UsageAggregate> {
+        let Some(hours) = self.rollups.get(&(kind, window)) else {
+            return Vec::new();
+        };
+
+        let mut periods: BTreeMap<NaiveDate, HourStats> = BTreeMap::new();
+        for (hour, stats) in hours {
+            let day = hour.with_timezone(tz).date_naive();
+            periods
+                .entry(period.start_of(day))
+                .or_default()
+                .merge(*stats);
+        }
+
+        periods
+            .into_iter()
+            .map(|(start, stats)| UsageAggregate {
+                start,
+                peak_percent: stats.peak,
+                average_percent: stats.sum / f64::from(stats.count.max(1)),
+                samples: stats.count,
+            })
+            .collect()
+    }
+
     /// Returns history for a specific provider.
     pub fn get(&self, kind: ProviderKind) -> Option<&VecDeque<HistoryEntry>> {
         self.entries.get(&kind)
@@ -74,7 +293,7 @@ impl UsageHistory {
             .map(|entries| {
                 entries
                     .iter()
-                    .filter(|e| e.timestamp >= start && e.timestamp <= end)
+                    .filter(|e| e.recorded_at >= start && e.recorded_at <= end)
                     .collect()
             })
             .unwrap_or_default()
@@ -85,13 +304,563 @@ impl UsageHistory {
         self.entries.get(&kind).and_then(|e| e.back())
     }
 
+    /// Returns the used percentages of a window since its last reset.
+    ///
+    /// A reset is detected when the window's `resets_at` moves forward or
+    /// its usage drops noticeably between two consecutive entries.
+    pub fn trajectory_since_reset(&self, kind: ProviderKind, window: WindowKind) -> Vec<f64> {
+        self.periods(kind, window)
+            .pop()
+            .map(|period| period.into_iter().map(|(_, percent)| percent).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns how fast a window has been filling, in percentage points per
+    /// hour, over the readings of the last hour since its last reset.
+    ///
+    /// Returns `None` if those readings span less than ten minutes, or
+    /// usage isn't rising.
+    pub fn burn_rate(
+        &self,
+        kind: ProviderKind,
+        window: WindowKind,
+        now: DateTime<Utc>,
+    ) -> Option<f64> {
+        let period = self.periods(kind, window).pop()?;
+        burn_rate(&period, now)
+    }
+
+    /// Returns how long until a window is used up at its recent burn rate.
+    pub fn time_until_limit(
+        &self,
+        kind: ProviderKind,
+        window: WindowKind,
+        now: DateTime<Utc>,
+    ) -> Option<Duration> {
+        let period = self.periods(kind, window).pop()?;
+        let rate = burn_rate(&period, now)?;
+        let &(_, current) = period.last()?;
+        let hours = (100.0 - current).max(0.0) / rate;
+        #[allow(clippy::cast_possible_truncation)]
+        Some(Duration::seconds((hours * 3600.0) as i64))
+    }
+
+    /// Returns every recorded `(time, used percent)` point for a window.
+    pub fn usage_points(
+        &self,
+        kind: ProviderKind,
+        window: WindowKind,
+    ) -> Vec<(DateTime<Utc>, f64)> {
+        self.entries
+            .get(&kind)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter_map(|e| Some((e.recorded_at, e.snapshot.window(window)?.used_percent)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the resets observed for a window, oldest first.
+    pub fn resets(&self, kind: ProviderKind, window: WindowKind) -> Vec<ResetEvent> {
+        self.periods(kind, window)
+            .windows(2)
+            .map(|pair| ResetEvent {
+                window,
+                observed_at: pair[1][0].0,
+                peak_percent: pair[0].iter().map(|&(_, p)| p).fold(0.0, f64::max),
+            })
+            .collect()
+    }
+
+    /// Returns the amount spent per day, oldest first.
+    ///
+    /// Providers report spend so far in the current billing period, so each
+    /// reading adds its increase over the one before to its day. The first
+    /// reading is only the baseline, since how much of it fell on that day
+    /// isn't known; a decrease means the period rolled over, and the reading
+    /// is then all spent in the new period.
+    pub fn daily_spend<Tz: TimeZone>(&self, kind: ProviderKind, tz: &Tz) -> Vec<(NaiveDate, f64)> {
+        let Some(entries) = self.entries.get(&kind) else {
+            return Vec::new();
+        };
+
+        let mut days: Vec<(NaiveDate, f64)> = Vec::new();
+        let mut previous: Option<f64> = None;
+        for entry in entries {
+            let Some(cost) = &entry.snapshot.cost else {
+                continue;
+            };
+            let spent = match previous {
+                Some(prev) if cost.used >= prev => cost.used - prev,
+                Some(_) => cost.used,
+                None => 0.0,
+            };
+            previous = Some(cost.used);
+
+            let day = entry.recorded_at.with_timezone(tz).date_naive();
+            match days.last_mut() {
+                Some((last_day, total)) if *last_day == day => *total += spent,
+                _ => days.push((day, spent)),
+            }
+        }
+        days
+    }
+
+    /// Splits a window's history into periods between resets, oldest first.
+    ///
+    /// A reset is detected when the window's `resets_at` moves forward or
+    /// its usage drops noticeably between two consecutive entries.
+    fn periods(&self, kind: ProviderKind, window: WindowKind) -> Vec<Vec<(DateTime<Utc>, f64)>> {
+        let Some(entries) = self.entries.get(&kind) else {
+            return Vec::new();
+        };
+
+        let mut periods: Vec<Vec<(DateTime<Utc>, f64)>> = Vec::new();
+        let mut last_reset_at: Option<DateTime<Utc>> = None;
+
+        for entry in entries {
+            let Some(w) = entry.snapshot.window(window) else {
+                continue;
+            };
+
+            let is_reset =
+                periods
+                    .last()
+                    .and_then(|period| period.last())
+                    .is_some_and(|&(_, prev)| {
+                        let moved_forward = matches!(
+                            (last_reset_at, w.resets_at),
+                            (Some(prev_reset), Some(reset)) if reset > prev_reset
+                        );
+                        let dropped = prev - w.used_percent > RESET_DROP_THRESHOLD;
+                        moved_forward || dropped
+                    });
+
+            if is_reset || periods.is_empty() {
+                periods.push(Vec::new());
+            }
+
+            if w.resets_at.is_some() {
+                last_reset_at = w.resets_at;
+            }
+            if let Some(period) = periods.last_mut() {
+                period.push((entry.recorded_at, w.used_percent));
+            }
+        }
+
+        periods
+    }
+
     /// Clears history for a specific provider.
     pub fn clear_provider(&mut self, kind: ProviderKind) {
         self.entries.remove(&kind);
+        self.rollups.retain(|(provider, _), _| *provider != kind);
     }
 
     /// Clears all history.
     pub fn clear_all(&mut self) {
         self.entries.clear();
+        self.rollups.clear();
+    }
+}
+
+/// Measures the burn rate over the readings of `period` within
+/// [`BURN_RATE_LOOKBACK`] of `now`.
+fn burn_rate(period: &[(DateTime<Utc>, f64)], now: DateTime<Utc>) -> Option<f64> {
+    let since = now - BURN_RATE_LOOKBACK;
+    let start = period.iter().position(|&(at, _)| at >= since)?;
+    let (first_at, first) = period[start];
+    let &(last_at, last) = period.last()?;
+    let span = last_at - first_at;
+    if span < BURN_RATE_MIN_SPAN {
+        return None;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let hours = span.num_seconds() as f64 / 3600.0;
+    let rate = (last - first) / hours;
+    (rate > 0.0).then_some(rate)
+}
+
+/// Truncates a time to the start of its hour.
+fn start_of_hour(at: DateTime<Utc>) -> DateTime<Utc> {
+    at.with_minute(0)
+        .and_then(|at| at.with_second(0))
+        .and_then(|at| at.with_nanosecond(0))
+        .unwrap_or(at)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use exactobar_core::{ProviderCost, UsageWindow};
+
+    fn snapshot(percent: f64, resets_at: Option<DateTime<Utc>>) -> UsageSnapshot {
+        let mut snapshot = UsageSnapshot::new();
+        let mut window = UsageWindow::new(percent);
+        window.resets_at = resets_at;
+        snapshot.primary = Some(window);
+        snapshot
+    }
+
+    #[test]
+    fn test_record_and_latest() {
+        let mut history = UsageHistory::new();
+        history.record(ProviderKind::Claude, &snapshot(10.0, None));
+        history.record(ProviderKind::Claude, &snapshot(20.0, None));
+
+        assert_eq!(history.get(ProviderKind::Claude).unwrap().len(), 2);
+        let latest = history.latest(ProviderKind::Claude).unwrap();
+        assert!((latest.snapshot.primary.as_ref().unwrap().used_percent - 20.0).abs() < 1e-9);
+        assert!(history.latest(ProviderKind::Codex).is_none());
+    }
+
+    #[test]
+    fn test_trajectory_resets_on_usage_drop() {
+        let mut history = UsageHistory::new();
+        for percent in [40.0, 60.0, 5.0, 15.0, 30.0] {
+            history.record(ProviderKind::Claude, &snapshot(percent, None));
+        }
+
+        let points = history.trajectory_since_reset(ProviderKind::Claude, WindowKind::Primary);
+        assert_eq!(points, vec![5.0, 15.0, 30.0]);
+    }
+
+    #[test]
+    fn test_trajectory_resets_when_reset_time_moves_forward() {
+        let mut history = UsageHistory::new();
+        let first = Utc::now() + Duration::hours(1);
+        let second = first + Duration::hours(5);
+
+        history.record(ProviderKind::Codex, &snapshot(10.0, Some(first)));
+        history.record(ProviderKind::Codex, &snapshot(12.0, Some(first)));
+        history.record(ProviderKind::Codex, &snapshot(12.0, Some(second)));
+
+        let points = history.trajectory_since_reset(ProviderKind::Codex, WindowKind::Primary);
+        assert_eq!(points, vec![12.0]);
+    }
+
+    #[test]
+    fn test_trajectory_skips_missing_window() {
+        let mut history = UsageHistory::new();
+        history.record(ProviderKind::Claude, &snapshot(10.0, None));
+
+        assert!(
+            history
+                .trajectory_since_reset(ProviderKind::Claude, WindowKind::Secondary)
+                .is_empty()
+        );
+    }
+
+    fn record_at(history: &mut UsageHistory, at: DateTime<Utc>, snapshot: UsageSnapshot) {
+        history.record(
+            ProviderKind::Claude,
+            &UsageSnapshot {
+                updated_at: at,
+                ..snapshot
+            },
+        );
+    }
+
+    #[test]
+    fn test_resets_report_peak_before_each_reset() {
+        let mut history = UsageHistory::new();
+        let start = Utc::now() - Duration::hours(10);
+        for (hour, percent) in (0..).zip([20.0, 70.0, 10.0, 90.0, 5.0]) {
+            record_at(
+                &mut history,
+                start + Duration::hours(hour),
+                snapshot(percent, None),
+            );
+        }
+
+        let resets = history.resets(ProviderKind::Claude, WindowKind::Primary);
+        assert_eq!(
+            resets,
+            vec![
+                ResetEvent {
+                    window: WindowKind::Primary,
+                    observed_at: start + Duration::hours(2),
+                    peak_percent: 70.0,
+                },
+                ResetEvent {
+                    window: WindowKind::Primary,
+                    observed_at: start + Duration::hours(4),
+                    peak_percent: 90.0,
+                },
+            ]
+        );
+        assert_eq!(
+            history
+                .usage_points(ProviderKind::Claude, WindowKind::Primary)
+                .len(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_daily_spend_uses_increase_per_day() {
+        let spend = |used: f64| {
+            let mut snapshot = UsageSnapshot::new();
+            snapshot.cost = Some(ProviderCost::new(used));
+            snapshot
+        };
+        let day = |d: u32, h: u32| Utc.with_ymd_and_hms(2025, 3, d, h, 0, 0).unwrap();
+
+        let mut history = UsageHistory::new();
+        record_at(&mut history, day(1, 9), spend(2.0));
+        record_at(&mut history, day(1, 18), spend(5.0));
+        record_at(&mut history, day(1, 20), snapshot(10.0, None));
+        record_at(&mut history, day(2, 12), spend(12.0));
+        // New billing period
+        record_at(&mut history, day(3, 12), spend(1.5));
+
+        // The first reading is the baseline, not day 1's spend
+        let date = |d: u32| NaiveDate::from_ymd_opt(2025, 3, d).unwrap();
+        assert_eq!(
+            history.daily_spend(ProviderKind::Claude, &Utc),
+            vec![(date(1), 3.0), (date(2), 7.0), (date(3), 1.5)]
+        );
+    }
+
+    #[test]
+    fn test_daily_spend_splits_a_rollover_day() {
+        let spend = |used: f64| {
+            let mut snapshot = UsageSnapshot::new();
+            snapshot.cost = Some(ProviderCost::new(used));
+            snapshot
+        };
+        let at = |h: u32| Utc.with_ymd_and_hms(2025, 3, 31, h, 0, 0).unwrap();
+
+        let mut history = UsageHistory::new();
+        record_at(&mut history, at(8), spend(40.0));
+        record_at(&mut history, at(12), spend(46.0));
+        // The period rolls over during the day
+        record_at(&mut history, at(16), spend(2.0));
+        record_at(&mut history, at(20), spend(3.5));
+
+        assert_eq!(
+            history.daily_spend(ProviderKind::Claude, &Utc),
+            vec![(NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(), 9.5)]
+        );
+    }
+
+    #[test]
+    fn test_merge_older() {
+        let now = Utc::now();
+        let at = |minutes: i64, percent: f64| {
+            let mut snapshot = snapshot(percent, None);
+            snapshot.updated_at = now + Duration::minutes(minutes);
+            snapshot
+        };
+
+        let mut history = UsageHistory::new();
+        history.record(ProviderKind::Claude, &at(10, 30.0));
+
+        // Entries from disk go first; one already recorded isn't repeated
+        let mut loaded = UsageHistory::new();
+        loaded.record(ProviderKind::Claude, &at(0, 10.0));
+        loaded.record(ProviderKind::Claude, &at(5, 20.0));
+        loaded.record(ProviderKind::Claude, &at(10, 30.0));
+        loaded.record(ProviderKind::Codex, &at(0, 50.0));
+        history.merge_older(loaded);
+
+        let points: Vec<f64> = history
+            .usage_points(ProviderKind::Claude, WindowKind::Primary)
+            .into_iter()
+            .map(|(_, percent)| percent)
+            .collect();
+        assert_eq!(points, vec![10.0, 20.0, 30.0]);
+        assert!(history.latest(ProviderKind::Codex).is_some());
+    }
+
+    #[test]
+    fn test_prune() {
+        let now = Utc::now();
+        let mut history = UsageHistory::new();
+        for days in [100, 50, 0] {
+            let mut snapshot = snapshot(1.0, None);
+            snapshot.updated_at = now - Duration::days(days);
+            history.record(ProviderKind::Claude, &snapshot);
+        }
+        let mut old = snapshot(1.0, None);
+        old.updated_at = now - Duration::days(200);
+        history.record(ProviderKind::Codex, &old);
+
+        history.prune(now - Duration::days(90));
+        assert_eq!(history.get(ProviderKind::Claude).unwrap().len(), 2);
+        assert!(history.get(ProviderKind::Codex).is_none());
+    }
+
+    #[test]
+    fn test_aggregate_by_period() {
+        // Wednesday 2026-03-04 and Thursday 2026-03-05, then Monday 2026-03-09
+        let readings = [
+            ((2026, 3, 4, 9), 10.0),
+            ((2026, 3, 4, 9), 30.0),
+            ((2026, 3, 4, 17), 20.0),
+            ((2026, 3, 5, 8), 60.0),
+            ((2026, 3, 9, 12), 40.0),
+        ];
+        let mut history = UsageHistory::new();
+        for ((y, m, d, h), percent) in readings {
+            let mut snapshot = snapshot(percent, None);
+            snapshot.updated_at = Utc.with_ymd_and_hms(y, m, d, h, 15, 0).unwrap();
+            history.record(ProviderKind::Claude, &snapshot);
+        }
+        let day = |d| NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+        let aggregate =
+            |period| history.aggregate(ProviderKind::Claude, WindowKind::Primary, period, &Utc);
+
+        let days = aggregate(AggregatePeriod::Day);
+        assert_eq!(days.len(), 3);
+        assert_eq!(days[0].start, day(4));
+        assert!((days[0].peak_percent - 30.0).abs() < 1e-9);
+        assert!((days[0].average_percent - 20.0).abs() < 1e-9);
+        assert_eq!(days[0].samples, 3);
+
+        let weeks = aggregate(AggregatePeriod::Week);
+        assert_eq!(
+            weeks.iter().map(|w| w.start).collect::<Vec<_>>(),
+            vec![day(2), day(9)]
+        );
+        assert!((weeks[0].peak_percent - 60.0).abs() < 1e-9);
+        assert!((weeks[0].average_percent - 30.0).abs() < 1e-9);
+
+        let months = aggregate(AggregatePeriod::Month);
+        assert_eq!(months.len(), 1);
+        assert_eq!(months[0].start, day(1));
+        assert_eq!(months[0].samples, 5);
+
+        assert!(
+            history
+                .aggregate(
+                    ProviderKind::Claude,
+                    WindowKind::Secondary,
+                    AggregatePeriod::Day,
+                    &Utc
+                )
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_aggregate_outlives_entries() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let mut history = UsageHistory::new();
+        let mut at = start;
+        for _ in 0..(MAX_HISTORY_ENTRIES + 500) {
+            let mut snapshot = snapshot(50.0, None);
+            snapshot.updated_at = at;
+            history.record(ProviderKind::Codex, &snapshot);
+            at += Duration::minutes(5);
+        }
+
+        let days = history.aggregate(
+            ProviderKind::Codex,
+            WindowKind::Primary,
+            AggregatePeriod::Day,
+            &Utc,
+        );
+        assert_eq!(days[0].start, NaiveDate::from_ymd_opt(2026, 1, 1).unwrap());
+        let samples: u32 = days.iter().map(|d| d.samples).sum();
+        assert_eq!(samples as usize, MAX_HISTORY_ENTRIES + 500);
+
+        // Pruning drops whole hours before the cutoff
+        history.prune(start + Duration::days(1));
+        let days = history.aggregate(
+            ProviderKind::Codex,
+            WindowKind::Primary,
+            AggregatePeriod::Day,
+            &Utc,
+        );
+        assert_eq!(days[0].start, NaiveDate::from_ymd_opt(2026, 1, 2).unwrap());
+    }
+
+    #[test]
+    fn test_period_starts() {
+        let day = |m, d| NaiveDate::from_ymd_opt(2026, m, d).unwrap();
+        assert_eq!(AggregatePeriod::Week.start_of(day(3, 8)), day(3, 2));
+        assert_eq!(AggregatePeriod::Month.start_of(day(3, 8)), day(3, 1));
+        assert_eq!(
+            AggregatePeriod::Week.starts_between(day(3, 4), day(3, 16)),
+            vec![day(3, 2), day(3, 9), day(3, 16)]
+        );
+        assert_eq!(
+            AggregatePeriod::Month.starts_between(day(1, 31), day(3, 1)),
+            vec![day(1, 1), day(2, 1), day(3, 1)]
+        );
+    }
+
+    #[test]
+    fn test_time_until_limit() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 4, 12, 0, 0).unwrap();
+        let mut history = UsageHistory::new();
+        let readings = [
+            // Before the reset, and too long ago to count
+            (-180, 90.0),
+            (-120, 5.0),
+            (-50, 20.0),
+            (-20, 30.0),
+            (0, 40.0),
+        ];
+        for (minutes, percent) in readings {
+            let mut snapshot = snapshot(percent, None);
+            snapshot.updated_at = now + Duration::minutes(minutes);
+            history.record(ProviderKind::Claude, &snapshot);
+        }
+
+        // 20 points over 50 minutes is 24 points an hour
+        let rate = history
+            .burn_rate(ProviderKind::Claude, WindowKind::Primary, now)
+            .unwrap();
+        assert!((rate - 24.0).abs() < 1e-9);
+        assert_eq!(
+            history.time_until_limit(ProviderKind::Claude, WindowKind::Primary, now),
+            Some(Duration::minutes(150))
+        );
+
+        // Not enough recent readings
+        let later = now + Duration::minutes(55);
+        assert!(
+            history
+                .burn_rate(ProviderKind::Claude, WindowKind::Primary, later)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_no_burn_rate_when_flat() {
+        let now = Utc::now();
+        let mut history = UsageHistory::new();
+        for minutes in [30, 15, 0] {
+            let mut snapshot = snapshot(50.0, None);
+            snapshot.updated_at = now - Duration::minutes(minutes);
+            history.record(ProviderKind::Codex, &snapshot);
+        }
+        assert!(
+            history
+                .time_until_limit(ProviderKind::Codex, WindowKind::Primary, now)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let mut history = UsageHistory::new();
+        for _ in 0..(MAX_HISTORY_ENTRIES + 10) {
+            history.record(ProviderKind::Claude, &snapshot(1.0, None));
+        }
+
+        assert_eq!(
+            history.get(ProviderKind::Claude).unwrap().len(),
+            MAX_HISTORY_ENTRIES
+        );
     }
 }
@@ -0,0 +1,277 @@
+//! On-disk usage history.
+//!
+//! Recorded snapshots are appended to `history.jsonl`, one JSON object per
+//! line, so charts and reset history survive restarts. Entries older than
+//! the retention setting are pruned by rewriting the file.
+
+use chrono::{DateTime, Utc};
+use exactobar_core::{ProviderKind, UsageSnapshot};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+use crate::error::StoreError;
+use crate::history::{HistoryEntry, UsageHistory};
+use crate::persistence::{
+    create_secure_parent_dirs, default_history_path, set_restrictive_permissions,
+};
+
+/// A line of the history file.
+#[derive(Debug, Deserialize)]
+struct HistoryLine {
+    provider: ProviderKind,
+    recorded_at: DateTime<Utc>,
+    snapshot: UsageSnapshot,
+}
+
+/// A line of the history file, borrowed for writing.
+#[derive(Serialize)]
+struct HistoryLineRef<'a> {
+    provider: ProviderKind,
+    recorded_at: DateTime<Utc>,
+    snapshot: &'a UsageSnapshot,
+}
+
+/// The usage history file.
+///
+/// Operations on one `HistoryFile` run one at a time, so an append can't
+/// land in the middle of a prune.
+#[derive(Debug)]
+pub struct HistoryFile {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl HistoryFile {
+    /// Creates a history file at `path`. Nothing is written until entries
+    /// are appended.
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Creates the history file at the default location.
+    pub fn default_location() -> Self {
+        Self::new(default_history_path())
+    }
+
+    /// Returns the file's path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads the recorded history, or an empty history if there is none.
+    /// Unreadable lines are skipped.
+    pub async fn load(&self) -> UsageHistory {
+        let _guard = self.lock.lock().await;
+        let mut history = UsageHistory::new();
+        let content = match tokio::fs::read_to_string(&self.path).await {
+            Ok(content) => content,
+            Err(e) => {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    warn!(path = %self.path.display(), error = %e, "Failed to read history");
+                }
+                return history;
+            }
+        };
+
+        let mut skipped = 0;
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            match serde_json::from_str::<HistoryLine>(line) {
+                Ok(line) => history.push(
+                    line.provider,
+                    HistoryEntry {
+                        recorded_at: line.recorded_at,
+                        snapshot: line.snapshot,
+                    },
+                ),
+                Err(_) => skipped += 1,
+            }
+        }
+        if skipped > 0 {
+            warn!(path = %self.path.display(), skipped, "Skipped unreadable history lines");
+        }
+        history
+    }
+
+    /// Appends entries to the file.
+    pub async fn append(&self, entries: &[(ProviderKind, HistoryEntry)]) -> Result<(), StoreError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let _guard = self.lock.lock().await;
+
+        let mut lines = String::new();
+        for (provider, entry) in entries {
+            lines.push_str(&serde_json::to_string(&HistoryLineRef {
+                provider: *provider,
+                recorded_at: entry.recorded_at,
+                snapshot: &entry.snapshot,
+            })?);
+            lines.push('\n');
+        }
+
+        create_secure_parent_dirs(&self.path).await?;
+        let created = !self.path.exists();
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+        file.write_all(lines.as_bytes()).await?;
+        file.flush().await?;
+        if created {
+            set_restrictive_permissions(&self.path).await?;
+        }
+
+        debug!(path = %self.path.display(), count = entries.len(), "Appended history");
+        Ok(())
+    }
+
+    /// Removes entries recorded before `cutoff`, and any unreadable lines.
+    /// Returns how many lines were removed.
+    pub async fn prune(&self, cutoff: DateTime<Utc>) -> Result<usize, StoreError> {
+        let _guard = self.lock.lock().await;
+        let content = match tokio::fs::read_to_string(&self.path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut kept = String::with_capacity(content.len());
+        let mut removed = 0;
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            let keep = serde_json::from_str::<HistoryLine>(line)
+                .is_ok_and(|line| line.recorded_at >= cutoff);
+            if keep {
+                kept.push_str(line);
+                kept.push('\n');
+            } else {
+                removed += 1;
+            }
+        }
+        if removed == 0 {
+            return Ok(0);
+        }
+
+        // Write atomically (write to temp file, then rename)
+        let temp_path = self.path.with_extension("jsonl.tmp");
+        tokio::fs::write(&temp_path, &kept).await?;
+        set_restrictive_permissions(&temp_path).await?;
+        tokio::fs::rename(&temp_path, &self.path).await?;
+
+        debug!(path = %self.path.display(), removed, "Pruned history");
+        Ok(removed)
+    }
+
+    /// Deletes all recorded history.
+    pub async fn clear(&self) -> Result<(), StoreError> {
+        let _guard = self.lock.lock().await;
+        match tokio::fs::remove_file(&self.path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns the file's size in bytes.
+    pub fn disk_usage(&self) -> u64 {
+        std::fs::metadata(&self.path).map_or(0, |m| m.len())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use exactobar_core::{UsageWindow, WindowKind};
+
+    fn entry(recorded_at: DateTime<Utc>, percent: f64) -> HistoryEntry {
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(UsageWindow::new(percent));
+        snapshot.updated_at = recorded_at;
+        HistoryEntry::from(&snapshot)
+    }
+
+    fn percents(history: &UsageHistory, provider: ProviderKind) -> Vec<f64> {
+        history
+            .usage_points(provider, WindowKind::Primary)
+            .into_iter()
+            .map(|(_, percent)| percent)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_append_and_load() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = HistoryFile::new(temp_dir.path().join("history.jsonl"));
+        let now = Utc::now();
+
+        assert!(file.load().await.latest(ProviderKind::Claude).is_none());
+        assert_eq!(file.disk_usage(), 0);
+
+        file.append(&[
+            (ProviderKind::Claude, entry(now - Duration::hours(2), 10.0)),
+            (ProviderKind::Codex, entry(now - Duration::hours(2), 50.0)),
+        ])
+        .await
+        .unwrap();
+        file.append(&[(ProviderKind::Claude, entry(now, 20.0))])
+            .await
+            .unwrap();
+
+        let history = file.load().await;
+        assert_eq!(percents(&history, ProviderKind::Claude), vec![10.0, 20.0]);
+        assert_eq!(percents(&history, ProviderKind::Codex), vec![50.0]);
+        assert!(file.disk_usage() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_prune() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("history.jsonl");
+        let file = HistoryFile::new(path.clone());
+        let now = Utc::now();
+
+        file.append(&[
+            (ProviderKind::Claude, entry(now - Duration::days(120), 10.0)),
+            (ProviderKind::Claude, entry(now - Duration::days(30), 20.0)),
+            (ProviderKind::Claude, entry(now, 30.0)),
+        ])
+        .await
+        .unwrap();
+        // A line cut short by a crash mid-write
+        let mut content = tokio::fs::read_to_string(&path).await.unwrap();
+        content.push_str("{\"provider\":\"claude\",\"recor\n");
+        tokio::fs::write(&path, content).await.unwrap();
+
+        assert_eq!(file.prune(now - Duration::days(90)).await.unwrap(), 2);
+        assert_eq!(
+            percents(&file.load().await, ProviderKind::Claude),
+            vec![20.0, 30.0]
+        );
+        assert_eq!(file.prune(now - Duration::days(90)).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_clear() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = HistoryFile::new(temp_dir.path().join("history.jsonl"));
+
+        file.clear().await.unwrap();
+        file.append(&[(ProviderKind::Claude, entry(Utc::now(), 10.0))])
+            .await
+            .unwrap();
+        file.clear().await.unwrap();
+        assert!(file.load().await.latest(ProviderKind::Claude).is_none());
+        assert_eq!(file.disk_usage(), 0);
+    }
+}
@@ -15,6 +15,9 @@
 //!
 //! - **`UsageStore`**: Main state for provider usage data with watch channels
 //! - **`SettingsStore`**: User preferences with persistence
+//! - **`UsageHistory`**: Recorded snapshots for trend display
+//! - **`HistoryFile`**: Usage history on disk, pruned by retention
+//! - **`UsageCache`**: Latest usage on disk, for reading without fetching
 //! - **Persistence**: File I/O helpers for JSON data
 //!
 //! ## Usage
@@ -38,21 +41,32 @@
 //! ```
 
 pub mod error;
+pub mod history;
+pub mod history_file;
 pub mod keychain;
 pub mod persistence;
 pub mod settings_store;
+pub mod time_format;
+pub mod usage_cache;
 pub mod usage_store;
 
 pub use error::StoreError;
+pub use history::{AggregatePeriod, HistoryEntry, ResetEvent, UsageAggregate, UsageHistory};
+pub use history_file::HistoryFile;
 pub use keychain::{delete_api_key, get_api_key, has_api_key, store_api_key};
 pub use persistence::{
-    default_cache_dir, default_cache_path, default_config_dir, default_settings_path, load_json,
-    load_json_or_default, save_json,
+    default_cache_dir, default_cache_path, default_config_dir, default_history_path,
+    default_settings_path, load_json, load_json_or_default, save_json,
 };
 pub use settings_store::{
-    CookieSource, DataSourceMode, LogLevel, ProviderSettings, RefreshCadence, Settings,
-    SettingsStore, ThemeMode,
+    CookieSource, DataSourceMode, HistoryRetention, LogLevel, MqttSettings, ProviderGroup,
+    ProviderLink, ProviderSettings, ProxyMode, ProxySettings, QuickAction, QuickActionTarget,
+    RefreshCadence, Settings, SettingsStore, ThemeMode, UpdateChannel, UsageGradient,
+    VibrancyMaterial, Webhook, WebhookEventKind, WebhookFormat, WindowBackground, WindowPosition,
+    WindowSize,
 };
+pub use time_format::{TimeFormat, format_date};
+pub use usage_cache::UsageCache;
 pub use usage_store::{CostUsageSnapshot, DailyCost, UsageStore};
 #[cfg(test)]
 mod persistence_tests;
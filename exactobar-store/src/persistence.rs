@@ -66,6 +66,14 @@ pub fn default_cache_path() -> PathBuf {
     default_cache_dir().join("usage_cache.json")
 }
 
+/// Returns the default usage history file path.
+///
+/// History lives with the config rather than the cache, so the OS doesn't
+/// clear it along with caches.
+pub fn default_history_path() -> PathBuf {
+    default_config_dir().join("history.jsonl")
+}
+
 // ============================================================================
 // Security: File Permissions
 // ============================================================================
@@ -75,7 +83,7 @@ pub fn default_cache_path() -> PathBuf {
 /// This ensures config files containing sensitive data are only
 /// readable by the owner.
 #[cfg(unix)]
-async fn set_restrictive_permissions(path: &Path) -> Result<(), StoreError> {
+pub(crate) async fn set_restrictive_permissions(path: &Path) -> Result<(), StoreError> {
     use std::os::unix::fs::PermissionsExt;
 
     let metadata = tokio::fs::metadata(path).await?;
@@ -105,7 +113,7 @@ async fn set_restrictive_dir_permissions(path: &Path) -> Result<(), StoreError>
 
 /// No-op for non-Unix systems.
 #[cfg(not(unix))]
-async fn set_restrictive_permissions(_path: &Path) -> Result<(), StoreError> {
+pub(crate) async fn set_restrictive_permissions(_path: &Path) -> Result<(), StoreError> {
     Ok(())
 }
 
@@ -123,7 +131,7 @@ async fn set_restrictive_dir_permissions(_path: &Path) -> Result<(), StoreError>
 ///
 /// On Unix systems, directories are created with 0o700 permissions
 /// to ensure only the owner can access config files.
-async fn create_secure_parent_dirs(path: &Path) -> Result<(), StoreError> {
+pub(crate) async fn create_secure_parent_dirs(path: &Path) -> Result<(), StoreError> {
     if let Some(parent) = path.parent() {
         if !parent.exists() {
             debug!(path = %parent.display(), "Creating secure directory");
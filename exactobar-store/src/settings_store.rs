@@ -2,7 +2,9 @@
 //!
 //! Manages user settings with persistence and change notification.
 
-use exactobar_core::ProviderKind;
+use chrono::{DateTime, Utc};
+use exactobar_core::{ModelPrice, PricingTable, ProviderKind};
+use exactobar_fetch::{ProxyConfig, RateLimit, TlsConfig};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
@@ -13,6 +15,13 @@ use tracing::{debug, info, warn};
 
 use crate::error::StoreError;
 use crate::persistence::{default_settings_path, load_json, save_json};
+use crate::time_format::TimeFormat;
+
+/// Default seconds after a refresh before data is shown as stale.
+const DEFAULT_STALE_AFTER_SECS: u64 = 600;
+
+/// Default requests sent to one host per minute.
+const DEFAULT_REQUESTS_PER_MINUTE: u32 = 20;
 
 // ============================================================================
 // Settings Types
@@ -32,6 +41,13 @@ pub struct Settings {
     /// Auto-refresh cadence.
     pub refresh_cadence: RefreshCadence,
 
+    /// Skip scheduled refreshes, keeping the cadence for when they resume.
+    pub refresh_paused: bool,
+
+    /// Seconds after a refresh before a provider's data is shown as stale,
+    /// unless the provider sets its own threshold.
+    pub stale_after_secs: u64,
+
     /// Refresh on wake from sleep.
     pub auto_refresh_on_wake: bool,
 
@@ -41,7 +57,7 @@ pub struct Settings {
     /// Show countdown vs absolute time for resets.
     pub show_reset_countdown: bool,
 
-    /// Selected provider (for merged mode).
+    /// Last selected provider tab in the menu (None = "All").
     pub selected_provider: Option<ProviderKind>,
 
     /// Debug mode.
@@ -53,6 +69,21 @@ pub struct Settings {
     /// Theme mode preference.
     pub theme_mode: ThemeMode,
 
+    /// What shows through behind the menu windows.
+    pub window_background: WindowBackground,
+
+    /// macOS material the blurred background is drawn with.
+    pub vibrancy_material: VibrancyMaterial,
+
+    /// Release channel checked for updates.
+    pub update_channel: UpdateChannel,
+
+    /// Version the user chose to skip in the update dialog (e.g., "0.3.0").
+    pub skipped_update_version: Option<String>,
+
+    /// Don't offer updates again before this time ("Remind Me Later").
+    pub update_reminder_after: Option<DateTime<Utc>>,
+
     /// Per-provider settings.
     pub provider_settings: HashMap<ProviderKind, ProviderSettings>,
 
@@ -62,15 +93,60 @@ pub struct Settings {
     /// When true, progress bars show "percent used" instead of "percent remaining".
     pub usage_bars_show_used: bool,
 
+    /// Where usage turns from green to yellow to red, in the menu, the
+    /// status icons and quota alerts.
+    pub usage_gradient: UsageGradient,
+
+    /// How long recorded usage history is kept on disk.
+    pub history_retention: HistoryRetention,
+
     /// Show reset times as absolute clock values instead of countdowns.
     pub reset_times_show_absolute: bool,
 
+    /// 12- or 24-hour clock for reset times (system locale by default).
+    pub time_format: TimeFormat,
+
     /// Use provider branding icons with percentage in menu bar.
     pub menu_bar_shows_brand_icon_with_percent: bool,
 
     /// Show provider icons in the in-menu switcher.
     pub switcher_shows_icons: bool,
 
+    /// Collapse each menu card to a single line.
+    pub compact_menu: bool,
+
+    /// Ask for a second click before the menu's Quit button quits.
+    pub confirm_quit: bool,
+
+    /// Only quit from the menu's Quit button while Option (Alt) is held.
+    pub quit_requires_modifier: bool,
+
+    /// Show a card of totals across providers atop the menu's All view.
+    pub show_totals_card: bool,
+
+    /// Run as a regular app with a Dock icon and app menu (macOS).
+    pub show_dock_icon: bool,
+
+    /// Providers whose cards are collapsed to their header in the "All" view.
+    pub collapsed_providers: HashSet<ProviderKind>,
+
+    /// Last screen position of the detached (pinned) panel window.
+    pub detached_panel_position: Option<WindowPosition>,
+
+    /// User-chosen size of the menu panel (None = default size).
+    pub menu_panel_size: Option<WindowSize>,
+
+    /// System-wide shortcut that opens and closes the menu, e.g.
+    /// "alt+shift+e" (None = no shortcut).
+    pub global_hotkey: Option<String>,
+
+    /// ISO 4217 currency code used to format spend (e.g., "USD").
+    pub currency_code: String,
+
+    /// Token prices by model name prefix, replacing or adding to the
+    /// shipped prices used to estimate spend.
+    pub model_prices: HashMap<String, ModelPrice>,
+
     // ========================================================================
     // Feature Toggles (new from CodexBar)
     // ========================================================================
@@ -86,6 +162,18 @@ pub struct Settings {
     /// Enable random blink animation on status icon.
     pub random_blink_enabled: bool,
 
+    /// Turn off icon animations (blinks, the loading sweep), also done when
+    /// the OS asks to reduce motion.
+    pub reduce_motion: bool,
+
+    /// Refresh less often and pause icon animations in Low Power Mode
+    /// (macOS) or on battery (Linux).
+    pub power_saving_enabled: bool,
+
+    /// Start without opening any windows or refreshing right away, for
+    /// launching at login.
+    pub start_silently: bool,
+
     /// Enable Claude web extras (via browser cookies).
     pub claude_web_extras_enabled: bool,
 
@@ -104,17 +192,77 @@ pub struct Settings {
     /// Claude usage data source mode.
     pub claude_usage_data_source: DataSourceMode,
 
+    // ========================================================================
+    // Integrations
+    // ========================================================================
+    /// Serve usage to coding agents over MCP on localhost.
+    pub mcp_server_enabled: bool,
+
+    /// Serve Prometheus metrics on localhost.
+    pub metrics_enabled: bool,
+
+    /// Stream usage over WebSocket on localhost (e.g. to a Stream Deck plugin).
+    pub websocket_enabled: bool,
+
+    /// Localhost port for the integrations server (MCP, metrics, WebSocket).
+    pub server_port: u16,
+
+    /// Outbound webhooks notified of usage events.
+    pub webhooks: Vec<Webhook>,
+
+    /// Where to write a SwiftBar/xbar plugin showing the cached usage.
+    pub swiftbar_plugin_path: Option<PathBuf>,
+
+    /// MQTT broker to publish usage to, if any.
+    pub mqtt: Option<MqttSettings>,
+
+    /// How HTTP requests reach the network.
+    pub proxy_mode: ProxyMode,
+
+    /// Proxy used when `proxy_mode` is manual.
+    pub proxy: Option<ProxySettings>,
+
+    /// PEM file of extra CA certificates to trust, e.g. a TLS-inspecting
+    /// proxy's.
+    pub ca_bundle_path: Option<PathBuf>,
+
+    /// Trust the system certificate store as well as the built-in roots.
+    pub system_certificates: bool,
+
+    /// SHA-256 certificate fingerprints a host must present, keyed by host.
+    pub certificate_pins: HashMap<String, Vec<String>>,
+
+    /// Requests sent to one host per minute. Half as many can go out back
+    /// to back.
+    pub requests_per_minute: u32,
+
     // ========================================================================
     // Provider Order & Debug (new from CodexBar)
     // ========================================================================
     /// Provider display order in menu (empty = default order).
     pub provider_order: Vec<ProviderKind>,
 
+    /// Pinned providers, always shown first (in pin order).
+    pub pinned_providers: Vec<ProviderKind>,
+
+    /// Named groups the menu's All view is split into, in display order.
+    pub provider_groups: Vec<ProviderGroup>,
+
+    /// Show a tab per provider group in the menu's switcher.
+    pub group_tabs: bool,
+
     /// Debug loading pattern override.
     pub debug_loading_pattern: Option<String>,
 
+    /// Show the demo provider, which generates fake usage for UI work and
+    /// screenshots.
+    pub demo_provider_enabled: bool,
+
     /// Whether provider detection has completed (for first-run experience).
     pub provider_detection_completed: bool,
+
+    /// Whether the first-run tour of the menu has been completed or dismissed.
+    pub tour_completed: bool,
 }
 
 impl Default for Settings {
@@ -127,6 +275,8 @@ impl Default for Settings {
             // Core settings
             enabled_providers: enabled,
             refresh_cadence: RefreshCadence::default(),
+            refresh_paused: false,
+            stale_after_secs: DEFAULT_STALE_AFTER_SECS,
             auto_refresh_on_wake: true,
             merge_icons: true,
             show_reset_countdown: true,
@@ -134,19 +284,41 @@ impl Default for Settings {
             debug_mode: false,
             log_level: LogLevel::default(),
             theme_mode: ThemeMode::Dark,
+            window_background: WindowBackground::default(),
+            vibrancy_material: VibrancyMaterial::default(),
+            update_channel: UpdateChannel::default(),
+            skipped_update_version: None,
+            update_reminder_after: None,
             provider_settings: HashMap::new(),
 
             // Display settings - sensible defaults
             usage_bars_show_used: false,
+            usage_gradient: UsageGradient::default(),
+            history_retention: HistoryRetention::default(),
             reset_times_show_absolute: false,
+            time_format: TimeFormat::System,
             menu_bar_shows_brand_icon_with_percent: false,
             switcher_shows_icons: true,
+            compact_menu: false,
+            confirm_quit: false,
+            quit_requires_modifier: false,
+            show_totals_card: false,
+            show_dock_icon: false,
+            collapsed_providers: HashSet::new(),
+            detached_panel_position: None,
+            menu_panel_size: None,
+            global_hotkey: None,
+            currency_code: "USD".to_string(),
+            model_prices: HashMap::new(),
 
             // Feature toggles - most enabled by default
             status_checks_enabled: true,
             session_quota_notifications_enabled: true,
             cost_usage_enabled: false, // Off by default - requires local logs
             random_blink_enabled: false, // Off by default - can be annoying
+            reduce_motion: false,
+            power_saving_enabled: true,
+            start_silently: false,
             claude_web_extras_enabled: false, // Off by default - requires cookies
             show_optional_credits_and_extra_usage: true,
             openai_web_access_enabled: true,
@@ -155,12 +327,251 @@ impl Default for Settings {
             codex_usage_data_source: DataSourceMode::Auto,
             claude_usage_data_source: DataSourceMode::Auto,
 
+            // Integrations - off by default
+            mcp_server_enabled: false,
+            metrics_enabled: false,
+            websocket_enabled: false,
+            server_port: 47_821,
+            webhooks: vec![],
+            swiftbar_plugin_path: None,
+            mqtt: None,
+            proxy_mode: ProxyMode::default(),
+            proxy: None,
+            ca_bundle_path: None,
+            system_certificates: false,
+            certificate_pins: HashMap::new(),
+            requests_per_minute: DEFAULT_REQUESTS_PER_MINUTE,
+
             // Provider order & debug
             provider_order: vec![],
+            pinned_providers: vec![],
+            provider_groups: vec![],
+            group_tabs: false,
             debug_loading_pattern: None,
+            demo_provider_enabled: false,
             provider_detection_completed: false,
+            tour_completed: false,
+        }
+    }
+}
+
+impl Settings {
+    /// Returns false for the demo provider unless it's been turned on in the
+    /// debug settings.
+    pub fn shows_provider(&self, provider: ProviderKind) -> bool {
+        provider != ProviderKind::Demo || self.demo_provider_enabled
+    }
+
+    /// Returns true if the update dialog should be shown for `version`.
+    ///
+    /// Skipped versions are never offered, and nothing is offered while a
+    /// "Remind Me Later" is pending.
+    pub fn should_offer_update(&self, version: &str, now: DateTime<Utc>) -> bool {
+        self.skipped_update_version.as_deref() != Some(version)
+            && self.update_reminder_after.is_none_or(|after| now >= after)
+    }
+
+    /// Returns how long after a refresh a provider's data is shown as stale.
+    pub fn stale_after(&self, provider: ProviderKind) -> Duration {
+        let secs = self
+            .provider_settings
+            .get(&provider)
+            .and_then(|ps| ps.stale_after_secs)
+            .unwrap_or(self.stale_after_secs);
+        Duration::from_secs(secs)
+    }
+
+    /// Returns true if a provider's data updated at `updated_at` is stale at `now`.
+    pub fn is_stale(
+        &self,
+        provider: ProviderKind,
+        updated_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        (now - updated_at)
+            .to_std()
+            .is_ok_and(|age| age > self.stale_after(provider))
+    }
+
+    /// Returns true if a provider's card is collapsed in the "All" view.
+    pub fn is_provider_collapsed(&self, provider: ProviderKind) -> bool {
+        self.collapsed_providers.contains(&provider)
+    }
+
+    /// Returns true if a provider is pinned.
+    pub fn is_provider_pinned(&self, provider: ProviderKind) -> bool {
+        self.pinned_providers.contains(&provider)
+    }
+
+    /// Returns enabled providers split into the sections of the All view.
+    ///
+    /// Each group with an enabled provider becomes a section named after
+    /// it, followed by an unnamed section of the providers in no group.
+    /// Providers keep their display order within a section, and a provider
+    /// in several groups shows in the first.
+    pub fn provider_sections(&self) -> Vec<(Option<String>, Vec<ProviderKind>)> {
+        let providers = self.sorted_enabled_providers();
+        let mut grouped = HashSet::new();
+        let mut sections = Vec::new();
+
+        for group in &self.provider_groups {
+            let members: Vec<ProviderKind> = providers
+                .iter()
+                .copied()
+                .filter(|p| group.providers.contains(p) && grouped.insert(*p))
+                .collect();
+            if !members.is_empty() {
+                sections.push((Some(group.name.clone()), members));
+            }
+        }
+
+        let rest: Vec<ProviderKind> = providers
+            .into_iter()
+            .filter(|p| !grouped.contains(p))
+            .collect();
+        if !rest.is_empty() {
+            sections.push((None, rest));
+        }
+        sections
+    }
+
+    /// Returns enabled providers in display order.
+    ///
+    /// Pinned providers come first (in pin order), followed by the rest in
+    /// the custom `provider_order`, or the default provider order if unset.
+    pub fn sorted_enabled_providers(&self) -> Vec<ProviderKind> {
+        let base: &[ProviderKind] = if self.provider_order.is_empty() {
+            ProviderKind::all()
+        } else {
+            &self.provider_order
+        };
+
+        let mut providers: Vec<ProviderKind> = self
+            .pinned_providers
+            .iter()
+            .chain(base)
+            .chain(ProviderKind::all())
+            .copied()
+            .filter(|p| self.enabled_providers.contains(p) && self.shows_provider(*p))
+            .collect();
+
+        // Keep the first occurrence of each provider
+        let mut seen = HashSet::new();
+        providers.retain(|p| seen.insert(*p));
+        providers
+    }
+}
+
+/// Screen position of a window's top-left corner, in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowPosition {
+    /// Horizontal offset from the left edge of the screen.
+    pub x: f32,
+    /// Vertical offset from the top edge of the screen.
+    pub y: f32,
+}
+
+/// Size of a window, in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowSize {
+    /// Window width.
+    pub width: f32,
+    /// Window height.
+    pub height: f32,
+}
+
+/// Breakpoints and hues of the usage color scale.
+///
+/// Usage fades from `good_hue` at 0% to `warning_hue` at `warning_percent`,
+/// to `danger_hue` at `danger_percent`, and to `full_hue` at 100%. Hues are
+/// in degrees (0 = red, 60 = yellow, 120 = green).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UsageGradient {
+    /// Percent used where usage stops counting as low.
+    pub warning_percent: f32,
+    /// Percent used where usage counts as high, and quota alerts start.
+    pub danger_percent: f32,
+    /// Hue at 0% used.
+    pub good_hue: f32,
+    /// Hue at `warning_percent`.
+    pub warning_hue: f32,
+    /// Hue at `danger_percent`.
+    pub danger_hue: f32,
+    /// Hue at 100% used.
+    pub full_hue: f32,
+}
+
+impl UsageGradient {
+    /// Green to yellow at 50%, orange at 80%, red at 100%.
+    pub const DEFAULT: Self = Self {
+        warning_percent: 50.0,
+        danger_percent: 80.0,
+        good_hue: 120.0,
+        warning_hue: 60.0,
+        danger_hue: 30.0,
+        full_hue: 0.0,
+    };
+
+    /// Returns the default gradient with other breakpoints.
+    pub fn with_breakpoints(warning_percent: f32, danger_percent: f32) -> Self {
+        Self {
+            warning_percent,
+            danger_percent,
+            ..Self::DEFAULT
         }
     }
+
+    /// Returns the breakpoints clamped to 0-100%, with the danger one no
+    /// lower than the warning one, so hand-edited settings can't invert
+    /// the scale.
+    pub fn breakpoints(&self) -> (f32, f32) {
+        let warning = finite_or(self.warning_percent, 50.0).clamp(0.0, 100.0);
+        let danger = finite_or(self.danger_percent, 80.0).clamp(warning, 100.0);
+        (warning, danger)
+    }
+
+    /// Returns whether `used_percent` is past the warning breakpoint.
+    pub fn is_warning(&self, used_percent: f32) -> bool {
+        used_percent >= self.breakpoints().0
+    }
+
+    /// Returns whether `used_percent` is past the danger breakpoint.
+    pub fn is_danger(&self, used_percent: f32) -> bool {
+        used_percent >= self.breakpoints().1
+    }
+
+    /// Returns the hue in degrees for `used_percent`.
+    pub fn hue_at(&self, used_percent: f32) -> f32 {
+        let used = finite_or(used_percent, 0.0).clamp(0.0, 100.0);
+        let (warning, danger) = self.breakpoints();
+        let stops = [
+            (0.0, self.good_hue),
+            (warning, self.warning_hue),
+            (danger, self.danger_hue),
+            (100.0, self.full_hue),
+        ];
+
+        // The last stop whose segment contains `used`, skipping empty ones
+        let (from, to) = stops
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .rfind(|&((start, _), (end, _))| start <= used && start < end)
+            .unwrap_or((stops[2], stops[3]));
+        let ((start, from_hue), (end, to_hue)) = (from, to);
+        let t = ((used - start) / (end - start)).clamp(0.0, 1.0);
+        from_hue + (to_hue - from_hue) * t
+    }
+}
+
+impl Default for UsageGradient {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+fn finite_or(value: f32, fallback: f32) -> f32 {
+    if value.is_finite() { value } else { fallback }
 }
 
 /// Refresh cadence options.
@@ -256,6 +667,8 @@ pub enum ThemeMode {
     Light,
     /// Follow system appearance.
     System,
+    /// Opaque, high-contrast dark theme.
+    HighContrast,
 }
 
 impl std::fmt::Display for ThemeMode {
@@ -264,8 +677,94 @@ impl std::fmt::Display for ThemeMode {
             ThemeMode::Dark => write!(f, "dark"),
             ThemeMode::Light => write!(f, "light"),
             ThemeMode::System => write!(f, "system"),
+            ThemeMode::HighContrast => write!(f, "high_contrast"),
+        }
+    }
+}
+
+/// What shows through behind the menu windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowBackground {
+    /// Blurred desktop behind a light tint (liquid glass).
+    #[default]
+    Blurred,
+    /// The tint alone, without blur.
+    Translucent,
+    /// A solid background.
+    Opaque,
+}
+
+/// Material of the blurred window background on macOS, named after
+/// `NSVisualEffectView`'s materials.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum VibrancyMaterial {
+    /// Whatever the window is created with.
+    #[default]
+    Standard,
+    /// The material of menus.
+    Menu,
+    /// The material of popovers.
+    Popover,
+    /// The material of window sidebars.
+    Sidebar,
+    /// The dark material of heads-up displays.
+    HudWindow,
+    /// The material behind window content; the most opaque.
+    WindowBackground,
+}
+
+/// Release channel for update checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateChannel {
+    /// Published releases only.
+    #[default]
+    Stable,
+    /// Stable releases plus betas and release candidates.
+    Beta,
+    /// Every release, including nightly builds.
+    Nightly,
+}
+
+impl std::fmt::Display for UpdateChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UpdateChannel::Stable => write!(f, "stable"),
+            UpdateChannel::Beta => write!(f, "beta"),
+            UpdateChannel::Nightly => write!(f, "nightly"),
+        }
+    }
+}
+
+/// How long recorded usage history is kept on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryRetention {
+    /// The last 90 days.
+    #[default]
+    NinetyDays,
+    /// The last year.
+    OneYear,
+    /// Everything ever recorded.
+    Forever,
+}
+
+impl HistoryRetention {
+    /// Returns how long entries are kept, or `None` if they never expire.
+    pub fn max_age(self) -> Option<chrono::Duration> {
+        match self {
+            HistoryRetention::NinetyDays => Some(chrono::Duration::days(90)),
+            HistoryRetention::OneYear => Some(chrono::Duration::days(365)),
+            HistoryRetention::Forever => None,
         }
     }
+
+    /// Returns when the oldest entry worth keeping at `now` was recorded.
+    pub fn cutoff(self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.max_age().map(|age| now - age)
+    }
 }
 
 /// Data source mode for usage fetching.
@@ -374,6 +873,312 @@ pub struct ProviderSettings {
 
     /// Manual cookie header (stored inline for simplicity).
     pub cookie_header: Option<String>,
+
+    /// Monthly spend budget, used when the provider doesn't report a limit.
+    pub monthly_budget: Option<f64>,
+
+    /// Stale threshold in seconds (None = the global `stale_after_secs`).
+    pub stale_after_secs: Option<u64>,
+
+    /// Custom quick-action buttons for the menu card (None = provider defaults).
+    pub quick_actions: Option<Vec<QuickAction>>,
+
+    /// Brand color override as a `#rrggbb` hex string (None = the built-in color).
+    pub color: Option<String>,
+
+    /// API base URL used instead of the provider's own host, e.g. a
+    /// corporate gateway or regional endpoint (None = the provider's host).
+    pub base_url: Option<String>,
+
+    /// Page opened by the Dashboard button (None = the provider's own).
+    pub dashboard_url: Option<String>,
+
+    /// Page opened by the Status button (None = the provider's own).
+    pub status_url: Option<String>,
+
+    /// Page opened by the Buy Credits button, e.g. an SSO-gated billing
+    /// portal (None = the provider's own).
+    pub billing_url: Option<String>,
+}
+
+/// A web page linked from a provider's menu card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderLink {
+    /// The Dashboard button.
+    Dashboard,
+    /// The Status button.
+    Status,
+    /// The Buy Credits button.
+    Billing,
+}
+
+impl ProviderSettings {
+    fn link_url(&self, link: ProviderLink) -> Option<&str> {
+        match link {
+            ProviderLink::Dashboard => self.dashboard_url.as_deref(),
+            ProviderLink::Status => self.status_url.as_deref(),
+            ProviderLink::Billing => self.billing_url.as_deref(),
+        }
+    }
+
+    fn link_url_mut(&mut self, link: ProviderLink) -> &mut Option<String> {
+        match link {
+            ProviderLink::Dashboard => &mut self.dashboard_url,
+            ProviderLink::Status => &mut self.status_url,
+            ProviderLink::Billing => &mut self.billing_url,
+        }
+    }
+}
+
+/// An outbound webhook that receives usage events as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Webhook {
+    /// URL the events are posted to.
+    pub url: String,
+    /// Shared secret used to sign payloads with HMAC-SHA256.
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// Events to send (empty = all).
+    #[serde(default)]
+    pub events: Vec<WebhookEventKind>,
+    /// Message format of the posted body.
+    #[serde(default)]
+    pub format: WebhookFormat,
+}
+
+impl Webhook {
+    /// Creates a webhook that receives every event, in the format its URL
+    /// suggests (see [`WebhookFormat::detect`]).
+    pub fn new(url: impl Into<String>) -> Self {
+        let url = url.into();
+        Self {
+            format: WebhookFormat::detect(&url),
+            url,
+            secret: None,
+            events: Vec::new(),
+        }
+    }
+
+    /// Returns true if this webhook wants events of `kind`.
+    pub fn wants(&self, kind: WebhookEventKind) -> bool {
+        self.events.is_empty() || self.events.contains(&kind)
+    }
+}
+
+/// Kinds of usage events sent to webhooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventKind {
+    /// Usage crossed the warning or critical threshold.
+    Threshold,
+    /// A refresh failed (sent once until it succeeds again).
+    Error,
+    /// A usage window reset.
+    Reset,
+}
+
+impl std::fmt::Display for WebhookEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Threshold => write!(f, "threshold"),
+            Self::Error => write!(f, "error"),
+            Self::Reset => write!(f, "reset"),
+        }
+    }
+}
+
+/// Message format posted to a webhook.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookFormat {
+    /// The raw event as JSON.
+    #[default]
+    Json,
+    /// A Slack incoming-webhook message.
+    Slack,
+    /// A Discord webhook message.
+    Discord,
+}
+
+impl WebhookFormat {
+    /// Picks the format for a webhook URL: Slack and Discord webhook URLs
+    /// get their own format, anything else gets JSON.
+    pub fn detect(url: &str) -> Self {
+        let host = url
+            .split("://")
+            .nth(1)
+            .unwrap_or(url)
+            .split('/')
+            .next()
+            .unwrap_or("");
+        match host {
+            "hooks.slack.com" => Self::Slack,
+            "discord.com" | "discordapp.com" | "ptb.discord.com" | "canary.discord.com" => {
+                Self::Discord
+            }
+            _ => Self::Json,
+        }
+    }
+}
+
+impl std::fmt::Display for WebhookFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json => write!(f, "json"),
+            Self::Slack => write!(f, "slack"),
+            Self::Discord => write!(f, "discord"),
+        }
+    }
+}
+
+/// Connection settings for publishing usage over MQTT.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MqttSettings {
+    /// Broker address as `host:port` (plain TCP, e.g. "homeassistant.local:1883").
+    pub broker: String,
+    /// Topics are published under `<topic_prefix>/<provider>/...`.
+    #[serde(default = "MqttSettings::default_topic_prefix")]
+    pub topic_prefix: String,
+    /// Username, if the broker requires one.
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Password, if the broker requires one.
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+impl MqttSettings {
+    /// Creates settings for a broker with the default topic prefix.
+    pub fn new(broker: impl Into<String>) -> Self {
+        Self {
+            broker: broker.into(),
+            topic_prefix: Self::default_topic_prefix(),
+            username: None,
+            password: None,
+        }
+    }
+
+    fn default_topic_prefix() -> String {
+        "exactobar".to_string()
+    }
+}
+
+/// A named set of providers shown together in the menu.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProviderGroup {
+    /// Section and tab title, e.g. "Coding agents".
+    pub name: String,
+    /// Providers in the group.
+    #[serde(default)]
+    pub providers: Vec<ProviderKind>,
+}
+
+/// How HTTP requests reach the network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ProxyMode {
+    /// The system proxy settings and `HTTP_PROXY`/`HTTPS_PROXY`.
+    #[default]
+    System,
+    /// The proxy in [`Settings::proxy`].
+    Manual,
+    /// Connect directly.
+    Off,
+}
+
+/// An explicit HTTP or SOCKS proxy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxySettings {
+    /// Proxy URL, e.g. `http://proxy.corp:3128` or `socks5://localhost:1080`.
+    pub url: String,
+    /// Username, if the proxy requires one.
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Password, if the proxy requires one.
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+impl Settings {
+    /// Returns the proxy HTTP clients should use. Manual mode without a
+    /// proxy URL falls back to the system proxy.
+    pub fn proxy_config(&self) -> ProxyConfig {
+        match (self.proxy_mode, &self.proxy) {
+            (ProxyMode::Manual, Some(proxy)) if !proxy.url.trim().is_empty() => {
+                ProxyConfig::Manual {
+                    url: proxy.url.clone(),
+                    username: proxy.username.clone(),
+                    password: proxy.password.clone(),
+                }
+            }
+            (ProxyMode::Off, _) => ProxyConfig::Off,
+            _ => ProxyConfig::System,
+        }
+    }
+
+    /// Returns the URL overriding a provider's link, if one is set.
+    pub fn link_url(&self, provider: ProviderKind, link: ProviderLink) -> Option<&str> {
+        self.provider_settings
+            .get(&provider)
+            .and_then(|ps| ps.link_url(link))
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+    }
+
+    /// Returns the TLS options HTTP clients should use.
+    pub fn tls_config(&self) -> TlsConfig {
+        TlsConfig {
+            ca_bundle: self.ca_bundle_path.clone(),
+            system_certificates: self.system_certificates,
+            pins: self.certificate_pins.clone(),
+        }
+    }
+
+    /// Returns the request budget for each host.
+    pub fn rate_limit(&self) -> RateLimit {
+        let per_minute = self.requests_per_minute.max(1);
+        RateLimit {
+            burst: per_minute.div_ceil(2),
+            per_minute,
+        }
+    }
+}
+
+/// A quick-action button shown on a provider's menu card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuickAction {
+    /// Button label.
+    pub label: String,
+    /// What the button does.
+    pub target: QuickActionTarget,
+}
+
+impl QuickAction {
+    /// Creates a button that opens a URL.
+    pub fn url(label: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            target: QuickActionTarget::Url(url.into()),
+        }
+    }
+
+    /// Creates a button that runs a shell command.
+    pub fn command(label: impl Into<String>, command: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            target: QuickActionTarget::Command(command.into()),
+        }
+    }
+}
+
+/// What a quick-action button does when clicked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuickActionTarget {
+    /// Open a URL in the default browser.
+    Url(String),
+    /// Run a shell command.
+    Command(String),
 }
 
 // ============================================================================
@@ -511,6 +1316,21 @@ impl SettingsStore {
         self.update(|s| s.refresh_cadence = cadence).await;
     }
 
+    /// Gets whether scheduled refreshes are paused.
+    pub async fn refresh_paused(&self) -> bool {
+        self.settings.read().await.refresh_paused
+    }
+
+    /// Pauses or resumes scheduled refreshes.
+    pub async fn set_refresh_paused(&self, paused: bool) {
+        self.update(|s| s.refresh_paused = paused).await;
+    }
+
+    /// Sets the global stale threshold, in seconds.
+    pub async fn set_stale_after_secs(&self, secs: u64) {
+        self.update(|s| s.stale_after_secs = secs).await;
+    }
+
     /// Gets enabled providers.
     pub async fn enabled_providers(&self) -> HashSet<ProviderKind> {
         self.settings.read().await.enabled_providers.clone()
@@ -540,6 +1360,16 @@ impl SettingsStore {
         self.update(|s| s.reset_times_show_absolute = value).await;
     }
 
+    /// Gets the clock format for reset times.
+    pub async fn time_format(&self) -> TimeFormat {
+        self.settings.read().await.time_format
+    }
+
+    /// Sets the clock format for reset times.
+    pub async fn set_time_format(&self, format: TimeFormat) {
+        self.update(|s| s.time_format = format).await;
+    }
+
     /// Gets whether menu bar shows brand icon with percent.
     pub async fn menu_bar_shows_brand_icon_with_percent(&self) -> bool {
         self.settings
@@ -564,17 +1394,175 @@ impl SettingsStore {
         self.update(|s| s.switcher_shows_icons = value).await;
     }
 
-    // ========================================================================
-    // Feature Toggle Methods
-    // ========================================================================
+    /// Gets the last selected provider tab (None = "All").
+    pub async fn selected_provider(&self) -> Option<ProviderKind> {
+        self.settings.read().await.selected_provider
+    }
 
-    /// Gets whether status checks are enabled.
-    pub async fn status_checks_enabled(&self) -> bool {
-        self.settings.read().await.status_checks_enabled
+    /// Sets the last selected provider tab (None = "All").
+    pub async fn set_selected_provider(&self, provider: Option<ProviderKind>) {
+        self.update(|s| s.selected_provider = provider).await;
     }
 
-    /// Sets whether status checks are enabled.
-    pub async fn set_status_checks_enabled(&self, value: bool) {
+    /// Gets whether menu cards are collapsed to a single line.
+    pub async fn compact_menu(&self) -> bool {
+        self.settings.read().await.compact_menu
+    }
+
+    /// Sets whether menu cards are collapsed to a single line.
+    pub async fn set_compact_menu(&self, value: bool) {
+        self.update(|s| s.compact_menu = value).await;
+    }
+
+    /// Gets whether the menu's Quit button asks for confirmation.
+    pub async fn confirm_quit(&self) -> bool {
+        self.settings.read().await.confirm_quit
+    }
+
+    /// Sets whether the menu's Quit button asks for confirmation.
+    pub async fn set_confirm_quit(&self, value: bool) {
+        self.update(|s| s.confirm_quit = value).await;
+    }
+
+    /// Gets whether the menu's Quit button needs Option (Alt) held.
+    pub async fn quit_requires_modifier(&self) -> bool {
+        self.settings.read().await.quit_requires_modifier
+    }
+
+    /// Sets whether the menu's Quit button needs Option (Alt) held.
+    pub async fn set_quit_requires_modifier(&self, value: bool) {
+        self.update(|s| s.quit_requires_modifier = value).await;
+    }
+
+    /// Gets whether the totals card is shown atop the All view.
+    pub async fn show_totals_card(&self) -> bool {
+        self.settings.read().await.show_totals_card
+    }
+
+    /// Sets whether the totals card is shown atop the All view.
+    pub async fn set_show_totals_card(&self, value: bool) {
+        self.update(|s| s.show_totals_card = value).await;
+    }
+
+    /// Gets whether the app shows a Dock icon.
+    pub async fn show_dock_icon(&self) -> bool {
+        self.settings.read().await.show_dock_icon
+    }
+
+    /// Sets whether the app shows a Dock icon.
+    pub async fn set_show_dock_icon(&self, value: bool) {
+        self.update(|s| s.show_dock_icon = value).await;
+    }
+
+    /// Gets the providers whose cards are collapsed in the "All" view.
+    pub async fn collapsed_providers(&self) -> HashSet<ProviderKind> {
+        self.settings.read().await.collapsed_providers.clone()
+    }
+
+    /// Collapses or expands a provider's card in the "All" view.
+    pub async fn set_provider_collapsed(&self, provider: ProviderKind, collapsed: bool) {
+        self.update(|s| {
+            if collapsed {
+                s.collapsed_providers.insert(provider);
+            } else {
+                s.collapsed_providers.remove(&provider);
+            }
+        })
+        .await;
+    }
+
+    /// Gets the last position of the detached panel window.
+    pub async fn detached_panel_position(&self) -> Option<WindowPosition> {
+        self.settings.read().await.detached_panel_position
+    }
+
+    /// Sets the last position of the detached panel window.
+    pub async fn set_detached_panel_position(&self, position: Option<WindowPosition>) {
+        self.update(|s| s.detached_panel_position = position).await;
+    }
+
+    /// Gets the usage color breakpoints and hues.
+    pub async fn usage_gradient(&self) -> UsageGradient {
+        self.settings.read().await.usage_gradient
+    }
+
+    /// Gets how long usage history is kept.
+    pub async fn history_retention(&self) -> HistoryRetention {
+        self.settings.read().await.history_retention
+    }
+
+    /// Sets how long usage history is kept.
+    pub async fn set_history_retention(&self, retention: HistoryRetention) {
+        self.update(|s| s.history_retention = retention).await;
+    }
+
+    /// Sets the usage color breakpoints and hues.
+    pub async fn set_usage_gradient(&self, gradient: UsageGradient) {
+        self.update(|s| s.usage_gradient = gradient).await;
+    }
+
+    /// Gets the user-chosen menu panel size.
+    pub async fn menu_panel_size(&self) -> Option<WindowSize> {
+        self.settings.read().await.menu_panel_size
+    }
+
+    /// Sets the menu panel size.
+    pub async fn set_menu_panel_size(&self, size: Option<WindowSize>) {
+        self.update(|s| s.menu_panel_size = size).await;
+    }
+
+    /// Gets the shortcut that toggles the menu.
+    pub async fn global_hotkey(&self) -> Option<String> {
+        self.settings.read().await.global_hotkey.clone()
+    }
+
+    /// Sets the shortcut that toggles the menu.
+    pub async fn set_global_hotkey(&self, hotkey: Option<String>) {
+        self.update(|s| s.global_hotkey = hotkey).await;
+    }
+
+    /// Gets the currency code used to format spend.
+    pub async fn currency_code(&self) -> String {
+        self.settings.read().await.currency_code.clone()
+    }
+
+    /// Sets the currency code used to format spend.
+    pub async fn set_currency_code(&self, code: String) {
+        self.update(|s| s.currency_code = code).await;
+    }
+
+    /// Gets the token prices used to estimate spend: the shipped prices
+    /// with the user's overrides applied.
+    pub async fn pricing_table(&self) -> PricingTable {
+        PricingTable::with_overrides(&self.settings.read().await.model_prices)
+    }
+
+    /// Sets the token price for models starting with `model`, or goes back
+    /// to the shipped price with `None`.
+    pub async fn set_model_price(&self, model: String, price: Option<ModelPrice>) {
+        let model = model.to_ascii_lowercase();
+        self.update(|s| match price {
+            Some(price) => {
+                s.model_prices.insert(model, price);
+            }
+            None => {
+                s.model_prices.remove(&model);
+            }
+        })
+        .await;
+    }
+
+    // ========================================================================
+    // Feature Toggle Methods
+    // ========================================================================
+
+    /// Gets whether status checks are enabled.
+    pub async fn status_checks_enabled(&self) -> bool {
+        self.settings.read().await.status_checks_enabled
+    }
+
+    /// Sets whether status checks are enabled.
+    pub async fn set_status_checks_enabled(&self, value: bool) {
         self.update(|s| s.status_checks_enabled = value).await;
     }
 
@@ -612,6 +1600,36 @@ impl SettingsStore {
         self.update(|s| s.random_blink_enabled = value).await;
     }
 
+    /// Gets whether icon animations are turned off.
+    pub async fn reduce_motion(&self) -> bool {
+        self.settings.read().await.reduce_motion
+    }
+
+    /// Sets whether icon animations are turned off.
+    pub async fn set_reduce_motion(&self, value: bool) {
+        self.update(|s| s.reduce_motion = value).await;
+    }
+
+    /// Gets whether refreshes and animations slow down to save power.
+    pub async fn power_saving_enabled(&self) -> bool {
+        self.settings.read().await.power_saving_enabled
+    }
+
+    /// Sets whether refreshes and animations slow down to save power.
+    pub async fn set_power_saving_enabled(&self, value: bool) {
+        self.update(|s| s.power_saving_enabled = value).await;
+    }
+
+    /// Gets whether the app starts without opening windows.
+    pub async fn start_silently(&self) -> bool {
+        self.settings.read().await.start_silently
+    }
+
+    /// Sets whether the app starts without opening windows.
+    pub async fn set_start_silently(&self, value: bool) {
+        self.update(|s| s.start_silently = value).await;
+    }
+
     /// Gets whether Claude web extras are enabled.
     pub async fn claude_web_extras_enabled(&self) -> bool {
         self.settings.read().await.claude_web_extras_enabled
@@ -657,6 +1675,64 @@ impl SettingsStore {
         Ok(())
     }
 
+    /// Gets what shows through behind the menu windows.
+    pub async fn window_background(&self) -> WindowBackground {
+        self.settings.read().await.window_background
+    }
+
+    /// Sets what shows through behind the menu windows.
+    pub async fn set_window_background(
+        &self,
+        background: WindowBackground,
+    ) -> Result<(), StoreError> {
+        self.update(|s| s.window_background = background).await;
+        Ok(())
+    }
+
+    /// Gets the macOS material of the blurred window background.
+    pub async fn vibrancy_material(&self) -> VibrancyMaterial {
+        self.settings.read().await.vibrancy_material
+    }
+
+    /// Sets the macOS material of the blurred window background.
+    pub async fn set_vibrancy_material(
+        &self,
+        material: VibrancyMaterial,
+    ) -> Result<(), StoreError> {
+        self.update(|s| s.vibrancy_material = material).await;
+        Ok(())
+    }
+
+    /// Gets the update channel.
+    pub async fn update_channel(&self) -> UpdateChannel {
+        self.settings.read().await.update_channel
+    }
+
+    /// Sets the update channel.
+    pub async fn set_update_channel(&self, channel: UpdateChannel) {
+        self.update(|s| s.update_channel = channel).await;
+    }
+
+    /// Gets the version skipped in the update dialog.
+    pub async fn skipped_update_version(&self) -> Option<String> {
+        self.settings.read().await.skipped_update_version.clone()
+    }
+
+    /// Sets the version skipped in the update dialog.
+    pub async fn set_skipped_update_version(&self, version: Option<String>) {
+        self.update(|s| s.skipped_update_version = version).await;
+    }
+
+    /// Gets the time before which updates aren't offered again.
+    pub async fn update_reminder_after(&self) -> Option<DateTime<Utc>> {
+        self.settings.read().await.update_reminder_after
+    }
+
+    /// Sets the time before which updates aren't offered again.
+    pub async fn set_update_reminder_after(&self, after: Option<DateTime<Utc>>) {
+        self.update(|s| s.update_reminder_after = after).await;
+    }
+
     // ========================================================================
     // Data Source Methods
     // ========================================================================
@@ -702,6 +1778,42 @@ impl SettingsStore {
         self.update(|s| s.provider_order = order).await;
     }
 
+    /// Gets the pinned providers, in pin order.
+    pub async fn pinned_providers(&self) -> Vec<ProviderKind> {
+        self.settings.read().await.pinned_providers.clone()
+    }
+
+    /// Pins or unpins a provider. Newly pinned providers go after existing pins.
+    pub async fn set_provider_pinned(&self, provider: ProviderKind, pinned: bool) {
+        self.update(|s| {
+            s.pinned_providers.retain(|p| *p != provider);
+            if pinned {
+                s.pinned_providers.push(provider);
+            }
+        })
+        .await;
+    }
+
+    /// Gets the provider groups.
+    pub async fn provider_groups(&self) -> Vec<ProviderGroup> {
+        self.settings.read().await.provider_groups.clone()
+    }
+
+    /// Sets the provider groups.
+    pub async fn set_provider_groups(&self, groups: Vec<ProviderGroup>) {
+        self.update(|s| s.provider_groups = groups).await;
+    }
+
+    /// Gets whether provider groups get their own tabs.
+    pub async fn group_tabs(&self) -> bool {
+        self.settings.read().await.group_tabs
+    }
+
+    /// Sets whether provider groups get their own tabs.
+    pub async fn set_group_tabs(&self, enabled: bool) {
+        self.update(|s| s.group_tabs = enabled).await;
+    }
+
     // ========================================================================
     // Per-Provider Cookie Source Methods
     // ========================================================================
@@ -768,6 +1880,129 @@ impl SettingsStore {
         .await;
     }
 
+    /// Gets the monthly spend budget for a provider.
+    pub async fn monthly_budget(&self, provider: ProviderKind) -> Option<f64> {
+        self.settings
+            .read()
+            .await
+            .provider_settings
+            .get(&provider)
+            .and_then(|ps| ps.monthly_budget)
+    }
+
+    /// Sets the monthly spend budget for a provider.
+    pub async fn set_monthly_budget(&self, provider: ProviderKind, budget: Option<f64>) {
+        self.update(|s| {
+            s.provider_settings
+                .entry(provider)
+                .or_default()
+                .monthly_budget = budget;
+        })
+        .await;
+    }
+
+    /// Gets the stale threshold for a provider, in seconds.
+    pub async fn stale_after_secs(&self, provider: ProviderKind) -> u64 {
+        self.settings.read().await.stale_after(provider).as_secs()
+    }
+
+    /// Sets the stale threshold for a provider (None = the global threshold).
+    pub async fn set_provider_stale_after_secs(&self, provider: ProviderKind, secs: Option<u64>) {
+        self.update(|s| {
+            s.provider_settings
+                .entry(provider)
+                .or_default()
+                .stale_after_secs = secs;
+        })
+        .await;
+    }
+
+    /// Gets the custom quick-action buttons for a provider (None = defaults).
+    pub async fn quick_actions(&self, provider: ProviderKind) -> Option<Vec<QuickAction>> {
+        self.settings
+            .read()
+            .await
+            .provider_settings
+            .get(&provider)
+            .and_then(|ps| ps.quick_actions.clone())
+    }
+
+    /// Sets the custom quick-action buttons for a provider (None = defaults).
+    pub async fn set_quick_actions(
+        &self,
+        provider: ProviderKind,
+        actions: Option<Vec<QuickAction>>,
+    ) {
+        self.update(|s| {
+            s.provider_settings
+                .entry(provider)
+                .or_default()
+                .quick_actions = actions;
+        })
+        .await;
+    }
+
+    /// Gets the brand color override for a provider.
+    pub async fn provider_color(&self, provider: ProviderKind) -> Option<String> {
+        self.settings
+            .read()
+            .await
+            .provider_settings
+            .get(&provider)
+            .and_then(|ps| ps.color.clone())
+    }
+
+    /// Sets the brand color override for a provider (None = the built-in color).
+    pub async fn set_provider_color(&self, provider: ProviderKind, color: Option<String>) {
+        self.update(|s| {
+            s.provider_settings.entry(provider).or_default().color = color;
+        })
+        .await;
+    }
+
+    /// Gets the API base URL override for a provider.
+    pub async fn base_url(&self, provider: ProviderKind) -> Option<String> {
+        self.settings
+            .read()
+            .await
+            .provider_settings
+            .get(&provider)
+            .and_then(|ps| ps.base_url.clone())
+    }
+
+    /// Sets the API base URL override for a provider (None = the provider's host).
+    pub async fn set_base_url(&self, provider: ProviderKind, url: Option<String>) {
+        self.update(|s| {
+            s.provider_settings.entry(provider).or_default().base_url = url;
+        })
+        .await;
+    }
+
+    /// Gets the URL overriding a provider's link (None = the provider's own).
+    pub async fn link_url(&self, provider: ProviderKind, link: ProviderLink) -> Option<String> {
+        self.settings
+            .read()
+            .await
+            .link_url(provider, link)
+            .map(str::to_string)
+    }
+
+    /// Sets the URL overriding a provider's link (None = the provider's own).
+    pub async fn set_link_url(
+        &self,
+        provider: ProviderKind,
+        link: ProviderLink,
+        url: Option<String>,
+    ) {
+        self.update(|s| {
+            *s.provider_settings
+                .entry(provider)
+                .or_default()
+                .link_url_mut(link) = url;
+        })
+        .await;
+    }
+
     // ========================================================================
     // Debug & Detection Methods
     // ========================================================================
@@ -783,6 +2018,169 @@ impl SettingsStore {
             .await;
     }
 
+    /// Gets whether the MCP server is enabled.
+    pub async fn mcp_server_enabled(&self) -> bool {
+        self.settings.read().await.mcp_server_enabled
+    }
+
+    /// Sets whether the MCP server is enabled.
+    pub async fn set_mcp_server_enabled(&self, value: bool) {
+        self.update(|s| s.mcp_server_enabled = value).await;
+    }
+
+    /// Gets whether the Prometheus metrics endpoint is enabled.
+    pub async fn metrics_enabled(&self) -> bool {
+        self.settings.read().await.metrics_enabled
+    }
+
+    /// Sets whether the Prometheus metrics endpoint is enabled.
+    pub async fn set_metrics_enabled(&self, value: bool) {
+        self.update(|s| s.metrics_enabled = value).await;
+    }
+
+    /// Gets whether the WebSocket API is enabled.
+    pub async fn websocket_enabled(&self) -> bool {
+        self.settings.read().await.websocket_enabled
+    }
+
+    /// Sets whether the WebSocket API is enabled.
+    pub async fn set_websocket_enabled(&self, value: bool) {
+        self.update(|s| s.websocket_enabled = value).await;
+    }
+
+    /// Gets the integrations server port.
+    pub async fn server_port(&self) -> u16 {
+        self.settings.read().await.server_port
+    }
+
+    /// Sets the integrations server port.
+    pub async fn set_server_port(&self, port: u16) {
+        self.update(|s| s.server_port = port).await;
+    }
+
+    /// Gets the registered webhooks.
+    pub async fn webhooks(&self) -> Vec<Webhook> {
+        self.settings.read().await.webhooks.clone()
+    }
+
+    /// Registers a webhook, replacing any existing one with the same URL.
+    pub async fn add_webhook(&self, webhook: Webhook) {
+        self.update(|s| {
+            s.webhooks.retain(|w| w.url != webhook.url);
+            s.webhooks.push(webhook);
+        })
+        .await;
+    }
+
+    /// Removes the webhook with the given URL. Returns false if none matched.
+    pub async fn remove_webhook(&self, url: &str) -> bool {
+        let mut removed = false;
+        self.update(|s| {
+            let before = s.webhooks.len();
+            s.webhooks.retain(|w| w.url != url);
+            removed = s.webhooks.len() != before;
+        })
+        .await;
+        removed
+    }
+
+    /// Gets where the SwiftBar/xbar plugin is written, if enabled.
+    pub async fn swiftbar_plugin_path(&self) -> Option<PathBuf> {
+        self.settings.read().await.swiftbar_plugin_path.clone()
+    }
+
+    /// Sets where the SwiftBar/xbar plugin is written (`None` disables it).
+    pub async fn set_swiftbar_plugin_path(&self, path: Option<PathBuf>) {
+        self.update(|s| s.swiftbar_plugin_path = path).await;
+    }
+
+    /// Gets the MQTT settings, if publishing is enabled.
+    pub async fn mqtt(&self) -> Option<MqttSettings> {
+        self.settings.read().await.mqtt.clone()
+    }
+
+    /// Sets the MQTT settings (`None` disables publishing).
+    pub async fn set_mqtt(&self, mqtt: Option<MqttSettings>) {
+        self.update(|s| s.mqtt = mqtt).await;
+    }
+
+    /// Gets how HTTP requests reach the network.
+    pub async fn proxy_mode(&self) -> ProxyMode {
+        self.settings.read().await.proxy_mode
+    }
+
+    /// Sets how HTTP requests reach the network.
+    pub async fn set_proxy_mode(&self, mode: ProxyMode) {
+        self.update(|s| s.proxy_mode = mode).await;
+    }
+
+    /// Gets the explicit proxy, if one is configured.
+    pub async fn proxy(&self) -> Option<ProxySettings> {
+        self.settings.read().await.proxy.clone()
+    }
+
+    /// Sets the explicit proxy used in manual mode.
+    pub async fn set_proxy(&self, proxy: Option<ProxySettings>) {
+        self.update(|s| s.proxy = proxy).await;
+    }
+
+    /// Gets the extra CA bundle, if one is set.
+    pub async fn ca_bundle_path(&self) -> Option<PathBuf> {
+        self.settings.read().await.ca_bundle_path.clone()
+    }
+
+    /// Sets the extra CA bundle (`None` trusts only the usual roots).
+    pub async fn set_ca_bundle_path(&self, path: Option<PathBuf>) {
+        self.update(|s| s.ca_bundle_path = path).await;
+    }
+
+    /// Checks if the system certificate store is trusted.
+    pub async fn system_certificates(&self) -> bool {
+        self.settings.read().await.system_certificates
+    }
+
+    /// Sets whether the system certificate store is trusted.
+    pub async fn set_system_certificates(&self, enabled: bool) {
+        self.update(|s| s.system_certificates = enabled).await;
+    }
+
+    /// Gets the requests sent to one host per minute.
+    pub async fn requests_per_minute(&self) -> u32 {
+        self.settings.read().await.requests_per_minute
+    }
+
+    /// Sets the requests sent to one host per minute.
+    pub async fn set_requests_per_minute(&self, requests: u32) {
+        self.update(|s| s.requests_per_minute = requests).await;
+    }
+
+    /// Gets the certificate pins, keyed by host.
+    pub async fn certificate_pins(&self) -> HashMap<String, Vec<String>> {
+        self.settings.read().await.certificate_pins.clone()
+    }
+
+    /// Sets a host's certificate pins (empty removes them).
+    pub async fn set_certificate_pins(&self, host: &str, pins: Vec<String>) {
+        self.update(|s| {
+            if pins.is_empty() {
+                s.certificate_pins.remove(host);
+            } else {
+                s.certificate_pins.insert(host.to_string(), pins);
+            }
+        })
+        .await;
+    }
+
+    /// Gets whether the first-run tour has been completed.
+    pub async fn tour_completed(&self) -> bool {
+        self.settings.read().await.tour_completed
+    }
+
+    /// Sets whether the first-run tour has been completed.
+    pub async fn set_tour_completed(&self, value: bool) {
+        self.update(|s| s.tour_completed = value).await;
+    }
+
     /// Gets the debug loading pattern.
     pub async fn debug_loading_pattern(&self) -> Option<String> {
         self.settings.read().await.debug_loading_pattern.clone()
@@ -792,6 +2190,22 @@ impl SettingsStore {
     pub async fn set_debug_loading_pattern(&self, pattern: Option<String>) {
         self.update(|s| s.debug_loading_pattern = pattern).await;
     }
+
+    /// Gets whether the demo provider is shown.
+    pub async fn demo_provider_enabled(&self) -> bool {
+        self.settings.read().await.demo_provider_enabled
+    }
+
+    /// Shows or hides the demo provider. Showing it also enables it.
+    pub async fn set_demo_provider_enabled(&self, value: bool) {
+        self.update(|s| {
+            s.demo_provider_enabled = value;
+            if value {
+                s.enabled_providers.insert(ProviderKind::Demo);
+            }
+        })
+        .await;
+    }
 }
 
 // ============================================================================
@@ -808,6 +2222,7 @@ mod tests {
         assert!(settings.enabled_providers.contains(&ProviderKind::Codex));
         assert!(settings.enabled_providers.contains(&ProviderKind::Claude));
         assert_eq!(settings.refresh_cadence, RefreshCadence::TwoMinutes);
+        assert_eq!(settings.stale_after_secs, 600);
     }
 
     #[test]
@@ -853,14 +2268,24 @@ mod tests {
         // Display settings defaults
         assert!(!settings.usage_bars_show_used);
         assert!(!settings.reset_times_show_absolute);
+        assert_eq!(settings.time_format, TimeFormat::System);
         assert!(!settings.menu_bar_shows_brand_icon_with_percent);
         assert!(settings.switcher_shows_icons);
+        assert!(!settings.compact_menu);
+        assert!(!settings.show_dock_icon);
+        assert_eq!(settings.currency_code, "USD");
+        assert!(settings.detached_panel_position.is_none());
+        assert!(settings.menu_panel_size.is_none());
+        assert!(settings.global_hotkey.is_none());
 
         // Feature toggle defaults
         assert!(settings.status_checks_enabled);
         assert!(settings.session_quota_notifications_enabled);
         assert!(!settings.cost_usage_enabled);
         assert!(!settings.random_blink_enabled);
+        assert!(!settings.reduce_motion);
+        assert!(settings.power_saving_enabled);
+        assert!(!settings.start_silently);
         assert!(!settings.claude_web_extras_enabled);
         assert!(settings.show_optional_credits_and_extra_usage);
         assert!(settings.openai_web_access_enabled);
@@ -869,9 +2294,220 @@ mod tests {
         assert_eq!(settings.codex_usage_data_source, DataSourceMode::Auto);
         assert_eq!(settings.claude_usage_data_source, DataSourceMode::Auto);
 
+        // Integrations are opt-in
+        assert!(!settings.mcp_server_enabled);
+        assert!(!settings.metrics_enabled);
+        assert!(!settings.websocket_enabled);
+        assert_eq!(settings.server_port, 47_821);
+        assert!(settings.webhooks.is_empty());
+        assert!(settings.swiftbar_plugin_path.is_none());
+        assert!(settings.mqtt.is_none());
+
         // Provider order defaults
         assert!(settings.provider_order.is_empty());
         assert!(!settings.provider_detection_completed);
+        assert!(!settings.tour_completed);
+        assert_eq!(settings.update_channel, UpdateChannel::Stable);
+        assert!(settings.skipped_update_version.is_none());
+        assert!(settings.update_reminder_after.is_none());
+    }
+
+    #[test]
+    fn test_should_offer_update() {
+        let now = Utc::now();
+        let mut settings = Settings::default();
+        assert!(settings.should_offer_update("0.3.0", now));
+
+        settings.skipped_update_version = Some("0.3.0".to_string());
+        assert!(!settings.should_offer_update("0.3.0", now));
+        assert!(settings.should_offer_update("0.3.1", now));
+
+        settings.update_reminder_after = Some(now + chrono::Duration::hours(1));
+        assert!(!settings.should_offer_update("0.3.1", now));
+        assert!(settings.should_offer_update("0.3.1", now + chrono::Duration::hours(2)));
+    }
+
+    #[test]
+    fn test_stale_after() {
+        let now = Utc::now();
+        let mut settings = Settings::default();
+        let updated_at = now - chrono::Duration::minutes(5);
+        assert!(!settings.is_stale(ProviderKind::Codex, updated_at, now));
+
+        // Per-provider thresholds override the global one
+        settings.provider_settings.insert(
+            ProviderKind::Codex,
+            ProviderSettings {
+                stale_after_secs: Some(30),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            settings.stale_after(ProviderKind::Codex),
+            Duration::from_secs(30)
+        );
+        assert!(settings.is_stale(ProviderKind::Codex, updated_at, now));
+        assert!(!settings.is_stale(ProviderKind::Claude, updated_at, now));
+
+        settings.stale_after_secs = 60;
+        assert!(settings.is_stale(ProviderKind::Claude, updated_at, now));
+    }
+
+    #[tokio::test]
+    async fn test_webhooks() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_webhooks.json"));
+
+        let mut hook = Webhook::new("https://example.com/hook");
+        store.add_webhook(hook.clone()).await;
+        hook.events = vec![WebhookEventKind::Threshold];
+        store.add_webhook(hook).await;
+
+        // Same URL replaces the existing webhook
+        let hooks = store.webhooks().await;
+        assert_eq!(hooks.len(), 1);
+        assert!(hooks[0].wants(WebhookEventKind::Threshold));
+        assert!(!hooks[0].wants(WebhookEventKind::Reset));
+        assert!(Webhook::new("x").wants(WebhookEventKind::Reset));
+
+        assert!(store.remove_webhook("https://example.com/hook").await);
+        assert!(!store.remove_webhook("https://example.com/hook").await);
+    }
+
+    #[test]
+    fn test_webhook_format_detect() {
+        assert_eq!(
+            Webhook::new("https://hooks.slack.com/services/T0/B0/x").format,
+            WebhookFormat::Slack
+        );
+        assert_eq!(
+            WebhookFormat::detect("https://discord.com/api/webhooks/1/abc"),
+            WebhookFormat::Discord
+        );
+        assert_eq!(
+            WebhookFormat::detect("https://example.com/hooks.slack.com"),
+            WebhookFormat::Json
+        );
+    }
+
+    #[test]
+    fn test_mqtt_settings_defaults() {
+        let mqtt: MqttSettings = serde_json::from_str(r#"{"broker": "localhost:1883"}"#).unwrap();
+        assert_eq!(mqtt, MqttSettings::new("localhost:1883"));
+        assert_eq!(mqtt.topic_prefix, "exactobar");
+    }
+
+    #[test]
+    fn test_proxy_config() {
+        let mut settings = Settings::default();
+        assert_eq!(settings.proxy_config(), ProxyConfig::System);
+
+        settings.proxy_mode = ProxyMode::Manual;
+        assert_eq!(settings.proxy_config(), ProxyConfig::System);
+
+        settings.proxy = serde_json::from_str(r#"{"url": "http://proxy.corp:3128"}"#).unwrap();
+        assert_eq!(
+            settings.proxy_config(),
+            ProxyConfig::Manual {
+                url: "http://proxy.corp:3128".to_string(),
+                username: None,
+                password: None,
+            }
+        );
+
+        settings.proxy_mode = ProxyMode::Off;
+        assert_eq!(settings.proxy_config(), ProxyConfig::Off);
+    }
+
+    fn assert_hue(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn test_usage_gradient() {
+        let gradient = UsageGradient::default();
+        assert_hue(gradient.hue_at(0.0), 120.0);
+        assert_hue(gradient.hue_at(25.0), 90.0);
+        assert_hue(gradient.hue_at(50.0), 60.0);
+        assert_hue(gradient.hue_at(65.0), 45.0);
+        assert_hue(gradient.hue_at(90.0), 15.0);
+        assert_hue(gradient.hue_at(150.0), 0.0);
+        assert!(!gradient.is_warning(49.0));
+        assert!(gradient.is_warning(50.0));
+        assert!(gradient.is_danger(80.0));
+
+        // Red from 60%
+        let early = UsageGradient::with_breakpoints(40.0, 60.0);
+        assert!(early.is_danger(60.0));
+        assert_hue(early.hue_at(60.0), 30.0);
+        assert_hue(early.hue_at(80.0), 15.0);
+
+        // Inverted breakpoints collapse instead of running backwards
+        let inverted = UsageGradient::with_breakpoints(90.0, 70.0);
+        let (warning, danger) = inverted.breakpoints();
+        assert_hue(warning, 90.0);
+        assert_hue(danger, 90.0);
+        assert_hue(inverted.hue_at(90.0), 30.0);
+        assert_hue(inverted.hue_at(95.0), 15.0);
+
+        let zero = UsageGradient::with_breakpoints(0.0, 0.0);
+        assert_hue(zero.hue_at(0.0), 30.0);
+
+        // Missing keys keep their defaults
+        let parsed: UsageGradient = serde_json::from_str(r#"{"danger_percent": 60}"#).unwrap();
+        assert_eq!(parsed, UsageGradient::with_breakpoints(50.0, 60.0));
+    }
+
+    #[tokio::test]
+    async fn test_usage_gradient_setting() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_usage_gradient.json"));
+
+        assert_eq!(store.usage_gradient().await, UsageGradient::default());
+        let early = UsageGradient::with_breakpoints(40.0, 60.0);
+        store.set_usage_gradient(early).await;
+        assert_eq!(store.usage_gradient().await, early);
+    }
+
+    #[tokio::test]
+    async fn test_history_retention() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_history_retention.json"));
+
+        assert_eq!(
+            store.history_retention().await,
+            HistoryRetention::NinetyDays
+        );
+        store.set_history_retention(HistoryRetention::Forever).await;
+        assert_eq!(store.history_retention().await, HistoryRetention::Forever);
+
+        let now = Utc::now();
+        assert_eq!(
+            HistoryRetention::OneYear.cutoff(now),
+            Some(now - chrono::Duration::days(365))
+        );
+        assert!(HistoryRetention::Forever.cutoff(now).is_none());
+        let json = serde_json::to_string(&HistoryRetention::NinetyDays).unwrap();
+        assert_eq!(json, "\"ninety_days\"");
+    }
+
+    #[tokio::test]
+    async fn test_global_hotkey() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_global_hotkey.json"));
+
+        assert!(store.global_hotkey().await.is_none());
+        store
+            .set_global_hotkey(Some("alt+shift+e".to_string()))
+            .await;
+        assert_eq!(store.global_hotkey().await.as_deref(), Some("alt+shift+e"));
+        store.set_global_hotkey(None).await;
+        assert!(store.global_hotkey().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_tour_completed() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_tour_completed.json"));
+
+        assert!(!store.tour_completed().await);
+        store.set_tour_completed(true).await;
+        assert!(store.tour_completed().await);
     }
 
     #[tokio::test]
@@ -887,6 +2523,70 @@ mod tests {
         assert!(!store.reset_times_show_absolute().await);
         store.set_reset_times_show_absolute(true).await;
         assert!(store.reset_times_show_absolute().await);
+
+        // Test selected tab
+        assert_eq!(store.selected_provider().await, None);
+        store
+            .set_selected_provider(Some(ProviderKind::Claude))
+            .await;
+        assert_eq!(store.selected_provider().await, Some(ProviderKind::Claude));
+
+        // Test compact menu toggle
+        assert!(!store.compact_menu().await);
+        store.set_compact_menu(true).await;
+        assert!(store.compact_menu().await);
+
+        // Test totals card toggle
+        assert!(!store.show_totals_card().await);
+        store.set_show_totals_card(true).await;
+        assert!(store.show_totals_card().await);
+
+        // Test quit protection
+        assert!(!store.confirm_quit().await);
+        assert!(!store.quit_requires_modifier().await);
+        store.set_confirm_quit(true).await;
+        store.set_quit_requires_modifier(true).await;
+        assert!(store.confirm_quit().await);
+        assert!(store.quit_requires_modifier().await);
+
+        // Test pausing refreshes
+        assert!(!store.refresh_paused().await);
+        store.set_refresh_paused(true).await;
+        assert!(store.refresh_paused().await);
+
+        // Test Dock icon toggle
+        assert!(!store.show_dock_icon().await);
+        store.set_show_dock_icon(true).await;
+        assert!(store.show_dock_icon().await);
+
+        // Test currency code
+        assert_eq!(store.currency_code().await, "USD");
+        store.set_currency_code("EUR".to_string()).await;
+        assert_eq!(store.currency_code().await, "EUR");
+    }
+
+    #[tokio::test]
+    async fn test_model_prices() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_model_prices.json"));
+        let shipped = store.pricing_table().await.price_for("gpt-5");
+        assert!(shipped.is_some());
+
+        let custom = ModelPrice::new(1.0, 4.0);
+        store
+            .set_model_price("GPT-5".to_string(), Some(custom))
+            .await;
+        store
+            .set_model_price(
+                "my-local-model".to_string(),
+                Some(ModelPrice::new(0.0, 0.0)),
+            )
+            .await;
+        let table = store.pricing_table().await;
+        assert_eq!(table.price_for("gpt-5-codex"), Some(custom));
+        assert!(table.price_for("my-local-model-7b").is_some());
+
+        store.set_model_price("gpt-5".to_string(), None).await;
+        assert_eq!(store.pricing_table().await.price_for("gpt-5"), shipped);
     }
 
     #[tokio::test]
@@ -902,6 +2602,16 @@ mod tests {
         assert!(!store.cost_usage_enabled().await);
         store.set_cost_usage_enabled(true).await;
         assert!(store.cost_usage_enabled().await);
+
+        // Power saving (default enabled)
+        assert!(store.power_saving_enabled().await);
+        store.set_power_saving_enabled(false).await;
+        assert!(!store.power_saving_enabled().await);
+
+        // Quiet start (default disabled)
+        assert!(!store.start_silently().await);
+        store.set_start_silently(true).await;
+        assert!(store.start_silently().await);
     }
 
     #[tokio::test]
@@ -944,6 +2654,279 @@ mod tests {
         assert_eq!(store.provider_order().await, custom_order);
     }
 
+    #[tokio::test]
+    async fn test_pinned_providers() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_pinned_providers.json"));
+        assert!(store.pinned_providers().await.is_empty());
+
+        store.set_provider_pinned(ProviderKind::Claude, true).await;
+        store.set_provider_pinned(ProviderKind::Claude, true).await;
+        assert_eq!(store.pinned_providers().await, vec![ProviderKind::Claude]);
+
+        store.set_provider_pinned(ProviderKind::Claude, false).await;
+        assert!(store.pinned_providers().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_quick_actions() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_quick_actions.json"));
+        assert!(store.quick_actions(ProviderKind::Claude).await.is_none());
+
+        let actions = vec![
+            QuickAction::url("Billing wiki", "https://wiki.example.com/billing"),
+            QuickAction::command("Usage", "claude /usage"),
+        ];
+        store
+            .set_quick_actions(ProviderKind::Claude, Some(actions.clone()))
+            .await;
+        assert_eq!(
+            store.quick_actions(ProviderKind::Claude).await,
+            Some(actions)
+        );
+
+        store.set_quick_actions(ProviderKind::Claude, None).await;
+        assert!(store.quick_actions(ProviderKind::Claude).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_provider_color() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_provider_color.json"));
+        assert!(store.provider_color(ProviderKind::Copilot).await.is_none());
+
+        store
+            .set_provider_color(ProviderKind::Copilot, Some("#a6adc8".to_string()))
+            .await;
+        assert_eq!(
+            store.provider_color(ProviderKind::Copilot).await.as_deref(),
+            Some("#a6adc8")
+        );
+        assert!(store.provider_color(ProviderKind::Claude).await.is_none());
+
+        store.set_provider_color(ProviderKind::Copilot, None).await;
+        assert!(store.provider_color(ProviderKind::Copilot).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_base_url() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_base_url.json"));
+        assert!(store.base_url(ProviderKind::Claude).await.is_none());
+
+        store
+            .set_base_url(
+                ProviderKind::Claude,
+                Some("https://llm-gateway.corp.example/anthropic".to_string()),
+            )
+            .await;
+        assert_eq!(
+            store.base_url(ProviderKind::Claude).await.as_deref(),
+            Some("https://llm-gateway.corp.example/anthropic")
+        );
+        assert!(store.base_url(ProviderKind::Synthetic).await.is_none());
+
+        store.set_base_url(ProviderKind::Claude, None).await;
+        assert!(store.base_url(ProviderKind::Claude).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_link_url() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_link_url.json"));
+        assert!(
+            store
+                .link_url(ProviderKind::Claude, ProviderLink::Billing)
+                .await
+                .is_none()
+        );
+
+        store
+            .set_link_url(
+                ProviderKind::Claude,
+                ProviderLink::Billing,
+                Some("https://sso.corp.example/billing".to_string()),
+            )
+            .await;
+        assert_eq!(
+            store
+                .link_url(ProviderKind::Claude, ProviderLink::Billing)
+                .await
+                .as_deref(),
+            Some("https://sso.corp.example/billing")
+        );
+        assert!(
+            store
+                .link_url(ProviderKind::Claude, ProviderLink::Dashboard)
+                .await
+                .is_none()
+        );
+
+        // A blank override falls back to the provider's own page
+        store
+            .set_link_url(
+                ProviderKind::Claude,
+                ProviderLink::Dashboard,
+                Some("  ".to_string()),
+            )
+            .await;
+        assert!(
+            store
+                .link_url(ProviderKind::Claude, ProviderLink::Dashboard)
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tls_config() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_tls_config.json"));
+        assert_eq!(store.get().await.tls_config(), TlsConfig::default());
+
+        store
+            .set_ca_bundle_path(Some(PathBuf::from("/etc/corp/ca.pem")))
+            .await;
+        store.set_system_certificates(true).await;
+        store
+            .set_certificate_pins("api.anthropic.com", vec!["ab".repeat(32)])
+            .await;
+
+        let tls = store.get().await.tls_config();
+        assert_eq!(tls.ca_bundle, Some(PathBuf::from("/etc/corp/ca.pem")));
+        assert!(tls.system_certificates);
+        assert_eq!(tls.pins["api.anthropic.com"], vec!["ab".repeat(32)]);
+
+        store
+            .set_certificate_pins("api.anthropic.com", Vec::new())
+            .await;
+        assert!(store.certificate_pins().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_rate_limit.json"));
+        assert_eq!(store.get().await.rate_limit(), RateLimit::default());
+
+        store.set_requests_per_minute(60).await;
+        assert_eq!(store.requests_per_minute().await, 60);
+        let limit = store.get().await.rate_limit();
+        assert_eq!(limit.per_minute, 60);
+        assert_eq!(limit.burst, 30);
+
+        // Zero would never refill
+        store.set_requests_per_minute(0).await;
+        assert_eq!(store.get().await.rate_limit().per_minute, 1);
+    }
+
+    #[tokio::test]
+    async fn test_provider_sections() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_provider_sections.json"));
+        store.set_provider_enabled(ProviderKind::Gemini, true).await;
+        store.set_provider_enabled(ProviderKind::Kiro, true).await;
+        assert_eq!(store.get().await.provider_sections().len(), 1);
+
+        store
+            .set_provider_groups(vec![
+                ProviderGroup {
+                    name: "Coding agents".to_string(),
+                    providers: vec![ProviderKind::Claude, ProviderKind::Codex],
+                },
+                ProviderGroup {
+                    name: "APIs".to_string(),
+                    providers: vec![ProviderKind::Claude, ProviderKind::Zai],
+                },
+                ProviderGroup {
+                    name: "IDEs".to_string(),
+                    providers: vec![ProviderKind::Kiro],
+                },
+            ])
+            .await;
+
+        // Claude stays in its first group, and APIs has no enabled provider
+        let sections = store.get().await.provider_sections();
+        assert_eq!(
+            sections,
+            vec![
+                (
+                    Some("Coding agents".to_string()),
+                    vec![ProviderKind::Codex, ProviderKind::Claude]
+                ),
+                (Some("IDEs".to_string()), vec![ProviderKind::Kiro]),
+                (None, vec![ProviderKind::Gemini]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_quick_action_serde() {
+        let action = QuickAction::command("Usage", "claude /usage");
+        let json = serde_json::to_string(&action).unwrap();
+        assert_eq!(
+            json,
+            r#"{"label":"Usage","target":{"command":"claude /usage"}}"#
+        );
+        assert_eq!(serde_json::from_str::<QuickAction>(&json).unwrap(), action);
+    }
+
+    #[tokio::test]
+    async fn test_collapsed_providers() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_collapsed_providers.json"));
+        assert!(store.collapsed_providers().await.is_empty());
+
+        store
+            .set_provider_collapsed(ProviderKind::Codex, true)
+            .await;
+        assert!(store.get().await.is_provider_collapsed(ProviderKind::Codex));
+        assert!(
+            !store
+                .get()
+                .await
+                .is_provider_collapsed(ProviderKind::Claude)
+        );
+
+        store
+            .set_provider_collapsed(ProviderKind::Codex, false)
+            .await;
+        assert!(store.collapsed_providers().await.is_empty());
+    }
+
+    #[test]
+    fn test_sorted_enabled_providers_pins_first() {
+        let mut settings = Settings::default();
+        settings.enabled_providers.insert(ProviderKind::Cursor);
+
+        // Default order follows ProviderKind::all()
+        let default_order = settings.sorted_enabled_providers();
+        assert_eq!(default_order.len(), 3);
+
+        settings.pinned_providers = vec![ProviderKind::Cursor, ProviderKind::Gemini];
+        let order = settings.sorted_enabled_providers();
+
+        // Pinned first; disabled pins are skipped; no duplicates
+        assert_eq!(order[0], ProviderKind::Cursor);
+        assert_eq!(order.len(), 3);
+        assert!(!order.contains(&ProviderKind::Gemini));
+    }
+
+    #[tokio::test]
+    async fn test_demo_provider_hidden_until_enabled() {
+        let store = SettingsStore::new(PathBuf::from("/tmp/test_demo_provider.json"));
+        store.set_provider_enabled(ProviderKind::Demo, true).await;
+        assert!(
+            !store
+                .get()
+                .await
+                .sorted_enabled_providers()
+                .contains(&ProviderKind::Demo)
+        );
+
+        store.set_demo_provider_enabled(true).await;
+        assert!(store.demo_provider_enabled().await);
+        assert!(
+            store
+                .get()
+                .await
+                .sorted_enabled_providers()
+                .contains(&ProviderKind::Demo)
+        );
+    }
+
     #[test]
     fn test_data_source_mode_display() {
         assert_eq!(format!("{}", DataSourceMode::Auto), "Auto");
@@ -1023,6 +3006,30 @@ mod tests {
         assert_eq!(format!("{}", ThemeMode::Dark), "dark");
         assert_eq!(format!("{}", ThemeMode::Light), "light");
         assert_eq!(format!("{}", ThemeMode::System), "system");
+        assert_eq!(format!("{}", ThemeMode::HighContrast), "high_contrast");
+    }
+
+    #[test]
+    fn test_window_background_serde() {
+        let settings: Settings = serde_json::from_str(
+            r#"{"window_background": "translucent", "vibrancy_material": "hud_window"}"#,
+        )
+        .unwrap();
+        assert_eq!(settings.window_background, WindowBackground::Translucent);
+        assert_eq!(settings.vibrancy_material, VibrancyMaterial::HudWindow);
+
+        let settings: Settings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings.window_background, WindowBackground::Blurred);
+        assert_eq!(settings.vibrancy_material, VibrancyMaterial::Standard);
+    }
+
+    #[test]
+    fn test_update_channel_serde() {
+        let json = serde_json::to_string(&UpdateChannel::Nightly).unwrap();
+        assert_eq!(json, "\"nightly\"");
+        let channel: UpdateChannel = serde_json::from_str("\"beta\"").unwrap();
+        assert_eq!(channel, UpdateChannel::Beta);
+        assert_eq!(format!("{}", UpdateChannel::Stable), "stable");
     }
 
     #[test]
@@ -0,0 +1,199 @@
+//! Clock time formatting.
+//!
+//! Reset times are shown on a 12- or 24-hour clock as the user prefers, or
+//! as the system locale does by default. Day and month names always come
+//! from the system locale.
+
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Local, Locale, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Locale used when the system one can't be read or isn't known.
+const FALLBACK_LOCALE: Locale = Locale::en_US;
+
+/// Clock format for displayed times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeFormat {
+    /// Follow the system locale.
+    #[default]
+    System,
+    /// 12-hour clock ("3:00 PM").
+    TwelveHour,
+    /// 24-hour clock ("15:00").
+    TwentyFourHour,
+}
+
+impl std::fmt::Display for TimeFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeFormat::System => write!(f, "system"),
+            TimeFormat::TwelveHour => write!(f, "12h"),
+            TimeFormat::TwentyFourHour => write!(f, "24h"),
+        }
+    }
+}
+
+impl TimeFormat {
+    /// Formats the clock time of `time` in local time, e.g. "15:00" or "3:00 PM".
+    pub fn format_time(self, time: DateTime<Utc>) -> String {
+        self.format_time_in(&time.with_timezone(&Local), system_locale())
+    }
+
+    /// Formats a reset time in local time, naming the day when it isn't
+    /// today: "09:00", "Tue 09:00", or "4 Mar 09:00" more than a week out.
+    pub fn format_reset(self, resets_at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+        self.format_reset_in(
+            &resets_at.with_timezone(&Local),
+            &now.with_timezone(&Local),
+            system_locale(),
+        )
+    }
+
+    fn is_24_hour_in(self, locale: Locale) -> bool {
+        match self {
+            TimeFormat::System => locale_is_24_hour(locale),
+            TimeFormat::TwelveHour => false,
+            TimeFormat::TwentyFourHour => true,
+        }
+    }
+
+    fn format_time_in<Tz: TimeZone>(self, time: &DateTime<Tz>, locale: Locale) -> String
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        let format = if self.is_24_hour_in(locale) {
+            "%H:%M"
+        } else {
+            "%-I:%M %p"
+        };
+        // Locales without AM/PM leave a trailing space
+        time.format_localized(format, locale)
+            .to_string()
+            .trim()
+            .to_string()
+    }
+
+    fn format_reset_in<Tz: TimeZone>(
+        self,
+        resets_at: &DateTime<Tz>,
+        now: &DateTime<Tz>,
+        locale: Locale,
+    ) -> String
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        let time = self.format_time_in(resets_at, locale);
+        let days = (resets_at.date_naive() - now.date_naive()).num_days();
+        if days == 0 {
+            time
+        } else if (1..7).contains(&days) {
+            format!("{} {}", resets_at.format_localized("%a", locale), time)
+        } else {
+            format!("{} {}", resets_at.format_localized("%-d %b", locale), time)
+        }
+    }
+}
+
+/// Formats the local date of `time` with the system locale's day and month
+/// names, e.g. "Tue 4 Mar".
+pub fn format_date(time: DateTime<Utc>) -> String {
+    time.with_timezone(&Local)
+        .format_localized("%a %-d %b", system_locale())
+        .to_string()
+}
+
+/// Returns the system locale, read once.
+fn system_locale() -> Locale {
+    static LOCALE: OnceLock<Locale> = OnceLock::new();
+    *LOCALE.get_or_init(|| {
+        sys_locale::get_locale()
+            .and_then(|tag| parse_locale(&tag))
+            .unwrap_or(FALLBACK_LOCALE)
+    })
+}
+
+/// Parses a BCP 47 tag ("en-US", "zh-Hans-CN") or a POSIX locale name
+/// ("de_DE.UTF-8").
+fn parse_locale(tag: &str) -> Option<Locale> {
+    let name = tag.split(['.', '@']).next()?.replace('-', "_");
+    if let Ok(locale) = Locale::try_from(name.as_str()) {
+        return Some(locale);
+    }
+    // Drop a script subtag: "zh_Hans_CN" -> "zh_CN"
+    let mut parts = name.split('_');
+    let language = parts.next()?;
+    let region = parts.next_back()?;
+    Locale::try_from(format!("{language}_{region}").as_str()).ok()
+}
+
+/// A locale uses the 24-hour clock if its time format shows 13:00 as 13.
+fn locale_is_24_hour(locale: Locale) -> bool {
+    NaiveDate::from_ymd_opt(2000, 1, 1)
+        .and_then(|date| date.and_hms_opt(13, 0, 0))
+        .is_some_and(|afternoon| {
+            afternoon
+                .and_utc()
+                .format_localized("%X", locale)
+                .to_string()
+                .contains("13")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    fn at(day: u32, hour: u32) -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(3600)
+            .unwrap()
+            .with_ymd_and_hms(2026, 3, day, hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_locale_clock() {
+        assert!(!locale_is_24_hour(Locale::en_US));
+        assert!(locale_is_24_hour(Locale::de_DE));
+        assert!(TimeFormat::TwentyFourHour.is_24_hour_in(Locale::en_US));
+        assert!(!TimeFormat::TwelveHour.is_24_hour_in(Locale::de_DE));
+    }
+
+    #[test]
+    fn test_parse_locale() {
+        assert_eq!(parse_locale("en-US"), Some(Locale::en_US));
+        assert_eq!(parse_locale("de_DE.UTF-8"), Some(Locale::de_DE));
+        assert_eq!(parse_locale("zh-Hans-CN"), Some(Locale::zh_CN));
+        assert_eq!(parse_locale("xx"), None);
+    }
+
+    #[test]
+    fn test_format_reset() {
+        // 2026-03-03 is a Tuesday
+        let now = at(3, 8);
+        let system = TimeFormat::System;
+
+        assert_eq!(
+            system.format_reset_in(&at(3, 15), &now, Locale::en_US),
+            "3:00 PM"
+        );
+        assert_eq!(
+            system.format_reset_in(&at(3, 15), &now, Locale::de_DE),
+            "15:00"
+        );
+        assert_eq!(
+            TimeFormat::TwentyFourHour.format_reset_in(&at(4, 9), &now, Locale::en_US),
+            "Wed 09:00"
+        );
+        assert_eq!(
+            system.format_reset_in(&at(4, 9), &now, Locale::de_DE),
+            "Mi 09:00"
+        );
+        assert_eq!(
+            system.format_reset_in(&at(12, 9), &now, Locale::en_US),
+            "12 Mar 9:00 AM"
+        );
+    }
+}
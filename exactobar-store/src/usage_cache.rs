@@ -0,0 +1,89 @@
+//! On-disk usage cache.
+//!
+//! The app saves the latest usage after every refresh so other processes
+//! (e.g. launcher extensions via `exactobar-app --query`) can read it
+//! instantly without fetching or starting the GUI.
+
+use chrono::{DateTime, Utc};
+use exactobar_core::{ProviderKind, UsageSnapshot};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::error::StoreError;
+use crate::persistence::{default_cache_path, load_json_or_default, save_json};
+
+/// Latest usage per provider, as last seen by the app.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UsageCache {
+    /// Last successful snapshot per provider.
+    pub snapshots: HashMap<ProviderKind, UsageSnapshot>,
+    /// Error from the latest refresh, for providers whose last refresh failed.
+    pub errors: HashMap<ProviderKind, String>,
+    /// When the cache was written.
+    pub saved_at: Option<DateTime<Utc>>,
+}
+
+impl UsageCache {
+    /// Loads the cache from `path`, or an empty cache if it can't be read.
+    pub async fn load(path: &Path) -> Self {
+        load_json_or_default(path).await
+    }
+
+    /// Loads the cache from the default location.
+    pub async fn load_default() -> Self {
+        Self::load(&default_cache_path()).await
+    }
+
+    /// Saves the cache to `path`, stamping `saved_at`.
+    pub async fn save(&mut self, path: &Path) -> Result<(), StoreError> {
+        self.saved_at = Some(Utc::now());
+        save_json(path, self).await
+    }
+
+    /// Saves the cache to the default location.
+    pub async fn save_default(&mut self) -> Result<(), StoreError> {
+        self.save(&default_cache_path()).await
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exactobar_core::UsageWindow;
+
+    #[tokio::test]
+    async fn test_save_and_load() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("usage_cache.json");
+
+        let mut snapshot = UsageSnapshot::new();
+        snapshot.primary = Some(UsageWindow::new(42.0));
+
+        let mut cache = UsageCache::default();
+        cache.snapshots.insert(ProviderKind::Claude, snapshot);
+        cache
+            .errors
+            .insert(ProviderKind::Codex, "Not logged in".to_string());
+        cache.save(&path).await.unwrap();
+
+        let loaded = UsageCache::load(&path).await;
+        assert!(loaded.saved_at.is_some());
+        let primary = loaded.snapshots[&ProviderKind::Claude].primary.as_ref();
+        assert!((primary.unwrap().used_percent - 42.0).abs() < 1e-9);
+        assert_eq!(loaded.errors[&ProviderKind::Codex], "Not logged in");
+    }
+
+    #[tokio::test]
+    async fn test_load_missing() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = UsageCache::load(&temp_dir.path().join("missing.json")).await;
+        assert!(cache.snapshots.is_empty());
+        assert!(cache.saved_at.is_none());
+    }
+}